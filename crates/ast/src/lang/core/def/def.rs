@@ -281,6 +281,7 @@ fn to_pending_def<'a>(
         Value(AstValueDef::Dbg { .. }) => todo!(),
         Value(AstValueDef::Expect { .. }) => todo!(),
         Value(AstValueDef::ExpectFx { .. }) => todo!(),
+        Value(AstValueDef::Malformed(_)) => todo!(),
 
         SpaceBefore(sub_def, _) | SpaceAfter(sub_def, _) => {
             to_pending_def(env, sub_def, scope, pattern_type)
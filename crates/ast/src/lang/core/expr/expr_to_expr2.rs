@@ -650,6 +650,7 @@ pub fn expr_to_expr2<'a>(
             //            (RuntimeError(MalformedClosure(region)), Output::default())
             todo!()
         }
+        Malformed(_) => todo!(),
         MalformedIdent(_name, _problem) => {
             //            use roc_problem::can::RuntimeError::*;
             //
@@ -91,31 +91,21 @@ fn flatten_str_lines<'a>(
                     }
                 },
                 Interpolated(loc_expr) => {
-                    if roc_can::expr::is_valid_interpolation(loc_expr.value) {
-                        // Interpolations desugar to Str.concat calls
-                        output.references.calls.insert(Symbol::STR_CONCAT);
+                    // Interpolations desugar to Str.concat calls
+                    output.references.calls.insert(Symbol::STR_CONCAT);
 
-                        if !buf.is_empty() {
-                            segments.push(StrSegment::Plaintext(PoolStr::new(&buf, env.pool)));
+                    if !buf.is_empty() {
+                        segments.push(StrSegment::Plaintext(PoolStr::new(&buf, env.pool)));
 
-                            buf = String::new();
-                        }
+                        buf = String::new();
+                    }
 
-                        let (loc_expr, new_output) =
-                            expr_to_expr2(env, scope, loc_expr.value, loc_expr.region);
+                    let (loc_expr, new_output) =
+                        expr_to_expr2(env, scope, loc_expr.value, loc_expr.region);
 
-                        output.union(new_output);
+                    output.union(new_output);
 
-                        segments.push(StrSegment::Interpolation(loc_expr));
-                    } else {
-                        //                        env.problem(Problem::InvalidInterpolation(loc_expr.region));
-                        //
-                        //                        return (
-                        //                            Expr::RuntimeError(RuntimeError::InvalidInterpolation(loc_expr.region)),
-                        //                            output,
-                        //                        );
-                        todo!()
-                    }
+                    segments.push(StrSegment::Interpolation(loc_expr));
                 }
                 EscapedChar(escaped) => buf.push(escaped.unescape()),
             }
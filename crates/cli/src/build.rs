@@ -4,12 +4,14 @@ use roc_build::{
         legacy_host_filename, link, preprocess_host_wasm32, preprocessed_host_filename,
         rebuild_host, LinkType, LinkingStrategy,
     },
-    program::{self, CodeGenBackend, CodeGenOptions},
+    program::{self, CodeGenBackend, CodeGenOptions, EmitStage},
 };
 use roc_builtins::bitcode;
+use roc_can::debug::{pretty_print_declarations, PPCtx};
+use roc_error_macros::user_error;
 use roc_load::{
-    EntryPoint, ExecutionMode, ExpectMetadata, LoadConfig, LoadMonomorphizedError, LoadedModule,
-    LoadingProblem, Threading,
+    CachedTypes, EntryPoint, ExecutionMode, ExpectMetadata, LoadConfig, LoadMonomorphizedError,
+    LoadedModule, LoadingProblem, Threading,
 };
 use roc_mono::ir::OptLevel;
 use roc_packaging::cache::RocCacheDir;
@@ -18,12 +20,13 @@ use roc_reporting::{
     report::{RenderTarget, DEFAULT_PALETTE},
 };
 use roc_target::TargetInfo;
+use roc_wasm_module::{wat::module_to_wat, WasmModule};
 use std::{
     path::Path,
     time::{Duration, Instant},
 };
 use std::{path::PathBuf, thread::JoinHandle};
-use target_lexicon::Triple;
+use target_lexicon::{Architecture, Triple};
 
 fn report_timing(buf: &mut String, label: &str, duration: Duration) {
     use std::fmt::Write;
@@ -37,13 +40,50 @@ fn report_timing(buf: &mut String, label: &str, duration: Duration) {
     .unwrap()
 }
 
+/// The most memory this process has had resident at once, in bytes
+#[cfg(unix)]
+fn peak_rss() -> Option<usize> {
+    let mut usage = std::mem::MaybeUninit::<libc::rusage>::zeroed();
+
+    if unsafe { libc::getrusage(libc::RUSAGE_SELF, usage.as_mut_ptr()) } != 0 {
+        return None;
+    }
+
+    let max_rss = unsafe { usage.assume_init() }.ru_maxrss as usize;
+
+    // macOS reports this in bytes, and everything else in kilobytes
+    if cfg!(target_os = "macos") {
+        Some(max_rss)
+    } else {
+        Some(max_rss * 1024)
+    }
+}
+
+#[cfg(not(unix))]
+fn peak_rss() -> Option<usize> {
+    None
+}
+
+fn print_peak_rss() {
+    match peak_rss() {
+        Some(bytes) => println!(
+            "Peak memory usage (resident set size): {:.3} MiB\n",
+            bytes as f64 / (1024.0 * 1024.0)
+        ),
+        None => println!("Peak memory usage isn't available on this operating system\n"),
+    }
+}
+
 pub struct BuiltFile<'a> {
     pub binary_path: PathBuf,
     pub problems: Problems,
     pub total_time: Duration,
     pub expect_metadata: ExpectMetadata<'a>,
+    /// The .roc files that were loaded, which `--watch` watches for changes
+    pub loaded_files: Vec<PathBuf>,
 }
 
+#[derive(Clone, Copy)]
 pub enum BuildOrdering {
     /// Run up through typechecking first; continue building iff that is successful.
     BuildIfChecks,
@@ -79,6 +119,7 @@ pub fn standard_load_config(
     target: &Triple,
     order: BuildOrdering,
     threading: Threading,
+    opt_level: OptLevel,
 ) -> LoadConfig {
     let target_info = TargetInfo::from(target);
 
@@ -93,6 +134,7 @@ pub fn standard_load_config(
         palette: DEFAULT_PALETTE,
         threading,
         exec_mode,
+        opt_level,
     }
 }
 
@@ -103,19 +145,26 @@ pub fn build_file<'a>(
     app_module_path: PathBuf,
     code_gen_options: CodeGenOptions,
     emit_timings: bool,
+    emit_mem_stats: bool,
     link_type: LinkType,
     linking_strategy: LinkingStrategy,
     prebuilt_requested: bool,
     wasm_dev_stack_bytes: Option<u32>,
     roc_cache_dir: RocCacheDir<'_>,
     load_config: LoadConfig,
+    cached_types: CachedTypes,
 ) -> Result<BuiltFile<'a>, BuildFileError<'a>> {
     let compilation_start = Instant::now();
 
     // Step 1: compile the app and generate the .o file
-    let loaded =
-        roc_load::load_and_monomorphize(arena, app_module_path.clone(), roc_cache_dir, load_config)
-            .map_err(|e| BuildFileError::from_mono_error(e, compilation_start))?;
+    let loaded = roc_load::load_and_monomorphize_with_types(
+        arena,
+        app_module_path.clone(),
+        roc_cache_dir,
+        load_config,
+        cached_types,
+    )
+    .map_err(|e| BuildFileError::from_mono_error(e, compilation_start))?;
 
     build_loaded_file(
         arena,
@@ -123,15 +172,62 @@ pub fn build_file<'a>(
         app_module_path,
         code_gen_options,
         emit_timings,
+        emit_mem_stats,
         link_type,
         linking_strategy,
         prebuilt_requested,
         wasm_dev_stack_bytes,
+        roc_cache_dir,
         loaded,
         compilation_start,
     )
 }
 
+/// The app module's canonical IR
+fn can_to_pretty(loaded: &roc_load::MonomorphizedModule) -> Option<String> {
+    let declarations = loaded.declarations_by_id.get(&loaded.module_id)?;
+    let ctx = PPCtx {
+        home: loaded.module_id,
+        interns: &loaded.interns,
+        print_lambda_names: true,
+    };
+
+    Some(pretty_print_declarations(&ctx, declarations))
+}
+
+/// Print the final wasm binary in the WebAssembly text format
+fn emit_wat(target: &Triple, binary_path: &Path) {
+    if target.architecture != Architecture::Wasm32 {
+        eprintln!("--emit=wat only applies to wasm32 targets, so I skipped it.");
+        return;
+    }
+
+    let bytes = match std::fs::read(binary_path) {
+        Ok(bytes) => bytes,
+        Err(error) => {
+            eprintln!(
+                "I couldn't read {} for --emit=wat: {}",
+                binary_path.display(),
+                error
+            );
+            return;
+        }
+    };
+
+    let arena = Bump::new();
+    let wat = WasmModule::preload(&arena, &bytes, false).and_then(|module| module_to_wat(&module));
+
+    match wat {
+        Ok(wat) => program::write_emitted(binary_path, "wat", wat.as_bytes()),
+        Err(error) => eprintln!(
+            "I couldn't print {} for --emit=wat, because of a problem at offset 0x{:x}:\n{}",
+            binary_path.display(),
+            error.offset,
+            error.message
+        ),
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn build_loaded_file<'a>(
     arena: &'a Bump,
@@ -139,10 +235,12 @@ fn build_loaded_file<'a>(
     app_module_path: PathBuf,
     code_gen_options: CodeGenOptions,
     emit_timings: bool,
+    emit_mem_stats: bool,
     link_type: LinkType,
     linking_strategy: LinkingStrategy,
     prebuilt_requested: bool,
     wasm_dev_stack_bytes: Option<u32>,
+    roc_cache_dir: RocCacheDir<'_>,
     loaded: roc_load::MonomorphizedModule<'a>,
     compilation_start: Instant,
 ) -> Result<BuiltFile<'a>, BuildFileError<'a>> {
@@ -177,8 +275,17 @@ fn build_loaded_file<'a>(
         output_exe_path.set_extension(extension);
     }
 
+    if code_gen_options.emit.contains(EmitStage::Can) {
+        if let Some(can_output) = can_to_pretty(&loaded) {
+            program::write_emitted(&output_exe_path, "can", can_output.as_bytes());
+        }
+    }
+
     // We don't need to spawn a rebuild thread when using a prebuilt host.
-    let rebuild_thread = if matches!(link_type, LinkType::Dylib | LinkType::None) {
+    let rebuild_thread = if matches!(
+        link_type,
+        LinkType::Dylib | LinkType::StaticLib | LinkType::None
+    ) {
         None
     } else if is_platform_prebuilt {
         if !preprocessed_host_path.exists() {
@@ -235,34 +342,41 @@ fn build_loaded_file<'a>(
     };
 
     let buf = &mut String::with_capacity(1024);
+    let mem_buf = &mut String::with_capacity(1024);
 
     let mut it = loaded.timings.iter().peekable();
     while let Some((module_id, module_timing)) = it.next() {
         let module_name = loaded.interns.module_name(*module_id);
-
-        buf.push_str("    ");
-
-        if module_name.is_empty() {
+        let module_name = if module_name.is_empty() {
             // the App module
-            buf.push_str("Application Module");
+            "Application Module"
         } else {
-            buf.push_str(module_name);
-        }
-
-        buf.push('\n');
+            module_name
+        };
 
         use std::fmt::Write;
-        write!(buf, "{}", module_timing).unwrap();
+        write!(buf, "    {}\n{}", module_name, module_timing).unwrap();
+        write!(mem_buf, "    {}\n{}", module_name, module_timing.memory).unwrap();
 
         if it.peek().is_some() {
             buf.push('\n');
+            mem_buf.push('\n');
         }
     }
 
+    mem_buf.push_str("\n    All Modules\n");
+    roc_load::report_bytes(
+        mem_buf,
+        "Layout interner",
+        loaded.layout_interner.memory_usage(),
+    )
+    .unwrap();
+
     // This only needs to be mutable for report_problems. This can't be done
     // inside a nested scope without causing a borrow error!
     let mut loaded = loaded;
     let problems = program::report_problems_monomorphized(&mut loaded);
+    let loaded_files = crate::watch::loaded_files(&loaded.sources);
     let loaded = loaded;
 
     enum HostRebuildTiming {
@@ -291,16 +405,21 @@ fn build_loaded_file<'a>(
         None
     };
 
+    let code_gen_start = Instant::now();
     let (roc_app_bytes, code_gen_timing, expect_metadata) = program::gen_from_mono_module(
         arena,
         loaded,
         &app_module_path,
+        &output_exe_path,
         target,
         code_gen_options,
         &preprocessed_host_path,
+        linking_strategy,
         wasm_dev_stack_bytes,
     );
 
+    roc_tracing::chrome_trace::record("generate code", None, code_gen_start, Instant::now());
+
     buf.push('\n');
     buf.push_str("    ");
     buf.push_str("Code Generation");
@@ -328,6 +447,13 @@ fn build_loaded_file<'a>(
         );
     }
 
+    if emit_mem_stats {
+        println!(
+            "\n\nHere's how much memory each module used while compiling:\n\n{}",
+            mem_buf
+        );
+    }
+
     if let Some(HostRebuildTiming::ConcurrentWithApp(thread)) = opt_rebuild_timing {
         let rebuild_duration = thread.join().expect("Failed to (re)build platform.");
 
@@ -371,17 +497,24 @@ fn build_loaded_file<'a>(
 
             let mut inputs = vec![app_o_file.to_str().unwrap()];
 
-            if !matches!(link_type, LinkType::Dylib | LinkType::None) {
+            if !matches!(
+                link_type,
+                LinkType::Dylib | LinkType::StaticLib | LinkType::None
+            ) {
                 // the host has been compiled into a .o or .obj file
                 inputs.push(preprocessed_host_path.as_path().to_str().unwrap());
             }
 
-            if matches!(code_gen_options.backend, program::CodeGenBackend::Assembly) {
+            // A static library is linked by some other toolchain, so it brings the builtins along
+            if matches!(code_gen_options.backend, program::CodeGenBackend::Assembly)
+                || link_type == LinkType::StaticLib
+            {
                 inputs.push(builtins_host_tempfile.path().to_str().unwrap());
             }
 
-            let (mut child, _) = link(target, output_exe_path.clone(), &inputs, link_type)
-                .map_err(|_| todo!("gracefully handle `ld` failing to spawn."))?;
+            let (mut child, binary_path) =
+                link(target, output_exe_path.clone(), &inputs, link_type)
+                    .map_err(|_| todo!("gracefully handle `ld` failing to spawn."))?;
 
             let exit_status = child
                 .wait()
@@ -397,15 +530,37 @@ fn build_loaded_file<'a>(
                     exit_status.code()
                 );
             }
+
+            if matches!(link_type, LinkType::Dylib | LinkType::StaticLib) {
+                write_c_header(
+                    target,
+                    &platform_main_roc,
+                    &output_exe_path.with_extension("h"),
+                    roc_cache_dir,
+                );
+
+                // Libraries get an extension like .so or .a, so report where it really is
+                output_exe_path = binary_path;
+            }
         }
     }
 
     let linking_time = link_start.elapsed();
 
+    roc_tracing::chrome_trace::record("link", None, link_start, Instant::now());
+
     if emit_timings {
         println!("Finished linking in {} ms\n", linking_time.as_millis());
     }
 
+    if emit_mem_stats {
+        print_peak_rss();
+    }
+
+    if code_gen_options.emit.contains(EmitStage::Wat) {
+        emit_wat(target, &output_exe_path);
+    }
+
     let total_time = compilation_start.elapsed();
 
     Ok(BuiltFile {
@@ -413,9 +568,39 @@ fn build_loaded_file<'a>(
         problems,
         total_time,
         expect_metadata,
+        loaded_files,
     })
 }
 
+/// Declares the library's entry points, so C code (or anything that can use a C header) can call
+/// them.
+fn write_c_header(
+    target: &Triple,
+    platform_main_roc: &Path,
+    header_path: &Path,
+    roc_cache_dir: RocCacheDir<'_>,
+) {
+    let (types, entry_points) = roc_glue::load::load_entry_points(
+        platform_main_roc.to_path_buf(),
+        Threading::AllAvailable,
+        TargetInfo::from(target),
+        roc_cache_dir,
+    )
+    .unwrap_or_else(|problem| {
+        user_error!("I couldn't write the library's C header:\n\n{}", problem)
+    });
+
+    std::fs::write(header_path, roc_glue::c_glue::emit(&types, &entry_points)).unwrap_or_else(
+        |err| {
+            user_error!(
+                "I couldn't write the library's C header to {}: {}",
+                header_path.to_string_lossy(),
+                err
+            )
+        },
+    );
+}
+
 fn invalid_prebuilt_platform(prebuilt_requested: bool, preprocessed_host_path: PathBuf) {
     let prefix = match prebuilt_requested {
         true => "Because I was run with --prebuilt-platform=true, ",
@@ -451,7 +636,9 @@ fn spawn_rebuild_thread(
     exported_closure_types: Vec<String>,
 ) -> std::thread::JoinHandle<u128> {
     let thread_local_target = target.clone();
-    std::thread::spawn(move || {
+    let builder = std::thread::Builder::new().name("rebuild platform".to_string());
+
+    let handle = builder.spawn(move || {
         // Printing to stderr because we want stdout to contain only the output of the roc program.
         // We are aware of the trade-offs.
         // `cargo run` follows the same approach
@@ -494,8 +681,17 @@ fn spawn_rebuild_thread(
             }
         }
 
+        roc_tracing::chrome_trace::record(
+            "rebuild platform",
+            None,
+            rebuild_host_start,
+            Instant::now(),
+        );
+
         rebuild_host_start.elapsed().as_millis()
-    })
+    });
+
+    handle.expect("Failed to spawn the thread to rebuild the platform.")
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -503,9 +699,11 @@ pub fn check_file<'a>(
     arena: &'a Bump,
     roc_file_path: PathBuf,
     emit_timings: bool,
+    emit_mem_stats: bool,
     roc_cache_dir: RocCacheDir<'_>,
     threading: Threading,
-) -> Result<(Problems, Duration), LoadingProblem<'a>> {
+    cached_types: CachedTypes,
+) -> Result<(Problems, Duration, Vec<PathBuf>), LoadingProblem<'a>> {
     let compilation_start = Instant::now();
 
     // only used for generating errors. We don't do code generation, so hardcoding should be fine
@@ -521,27 +719,36 @@ pub fn check_file<'a>(
         palette: DEFAULT_PALETTE,
         threading,
         exec_mode: ExecutionMode::Check,
+        opt_level: OptLevel::Normal,
     };
-    let mut loaded =
-        roc_load::load_and_typecheck(arena, roc_file_path, roc_cache_dir, load_config)?;
+    let mut loaded = roc_load::load_and_typecheck_with_types(
+        arena,
+        roc_file_path,
+        roc_cache_dir,
+        load_config,
+        cached_types,
+    )?;
 
     let buf = &mut String::with_capacity(1024);
+    let mem_buf = &mut String::with_capacity(1024);
 
     let mut it = loaded.timings.iter().peekable();
     while let Some((module_id, module_timing)) = it.next() {
         let module_name = loaded.interns.module_name(*module_id);
-
-        buf.push_str("    ");
-
-        if module_name.is_empty() {
+        let module_name = if module_name.is_empty() {
             // the App module
-            buf.push_str("Application Module");
+            "Application Module"
         } else {
-            buf.push_str(module_name);
-        }
+            module_name
+        };
 
+        buf.push_str("    ");
+        buf.push_str(module_name);
         buf.push('\n');
 
+        use std::fmt::Write;
+        write!(mem_buf, "    {}\n{}", module_name, module_timing.memory).unwrap();
+
         report_timing(buf, "Read .roc file from disk", module_timing.read_roc_file);
         report_timing(buf, "Parse header", module_timing.parse_header);
         report_timing(buf, "Parse body", module_timing.parse_body);
@@ -554,6 +761,7 @@ pub fn check_file<'a>(
 
         if it.peek().is_some() {
             buf.push('\n');
+            mem_buf.push('\n');
         }
     }
 
@@ -568,9 +776,19 @@ pub fn check_file<'a>(
         println!("Finished checking in {} ms\n", compilation_end.as_millis(),);
     }
 
+    if emit_mem_stats {
+        println!(
+            "\n\nHere's how much memory each module used while checking:\n\n{}",
+            mem_buf
+        );
+
+        print_peak_rss();
+    }
+
     Ok((
         program::report_problems_typechecked(&mut loaded),
         compilation_end,
+        crate::watch::loaded_files(&loaded.sources),
     ))
 }
 
@@ -586,6 +804,7 @@ pub fn build_str_test<'a>(
         backend: CodeGenBackend::Llvm,
         opt_level: OptLevel::Normal,
         emit_debug_info: false,
+        emit: Default::default(),
     };
 
     let emit_timings = false;
@@ -597,7 +816,12 @@ pub fn build_str_test<'a>(
     let build_ordering = BuildOrdering::AlwaysBuild;
     let threading = Threading::AtMost(2);
 
-    let load_config = standard_load_config(&triple, build_ordering, threading);
+    let load_config = standard_load_config(
+        &triple,
+        build_ordering,
+        threading,
+        code_gen_options.opt_level,
+    );
 
     let compilation_start = std::time::Instant::now();
 
@@ -618,10 +842,12 @@ pub fn build_str_test<'a>(
         app_module_path.to_path_buf(),
         code_gen_options,
         emit_timings,
+        false,
         link_type,
         linking_strategy,
         assume_prebuild,
         wasm_dev_stack_bytes,
+        roc_cache_dir,
         loaded,
         compilation_start,
     )
@@ -0,0 +1,82 @@
+use crate::{roc_cache_dir_from_flags, FLAG_VENDOR_DIR, ROC_FILE};
+use clap::ArgMatches;
+use roc_packaging::cache::{self, RocCacheDir};
+use roc_packaging::fetch::{fetch_packages, vendor_packages};
+use roc_packaging::https::Problem;
+use std::io;
+use std::path::Path;
+
+/// Download every package the given module depends on into the cache, so that later builds
+/// can use --offline.
+pub fn fetch(matches: &ArgMatches) -> io::Result<i32> {
+    let root_module = Path::new(matches.value_of_os(ROC_FILE).unwrap());
+    let cache_dir = cache::roc_cache_dir();
+
+    match fetch_packages(RocCacheDir::Persistent(cache_dir.as_path()), root_module) {
+        Ok(packages) => {
+            for package in &packages {
+                println!("{}\n    {}", package.url, package.package_dir.display());
+            }
+
+            println!(
+                "\n\x1B[32m{}\x1B[39m {} in {}",
+                packages.len(),
+                if packages.len() == 1 {
+                    "package is"
+                } else {
+                    "packages are"
+                },
+                cache_dir.display()
+            );
+
+            Ok(0)
+        }
+        Err(problem) => {
+            report_problem(problem);
+
+            Ok(1)
+        }
+    }
+}
+
+/// Copy every package the given module depends on into a local directory.
+pub fn vendor(matches: &ArgMatches) -> io::Result<i32> {
+    let root_module = Path::new(matches.value_of_os(ROC_FILE).unwrap());
+    let vendor_dir = Path::new(matches.value_of_os(FLAG_VENDOR_DIR).unwrap());
+    let cache_dir = cache::roc_cache_dir();
+
+    match vendor_packages(
+        roc_cache_dir_from_flags(matches, cache_dir.as_path()),
+        root_module,
+        vendor_dir,
+    ) {
+        Ok(packages) => {
+            println!("To use the vendored packages, replace these URLs in your app's header with their paths:\n");
+
+            for (package, vendored_root_module) in &packages {
+                println!("{}\n    {}", package.url, vendored_root_module.display());
+            }
+
+            Ok(0)
+        }
+        Err(problem) => {
+            report_problem(problem);
+
+            Ok(1)
+        }
+    }
+}
+
+fn report_problem(problem: Problem) {
+    match problem {
+        Problem::NotCachedWhileOffline(url) => {
+            eprintln!("The package {url} isn't in the cache, and I'm not allowed to download it because of --offline.");
+        }
+        Problem::InvalidContentHash { expected, actual } => {
+            eprintln!("A downloaded package's hash was {actual}, but its URL says it should be {expected}. The package may have been tampered with, so I didn't use it.");
+        }
+        other => {
+            eprintln!("I couldn't get all the packages: {:?}", other);
+        }
+    }
+}
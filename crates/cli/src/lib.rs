@@ -5,11 +5,12 @@ extern crate const_format;
 
 use build::BuiltFile;
 use bumpalo::Bump;
-use clap::{Arg, ArgMatches, Command, ValueSource};
+use clap::{Arg, ArgMatches, Command, PossibleValue, ValueSource};
 use roc_build::link::{LinkType, LinkingStrategy};
-use roc_build::program::{CodeGenBackend, CodeGenOptions};
+use roc_build::program::{CodeGenBackend, CodeGenOptions, EmitStage, EmitStages};
+use roc_docs::DocsFormat;
 use roc_error_macros::{internal_error, user_error};
-use roc_load::{ExpectMetadata, LoadingProblem, Threading};
+use roc_load::{CachedTypes, ExpectMetadata, LoadingProblem, Threading};
 use roc_mono::ir::OptLevel;
 use roc_packaging::cache::RocCacheDir;
 use roc_packaging::tarball::Compression;
@@ -24,14 +25,19 @@ use std::time::Instant;
 use strum::{EnumIter, IntoEnumIterator, IntoStaticStr};
 use target_lexicon::BinaryFormat;
 use target_lexicon::{
-    Architecture, Environment, OperatingSystem, Triple, Vendor, X86_32Architecture,
+    Aarch64Architecture, Architecture, Environment, OperatingSystem, Triple, Vendor,
+    X86_32Architecture,
 };
 #[cfg(not(target_os = "linux"))]
 use tempfile::TempDir;
 
 pub mod build;
+mod fetch;
 mod format;
+mod watch;
+pub use fetch::{fetch, vendor};
 pub use format::format;
+pub use watch::watch;
 
 use crate::build::{standard_load_config, BuildFileError, BuildOrdering};
 
@@ -49,6 +55,8 @@ pub const CMD_FORMAT: &str = "format";
 pub const CMD_TEST: &str = "test";
 pub const CMD_GLUE: &str = "glue";
 pub const CMD_GEN_STUB_LIB: &str = "gen-stub-lib";
+pub const CMD_FETCH: &str = "fetch";
+pub const CMD_VENDOR: &str = "vendor";
 
 pub const FLAG_DEBUG: &str = "debug";
 pub const FLAG_BUNDLE: &str = "bundle";
@@ -60,10 +68,17 @@ pub const FLAG_LIB: &str = "lib";
 pub const FLAG_NO_LINK: &str = "no-link";
 pub const FLAG_TARGET: &str = "target";
 pub const FLAG_TIME: &str = "time";
+pub const FLAG_MEM_STATS: &str = "mem-stats";
+pub const FLAG_WATCH: &str = "watch";
 pub const FLAG_LINKER: &str = "linker";
 pub const FLAG_PREBUILT: &str = "prebuilt-platform";
 pub const FLAG_CHECK: &str = "check";
 pub const FLAG_WASM_STACK_SIZE_KB: &str = "wasm-stack-size-kb";
+pub const FLAG_DOCTESTS: &str = "doctests";
+pub const FLAG_DOCS_FORMAT: &str = "format";
+pub const FLAG_OFFLINE: &str = "offline";
+pub const FLAG_VENDOR_DIR: &str = "dir";
+pub const FLAG_EMIT: &str = "emit";
 pub const ROC_FILE: &str = "ROC_FILE";
 pub const ROC_DIR: &str = "ROC_DIR";
 pub const GLUE_FILE: &str = "GLUE_FILE";
@@ -102,12 +117,26 @@ pub fn build_app<'a>() -> Command<'a> {
 
     let flag_time = Arg::new(FLAG_TIME)
         .long(FLAG_TIME)
-        .help("Print detailed compilation time information")
+        .help("Print detailed compilation time information\n(With --time=<file>, also write a trace of each compiler phase, per module and per thread, to that file. It can be opened in chrome://tracing or https://ui.perfetto.dev)")
+        .value_name("trace.json")
+        .allow_invalid_utf8(true)
+        .min_values(0)
+        .require_equals(true)
+        .required(false);
+
+    let flag_mem_stats = Arg::new(FLAG_MEM_STATS)
+        .long(FLAG_MEM_STATS)
+        .help("Print how much memory compilation used, per module and phase, as well as the size of the layout interner and the peak resident set size")
+        .required(false);
+
+    let flag_watch = Arg::new(FLAG_WATCH)
+        .long(FLAG_WATCH)
+        .help("Run again whenever any of the .roc files that were loaded change, including the platform's and packages'")
         .required(false);
 
     let flag_linker = Arg::new(FLAG_LINKER)
         .long(FLAG_LINKER)
-        .help("Set which linker to use\n(The surgical linker is enabled by default only when building for wasm32 or x86_64 Linux, because those are the only targets it currently supports. Otherwise the legacy linker is used by default.)")
+        .help("Set which linker to use\n(The surgical linker is enabled by default only when building for x86_64 Linux, because that is the only target it fully supports. For wasm32 it has to be asked for with --linker=surgical. Otherwise the legacy linker is used by default.)")
         .possible_values(["surgical", "legacy"])
         .required(false);
 
@@ -124,6 +153,21 @@ pub fn build_app<'a>() -> Command<'a> {
         .validator(|s| s.parse::<u32>())
         .required(false);
 
+    let flag_offline = Arg::new(FLAG_OFFLINE)
+        .long(FLAG_OFFLINE)
+        .help("Never download packages, and report an error if one isn't in the cache already\n(Use `roc fetch` beforehand to download them all.)")
+        .required(false);
+
+    let flag_emit = Arg::new(FLAG_EMIT)
+        .long(FLAG_EMIT)
+        .help("Also write the output of these compiler stages next to the build output\n(For example, --emit=mono,llvm-ir writes app.mono and app.ll beside app.)")
+        .possible_values(EmitStage::NAMES)
+        .multiple_occurrences(true)
+        .use_value_delimiter(true)
+        .require_value_delimiter(true)
+        .require_equals(true)
+        .required(false);
+
     let roc_file_to_run = Arg::new(ROC_FILE)
         .help("The .roc file of an app to run")
         .allow_invalid_utf8(true)
@@ -149,23 +193,27 @@ pub fn build_app<'a>() -> Command<'a> {
             .arg(flag_dev.clone())
             .arg(flag_debug.clone())
             .arg(flag_time.clone())
+            .arg(flag_mem_stats.clone())
             .arg(flag_linker.clone())
             .arg(flag_prebuilt.clone())
+            .arg(flag_offline.clone())
             .arg(flag_wasm_stack_size_kb.clone())
             .arg(
                 Arg::new(FLAG_TARGET)
                     .long(FLAG_TARGET)
                     .help("Choose a different target")
                     .default_value(Target::default().into())
-                    .possible_values(Target::iter().map(|target| {
-                        Into::<&'static str>::into(target)
-                    }))
+                    .possible_values(Target::iter().map(Target::possible_value))
                     .required(false),
             )
             .arg(
                 Arg::new(FLAG_LIB)
                     .long(FLAG_LIB)
-                    .help("Build a C library instead of an executable")
+                    .help("Build a C library instead of an executable, along with a C header for it\n(This is a shared library unless it's `--lib=static`.)")
+                    .possible_values(["shared", "static"])
+                    .min_values(0)
+                    .require_equals(true)
+                    .default_missing_value("shared")
                     .required(false),
             )
             .arg(
@@ -182,6 +230,7 @@ pub fn build_app<'a>() -> Command<'a> {
                     .help("Do not link\n(Instead, just output the `.o` file.)")
                     .required(false),
             )
+            .arg(flag_emit.clone())
             .arg(
                 Arg::new(ROC_FILE)
                     .help("The .roc file to build")
@@ -198,8 +247,16 @@ pub fn build_app<'a>() -> Command<'a> {
             .arg(flag_dev.clone())
             .arg(flag_debug.clone())
             .arg(flag_time.clone())
+            .arg(flag_watch.clone())
             .arg(flag_linker.clone())
             .arg(flag_prebuilt.clone())
+            .arg(flag_offline.clone())
+            .arg(
+                Arg::new(FLAG_DOCTESTS)
+                    .long(FLAG_DOCTESTS)
+                    .help("Also run the `>>>` examples in doc comments which are followed by their expected result")
+                    .required(false),
+            )
             .arg(
                 Arg::new(ROC_FILE)
                     .help("The .roc file for the main module")
@@ -220,8 +277,11 @@ pub fn build_app<'a>() -> Command<'a> {
             .arg(flag_dev.clone())
             .arg(flag_debug.clone())
             .arg(flag_time.clone())
+            .arg(flag_mem_stats.clone())
             .arg(flag_linker.clone())
             .arg(flag_prebuilt.clone())
+            .arg(flag_offline.clone())
+            .arg(flag_emit.clone())
             .arg(roc_file_to_run.clone())
             .arg(args_for_app.clone())
         )
@@ -233,8 +293,12 @@ pub fn build_app<'a>() -> Command<'a> {
             .arg(flag_dev.clone())
             .arg(flag_debug.clone())
             .arg(flag_time.clone())
+            .arg(flag_mem_stats.clone())
+            .arg(flag_watch.clone())
             .arg(flag_linker.clone())
             .arg(flag_prebuilt.clone())
+            .arg(flag_offline.clone())
+            .arg(flag_emit.clone())
             .arg(roc_file_to_run.clone())
             .arg(args_for_app.clone())
         )
@@ -258,7 +322,10 @@ pub fn build_app<'a>() -> Command<'a> {
         .subcommand(Command::new(CMD_CHECK)
             .about("Check the code for problems, but don’t build or run it")
             .arg(flag_time.clone())
+            .arg(flag_mem_stats.clone())
+            .arg(flag_watch.clone())
             .arg(flag_max_threads.clone())
+            .arg(flag_offline.clone())
             .arg(
                 Arg::new(ROC_FILE)
                     .help("The .roc file of an app to check")
@@ -270,6 +337,15 @@ pub fn build_app<'a>() -> Command<'a> {
         .subcommand(
             Command::new(CMD_DOCS)
                 .about("Generate documentation for a Roc package")
+                .arg(
+                    Arg::new(FLAG_DOCS_FORMAT)
+                        .long(FLAG_DOCS_FORMAT)
+                        .help("Generate the docs as a website, a JSON file, or Markdown files")
+                        .takes_value(true)
+                        .possible_values(DocsFormat::NAMES)
+                        .default_value("html")
+                        .required(false),
+                )
                 .arg(Arg::new(ROC_FILE)
                     .multiple_values(true)
                     .help("The package's main .roc file")
@@ -278,6 +354,36 @@ pub fn build_app<'a>() -> Command<'a> {
                     .default_value(DEFAULT_ROC_FILENAME),
                 )
         )
+        .subcommand(Command::new(CMD_FETCH)
+            .about("Download all the packages an app or package depends on into the cache\n(After this, it can be built with --offline.)")
+            .arg(
+                Arg::new(ROC_FILE)
+                    .help("The .roc file whose packages should be downloaded")
+                    .allow_invalid_utf8(true)
+                    .required(false)
+                    .default_value(DEFAULT_ROC_FILENAME),
+            )
+        )
+        .subcommand(Command::new(CMD_VENDOR)
+            .about("Copy all the packages an app or package depends on into a local directory")
+            .arg(flag_offline.clone())
+            .arg(
+                Arg::new(FLAG_VENDOR_DIR)
+                    .long(FLAG_VENDOR_DIR)
+                    .help("The directory to copy the packages into")
+                    .takes_value(true)
+                    .allow_invalid_utf8(true)
+                    .default_value("vendor")
+                    .required(false),
+            )
+            .arg(
+                Arg::new(ROC_FILE)
+                    .help("The .roc file whose packages should be copied")
+                    .allow_invalid_utf8(true)
+                    .required(false)
+                    .default_value(DEFAULT_ROC_FILENAME),
+            )
+        )
         .subcommand(Command::new(CMD_GLUE)
             .about("Generate glue code between a platform's Roc API and its host language")
             .arg(
@@ -306,9 +412,7 @@ pub fn build_app<'a>() -> Command<'a> {
                     .long(FLAG_TARGET)
                     .help("Choose a different target")
                     .default_value(Target::default().into())
-                    .possible_values(Target::iter().map(|target| {
-                        Into::<&'static str>::into(target)
-                    }))
+                    .possible_values(Target::iter().map(Target::possible_value))
                     .required(false),
             )
         )
@@ -319,8 +423,12 @@ pub fn build_app<'a>() -> Command<'a> {
         .arg(flag_dev)
         .arg(flag_debug)
         .arg(flag_time)
+        .arg(flag_mem_stats)
+        .arg(flag_watch)
         .arg(flag_linker)
         .arg(flag_prebuilt)
+        .arg(flag_offline)
+        .arg(flag_emit)
         .arg(roc_file_to_run.required(false))
         .arg(args_for_app);
 
@@ -347,6 +455,16 @@ pub enum BuildConfig {
     BuildAndRunIfNoErrors,
 }
 
+/// Packages must already be in the cache when running with --offline; otherwise they are
+/// downloaded into it as needed.
+pub fn roc_cache_dir_from_flags<'a>(matches: &ArgMatches, cache_dir: &'a Path) -> RocCacheDir<'a> {
+    if matches.is_present(FLAG_OFFLINE) {
+        RocCacheDir::Offline(cache_dir)
+    } else {
+        RocCacheDir::Persistent(cache_dir)
+    }
+}
+
 pub enum FormatMode {
     Format,
     CheckOnly,
@@ -359,6 +477,48 @@ pub fn test(_matches: &ArgMatches, _triple: Triple) -> io::Result<i32> {
 
 #[cfg(not(windows))]
 pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
+    let filename = matches.value_of_os(ROC_FILE).unwrap();
+    let path = Path::new(filename);
+
+    // Spawn the root task
+    if !path.exists() {
+        let path_string = path.to_string_lossy();
+
+        // TODO these should use roc_reporting to display nicer error messages.
+        match matches.value_source(ROC_FILE) {
+            Some(ValueSource::DefaultValue) => {
+                eprintln!(
+                    "\nNo `.roc` file was specified, and the current directory does not contain a {} file to use as a default.\n\nYou can run `roc help` for more information on how to provide a .roc file.\n",
+                    DEFAULT_ROC_FILENAME
+                )
+            }
+            _ => eprintln!("\nThis file was not found: {}\n\nYou can run `roc help` for more information on how to provide a .roc file.\n", path_string),
+        }
+
+        process::exit(1);
+    }
+
+    if matches.is_present(FLAG_WATCH) {
+        watch(path, |loaded_files, cached_types| {
+            run_tests(matches, &triple, loaded_files, cached_types)
+        })
+    } else {
+        run_tests(
+            matches,
+            &triple,
+            &mut Vec::new(),
+            CachedTypes::deserialize(),
+        )
+    }
+}
+
+#[cfg(not(windows))]
+fn run_tests(
+    matches: &ArgMatches,
+    target: &Triple,
+    loaded_files: &mut Vec<PathBuf>,
+    cached_types: CachedTypes,
+) -> io::Result<i32> {
     use roc_build::program::report_problems_monomorphized;
     use roc_gen_llvm::llvm::build::LlvmBackendMode;
     use roc_load::{ExecutionMode, LoadConfig, LoadMonomorphizedError};
@@ -391,30 +551,16 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
     };
 
     let path = Path::new(filename);
-
-    // Spawn the root task
-    if !path.exists() {
-        let path_string = path.to_string_lossy();
-
-        // TODO these should use roc_reporting to display nicer error messages.
-        match matches.value_source(ROC_FILE) {
-            Some(ValueSource::DefaultValue) => {
-                eprintln!(
-                    "\nNo `.roc` file was specified, and the current directory does not contain a {} file to use as a default.\n\nYou can run `roc help` for more information on how to provide a .roc file.\n",
-                    DEFAULT_ROC_FILENAME
-                )
-            }
-            _ => eprintln!("\nThis file was not found: {}\n\nYou can run `roc help` for more information on how to provide a .roc file.\n", path_string),
-        }
-
-        process::exit(1);
-    }
-
     let arena = &arena;
-    let target = &triple;
     let opt_level = opt_level;
     let target_info = TargetInfo::from(target);
 
+    let exec_mode = if matches.is_present(FLAG_DOCTESTS) {
+        ExecutionMode::TestWithDoctests
+    } else {
+        ExecutionMode::Test
+    };
+
     // Step 1: compile the app and generate the .o file
     let load_config = LoadConfig {
         target_info,
@@ -422,13 +568,15 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
         render: roc_reporting::report::RenderTarget::ColorTerminal,
         palette: roc_reporting::report::DEFAULT_PALETTE,
         threading,
-        exec_mode: ExecutionMode::Test,
+        exec_mode,
+        opt_level,
     };
-    let load_result = roc_load::load_and_monomorphize(
+    let load_result = roc_load::load_and_monomorphize_with_types(
         arena,
         path.to_path_buf(),
-        RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
+        roc_cache_dir_from_flags(matches, cache::roc_cache_dir().as_path()),
         load_config,
+        cached_types,
     );
 
     let mut loaded = match load_result {
@@ -437,9 +585,13 @@ pub fn test(matches: &ArgMatches, triple: Triple) -> io::Result<i32> {
             return handle_loading_problem(problem);
         }
         Err(LoadMonomorphizedError::ErrorModule(module)) => {
+            *loaded_files = watch::loaded_files(&module.sources);
+
             return handle_error_module(module, start_time.elapsed(), filename, false);
         }
     };
+    *loaded_files = watch::loaded_files(&loaded.sources);
+
     let problems = report_problems_monomorphized(&mut loaded);
 
     let mut expectations = std::mem::take(&mut loaded.expectations);
@@ -590,10 +742,6 @@ pub fn build(
         path.to_path_buf()
     };
 
-    // the process will end after this function,
-    // so we don't want to spend time freeing these values
-    let arena = ManuallyDrop::new(Bump::new());
-
     let code_gen_backend = if matches!(triple.architecture, Architecture::Wasm32) {
         CodeGenBackend::Wasm
     } else {
@@ -620,6 +768,8 @@ pub fn build(
     };
     let emit_debug_info = matches.is_present(FLAG_DEBUG);
     let emit_timings = matches.is_present(FLAG_TIME);
+    let emit_mem_stats = matches.is_present(FLAG_MEM_STATS);
+    let opt_trace_path = start_chrome_trace(matches);
 
     let threading = match matches
         .value_of(FLAG_MAX_THREADS)
@@ -636,12 +786,25 @@ pub fn build(
 
     let linking_strategy = if wasm_dev_backend {
         LinkingStrategy::Additive
-    } else if !roc_linker::supported(link_type, &triple)
-        || matches.value_of(FLAG_LINKER) == Some("legacy")
-    {
+    } else if !roc_linker::supported(link_type, &triple) {
+        if matches.value_of(FLAG_LINKER) == Some("surgical") {
+            // e.g. the macOS targets, whose Mach-O support in the surgical linker is incomplete
+            user_error!(
+                "The surgical linker doesn't support {} yet, so please use --linker=legacy for it.",
+                triple
+            );
+        }
+
         LinkingStrategy::Legacy
-    } else {
+    } else if matches.value_of(FLAG_LINKER) == Some("legacy") {
+        LinkingStrategy::Legacy
+    } else if matches.value_of(FLAG_LINKER) == Some("surgical")
+        || roc_linker::supported_by_default(link_type, &triple)
+    {
         LinkingStrategy::Surgical
+    } else {
+        // e.g. wasm32, where the surgical linker is opt-in
+        LinkingStrategy::Legacy
     };
 
     let prebuilt = if matches.is_present(FLAG_PREBUILT) {
@@ -665,98 +828,178 @@ pub fn build(
         _ => BuildOrdering::AlwaysBuild,
     };
 
+    let emit: EmitStages = matches
+        .values_of_t::<EmitStage>(FLAG_EMIT)
+        .unwrap_or_default()
+        .into_iter()
+        .collect();
+
     let code_gen_options = CodeGenOptions {
         backend: code_gen_backend,
         opt_level,
         emit_debug_info,
+        emit,
     };
 
-    let load_config = standard_load_config(&triple, build_ordering, threading);
-
-    let res_binary_path = build_file(
-        &arena,
-        &triple,
-        path_buf,
-        code_gen_options,
-        emit_timings,
-        link_type,
-        linking_strategy,
-        prebuilt,
-        wasm_dev_stack_bytes,
-        roc_cache_dir,
-        load_config,
-    );
-
-    match res_binary_path {
-        Ok(BuiltFile {
-            binary_path,
-            problems,
-            total_time,
-            expect_metadata,
-        }) => {
-            match config {
-                BuildOnly => {
-                    // If possible, report the generated executable name relative to the current dir.
-                    let generated_filename = binary_path
-                        .strip_prefix(env::current_dir().unwrap())
-                        .unwrap_or(&binary_path)
-                        .to_str()
-                        .unwrap();
+    let watching = config == BuildAndRunIfNoErrors && matches.is_present(FLAG_WATCH);
+
+    let mut build_once = |loaded_files: &mut Vec<PathBuf>, cached_types: CachedTypes| {
+        // Unless we're watching, the process will end after this,
+        // so we don't want to spend time freeing these values
+        let arena = ManuallyDrop::new(Bump::new());
+
+        let load_config = standard_load_config(&triple, build_ordering, threading, opt_level);
+
+        let res_binary_path = build_file(
+            &arena,
+            &triple,
+            path_buf.clone(),
+            code_gen_options,
+            emit_timings,
+            emit_mem_stats,
+            link_type,
+            linking_strategy,
+            prebuilt,
+            wasm_dev_stack_bytes,
+            roc_cache_dir,
+            load_config,
+            cached_types,
+        );
 
-                    // No need to waste time freeing this memory,
-                    // since the process is about to exit anyway.
-                    // std::mem::forget(arena);
+        if let Some(trace_path) = &opt_trace_path {
+            finish_chrome_trace(trace_path);
+        }
 
-                    problems.print_to_stdout(total_time);
-                    println!(" while successfully building:\n\n    {generated_filename}");
+        let result = match res_binary_path {
+            Ok(BuiltFile {
+                binary_path,
+                problems,
+                total_time,
+                expect_metadata,
+                loaded_files: built_from,
+            }) => {
+                *loaded_files = built_from;
+
+                match config {
+                    BuildOnly => {
+                        // If possible, report the generated executable name relative to the current dir.
+                        let generated_filename = binary_path
+                            .strip_prefix(env::current_dir().unwrap())
+                            .unwrap_or(&binary_path)
+                            .to_str()
+                            .unwrap();
+
+                        // No need to waste time freeing this memory,
+                        // since the process is about to exit anyway.
+                        // std::mem::forget(arena);
 
-                    // Return a nonzero exit code if there were problems
-                    Ok(problems.exit_code())
-                }
-                BuildAndRun => {
-                    if problems.errors > 0 || problems.warnings > 0 {
                         problems.print_to_stdout(total_time);
-                        println!(
-                            ".\n\nRunning program anyway…\n\n\x1B[36m{}\x1B[39m",
-                            "─".repeat(80)
+                        println!(" while successfully building:\n\n    {generated_filename}");
+
+                        // Return a nonzero exit code if there were problems
+                        Ok(problems.exit_code())
+                    }
+                    BuildAndRun => {
+                        if problems.errors > 0 || problems.warnings > 0 {
+                            problems.print_to_stdout(total_time);
+                            println!(
+                                ".\n\nRunning program anyway…\n\n\x1B[36m{}\x1B[39m",
+                                "─".repeat(80)
+                            );
+                        }
+
+                        let args = matches.values_of_os(ARGS_FOR_APP).unwrap_or_default();
+
+                        // don't waste time deallocating; the process ends anyway
+                        // ManuallyDrop will leak the bytes because we don't drop manually
+                        let bytes = &ManuallyDrop::new(std::fs::read(&binary_path).unwrap());
+
+                        roc_run(
+                            &arena,
+                            opt_level,
+                            triple.clone(),
+                            args,
+                            bytes,
+                            expect_metadata,
+                            None,
+                        )
+                    }
+                    BuildAndRunIfNoErrors => {
+                        debug_assert_eq!(
+                            problems.errors, 0,
+                            "if there are errors, they should have been returned as an error variant"
                         );
+                        if problems.warnings > 0 {
+                            problems.print_to_stdout(total_time);
+                            println!(
+                                ".\n\nRunning program…\n\n\x1B[36m{}\x1B[39m",
+                                "─".repeat(80)
+                            );
+                        }
+
+                        let args = matches.values_of_os(ARGS_FOR_APP).unwrap_or_default();
+
+                        // With --watch, this happens once per change, so don't leak these bytes
+                        // like `roc run` does
+                        let bytes = std::fs::read(&binary_path).unwrap();
+
+                        // When watching, restart the program whenever its code changes
+                        let opt_restart_on_change =
+                            watching.then(|| watch::Snapshot::new(loaded_files.iter().cloned()));
+
+                        roc_run(
+                            &arena,
+                            opt_level,
+                            triple.clone(),
+                            args,
+                            &bytes,
+                            expect_metadata,
+                            opt_restart_on_change,
+                        )
                     }
+                }
+            }
+            Err(BuildFileError::ErrorModule { module, total_time }) => {
+                *loaded_files = watch::loaded_files(&module.sources);
 
-                    let args = matches.values_of_os(ARGS_FOR_APP).unwrap_or_default();
+                handle_error_module(module, total_time, filename, true)
+            }
+            Err(BuildFileError::LoadingProblem(problem)) => handle_loading_problem(problem),
+        };
 
-                    // don't waste time deallocating; the process ends anyway
-                    // ManuallyDrop will leak the bytes because we don't drop manually
-                    let bytes = &ManuallyDrop::new(std::fs::read(&binary_path).unwrap());
+        if watching {
+            drop(ManuallyDrop::into_inner(arena));
+        }
 
-                    roc_run(&arena, opt_level, triple, args, bytes, expect_metadata)
-                }
-                BuildAndRunIfNoErrors => {
-                    debug_assert_eq!(
-                        problems.errors, 0,
-                        "if there are errors, they should have been returned as an error variant"
-                    );
-                    if problems.warnings > 0 {
-                        problems.print_to_stdout(total_time);
-                        println!(
-                            ".\n\nRunning program…\n\n\x1B[36m{}\x1B[39m",
-                            "─".repeat(80)
-                        );
-                    }
+        result
+    };
 
-                    let args = matches.values_of_os(ARGS_FOR_APP).unwrap_or_default();
+    if watching {
+        watch(&path_buf, build_once)
+    } else {
+        build_once(&mut Vec::new(), CachedTypes::deserialize())
+    }
+}
 
-                    // don't waste time deallocating; the process ends anyway
-                    // ManuallyDrop will leak the bytes because we don't drop manually
-                    let bytes = &ManuallyDrop::new(std::fs::read(&binary_path).unwrap());
+/// With `--time=<file>`, starts recording how long each compiler phase takes, and returns the
+/// file to write the trace to once compilation is done.
+pub fn start_chrome_trace(matches: &ArgMatches) -> Option<PathBuf> {
+    let trace_path = PathBuf::from(matches.value_of_os(FLAG_TIME)?);
 
-                    roc_run(&arena, opt_level, triple, args, bytes, expect_metadata)
-                }
-            }
-        }
-        Err(BuildFileError::ErrorModule { module, total_time }) => {
-            handle_error_module(module, total_time, filename, true)
-        }
-        Err(BuildFileError::LoadingProblem(problem)) => handle_loading_problem(problem),
+    roc_tracing::chrome_trace::enable();
+
+    Some(trace_path)
+}
+
+pub fn finish_chrome_trace(trace_path: &Path) {
+    match roc_tracing::chrome_trace::write(trace_path) {
+        // stderr, like the other compiler output, so stdout only has the program's output
+        Ok(()) => eprintln!("Wrote a trace of compilation to {}\n", trace_path.display()),
+        Err(err) => user_error!(
+            "Unable to write the trace to {}: {}",
+            trace_path.display(),
+            err
+        ),
     }
 }
 
@@ -802,6 +1045,8 @@ fn handle_loading_problem(problem: LoadingProblem) -> io::Result<i32> {
     }
 }
 
+/// With `opt_restart_on_change`, a native program is stopped as soon as any of those files
+/// change, so `roc dev --watch` can rebuild and restart it.
 fn roc_run<'a, I: IntoIterator<Item = &'a OsStr>>(
     arena: &Bump,
     opt_level: OptLevel,
@@ -809,6 +1054,7 @@ fn roc_run<'a, I: IntoIterator<Item = &'a OsStr>>(
     args: I,
     binary_bytes: &[u8],
     expect_metadata: ExpectMetadata,
+    opt_restart_on_change: Option<watch::Snapshot>,
 ) -> io::Result<i32> {
     match triple.architecture {
         Architecture::Wasm32 => {
@@ -843,7 +1089,14 @@ fn roc_run<'a, I: IntoIterator<Item = &'a OsStr>>(
 
             Ok(0)
         }
-        _ => roc_run_native(arena, opt_level, args, binary_bytes, expect_metadata),
+        _ => roc_run_native(
+            arena,
+            opt_level,
+            args,
+            binary_bytes,
+            expect_metadata,
+            opt_restart_on_change,
+        ),
     }
 }
 
@@ -911,6 +1164,7 @@ fn roc_run_native<I: IntoIterator<Item = S>, S: AsRef<OsStr>>(
     args: I,
     binary_bytes: &[u8],
     expect_metadata: ExpectMetadata,
+    opt_restart_on_change: Option<watch::Snapshot>,
 ) -> std::io::Result<i32> {
     use bumpalo::collections::CollectIn;
 
@@ -931,7 +1185,16 @@ fn roc_run_native<I: IntoIterator<Item = S>, S: AsRef<OsStr>>(
             .collect_in(arena);
 
         match opt_level {
-            OptLevel::Development => roc_dev_native(arena, executable, argv, envp, expect_metadata),
+            OptLevel::Development => {
+                return Ok(roc_dev_native(
+                    arena,
+                    executable,
+                    argv,
+                    envp,
+                    expect_metadata,
+                    opt_restart_on_change,
+                ));
+            }
             OptLevel::Normal | OptLevel::Size | OptLevel::Optimize => {
                 roc_run_native_fast(executable, &argv, &envp);
             }
@@ -1003,6 +1266,17 @@ impl ExecutableFile {
     }
 }
 
+#[cfg(target_os = "linux")]
+impl Drop for ExecutableFile {
+    fn drop(&mut self) {
+        // Usually we exec the file (or exit) before this would run, but `roc dev --watch` runs
+        // a new one after every change, and each would otherwise keep its memory around.
+        let ExecutableFile::MemFd(fd, _path) = self;
+
+        unsafe { libc::close(*fd) };
+    }
+}
+
 // with Expect
 #[cfg(target_family = "unix")]
 fn roc_dev_native(
@@ -1011,7 +1285,8 @@ fn roc_dev_native(
     argv: bumpalo::collections::Vec<*const c_char>,
     envp: bumpalo::collections::Vec<*const c_char>,
     expect_metadata: ExpectMetadata,
-) -> ! {
+    opt_restart_on_change: Option<watch::Snapshot>,
+) -> i32 {
     use std::sync::{atomic::AtomicBool, Arc};
 
     use roc_repl_expect::run::{ChildProcessMsg, ExpectMemory};
@@ -1049,10 +1324,14 @@ fn roc_dev_native(
 
             std::process::exit(1)
         }
-        1.. => {
+        pid @ 1.. => {
             let sigchld = Arc::new(AtomicBool::new(false));
-            signal_hook::flag::register(signal_hook::consts::SIGCHLD, Arc::clone(&sigchld))
-                .unwrap();
+            let sigchld_id =
+                signal_hook::flag::register(signal_hook::consts::SIGCHLD, Arc::clone(&sigchld))
+                    .unwrap();
+
+            let opt_kill_on_change =
+                opt_restart_on_change.map(|snapshot| watch::KillOnChange::spawn(snapshot, pid));
 
             loop {
                 match memory.wait_for_child(sigchld.clone()) {
@@ -1086,7 +1365,19 @@ fn roc_dev_native(
                 }
             }
 
-            std::process::exit(0)
+            match opt_kill_on_change {
+                Some(kill_on_change) => {
+                    kill_on_change.stop();
+
+                    // We're going to keep running, so clean up after the child, and don't leave
+                    // its handler behind for the next run to register another one next to it.
+                    unsafe { libc::waitpid(pid, std::ptr::null_mut(), 0) };
+                    signal_hook::low_level::unregister(sigchld_id);
+
+                    0
+                }
+                None => std::process::exit(0),
+            }
         }
         _ => unreachable!(),
     }
@@ -1173,6 +1464,7 @@ fn roc_run_native<I: IntoIterator<Item = S>, S: AsRef<OsStr>>(
     args: I,
     binary_bytes: &[u8],
     _expect_metadata: ExpectMetadata,
+    _opt_restart_on_change: Option<watch::Snapshot>,
 ) -> io::Result<i32> {
     use bumpalo::collections::CollectIn;
 
@@ -1247,8 +1539,15 @@ pub enum Target {
     Linux32,
     #[strum(serialize = "linux64")]
     Linux64,
+    #[strum(serialize = "linux-arm64")]
+    LinuxArm64,
+    #[strum(serialize = "macos-x64")]
+    MacosX64,
+    #[strum(serialize = "macos-arm64")]
+    MacosArm64,
     #[strum(serialize = "windows64")]
     Windows64,
+    /// Also accepted as `wasm32-wasi`
     #[strum(serialize = "wasm32")]
     Wasm32,
 }
@@ -1260,6 +1559,15 @@ impl Default for Target {
 }
 
 impl Target {
+    fn possible_value(self) -> PossibleValue<'static> {
+        let value = PossibleValue::new(self.into());
+
+        match self {
+            Target::Wasm32 => value.alias("wasm32-wasi"),
+            _ => value,
+        }
+    }
+
     pub fn to_triple(self) -> Triple {
         use Target::*;
 
@@ -1279,6 +1587,27 @@ impl Target {
                 environment: Environment::Musl,
                 binary_format: BinaryFormat::Elf,
             },
+            LinuxArm64 => Triple {
+                architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+                vendor: Vendor::Unknown,
+                operating_system: OperatingSystem::Linux,
+                environment: Environment::Musl,
+                binary_format: BinaryFormat::Elf,
+            },
+            MacosX64 => Triple {
+                architecture: Architecture::X86_64,
+                vendor: Vendor::Apple,
+                operating_system: OperatingSystem::Darwin,
+                environment: Environment::Unknown,
+                binary_format: BinaryFormat::Macho,
+            },
+            MacosArm64 => Triple {
+                architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+                vendor: Vendor::Apple,
+                operating_system: OperatingSystem::Darwin,
+                environment: Environment::Unknown,
+                binary_format: BinaryFormat::Macho,
+            },
             Windows64 => Triple {
                 architecture: Architecture::X86_64,
                 vendor: Vendor::Unknown,
@@ -1317,8 +1646,11 @@ impl std::str::FromStr for Target {
             "system" => Ok(Target::System),
             "linux32" => Ok(Target::Linux32),
             "linux64" => Ok(Target::Linux64),
+            "linux-arm64" => Ok(Target::LinuxArm64),
+            "macos-x64" => Ok(Target::MacosX64),
+            "macos-arm64" => Ok(Target::MacosArm64),
             "windows64" => Ok(Target::Windows64),
-            "wasm32" => Ok(Target::Wasm32),
+            "wasm32" | "wasm32-wasi" => Ok(Target::Wasm32),
             _ => Err(format!("Roc does not know how to compile to {}", string)),
         }
     }
@@ -1360,3 +1692,69 @@ mod windows_roc_platform_functions {
         libc::free(c_ptr)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn targets_round_trip() {
+        for target in Target::iter() {
+            assert_eq!(Ok(target), Target::from_str(&target.to_string()));
+        }
+    }
+
+    #[test]
+    fn parse_targets() {
+        assert_eq!(Ok(Target::Wasm32), Target::from_str("wasm32-wasi"));
+        assert!(Target::from_str("macos-ppc").is_err());
+
+        assert_eq!(
+            Some("linux-arm64"),
+            roc_build::link::get_target_triple_str(&Target::LinuxArm64.to_triple())
+        );
+        assert_eq!(
+            Some("macos-x86_64"),
+            roc_build::link::get_target_triple_str(&Target::MacosX64.to_triple())
+        );
+        assert_eq!(
+            Some("macos-arm64"),
+            roc_build::link::get_target_triple_str(&Target::MacosArm64.to_triple())
+        );
+    }
+
+    #[test]
+    fn wasm32_wasi_is_accepted_by_the_cli() {
+        let matches = build_app()
+            .try_get_matches_from(["roc", CMD_BUILD, "--target=wasm32-wasi", "main.roc"])
+            .unwrap();
+        let build_matches = matches.subcommand_matches(CMD_BUILD).unwrap();
+
+        assert_eq!(
+            Ok(Target::Wasm32),
+            build_matches.value_of_t::<Target>(FLAG_TARGET)
+        );
+    }
+
+    #[test]
+    fn wasm32_uses_the_legacy_linker_unless_asked() {
+        let triple = Target::Wasm32.to_triple();
+
+        assert!(roc_linker::supported(LinkType::Executable, &triple));
+        assert!(!roc_linker::supported_by_default(
+            LinkType::Executable,
+            &triple
+        ));
+    }
+
+    #[test]
+    fn macos_targets_use_the_legacy_linker() {
+        for target in [Target::MacosX64, Target::MacosArm64] {
+            assert!(!roc_linker::supported(
+                LinkType::Executable,
+                &target.to_triple()
+            ));
+        }
+    }
+}
@@ -2,14 +2,15 @@
 use roc_build::link::LinkType;
 use roc_cli::build::check_file;
 use roc_cli::{
-    build_app, format, test, BuildConfig, FormatMode, Target, CMD_BUILD, CMD_CHECK, CMD_DEV,
-    CMD_DOCS, CMD_EDIT, CMD_FORMAT, CMD_GEN_STUB_LIB, CMD_GLUE, CMD_REPL, CMD_RUN, CMD_TEST,
-    CMD_VERSION, DIRECTORY_OR_FILES, FLAG_CHECK, FLAG_LIB, FLAG_NO_LINK, FLAG_TARGET, FLAG_TIME,
-    GLUE_FILE, ROC_FILE,
+    build_app, fetch, finish_chrome_trace, format, roc_cache_dir_from_flags, start_chrome_trace,
+    test, vendor, watch, BuildConfig, FormatMode, Target, CMD_BUILD, CMD_CHECK, CMD_DEV, CMD_DOCS,
+    CMD_EDIT, CMD_FETCH, CMD_FORMAT, CMD_GEN_STUB_LIB, CMD_GLUE, CMD_REPL, CMD_RUN, CMD_TEST,
+    CMD_VENDOR, CMD_VERSION, DIRECTORY_OR_FILES, FLAG_CHECK, FLAG_DOCS_FORMAT, FLAG_LIB,
+    FLAG_MEM_STATS, FLAG_NO_LINK, FLAG_TARGET, FLAG_TIME, FLAG_WATCH, GLUE_FILE, ROC_FILE,
 };
-use roc_docs::generate_docs_html;
+use roc_docs::{generate_docs, DocsFormat};
 use roc_error_macros::user_error;
-use roc_load::{LoadingProblem, Threading};
+use roc_load::{CachedTypes, LoadingProblem, Threading};
 use roc_packaging::cache::{self, RocCacheDir};
 use std::fs::{self, FileType};
 use std::io;
@@ -38,7 +39,7 @@ fn main() -> io::Result<()> {
                     &matches,
                     BuildConfig::BuildAndRunIfNoErrors,
                     Triple::host(),
-                    RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
+                    roc_cache_dir_from_flags(&matches, cache::roc_cache_dir().as_path()),
                     LinkType::Executable,
                 )
             } else {
@@ -53,7 +54,7 @@ fn main() -> io::Result<()> {
                     matches,
                     BuildConfig::BuildAndRun,
                     Triple::host(),
-                    RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
+                    roc_cache_dir_from_flags(matches, cache::roc_cache_dir().as_path()),
                     LinkType::Executable,
                 )
             } else {
@@ -77,7 +78,7 @@ fn main() -> io::Result<()> {
                     matches,
                     BuildConfig::BuildAndRunIfNoErrors,
                     Triple::host(),
-                    RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
+                    roc_cache_dir_from_flags(matches, cache::roc_cache_dir().as_path()),
                     LinkType::Executable,
                 )
             } else {
@@ -86,6 +87,8 @@ fn main() -> io::Result<()> {
                 Ok(1)
             }
         }
+        Some((CMD_FETCH, matches)) => fetch(matches),
+        Some((CMD_VENDOR, matches)) => vendor(matches),
         Some((CMD_GLUE, matches)) => {
             let input_path = Path::new(matches.value_of_os(ROC_FILE).unwrap());
             let output_path = Path::new(matches.value_of_os(GLUE_FILE).unwrap());
@@ -109,28 +112,26 @@ fn main() -> io::Result<()> {
         }
         Some((CMD_BUILD, matches)) => {
             let target: Target = matches.value_of_t(FLAG_TARGET).unwrap_or_default();
-            let link_type = match (
-                matches.is_present(FLAG_LIB),
-                matches.is_present(FLAG_NO_LINK),
-            ) {
-                (true, false) => LinkType::Dylib,
-                (true, true) => user_error!("build can only be one of `--lib` or `--no-link`"),
-                (false, true) => LinkType::None,
-                (false, false) => LinkType::Executable,
+            let link_type = match (matches.value_of(FLAG_LIB), matches.is_present(FLAG_NO_LINK)) {
+                (Some("static"), false) => LinkType::StaticLib,
+                (Some(_), false) => LinkType::Dylib,
+                (Some(_), true) => user_error!("build can only be one of `--lib` or `--no-link`"),
+                (None, true) => LinkType::None,
+                (None, false) => LinkType::Executable,
             };
 
             Ok(build(
                 matches,
                 BuildConfig::BuildOnly,
                 target.to_triple(),
-                RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
+                roc_cache_dir_from_flags(matches, cache::roc_cache_dir().as_path()),
                 link_type,
             )?)
         }
         Some((CMD_CHECK, matches)) => {
-            let arena = bumpalo::Bump::new();
-
             let emit_timings = matches.is_present(FLAG_TIME);
+            let emit_mem_stats = matches.is_present(FLAG_MEM_STATS);
+            let opt_trace_path = start_chrome_trace(matches);
             let filename = matches.value_of_os(ROC_FILE).unwrap();
             let roc_file_path = PathBuf::from(filename);
             let threading = match matches
@@ -143,52 +144,71 @@ fn main() -> io::Result<()> {
                 Some(n) => Threading::AtMost(n),
             };
 
-            match check_file(
-                &arena,
-                roc_file_path,
-                emit_timings,
-                RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
-                threading,
-            ) {
-                Ok((problems, total_time)) => {
-                    println!(
-                        "\x1B[{}m{}\x1B[39m {} and \x1B[{}m{}\x1B[39m {} found in {} ms.",
-                        if problems.errors == 0 {
-                            32 // green
-                        } else {
-                            33 // yellow
-                        },
-                        problems.errors,
-                        if problems.errors == 1 {
-                            "error"
-                        } else {
-                            "errors"
-                        },
-                        if problems.warnings == 0 {
-                            32 // green
-                        } else {
-                            33 // yellow
-                        },
-                        problems.warnings,
-                        if problems.warnings == 1 {
-                            "warning"
-                        } else {
-                            "warnings"
-                        },
-                        total_time.as_millis(),
-                    );
-
-                    Ok(problems.exit_code())
+            let mut check_once = |loaded_files: &mut Vec<PathBuf>, cached_types: CachedTypes| {
+                let arena = bumpalo::Bump::new();
+                let res_check = check_file(
+                    &arena,
+                    roc_file_path.clone(),
+                    emit_timings,
+                    emit_mem_stats,
+                    roc_cache_dir_from_flags(matches, cache::roc_cache_dir().as_path()),
+                    threading,
+                    cached_types,
+                );
+
+                if let Some(trace_path) = &opt_trace_path {
+                    finish_chrome_trace(trace_path);
                 }
 
-                Err(LoadingProblem::FormattedReport(report)) => {
-                    print!("{}", report);
+                match res_check {
+                    Ok((problems, total_time, checked_files)) => {
+                        *loaded_files = checked_files;
+
+                        println!(
+                            "\x1B[{}m{}\x1B[39m {} and \x1B[{}m{}\x1B[39m {} found in {} ms.",
+                            if problems.errors == 0 {
+                                32 // green
+                            } else {
+                                33 // yellow
+                            },
+                            problems.errors,
+                            if problems.errors == 1 {
+                                "error"
+                            } else {
+                                "errors"
+                            },
+                            if problems.warnings == 0 {
+                                32 // green
+                            } else {
+                                33 // yellow
+                            },
+                            problems.warnings,
+                            if problems.warnings == 1 {
+                                "warning"
+                            } else {
+                                "warnings"
+                            },
+                            total_time.as_millis(),
+                        );
+
+                        Ok(problems.exit_code())
+                    }
 
-                    Ok(1)
-                }
-                Err(other) => {
-                    panic!("build_file failed with error:\n{:?}", other);
+                    Err(LoadingProblem::FormattedReport(report)) => {
+                        print!("{}", report);
+
+                        Ok(1)
+                    }
+                    Err(other) => {
+                        panic!("build_file failed with error:\n{:?}", other);
+                    }
                 }
+            };
+
+            if matches.is_present(FLAG_WATCH) {
+                watch(&roc_file_path, check_once)
+            } else {
+                check_once(&mut Vec::new(), CachedTypes::deserialize())
             }
         }
         Some((CMD_REPL, _)) => Ok(roc_repl_cli::main()),
@@ -211,7 +231,9 @@ fn main() -> io::Result<()> {
         Some((CMD_DOCS, matches)) => {
             let root_filename = matches.value_of_os(ROC_FILE).unwrap();
 
-            generate_docs_html(PathBuf::from(root_filename));
+            let format: DocsFormat = matches.value_of_t_or_exit(FLAG_DOCS_FORMAT);
+
+            generate_docs(PathBuf::from(root_filename), format);
 
             Ok(0)
         }
@@ -0,0 +1,309 @@
+//! Reruns a command (like `roc check --watch`) whenever any of the .roc files it loaded change.
+//!
+//! This polls the files' modification times rather than asking the operating system to notify
+//! us, which keeps it portable and means there's nothing to clean up if a file is replaced
+//! rather than modified (as many editors do when saving).
+//!
+//! Every run happens in the same process, so the next run's copy of the builtins' cached types
+//! can be deserialized while waiting for changes, rather than after them.
+use roc_collections::all::MutMap;
+use roc_load::CachedTypes;
+use roc_module::symbol::ModuleId;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Runs the given command, then waits for any of the files it loaded to change and runs it
+/// again, until the process is interrupted.
+///
+/// The command should put the paths of the .roc files it loaded into the given `Vec`. If it
+/// can't (for example, because loading failed), whatever was loaded last time is watched
+/// instead, or on the first run, every .roc file in the same directory as the root module.
+pub fn watch<F>(root_module: &Path, mut run: F) -> io::Result<i32>
+where
+    F: FnMut(&mut Vec<PathBuf>, CachedTypes) -> io::Result<i32>,
+{
+    let mut paths = roc_files_beside(root_module)?;
+    let mut cached_types = CachedTypes::deserialize();
+
+    loop {
+        clear_terminal();
+
+        // Taken before running, so that changes made while the command is running
+        // trigger another run once it's done.
+        let before = Snapshot::new(paths.iter().cloned());
+
+        let mut loaded = Vec::new();
+
+        if let Err(err) = run(&mut loaded, cached_types) {
+            eprintln!("{}", err);
+        }
+
+        // The run used these up, so get the next run's ready before waiting.
+        cached_types = CachedTypes::deserialize();
+
+        if !loaded.is_empty() {
+            paths = loaded;
+        }
+
+        let snapshot = before.retain_and_extend(&paths);
+
+        println!(
+            "\n\x1B[36mWatching {} {} for changes…\x1B[39m (Press Ctrl+C to stop.)",
+            snapshot.files.len(),
+            if snapshot.files.len() == 1 {
+                "file"
+            } else {
+                "files"
+            }
+        );
+
+        snapshot.wait_for_change();
+    }
+}
+
+/// The paths of the .roc files which were loaded for the given modules. Builtins aren't
+/// included, since they're compiled into the `roc` binary.
+pub fn loaded_files<S>(sources: &MutMap<ModuleId, (PathBuf, S)>) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = sources
+        .iter()
+        .filter(|(module_id, _)| !module_id.is_builtin())
+        .map(|(_, (path, _))| path.clone())
+        .collect();
+
+    paths.sort();
+    paths.dedup();
+
+    paths
+}
+
+/// When each watched file was last modified, as of when it started being watched.
+/// Files which don't exist have no modification time, so creating them counts as a change.
+#[derive(Clone, Debug)]
+pub struct Snapshot {
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl Snapshot {
+    pub fn new<I: IntoIterator<Item = PathBuf>>(paths: I) -> Self {
+        Snapshot {
+            files: paths
+                .into_iter()
+                .map(|path| {
+                    let modified = modified(&path);
+
+                    (path, modified)
+                })
+                .collect(),
+        }
+    }
+
+    /// Watches exactly the given paths. The ones this was already watching keep their earlier
+    /// modification times, so changes made since then aren't missed.
+    fn retain_and_extend(self, paths: &[PathBuf]) -> Self {
+        let mut earlier: MutMap<PathBuf, Option<SystemTime>> = self.files.into_iter().collect();
+
+        Snapshot {
+            files: paths
+                .iter()
+                .map(|path| match earlier.remove(path) {
+                    Some(modified) => (path.clone(), modified),
+                    None => (path.clone(), modified(path)),
+                })
+                .collect(),
+        }
+    }
+
+    pub fn has_changed(&self) -> bool {
+        self.files
+            .iter()
+            .any(|(path, modified_before)| modified(path) != *modified_before)
+    }
+
+    pub fn wait_for_change(&self) {
+        while !self.has_changed() {
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// Used when we don't know which modules the root module imports, because loading it failed.
+fn roc_files_beside(root_module: &Path) -> io::Result<Vec<PathBuf>> {
+    let dir = match root_module.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+
+    let mut paths = vec![root_module.to_path_buf()];
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.extension().map_or(false, |ext| ext == "roc") && !path.ends_with(root_module) {
+            paths.push(path);
+        }
+    }
+
+    Ok(paths)
+}
+
+fn clear_terminal() {
+    use std::io::Write;
+
+    // Clear the screen and the scrollback, then move the cursor to the top left.
+    print!("\x1B[2J\x1B[3J\x1B[H");
+
+    io::stdout().flush().ok();
+}
+
+/// Kills the given process as soon as any of the watched files change, so that
+/// `roc dev --watch` can restart the app with the new code.
+#[cfg(target_family = "unix")]
+pub struct KillOnChange {
+    stop: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    thread: thread::JoinHandle<()>,
+}
+
+#[cfg(target_family = "unix")]
+impl KillOnChange {
+    pub fn spawn(snapshot: Snapshot, pid: libc::pid_t) -> Self {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread = {
+            let stop = Arc::clone(&stop);
+
+            thread::Builder::new()
+                .name("watch".to_string())
+                .spawn(move || {
+                    while !stop.load(Ordering::Acquire) {
+                        if snapshot.has_changed() {
+                            unsafe { libc::kill(pid, libc::SIGTERM) };
+
+                            return;
+                        }
+
+                        thread::sleep(POLL_INTERVAL);
+                    }
+                })
+                .expect("Failed to spawn the thread which watches for changes")
+        };
+
+        KillOnChange { stop, thread }
+    }
+
+    /// Stops watching. Call this once the process has exited.
+    pub fn stop(self) {
+        self.stop.store(true, std::sync::atomic::Ordering::Release);
+
+        self.thread.join().unwrap();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs::File;
+    use std::io::Write;
+
+    fn touch(path: &Path) {
+        File::create(path).unwrap();
+    }
+
+    /// Writes to the file until its modification time changes, since the file system's clock
+    /// may not have ticked since it was last written.
+    fn change(path: &Path) {
+        let before = modified(path);
+
+        while modified(path) == before {
+            thread::sleep(Duration::from_millis(5));
+
+            File::options()
+                .append(true)
+                .open(path)
+                .unwrap()
+                .write_all(b"\n")
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn roc_files_beside_root_module() {
+        let dir = tempfile::tempdir().unwrap();
+        let main = dir.path().join("main.roc");
+
+        touch(&main);
+        touch(&dir.path().join("Lib.roc"));
+        touch(&dir.path().join("notes.txt"));
+
+        let mut paths = roc_files_beside(&main).unwrap();
+
+        assert_eq!(paths.remove(0), main);
+        assert_eq!(paths, vec![dir.path().join("Lib.roc")]);
+    }
+
+    #[test]
+    fn snapshot_notices_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let main = dir.path().join("main.roc");
+        let missing = dir.path().join("Missing.roc");
+
+        touch(&main);
+
+        let snapshot = Snapshot::new([main.clone(), missing.clone()]);
+
+        assert!(!snapshot.has_changed());
+
+        change(&main);
+
+        assert!(snapshot.has_changed());
+
+        let snapshot = Snapshot::new([main, missing.clone()]);
+
+        assert!(!snapshot.has_changed());
+
+        touch(&missing);
+
+        assert!(snapshot.has_changed());
+    }
+
+    #[test]
+    fn retain_and_extend_keeps_earlier_modification_times() {
+        let dir = tempfile::tempdir().unwrap();
+        let main = dir.path().join("main.roc");
+        let unused = dir.path().join("Unused.roc");
+        let imported = dir.path().join("Imported.roc");
+
+        touch(&main);
+        touch(&unused);
+        touch(&imported);
+
+        let before = Snapshot::new([main.clone(), unused.clone()]);
+
+        // Changed while the command was running
+        change(&main);
+
+        let snapshot = before.retain_and_extend(&[main.clone(), imported.clone()]);
+        let paths: Vec<&PathBuf> = snapshot.files.iter().map(|(path, _)| path).collect();
+
+        assert_eq!(paths, vec![&main, &imported]);
+        assert!(snapshot.has_changed());
+
+        let snapshot = Snapshot::new([main, unused.clone()]).retain_and_extend(&[imported]);
+
+        change(&unused);
+
+        assert!(!snapshot.has_changed());
+    }
+}
@@ -51,6 +51,7 @@ mod cli_run {
     const OPTIMIZE_FLAG: &str = concatcp!("--", roc_cli::FLAG_OPTIMIZE);
     const LINKER_FLAG: &str = concatcp!("--", roc_cli::FLAG_LINKER);
     const CHECK_FLAG: &str = concatcp!("--", roc_cli::FLAG_CHECK);
+    const DOCTESTS_FLAG: &str = concatcp!("--", roc_cli::FLAG_DOCTESTS);
     const PREBUILT_PLATFORM: &str = concatcp!("--", roc_cli::FLAG_PREBUILT, "=true");
     #[allow(dead_code)]
     const TARGET_FLAG: &str = concatcp!("--", roc_cli::FLAG_TARGET);
@@ -498,6 +499,70 @@ mod cli_run {
         )
     }
 
+    #[test]
+    #[serial(cli_platform)]
+    #[cfg_attr(windows, ignore)]
+    fn emit_intermediate_output() {
+        let file = file_path_from_root("examples", "helloWorldNoURL.roc");
+        let output_path = file.with_file_name("helloWorld");
+
+        for command in [CMD_BUILD, CMD_RUN] {
+            let out = run_roc(
+                [
+                    command,
+                    "--emit=can,mono,llvm-ir,asm",
+                    file.to_str().unwrap(),
+                ],
+                &[],
+                &[],
+            );
+
+            assert!(out.status.success(), "{} failed: {}", command, out.stderr);
+
+            for (extension, expected) in [
+                ("can", "\"Hello, World!\""),
+                ("mono", "procedure "),
+                ("ll", "define "),
+                ("s", "roc__mainForHost"),
+            ] {
+                let emitted_path = output_path.with_extension(extension);
+                let emitted = std::fs::read_to_string(&emitted_path).unwrap_or_else(|error| {
+                    panic!(
+                        "`roc {}` didn't write {:?}: {}",
+                        command, emitted_path, error
+                    )
+                });
+
+                assert!(
+                    emitted.contains(expected),
+                    "{:?} doesn't contain {:?}",
+                    emitted_path,
+                    expected
+                );
+
+                std::fs::remove_file(emitted_path).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn emit_rejects_unknown_stages_and_commands() {
+        let file = file_path_from_root("examples", "helloWorldNoURL.roc");
+
+        let out = run_roc(
+            [CMD_BUILD, "--emit=mono,hir", file.to_str().unwrap()],
+            &[],
+            &[],
+        );
+        assert!(!out.status.success());
+        assert!(out.stderr.contains("hir"), "stderr was: {}", out.stderr);
+
+        // `roc check` doesn't generate code, so there's nothing to emit.
+        let out = run_roc([CMD_CHECK, "--emit=mono", file.to_str().unwrap()], &[], &[]);
+        assert!(!out.status.success());
+        assert!(out.stderr.contains("--emit"), "stderr was: {}", out.stderr);
+    }
+
     #[cfg(windows)]
     const LINE_ENDING: &str = "\r\n";
     #[cfg(not(windows))]
@@ -637,6 +702,37 @@ mod cli_run {
         );
     }
 
+    #[test]
+    #[cfg_attr(windows, ignore)]
+    fn doctests() {
+        let path = file_path_from_root("crates/cli_testing_examples/expects", "Doctests.roc");
+
+        let out = run_roc([CMD_TEST, path.to_str().unwrap()], &[], &[]);
+        assert!(strip_colors(&out.stdout).contains("No expectations were found."));
+
+        let out = run_roc([CMD_TEST, DOCTESTS_FLAG, path.to_str().unwrap()], &[], &[]);
+        assert!(out.status.success());
+        assert!(strip_colors(&out.stdout).contains("0 failed and 3 passed"));
+    }
+
+    #[test]
+    #[cfg_attr(windows, ignore)]
+    fn doctests_failing() {
+        let path =
+            file_path_from_root("crates/cli_testing_examples/expects", "DoctestsFailing.roc");
+
+        let out = run_roc([CMD_TEST, DOCTESTS_FLAG, path.to_str().unwrap()], &[], &[]);
+        let stdout = strip_colors(&out.stdout);
+
+        assert!(!out.status.success());
+        assert!(stdout.contains("1 failed and 0 passed"));
+
+        // The failure is reported at the example in the doc comment.
+        assert!(stdout.contains("This expectation failed:"));
+        assert!(stdout.contains("7│>  ## >>> triple 2"), "{}", stdout);
+        assert!(stdout.contains("8│>  ## 5"), "{}", stdout);
+    }
+
     #[test]
     #[cfg_attr(
         windows,
@@ -1034,6 +1130,15 @@ mod cli_run {
                 &[OPTIMIZE_FLAG],
                 expected_ending,
             );
+
+            // The surgical linker is opt-in for wasm32
+            check_wasm_output_with_stdin(
+                file_name,
+                stdin,
+                executable_filename,
+                &[super::LINKER_FLAG, "surgical"],
+                expected_ending,
+            );
         }
 
         #[cfg(feature = "wasm32-cli-run")]
@@ -0,0 +1,28 @@
+interface Doctests
+    exposes [double, greet, pair]
+    imports []
+
+## Doubles a number.
+##
+## >>> Doctests.double 21
+## 42
+##
+## Examples without an expected result are not run:
+##
+## >>> double 0
+double : I64 -> I64
+double = \n -> n * 2
+
+## >>> name = "Roc"
+## >>>
+## >>> greet name
+## "Hello, Roc!"
+greet : Str -> Str
+greet = \name -> "Hello, \(name)!"
+
+## >>> pair 1
+## { first: 1,
+##   second: 1 }
+## Both fields get the same number.
+pair : I64 -> { first : I64, second : I64 }
+pair = \n -> { first: n, second: n }
@@ -0,0 +1,10 @@
+interface DoctestsFailing
+    exposes [triple]
+    imports []
+
+## Triples a number.
+##
+## >>> triple 2
+## 5
+triple : I64 -> I64
+triple = \n -> n * 3
@@ -0,0 +1,533 @@
+//! Lets a platform say how to build its host, in a `host.manifest` file next to its main.roc,
+//! rather than having `rebuild_host` guess from file names like host.zig or Cargo.toml.
+//!
+//! ```text
+//! # The host is built by the platform's own build script
+//! command = ./build-host.sh
+//! inputs = build-host.sh host.c src
+//! output = host.o
+//!
+//! # Targets can override any of the above
+//! [wasm32]
+//! command = zig build-obj host.zig -target wasm32-wasi -femit-bin=host.wasm.o
+//! inputs = host.zig
+//! output = host.wasm.o
+//! ```
+//!
+//! Sections are named like the precompiled hosts (e.g. `linux-x86_64`, `macos-arm64`). Paths are
+//! relative to the platform's directory, and input directories are hashed recursively. The
+//! command is split on whitespace (it doesn't go through a shell), run in the platform's
+//! directory, and gets these environment variables:
+//!
+//! - `ROC_TARGET`: the section name for the target
+//! - `ROC_OPT_LEVEL`: `development`, `normal`, `size` or `optimize`
+//! - `ROC_BUILTINS_HOST`: an object file with the builtins the host may need to link against
+//! - `ROC_SHARED_LIB`: the stub app library to link against, when building for the surgical linker
+//!
+//! Afterwards, the output is copied to wherever roc expects the host to be. A stamp with the
+//! hash of the inputs, the target and the opt level is written next to it, and as long as
+//! those don't change, later builds reuse the host instead of running the command again.
+use crate::link::get_target_triple_str;
+use roc_builtins::bitcode;
+use roc_error_macros::user_error;
+use roc_mono::ir::OptLevel;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use target_lexicon::Triple;
+
+pub const HOST_MANIFEST_FILENAME: &str = "host.manifest";
+
+/// How to build the host for one target
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HostBuild {
+    pub command: Vec<String>,
+    pub inputs: Vec<PathBuf>,
+    pub output: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ManifestProblem {
+    /// A line which isn't a comment, a `[section]` or a `key = value`
+    MalformedLine {
+        line_number: usize,
+        line: String,
+    },
+    UnknownKey {
+        line_number: usize,
+        key: String,
+    },
+    /// After applying the target's section, the command or the output was still missing
+    Missing {
+        target: String,
+        key: &'static str,
+    },
+}
+
+impl fmt::Display for ManifestProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ManifestProblem::MalformedLine { line_number, line } => write!(
+                f,
+                "line {} should be a `key = value`, a `[section]` or a `# comment`, but it was: {}",
+                line_number, line
+            ),
+            ManifestProblem::UnknownKey { line_number, key } => write!(
+                f,
+                "line {} has the key `{}`, but the only keys I know are `command`, `inputs` and `output`",
+                line_number, key
+            ),
+            ManifestProblem::Missing { target, key } => write!(
+                f,
+                "it doesn't say what the `{}` is for {}, either in a `[{}]` section or before the first section",
+                key, target, target
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Entries {
+    command: Option<String>,
+    inputs: Option<String>,
+    output: Option<String>,
+}
+
+/// Parses a manifest, returning the build for the given target (see `get_target_triple_str`).
+pub fn parse_manifest(src: &str, target: &str) -> Result<HostBuild, ManifestProblem> {
+    let mut defaults = Entries::default();
+    let mut for_target = Entries::default();
+    // None before the first section header, and Some(is_our_target) after
+    let mut current_section: Option<bool> = None;
+
+    for (index, line) in src.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(section) = line
+            .strip_prefix('[')
+            .and_then(|rest| rest.strip_suffix(']'))
+        {
+            current_section = Some(section.trim() == target);
+            continue;
+        }
+
+        let (key, value) = match line.split_once('=') {
+            Some((key, value)) => (key.trim(), value.trim().to_string()),
+            None => {
+                return Err(ManifestProblem::MalformedLine {
+                    line_number,
+                    line: line.to_string(),
+                })
+            }
+        };
+
+        let entries = match current_section {
+            None => &mut defaults,
+            Some(true) => &mut for_target,
+            Some(false) => continue,
+        };
+
+        match key {
+            "command" => entries.command = Some(value),
+            "inputs" => entries.inputs = Some(value),
+            "output" => entries.output = Some(value),
+            _ => {
+                return Err(ManifestProblem::UnknownKey {
+                    line_number,
+                    key: key.to_string(),
+                })
+            }
+        }
+    }
+
+    let missing = |key| ManifestProblem::Missing {
+        target: target.to_string(),
+        key,
+    };
+
+    let command = for_target
+        .command
+        .or(defaults.command)
+        .ok_or_else(|| missing("command"))?;
+    let inputs = for_target.inputs.or(defaults.inputs).unwrap_or_default();
+    let output = for_target
+        .output
+        .or(defaults.output)
+        .ok_or_else(|| missing("output"))?;
+
+    Ok(HostBuild {
+        command: command.split_whitespace().map(String::from).collect(),
+        inputs: inputs.split_whitespace().map(PathBuf::from).collect(),
+        output: PathBuf::from(output),
+    })
+}
+
+/// If the platform has a manifest, build its host at host_dest according to it (or leave it
+/// there, if it's up to date), and return true. Returns false if there's no manifest.
+pub fn build_host_from_manifest(
+    opt_level: OptLevel,
+    target: &Triple,
+    platform_main_roc: &Path,
+    shared_lib_path: Option<&Path>,
+    host_dest: &Path,
+) -> bool {
+    let manifest_path = platform_main_roc.with_file_name(HOST_MANIFEST_FILENAME);
+
+    let manifest = match fs::read_to_string(&manifest_path) {
+        Ok(manifest) => manifest,
+        Err(_) => return false,
+    };
+
+    let platform_dir = platform_main_roc.parent().unwrap();
+    let target_str = get_target_triple_str(target).unwrap_or("unknown");
+
+    let host_build = match parse_manifest(&manifest, target_str) {
+        Ok(host_build) => host_build,
+        Err(problem) => {
+            user_error!(
+                "I couldn't use {} because {}.",
+                manifest_path.to_string_lossy(),
+                problem
+            )
+        }
+    };
+
+    let stamp_path = stamp_path(host_dest);
+    let hash = match build_hash(
+        &host_build,
+        platform_dir,
+        target_str,
+        opt_level,
+        shared_lib_path,
+    ) {
+        Ok(hash) => hash,
+        Err(err) => {
+            user_error!(
+                "I couldn't read the inputs in {}: {}",
+                manifest_path.to_string_lossy(),
+                err
+            )
+        }
+    };
+
+    let stamp = fs::read_to_string(&stamp_path).unwrap_or_default();
+
+    if host_dest.exists() && stamp.trim() == hash {
+        return true;
+    }
+
+    // If the command fails part way, the old stamp mustn't make a later build think it's done.
+    let _ = fs::remove_file(&stamp_path);
+
+    let builtins_host_tempfile =
+        bitcode::host_tempfile().expect("failed to write host builtins object to tempfile");
+
+    let (program, args) = match host_build.command.split_first() {
+        Some(split) => split,
+        None => {
+            user_error!(
+                "The command in {} is empty.",
+                manifest_path.to_string_lossy()
+            )
+        }
+    };
+
+    let mut command = Command::new(program);
+
+    command
+        .args(args)
+        .current_dir(platform_dir)
+        .env("ROC_TARGET", target_str)
+        .env("ROC_OPT_LEVEL", opt_level_str(opt_level))
+        .env("ROC_BUILTINS_HOST", builtins_host_tempfile.path());
+
+    if let Some(shared_lib_path) = shared_lib_path {
+        command.env("ROC_SHARED_LIB", shared_lib_path);
+    }
+
+    let status = command.status().unwrap_or_else(|err| {
+        user_error!(
+            "I couldn't run the host build command {:?}: {}",
+            program,
+            err
+        )
+    });
+
+    if !status.success() {
+        user_error!(
+            "The host build command in {} failed with {}.",
+            manifest_path.to_string_lossy(),
+            status
+        )
+    }
+
+    let output = platform_dir.join(&host_build.output);
+
+    if output != host_dest {
+        if let Err(err) = fs::copy(&output, host_dest) {
+            user_error!(
+                "The host build command finished, but I couldn't copy its output {} to {}: {}",
+                output.to_string_lossy(),
+                host_dest.to_string_lossy(),
+                err
+            )
+        }
+    }
+
+    if let Err(err) = fs::write(&stamp_path, hash) {
+        user_error!(
+            "The host build command finished, but I couldn't write its stamp file {}: {}",
+            stamp_path.to_string_lossy(),
+            err
+        )
+    }
+
+    // Extend the lifetime of the tempfile so it doesn't get dropped
+    // (and thus deleted) before the build command is done using it!
+    let _ = builtins_host_tempfile;
+
+    true
+}
+
+fn stamp_path(host_dest: &Path) -> PathBuf {
+    let mut file_name = host_dest.file_name().unwrap().to_os_string();
+    file_name.push(".stamp");
+
+    host_dest.with_file_name(file_name)
+}
+
+fn opt_level_str(opt_level: OptLevel) -> &'static str {
+    match opt_level {
+        OptLevel::Development => "development",
+        OptLevel::Normal => "normal",
+        OptLevel::Size => "size",
+        OptLevel::Optimize => "optimize",
+    }
+}
+
+/// Hashes everything that could change the built host: the build itself, the contents of its
+/// inputs, the target and opt level, the builtins it can link against, and the stub library
+/// (which has the app's exposed symbols) for surgical linking.
+fn build_hash(
+    host_build: &HostBuild,
+    platform_dir: &Path,
+    target_str: &str,
+    opt_level: OptLevel,
+    shared_lib_path: Option<&Path>,
+) -> io::Result<String> {
+    let mut hasher = blake3::Hasher::new();
+
+    for arg in host_build.command.iter() {
+        hash_str(&mut hasher, arg);
+    }
+
+    hash_str(&mut hasher, &host_build.output.to_string_lossy());
+    hash_str(&mut hasher, target_str);
+    hash_str(&mut hasher, opt_level_str(opt_level));
+
+    #[cfg(windows)]
+    hasher.update(bitcode::HOST_WINDOWS);
+
+    #[cfg(not(windows))]
+    hasher.update(bitcode::HOST_UNIX);
+
+    if let Some(shared_lib_path) = shared_lib_path {
+        hasher.update(&fs::read(shared_lib_path)?);
+    }
+
+    for input in host_build.inputs.iter() {
+        hash_path(&mut hasher, platform_dir, input)?;
+    }
+
+    Ok(hasher.finalize().to_hex().to_string())
+}
+
+/// Hashes a file's path and contents, or every file in a directory
+fn hash_path(hasher: &mut blake3::Hasher, platform_dir: &Path, path: &Path) -> io::Result<()> {
+    let full_path = platform_dir.join(path);
+
+    if full_path.is_dir() {
+        let mut entries = fs::read_dir(&full_path)?
+            .map(|entry| entry.map(|entry| entry.file_name()))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        // read_dir's order depends on the filesystem
+        entries.sort();
+
+        for entry in entries {
+            hash_path(hasher, platform_dir, &path.join(entry))?;
+        }
+    } else {
+        let contents = fs::read(&full_path)?;
+
+        hash_str(hasher, &path.to_string_lossy());
+        hasher.update(&(contents.len() as u64).to_le_bytes());
+        hasher.update(&contents);
+    }
+
+    Ok(())
+}
+
+/// Hashes the length too, so that e.g. ["ab", "c"] and ["a", "bc"] don't hash the same.
+fn hash_str(hasher: &mut blake3::Hasher, string: &str) {
+    hasher.update(&(string.len() as u64).to_le_bytes());
+    hasher.update(string.as_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_host_from_manifest, parse_manifest, stamp_path, HostBuild, ManifestProblem};
+    use roc_mono::ir::OptLevel;
+    use std::fs;
+    use std::path::PathBuf;
+    use target_lexicon::Triple;
+
+    const MANIFEST: &str = r#"
+# Comments and blank lines are ignored
+command = ./build-host.sh --release
+inputs = build-host.sh src
+output = host.o
+
+[wasm32]
+command = zig build-obj host.zig
+output = host.wasm.o
+"#;
+
+    #[test]
+    fn defaults() {
+        assert_eq!(
+            Ok(HostBuild {
+                command: vec!["./build-host.sh".to_string(), "--release".to_string()],
+                inputs: vec![PathBuf::from("build-host.sh"), PathBuf::from("src")],
+                output: PathBuf::from("host.o"),
+            }),
+            parse_manifest(MANIFEST, "linux-x86_64")
+        );
+    }
+
+    #[test]
+    fn target_section_overrides_defaults() {
+        assert_eq!(
+            Ok(HostBuild {
+                command: vec![
+                    "zig".to_string(),
+                    "build-obj".to_string(),
+                    "host.zig".to_string()
+                ],
+                inputs: vec![PathBuf::from("build-host.sh"), PathBuf::from("src")],
+                output: PathBuf::from("host.wasm.o"),
+            }),
+            parse_manifest(MANIFEST, "wasm32")
+        );
+    }
+
+    #[test]
+    fn missing_output() {
+        assert_eq!(
+            Err(ManifestProblem::Missing {
+                target: "linux-x86_64".to_string(),
+                key: "output"
+            }),
+            parse_manifest("[linux-x86_64]\ncommand = make", "linux-x86_64")
+        );
+    }
+
+    #[test]
+    fn unknown_key() {
+        assert_eq!(
+            Err(ManifestProblem::UnknownKey {
+                line_number: 2,
+                key: "outputs".to_string()
+            }),
+            parse_manifest("command = make\noutputs = host.o", "linux-x86_64")
+        );
+    }
+
+    #[test]
+    fn problem_messages() {
+        assert_eq!(
+            "line 2 has the key `outputs`, but the only keys I know are `command`, `inputs` and `output`",
+            ManifestProblem::UnknownKey {
+                line_number: 2,
+                key: "outputs".to_string()
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "it doesn't say what the `output` is for wasm32, either in a `[wasm32]` section or before the first section",
+            ManifestProblem::Missing {
+                target: "wasm32".to_string(),
+                key: "output"
+            }
+            .to_string()
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn rebuilds_only_when_inputs_change() {
+        let platform_dir = tempfile::tempdir().unwrap();
+        let platform_main_roc = platform_dir.path().join("main.roc");
+        let host_dest = platform_dir.path().join("host.built");
+        let runs_path = platform_dir.path().join("runs");
+
+        // Each run of the build script appends to `runs`, so we can count them.
+        fs::write(
+            platform_dir.path().join("build-host.sh"),
+            "echo run >> runs\ncat host.c > host.o\n",
+        )
+        .unwrap();
+        fs::write(platform_dir.path().join("host.c"), "int x = 1;").unwrap();
+        fs::write(
+            platform_dir.path().join("host.manifest"),
+            "command = sh build-host.sh\ninputs = build-host.sh host.c\noutput = host.o\n",
+        )
+        .unwrap();
+
+        let build = || {
+            assert!(build_host_from_manifest(
+                OptLevel::Normal,
+                &Triple::host(),
+                &platform_main_roc,
+                None,
+                &host_dest,
+            ));
+        };
+        let runs = || fs::read_to_string(&runs_path).unwrap().lines().count();
+
+        build();
+        assert_eq!(1, runs());
+        assert_eq!("int x = 1;", fs::read_to_string(&host_dest).unwrap());
+        assert!(stamp_path(&host_dest).exists());
+
+        // Nothing changed, so the host is reused.
+        build();
+        assert_eq!(1, runs());
+
+        fs::write(platform_dir.path().join("host.c"), "int x = 2;").unwrap();
+
+        build();
+        assert_eq!(2, runs());
+        assert_eq!("int x = 2;", fs::read_to_string(&host_dest).unwrap());
+    }
+
+    #[test]
+    fn no_manifest() {
+        let platform_dir = tempfile::tempdir().unwrap();
+
+        assert!(!build_host_from_manifest(
+            OptLevel::Normal,
+            &Triple::host(),
+            &platform_dir.path().join("main.roc"),
+            None,
+            &platform_dir.path().join("host.o"),
+        ));
+    }
+}
@@ -1,8 +1,9 @@
-use crate::target::{arch_str, target_zig_str};
+use crate::host_manifest::build_host_from_manifest;
+use crate::target::{arch_str, target_zig_str, try_target_zig_str};
 use const_format::concatcp;
 use libloading::{Error, Library};
 use roc_builtins::bitcode;
-use roc_error_macros::internal_error;
+use roc_error_macros::{internal_error, user_error};
 use roc_mono::ir::OptLevel;
 use roc_utils::{cargo, clang, zig};
 use roc_utils::{get_lib_path, rustup};
@@ -21,6 +22,7 @@ pub enum LinkType {
     Executable = 0,
     Dylib = 1,
     None = 2,
+    StaticLib = 3,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -40,6 +42,10 @@ pub fn link(
     input_paths: &[&str],
     link_type: LinkType,
 ) -> io::Result<(Child, PathBuf)> {
+    if let LinkType::StaticLib = link_type {
+        return link_static_lib(target, output_path, input_paths);
+    }
+
     match target {
         Triple {
             architecture: Architecture::Wasm32,
@@ -64,6 +70,7 @@ pub fn link(
 const PRECOMPILED_HOST_EXT: &str = "rh1"; // Short for "roc host version 1" (so we can change format in the future)
 
 const WASM_TARGET_STR: &str = "wasm32";
+const LINUX_X86_32_TARGET_STR: &str = "linux-x86_32";
 const LINUX_X86_64_TARGET_STR: &str = "linux-x86_64";
 const LINUX_ARM64_TARGET_STR: &str = "linux-arm64";
 const MACOS_ARM64_TARGET_STR: &str = "macos-arm64";
@@ -79,6 +86,15 @@ pub const fn preprocessed_host_filename(target: &Triple) -> Option<&'static str>
             architecture: Architecture::Wasm32,
             ..
         } => Some(concatcp!(WASM_TARGET_STR, '.', PRECOMPILED_HOST_EXT)),
+        Triple {
+            operating_system: OperatingSystem::Linux,
+            architecture: Architecture::X86_32(_),
+            ..
+        } => Some(concatcp!(
+            LINUX_X86_32_TARGET_STR,
+            '.',
+            PRECOMPILED_HOST_EXT
+        )),
         Triple {
             operating_system: OperatingSystem::Linux,
             architecture: Architecture::X86_64,
@@ -144,6 +160,11 @@ pub fn get_target_triple_str(target: &Triple) -> Option<&'static str> {
             architecture: Architecture::Wasm32,
             ..
         } => Some(WASM_TARGET_STR),
+        Triple {
+            operating_system: OperatingSystem::Linux,
+            architecture: Architecture::X86_32(_),
+            ..
+        } => Some(LINUX_X86_32_TARGET_STR),
         Triple {
             operating_system: OperatingSystem::Linux,
             architecture: Architecture::X86_64,
@@ -468,21 +489,9 @@ pub fn build_zig_host_wasm32(
         unimplemented!("Linking a shared library to wasm not yet implemented");
     }
 
-    let zig_target = if matches!(opt_level, OptLevel::Development) {
-        "wasm32-wasi"
-    } else {
-        // For LLVM backend wasm we are emitting a .bc file anyway so this target is OK
-        "i386-linux-musl"
-    };
-
-    // NOTE currently just to get compiler warnings if the host code is invalid.
-    // the produced artifact is not used
-    //
-    // NOTE we're emitting LLVM IR here (again, it is not actually used)
-    //
-    // we'd like to compile with `-target wasm32-wasi` but that is blocked on
-    //
-    // https://github.com/ziglang/zig/issues/9414
+    // The Wasm dev backend and the surgical linker link the app into this object.
+    // The legacy linker compiles host.zig along with the app instead, so for it, this is
+    // just to get compiler warnings if the host code is invalid.
     let mut zig_cmd = zig();
     let args = &[
         "build-obj",
@@ -498,7 +507,7 @@ pub fn build_zig_host_wasm32(
         "--library",
         "c",
         "-target",
-        zig_target,
+        "wasm32-wasi",
         // "-femit-llvm-ir=/home/folkertdev/roc/roc/crates/cli_testing_examples/benchmarks/platform/host.ll",
         "-fPIC",
         "--strip",
@@ -664,11 +673,7 @@ pub fn rebuild_host(
     };
 
     let host_dest = if matches!(target.architecture, Architecture::Wasm32) {
-        if matches!(opt_level, OptLevel::Development) {
-            platform_main_roc.with_extension("o")
-        } else {
-            platform_main_roc.with_extension("bc")
-        }
+        platform_main_roc.with_extension("o")
     } else if shared_lib_path.is_some() {
         platform_main_roc
             .with_file_name("dynhost")
@@ -677,6 +682,16 @@ pub fn rebuild_host(
         platform_main_roc.with_file_name(legacy_host_filename(target).unwrap())
     };
 
+    if build_host_from_manifest(
+        opt_level,
+        target,
+        platform_main_roc,
+        shared_lib_path,
+        &host_dest,
+    ) {
+        return host_dest;
+    }
+
     let env_path = env::var("PATH").unwrap_or_else(|_| "".to_string());
     let env_home = env::var("HOME").unwrap_or_else(|_| "".to_string());
     let env_cpath = env::var("CPATH").unwrap_or_else(|_| "".to_string());
@@ -697,11 +712,7 @@ pub fn rebuild_host(
 
         let zig_cmd = match target.architecture {
             Architecture::Wasm32 => {
-                let emit_bin = if matches!(opt_level, OptLevel::Development) {
-                    format!("-femit-bin={}", host_dest.to_str().unwrap())
-                } else {
-                    format!("-femit-llvm-ir={}", host_dest.to_str().unwrap())
-                };
+                let emit_bin = format!("-femit-bin={}", host_dest.to_str().unwrap());
                 build_zig_host_wasm32(
                     &env_path,
                     &env_home,
@@ -996,12 +1007,26 @@ fn find_in_folder_or_subfolders(path: &PathBuf, folder_to_find: &str) -> Vec<Dir
 }
 
 fn get_target_str(target: &Triple) -> &str {
+    let host = Triple::host();
+
     if target.operating_system == OperatingSystem::Windows
         && target.environment == target_lexicon::Environment::Gnu
     {
         "x86_64-windows-gnu"
-    } else {
+    } else if target.architecture == host.architecture
+        && target.operating_system == host.operating_system
+    {
         "native"
+    } else {
+        // Cross-compiling, e.g. building a macOS host on Linux
+        match try_target_zig_str(target) {
+            Some(zig_target) => zig_target,
+            None => user_error!(
+                "I don't know how to build a host for {} on this machine. You can build the host on a {} machine instead, and then use it here with --prebuilt-platform=true.",
+                target,
+                target
+            ),
+        }
     }
 }
 
@@ -1205,6 +1230,9 @@ fn link_linux(
             )
         }
         LinkType::None => internal_error!("link_linux should not be called with link type of none"),
+        LinkType::StaticLib => {
+            internal_error!("link_linux should not be called with link type of static lib")
+        }
     };
 
     let env_path = env::var("PATH").unwrap_or_else(|_| "".to_string());
@@ -1278,6 +1306,9 @@ fn link_macos(
             )
         }
         LinkType::None => internal_error!("link_macos should not be called with link type of none"),
+        LinkType::StaticLib => {
+            internal_error!("link_macos should not be called with link type of static lib")
+        }
     };
 
     let arch = match target.architecture {
@@ -1475,9 +1506,56 @@ fn link_windows(
             Ok((child, output_path))
         }
         LinkType::None => todo!(),
+        LinkType::StaticLib => {
+            internal_error!("link_windows should not be called with link type of static lib")
+        }
     }
 }
 
+/// Bundles the object files into a static library (`.a`, or `.lib` on Windows).
+fn link_static_lib(
+    target: &Triple,
+    output_path: PathBuf,
+    input_paths: &[&str],
+) -> io::Result<(Child, PathBuf)> {
+    let mut output_path = output_path;
+
+    if let OperatingSystem::Windows = target.operating_system {
+        output_path.set_extension("lib");
+
+        let child = zig()
+            .args(["build-lib"])
+            .args(input_paths)
+            .args([
+                &format!("-femit-bin={}", output_path.to_str().unwrap()),
+                "-target",
+                get_target_str(target),
+                "--strip",
+                "-O",
+                "Debug",
+            ])
+            .spawn()?;
+
+        return Ok((child, output_path));
+    }
+
+    output_path.set_extension("a");
+
+    // `ar r` adds to an existing archive, which would keep the objects from previous builds
+    if output_path.exists() {
+        fs::remove_file(&output_path)?;
+    }
+
+    // The `s` writes an index of the archive's symbols, which linkers need to find them
+    let child = Command::new("ar")
+        .arg("rcs")
+        .arg(&output_path)
+        .args(input_paths)
+        .spawn()?;
+
+    Ok((child, output_path))
+}
+
 pub fn llvm_module_to_dylib(
     module: &inkwell::module::Module,
     target: &Triple,
@@ -1631,3 +1709,49 @@ fn run_build_command(mut command: Command, file_to_build: &str, flaky_fail_count
         }
     }
 }
+
+#[cfg(all(test, unix))]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    fn archive_members(archive: &Path) -> Vec<String> {
+        let output = Command::new("ar").arg("t").arg(archive).output().unwrap();
+
+        String::from_utf8(output.stdout)
+            .unwrap()
+            .lines()
+            .map(|line| line.to_string())
+            .collect()
+    }
+
+    fn link_static(output_path: PathBuf, input_paths: &[&str]) -> PathBuf {
+        let target = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        let (mut child, binary_path) =
+            link(&target, output_path, input_paths, LinkType::StaticLib).unwrap();
+
+        assert!(child.wait().unwrap().success());
+
+        binary_path
+    }
+
+    #[test]
+    fn static_lib_is_rebuilt_from_scratch() {
+        let dir = tempfile::tempdir().unwrap();
+        let app_o = dir.path().join("app.o");
+        let builtins_o = dir.path().join("builtins.o");
+        fs::write(&app_o, "app").unwrap();
+        fs::write(&builtins_o, "builtins").unwrap();
+
+        let inputs = [app_o.to_str().unwrap(), builtins_o.to_str().unwrap()];
+        let archive = link_static(dir.path().join("libapp"), &inputs);
+
+        assert_eq!(dir.path().join("libapp.a"), archive);
+        assert_eq!(vec!["app.o", "builtins.o"], archive_members(&archive));
+
+        // `ar` adds to an existing archive, so the builtins would still be in there
+        let archive = link_static(dir.path().join("libapp"), &inputs[..1]);
+
+        assert_eq!(vec!["app.o"], archive_members(&archive));
+    }
+}
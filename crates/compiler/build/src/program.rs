@@ -1,3 +1,4 @@
+use crate::link::LinkingStrategy;
 use inkwell::memory_buffer::MemoryBuffer;
 use roc_error_macros::internal_error;
 use roc_gen_llvm::llvm::build::{module_from_builtins, LlvmBackendMode};
@@ -7,6 +8,7 @@ use roc_mono::ir::{OptLevel, SingleEntryPoint};
 use roc_reporting::cli::{report_problems, Problems};
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::time::{Duration, Instant};
 
 #[cfg(feature = "target-wasm32")]
@@ -65,36 +67,185 @@ pub struct CodeGenOptions {
     pub backend: CodeGenBackend,
     pub opt_level: OptLevel,
     pub emit_debug_info: bool,
+    /// Intermediate output to write next to the build output
+    pub emit: EmitStages,
+}
+
+/// A stage of compilation whose output `roc build --emit` can write to disk
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmitStage {
+    /// Canonical IR, after name resolution and desugaring
+    Can,
+    /// Mono IR, after specialization and reference counting
+    Mono,
+    LlvmIr,
+    Asm,
+    Obj,
+    /// WebAssembly text format, for wasm32 targets
+    Wat,
+}
+
+impl EmitStage {
+    pub const NAMES: [&'static str; 6] = ["can", "mono", "llvm-ir", "asm", "obj", "wat"];
+}
+
+impl FromStr for EmitStage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "can" => Ok(EmitStage::Can),
+            "mono" => Ok(EmitStage::Mono),
+            "llvm-ir" => Ok(EmitStage::LlvmIr),
+            "asm" => Ok(EmitStage::Asm),
+            "obj" => Ok(EmitStage::Obj),
+            "wat" => Ok(EmitStage::Wat),
+            _ => Err(format!(
+                "Unrecognized --emit stage \"{}\"; the options are {}",
+                s,
+                Self::NAMES.join(", ")
+            )),
+        }
+    }
+}
+
+/// A set of [EmitStage]s. This is a bitset so that [CodeGenOptions] can stay Copy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EmitStages(u8);
+
+impl EmitStages {
+    pub fn insert(&mut self, stage: EmitStage) {
+        self.0 |= 1 << stage as u8;
+    }
+
+    pub fn contains(&self, stage: EmitStage) -> bool {
+        self.0 & (1 << stage as u8) != 0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl FromIterator<EmitStage> for EmitStages {
+    fn from_iter<T: IntoIterator<Item = EmitStage>>(iter: T) -> Self {
+        let mut stages = EmitStages::default();
+
+        for stage in iter {
+            stages.insert(stage);
+        }
+
+        stages
+    }
+}
+
+/// Write the output of an `--emit` stage next to the build output, replacing its extension,
+/// e.g. `app.ll` for `app`
+pub fn write_emitted(output_path: &Path, extension: &str, contents: &[u8]) {
+    let path = output_path.with_extension(extension);
+
+    if let Err(error) = std::fs::write(&path, contents) {
+        eprintln!(
+            "I couldn't write the --emit output to {}: {}",
+            path.display(),
+            error
+        );
+    }
 }
 
 type GenFromMono<'a> = (CodeObject, CodeGenTiming, ExpectMetadata<'a>);
 
+/// `output_path` is the final build output; `--emit` stages are written next to it.
 #[allow(clippy::too_many_arguments)]
 pub fn gen_from_mono_module<'a>(
     arena: &'a bumpalo::Bump,
     loaded: MonomorphizedModule<'a>,
     roc_file_path: &Path,
+    output_path: &Path,
     target: &target_lexicon::Triple,
     code_gen_options: CodeGenOptions,
     preprocessed_host_path: &Path,
+    linking_strategy: LinkingStrategy,
     wasm_dev_stack_bytes: Option<u32>,
 ) -> GenFromMono<'a> {
-    match code_gen_options.backend {
-        CodeGenBackend::Assembly => gen_from_mono_module_dev(
+    use target_lexicon::Architecture;
+
+    let emit = code_gen_options.emit;
+
+    if emit.contains(EmitStage::Mono) {
+        write_emitted(output_path, "mono", mono_to_pretty(&loaded).as_bytes());
+    }
+
+    let gen_from_mono = match code_gen_options.backend {
+        CodeGenBackend::Assembly => {
+            if emit.contains(EmitStage::LlvmIr) || emit.contains(EmitStage::Asm) {
+                eprintln!(
+                    "--emit=llvm-ir and --emit=asm need the LLVM backend, so I skipped them."
+                );
+            }
+
+            gen_from_mono_module_dev(
+                arena,
+                loaded,
+                target,
+                preprocessed_host_path,
+                wasm_dev_stack_bytes,
+            )
+        }
+        CodeGenBackend::Llvm => gen_from_mono_module_llvm(
             arena,
             loaded,
+            roc_file_path,
+            output_path,
             target,
-            preprocessed_host_path,
-            wasm_dev_stack_bytes,
+            code_gen_options,
+            linking_strategy,
         ),
-        CodeGenBackend::Llvm => {
-            gen_from_mono_module_llvm(arena, loaded, roc_file_path, target, code_gen_options)
-        }
         CodeGenBackend::Wasm => {
             // emit wasm via the llvm backend
-            gen_from_mono_module_llvm(arena, loaded, roc_file_path, target, code_gen_options)
+            gen_from_mono_module_llvm(
+                arena,
+                loaded,
+                roc_file_path,
+                output_path,
+                target,
+                code_gen_options,
+                linking_strategy,
+            )
         }
+    };
+
+    if emit.contains(EmitStage::Obj) {
+        let extension = match (code_gen_options.backend, target.architecture) {
+            // The dev backend produces a complete wasm module, and LLVM produces bitcode
+            // unless it's for the surgical linker
+            (CodeGenBackend::Assembly, Architecture::Wasm32) => "wasm",
+            (_, Architecture::Wasm32)
+                if !code_gen_options.emit_debug_info
+                    && linking_strategy != LinkingStrategy::Surgical =>
+            {
+                "bc"
+            }
+            _ => roc_target::OperatingSystem::from(target.operating_system).object_file_ext(),
+        };
+
+        write_emitted(output_path, extension, &gen_from_mono.0);
     }
+
+    gen_from_mono
+}
+
+/// Every specialized procedure, sorted so that the output is the same between builds
+fn mono_to_pretty(loaded: &MonomorphizedModule) -> String {
+    let mut procs: Vec<String> = loaded
+        .procedures
+        .values()
+        .map(|proc| proc.to_pretty(&loaded.layout_interner, 200, true))
+        .collect();
+
+    procs.sort();
+
+    procs.join("\n")
 }
 
 // TODO how should imported modules factor into this? What if those use builtins too?
@@ -104,8 +255,10 @@ fn gen_from_mono_module_llvm<'a>(
     arena: &'a bumpalo::Bump,
     mut loaded: MonomorphizedModule<'a>,
     roc_file_path: &Path,
+    output_path: &Path,
     target: &target_lexicon::Triple,
     code_gen_options: CodeGenOptions,
+    linking_strategy: LinkingStrategy,
 ) -> GenFromMono<'a> {
     use crate::target::{self, convert_opt_level};
     use inkwell::attributes::{Attribute, AttributeLoc};
@@ -159,6 +312,7 @@ fn gen_from_mono_module_llvm<'a>(
         backend: _,
         opt_level,
         emit_debug_info,
+        emit,
     } = code_gen_options;
 
     let builder = context.create_builder();
@@ -235,6 +389,47 @@ fn gen_from_mono_module_llvm<'a>(
     // Uncomment this to see the module's optimized LLVM instruction output:
     // env.module.print_to_stderr();
 
+    if emit.contains(EmitStage::LlvmIr) {
+        let app_ll_path = output_path.with_extension("ll");
+
+        if let Err(error) = env.module.print_to_file(&app_ll_path) {
+            eprintln!(
+                "I couldn't write the --emit output to {}: {}",
+                app_ll_path.display(),
+                error
+            );
+        }
+    }
+
+    if emit.contains(EmitStage::Asm) {
+        use target_lexicon::Architecture;
+        match target.architecture {
+            Architecture::X86_64 | Architecture::X86_32(_) | Architecture::Aarch64(_) => {
+                let app_s_path = output_path.with_extension("s");
+                let target_machine =
+                    target::target_machine(target, convert_opt_level(opt_level), RelocMode::PIC)
+                        .unwrap();
+
+                if let Err(error) =
+                    target_machine.write_to_file(env.module, FileType::Assembly, &app_s_path)
+                {
+                    eprintln!(
+                        "I couldn't write the --emit output to {}: {}",
+                        app_s_path.display(),
+                        error
+                    );
+                }
+            }
+            Architecture::Wasm32 => {
+                eprintln!("--emit=asm isn't supported for wasm32, so I skipped it. Try --emit=wat instead.");
+            }
+            _ => internal_error!(
+                "TODO gracefully handle unsupported architecture: {:?}",
+                target.architecture
+            ),
+        }
+    }
+
     // annotate the LLVM IR output with debug info
     // so errors are reported with the line number of the LLVM source
     let memory_buffer = if cfg!(feature = "sanitizers") && std::env::var("ROC_SANITIZERS").is_ok() {
@@ -399,6 +594,18 @@ fn gen_from_mono_module_llvm<'a>(
                     .write_to_memory_buffer(env.module, FileType::Object)
                     .expect("Writing .o file failed")
             }
+            Architecture::Wasm32 if linking_strategy == LinkingStrategy::Surgical => {
+                // The surgical linker needs an object file. Addresses of data and functions
+                // must be static, because with PIC they'd be imported from a global offset
+                // table, which the surgical linker doesn't handle.
+                let target_machine =
+                    target::target_machine(target, convert_opt_level(opt_level), RelocMode::Static)
+                        .unwrap();
+
+                target_machine
+                    .write_to_memory_buffer(env.module, FileType::Object)
+                    .expect("Writing .o file failed")
+            }
             Architecture::Wasm32 => {
                 // Useful for debugging
                 // module.print_to_file(app_ll_file);
@@ -50,6 +50,13 @@ pub fn target_triple_str(target: &Triple) -> &'static str {
 }
 
 pub fn target_zig_str(target: &Triple) -> &'static str {
+    try_target_zig_str(target).unwrap_or_else(|| {
+        internal_error!("TODO gracefully handle unsupported target: {:?}", target)
+    })
+}
+
+/// Like target_zig_str, but returns None for targets zig doesn't know how to build for
+pub fn try_target_zig_str(target: &Triple) -> Option<&'static str> {
     // Zig has its own architecture mappings, defined here:
     // https://github.com/ziglang/zig/blob/master/tools/process_headers.zig
     //
@@ -61,39 +68,45 @@ pub fn target_zig_str(target: &Triple) -> &'static str {
             operating_system: OperatingSystem::Linux,
             environment: Environment::Musl,
             ..
-        } => "x86_64-linux-musl",
+        } => Some("x86_64-linux-musl"),
         Triple {
             architecture: Architecture::X86_64,
             operating_system: OperatingSystem::Linux,
             ..
-        } => "x86_64-linux-gnu",
+        } => Some("x86_64-linux-gnu"),
         Triple {
             architecture: Architecture::X86_32(target_lexicon::X86_32Architecture::I386),
             operating_system: OperatingSystem::Linux,
             environment: Environment::Musl,
             ..
-        } => "i386-linux-musl",
+        } => Some("i386-linux-musl"),
         Triple {
             architecture: Architecture::X86_32(target_lexicon::X86_32Architecture::I386),
             operating_system: OperatingSystem::Linux,
             ..
-        } => "i386-linux-gnu",
+        } => Some("i386-linux-gnu"),
         Triple {
             architecture: Architecture::Aarch64(_),
             operating_system: OperatingSystem::Linux,
+            environment: Environment::Musl,
             ..
-        } => "aarch64-linux-gnu",
+        } => Some("aarch64-linux-musl"),
+        Triple {
+            architecture: Architecture::Aarch64(_),
+            operating_system: OperatingSystem::Linux,
+            ..
+        } => Some("aarch64-linux-gnu"),
         Triple {
             architecture: Architecture::X86_64,
             operating_system: OperatingSystem::Darwin,
             ..
-        } => "x86_64-apple-darwin",
+        } => Some("x86_64-apple-darwin"),
         Triple {
             architecture: Architecture::Aarch64(_),
             operating_system: OperatingSystem::Darwin,
             ..
-        } => "aarch64-apple-darwin",
-        _ => internal_error!("TODO gracefully handle unsupported target: {:?}", target),
+        } => Some("aarch64-apple-darwin"),
+        _ => None,
     }
 }
 
@@ -132,7 +145,7 @@ pub fn arch_str(target: &Triple) -> &'static str {
         Architecture::X86_32(_) if cfg!(feature = "target-x86") => "x86",
         Architecture::Aarch64(_) if cfg!(feature = "target-aarch64") => "aarch64",
         Architecture::Arm(_) if cfg!(feature = "target-arm") => "arm",
-        Architecture::Wasm32 if cfg!(feature = "target-webassembly") => "wasm32",
+        Architecture::Wasm32 if cfg!(feature = "target-wasm32") => "wasm32",
         _ => internal_error!(
             "TODO gracefully handle unsupported target architecture: {:?}",
             target.architecture
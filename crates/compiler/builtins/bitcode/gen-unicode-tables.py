@@ -0,0 +1,298 @@
+#!/usr/bin/env python3
+"""
+Generates the tables at the bottom of src/helpers/unicode.zig, and rewrites that file in place.
+
+    python3.9 gen-unicode-tables.py
+
+Most of the tables are indexed by the property index from the two-stage lookup in
+src/helpers/grapheme.zig, which is a translation of utf8proc 2.6. Its data is Unicode 13.0.0,
+so these tables must be too. They come from the unicodedata module and str methods of a Python
+whose Unicode database is that same version, which is Python 3.9.
+
+utf8proc gives codepoints with different properties different indices, so every codepoint with
+the same index must get the same value in every table here. This checks that, which also catches
+a Python whose Unicode database doesn't match grapheme.zig's.
+"""
+
+import os
+import re
+import sys
+import unicodedata
+
+UNICODE_VERSION = "13.0.0"
+
+HERE = os.path.dirname(os.path.abspath(__file__))
+GRAPHEME_ZIG = os.path.join(HERE, "src", "helpers", "grapheme.zig")
+UNICODE_ZIG = os.path.join(HERE, "src", "helpers", "unicode.zig")
+
+# Everything from this line to the end of unicode.zig is generated
+MARKER = "// Everything below this line is generated by gen-unicode-tables.py. Do not edit it by hand!"
+
+# Lines of generated arrays are at most this long, before `zig fmt` lines up their columns
+MAX_LINE_LENGTH = 100
+
+HANGUL_SYLLABLES = range(0xAC00, 0xAC00 + 11172)
+SURROGATES = range(0xD800, 0xE000)
+
+# The Word_Break values MidLetter, MidNumLet and Single_Quote, which make a scalar Case_Ignorable
+# https://www.unicode.org/Public/13.0.0/ucd/auxiliary/WordBreakProperty.txt
+WORD_BREAK_MID = {
+    0x0027, 0x002E, 0x003A, 0x00B7, 0x0387, 0x055F, 0x05F4, 0x2018, 0x2019, 0x2024, 0x2027,
+    0xFE13, 0xFE52, 0xFE55, 0xFF07, 0xFF0E, 0xFF1A,
+}
+
+
+def read_zig_array(source, name):
+    match = re.search(r"const " + name + r": \[(\d+)\]\w+ = \[_\]\w+\{(.*?)\};", source, re.S)
+    values = [int(value) for value in match.group(2).replace("\n", "").split(",") if value.strip()]
+
+    assert len(values) == int(match.group(1)), name
+
+    return values
+
+
+def property_indices():
+    """Maps every codepoint to its property index, using the same lookup as grapheme.zig"""
+    with open(GRAPHEME_ZIG) as file:
+        source = file.read()
+
+    stage1 = read_zig_array(source, "stage1_table")
+    stage2 = read_zig_array(source, "stage2_table")
+
+    return [stage2[stage1[cp >> 8] + (cp & 255)] for cp in range(0x110000)]
+
+
+def scalars(string):
+    return [ord(char) for char in string]
+
+
+def case_mapping(method):
+    def mapping(cp):
+        char = chr(cp)
+        mapped = method(char)
+
+        return None if mapped == char else scalars(mapped)
+
+    return mapping
+
+
+def decomposition(cp):
+    # Hangul syllables are decomposed algorithmically
+    if cp in HANGUL_SYLLABLES:
+        return None
+
+    char = chr(cp)
+    decomposed = unicodedata.normalize("NFD", char)
+
+    return None if decomposed == char else scalars(decomposed)
+
+
+def compositions():
+    """Every primary composite, other than Hangul syllables, as (first, second, composite)"""
+    result = []
+
+    for cp in range(0x110000):
+        if cp in SURROGATES or cp in HANGUL_SYLLABLES:
+            continue
+
+        char = chr(cp)
+        fields = unicodedata.decomposition(char).split()
+
+        # Only canonical decompositions into two scalars compose; singletons and
+        # compatibility decompositions (which start with a <tag>) never do
+        if len(fields) != 2 or fields[0].startswith("<"):
+            continue
+
+        # Full_Composition_Exclusion
+        if unicodedata.normalize("NFC", char) != char:
+            continue
+
+        first, second = (int(field, 16) for field in fields)
+        result.append((first, second, cp))
+
+    return sorted(result)
+
+
+def is_cased(char):
+    return char.islower() or char.isupper() or unicodedata.category(char) == "Lt"
+
+
+def is_case_ignorable(char):
+    return (
+        unicodedata.category(char) in ("Mn", "Me", "Cf", "Lm", "Sk")
+        or ord(char) in WORD_BREAK_MID
+    )
+
+
+def ranges(predicate):
+    result = []
+    start = None
+
+    for cp in range(0x110001):
+        matches = cp < 0x110000 and cp not in SURROGATES and predicate(chr(cp))
+
+        if matches and start is None:
+            start = cp
+        elif not matches and start is not None:
+            result.append((start, cp - 1))
+            start = None
+
+    return result
+
+
+def per_index(indices, index_count, property_of):
+    """The value of a property for each property index, checking that it's the same for every
+    codepoint with that index"""
+    values = [None] * index_count
+    seen = [False] * index_count
+
+    for cp in range(0x110000):
+        index = indices[cp]
+        value = property_of(cp)
+
+        if not seen[index]:
+            values[index] = value
+            seen[index] = True
+        elif values[index] != value:
+            sys.exit(
+                "U+{:04X} has property index {}, like U+{:04X}, but a different value for it. "
+                "Is this Python's Unicode database ({}) the same version as grapheme.zig's?".format(
+                    cp,
+                    index,
+                    indices.index(index),
+                    unicodedata.unidata_version,
+                )
+            )
+
+    return values
+
+
+def zig_array(name, element_type, values, format_value=str):
+    cells = [format_value(value) + "," for value in values]
+    width = max(len(cell) for cell in cells)
+    per_line = max(1, (MAX_LINE_LENGTH - 4 + 1) // (width + 1))
+
+    lines = ["const {}: [{}]{} = [_]{}{{".format(name, len(values), element_type, element_type)]
+
+    for start in range(0, len(cells), per_line):
+        row = cells[start : start + per_line]
+        lines.append("    " + " ".join(cell.ljust(width) for cell in row).rstrip())
+
+    lines.append("};")
+
+    return "\n".join(lines)
+
+
+def hex_scalar(cp):
+    return "0x{:04X}".format(cp)
+
+
+def zig_struct_array(name, element_type, values, fields):
+    lines = ["const {}: [{}]{} = [_]{}{{".format(name, len(values), element_type, element_type)]
+
+    for value in values:
+        inits = ", ".join(
+            ".{} = {}".format(field, hex_scalar(scalar)) for field, scalar in zip(fields, value)
+        )
+        lines.append("    .{{ {} }},".format(inits))
+
+    lines.append("};")
+
+    return "\n".join(lines)
+
+
+def generate():
+    indices = property_indices()
+    index_count = max(indices) + 1
+
+    combining_classes = per_index(
+        indices, index_count, lambda cp: unicodedata.combining(chr(cp))
+    )
+
+    # Mappings are stored in `sequences` as a length followed by that many scalars, and the
+    # tables share any that are the same. Offset 0 is a placeholder, so that an offset of 0 can
+    # mean "maps to itself".
+    sequences = [0]
+    sequence_offsets = {}
+
+    def offsets(mapping):
+        result = []
+
+        for value in per_index(indices, index_count, mapping):
+            if value is None:
+                result.append(0)
+                continue
+
+            key = tuple(value)
+
+            if key not in sequence_offsets:
+                sequence_offsets[key] = len(sequences)
+                sequences.append(len(value))
+                sequences.extend(value)
+
+            result.append(sequence_offsets[key])
+
+        return result
+
+    uppercase_offsets = offsets(case_mapping(str.upper))
+    lowercase_offsets = offsets(case_mapping(str.lower))
+    case_fold_offsets = offsets(case_mapping(str.casefold))
+    decomposition_offsets = offsets(decomposition)
+
+    return "\n".join(
+        [
+            MARKER,
+            "",
+            "// https://www.unicode.org/Public/{}/ucd/UnicodeData.txt".format(UNICODE_VERSION),
+            "",
+            zig_array("combining_classes", "u8", combining_classes),
+            "",
+            zig_array("uppercase_offsets", "u16", uppercase_offsets),
+            "",
+            zig_array("lowercase_offsets", "u16", lowercase_offsets),
+            "",
+            zig_array("case_fold_offsets", "u16", case_fold_offsets),
+            "",
+            zig_array("decomposition_offsets", "u16", decomposition_offsets),
+            "",
+            zig_array("sequences", "u21", sequences),
+            "",
+            zig_struct_array(
+                "compositions",
+                "Composition",
+                compositions(),
+                ["first", "second", "composite"],
+            ),
+            "",
+            "// https://www.unicode.org/Public/{}/ucd/DerivedCoreProperties.txt".format(
+                UNICODE_VERSION
+            ),
+            "",
+            zig_struct_array("cased_ranges", "Range", ranges(is_cased), ["first", "last"]),
+            "",
+            zig_struct_array(
+                "case_ignorable_ranges", "Range", ranges(is_case_ignorable), ["first", "last"]
+            ),
+            "",
+        ]
+    )
+
+
+def main():
+    if unicodedata.unidata_version != UNICODE_VERSION:
+        sys.exit(
+            "This Python's Unicode database is version {}, but grapheme.zig's is {}. "
+            "Run this with Python 3.9.".format(unicodedata.unidata_version, UNICODE_VERSION)
+        )
+
+    with open(UNICODE_ZIG) as file:
+        source = file.read()
+
+    handwritten = source[: source.index(MARKER)]
+
+    with open(UNICODE_ZIG, "w") as file:
+        file.write(handwritten + generate())
+
+
+if __name__ == "__main__":
+    main()
@@ -6,7 +6,8 @@ const expectEqual = std.testing.expectEqual;
 // the https://github.com/JuliaStrings/utf8proc library.
 // Thanks so much to those developers!
 //
-// The only function this file exposes is `isGraphemeBreak`
+// The only functions this file exposes are `isGraphemeBreak` and `codepointToPropertyIndex`,
+// which unicode.zig uses to look up its own per-property tables
 //
 // LICENSE -> https://github.com/JuliaStrings/utf8proc/blob/master/LICENSE.md
 
@@ -106,9 +107,13 @@ fn graphemeBreakExtended(lbc: BoundClass, tbc: BoundClass, opt_state_ptr: *?Boun
 const codepoint_max: u21 = 1114112;
 
 // https://github.com/JuliaStrings/utf8proc/blob/master/utf8proc.c#L233
-fn unsafeCodepointToBoundClass(codepoint: u21) *const BoundClass {
+pub fn codepointToPropertyIndex(codepoint: u21) u16 {
     assert(codepoint >= 0 and codepoint < codepoint_max);
-    return &boundclasses[stage2_table[stage1_table[codepoint >> 8] + (codepoint & 255)]];
+    return stage2_table[stage1_table[codepoint >> 8] + (codepoint & 255)];
+}
+
+fn unsafeCodepointToBoundClass(codepoint: u21) *const BoundClass {
+    return &boundclasses[codepointToPropertyIndex(codepoint)];
 }
 
 test "unsafeCodepointToBoundClass: valid" {
@@ -0,0 +1,4903 @@
+const std = @import("std");
+const grapheme = @import("grapheme.zig");
+const expect = std.testing.expect;
+const expectEqual = std.testing.expectEqual;
+const expectEqualSlices = std.testing.expectEqualSlices;
+
+// Case mappings, canonical combining classes and canonical (de)compositions, as used by
+// `Str.toUpper`, `Str.toLower`, `Str.caseFold`, `Str.toNfc` and `Str.toNfd`.
+//
+// The tables at the bottom of this file are indexed by the same property index as the
+// boundclasses in grapheme.zig, so both share its two-stage codepoint lookup. Case mappings
+// are the full, language-independent ones (SpecialCasing.txt and CaseFolding.txt). The one
+// context-dependent mapping among those, Final_Sigma, is checked separately by `isFinalSigma`,
+// using the Cased and Case_Ignorable properties stored as sorted codepoint ranges.
+// Decompositions are stored fully expanded. Hangul syllables are (de)composed algorithmically.
+//
+// The tables are generated by gen-unicode-tables.py, next to build.zig, from Unicode 13.0.0.
+// That is the version of the utf8proc 2.6 data that grapheme.zig is translated from, and it has
+// to match, since the property indices come from there. Run the script with Python 3.9, whose
+// unicodedata module is Unicode 13.0.0, to regenerate them.
+//
+// https://www.unicode.org/reports/tr15/
+
+const Composition = struct {
+    first: u21,
+    second: u21,
+    composite: u21,
+};
+
+// The most scalars a single scalar can canonically decompose into
+pub const max_decomposition_length: usize = 4;
+
+// Mappings are stored in `sequences` as a length followed by that many scalars;
+// an offset of 0 means "maps to itself".
+fn sequence(offset: u16) ?[]const u21 {
+    if (offset == 0) {
+        return null;
+    }
+
+    const start = @as(usize, offset) + 1;
+    return sequences[start .. start + @as(usize, sequences[offset])];
+}
+
+pub fn uppercaseMapping(codepoint: u21) ?[]const u21 {
+    return sequence(uppercase_offsets[grapheme.codepointToPropertyIndex(codepoint)]);
+}
+
+pub fn lowercaseMapping(codepoint: u21) ?[]const u21 {
+    return sequence(lowercase_offsets[grapheme.codepointToPropertyIndex(codepoint)]);
+}
+
+pub fn caseFoldMapping(codepoint: u21) ?[]const u21 {
+    return sequence(case_fold_offsets[grapheme.codepointToPropertyIndex(codepoint)]);
+}
+
+test "case mappings" {
+    try expectEqualSlices(u21, &[_]u21{'A'}, uppercaseMapping('a').?);
+    try expectEqualSlices(u21, &[_]u21{ 'S', 'S' }, uppercaseMapping(0xDF).?);
+    try expectEqualSlices(u21, &[_]u21{0x3C3}, lowercaseMapping(0x3A3).?);
+    try expectEqualSlices(u21, &[_]u21{ 's', 's' }, caseFoldMapping(0xDF).?);
+    try expect(uppercaseMapping('A') == null);
+    try expect(lowercaseMapping('1') == null);
+}
+
+pub fn combiningClass(codepoint: u21) u8 {
+    return combining_classes[grapheme.codepointToPropertyIndex(codepoint)];
+}
+
+test "combiningClass" {
+    try expectEqual(@as(u8, 0), combiningClass('e'));
+    try expectEqual(@as(u8, 230), combiningClass(0x301));
+    try expectEqual(@as(u8, 220), combiningClass(0x323));
+}
+
+// https://www.unicode.org/versions/Unicode13.0.0/ch03.pdf#G56669
+const hangul_s_base: u21 = 0xAC00;
+const hangul_l_base: u21 = 0x1100;
+const hangul_v_base: u21 = 0x1161;
+const hangul_t_base: u21 = 0x11A7;
+const hangul_l_count: u21 = 19;
+const hangul_v_count: u21 = 21;
+const hangul_t_count: u21 = 28;
+const hangul_n_count: u21 = hangul_v_count * hangul_t_count;
+const hangul_s_count: u21 = hangul_l_count * hangul_n_count;
+
+// Writes the full canonical decomposition of `codepoint` into `dest`, which must have room for
+// `max_decomposition_length` scalars, and returns how many scalars were written. Scalars that
+// do not decompose are written unchanged.
+pub fn decompose(codepoint: u21, dest: []u21) usize {
+    if (codepoint >= hangul_s_base and codepoint < hangul_s_base + hangul_s_count) {
+        const s_index = codepoint - hangul_s_base;
+        const t_index = s_index % hangul_t_count;
+
+        dest[0] = hangul_l_base + s_index / hangul_n_count;
+        dest[1] = hangul_v_base + (s_index % hangul_n_count) / hangul_t_count;
+
+        if (t_index == 0) {
+            return 2;
+        }
+
+        dest[2] = hangul_t_base + t_index;
+        return 3;
+    }
+
+    if (sequence(decomposition_offsets[grapheme.codepointToPropertyIndex(codepoint)])) |decomposition| {
+        std.mem.copy(u21, dest, decomposition);
+        return decomposition.len;
+    } else {
+        dest[0] = codepoint;
+        return 1;
+    }
+}
+
+test "decompose" {
+    var dest: [max_decomposition_length]u21 = undefined;
+
+    try expectEqual(@as(usize, 1), decompose('e', &dest));
+    try expectEqual(@as(u21, 'e'), dest[0]);
+
+    try expectEqual(@as(usize, 2), decompose(0xE9, &dest));
+    try expectEqualSlices(u21, &[_]u21{ 'e', 0x301 }, dest[0..2]);
+
+    // U+1E69 LATIN SMALL LETTER S WITH DOT BELOW AND DOT ABOVE decomposes recursively
+    try expectEqual(@as(usize, 3), decompose(0x1E69, &dest));
+    try expectEqualSlices(u21, &[_]u21{ 's', 0x323, 0x307 }, dest[0..3]);
+
+    // U+D4DB HANGUL SYLLABLE PWILH
+    try expectEqual(@as(usize, 3), decompose(0xD4DB, &dest));
+    try expectEqualSlices(u21, &[_]u21{ 0x1111, 0x1171, 0x11B6 }, dest[0..3]);
+}
+
+// Returns the primary composite of `first` followed by `second`, if there is one.
+pub fn compose(first: u21, second: u21) ?u21 {
+    if (first >= hangul_l_base and first < hangul_l_base + hangul_l_count and
+        second >= hangul_v_base and second < hangul_v_base + hangul_v_count)
+    {
+        const l_index = first - hangul_l_base;
+        const v_index = second - hangul_v_base;
+
+        return hangul_s_base + (l_index * hangul_v_count + v_index) * hangul_t_count;
+    }
+
+    if (first >= hangul_s_base and first < hangul_s_base + hangul_s_count and
+        (first - hangul_s_base) % hangul_t_count == 0 and
+        second > hangul_t_base and second < hangul_t_base + hangul_t_count)
+    {
+        return first + (second - hangul_t_base);
+    }
+
+    var low: usize = 0;
+    var high: usize = compositions.len;
+
+    while (low < high) {
+        const mid = low + (high - low) / 2;
+        const entry = compositions[mid];
+
+        if (entry.first == first and entry.second == second) {
+            return entry.composite;
+        } else if (entry.first < first or (entry.first == first and entry.second < second)) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    return null;
+}
+
+test "compose" {
+    try expectEqual(@as(?u21, 0xE9), compose('e', 0x301));
+    try expectEqual(@as(?u21, 0xD4CC), compose(0x1111, 0x1171));
+    try expectEqual(@as(?u21, 0xD4DB), compose(0xD4CC, 0x11B6));
+    try expectEqual(@as(?u21, null), compose('e', 'e'));
+    // U+0958 DEVANAGARI LETTER QA is a composition exclusion
+    try expectEqual(@as(?u21, null), compose(0x915, 0x93C));
+}
+
+// Σ lowercases to ς instead of σ when it ends a word: when it follows a cased scalar, with
+// only case-ignorable scalars in between, and is not followed by one in the same way.
+//
+// https://www.unicode.org/versions/Unicode13.0.0/ch03.pdf#G54277
+pub const final_sigma = [_]u21{0x3C2};
+
+// Whether the Σ at `bytes[index .. index + width]` is in the Final_Sigma context
+pub fn isFinalSigma(bytes: []const u8, index: usize, width: usize) bool {
+    return casedBefore(bytes, index) and !casedAfter(bytes, index + width);
+}
+
+fn casedBefore(bytes: []const u8, end: usize) bool {
+    var index = end;
+
+    while (index > 0) {
+        index -= 1;
+
+        // step back over continuation bytes to the start of the scalar
+        while (bytes[index] & 0b1100_0000 == 0b1000_0000) {
+            index -= 1;
+        }
+
+        const width = @intCast(usize, std.unicode.utf8ByteSequenceLength(bytes[index]) catch unreachable);
+        const scalar = std.unicode.utf8Decode(bytes[index .. index + width]) catch unreachable;
+
+        if (isCased(scalar)) {
+            return true;
+        } else if (!isCaseIgnorable(scalar)) {
+            return false;
+        }
+    }
+
+    return false;
+}
+
+fn casedAfter(bytes: []const u8, start: usize) bool {
+    var index = start;
+
+    while (index < bytes.len) {
+        const width = @intCast(usize, std.unicode.utf8ByteSequenceLength(bytes[index]) catch unreachable);
+        const scalar = std.unicode.utf8Decode(bytes[index .. index + width]) catch unreachable;
+
+        if (isCased(scalar)) {
+            return true;
+        } else if (!isCaseIgnorable(scalar)) {
+            return false;
+        }
+
+        index += width;
+    }
+
+    return false;
+}
+
+fn isCased(codepoint: u21) bool {
+    return inRanges(&cased_ranges, codepoint);
+}
+
+fn isCaseIgnorable(codepoint: u21) bool {
+    return inRanges(&case_ignorable_ranges, codepoint);
+}
+
+const Range = struct {
+    first: u21,
+    last: u21,
+};
+
+fn inRanges(ranges: []const Range, codepoint: u21) bool {
+    var low: usize = 0;
+    var high: usize = ranges.len;
+
+    while (low < high) {
+        const mid = low + (high - low) / 2;
+
+        if (codepoint < ranges[mid].first) {
+            high = mid;
+        } else if (codepoint > ranges[mid].last) {
+            low = mid + 1;
+        } else {
+            return true;
+        }
+    }
+
+    return false;
+}
+
+test "isFinalSigma" {
+    // "ΑΣ"
+    try expect(isFinalSigma("\u{391}\u{3A3}", 2, 2));
+    // "ΑΣ Α"
+    try expect(isFinalSigma("\u{391}\u{3A3} \u{391}", 2, 2));
+    // "ΑΣ'" and "Α'Σ", since an apostrophe is case-ignorable
+    try expect(isFinalSigma("\u{391}\u{3A3}'", 2, 2));
+    try expect(isFinalSigma("\u{391}'\u{3A3}", 3, 2));
+    // "ΣΑ" and "ΑΣΑ"
+    try expect(!isFinalSigma("\u{3A3}\u{391}", 0, 2));
+    try expect(!isFinalSigma("\u{391}\u{3A3}\u{391}", 2, 2));
+    // "Σ" and "1Σ"
+    try expect(!isFinalSigma("\u{3A3}", 0, 2));
+    try expect(!isFinalSigma("1\u{3A3}", 1, 2));
+}
+
+// Everything below this line is generated by gen-unicode-tables.py. Do not edit it by hand!
+
+// https://www.unicode.org/Public/13.0.0/ucd/UnicodeData.txt
+
+const combining_classes: [6857]u8 = [_]u8{
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230,
+    230, 230, 230, 232, 220, 216, 202, 220, 220, 220, 220, 202, 202, 220, 220, 220, 220, 1,   1,
+    230, 230, 230, 230, 230, 240, 0,   233, 234, 0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   222, 228, 10,  11,  12,  13,  14,  15,  16,  17,  18,  19,  20,  21,  22,
+    0,   23,  0,   24,  25,  0,   0,   0,   0,   0,   30,  31,  32,  0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   27,  28,  29,  33,  34,  230, 230, 220, 0,   0,   35,  0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   36,  0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   7,   9,   0,   0,   0,   0,   0,   0,   0,   0,   0,   7,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   84,  91,  0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   9,   0,   0,   0,   0,   0,
+    0,   0,   0,   103, 107, 0,   118, 122, 0,   0,   0,   216, 0,   0,   0,   0,   0,   0,   129,
+    130, 0,   132, 0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    9,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   214, 0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   218, 224, 0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   8,
+    8,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   26,  0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   7,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   9,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   6,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   216, 216, 226, 216, 216, 216, 216, 216, 0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+    0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,   0,
+};
+
+const uppercase_offsets: [6857]u16 = [_]u16{
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    1,    3,    5,    7,    9,    11,   13,   15,   17,   19,   21,   23,   25,   27,   29,
+    31,   33,   35,   37,   39,   41,   43,   45,   47,   49,   51,   0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    53,   0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    55,
+    58,   60,   62,   64,   66,   68,   70,   72,   74,   76,   78,   80,   82,   84,   86,   88,
+    90,   92,   94,   96,   98,   100,  102,  104,  106,  108,  110,  112,  114,  116,  118,  0,
+    120,  0,    122,  0,    124,  0,    126,  0,    128,  0,    130,  0,    132,  0,    134,  0,
+    136,  0,    138,  0,    140,  0,    142,  0,    144,  0,    146,  0,    148,  0,    150,  0,
+    152,  0,    154,  0,    156,  0,    158,  0,    160,  0,    162,  0,    164,  0,    166,  0,
+    17,   0,    168,  0,    170,  0,    172,  0,    0,    174,  0,    176,  0,    178,  0,    180,
+    0,    182,  0,    184,  0,    186,  0,    188,  190,  0,    193,  0,    195,  0,    197,  0,
+    199,  0,    201,  0,    203,  0,    205,  0,    207,  0,    209,  0,    211,  0,    213,  0,
+    215,  0,    217,  0,    219,  0,    221,  0,    223,  0,    225,  0,    227,  0,    229,  0,
+    231,  0,    233,  0,    235,  0,    237,  0,    0,    239,  0,    241,  0,    243,  37,   245,
+    0,    0,    247,  0,    249,  0,    0,    251,  0,    0,    0,    253,  0,    0,    0,    0,
+    255,  0,    0,    257,  0,    0,    0,    259,  261,  0,    0,    263,  0,    0,    265,  0,
+    267,  0,    269,  0,    0,    271,  0,    0,    273,  0,    0,    275,  0,    0,    0,    277,
+    0,    279,  0,    0,    281,  0,    0,    283,  285,  0,    287,  287,  0,    289,  289,  0,
+    291,  291,  0,    293,  0,    295,  0,    297,  0,    299,  0,    301,  0,    303,  0,    305,
+    0,    307,  309,  0,    311,  0,    313,  0,    315,  0,    317,  0,    319,  0,    321,  0,
+    323,  0,    325,  0,    327,  329,  0,    332,  332,  0,    334,  0,    0,    0,    336,  0,
+    338,  0,    340,  0,    342,  0,    344,  0,    346,  0,    348,  0,    350,  0,    352,  0,
+    354,  0,    356,  0,    358,  0,    360,  0,    362,  0,    364,  0,    366,  0,    368,  0,
+    370,  0,    372,  0,    374,  0,    0,    376,  0,    378,  0,    380,  0,    382,  0,    384,
+    0,    386,  0,    388,  0,    390,  0,    392,  0,    0,    394,  0,    0,    396,  398,  0,
+    400,  0,    0,    0,    0,    402,  0,    404,  0,    406,  0,    408,  0,    410,  412,  414,
+    416,  418,  420,  422,  424,  426,  428,  430,  432,  434,  436,  438,  440,  442,  444,  446,
+    448,  450,  452,  454,  456,  458,  460,  462,  464,  466,  468,  470,  472,  474,  476,  478,
+    480,  482,  484,  0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    486,
+    0,    0,    0,    0,    488,  0,    490,  0,    0,    492,  0,    494,  496,  498,  0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    500,  0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    504,  506,  508,  510,  512,  516,  518,  520,  522,  524,  526,  528,
+    530,  486,  532,  534,  53,   536,  538,  540,  542,  544,  546,  546,  548,  550,  552,  554,
+    556,  558,  560,  562,  564,  566,  568,  0,    518,  530,  0,    0,    0,    552,  542,  570,
+    0,    572,  0,    574,  0,    576,  0,    578,  0,    580,  0,    582,  0,    584,  0,    586,
+    0,    588,  0,    590,  0,    592,  0,    594,  532,  544,  596,  598,  0,    524,  0,    600,
+    0,    0,    602,  0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    604,  606,  608,  610,  612,  614,  616,  618,  620,  622,
+    624,  626,  628,  630,  632,  634,  636,  638,  640,  642,  644,  646,  648,  650,  652,  654,
+    656,  658,  660,  662,  664,  666,  668,  670,  672,  674,  676,  678,  680,  682,  684,  686,
+    688,  690,  692,  694,  696,  698,  0,    700,  0,    702,  0,    704,  0,    706,  0,    708,
+    0,    710,  0,    712,  0,    714,  0,    716,  0,    718,  0,    720,  0,    722,  0,    724,
+    0,    726,  0,    728,  0,    730,  0,    732,  0,    0,    0,    734,  0,    736,  0,    738,
+    0,    740,  0,    742,  0,    744,  0,    746,  0,    748,  0,    750,  0,    752,  0,    754,
+    0,    756,  0,    758,  0,    760,  0,    762,  0,    764,  0,    766,  0,    768,  0,    770,
+    0,    772,  0,    774,  0,    776,  0,    778,  0,    780,  0,    782,  0,    784,  0,    786,
+    0,    0,    788,  0,    790,  0,    792,  0,    794,  0,    796,  0,    798,  0,    800,  802,
+    0,    804,  0,    806,  0,    808,  0,    810,  0,    812,  0,    814,  0,    816,  0,    818,
+    0,    820,  0,    822,  0,    824,  0,    826,  0,    828,  0,    830,  0,    832,  0,    834,
+    0,    836,  0,    838,  0,    840,  0,    842,  0,    844,  0,    846,  0,    848,  0,    850,
+    0,    852,  0,    854,  0,    856,  0,    858,  0,    860,  0,    862,  0,    864,  0,    866,
+    0,    868,  0,    870,  0,    872,  0,    874,  0,    876,  0,    878,  0,    880,  0,    882,
+    0,    884,  0,    886,  0,    888,  0,    890,  0,    892,  0,    894,  0,    896,  0,    898,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    900,  902,  904,  906,  908,  910,  912,  914,  916,
+    918,  920,  922,  924,  926,  928,  930,  932,  934,  936,  938,  940,  942,  944,  946,  948,
+    950,  952,  954,  956,  958,  960,  962,  964,  966,  968,  970,  972,  974,  976,  0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    979,  981,  983,  985,  987,  989,  991,  993,  995,  997,  999,  1001, 1003, 1005, 1007,
+    1009, 1011, 1013, 1015, 1017, 1019, 1021, 1023, 1025, 1027, 1029, 1031, 1033, 1035, 1037, 1039,
+    1041, 1043, 1045, 1047, 1049, 1051, 1053, 1055, 1057, 1059, 1061, 1063, 0,    1065, 1067, 1069,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    1071, 1073, 1075, 1077, 1079,
+    1081, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    608,  612,  632,  638,  640,
+    656,  702,  1083, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    1085, 1087,
+    1089, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    1091, 0,    1093, 0,    1095, 0,    1097, 0,    1099,
+    0,    1101, 0,    1103, 0,    1105, 0,    1107, 0,    1109, 0,    1111, 0,    1113, 0,    1115,
+    0,    1117, 0,    1119, 0,    1121, 0,    1123, 0,    1125, 0,    1127, 0,    1129, 0,    1131,
+    0,    1133, 0,    1135, 0,    1137, 0,    1139, 0,    1141, 0,    1143, 0,    1145, 0,    1147,
+    0,    1149, 0,    1151, 0,    1153, 0,    1155, 0,    1157, 0,    1159, 0,    1161, 0,    1163,
+    0,    1165, 0,    1167, 0,    1169, 0,    1171, 0,    1173, 0,    1175, 0,    1177, 0,    1179,
+    0,    1181, 0,    1183, 0,    1185, 0,    1187, 0,    1189, 0,    1191, 0,    1193, 0,    1195,
+    0,    1197, 0,    1199, 0,    1201, 0,    1203, 0,    1205, 0,    1207, 0,    1209, 0,    1211,
+    0,    1213, 0,    1215, 0,    1217, 0,    1219, 0,    1221, 0,    1223, 0,    1225, 0,    1227,
+    0,    1229, 0,    1231, 0,    1233, 0,    1235, 0,    1237, 0,    1239, 1241, 1244, 1247, 1250,
+    1253, 1187, 0,    0,    1256, 0,    1258, 0,    1260, 0,    1262, 0,    1264, 0,    1266, 0,
+    1268, 0,    1270, 0,    1272, 0,    1274, 0,    1276, 0,    1278, 0,    1280, 0,    1282, 0,
+    1284, 0,    1286, 0,    1288, 0,    1290, 0,    1292, 0,    1294, 0,    1296, 0,    1298, 0,
+    1300, 0,    1302, 0,    1304, 0,    1306, 0,    1308, 0,    1310, 0,    1312, 0,    1314, 0,
+    1316, 0,    1318, 0,    1320, 0,    1322, 0,    1324, 0,    1326, 0,    1328, 0,    1330, 0,
+    1332, 0,    1334, 0,    1336, 0,    1338, 0,    1340, 0,    1342, 0,    1344, 0,    1346, 0,
+    1348, 0,    1350, 1352, 1354, 1356, 1358, 1360, 1362, 1364, 1366, 0,    0,    0,    0,    0,
+    0,    0,    0,    1368, 1370, 1372, 1374, 1376, 1378, 0,    0,    0,    0,    0,    0,    1380,
+    1382, 1384, 1386, 1388, 1390, 1392, 1394, 0,    0,    0,    0,    0,    0,    0,    0,    1396,
+    1398, 1400, 1402, 1404, 1406, 1408, 1410, 0,    0,    0,    0,    0,    0,    0,    0,    1412,
+    1414, 1416, 1418, 1420, 1422, 0,    0,    0,    0,    0,    0,    1424, 1427, 1429, 1433, 1435,
+    1439, 1441, 1445, 0,    0,    0,    0,    1447, 1449, 1451, 1453, 1455, 1457, 1459, 1461, 0,
+    0,    0,    0,    0,    0,    0,    0,    1463, 1465, 1467, 1469, 1471, 1473, 1475, 1477, 1479,
+    1481, 1483, 1485, 1487, 1489, 1491, 1494, 1497, 1500, 1503, 1506, 1509, 1512, 1491, 1494, 1497,
+    1500, 1503, 1506, 1509, 1512, 1515, 1518, 1521, 1524, 1527, 1530, 1533, 1536, 1515, 1518, 1521,
+    1524, 1527, 1530, 1533, 1536, 1539, 1542, 1545, 1548, 1551, 1554, 1557, 1560, 1539, 1542, 1545,
+    1548, 1551, 1554, 1557, 1560, 1563, 1565, 1567, 1570, 1573, 1576, 1579, 0,    0,    0,    0,
+    1570, 0,    486,  0,    0,    0,    1583, 1586, 1589, 1592, 1595, 0,    0,    0,    0,    1586,
+    0,    0,    0,    1599, 1601, 1603, 500,  1607, 1610, 0,    0,    0,    0,    0,    0,    0,
+    1614, 1616, 1618, 512,  1622, 1625, 1627, 1630, 0,    0,    0,    0,    0,    0,    0,    0,
+    1634, 1637, 1640, 1643, 1646, 0,    0,    0,    0,    1637, 0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    1650, 0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    1652,
+    1654, 1656, 1658, 1660, 1662, 1664, 1666, 1668, 1670, 1672, 1674, 1676, 1678, 1680, 1682, 0,
+    1684, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    1686,
+    1688, 1690, 1692, 1694, 1696, 1698, 1700, 1702, 1704, 1706, 1708, 1710, 1712, 1714, 1716, 1718,
+    1720, 1722, 1724, 1726, 1728, 1730, 1732, 1734, 1736, 0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    1738, 1740, 1742, 1744, 1746, 1748, 1750, 1752, 1754, 1756, 1758, 1760, 1762,
+    1764, 1766, 1768, 1770, 1772, 1774, 1776, 1778, 1780, 1782, 1784, 1786, 1788, 1790, 1792, 1794,
+    1796, 1798, 1800, 1802, 1804, 1806, 1808, 1810, 1812, 1814, 1816, 1818, 1820, 1822, 1824, 1826,
+    1828, 1830, 0,    1832, 0,    0,    0,    1834, 1836, 0,    1838, 0,    1840, 0,    1842, 0,
+    0,    0,    0,    0,    1844, 0,    1846, 0,    0,    0,    0,    0,    1848, 0,    1850, 0,
+    1852, 0,    1854, 0,    1856, 0,    1858, 0,    1860, 0,    1862, 0,    1864, 0,    1866, 0,
+    1868, 0,    1870, 0,    1872, 0,    1874, 0,    1876, 0,    1878, 0,    1880, 0,    1882, 0,
+    1884, 0,    1886, 0,    1888, 0,    1890, 0,    1892, 0,    1894, 0,    1896, 0,    1898, 0,
+    1900, 0,    1902, 0,    1904, 0,    1906, 0,    1908, 0,    1910, 0,    1912, 0,    1914, 0,
+    1916, 0,    1918, 0,    1920, 0,    1922, 0,    1924, 0,    1926, 0,    1928, 0,    1930, 0,
+    1932, 0,    1934, 0,    1936, 0,    1938, 0,    1940, 0,    1942, 0,    1944, 0,    1946, 0,
+    1948, 0,    1950, 0,    1952, 1954, 1956, 1958, 1960, 1962, 1964, 1966, 1968, 1970, 1972, 1974,
+    1976, 1978, 1980, 1982, 1984, 1986, 1988, 1990, 1992, 1994, 1996, 1998, 2000, 2002, 2004, 2006,
+    2008, 2010, 2012, 2014, 2016, 2018, 2020, 2022, 2024, 2026, 2028, 2030, 2032, 0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    2034, 0,    2036,
+    0,    2038, 0,    2040, 0,    2042, 0,    1083, 0,    2044, 0,    2046, 0,    2048, 0,    2050,
+    0,    2052, 0,    2054, 0,    2056, 0,    2058, 0,    2060, 0,    2062, 0,    2064, 0,    2066,
+    0,    2068, 0,    2070, 0,    2072, 0,    2074, 0,    2076, 0,    2078, 0,    2080, 0,    2082,
+    0,    2084, 0,    2086, 0,    2088, 0,    2090, 0,    2092, 0,    2094, 0,    2096, 0,    2098,
+    0,    2100, 0,    2102, 0,    2104, 0,    0,    0,    2106, 0,    2108, 0,    2110, 0,    2112,
+    0,    2114, 0,    2116, 0,    2118, 0,    2120, 0,    2122, 0,    2124, 0,    2126, 0,    2128,
+    0,    2130, 0,    2132, 0,    2134, 0,    2136, 0,    2138, 0,    2140, 0,    2142, 0,    2144,
+    0,    2146, 0,    2148, 0,    2150, 0,    2152, 0,    2154, 0,    2156, 0,    2158, 0,    2160,
+    0,    2162, 0,    2164, 0,    2166, 0,    2168, 0,    2170, 0,    2172, 0,    2174, 0,    2176,
+    0,    2178, 0,    2180, 0,    0,    2182, 0,    2184, 0,    0,    2186, 0,    2188, 0,    2190,
+    0,    2192, 0,    2194, 0,    0,    2196, 0,    0,    2198, 0,    2200, 2202, 0,    2204, 0,
+    2206, 0,    2208, 0,    2210, 0,    2212, 0,    2214, 0,    2216, 0,    2218, 0,    2220, 0,
+    2222, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    2224, 0,    2226, 0,    2228,
+    0,    2230, 0,    2232, 0,    2234, 0,    2236, 0,    0,    0,    0,    2238, 0,    2240, 0,
+    2242, 0,    0,    2244, 0,    0,    0,    0,    0,    2246, 2248, 2250, 2252, 2254, 2256, 2258,
+    2260, 2262, 2264, 2266, 2268, 2270, 2272, 2274, 2276, 2278, 2280, 2282, 2284, 2286, 2288, 2290,
+    2292, 2294, 2296, 2298, 2300, 2302, 2304, 2306, 2308, 2310, 2312, 2314, 2316, 2318, 2320, 2322,
+    2324, 2326, 2328, 2330, 2332, 2334, 2336, 2338, 2340, 2342, 2344, 2346, 2348, 2350, 2352, 2354,
+    2356, 2358, 2360, 2362, 2364, 2366, 2368, 2370, 2372, 2374, 2376, 2378, 2380, 2382, 2384, 2386,
+    2388, 2390, 2392, 2394, 2396, 2398, 2400, 2402, 2404, 0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    2406, 2409, 2412, 2415, 2419, 2423, 2423, 2426, 2429, 2432,
+    2435, 2438, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    2441, 2443, 2445, 2447, 2449, 2451, 2453, 2455, 2457,
+    2459, 2461, 2463, 2465, 2467, 2469, 2471, 2473, 2475, 2477, 2479, 2481, 2483, 2485, 2487, 2489,
+    2491, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    2493, 2495, 2497, 2499, 2501, 2503, 2505, 2507, 2509, 2511, 2513, 2515, 2517,
+    2519, 2521, 2523, 2525, 2527, 2529, 2531, 2533, 2535, 2537, 2539, 2541, 2543, 2545, 2547, 2549,
+    2551, 2553, 2555, 2557, 2559, 2561, 2563, 2565, 2567, 2569, 2571, 0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    2573,
+    2575, 2577, 2579, 2581, 2583, 2585, 2587, 2589, 2591, 2593, 2595, 2597, 2599, 2601, 2603, 2605,
+    2607, 2609, 2611, 2613, 2615, 2617, 2619, 2621, 2623, 2625, 2627, 2629, 2631, 2633, 2635, 2637,
+    2639, 2641, 2643, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    2645, 2647, 2649, 2651, 2653, 2655, 2657, 2659,
+    2661, 2663, 2665, 2667, 2669, 2671, 2673, 2675, 2677, 2679, 2681, 2683, 2685, 2687, 2689, 2691,
+    2693, 2695, 2697, 2699, 2701, 2703, 2705, 2707, 2709, 2711, 2713, 2715, 2717, 2719, 2721, 2723,
+    2725, 2727, 2729, 2731, 2733, 2735, 2737, 2739, 2741, 2743, 2745, 0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    2747, 2749, 2751, 2753, 2755,
+    2757, 2759, 2761, 2763, 2765, 2767, 2769, 2771, 2773, 2775, 2777, 2779, 2781, 2783, 2785, 2787,
+    2789, 2791, 2793, 2795, 2797, 2799, 2801, 2803, 2805, 2807, 2809, 0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    2811, 2813, 2815, 2817, 2819, 2821, 2823, 2825, 2827, 2829, 2831, 2833, 2835, 2837, 2839,
+    2841, 2843, 2845, 2847, 2849, 2851, 2853, 2855, 2857, 2859, 2861, 2863, 2865, 2867, 2869, 2871,
+    2873, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    2875,
+    2877, 2879, 2881, 2883, 2885, 2887, 2889, 2891, 2893, 2895, 2897, 2899, 2901, 2903, 2905, 2907,
+    2909, 2911, 2913, 2915, 2917, 2919, 2921, 2923, 2925, 2927, 2929, 2931, 2933, 2935, 2937, 2939,
+    2941, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,
+};
+
+const lowercase_offsets: [6857]u16 = [_]u16{
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    2943, 2945, 2947, 2949, 2951, 2953, 2955, 2957, 2959, 2961, 2963,
+    2965, 2967, 2969, 2971, 2973, 2975, 2977, 2979, 2981, 2983, 2985, 2987, 2989, 2991, 2993, 0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    2995, 2997, 2999, 3001, 3003, 3005, 3007, 3009, 3011, 3013, 3015, 3017, 3019, 3021, 3023,
+    3025, 3027, 3029, 3031, 3033, 3035, 3037, 3039, 3041, 3043, 3045, 3047, 3049, 3051, 3053, 0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    3055,
+    0,    3057, 0,    3059, 0,    3061, 0,    3063, 0,    3065, 0,    3067, 0,    3069, 0,    3071,
+    0,    3073, 0,    3075, 0,    3077, 0,    3079, 0,    3081, 0,    3083, 0,    3085, 0,    3087,
+    0,    3089, 0,    3091, 0,    3093, 0,    3095, 0,    3097, 0,    3099, 0,    3101, 0,    3103,
+    0,    3106, 0,    3108, 0,    3110, 0,    0,    3112, 0,    3114, 0,    3116, 0,    3118, 0,
+    3120, 0,    3122, 0,    3124, 0,    3126, 0,    0,    3128, 0,    3130, 0,    3132, 0,    3134,
+    0,    3136, 0,    3138, 0,    3140, 0,    3142, 0,    3144, 0,    3146, 0,    3148, 0,    3150,
+    0,    3152, 0,    3154, 0,    3156, 0,    3158, 0,    3160, 0,    3162, 0,    3164, 0,    3166,
+    0,    3168, 0,    3170, 0,    3172, 0,    3174, 3176, 0,    3178, 0,    3180, 0,    0,    0,
+    3182, 3184, 0,    3186, 0,    3188, 3190, 0,    3192, 3194, 3196, 0,    3198, 3200, 3202, 3204,
+    0,    3206, 3208, 0,    3210, 3212, 3214, 0,    0,    3216, 3218, 0,    3220, 3222, 0,    3224,
+    0,    3226, 0,    3228, 3230, 0,    3232, 3234, 0,    3236, 3238, 0,    3240, 3242, 3244, 0,
+    3246, 0,    3248, 3250, 0,    0,    3252, 0,    0,    3254, 3254, 0,    3256, 3256, 0,    3258,
+    3258, 0,    3260, 0,    3262, 0,    3264, 0,    3266, 0,    3268, 0,    3270, 0,    3272, 0,
+    3274, 0,    0,    3276, 0,    3278, 0,    3280, 0,    3282, 0,    3284, 0,    3286, 0,    3288,
+    0,    3290, 0,    3292, 0,    0,    3294, 3294, 0,    3296, 0,    3298, 3300, 3302, 0,    3304,
+    0,    3306, 0,    3308, 0,    3310, 0,    3312, 0,    3314, 0,    3316, 0,    3318, 0,    3320,
+    0,    3322, 0,    3324, 0,    3326, 0,    3328, 0,    3330, 0,    3332, 0,    3334, 0,    3336,
+    0,    3338, 0,    3340, 0,    3342, 3344, 0,    3346, 0,    3348, 0,    3350, 0,    3352, 0,
+    3354, 0,    3356, 0,    3358, 0,    3360, 0,    3362, 3364, 0,    3366, 3368, 0,    0,    3370,
+    0,    3372, 3374, 3376, 3378, 0,    3380, 0,    3382, 0,    3384, 0,    3386, 0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    3388, 0,    3390, 0,    0,    3392, 0,    0,    0,    0,    0,    0,    3394,
+    0,    3396, 0,    3398, 3400, 3402, 3404, 3406, 3408, 0,    3410, 3412, 3414, 3416, 3418, 3420,
+    3422, 3424, 3426, 3428, 3430, 3432, 3434, 3436, 3438, 3440, 3442, 3444, 3446, 3448, 3450, 3452,
+    3454, 3456, 3458, 3460, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    3462, 0,    0,    0,    0,    0,    0,    0,    0,
+    3464, 0,    3466, 0,    3468, 0,    3470, 0,    3472, 0,    3474, 0,    3476, 0,    3478, 0,
+    3480, 0,    3482, 0,    3484, 0,    3486, 0,    0,    0,    0,    0,    3424, 0,    3488, 0,
+    3490, 3492, 0,    3494, 3496, 3498, 3500, 3502, 3504, 3506, 3508, 3510, 3512, 3514, 3516, 3518,
+    3520, 3522, 3524, 3526, 3528, 3530, 3532, 3534, 3536, 3538, 3540, 3542, 3544, 3546, 3548, 3550,
+    3552, 3554, 3556, 3558, 3560, 3562, 3564, 3566, 3568, 3570, 3572, 3574, 3576, 3578, 3580, 3582,
+    3584, 3586, 3588, 3590, 3592, 3594, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    3596, 0,    3598, 0,    3600, 0,    3602, 0,    3604, 0,
+    3606, 0,    3608, 0,    3610, 0,    3612, 0,    3614, 0,    3616, 0,    3618, 0,    3620, 0,
+    3622, 0,    3624, 0,    3626, 0,    3628, 0,    0,    0,    3630, 0,    3632, 0,    3634, 0,
+    3636, 0,    3638, 0,    3640, 0,    3642, 0,    3644, 0,    3646, 0,    3648, 0,    3650, 0,
+    3652, 0,    3654, 0,    3656, 0,    3658, 0,    3660, 0,    3662, 0,    3664, 0,    3666, 0,
+    3668, 0,    3670, 0,    3672, 0,    3674, 0,    3676, 0,    3678, 0,    3680, 0,    3682, 0,
+    3684, 3686, 0,    3688, 0,    3690, 0,    3692, 0,    3694, 0,    3696, 0,    3698, 0,    0,
+    3700, 0,    3702, 0,    3704, 0,    3706, 0,    3708, 0,    3710, 0,    3712, 0,    3714, 0,
+    3716, 0,    3718, 0,    3720, 0,    3722, 0,    3724, 0,    3726, 0,    3728, 0,    3730, 0,
+    3732, 0,    3734, 0,    3736, 0,    3738, 0,    3740, 0,    3742, 0,    3744, 0,    3746, 0,
+    3748, 0,    3750, 0,    3752, 0,    3754, 0,    3756, 0,    3758, 0,    3760, 0,    3762, 0,
+    3764, 0,    3766, 0,    3768, 0,    3770, 0,    3772, 0,    3774, 0,    3776, 0,    3778, 0,
+    3780, 0,    3782, 0,    3784, 0,    3786, 0,    3788, 0,    3790, 0,    3792, 0,    3794, 0,
+    3796, 3798, 3800, 3802, 3804, 3806, 3808, 3810, 3812, 3814, 3816, 3818, 3820, 3822, 3824, 3826,
+    3828, 3830, 3832, 3834, 3836, 3838, 3840, 3842, 3844, 3846, 3848, 3850, 3852, 3854, 3856, 3858,
+    3860, 3862, 3864, 3866, 3868, 3870, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    3872, 3874, 3876, 3878, 3880, 3882, 3884,
+    3886, 3888, 3890, 3892, 3894, 3896, 3898, 3900, 3902, 3904, 3906, 3908, 3910, 3912, 3914, 3916,
+    3918, 3920, 3922, 3924, 3926, 3928, 3930, 3932, 3934, 3936, 3938, 3940, 3942, 3944, 3946, 3948,
+    3950, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    3952, 3954, 3956, 3958, 3960, 3962, 3964, 3966, 3968, 3970, 3972,
+    3974, 3976, 3978, 3980, 3982, 3984, 3986, 3988, 3990, 3992, 3994, 3996, 3998, 4000, 4002, 4004,
+    4006, 4008, 4010, 4012, 4014, 4016, 4018, 4020, 4022, 4024, 4026, 4028, 4030, 4032, 4034, 4036,
+    4038, 4040, 4042, 4044, 4046, 4048, 4050, 4052, 4054, 4056, 4058, 4060, 4062, 4064, 4066, 4068,
+    4070, 4072, 4074, 4076, 4078, 4080, 4082, 4084, 4086, 4088, 4090, 4092, 4094, 4096, 4098, 4100,
+    4102, 4104, 4106, 4108, 4110, 4112, 4114, 4116, 4118, 4120, 4122, 0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    4124, 4126, 4128, 4130, 4132, 4134, 4136, 4138, 4140, 4142, 4144, 4146, 4148,
+    4150, 4152, 4154, 4156, 4158, 4160, 4162, 4164, 4166, 4168, 4170, 4172, 4174, 4176, 4178, 4180,
+    4182, 4184, 4186, 4188, 4190, 4192, 4194, 4196, 4198, 4200, 4202, 4204, 4206, 4208, 4210, 4212,
+    4214, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    4216, 0,    4218, 0,    4220, 0,    4222, 0,    4224, 0,
+    4226, 0,    4228, 0,    4230, 0,    4232, 0,    4234, 0,    4236, 0,    4238, 0,    4240, 0,
+    4242, 0,    4244, 0,    4246, 0,    4248, 0,    4250, 0,    4252, 0,    4254, 0,    4256, 0,
+    4258, 0,    4260, 0,    4262, 0,    4264, 0,    4266, 0,    4268, 0,    4270, 0,    4272, 0,
+    4274, 0,    4276, 0,    4278, 0,    4280, 0,    4282, 0,    4284, 0,    4286, 0,    4288, 0,
+    4290, 0,    4292, 0,    4294, 0,    4296, 0,    4298, 0,    4300, 0,    4302, 0,    4304, 0,
+    4306, 0,    4308, 0,    4310, 0,    4312, 0,    4314, 0,    4316, 0,    4318, 0,    4320, 0,
+    4322, 0,    4324, 0,    4326, 0,    4328, 0,    4330, 0,    4332, 0,    4334, 0,    4336, 0,
+    4338, 0,    4340, 0,    4342, 0,    4344, 0,    4346, 0,    4348, 0,    4350, 0,    4352, 0,
+    4354, 0,    4356, 0,    4358, 0,    4360, 0,    4362, 0,    4364, 0,    0,    0,    0,    0,
+    0,    0,    4366, 4368, 0,    4370, 0,    4372, 0,    4374, 0,    4376, 0,    4378, 0,    4380,
+    0,    4382, 0,    4384, 0,    4386, 0,    4388, 0,    4390, 0,    4392, 0,    4394, 0,    4396,
+    0,    4398, 0,    4400, 0,    4402, 0,    4404, 0,    4406, 0,    4408, 0,    4410, 0,    4412,
+    0,    4414, 0,    4416, 0,    4418, 0,    4420, 0,    4422, 0,    4424, 0,    4426, 0,    4428,
+    0,    4430, 0,    4432, 0,    4434, 0,    4436, 0,    4438, 0,    4440, 0,    4442, 0,    4444,
+    0,    4446, 0,    4448, 0,    4450, 0,    4452, 0,    4454, 0,    4456, 0,    4458, 0,    4460,
+    0,    4462, 0,    0,    0,    0,    0,    0,    0,    0,    0,    4464, 4466, 4468, 4470, 4472,
+    4474, 4476, 4478, 0,    0,    0,    0,    0,    0,    4480, 4482, 4484, 4486, 4488, 4490, 0,
+    0,    0,    0,    0,    0,    0,    0,    4492, 4494, 4496, 4498, 4500, 4502, 4504, 4506, 0,
+    0,    0,    0,    0,    0,    0,    0,    4508, 4510, 4512, 4514, 4516, 4518, 4520, 4522, 0,
+    0,    0,    0,    0,    0,    4524, 4526, 4528, 4530, 4532, 4534, 0,    0,    0,    0,    0,
+    0,    0,    0,    4536, 4538, 4540, 4542, 0,    0,    0,    0,    0,    0,    0,    0,    4544,
+    4546, 4548, 4550, 4552, 4554, 4556, 4558, 0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    4560, 4562, 4564,
+    4566, 4568, 4570, 4572, 4574, 0,    0,    0,    0,    0,    0,    0,    0,    4576, 4578, 4580,
+    4582, 4584, 4586, 4588, 4590, 0,    0,    0,    0,    0,    0,    0,    0,    4592, 4594, 4596,
+    4598, 4600, 4602, 4604, 4606, 0,    0,    0,    0,    0,    0,    0,    4608, 4610, 4612, 4614,
+    4616, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    4618, 4620, 4622, 4624, 4626,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    4628, 4630, 4632, 4634, 0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    4636, 4638, 4640, 4642, 4644, 0,    0,    0,
+    0,    0,    0,    0,    0,    4646, 4648, 4650, 4652, 4654, 0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    3456, 2963, 3005, 0,    0,    0,    0,    4656, 0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    4658,
+    4660, 4662, 4664, 4666, 4668, 4670, 4672, 4674, 4676, 4678, 4680, 4682, 4684, 4686, 4688, 0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    4690,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    4692, 4694, 4696, 4698, 4700, 4702, 4704, 4706, 4708, 4710, 4712,
+    4714, 4716, 4718, 4720, 4722, 4724, 4726, 4728, 4730, 4732, 4734, 4736, 4738, 4740, 4742, 0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    4744, 4746, 4748, 4750, 4752, 4754, 4756, 4758, 4760, 4762, 4764, 4766,
+    4768, 4770, 4772, 4774, 4776, 4778, 4780, 4782, 4784, 4786, 4788, 4790, 4792, 4794, 4796, 4798,
+    4800, 4802, 4804, 4806, 4808, 4810, 4812, 4814, 4816, 4818, 4820, 4822, 4824, 4826, 4828, 4830,
+    4832, 4834, 4836, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    4838, 0,    4840, 4842, 4844, 0,    0,    4846, 0,    4848, 0,    4850, 0,    4852,
+    4854, 4856, 4858, 4860, 0,    4862, 0,    0,    0,    4864, 4866, 4868, 0,    4870, 0,    4872,
+    0,    4874, 0,    4876, 0,    4878, 0,    4880, 0,    4882, 0,    4884, 0,    4886, 0,    4888,
+    0,    4890, 0,    4892, 0,    4894, 0,    4896, 0,    4898, 0,    4900, 0,    4902, 0,    4904,
+    0,    4906, 0,    4908, 0,    4910, 0,    4912, 0,    4914, 0,    4916, 0,    4918, 0,    4920,
+    0,    4922, 0,    4924, 0,    4926, 0,    4928, 0,    4930, 0,    4932, 0,    4934, 0,    4936,
+    0,    4938, 0,    4940, 0,    4942, 0,    4944, 0,    4946, 0,    4948, 0,    4950, 0,    4952,
+    0,    4954, 0,    4956, 0,    4958, 0,    4960, 0,    4962, 0,    4964, 0,    4966, 0,    4968,
+    0,    4970, 0,    4972, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    4974, 0,    4976, 0,
+    4978, 0,    4980, 0,    4982, 0,    4984, 0,    4986, 0,    4988, 0,    4990, 0,    4992, 0,
+    4994, 0,    4996, 0,    4998, 0,    5000, 0,    5002, 0,    5004, 0,    5006, 0,    5008, 0,
+    5010, 0,    5012, 0,    5014, 0,    5016, 0,    5018, 0,    5020, 0,    5022, 0,    5024, 0,
+    5026, 0,    5028, 0,    5030, 0,    5032, 0,    5034, 0,    5036, 0,    5038, 0,    5040, 0,
+    5042, 0,    5044, 0,    5046, 0,    0,    0,    5048, 0,    5050, 0,    5052, 0,    5054, 0,
+    5056, 0,    5058, 0,    5060, 0,    5062, 0,    5064, 0,    5066, 0,    5068, 0,    5070, 0,
+    5072, 0,    5074, 0,    5076, 0,    5078, 0,    5080, 0,    5082, 0,    5084, 0,    5086, 0,
+    5088, 0,    5090, 0,    5092, 0,    5094, 0,    5096, 0,    5098, 0,    5100, 0,    5102, 0,
+    5104, 0,    5106, 0,    5108, 0,    5110, 0,    5112, 0,    5114, 0,    5116, 0,    5118, 0,
+    5120, 0,    5122, 0,    0,    5124, 0,    5126, 0,    5128, 5130, 0,    5132, 0,    5134, 0,
+    5136, 0,    5138, 0,    0,    5140, 0,    5142, 5144, 0,    5146, 0,    0,    5148, 0,    5150,
+    0,    5152, 0,    5154, 0,    5156, 0,    5158, 0,    5160, 0,    5162, 0,    5164, 0,    5166,
+    0,    5168, 5170, 5172, 5174, 5176, 5178, 5180, 5182, 5184, 5186, 0,    5188, 0,    5190, 0,
+    5192, 0,    5194, 0,    5196, 0,    5198, 0,    5200, 5202, 5204, 5206, 0,    5208, 0,    5210,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    5212, 5214, 5216, 5218, 5220, 5222, 5224, 5226, 5228,
+    5230, 5232, 5234, 5236, 5238, 5240, 5242, 5244, 5246, 5248, 5250, 5252, 5254, 5256, 5258, 5260,
+    5262, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    5264, 5266, 5268, 5270, 5272,
+    5274, 5276, 5278, 5280, 5282, 5284, 5286, 5288, 5290, 5292, 5294, 5296, 5298, 5300, 5302, 5304,
+    5306, 5308, 5310, 5312, 5314, 5316, 5318, 5320, 5322, 5324, 5326, 5328, 5330, 5332, 5334, 5336,
+    5338, 5340, 5342, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    5344, 5346, 5348, 5350, 5352,
+    5354, 5356, 5358, 5360, 5362, 5364, 5366, 5368, 5370, 5372, 5374, 5376, 5378, 5380, 5382, 5384,
+    5386, 5388, 5390, 5392, 5394, 5396, 5398, 5400, 5402, 5404, 5406, 5408, 5410, 5412, 5414, 0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    5416, 5418, 5420, 5422, 5424, 5426, 5428, 5430, 5432, 5434, 5436,
+    5438, 5440, 5442, 5444, 5446, 5448, 5450, 5452, 5454, 5456, 5458, 5460, 5462, 5464, 5466, 5468,
+    5470, 5472, 5474, 5476, 5478, 5480, 5482, 5484, 5486, 5488, 5490, 5492, 5494, 5496, 5498, 5500,
+    5502, 5504, 5506, 5508, 5510, 5512, 5514, 5516, 0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    5518, 5520, 5522, 5524, 5526,
+    5528, 5530, 5532, 5534, 5536, 5538, 5540, 5542, 5544, 5546, 5548, 5550, 5552, 5554, 5556, 5558,
+    5560, 5562, 5564, 5566, 5568, 5570, 5572, 5574, 5576, 5578, 5580, 0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    5582, 5584, 5586, 5588, 5590, 5592, 5594, 5596, 5598, 5600, 5602, 5604, 5606, 5608, 5610,
+    5612, 5614, 5616, 5618, 5620, 5622, 5624, 5626, 5628, 5630, 5632, 5634, 5636, 5638, 5640, 5642,
+    5644, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    5646, 5648, 5650,
+    5652, 5654, 5656, 5658, 5660, 5662, 5664, 5666, 5668, 5670, 5672, 5674, 5676, 5678, 5680, 5682,
+    5684, 5686, 5688, 5690, 5692, 5694, 5696, 5698, 5700, 5702, 5704, 5706, 5708, 5710, 5712, 0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,
+};
+
+const case_fold_offsets: [6857]u16 = [_]u16{
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    2943, 2945, 2947, 2949, 2951, 2953, 2955, 2957, 2959, 2961, 2963,
+    2965, 2967, 2969, 2971, 2973, 2975, 2977, 2979, 2981, 2983, 2985, 2987, 2989, 2991, 2993, 0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    3432, 0,    0,    0,    0,    0,    0,
+    0,    2995, 2997, 2999, 3001, 3003, 3005, 3007, 3009, 3011, 3013, 3015, 3017, 3019, 3021, 3023,
+    3025, 3027, 3029, 3031, 3033, 3035, 3037, 3039, 3041, 3043, 3045, 3047, 3049, 3051, 3053, 5714,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    3055,
+    0,    3057, 0,    3059, 0,    3061, 0,    3063, 0,    3065, 0,    3067, 0,    3069, 0,    3071,
+    0,    3073, 0,    3075, 0,    3077, 0,    3079, 0,    3081, 0,    3083, 0,    3085, 0,    3087,
+    0,    3089, 0,    3091, 0,    3093, 0,    3095, 0,    3097, 0,    3099, 0,    3101, 0,    3103,
+    0,    3106, 0,    3108, 0,    3110, 0,    0,    3112, 0,    3114, 0,    3116, 0,    3118, 0,
+    3120, 0,    3122, 0,    3124, 0,    3126, 0,    5717, 3128, 0,    3130, 0,    3132, 0,    3134,
+    0,    3136, 0,    3138, 0,    3140, 0,    3142, 0,    3144, 0,    3146, 0,    3148, 0,    3150,
+    0,    3152, 0,    3154, 0,    3156, 0,    3158, 0,    3160, 0,    3162, 0,    3164, 0,    3166,
+    0,    3168, 0,    3170, 0,    3172, 0,    3174, 3176, 0,    3178, 0,    3180, 0,    2979, 0,
+    3182, 3184, 0,    3186, 0,    3188, 3190, 0,    3192, 3194, 3196, 0,    3198, 3200, 3202, 3204,
+    0,    3206, 3208, 0,    3210, 3212, 3214, 0,    0,    3216, 3218, 0,    3220, 3222, 0,    3224,
+    0,    3226, 0,    3228, 3230, 0,    3232, 3234, 0,    3236, 3238, 0,    3240, 3242, 3244, 0,
+    3246, 0,    3248, 3250, 0,    0,    3252, 0,    0,    3254, 3254, 0,    3256, 3256, 0,    3258,
+    3258, 0,    3260, 0,    3262, 0,    3264, 0,    3266, 0,    3268, 0,    3270, 0,    3272, 0,
+    3274, 0,    0,    3276, 0,    3278, 0,    3280, 0,    3282, 0,    3284, 0,    3286, 0,    3288,
+    0,    3290, 0,    3292, 0,    5720, 3294, 3294, 0,    3296, 0,    3298, 3300, 3302, 0,    3304,
+    0,    3306, 0,    3308, 0,    3310, 0,    3312, 0,    3314, 0,    3316, 0,    3318, 0,    3320,
+    0,    3322, 0,    3324, 0,    3326, 0,    3328, 0,    3330, 0,    3332, 0,    3334, 0,    3336,
+    0,    3338, 0,    3340, 0,    3342, 3344, 0,    3346, 0,    3348, 0,    3350, 0,    3352, 0,
+    3354, 0,    3356, 0,    3358, 0,    3360, 0,    3362, 3364, 0,    3366, 3368, 0,    0,    3370,
+    0,    3372, 3374, 3376, 3378, 0,    3380, 0,    3382, 0,    3384, 0,    3386, 0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    3426,
+    0,    0,    0,    3388, 0,    3390, 0,    0,    3392, 0,    0,    0,    0,    0,    0,    3394,
+    0,    3396, 0,    3398, 3400, 3402, 3404, 3406, 3408, 5723, 3410, 3412, 3414, 3416, 3418, 3420,
+    3422, 3424, 3426, 3428, 3430, 3432, 3434, 3436, 3438, 3440, 3442, 3444, 3446, 3448, 3450, 3452,
+    3454, 3456, 3458, 3460, 0,    0,    0,    0,    5727, 0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    3444, 0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    3462, 3412, 3424, 0,    0,    0,    3450, 3440, 0,
+    3464, 0,    3466, 0,    3468, 0,    3470, 0,    3472, 0,    3474, 0,    3476, 0,    3478, 0,
+    3480, 0,    3482, 0,    3484, 0,    3486, 0,    3428, 3442, 0,    0,    3424, 3418, 3488, 0,
+    3490, 3492, 0,    3494, 3496, 3498, 3500, 3502, 3504, 3506, 3508, 3510, 3512, 3514, 3516, 3518,
+    3520, 3522, 3524, 3526, 3528, 3530, 3532, 3534, 3536, 3538, 3540, 3542, 3544, 3546, 3548, 3550,
+    3552, 3554, 3556, 3558, 3560, 3562, 3564, 3566, 3568, 3570, 3572, 3574, 3576, 3578, 3580, 3582,
+    3584, 3586, 3588, 3590, 3592, 3594, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    3596, 0,    3598, 0,    3600, 0,    3602, 0,    3604, 0,
+    3606, 0,    3608, 0,    3610, 0,    3612, 0,    3614, 0,    3616, 0,    3618, 0,    3620, 0,
+    3622, 0,    3624, 0,    3626, 0,    3628, 0,    0,    0,    3630, 0,    3632, 0,    3634, 0,
+    3636, 0,    3638, 0,    3640, 0,    3642, 0,    3644, 0,    3646, 0,    3648, 0,    3650, 0,
+    3652, 0,    3654, 0,    3656, 0,    3658, 0,    3660, 0,    3662, 0,    3664, 0,    3666, 0,
+    3668, 0,    3670, 0,    3672, 0,    3674, 0,    3676, 0,    3678, 0,    3680, 0,    3682, 0,
+    3684, 3686, 0,    3688, 0,    3690, 0,    3692, 0,    3694, 0,    3696, 0,    3698, 0,    0,
+    3700, 0,    3702, 0,    3704, 0,    3706, 0,    3708, 0,    3710, 0,    3712, 0,    3714, 0,
+    3716, 0,    3718, 0,    3720, 0,    3722, 0,    3724, 0,    3726, 0,    3728, 0,    3730, 0,
+    3732, 0,    3734, 0,    3736, 0,    3738, 0,    3740, 0,    3742, 0,    3744, 0,    3746, 0,
+    3748, 0,    3750, 0,    3752, 0,    3754, 0,    3756, 0,    3758, 0,    3760, 0,    3762, 0,
+    3764, 0,    3766, 0,    3768, 0,    3770, 0,    3772, 0,    3774, 0,    3776, 0,    3778, 0,
+    3780, 0,    3782, 0,    3784, 0,    3786, 0,    3788, 0,    3790, 0,    3792, 0,    3794, 0,
+    3796, 3798, 3800, 3802, 3804, 3806, 3808, 3810, 3812, 3814, 3816, 3818, 3820, 3822, 3824, 3826,
+    3828, 3830, 3832, 3834, 3836, 3838, 3840, 3842, 3844, 3846, 3848, 3850, 3852, 3854, 3856, 3858,
+    3860, 3862, 3864, 3866, 3868, 3870, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    5731, 0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    3872, 3874, 3876, 3878, 3880, 3882, 3884,
+    3886, 3888, 3890, 3892, 3894, 3896, 3898, 3900, 3902, 3904, 3906, 3908, 3910, 3912, 3914, 3916,
+    3918, 3920, 3922, 3924, 3926, 3928, 3930, 3932, 3934, 3936, 3938, 3940, 3942, 3944, 3946, 3948,
+    3950, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    1071, 1073, 1075, 1077, 1079,
+    1081, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    3536, 3540, 3560, 3566, 3568,
+    3584, 3598, 4984, 4124, 4126, 4128, 4130, 4132, 4134, 4136, 4138, 4140, 4142, 4144, 4146, 4148,
+    4150, 4152, 4154, 4156, 4158, 4160, 4162, 4164, 4166, 4168, 4170, 4172, 4174, 4176, 4178, 4180,
+    4182, 4184, 4186, 4188, 4190, 4192, 4194, 4196, 4198, 4200, 4202, 4204, 4206, 4208, 4210, 4212,
+    4214, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    4216, 0,    4218, 0,    4220, 0,    4222, 0,    4224, 0,
+    4226, 0,    4228, 0,    4230, 0,    4232, 0,    4234, 0,    4236, 0,    4238, 0,    4240, 0,
+    4242, 0,    4244, 0,    4246, 0,    4248, 0,    4250, 0,    4252, 0,    4254, 0,    4256, 0,
+    4258, 0,    4260, 0,    4262, 0,    4264, 0,    4266, 0,    4268, 0,    4270, 0,    4272, 0,
+    4274, 0,    4276, 0,    4278, 0,    4280, 0,    4282, 0,    4284, 0,    4286, 0,    4288, 0,
+    4290, 0,    4292, 0,    4294, 0,    4296, 0,    4298, 0,    4300, 0,    4302, 0,    4304, 0,
+    4306, 0,    4308, 0,    4310, 0,    4312, 0,    4314, 0,    4316, 0,    4318, 0,    4320, 0,
+    4322, 0,    4324, 0,    4326, 0,    4328, 0,    4330, 0,    4332, 0,    4334, 0,    4336, 0,
+    4338, 0,    4340, 0,    4342, 0,    4344, 0,    4346, 0,    4348, 0,    4350, 0,    4352, 0,
+    4354, 0,    4356, 0,    4358, 0,    4360, 0,    4362, 0,    4364, 0,    5734, 5737, 5740, 5743,
+    5746, 4312, 5714, 4368, 0,    4370, 0,    4372, 0,    4374, 0,    4376, 0,    4378, 0,    4380,
+    0,    4382, 0,    4384, 0,    4386, 0,    4388, 0,    4390, 0,    4392, 0,    4394, 0,    4396,
+    0,    4398, 0,    4400, 0,    4402, 0,    4404, 0,    4406, 0,    4408, 0,    4410, 0,    4412,
+    0,    4414, 0,    4416, 0,    4418, 0,    4420, 0,    4422, 0,    4424, 0,    4426, 0,    4428,
+    0,    4430, 0,    4432, 0,    4434, 0,    4436, 0,    4438, 0,    4440, 0,    4442, 0,    4444,
+    0,    4446, 0,    4448, 0,    4450, 0,    4452, 0,    4454, 0,    4456, 0,    4458, 0,    4460,
+    0,    4462, 0,    0,    0,    0,    0,    0,    0,    0,    0,    4464, 4466, 4468, 4470, 4472,
+    4474, 4476, 4478, 0,    0,    0,    0,    0,    0,    4480, 4482, 4484, 4486, 4488, 4490, 0,
+    0,    0,    0,    0,    0,    0,    0,    4492, 4494, 4496, 4498, 4500, 4502, 4504, 4506, 0,
+    0,    0,    0,    0,    0,    0,    0,    4508, 4510, 4512, 4514, 4516, 4518, 4520, 4522, 0,
+    0,    0,    0,    0,    0,    4524, 4526, 4528, 4530, 4532, 4534, 5749, 0,    5752, 0,    5756,
+    0,    5760, 0,    4536, 4538, 4540, 4542, 0,    0,    0,    0,    0,    0,    0,    0,    4544,
+    4546, 4548, 4550, 4552, 4554, 4556, 4558, 0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    5764, 5767, 5770, 5773, 5776, 5779, 5782, 5785, 5764, 5767, 5770,
+    5773, 5776, 5779, 5782, 5785, 5788, 5791, 5794, 5797, 5800, 5803, 5806, 5809, 5788, 5791, 5794,
+    5797, 5800, 5803, 5806, 5809, 5812, 5815, 5818, 5821, 5824, 5827, 5830, 5833, 5812, 5815, 5818,
+    5821, 5824, 5827, 5830, 5833, 0,    0,    5836, 5839, 5842, 5845, 5848, 4608, 4610, 4612, 4614,
+    5839, 0,    3426, 0,    0,    0,    5852, 5855, 5858, 5861, 5864, 4618, 4620, 4622, 4624, 5855,
+    0,    0,    0,    0,    0,    5868, 5723, 5872, 5875, 4628, 4630, 4632, 4634, 0,    0,    0,
+    0,    0,    5879, 5727, 5883, 0,    5886, 5889, 4636, 4638, 4640, 4642, 4644, 0,    0,    0,
+    5893, 5896, 5899, 5902, 5905, 4646, 4648, 4650, 4652, 5896, 0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    3456, 2963, 3005, 0,    0,    0,    0,    4656, 0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    4658,
+    4660, 4662, 4664, 4666, 4668, 4670, 4672, 4674, 4676, 4678, 4680, 4682, 4684, 4686, 4688, 0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    4690,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    4692, 4694, 4696, 4698, 4700, 4702, 4704, 4706, 4708, 4710, 4712,
+    4714, 4716, 4718, 4720, 4722, 4724, 4726, 4728, 4730, 4732, 4734, 4736, 4738, 4740, 4742, 0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    4744, 4746, 4748, 4750, 4752, 4754, 4756, 4758, 4760, 4762, 4764, 4766,
+    4768, 4770, 4772, 4774, 4776, 4778, 4780, 4782, 4784, 4786, 4788, 4790, 4792, 4794, 4796, 4798,
+    4800, 4802, 4804, 4806, 4808, 4810, 4812, 4814, 4816, 4818, 4820, 4822, 4824, 4826, 4828, 4830,
+    4832, 4834, 4836, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    4838, 0,    4840, 4842, 4844, 0,    0,    4846, 0,    4848, 0,    4850, 0,    4852,
+    4854, 4856, 4858, 4860, 0,    4862, 0,    0,    0,    4864, 4866, 4868, 0,    4870, 0,    4872,
+    0,    4874, 0,    4876, 0,    4878, 0,    4880, 0,    4882, 0,    4884, 0,    4886, 0,    4888,
+    0,    4890, 0,    4892, 0,    4894, 0,    4896, 0,    4898, 0,    4900, 0,    4902, 0,    4904,
+    0,    4906, 0,    4908, 0,    4910, 0,    4912, 0,    4914, 0,    4916, 0,    4918, 0,    4920,
+    0,    4922, 0,    4924, 0,    4926, 0,    4928, 0,    4930, 0,    4932, 0,    4934, 0,    4936,
+    0,    4938, 0,    4940, 0,    4942, 0,    4944, 0,    4946, 0,    4948, 0,    4950, 0,    4952,
+    0,    4954, 0,    4956, 0,    4958, 0,    4960, 0,    4962, 0,    4964, 0,    4966, 0,    4968,
+    0,    4970, 0,    4972, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    4974, 0,    4976, 0,
+    4978, 0,    4980, 0,    4982, 0,    4984, 0,    4986, 0,    4988, 0,    4990, 0,    4992, 0,
+    4994, 0,    4996, 0,    4998, 0,    5000, 0,    5002, 0,    5004, 0,    5006, 0,    5008, 0,
+    5010, 0,    5012, 0,    5014, 0,    5016, 0,    5018, 0,    5020, 0,    5022, 0,    5024, 0,
+    5026, 0,    5028, 0,    5030, 0,    5032, 0,    5034, 0,    5036, 0,    5038, 0,    5040, 0,
+    5042, 0,    5044, 0,    5046, 0,    0,    0,    5048, 0,    5050, 0,    5052, 0,    5054, 0,
+    5056, 0,    5058, 0,    5060, 0,    5062, 0,    5064, 0,    5066, 0,    5068, 0,    5070, 0,
+    5072, 0,    5074, 0,    5076, 0,    5078, 0,    5080, 0,    5082, 0,    5084, 0,    5086, 0,
+    5088, 0,    5090, 0,    5092, 0,    5094, 0,    5096, 0,    5098, 0,    5100, 0,    5102, 0,
+    5104, 0,    5106, 0,    5108, 0,    5110, 0,    5112, 0,    5114, 0,    5116, 0,    5118, 0,
+    5120, 0,    5122, 0,    0,    5124, 0,    5126, 0,    5128, 5130, 0,    5132, 0,    5134, 0,
+    5136, 0,    5138, 0,    0,    5140, 0,    5142, 5144, 0,    5146, 0,    0,    5148, 0,    5150,
+    0,    5152, 0,    5154, 0,    5156, 0,    5158, 0,    5160, 0,    5162, 0,    5164, 0,    5166,
+    0,    5168, 5170, 5172, 5174, 5176, 5178, 5180, 5182, 5184, 5186, 0,    5188, 0,    5190, 0,
+    5192, 0,    5194, 0,    5196, 0,    5198, 0,    5200, 5202, 5204, 5206, 0,    5208, 0,    5210,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    2246, 2248, 2250, 2252, 2254, 2256, 2258,
+    2260, 2262, 2264, 2266, 2268, 2270, 2272, 2274, 2276, 2278, 2280, 2282, 2284, 2286, 2288, 2290,
+    2292, 2294, 2296, 2298, 2300, 2302, 2304, 2306, 2308, 2310, 2312, 2314, 2316, 2318, 2320, 2322,
+    2324, 2326, 2328, 2330, 2332, 2334, 2336, 2338, 2340, 2342, 2344, 2346, 2348, 2350, 2352, 2354,
+    2356, 2358, 2360, 2362, 2364, 2366, 2368, 2370, 2372, 2374, 2376, 2378, 2380, 2382, 2384, 2386,
+    2388, 2390, 2392, 2394, 2396, 2398, 2400, 2402, 2404, 0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    5909, 5912, 5915, 5918, 5922, 5926, 5926, 5929, 5932, 5935,
+    5938, 5941, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    5212, 5214, 5216, 5218, 5220, 5222, 5224, 5226, 5228,
+    5230, 5232, 5234, 5236, 5238, 5240, 5242, 5244, 5246, 5248, 5250, 5252, 5254, 5256, 5258, 5260,
+    5262, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    5264, 5266, 5268, 5270, 5272,
+    5274, 5276, 5278, 5280, 5282, 5284, 5286, 5288, 5290, 5292, 5294, 5296, 5298, 5300, 5302, 5304,
+    5306, 5308, 5310, 5312, 5314, 5316, 5318, 5320, 5322, 5324, 5326, 5328, 5330, 5332, 5334, 5336,
+    5338, 5340, 5342, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    5344, 5346, 5348, 5350, 5352,
+    5354, 5356, 5358, 5360, 5362, 5364, 5366, 5368, 5370, 5372, 5374, 5376, 5378, 5380, 5382, 5384,
+    5386, 5388, 5390, 5392, 5394, 5396, 5398, 5400, 5402, 5404, 5406, 5408, 5410, 5412, 5414, 0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    5416, 5418, 5420, 5422, 5424, 5426, 5428, 5430, 5432, 5434, 5436,
+    5438, 5440, 5442, 5444, 5446, 5448, 5450, 5452, 5454, 5456, 5458, 5460, 5462, 5464, 5466, 5468,
+    5470, 5472, 5474, 5476, 5478, 5480, 5482, 5484, 5486, 5488, 5490, 5492, 5494, 5496, 5498, 5500,
+    5502, 5504, 5506, 5508, 5510, 5512, 5514, 5516, 0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    5518, 5520, 5522, 5524, 5526,
+    5528, 5530, 5532, 5534, 5536, 5538, 5540, 5542, 5544, 5546, 5548, 5550, 5552, 5554, 5556, 5558,
+    5560, 5562, 5564, 5566, 5568, 5570, 5572, 5574, 5576, 5578, 5580, 0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    5582, 5584, 5586, 5588, 5590, 5592, 5594, 5596, 5598, 5600, 5602, 5604, 5606, 5608, 5610,
+    5612, 5614, 5616, 5618, 5620, 5622, 5624, 5626, 5628, 5630, 5632, 5634, 5636, 5638, 5640, 5642,
+    5644, 0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    5646, 5648, 5650,
+    5652, 5654, 5656, 5658, 5660, 5662, 5664, 5666, 5668, 5670, 5672, 5674, 5676, 5678, 5680, 5682,
+    5684, 5686, 5688, 5690, 5692, 5694, 5696, 5698, 5700, 5702, 5704, 5706, 5708, 5710, 5712, 0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,    0,
+    0,    0,    0,    0,    0,    0,    0,    0,    0,
+};
+
+const decomposition_offsets: [6857]u16 = [_]u16{
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     5944,  5947,  5950,  5953,  5956,  5959,  0,
+    5962,  5965,  5968,  5971,  5974,  5977,  5980,  5983,  5986,  0,     5989,  5992,  5995,
+    5998,  6001,  6004,  0,     6007,  6010,  6013,  6016,  6019,  0,     0,     6022,  6025,
+    6028,  6031,  6034,  6037,  0,     6040,  6043,  6046,  6049,  6052,  6055,  6058,  6061,
+    6064,  0,     6067,  6070,  6073,  6076,  6079,  6082,  0,     6085,  6088,  6091,  6094,
+    6097,  0,     6100,  6103,  6106,  6109,  6112,  6115,  6118,  6121,  6124,  6127,  6130,
+    6133,  6136,  6139,  6142,  6145,  6148,  0,     0,     6151,  6154,  6157,  6160,  6163,
+    6166,  6169,  6172,  6175,  6178,  6181,  6184,  6187,  6190,  6193,  6196,  6199,  6202,
+    6205,  6208,  0,     0,     6211,  6214,  6217,  6220,  6223,  6226,  6229,  6232,  6235,
+    0,     0,     0,     6238,  6241,  6244,  6247,  0,     6250,  6253,  6256,  6259,  6262,
+    6265,  0,     0,     0,     0,     6268,  6271,  6274,  6277,  6280,  6283,  0,     0,
+    0,     6286,  6289,  6292,  6295,  6298,  6301,  0,     0,     6304,  6307,  6310,  6313,
+    6316,  6319,  6322,  6325,  6328,  6331,  6334,  6337,  6340,  6343,  6346,  6349,  6352,
+    6355,  0,     0,     6358,  6361,  6364,  6367,  6370,  6373,  6376,  6379,  6382,  6385,
+    6388,  6391,  6394,  6397,  6400,  6403,  6406,  6409,  6412,  6415,  6418,  6421,  6424,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     6427,  6430,  0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     6433,  6436,  0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     6439,  6442,  6445,  6448,  6451,  6454,  6457,  6460,  6463,  6467,
+    6471,  6475,  6479,  6483,  6487,  6491,  0,     6495,  6499,  6503,  6507,  6511,  6514,
+    0,     0,     6517,  6520,  6523,  6526,  6529,  6532,  6535,  6539,  6543,  6546,  5720,
+    0,     0,     0,     6549,  6552,  0,     0,     6555,  6558,  6561,  6565,  6569,  6572,
+    6575,  6578,  6581,  6584,  6587,  6590,  6593,  6596,  6599,  6602,  6605,  6608,  6611,
+    6614,  6617,  6620,  6623,  6626,  6629,  6632,  6635,  6638,  6641,  6644,  6647,  6650,
+    6653,  6656,  6659,  6662,  0,     0,     6665,  6668,  0,     0,     0,     0,     0,
+    6671,  6674,  6677,  6680,  6683,  6687,  6691,  6695,  6699,  6702,  6705,  6709,  6713,
+    6716,  0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     6719,  6721,
+    0,     6723,  6725,  0,     0,     0,     0,     0,     0,     0,     0,     6728,  0,
+    0,     0,     0,     0,     0,     6730,  0,     6732,  6735,  6738,  6740,  6743,  6746,
+    6749,  6752,  6755,  5723,  0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     6758,  6761,  6764,  6767,  6770,  6773,  5727,  0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     6776,  6779,  6782,  6785,  6788,
+    0,     0,     0,     0,     6791,  6794,  0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     6797,  6800,  0,     6803,  0,
+    0,     0,     6806,  0,     0,     0,     0,     6809,  6812,  6815,  0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     6818,  0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    6821,  0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     6824,  6827,  0,
+    6830,  0,     0,     0,     6833,  0,     0,     0,     0,     6836,  6839,  6842,  0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     6845,  6848,  0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    6851,  6854,  0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     6857,  6860,  6863,  6866,  0,     0,     6869,  6872,  0,     0,     6875,
+    6878,  6881,  6884,  6887,  6890,  0,     0,     6893,  6896,  6899,  6902,  6905,  6908,
+    0,     0,     6911,  6914,  6917,  6920,  6923,  6926,  6929,  6932,  6935,  6938,  6941,
+    6944,  0,     0,     6947,  6950,  0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     6953,
+    6956,  6959,  6962,  6965,  0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     6968,  0,     6971,
+    0,     6974,  0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     6977,
+    0,     6980,  0,     6983,  0,     0,     6986,  6989,  6992,  6995,  6998,  7001,  7004,
+    7007,  0,     0,     0,     0,     7010,  7013,  0,     7016,  7019,  7022,  0,     7025,
+    7028,  7031,  7034,  7037,  7040,  0,     0,     7043,  7046,  7049,  0,     0,     7052,
+    7055,  0,     7058,  0,     0,     0,     7061,  7064,  7067,  0,     0,     7070,  0,
+    0,     0,     0,     7073,  0,     0,     7076,  7079,  7082,  7085,  0,     0,     0,
+    0,     0,     7089,  7092,  7095,  0,     0,     0,     0,     0,     7098,  7101,  7104,
+    7108,  0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     7111,
+    7114,  7117,  7120,  7123,  7126,  0,     0,     7129,  0,     7132,  7135,  0,     7138,
+    0,     7141,  7144,  7147,  7150,  7153,  7156,  7159,  0,     7162,  0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     7165,  0,     7168,  0,     7171,  0,     7174,  0,     7177,
+    0,     7180,  0,     0,     7183,  0,     7186,  0,     0,     7189,  7192,  0,     7195,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    7198,  7201,  7204,  7207,  7210,  7213,  7216,  7219,  7222,  7226,  7230,  7233,  7236,
+    7239,  7242,  7245,  7248,  7251,  7254,  7257,  7260,  7264,  7268,  7272,  7276,  7279,
+    7282,  7285,  7288,  7292,  7296,  7299,  7302,  7305,  7308,  7311,  7314,  7317,  7320,
+    7323,  7326,  7329,  7332,  7335,  7338,  7341,  7344,  7348,  7352,  7355,  7358,  7361,
+    7364,  7367,  7370,  7373,  7376,  7380,  7384,  7387,  7390,  7393,  7396,  7399,  7402,
+    7405,  7408,  7411,  7414,  7417,  7420,  7423,  7426,  7429,  7432,  7435,  7438,  7442,
+    7446,  7450,  7454,  7458,  7462,  7466,  7470,  7473,  7476,  7479,  7482,  7485,  7488,
+    7491,  7494,  7498,  7502,  7505,  7508,  7511,  7514,  7517,  7520,  7524,  7528,  7532,
+    7536,  7540,  7544,  7547,  7550,  7553,  7556,  7559,  7562,  7565,  7568,  7571,  7574,
+    7577,  7580,  7583,  7586,  7590,  7594,  7598,  7602,  7605,  7608,  7611,  7614,  7617,
+    7620,  7623,  7626,  7629,  7632,  7635,  7638,  7641,  7644,  7647,  7650,  7653,  7656,
+    7659,  7662,  7665,  7668,  7671,  7674,  7677,  5734,  5737,  5740,  5743,  0,     7680,
+    0,     7683,  7686,  7689,  7692,  7695,  7699,  7703,  7707,  7711,  7715,  7719,  7723,
+    7727,  7731,  7735,  7739,  7743,  7747,  7751,  7755,  7759,  7763,  7767,  7771,  7775,
+    7778,  7781,  7784,  7787,  7790,  7793,  7797,  7801,  7805,  7809,  7813,  7817,  7821,
+    7825,  7829,  7833,  7836,  7839,  7842,  7845,  7848,  7851,  7854,  7857,  7861,  7865,
+    7869,  7873,  7877,  7881,  7885,  7889,  7893,  7897,  7901,  7905,  7909,  7913,  7917,
+    7921,  7925,  7929,  7933,  7937,  7940,  7943,  7946,  7949,  7953,  7957,  7961,  7965,
+    7969,  7973,  7977,  7981,  7985,  7989,  7992,  7995,  7998,  8001,  8004,  8007,  8010,
+    0,     0,     0,     0,     0,     0,     8013,  8016,  8019,  8023,  8027,  8031,  8035,
+    8039,  8043,  8046,  8049,  8053,  8057,  8061,  8065,  8069,  8073,  8076,  8079,  8083,
+    8087,  8091,  8095,  8098,  8101,  8105,  8109,  8113,  8117,  8120,  8123,  8127,  8131,
+    8135,  8139,  8143,  8147,  8150,  8153,  8157,  8161,  8165,  8169,  8173,  8177,  8180,
+    8183,  8187,  8191,  8195,  8199,  8203,  8207,  8210,  8213,  8217,  8221,  8225,  8229,
+    8233,  8237,  8240,  8243,  8247,  8251,  8255,  8259,  8262,  8265,  8269,  8273,  8277,
+    5749,  8281,  5752,  8284,  5756,  8288,  5760,  8292,  8296,  8299,  8303,  8307,  8311,
+    8314,  8317,  8321,  8325,  8329,  8333,  8337,  8341,  8344,  8347,  8351,  8355,  8359,
+    8363,  8367,  8371,  6764,  8374,  6767,  8377,  6770,  8380,  6773,  8383,  6782,  8386,
+    6785,  8389,  6788,  8392,  8396,  8400,  8405,  8410,  8415,  8420,  8425,  8430,  8434,
+    8438,  8443,  8448,  8453,  8458,  8463,  8468,  8472,  8476,  8481,  8486,  8491,  8496,
+    8501,  8506,  8510,  8514,  8519,  8524,  8529,  8534,  8539,  8544,  8548,  8552,  8557,
+    8562,  8567,  8572,  8577,  8582,  8586,  8590,  8595,  8600,  8605,  8610,  8615,  8620,
+    8623,  8626,  8630,  8633,  5845,  8637,  8641,  8644,  8647,  6735,  8650,  0,     3426,
+    0,     0,     8653,  8656,  8660,  8663,  5861,  8667,  8671,  6740,  8674,  6743,  8677,
+    8680,  8683,  8686,  8689,  8692,  5868,  5723,  5872,  5875,  8695,  8698,  8701,  6746,
+    8704,  8707,  8710,  8713,  8716,  5879,  5727,  5883,  8719,  5886,  5889,  8722,  8725,
+    8728,  6752,  8731,  8734,  6732,  8737,  8739,  8743,  8746,  5902,  8750,  8754,  6749,
+    8757,  6755,  8760,  8763,  0,     8765,  8767,  0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     558,   21,    5959,  0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     8769,  8772,  8775,  8778,  8781,
+    8784,  0,     0,     0,     0,     0,     8787,  0,     8790,  0,     8793,  0,     8796,
+    0,     8799,  0,     0,     0,     0,     0,     8802,  0,     8805,  0,     8808,  0,
+    8811,  0,     8814,  0,     8817,  0,     0,     8820,  8823,  8826,  8829,  8832,  0,
+    0,     8835,  8838,  0,     0,     8841,  8844,  0,     0,     0,     0,     8847,  8850,
+    0,     0,     8853,  8856,  0,     0,     8859,  8862,  0,     0,     0,     0,     0,
+    0,     8865,  8868,  8871,  8874,  0,     0,     0,     0,     8877,  8880,  8883,  8886,
+    8889,  8892,  8895,  8898,  0,     8901,  8903,  0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     8905,  0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     8908,  0,     8911,  0,
+    8914,  0,     8917,  0,     8920,  0,     8923,  0,     8926,  0,     8929,  0,     8932,
+    0,     8935,  0,     8938,  0,     8941,  0,     8944,  0,     8947,  0,     8950,  0,
+    8953,  8956,  0,     8959,  8962,  0,     8965,  8968,  0,     8971,  8974,  0,     8977,
+    8980,  8983,  0,     0,     0,     0,     0,     8986,  0,     0,     0,     8989,  0,
+    8992,  0,     8995,  0,     8998,  0,     9001,  0,     9004,  0,     9007,  0,     9010,
+    0,     9013,  0,     9016,  0,     9019,  0,     9022,  0,     9025,  0,     9028,  0,
+    9031,  0,     9034,  9037,  0,     9040,  9043,  0,     9046,  9049,  0,     9052,  9055,
+    0,     9058,  9061,  0,     0,     0,     0,     9064,  9067,  9070,  9073,  9076,  0,
+    9079,  0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     9082,  9084,
+    9086,  9088,  9090,  9092,  9094,  9096,  9098,  9100,  9102,  9104,  9106,  9108,  9110,
+    9112,  9114,  9116,  9118,  9120,  9122,  9124,  9126,  9128,  9130,  9132,  9134,  9136,
+    9138,  9140,  9142,  9144,  9146,  9148,  9150,  9152,  9154,  9156,  9158,  9160,  9162,
+    9164,  9166,  9168,  9170,  9172,  9174,  9176,  9178,  9180,  9182,  9184,  9186,  9188,
+    9190,  9192,  9194,  9196,  9198,  9200,  9202,  9204,  9206,  9208,  9210,  9212,  9214,
+    9216,  9218,  9220,  9222,  9224,  9226,  9228,  9230,  9232,  9234,  9236,  9238,  9240,
+    9242,  9244,  9246,  9248,  9250,  9252,  9254,  9256,  9258,  9260,  9262,  9264,  9266,
+    9268,  9270,  9272,  9274,  9276,  9278,  9280,  9282,  9284,  9286,  9288,  9290,  9292,
+    9294,  9296,  9298,  9300,  9302,  9304,  9306,  9308,  9310,  9312,  9314,  9316,  9318,
+    9320,  9322,  9324,  9326,  9328,  9330,  9332,  9334,  9336,  9338,  9340,  9342,  9344,
+    9346,  9348,  9350,  9352,  9354,  9356,  9358,  9360,  9362,  9364,  9366,  9368,  9370,
+    9372,  9374,  9376,  9378,  9380,  9382,  9384,  9386,  9388,  9390,  9392,  9394,  9396,
+    9398,  9400,  9402,  9404,  9406,  9408,  9410,  9412,  9414,  9416,  9418,  9420,  9422,
+    9424,  9426,  9428,  9430,  9432,  9434,  9436,  9438,  9440,  9442,  9444,  9446,  9448,
+    9450,  9452,  9454,  9456,  9458,  9460,  9462,  9464,  9466,  9468,  9470,  9472,  9474,
+    9476,  9478,  9480,  9482,  9484,  9486,  9488,  9490,  9492,  9494,  9496,  9498,  9500,
+    9502,  9504,  9506,  9508,  9510,  9512,  9514,  9516,  9518,  9520,  9522,  9524,  9526,
+    9528,  9530,  9532,  9534,  9536,  9538,  9540,  9542,  9544,  9546,  9548,  9550,  9552,
+    9554,  9556,  9558,  9560,  9562,  9564,  9566,  9568,  9570,  9572,  9574,  9576,  9578,
+    9580,  9582,  9584,  9586,  9588,  9590,  9592,  9594,  9596,  9598,  9600,  9602,  9604,
+    9606,  9608,  9610,  9612,  9614,  9616,  9618,  9620,  9622,  9624,  9626,  9628,  9630,
+    9632,  9634,  9636,  9638,  9640,  9642,  9644,  9646,  9648,  9650,  9652,  9654,  9656,
+    9658,  9660,  9662,  9664,  9666,  9668,  9670,  9672,  9674,  9676,  9678,  9680,  9682,
+    9684,  9686,  9688,  9690,  9692,  9694,  9696,  9698,  9700,  9702,  9704,  9706,  9708,
+    9710,  9712,  9714,  9716,  9718,  9720,  9722,  9724,  9726,  9728,  9730,  9732,  9734,
+    9736,  9738,  9740,  9742,  9744,  9746,  9748,  9750,  9752,  9754,  9756,  9758,  9760,
+    9762,  9764,  9766,  9768,  9770,  9772,  9774,  9776,  9778,  9780,  9782,  9784,  9786,
+    9788,  9790,  9792,  9794,  9796,  9798,  9800,  9802,  9804,  9806,  9808,  9810,  9812,
+    9814,  9816,  9818,  9820,  9822,  9824,  9826,  9828,  9830,  9832,  9834,  9836,  9838,
+    9840,  9842,  9844,  9846,  9848,  9850,  9852,  9854,  9856,  9858,  9860,  9862,  9864,
+    9866,  9868,  9870,  9872,  9874,  9876,  9878,  9880,  9882,  9884,  9886,  9888,  9890,
+    9892,  9894,  9896,  9898,  9900,  9902,  9904,  9906,  9908,  9910,  9912,  9914,  9916,
+    9918,  9920,  9922,  9924,  9926,  9928,  9930,  0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     9932,  0,     9935,  0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     9938,  9941,  9944,  9948,  9952,  9955,  9958,
+    9961,  9964,  9967,  9970,  9973,  9976,  9979,  9982,  9985,  9988,  9991,  9994,  9997,
+    10000, 10003, 10006, 10009, 10012, 10015, 10018, 10021, 10024, 10027, 10030, 10033, 0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     10036, 0,     10039, 0,     10042, 0,     0,     0,     10045,
+    10048, 0,     0,     0,     0,     10051, 10054, 0,     0,     0,     0,     10057, 10060,
+    0,     10063, 0,     0,     0,     10066, 10069, 0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     10072, 0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     10075, 10078, 10081, 10085, 10089, 10093, 10097, 0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     10101, 10104, 10107, 10111, 10115, 10119, 0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     0,
+    0,     0,     0,     0,     0,     0,     0,     0,     0,     0,     10123, 10125, 10127,
+    10129, 10131, 10133, 10135, 10137, 10139, 10141, 10143, 10145, 10147, 10149, 10151, 10153,
+    10155, 10157, 10159, 10161, 10163, 10165, 10167, 10169, 10171, 10173, 10175, 10177, 10179,
+    10181, 10183, 10185, 10187, 10189, 10191, 10193, 10195, 10197, 10199, 10201, 10203, 10205,
+    10207, 10209, 10211, 10213, 10215, 10217, 10219, 10221, 10223, 10225, 10227, 10229, 10231,
+    10233, 10235, 10237, 10239, 10241, 10243, 10245, 10247, 10249, 10251, 10253, 10255, 10257,
+    10259, 10261, 10263, 10265, 10267, 10269, 10271, 10273, 10275, 10277, 10279, 10281, 10283,
+    10285, 10287, 10289, 10291, 10293, 10295, 10297, 10299, 10301, 10303, 10305, 10307, 10309,
+    10311, 10313, 10315, 10317, 10319, 10321, 10323, 10325, 10327, 10329, 10331, 10333, 10335,
+    10337, 10339, 10341, 10343, 10345, 10347, 10349, 10351, 10353, 10355, 10357, 10359, 10361,
+    10363, 10365, 10367, 10369, 10371, 10373, 10375, 10377, 10379, 10381, 10383, 10385, 10387,
+    10389, 10391, 10393, 10395, 10397, 10399, 10401, 10403, 10405, 10407, 10409, 10411, 10413,
+    10415, 10417, 10419, 10421, 10423, 10425, 10427, 10429, 10431, 10433, 10435, 10437, 10439,
+    10441, 10443, 10445, 10447, 10449, 10451, 10453, 10455, 10457, 10459, 10461, 10463, 10465,
+    10467, 10469, 10471, 10473, 10475, 10477, 10479, 10481, 10483, 10485, 10487, 10489, 10491,
+    10493, 10495, 10497, 10499, 10501, 10503, 10505, 10507, 10509, 10511, 10513, 10515, 10517,
+    10519, 10521, 10523, 10525, 10527, 10529, 10531, 10533, 10535, 10537, 10539, 10541, 10543,
+    10545, 10547, 10549, 10551, 10553, 10555, 10557, 10559, 10561, 10563, 10565, 10567, 10569,
+    10571, 10573, 10575, 10577, 10579, 10581, 10583, 10585, 10587, 10589, 10591, 10593, 10595,
+    10597, 10599, 10601, 10603, 10605, 10607, 10609, 10611, 10613, 10615, 10617, 10619, 10621,
+    10623, 10625, 10627, 10629, 10631, 10633, 10635, 10637, 10639, 10641, 10643, 10645, 10647,
+    10649, 10651, 10653, 10655, 10657, 10659, 10661, 10663, 10665, 10667, 10669, 10671, 10673,
+    10675, 10677, 10679, 10681, 10683, 10685, 10687, 10689, 10691, 10693, 10695, 10697, 10699,
+    10701, 10703, 10705, 10707, 10709, 10711, 10713, 10715, 10717, 10719, 10721, 10723, 10725,
+    10727, 10729, 10731, 10733, 10735, 10737, 10739, 10741, 10743, 10745, 10747, 10749, 10751,
+    10753, 10755, 10757, 10759, 10761, 10763, 10765, 10767, 10769, 10771, 10773, 10775, 10777,
+    10779, 10781, 10783, 10785, 10787, 10789, 10791, 10793, 10795, 10797, 10799, 10801, 10803,
+    10805, 10807, 10809, 10811, 10813, 10815, 10817, 10819, 10821, 10823, 10825, 10827, 10829,
+    10831, 10833, 10835, 10837, 10839, 10841, 10843, 10845, 10847, 10849, 10851, 10853, 10855,
+    10857, 10859, 10861, 10863, 10865, 10867, 10869, 10871, 10873, 10875, 10877, 10879, 10881,
+    10883, 10885, 10887, 10889, 10891, 10893, 10895, 10897, 10899, 10901, 10903, 10905, 10907,
+    10909, 10911, 10913, 10915, 10917, 10919, 10921, 10923, 10925, 10927, 10929, 10931, 10933,
+    10935, 10937, 10939, 10941, 10943, 10945, 10947, 10949, 10951, 10953, 10955, 10957, 10959,
+    10961, 10963, 10965, 10967, 10969, 10971, 10973, 10975, 10977, 10979, 10981, 10983, 10985,
+    10987, 10989, 10991, 10993, 10995, 10997, 10999, 11001, 11003, 11005, 11007, 11009, 11011,
+    11013, 11015, 11017, 11019, 11021, 11023, 11025, 11027, 11029, 11031, 11033, 11035, 11037,
+    11039, 11041, 11043, 11045, 11047, 11049, 11051, 11053, 11055, 11057, 11059, 11061, 11063,
+    11065, 11067, 11069, 11071, 11073, 11075,
+};
+
+const sequences: [11077]u21 = [_]u21{
+    0,      1,      65,     1,      66,     1,      67,     1,      68,     1,      69,     1,
+    70,     1,      71,     1,      72,     1,      73,     1,      74,     1,      75,     1,
+    76,     1,      77,     1,      78,     1,      79,     1,      80,     1,      81,     1,
+    82,     1,      83,     1,      84,     1,      85,     1,      86,     1,      87,     1,
+    88,     1,      89,     1,      90,     1,      924,    2,      83,     83,     1,      192,
+    1,      193,    1,      194,    1,      195,    1,      196,    1,      197,    1,      198,
+    1,      199,    1,      200,    1,      201,    1,      202,    1,      203,    1,      204,
+    1,      205,    1,      206,    1,      207,    1,      208,    1,      209,    1,      210,
+    1,      211,    1,      212,    1,      213,    1,      214,    1,      216,    1,      217,
+    1,      218,    1,      219,    1,      220,    1,      221,    1,      222,    1,      376,
+    1,      256,    1,      258,    1,      260,    1,      262,    1,      264,    1,      266,
+    1,      268,    1,      270,    1,      272,    1,      274,    1,      276,    1,      278,
+    1,      280,    1,      282,    1,      284,    1,      286,    1,      288,    1,      290,
+    1,      292,    1,      294,    1,      296,    1,      298,    1,      300,    1,      302,
+    1,      306,    1,      308,    1,      310,    1,      313,    1,      315,    1,      317,
+    1,      319,    1,      321,    1,      323,    1,      325,    1,      327,    2,      700,
+    78,     1,      330,    1,      332,    1,      334,    1,      336,    1,      338,    1,
+    340,    1,      342,    1,      344,    1,      346,    1,      348,    1,      350,    1,
+    352,    1,      354,    1,      356,    1,      358,    1,      360,    1,      362,    1,
+    364,    1,      366,    1,      368,    1,      370,    1,      372,    1,      374,    1,
+    377,    1,      379,    1,      381,    1,      579,    1,      386,    1,      388,    1,
+    391,    1,      395,    1,      401,    1,      502,    1,      408,    1,      573,    1,
+    544,    1,      416,    1,      418,    1,      420,    1,      423,    1,      428,    1,
+    431,    1,      435,    1,      437,    1,      440,    1,      444,    1,      503,    1,
+    452,    1,      455,    1,      458,    1,      461,    1,      463,    1,      465,    1,
+    467,    1,      469,    1,      471,    1,      473,    1,      475,    1,      398,    1,
+    478,    1,      480,    1,      482,    1,      484,    1,      486,    1,      488,    1,
+    490,    1,      492,    1,      494,    2,      74,     780,    1,      497,    1,      500,
+    1,      504,    1,      506,    1,      508,    1,      510,    1,      512,    1,      514,
+    1,      516,    1,      518,    1,      520,    1,      522,    1,      524,    1,      526,
+    1,      528,    1,      530,    1,      532,    1,      534,    1,      536,    1,      538,
+    1,      540,    1,      542,    1,      546,    1,      548,    1,      550,    1,      552,
+    1,      554,    1,      556,    1,      558,    1,      560,    1,      562,    1,      571,
+    1,      11390,  1,      11391,  1,      577,    1,      582,    1,      584,    1,      586,
+    1,      588,    1,      590,    1,      11375,  1,      11373,  1,      11376,  1,      385,
+    1,      390,    1,      393,    1,      394,    1,      399,    1,      400,    1,      42923,
+    1,      403,    1,      42924,  1,      404,    1,      42893,  1,      42922,  1,      407,
+    1,      406,    1,      42926,  1,      11362,  1,      42925,  1,      412,    1,      11374,
+    1,      413,    1,      415,    1,      11364,  1,      422,    1,      42949,  1,      425,
+    1,      42929,  1,      430,    1,      580,    1,      433,    1,      434,    1,      581,
+    1,      439,    1,      42930,  1,      42928,  1,      921,    1,      880,    1,      882,
+    1,      886,    1,      1021,   1,      1022,   1,      1023,   3,      921,    776,    769,
+    1,      902,    1,      904,    1,      905,    1,      906,    3,      933,    776,    769,
+    1,      913,    1,      914,    1,      915,    1,      916,    1,      917,    1,      918,
+    1,      919,    1,      920,    1,      922,    1,      923,    1,      925,    1,      926,
+    1,      927,    1,      928,    1,      929,    1,      931,    1,      932,    1,      933,
+    1,      934,    1,      935,    1,      936,    1,      937,    1,      938,    1,      939,
+    1,      908,    1,      910,    1,      911,    1,      975,    1,      984,    1,      986,
+    1,      988,    1,      990,    1,      992,    1,      994,    1,      996,    1,      998,
+    1,      1000,   1,      1002,   1,      1004,   1,      1006,   1,      1017,   1,      895,
+    1,      1015,   1,      1018,   1,      1040,   1,      1041,   1,      1042,   1,      1043,
+    1,      1044,   1,      1045,   1,      1046,   1,      1047,   1,      1048,   1,      1049,
+    1,      1050,   1,      1051,   1,      1052,   1,      1053,   1,      1054,   1,      1055,
+    1,      1056,   1,      1057,   1,      1058,   1,      1059,   1,      1060,   1,      1061,
+    1,      1062,   1,      1063,   1,      1064,   1,      1065,   1,      1066,   1,      1067,
+    1,      1068,   1,      1069,   1,      1070,   1,      1071,   1,      1024,   1,      1025,
+    1,      1026,   1,      1027,   1,      1028,   1,      1029,   1,      1030,   1,      1031,
+    1,      1032,   1,      1033,   1,      1034,   1,      1035,   1,      1036,   1,      1037,
+    1,      1038,   1,      1039,   1,      1120,   1,      1122,   1,      1124,   1,      1126,
+    1,      1128,   1,      1130,   1,      1132,   1,      1134,   1,      1136,   1,      1138,
+    1,      1140,   1,      1142,   1,      1144,   1,      1146,   1,      1148,   1,      1150,
+    1,      1152,   1,      1162,   1,      1164,   1,      1166,   1,      1168,   1,      1170,
+    1,      1172,   1,      1174,   1,      1176,   1,      1178,   1,      1180,   1,      1182,
+    1,      1184,   1,      1186,   1,      1188,   1,      1190,   1,      1192,   1,      1194,
+    1,      1196,   1,      1198,   1,      1200,   1,      1202,   1,      1204,   1,      1206,
+    1,      1208,   1,      1210,   1,      1212,   1,      1214,   1,      1217,   1,      1219,
+    1,      1221,   1,      1223,   1,      1225,   1,      1227,   1,      1229,   1,      1216,
+    1,      1232,   1,      1234,   1,      1236,   1,      1238,   1,      1240,   1,      1242,
+    1,      1244,   1,      1246,   1,      1248,   1,      1250,   1,      1252,   1,      1254,
+    1,      1256,   1,      1258,   1,      1260,   1,      1262,   1,      1264,   1,      1266,
+    1,      1268,   1,      1270,   1,      1272,   1,      1274,   1,      1276,   1,      1278,
+    1,      1280,   1,      1282,   1,      1284,   1,      1286,   1,      1288,   1,      1290,
+    1,      1292,   1,      1294,   1,      1296,   1,      1298,   1,      1300,   1,      1302,
+    1,      1304,   1,      1306,   1,      1308,   1,      1310,   1,      1312,   1,      1314,
+    1,      1316,   1,      1318,   1,      1320,   1,      1322,   1,      1324,   1,      1326,
+    1,      1329,   1,      1330,   1,      1331,   1,      1332,   1,      1333,   1,      1334,
+    1,      1335,   1,      1336,   1,      1337,   1,      1338,   1,      1339,   1,      1340,
+    1,      1341,   1,      1342,   1,      1343,   1,      1344,   1,      1345,   1,      1346,
+    1,      1347,   1,      1348,   1,      1349,   1,      1350,   1,      1351,   1,      1352,
+    1,      1353,   1,      1354,   1,      1355,   1,      1356,   1,      1357,   1,      1358,
+    1,      1359,   1,      1360,   1,      1361,   1,      1362,   1,      1363,   1,      1364,
+    1,      1365,   1,      1366,   2,      1333,   1362,   1,      7312,   1,      7313,   1,
+    7314,   1,      7315,   1,      7316,   1,      7317,   1,      7318,   1,      7319,   1,
+    7320,   1,      7321,   1,      7322,   1,      7323,   1,      7324,   1,      7325,   1,
+    7326,   1,      7327,   1,      7328,   1,      7329,   1,      7330,   1,      7331,   1,
+    7332,   1,      7333,   1,      7334,   1,      7335,   1,      7336,   1,      7337,   1,
+    7338,   1,      7339,   1,      7340,   1,      7341,   1,      7342,   1,      7343,   1,
+    7344,   1,      7345,   1,      7346,   1,      7347,   1,      7348,   1,      7349,   1,
+    7350,   1,      7351,   1,      7352,   1,      7353,   1,      7354,   1,      7357,   1,
+    7358,   1,      7359,   1,      5104,   1,      5105,   1,      5106,   1,      5107,   1,
+    5108,   1,      5109,   1,      42570,  1,      42877,  1,      11363,  1,      42950,  1,
+    7680,   1,      7682,   1,      7684,   1,      7686,   1,      7688,   1,      7690,   1,
+    7692,   1,      7694,   1,      7696,   1,      7698,   1,      7700,   1,      7702,   1,
+    7704,   1,      7706,   1,      7708,   1,      7710,   1,      7712,   1,      7714,   1,
+    7716,   1,      7718,   1,      7720,   1,      7722,   1,      7724,   1,      7726,   1,
+    7728,   1,      7730,   1,      7732,   1,      7734,   1,      7736,   1,      7738,   1,
+    7740,   1,      7742,   1,      7744,   1,      7746,   1,      7748,   1,      7750,   1,
+    7752,   1,      7754,   1,      7756,   1,      7758,   1,      7760,   1,      7762,   1,
+    7764,   1,      7766,   1,      7768,   1,      7770,   1,      7772,   1,      7774,   1,
+    7776,   1,      7778,   1,      7780,   1,      7782,   1,      7784,   1,      7786,   1,
+    7788,   1,      7790,   1,      7792,   1,      7794,   1,      7796,   1,      7798,   1,
+    7800,   1,      7802,   1,      7804,   1,      7806,   1,      7808,   1,      7810,   1,
+    7812,   1,      7814,   1,      7816,   1,      7818,   1,      7820,   1,      7822,   1,
+    7824,   1,      7826,   1,      7828,   2,      72,     817,    2,      84,     776,    2,
+    87,     778,    2,      89,     778,    2,      65,     702,    1,      7840,   1,      7842,
+    1,      7844,   1,      7846,   1,      7848,   1,      7850,   1,      7852,   1,      7854,
+    1,      7856,   1,      7858,   1,      7860,   1,      7862,   1,      7864,   1,      7866,
+    1,      7868,   1,      7870,   1,      7872,   1,      7874,   1,      7876,   1,      7878,
+    1,      7880,   1,      7882,   1,      7884,   1,      7886,   1,      7888,   1,      7890,
+    1,      7892,   1,      7894,   1,      7896,   1,      7898,   1,      7900,   1,      7902,
+    1,      7904,   1,      7906,   1,      7908,   1,      7910,   1,      7912,   1,      7914,
+    1,      7916,   1,      7918,   1,      7920,   1,      7922,   1,      7924,   1,      7926,
+    1,      7928,   1,      7930,   1,      7932,   1,      7934,   1,      7944,   1,      7945,
+    1,      7946,   1,      7947,   1,      7948,   1,      7949,   1,      7950,   1,      7951,
+    1,      7960,   1,      7961,   1,      7962,   1,      7963,   1,      7964,   1,      7965,
+    1,      7976,   1,      7977,   1,      7978,   1,      7979,   1,      7980,   1,      7981,
+    1,      7982,   1,      7983,   1,      7992,   1,      7993,   1,      7994,   1,      7995,
+    1,      7996,   1,      7997,   1,      7998,   1,      7999,   1,      8008,   1,      8009,
+    1,      8010,   1,      8011,   1,      8012,   1,      8013,   2,      933,    787,    1,
+    8025,   3,      933,    787,    768,    1,      8027,   3,      933,    787,    769,    1,
+    8029,   3,      933,    787,    834,    1,      8031,   1,      8040,   1,      8041,   1,
+    8042,   1,      8043,   1,      8044,   1,      8045,   1,      8046,   1,      8047,   1,
+    8122,   1,      8123,   1,      8136,   1,      8137,   1,      8138,   1,      8139,   1,
+    8154,   1,      8155,   1,      8184,   1,      8185,   1,      8170,   1,      8171,   1,
+    8186,   1,      8187,   2,      7944,   921,    2,      7945,   921,    2,      7946,   921,
+    2,      7947,   921,    2,      7948,   921,    2,      7949,   921,    2,      7950,   921,
+    2,      7951,   921,    2,      7976,   921,    2,      7977,   921,    2,      7978,   921,
+    2,      7979,   921,    2,      7980,   921,    2,      7981,   921,    2,      7982,   921,
+    2,      7983,   921,    2,      8040,   921,    2,      8041,   921,    2,      8042,   921,
+    2,      8043,   921,    2,      8044,   921,    2,      8045,   921,    2,      8046,   921,
+    2,      8047,   921,    1,      8120,   1,      8121,   2,      8122,   921,    2,      913,
+    921,    2,      902,    921,    2,      913,    834,    3,      913,    834,    921,    2,
+    8138,   921,    2,      919,    921,    2,      905,    921,    2,      919,    834,    3,
+    919,    834,    921,    1,      8152,   1,      8153,   3,      921,    776,    768,    2,
+    921,    834,    3,      921,    776,    834,    1,      8168,   1,      8169,   3,      933,
+    776,    768,    2,      929,    787,    1,      8172,   2,      933,    834,    3,      933,
+    776,    834,    2,      8186,   921,    2,      937,    921,    2,      911,    921,    2,
+    937,    834,    3,      937,    834,    921,    1,      8498,   1,      8544,   1,      8545,
+    1,      8546,   1,      8547,   1,      8548,   1,      8549,   1,      8550,   1,      8551,
+    1,      8552,   1,      8553,   1,      8554,   1,      8555,   1,      8556,   1,      8557,
+    1,      8558,   1,      8559,   1,      8579,   1,      9398,   1,      9399,   1,      9400,
+    1,      9401,   1,      9402,   1,      9403,   1,      9404,   1,      9405,   1,      9406,
+    1,      9407,   1,      9408,   1,      9409,   1,      9410,   1,      9411,   1,      9412,
+    1,      9413,   1,      9414,   1,      9415,   1,      9416,   1,      9417,   1,      9418,
+    1,      9419,   1,      9420,   1,      9421,   1,      9422,   1,      9423,   1,      11264,
+    1,      11265,  1,      11266,  1,      11267,  1,      11268,  1,      11269,  1,      11270,
+    1,      11271,  1,      11272,  1,      11273,  1,      11274,  1,      11275,  1,      11276,
+    1,      11277,  1,      11278,  1,      11279,  1,      11280,  1,      11281,  1,      11282,
+    1,      11283,  1,      11284,  1,      11285,  1,      11286,  1,      11287,  1,      11288,
+    1,      11289,  1,      11290,  1,      11291,  1,      11292,  1,      11293,  1,      11294,
+    1,      11295,  1,      11296,  1,      11297,  1,      11298,  1,      11299,  1,      11300,
+    1,      11301,  1,      11302,  1,      11303,  1,      11304,  1,      11305,  1,      11306,
+    1,      11307,  1,      11308,  1,      11309,  1,      11310,  1,      11360,  1,      570,
+    1,      574,    1,      11367,  1,      11369,  1,      11371,  1,      11378,  1,      11381,
+    1,      11392,  1,      11394,  1,      11396,  1,      11398,  1,      11400,  1,      11402,
+    1,      11404,  1,      11406,  1,      11408,  1,      11410,  1,      11412,  1,      11414,
+    1,      11416,  1,      11418,  1,      11420,  1,      11422,  1,      11424,  1,      11426,
+    1,      11428,  1,      11430,  1,      11432,  1,      11434,  1,      11436,  1,      11438,
+    1,      11440,  1,      11442,  1,      11444,  1,      11446,  1,      11448,  1,      11450,
+    1,      11452,  1,      11454,  1,      11456,  1,      11458,  1,      11460,  1,      11462,
+    1,      11464,  1,      11466,  1,      11468,  1,      11470,  1,      11472,  1,      11474,
+    1,      11476,  1,      11478,  1,      11480,  1,      11482,  1,      11484,  1,      11486,
+    1,      11488,  1,      11490,  1,      11499,  1,      11501,  1,      11506,  1,      4256,
+    1,      4257,   1,      4258,   1,      4259,   1,      4260,   1,      4261,   1,      4262,
+    1,      4263,   1,      4264,   1,      4265,   1,      4266,   1,      4267,   1,      4268,
+    1,      4269,   1,      4270,   1,      4271,   1,      4272,   1,      4273,   1,      4274,
+    1,      4275,   1,      4276,   1,      4277,   1,      4278,   1,      4279,   1,      4280,
+    1,      4281,   1,      4282,   1,      4283,   1,      4284,   1,      4285,   1,      4286,
+    1,      4287,   1,      4288,   1,      4289,   1,      4290,   1,      4291,   1,      4292,
+    1,      4293,   1,      4295,   1,      4301,   1,      42560,  1,      42562,  1,      42564,
+    1,      42566,  1,      42568,  1,      42572,  1,      42574,  1,      42576,  1,      42578,
+    1,      42580,  1,      42582,  1,      42584,  1,      42586,  1,      42588,  1,      42590,
+    1,      42592,  1,      42594,  1,      42596,  1,      42598,  1,      42600,  1,      42602,
+    1,      42604,  1,      42624,  1,      42626,  1,      42628,  1,      42630,  1,      42632,
+    1,      42634,  1,      42636,  1,      42638,  1,      42640,  1,      42642,  1,      42644,
+    1,      42646,  1,      42648,  1,      42650,  1,      42786,  1,      42788,  1,      42790,
+    1,      42792,  1,      42794,  1,      42796,  1,      42798,  1,      42802,  1,      42804,
+    1,      42806,  1,      42808,  1,      42810,  1,      42812,  1,      42814,  1,      42816,
+    1,      42818,  1,      42820,  1,      42822,  1,      42824,  1,      42826,  1,      42828,
+    1,      42830,  1,      42832,  1,      42834,  1,      42836,  1,      42838,  1,      42840,
+    1,      42842,  1,      42844,  1,      42846,  1,      42848,  1,      42850,  1,      42852,
+    1,      42854,  1,      42856,  1,      42858,  1,      42860,  1,      42862,  1,      42873,
+    1,      42875,  1,      42878,  1,      42880,  1,      42882,  1,      42884,  1,      42886,
+    1,      42891,  1,      42896,  1,      42898,  1,      42948,  1,      42902,  1,      42904,
+    1,      42906,  1,      42908,  1,      42910,  1,      42912,  1,      42914,  1,      42916,
+    1,      42918,  1,      42920,  1,      42932,  1,      42934,  1,      42936,  1,      42938,
+    1,      42940,  1,      42942,  1,      42946,  1,      42951,  1,      42953,  1,      42997,
+    1,      42931,  1,      5024,   1,      5025,   1,      5026,   1,      5027,   1,      5028,
+    1,      5029,   1,      5030,   1,      5031,   1,      5032,   1,      5033,   1,      5034,
+    1,      5035,   1,      5036,   1,      5037,   1,      5038,   1,      5039,   1,      5040,
+    1,      5041,   1,      5042,   1,      5043,   1,      5044,   1,      5045,   1,      5046,
+    1,      5047,   1,      5048,   1,      5049,   1,      5050,   1,      5051,   1,      5052,
+    1,      5053,   1,      5054,   1,      5055,   1,      5056,   1,      5057,   1,      5058,
+    1,      5059,   1,      5060,   1,      5061,   1,      5062,   1,      5063,   1,      5064,
+    1,      5065,   1,      5066,   1,      5067,   1,      5068,   1,      5069,   1,      5070,
+    1,      5071,   1,      5072,   1,      5073,   1,      5074,   1,      5075,   1,      5076,
+    1,      5077,   1,      5078,   1,      5079,   1,      5080,   1,      5081,   1,      5082,
+    1,      5083,   1,      5084,   1,      5085,   1,      5086,   1,      5087,   1,      5088,
+    1,      5089,   1,      5090,   1,      5091,   1,      5092,   1,      5093,   1,      5094,
+    1,      5095,   1,      5096,   1,      5097,   1,      5098,   1,      5099,   1,      5100,
+    1,      5101,   1,      5102,   1,      5103,   2,      70,     70,     2,      70,     73,
+    2,      70,     76,     3,      70,     70,     73,     3,      70,     70,     76,     2,
+    83,     84,     2,      1348,   1350,   2,      1348,   1333,   2,      1348,   1339,   2,
+    1358,   1350,   2,      1348,   1341,   1,      65313,  1,      65314,  1,      65315,  1,
+    65316,  1,      65317,  1,      65318,  1,      65319,  1,      65320,  1,      65321,  1,
+    65322,  1,      65323,  1,      65324,  1,      65325,  1,      65326,  1,      65327,  1,
+    65328,  1,      65329,  1,      65330,  1,      65331,  1,      65332,  1,      65333,  1,
+    65334,  1,      65335,  1,      65336,  1,      65337,  1,      65338,  1,      66560,  1,
+    66561,  1,      66562,  1,      66563,  1,      66564,  1,      66565,  1,      66566,  1,
+    66567,  1,      66568,  1,      66569,  1,      66570,  1,      66571,  1,      66572,  1,
+    66573,  1,      66574,  1,      66575,  1,      66576,  1,      66577,  1,      66578,  1,
+    66579,  1,      66580,  1,      66581,  1,      66582,  1,      66583,  1,      66584,  1,
+    66585,  1,      66586,  1,      66587,  1,      66588,  1,      66589,  1,      66590,  1,
+    66591,  1,      66592,  1,      66593,  1,      66594,  1,      66595,  1,      66596,  1,
+    66597,  1,      66598,  1,      66599,  1,      66736,  1,      66737,  1,      66738,  1,
+    66739,  1,      66740,  1,      66741,  1,      66742,  1,      66743,  1,      66744,  1,
+    66745,  1,      66746,  1,      66747,  1,      66748,  1,      66749,  1,      66750,  1,
+    66751,  1,      66752,  1,      66753,  1,      66754,  1,      66755,  1,      66756,  1,
+    66757,  1,      66758,  1,      66759,  1,      66760,  1,      66761,  1,      66762,  1,
+    66763,  1,      66764,  1,      66765,  1,      66766,  1,      66767,  1,      66768,  1,
+    66769,  1,      66770,  1,      66771,  1,      68736,  1,      68737,  1,      68738,  1,
+    68739,  1,      68740,  1,      68741,  1,      68742,  1,      68743,  1,      68744,  1,
+    68745,  1,      68746,  1,      68747,  1,      68748,  1,      68749,  1,      68750,  1,
+    68751,  1,      68752,  1,      68753,  1,      68754,  1,      68755,  1,      68756,  1,
+    68757,  1,      68758,  1,      68759,  1,      68760,  1,      68761,  1,      68762,  1,
+    68763,  1,      68764,  1,      68765,  1,      68766,  1,      68767,  1,      68768,  1,
+    68769,  1,      68770,  1,      68771,  1,      68772,  1,      68773,  1,      68774,  1,
+    68775,  1,      68776,  1,      68777,  1,      68778,  1,      68779,  1,      68780,  1,
+    68781,  1,      68782,  1,      68783,  1,      68784,  1,      68785,  1,      68786,  1,
+    71840,  1,      71841,  1,      71842,  1,      71843,  1,      71844,  1,      71845,  1,
+    71846,  1,      71847,  1,      71848,  1,      71849,  1,      71850,  1,      71851,  1,
+    71852,  1,      71853,  1,      71854,  1,      71855,  1,      71856,  1,      71857,  1,
+    71858,  1,      71859,  1,      71860,  1,      71861,  1,      71862,  1,      71863,  1,
+    71864,  1,      71865,  1,      71866,  1,      71867,  1,      71868,  1,      71869,  1,
+    71870,  1,      71871,  1,      93760,  1,      93761,  1,      93762,  1,      93763,  1,
+    93764,  1,      93765,  1,      93766,  1,      93767,  1,      93768,  1,      93769,  1,
+    93770,  1,      93771,  1,      93772,  1,      93773,  1,      93774,  1,      93775,  1,
+    93776,  1,      93777,  1,      93778,  1,      93779,  1,      93780,  1,      93781,  1,
+    93782,  1,      93783,  1,      93784,  1,      93785,  1,      93786,  1,      93787,  1,
+    93788,  1,      93789,  1,      93790,  1,      93791,  1,      125184, 1,      125185, 1,
+    125186, 1,      125187, 1,      125188, 1,      125189, 1,      125190, 1,      125191, 1,
+    125192, 1,      125193, 1,      125194, 1,      125195, 1,      125196, 1,      125197, 1,
+    125198, 1,      125199, 1,      125200, 1,      125201, 1,      125202, 1,      125203, 1,
+    125204, 1,      125205, 1,      125206, 1,      125207, 1,      125208, 1,      125209, 1,
+    125210, 1,      125211, 1,      125212, 1,      125213, 1,      125214, 1,      125215, 1,
+    125216, 1,      125217, 1,      97,     1,      98,     1,      99,     1,      100,    1,
+    101,    1,      102,    1,      103,    1,      104,    1,      105,    1,      106,    1,
+    107,    1,      108,    1,      109,    1,      110,    1,      111,    1,      112,    1,
+    113,    1,      114,    1,      115,    1,      116,    1,      117,    1,      118,    1,
+    119,    1,      120,    1,      121,    1,      122,    1,      224,    1,      225,    1,
+    226,    1,      227,    1,      228,    1,      229,    1,      230,    1,      231,    1,
+    232,    1,      233,    1,      234,    1,      235,    1,      236,    1,      237,    1,
+    238,    1,      239,    1,      240,    1,      241,    1,      242,    1,      243,    1,
+    244,    1,      245,    1,      246,    1,      248,    1,      249,    1,      250,    1,
+    251,    1,      252,    1,      253,    1,      254,    1,      257,    1,      259,    1,
+    261,    1,      263,    1,      265,    1,      267,    1,      269,    1,      271,    1,
+    273,    1,      275,    1,      277,    1,      279,    1,      281,    1,      283,    1,
+    285,    1,      287,    1,      289,    1,      291,    1,      293,    1,      295,    1,
+    297,    1,      299,    1,      301,    1,      303,    2,      105,    775,    1,      307,
+    1,      309,    1,      311,    1,      314,    1,      316,    1,      318,    1,      320,
+    1,      322,    1,      324,    1,      326,    1,      328,    1,      331,    1,      333,
+    1,      335,    1,      337,    1,      339,    1,      341,    1,      343,    1,      345,
+    1,      347,    1,      349,    1,      351,    1,      353,    1,      355,    1,      357,
+    1,      359,    1,      361,    1,      363,    1,      365,    1,      367,    1,      369,
+    1,      371,    1,      373,    1,      375,    1,      255,    1,      378,    1,      380,
+    1,      382,    1,      595,    1,      387,    1,      389,    1,      596,    1,      392,
+    1,      598,    1,      599,    1,      396,    1,      477,    1,      601,    1,      603,
+    1,      402,    1,      608,    1,      611,    1,      617,    1,      616,    1,      409,
+    1,      623,    1,      626,    1,      629,    1,      417,    1,      419,    1,      421,
+    1,      640,    1,      424,    1,      643,    1,      429,    1,      648,    1,      432,
+    1,      650,    1,      651,    1,      436,    1,      438,    1,      658,    1,      441,
+    1,      445,    1,      454,    1,      457,    1,      460,    1,      462,    1,      464,
+    1,      466,    1,      468,    1,      470,    1,      472,    1,      474,    1,      476,
+    1,      479,    1,      481,    1,      483,    1,      485,    1,      487,    1,      489,
+    1,      491,    1,      493,    1,      495,    1,      499,    1,      501,    1,      405,
+    1,      447,    1,      505,    1,      507,    1,      509,    1,      511,    1,      513,
+    1,      515,    1,      517,    1,      519,    1,      521,    1,      523,    1,      525,
+    1,      527,    1,      529,    1,      531,    1,      533,    1,      535,    1,      537,
+    1,      539,    1,      541,    1,      543,    1,      414,    1,      547,    1,      549,
+    1,      551,    1,      553,    1,      555,    1,      557,    1,      559,    1,      561,
+    1,      563,    1,      11365,  1,      572,    1,      410,    1,      11366,  1,      578,
+    1,      384,    1,      649,    1,      652,    1,      583,    1,      585,    1,      587,
+    1,      589,    1,      591,    1,      881,    1,      883,    1,      887,    1,      1011,
+    1,      940,    1,      941,    1,      942,    1,      943,    1,      972,    1,      973,
+    1,      974,    1,      945,    1,      946,    1,      947,    1,      948,    1,      949,
+    1,      950,    1,      951,    1,      952,    1,      953,    1,      954,    1,      955,
+    1,      956,    1,      957,    1,      958,    1,      959,    1,      960,    1,      961,
+    1,      963,    1,      964,    1,      965,    1,      966,    1,      967,    1,      968,
+    1,      969,    1,      970,    1,      971,    1,      983,    1,      985,    1,      987,
+    1,      989,    1,      991,    1,      993,    1,      995,    1,      997,    1,      999,
+    1,      1001,   1,      1003,   1,      1005,   1,      1007,   1,      1016,   1,      1010,
+    1,      1019,   1,      891,    1,      892,    1,      893,    1,      1104,   1,      1105,
+    1,      1106,   1,      1107,   1,      1108,   1,      1109,   1,      1110,   1,      1111,
+    1,      1112,   1,      1113,   1,      1114,   1,      1115,   1,      1116,   1,      1117,
+    1,      1118,   1,      1119,   1,      1072,   1,      1073,   1,      1074,   1,      1075,
+    1,      1076,   1,      1077,   1,      1078,   1,      1079,   1,      1080,   1,      1081,
+    1,      1082,   1,      1083,   1,      1084,   1,      1085,   1,      1086,   1,      1087,
+    1,      1088,   1,      1089,   1,      1090,   1,      1091,   1,      1092,   1,      1093,
+    1,      1094,   1,      1095,   1,      1096,   1,      1097,   1,      1098,   1,      1099,
+    1,      1100,   1,      1101,   1,      1102,   1,      1103,   1,      1121,   1,      1123,
+    1,      1125,   1,      1127,   1,      1129,   1,      1131,   1,      1133,   1,      1135,
+    1,      1137,   1,      1139,   1,      1141,   1,      1143,   1,      1145,   1,      1147,
+    1,      1149,   1,      1151,   1,      1153,   1,      1163,   1,      1165,   1,      1167,
+    1,      1169,   1,      1171,   1,      1173,   1,      1175,   1,      1177,   1,      1179,
+    1,      1181,   1,      1183,   1,      1185,   1,      1187,   1,      1189,   1,      1191,
+    1,      1193,   1,      1195,   1,      1197,   1,      1199,   1,      1201,   1,      1203,
+    1,      1205,   1,      1207,   1,      1209,   1,      1211,   1,      1213,   1,      1215,
+    1,      1231,   1,      1218,   1,      1220,   1,      1222,   1,      1224,   1,      1226,
+    1,      1228,   1,      1230,   1,      1233,   1,      1235,   1,      1237,   1,      1239,
+    1,      1241,   1,      1243,   1,      1245,   1,      1247,   1,      1249,   1,      1251,
+    1,      1253,   1,      1255,   1,      1257,   1,      1259,   1,      1261,   1,      1263,
+    1,      1265,   1,      1267,   1,      1269,   1,      1271,   1,      1273,   1,      1275,
+    1,      1277,   1,      1279,   1,      1281,   1,      1283,   1,      1285,   1,      1287,
+    1,      1289,   1,      1291,   1,      1293,   1,      1295,   1,      1297,   1,      1299,
+    1,      1301,   1,      1303,   1,      1305,   1,      1307,   1,      1309,   1,      1311,
+    1,      1313,   1,      1315,   1,      1317,   1,      1319,   1,      1321,   1,      1323,
+    1,      1325,   1,      1327,   1,      1377,   1,      1378,   1,      1379,   1,      1380,
+    1,      1381,   1,      1382,   1,      1383,   1,      1384,   1,      1385,   1,      1386,
+    1,      1387,   1,      1388,   1,      1389,   1,      1390,   1,      1391,   1,      1392,
+    1,      1393,   1,      1394,   1,      1395,   1,      1396,   1,      1397,   1,      1398,
+    1,      1399,   1,      1400,   1,      1401,   1,      1402,   1,      1403,   1,      1404,
+    1,      1405,   1,      1406,   1,      1407,   1,      1408,   1,      1409,   1,      1410,
+    1,      1411,   1,      1412,   1,      1413,   1,      1414,   1,      11520,  1,      11521,
+    1,      11522,  1,      11523,  1,      11524,  1,      11525,  1,      11526,  1,      11527,
+    1,      11528,  1,      11529,  1,      11530,  1,      11531,  1,      11532,  1,      11533,
+    1,      11534,  1,      11535,  1,      11536,  1,      11537,  1,      11538,  1,      11539,
+    1,      11540,  1,      11541,  1,      11542,  1,      11543,  1,      11544,  1,      11545,
+    1,      11546,  1,      11547,  1,      11548,  1,      11549,  1,      11550,  1,      11551,
+    1,      11552,  1,      11553,  1,      11554,  1,      11555,  1,      11556,  1,      11557,
+    1,      11559,  1,      11565,  1,      43888,  1,      43889,  1,      43890,  1,      43891,
+    1,      43892,  1,      43893,  1,      43894,  1,      43895,  1,      43896,  1,      43897,
+    1,      43898,  1,      43899,  1,      43900,  1,      43901,  1,      43902,  1,      43903,
+    1,      43904,  1,      43905,  1,      43906,  1,      43907,  1,      43908,  1,      43909,
+    1,      43910,  1,      43911,  1,      43912,  1,      43913,  1,      43914,  1,      43915,
+    1,      43916,  1,      43917,  1,      43918,  1,      43919,  1,      43920,  1,      43921,
+    1,      43922,  1,      43923,  1,      43924,  1,      43925,  1,      43926,  1,      43927,
+    1,      43928,  1,      43929,  1,      43930,  1,      43931,  1,      43932,  1,      43933,
+    1,      43934,  1,      43935,  1,      43936,  1,      43937,  1,      43938,  1,      43939,
+    1,      43940,  1,      43941,  1,      43942,  1,      43943,  1,      43944,  1,      43945,
+    1,      43946,  1,      43947,  1,      43948,  1,      43949,  1,      43950,  1,      43951,
+    1,      43952,  1,      43953,  1,      43954,  1,      43955,  1,      43956,  1,      43957,
+    1,      43958,  1,      43959,  1,      43960,  1,      43961,  1,      43962,  1,      43963,
+    1,      43964,  1,      43965,  1,      43966,  1,      43967,  1,      5112,   1,      5113,
+    1,      5114,   1,      5115,   1,      5116,   1,      5117,   1,      4304,   1,      4305,
+    1,      4306,   1,      4307,   1,      4308,   1,      4309,   1,      4310,   1,      4311,
+    1,      4312,   1,      4313,   1,      4314,   1,      4315,   1,      4316,   1,      4317,
+    1,      4318,   1,      4319,   1,      4320,   1,      4321,   1,      4322,   1,      4323,
+    1,      4324,   1,      4325,   1,      4326,   1,      4327,   1,      4328,   1,      4329,
+    1,      4330,   1,      4331,   1,      4332,   1,      4333,   1,      4334,   1,      4335,
+    1,      4336,   1,      4337,   1,      4338,   1,      4339,   1,      4340,   1,      4341,
+    1,      4342,   1,      4343,   1,      4344,   1,      4345,   1,      4346,   1,      4349,
+    1,      4350,   1,      4351,   1,      7681,   1,      7683,   1,      7685,   1,      7687,
+    1,      7689,   1,      7691,   1,      7693,   1,      7695,   1,      7697,   1,      7699,
+    1,      7701,   1,      7703,   1,      7705,   1,      7707,   1,      7709,   1,      7711,
+    1,      7713,   1,      7715,   1,      7717,   1,      7719,   1,      7721,   1,      7723,
+    1,      7725,   1,      7727,   1,      7729,   1,      7731,   1,      7733,   1,      7735,
+    1,      7737,   1,      7739,   1,      7741,   1,      7743,   1,      7745,   1,      7747,
+    1,      7749,   1,      7751,   1,      7753,   1,      7755,   1,      7757,   1,      7759,
+    1,      7761,   1,      7763,   1,      7765,   1,      7767,   1,      7769,   1,      7771,
+    1,      7773,   1,      7775,   1,      7777,   1,      7779,   1,      7781,   1,      7783,
+    1,      7785,   1,      7787,   1,      7789,   1,      7791,   1,      7793,   1,      7795,
+    1,      7797,   1,      7799,   1,      7801,   1,      7803,   1,      7805,   1,      7807,
+    1,      7809,   1,      7811,   1,      7813,   1,      7815,   1,      7817,   1,      7819,
+    1,      7821,   1,      7823,   1,      7825,   1,      7827,   1,      7829,   1,      223,
+    1,      7841,   1,      7843,   1,      7845,   1,      7847,   1,      7849,   1,      7851,
+    1,      7853,   1,      7855,   1,      7857,   1,      7859,   1,      7861,   1,      7863,
+    1,      7865,   1,      7867,   1,      7869,   1,      7871,   1,      7873,   1,      7875,
+    1,      7877,   1,      7879,   1,      7881,   1,      7883,   1,      7885,   1,      7887,
+    1,      7889,   1,      7891,   1,      7893,   1,      7895,   1,      7897,   1,      7899,
+    1,      7901,   1,      7903,   1,      7905,   1,      7907,   1,      7909,   1,      7911,
+    1,      7913,   1,      7915,   1,      7917,   1,      7919,   1,      7921,   1,      7923,
+    1,      7925,   1,      7927,   1,      7929,   1,      7931,   1,      7933,   1,      7935,
+    1,      7936,   1,      7937,   1,      7938,   1,      7939,   1,      7940,   1,      7941,
+    1,      7942,   1,      7943,   1,      7952,   1,      7953,   1,      7954,   1,      7955,
+    1,      7956,   1,      7957,   1,      7968,   1,      7969,   1,      7970,   1,      7971,
+    1,      7972,   1,      7973,   1,      7974,   1,      7975,   1,      7984,   1,      7985,
+    1,      7986,   1,      7987,   1,      7988,   1,      7989,   1,      7990,   1,      7991,
+    1,      8000,   1,      8001,   1,      8002,   1,      8003,   1,      8004,   1,      8005,
+    1,      8017,   1,      8019,   1,      8021,   1,      8023,   1,      8032,   1,      8033,
+    1,      8034,   1,      8035,   1,      8036,   1,      8037,   1,      8038,   1,      8039,
+    1,      8064,   1,      8065,   1,      8066,   1,      8067,   1,      8068,   1,      8069,
+    1,      8070,   1,      8071,   1,      8080,   1,      8081,   1,      8082,   1,      8083,
+    1,      8084,   1,      8085,   1,      8086,   1,      8087,   1,      8096,   1,      8097,
+    1,      8098,   1,      8099,   1,      8100,   1,      8101,   1,      8102,   1,      8103,
+    1,      8112,   1,      8113,   1,      8048,   1,      8049,   1,      8115,   1,      8050,
+    1,      8051,   1,      8052,   1,      8053,   1,      8131,   1,      8144,   1,      8145,
+    1,      8054,   1,      8055,   1,      8160,   1,      8161,   1,      8058,   1,      8059,
+    1,      8165,   1,      8056,   1,      8057,   1,      8060,   1,      8061,   1,      8179,
+    1,      8526,   1,      8560,   1,      8561,   1,      8562,   1,      8563,   1,      8564,
+    1,      8565,   1,      8566,   1,      8567,   1,      8568,   1,      8569,   1,      8570,
+    1,      8571,   1,      8572,   1,      8573,   1,      8574,   1,      8575,   1,      8580,
+    1,      9424,   1,      9425,   1,      9426,   1,      9427,   1,      9428,   1,      9429,
+    1,      9430,   1,      9431,   1,      9432,   1,      9433,   1,      9434,   1,      9435,
+    1,      9436,   1,      9437,   1,      9438,   1,      9439,   1,      9440,   1,      9441,
+    1,      9442,   1,      9443,   1,      9444,   1,      9445,   1,      9446,   1,      9447,
+    1,      9448,   1,      9449,   1,      11312,  1,      11313,  1,      11314,  1,      11315,
+    1,      11316,  1,      11317,  1,      11318,  1,      11319,  1,      11320,  1,      11321,
+    1,      11322,  1,      11323,  1,      11324,  1,      11325,  1,      11326,  1,      11327,
+    1,      11328,  1,      11329,  1,      11330,  1,      11331,  1,      11332,  1,      11333,
+    1,      11334,  1,      11335,  1,      11336,  1,      11337,  1,      11338,  1,      11339,
+    1,      11340,  1,      11341,  1,      11342,  1,      11343,  1,      11344,  1,      11345,
+    1,      11346,  1,      11347,  1,      11348,  1,      11349,  1,      11350,  1,      11351,
+    1,      11352,  1,      11353,  1,      11354,  1,      11355,  1,      11356,  1,      11357,
+    1,      11358,  1,      11361,  1,      619,    1,      7549,   1,      637,    1,      11368,
+    1,      11370,  1,      11372,  1,      593,    1,      625,    1,      592,    1,      594,
+    1,      11379,  1,      11382,  1,      575,    1,      576,    1,      11393,  1,      11395,
+    1,      11397,  1,      11399,  1,      11401,  1,      11403,  1,      11405,  1,      11407,
+    1,      11409,  1,      11411,  1,      11413,  1,      11415,  1,      11417,  1,      11419,
+    1,      11421,  1,      11423,  1,      11425,  1,      11427,  1,      11429,  1,      11431,
+    1,      11433,  1,      11435,  1,      11437,  1,      11439,  1,      11441,  1,      11443,
+    1,      11445,  1,      11447,  1,      11449,  1,      11451,  1,      11453,  1,      11455,
+    1,      11457,  1,      11459,  1,      11461,  1,      11463,  1,      11465,  1,      11467,
+    1,      11469,  1,      11471,  1,      11473,  1,      11475,  1,      11477,  1,      11479,
+    1,      11481,  1,      11483,  1,      11485,  1,      11487,  1,      11489,  1,      11491,
+    1,      11500,  1,      11502,  1,      11507,  1,      42561,  1,      42563,  1,      42565,
+    1,      42567,  1,      42569,  1,      42571,  1,      42573,  1,      42575,  1,      42577,
+    1,      42579,  1,      42581,  1,      42583,  1,      42585,  1,      42587,  1,      42589,
+    1,      42591,  1,      42593,  1,      42595,  1,      42597,  1,      42599,  1,      42601,
+    1,      42603,  1,      42605,  1,      42625,  1,      42627,  1,      42629,  1,      42631,
+    1,      42633,  1,      42635,  1,      42637,  1,      42639,  1,      42641,  1,      42643,
+    1,      42645,  1,      42647,  1,      42649,  1,      42651,  1,      42787,  1,      42789,
+    1,      42791,  1,      42793,  1,      42795,  1,      42797,  1,      42799,  1,      42803,
+    1,      42805,  1,      42807,  1,      42809,  1,      42811,  1,      42813,  1,      42815,
+    1,      42817,  1,      42819,  1,      42821,  1,      42823,  1,      42825,  1,      42827,
+    1,      42829,  1,      42831,  1,      42833,  1,      42835,  1,      42837,  1,      42839,
+    1,      42841,  1,      42843,  1,      42845,  1,      42847,  1,      42849,  1,      42851,
+    1,      42853,  1,      42855,  1,      42857,  1,      42859,  1,      42861,  1,      42863,
+    1,      42874,  1,      42876,  1,      7545,   1,      42879,  1,      42881,  1,      42883,
+    1,      42885,  1,      42887,  1,      42892,  1,      613,    1,      42897,  1,      42899,
+    1,      42903,  1,      42905,  1,      42907,  1,      42909,  1,      42911,  1,      42913,
+    1,      42915,  1,      42917,  1,      42919,  1,      42921,  1,      614,    1,      604,
+    1,      609,    1,      620,    1,      618,    1,      670,    1,      647,    1,      669,
+    1,      43859,  1,      42933,  1,      42935,  1,      42937,  1,      42939,  1,      42941,
+    1,      42943,  1,      42947,  1,      42900,  1,      642,    1,      7566,   1,      42952,
+    1,      42954,  1,      42998,  1,      65345,  1,      65346,  1,      65347,  1,      65348,
+    1,      65349,  1,      65350,  1,      65351,  1,      65352,  1,      65353,  1,      65354,
+    1,      65355,  1,      65356,  1,      65357,  1,      65358,  1,      65359,  1,      65360,
+    1,      65361,  1,      65362,  1,      65363,  1,      65364,  1,      65365,  1,      65366,
+    1,      65367,  1,      65368,  1,      65369,  1,      65370,  1,      66600,  1,      66601,
+    1,      66602,  1,      66603,  1,      66604,  1,      66605,  1,      66606,  1,      66607,
+    1,      66608,  1,      66609,  1,      66610,  1,      66611,  1,      66612,  1,      66613,
+    1,      66614,  1,      66615,  1,      66616,  1,      66617,  1,      66618,  1,      66619,
+    1,      66620,  1,      66621,  1,      66622,  1,      66623,  1,      66624,  1,      66625,
+    1,      66626,  1,      66627,  1,      66628,  1,      66629,  1,      66630,  1,      66631,
+    1,      66632,  1,      66633,  1,      66634,  1,      66635,  1,      66636,  1,      66637,
+    1,      66638,  1,      66639,  1,      66776,  1,      66777,  1,      66778,  1,      66779,
+    1,      66780,  1,      66781,  1,      66782,  1,      66783,  1,      66784,  1,      66785,
+    1,      66786,  1,      66787,  1,      66788,  1,      66789,  1,      66790,  1,      66791,
+    1,      66792,  1,      66793,  1,      66794,  1,      66795,  1,      66796,  1,      66797,
+    1,      66798,  1,      66799,  1,      66800,  1,      66801,  1,      66802,  1,      66803,
+    1,      66804,  1,      66805,  1,      66806,  1,      66807,  1,      66808,  1,      66809,
+    1,      66810,  1,      66811,  1,      68800,  1,      68801,  1,      68802,  1,      68803,
+    1,      68804,  1,      68805,  1,      68806,  1,      68807,  1,      68808,  1,      68809,
+    1,      68810,  1,      68811,  1,      68812,  1,      68813,  1,      68814,  1,      68815,
+    1,      68816,  1,      68817,  1,      68818,  1,      68819,  1,      68820,  1,      68821,
+    1,      68822,  1,      68823,  1,      68824,  1,      68825,  1,      68826,  1,      68827,
+    1,      68828,  1,      68829,  1,      68830,  1,      68831,  1,      68832,  1,      68833,
+    1,      68834,  1,      68835,  1,      68836,  1,      68837,  1,      68838,  1,      68839,
+    1,      68840,  1,      68841,  1,      68842,  1,      68843,  1,      68844,  1,      68845,
+    1,      68846,  1,      68847,  1,      68848,  1,      68849,  1,      68850,  1,      71872,
+    1,      71873,  1,      71874,  1,      71875,  1,      71876,  1,      71877,  1,      71878,
+    1,      71879,  1,      71880,  1,      71881,  1,      71882,  1,      71883,  1,      71884,
+    1,      71885,  1,      71886,  1,      71887,  1,      71888,  1,      71889,  1,      71890,
+    1,      71891,  1,      71892,  1,      71893,  1,      71894,  1,      71895,  1,      71896,
+    1,      71897,  1,      71898,  1,      71899,  1,      71900,  1,      71901,  1,      71902,
+    1,      71903,  1,      93792,  1,      93793,  1,      93794,  1,      93795,  1,      93796,
+    1,      93797,  1,      93798,  1,      93799,  1,      93800,  1,      93801,  1,      93802,
+    1,      93803,  1,      93804,  1,      93805,  1,      93806,  1,      93807,  1,      93808,
+    1,      93809,  1,      93810,  1,      93811,  1,      93812,  1,      93813,  1,      93814,
+    1,      93815,  1,      93816,  1,      93817,  1,      93818,  1,      93819,  1,      93820,
+    1,      93821,  1,      93822,  1,      93823,  1,      125218, 1,      125219, 1,      125220,
+    1,      125221, 1,      125222, 1,      125223, 1,      125224, 1,      125225, 1,      125226,
+    1,      125227, 1,      125228, 1,      125229, 1,      125230, 1,      125231, 1,      125232,
+    1,      125233, 1,      125234, 1,      125235, 1,      125236, 1,      125237, 1,      125238,
+    1,      125239, 1,      125240, 1,      125241, 1,      125242, 1,      125243, 1,      125244,
+    1,      125245, 1,      125246, 1,      125247, 1,      125248, 1,      125249, 1,      125250,
+    1,      125251, 2,      115,    115,    2,      700,    110,    2,      106,    780,    3,
+    953,    776,    769,    3,      965,    776,    769,    2,      1381,   1410,   2,      104,
+    817,    2,      116,    776,    2,      119,    778,    2,      121,    778,    2,      97,
+    702,    2,      965,    787,    3,      965,    787,    768,    3,      965,    787,    769,
+    3,      965,    787,    834,    2,      7936,   953,    2,      7937,   953,    2,      7938,
+    953,    2,      7939,   953,    2,      7940,   953,    2,      7941,   953,    2,      7942,
+    953,    2,      7943,   953,    2,      7968,   953,    2,      7969,   953,    2,      7970,
+    953,    2,      7971,   953,    2,      7972,   953,    2,      7973,   953,    2,      7974,
+    953,    2,      7975,   953,    2,      8032,   953,    2,      8033,   953,    2,      8034,
+    953,    2,      8035,   953,    2,      8036,   953,    2,      8037,   953,    2,      8038,
+    953,    2,      8039,   953,    2,      8048,   953,    2,      945,    953,    2,      940,
+    953,    2,      945,    834,    3,      945,    834,    953,    2,      8052,   953,    2,
+    951,    953,    2,      942,    953,    2,      951,    834,    3,      951,    834,    953,
+    3,      953,    776,    768,    2,      953,    834,    3,      953,    776,    834,    3,
+    965,    776,    768,    2,      961,    787,    2,      965,    834,    3,      965,    776,
+    834,    2,      8060,   953,    2,      969,    953,    2,      974,    953,    2,      969,
+    834,    3,      969,    834,    953,    2,      102,    102,    2,      102,    105,    2,
+    102,    108,    3,      102,    102,    105,    3,      102,    102,    108,    2,      115,
+    116,    2,      1396,   1398,   2,      1396,   1381,   2,      1396,   1387,   2,      1406,
+    1398,   2,      1396,   1389,   2,      65,     768,    2,      65,     769,    2,      65,
+    770,    2,      65,     771,    2,      65,     776,    2,      65,     778,    2,      67,
+    807,    2,      69,     768,    2,      69,     769,    2,      69,     770,    2,      69,
+    776,    2,      73,     768,    2,      73,     769,    2,      73,     770,    2,      73,
+    776,    2,      78,     771,    2,      79,     768,    2,      79,     769,    2,      79,
+    770,    2,      79,     771,    2,      79,     776,    2,      85,     768,    2,      85,
+    769,    2,      85,     770,    2,      85,     776,    2,      89,     769,    2,      97,
+    768,    2,      97,     769,    2,      97,     770,    2,      97,     771,    2,      97,
+    776,    2,      97,     778,    2,      99,     807,    2,      101,    768,    2,      101,
+    769,    2,      101,    770,    2,      101,    776,    2,      105,    768,    2,      105,
+    769,    2,      105,    770,    2,      105,    776,    2,      110,    771,    2,      111,
+    768,    2,      111,    769,    2,      111,    770,    2,      111,    771,    2,      111,
+    776,    2,      117,    768,    2,      117,    769,    2,      117,    770,    2,      117,
+    776,    2,      121,    769,    2,      121,    776,    2,      65,     772,    2,      97,
+    772,    2,      65,     774,    2,      97,     774,    2,      65,     808,    2,      97,
+    808,    2,      67,     769,    2,      99,     769,    2,      67,     770,    2,      99,
+    770,    2,      67,     775,    2,      99,     775,    2,      67,     780,    2,      99,
+    780,    2,      68,     780,    2,      100,    780,    2,      69,     772,    2,      101,
+    772,    2,      69,     774,    2,      101,    774,    2,      69,     775,    2,      101,
+    775,    2,      69,     808,    2,      101,    808,    2,      69,     780,    2,      101,
+    780,    2,      71,     770,    2,      103,    770,    2,      71,     774,    2,      103,
+    774,    2,      71,     775,    2,      103,    775,    2,      71,     807,    2,      103,
+    807,    2,      72,     770,    2,      104,    770,    2,      73,     771,    2,      105,
+    771,    2,      73,     772,    2,      105,    772,    2,      73,     774,    2,      105,
+    774,    2,      73,     808,    2,      105,    808,    2,      73,     775,    2,      74,
+    770,    2,      106,    770,    2,      75,     807,    2,      107,    807,    2,      76,
+    769,    2,      108,    769,    2,      76,     807,    2,      108,    807,    2,      76,
+    780,    2,      108,    780,    2,      78,     769,    2,      110,    769,    2,      78,
+    807,    2,      110,    807,    2,      78,     780,    2,      110,    780,    2,      79,
+    772,    2,      111,    772,    2,      79,     774,    2,      111,    774,    2,      79,
+    779,    2,      111,    779,    2,      82,     769,    2,      114,    769,    2,      82,
+    807,    2,      114,    807,    2,      82,     780,    2,      114,    780,    2,      83,
+    769,    2,      115,    769,    2,      83,     770,    2,      115,    770,    2,      83,
+    807,    2,      115,    807,    2,      83,     780,    2,      115,    780,    2,      84,
+    807,    2,      116,    807,    2,      84,     780,    2,      116,    780,    2,      85,
+    771,    2,      117,    771,    2,      85,     772,    2,      117,    772,    2,      85,
+    774,    2,      117,    774,    2,      85,     778,    2,      117,    778,    2,      85,
+    779,    2,      117,    779,    2,      85,     808,    2,      117,    808,    2,      87,
+    770,    2,      119,    770,    2,      89,     770,    2,      121,    770,    2,      89,
+    776,    2,      90,     769,    2,      122,    769,    2,      90,     775,    2,      122,
+    775,    2,      90,     780,    2,      122,    780,    2,      79,     795,    2,      111,
+    795,    2,      85,     795,    2,      117,    795,    2,      65,     780,    2,      97,
+    780,    2,      73,     780,    2,      105,    780,    2,      79,     780,    2,      111,
+    780,    2,      85,     780,    2,      117,    780,    3,      85,     776,    772,    3,
+    117,    776,    772,    3,      85,     776,    769,    3,      117,    776,    769,    3,
+    85,     776,    780,    3,      117,    776,    780,    3,      85,     776,    768,    3,
+    117,    776,    768,    3,      65,     776,    772,    3,      97,     776,    772,    3,
+    65,     775,    772,    3,      97,     775,    772,    2,      198,    772,    2,      230,
+    772,    2,      71,     780,    2,      103,    780,    2,      75,     780,    2,      107,
+    780,    2,      79,     808,    2,      111,    808,    3,      79,     808,    772,    3,
+    111,    808,    772,    2,      439,    780,    2,      658,    780,    2,      71,     769,
+    2,      103,    769,    2,      78,     768,    2,      110,    768,    3,      65,     778,
+    769,    3,      97,     778,    769,    2,      198,    769,    2,      230,    769,    2,
+    216,    769,    2,      248,    769,    2,      65,     783,    2,      97,     783,    2,
+    65,     785,    2,      97,     785,    2,      69,     783,    2,      101,    783,    2,
+    69,     785,    2,      101,    785,    2,      73,     783,    2,      105,    783,    2,
+    73,     785,    2,      105,    785,    2,      79,     783,    2,      111,    783,    2,
+    79,     785,    2,      111,    785,    2,      82,     783,    2,      114,    783,    2,
+    82,     785,    2,      114,    785,    2,      85,     783,    2,      117,    783,    2,
+    85,     785,    2,      117,    785,    2,      83,     806,    2,      115,    806,    2,
+    84,     806,    2,      116,    806,    2,      72,     780,    2,      104,    780,    2,
+    65,     775,    2,      97,     775,    2,      69,     807,    2,      101,    807,    3,
+    79,     776,    772,    3,      111,    776,    772,    3,      79,     771,    772,    3,
+    111,    771,    772,    2,      79,     775,    2,      111,    775,    3,      79,     775,
+    772,    3,      111,    775,    772,    2,      89,     772,    2,      121,    772,    1,
+    768,    1,      769,    1,      787,    2,      776,    769,    1,      697,    1,      59,
+    2,      168,    769,    2,      913,    769,    1,      183,    2,      917,    769,    2,
+    919,    769,    2,      921,    769,    2,      927,    769,    2,      933,    769,    2,
+    937,    769,    2,      921,    776,    2,      933,    776,    2,      945,    769,    2,
+    949,    769,    2,      951,    769,    2,      953,    769,    2,      953,    776,    2,
+    965,    776,    2,      959,    769,    2,      965,    769,    2,      969,    769,    2,
+    978,    769,    2,      978,    776,    2,      1045,   768,    2,      1045,   776,    2,
+    1043,   769,    2,      1030,   776,    2,      1050,   769,    2,      1048,   768,    2,
+    1059,   774,    2,      1048,   774,    2,      1080,   774,    2,      1077,   768,    2,
+    1077,   776,    2,      1075,   769,    2,      1110,   776,    2,      1082,   769,    2,
+    1080,   768,    2,      1091,   774,    2,      1140,   783,    2,      1141,   783,    2,
+    1046,   774,    2,      1078,   774,    2,      1040,   774,    2,      1072,   774,    2,
+    1040,   776,    2,      1072,   776,    2,      1045,   774,    2,      1077,   774,    2,
+    1240,   776,    2,      1241,   776,    2,      1046,   776,    2,      1078,   776,    2,
+    1047,   776,    2,      1079,   776,    2,      1048,   772,    2,      1080,   772,    2,
+    1048,   776,    2,      1080,   776,    2,      1054,   776,    2,      1086,   776,    2,
+    1256,   776,    2,      1257,   776,    2,      1069,   776,    2,      1101,   776,    2,
+    1059,   772,    2,      1091,   772,    2,      1059,   776,    2,      1091,   776,    2,
+    1059,   779,    2,      1091,   779,    2,      1063,   776,    2,      1095,   776,    2,
+    1067,   776,    2,      1099,   776,    2,      1575,   1619,   2,      1575,   1620,   2,
+    1608,   1620,   2,      1575,   1621,   2,      1610,   1620,   2,      1749,   1620,   2,
+    1729,   1620,   2,      1746,   1620,   2,      2344,   2364,   2,      2352,   2364,   2,
+    2355,   2364,   2,      2325,   2364,   2,      2326,   2364,   2,      2327,   2364,   2,
+    2332,   2364,   2,      2337,   2364,   2,      2338,   2364,   2,      2347,   2364,   2,
+    2351,   2364,   2,      2503,   2494,   2,      2503,   2519,   2,      2465,   2492,   2,
+    2466,   2492,   2,      2479,   2492,   2,      2610,   2620,   2,      2616,   2620,   2,
+    2582,   2620,   2,      2583,   2620,   2,      2588,   2620,   2,      2603,   2620,   2,
+    2887,   2902,   2,      2887,   2878,   2,      2887,   2903,   2,      2849,   2876,   2,
+    2850,   2876,   2,      2962,   3031,   2,      3014,   3006,   2,      3015,   3006,   2,
+    3014,   3031,   2,      3142,   3158,   2,      3263,   3285,   2,      3270,   3285,   2,
+    3270,   3286,   2,      3270,   3266,   3,      3270,   3266,   3285,   2,      3398,   3390,
+    2,      3399,   3390,   2,      3398,   3415,   2,      3545,   3530,   2,      3545,   3535,
+    3,      3545,   3535,   3530,   2,      3545,   3551,   2,      3906,   4023,   2,      3916,
+    4023,   2,      3921,   4023,   2,      3926,   4023,   2,      3931,   4023,   2,      3904,
+    4021,   2,      3953,   3954,   2,      3953,   3956,   2,      4018,   3968,   2,      4019,
+    3968,   2,      3953,   3968,   2,      3986,   4023,   2,      3996,   4023,   2,      4001,
+    4023,   2,      4006,   4023,   2,      4011,   4023,   2,      3984,   4021,   2,      4133,
+    4142,   2,      6917,   6965,   2,      6919,   6965,   2,      6921,   6965,   2,      6923,
+    6965,   2,      6925,   6965,   2,      6929,   6965,   2,      6970,   6965,   2,      6972,
+    6965,   2,      6974,   6965,   2,      6975,   6965,   2,      6978,   6965,   2,      65,
+    805,    2,      97,     805,    2,      66,     775,    2,      98,     775,    2,      66,
+    803,    2,      98,     803,    2,      66,     817,    2,      98,     817,    3,      67,
+    807,    769,    3,      99,     807,    769,    2,      68,     775,    2,      100,    775,
+    2,      68,     803,    2,      100,    803,    2,      68,     817,    2,      100,    817,
+    2,      68,     807,    2,      100,    807,    2,      68,     813,    2,      100,    813,
+    3,      69,     772,    768,    3,      101,    772,    768,    3,      69,     772,    769,
+    3,      101,    772,    769,    2,      69,     813,    2,      101,    813,    2,      69,
+    816,    2,      101,    816,    3,      69,     807,    774,    3,      101,    807,    774,
+    2,      70,     775,    2,      102,    775,    2,      71,     772,    2,      103,    772,
+    2,      72,     775,    2,      104,    775,    2,      72,     803,    2,      104,    803,
+    2,      72,     776,    2,      104,    776,    2,      72,     807,    2,      104,    807,
+    2,      72,     814,    2,      104,    814,    2,      73,     816,    2,      105,    816,
+    3,      73,     776,    769,    3,      105,    776,    769,    2,      75,     769,    2,
+    107,    769,    2,      75,     803,    2,      107,    803,    2,      75,     817,    2,
+    107,    817,    2,      76,     803,    2,      108,    803,    3,      76,     803,    772,
+    3,      108,    803,    772,    2,      76,     817,    2,      108,    817,    2,      76,
+    813,    2,      108,    813,    2,      77,     769,    2,      109,    769,    2,      77,
+    775,    2,      109,    775,    2,      77,     803,    2,      109,    803,    2,      78,
+    775,    2,      110,    775,    2,      78,     803,    2,      110,    803,    2,      78,
+    817,    2,      110,    817,    2,      78,     813,    2,      110,    813,    3,      79,
+    771,    769,    3,      111,    771,    769,    3,      79,     771,    776,    3,      111,
+    771,    776,    3,      79,     772,    768,    3,      111,    772,    768,    3,      79,
+    772,    769,    3,      111,    772,    769,    2,      80,     769,    2,      112,    769,
+    2,      80,     775,    2,      112,    775,    2,      82,     775,    2,      114,    775,
+    2,      82,     803,    2,      114,    803,    3,      82,     803,    772,    3,      114,
+    803,    772,    2,      82,     817,    2,      114,    817,    2,      83,     775,    2,
+    115,    775,    2,      83,     803,    2,      115,    803,    3,      83,     769,    775,
+    3,      115,    769,    775,    3,      83,     780,    775,    3,      115,    780,    775,
+    3,      83,     803,    775,    3,      115,    803,    775,    2,      84,     775,    2,
+    116,    775,    2,      84,     803,    2,      116,    803,    2,      84,     817,    2,
+    116,    817,    2,      84,     813,    2,      116,    813,    2,      85,     804,    2,
+    117,    804,    2,      85,     816,    2,      117,    816,    2,      85,     813,    2,
+    117,    813,    3,      85,     771,    769,    3,      117,    771,    769,    3,      85,
+    772,    776,    3,      117,    772,    776,    2,      86,     771,    2,      118,    771,
+    2,      86,     803,    2,      118,    803,    2,      87,     768,    2,      119,    768,
+    2,      87,     769,    2,      119,    769,    2,      87,     776,    2,      119,    776,
+    2,      87,     775,    2,      119,    775,    2,      87,     803,    2,      119,    803,
+    2,      88,     775,    2,      120,    775,    2,      88,     776,    2,      120,    776,
+    2,      89,     775,    2,      121,    775,    2,      90,     770,    2,      122,    770,
+    2,      90,     803,    2,      122,    803,    2,      90,     817,    2,      122,    817,
+    2,      383,    775,    2,      65,     803,    2,      97,     803,    2,      65,     777,
+    2,      97,     777,    3,      65,     770,    769,    3,      97,     770,    769,    3,
+    65,     770,    768,    3,      97,     770,    768,    3,      65,     770,    777,    3,
+    97,     770,    777,    3,      65,     770,    771,    3,      97,     770,    771,    3,
+    65,     803,    770,    3,      97,     803,    770,    3,      65,     774,    769,    3,
+    97,     774,    769,    3,      65,     774,    768,    3,      97,     774,    768,    3,
+    65,     774,    777,    3,      97,     774,    777,    3,      65,     774,    771,    3,
+    97,     774,    771,    3,      65,     803,    774,    3,      97,     803,    774,    2,
+    69,     803,    2,      101,    803,    2,      69,     777,    2,      101,    777,    2,
+    69,     771,    2,      101,    771,    3,      69,     770,    769,    3,      101,    770,
+    769,    3,      69,     770,    768,    3,      101,    770,    768,    3,      69,     770,
+    777,    3,      101,    770,    777,    3,      69,     770,    771,    3,      101,    770,
+    771,    3,      69,     803,    770,    3,      101,    803,    770,    2,      73,     777,
+    2,      105,    777,    2,      73,     803,    2,      105,    803,    2,      79,     803,
+    2,      111,    803,    2,      79,     777,    2,      111,    777,    3,      79,     770,
+    769,    3,      111,    770,    769,    3,      79,     770,    768,    3,      111,    770,
+    768,    3,      79,     770,    777,    3,      111,    770,    777,    3,      79,     770,
+    771,    3,      111,    770,    771,    3,      79,     803,    770,    3,      111,    803,
+    770,    3,      79,     795,    769,    3,      111,    795,    769,    3,      79,     795,
+    768,    3,      111,    795,    768,    3,      79,     795,    777,    3,      111,    795,
+    777,    3,      79,     795,    771,    3,      111,    795,    771,    3,      79,     795,
+    803,    3,      111,    795,    803,    2,      85,     803,    2,      117,    803,    2,
+    85,     777,    2,      117,    777,    3,      85,     795,    769,    3,      117,    795,
+    769,    3,      85,     795,    768,    3,      117,    795,    768,    3,      85,     795,
+    777,    3,      117,    795,    777,    3,      85,     795,    771,    3,      117,    795,
+    771,    3,      85,     795,    803,    3,      117,    795,    803,    2,      89,     768,
+    2,      121,    768,    2,      89,     803,    2,      121,    803,    2,      89,     777,
+    2,      121,    777,    2,      89,     771,    2,      121,    771,    2,      945,    787,
+    2,      945,    788,    3,      945,    787,    768,    3,      945,    788,    768,    3,
+    945,    787,    769,    3,      945,    788,    769,    3,      945,    787,    834,    3,
+    945,    788,    834,    2,      913,    787,    2,      913,    788,    3,      913,    787,
+    768,    3,      913,    788,    768,    3,      913,    787,    769,    3,      913,    788,
+    769,    3,      913,    787,    834,    3,      913,    788,    834,    2,      949,    787,
+    2,      949,    788,    3,      949,    787,    768,    3,      949,    788,    768,    3,
+    949,    787,    769,    3,      949,    788,    769,    2,      917,    787,    2,      917,
+    788,    3,      917,    787,    768,    3,      917,    788,    768,    3,      917,    787,
+    769,    3,      917,    788,    769,    2,      951,    787,    2,      951,    788,    3,
+    951,    787,    768,    3,      951,    788,    768,    3,      951,    787,    769,    3,
+    951,    788,    769,    3,      951,    787,    834,    3,      951,    788,    834,    2,
+    919,    787,    2,      919,    788,    3,      919,    787,    768,    3,      919,    788,
+    768,    3,      919,    787,    769,    3,      919,    788,    769,    3,      919,    787,
+    834,    3,      919,    788,    834,    2,      953,    787,    2,      953,    788,    3,
+    953,    787,    768,    3,      953,    788,    768,    3,      953,    787,    769,    3,
+    953,    788,    769,    3,      953,    787,    834,    3,      953,    788,    834,    2,
+    921,    787,    2,      921,    788,    3,      921,    787,    768,    3,      921,    788,
+    768,    3,      921,    787,    769,    3,      921,    788,    769,    3,      921,    787,
+    834,    3,      921,    788,    834,    2,      959,    787,    2,      959,    788,    3,
+    959,    787,    768,    3,      959,    788,    768,    3,      959,    787,    769,    3,
+    959,    788,    769,    2,      927,    787,    2,      927,    788,    3,      927,    787,
+    768,    3,      927,    788,    768,    3,      927,    787,    769,    3,      927,    788,
+    769,    2,      965,    788,    3,      965,    788,    768,    3,      965,    788,    769,
+    3,      965,    788,    834,    2,      933,    788,    3,      933,    788,    768,    3,
+    933,    788,    769,    3,      933,    788,    834,    2,      969,    787,    2,      969,
+    788,    3,      969,    787,    768,    3,      969,    788,    768,    3,      969,    787,
+    769,    3,      969,    788,    769,    3,      969,    787,    834,    3,      969,    788,
+    834,    2,      937,    787,    2,      937,    788,    3,      937,    787,    768,    3,
+    937,    788,    768,    3,      937,    787,    769,    3,      937,    788,    769,    3,
+    937,    787,    834,    3,      937,    788,    834,    2,      945,    768,    2,      949,
+    768,    2,      951,    768,    2,      953,    768,    2,      959,    768,    2,      965,
+    768,    2,      969,    768,    3,      945,    787,    837,    3,      945,    788,    837,
+    4,      945,    787,    768,    837,    4,      945,    788,    768,    837,    4,      945,
+    787,    769,    837,    4,      945,    788,    769,    837,    4,      945,    787,    834,
+    837,    4,      945,    788,    834,    837,    3,      913,    787,    837,    3,      913,
+    788,    837,    4,      913,    787,    768,    837,    4,      913,    788,    768,    837,
+    4,      913,    787,    769,    837,    4,      913,    788,    769,    837,    4,      913,
+    787,    834,    837,    4,      913,    788,    834,    837,    3,      951,    787,    837,
+    3,      951,    788,    837,    4,      951,    787,    768,    837,    4,      951,    788,
+    768,    837,    4,      951,    787,    769,    837,    4,      951,    788,    769,    837,
+    4,      951,    787,    834,    837,    4,      951,    788,    834,    837,    3,      919,
+    787,    837,    3,      919,    788,    837,    4,      919,    787,    768,    837,    4,
+    919,    788,    768,    837,    4,      919,    787,    769,    837,    4,      919,    788,
+    769,    837,    4,      919,    787,    834,    837,    4,      919,    788,    834,    837,
+    3,      969,    787,    837,    3,      969,    788,    837,    4,      969,    787,    768,
+    837,    4,      969,    788,    768,    837,    4,      969,    787,    769,    837,    4,
+    969,    788,    769,    837,    4,      969,    787,    834,    837,    4,      969,    788,
+    834,    837,    3,      937,    787,    837,    3,      937,    788,    837,    4,      937,
+    787,    768,    837,    4,      937,    788,    768,    837,    4,      937,    787,    769,
+    837,    4,      937,    788,    769,    837,    4,      937,    787,    834,    837,    4,
+    937,    788,    834,    837,    2,      945,    774,    2,      945,    772,    3,      945,
+    768,    837,    2,      945,    837,    3,      945,    769,    837,    3,      945,    834,
+    837,    2,      913,    774,    2,      913,    772,    2,      913,    768,    2,      913,
+    837,    2,      168,    834,    3,      951,    768,    837,    2,      951,    837,    3,
+    951,    769,    837,    3,      951,    834,    837,    2,      917,    768,    2,      919,
+    768,    2,      919,    837,    2,      8127,   768,    2,      8127,   769,    2,      8127,
+    834,    2,      953,    774,    2,      953,    772,    2,      921,    774,    2,      921,
+    772,    2,      921,    768,    2,      8190,   768,    2,      8190,   769,    2,      8190,
+    834,    2,      965,    774,    2,      965,    772,    2,      961,    788,    2,      933,
+    774,    2,      933,    772,    2,      933,    768,    2,      929,    788,    2,      168,
+    768,    1,      96,     3,      969,    768,    837,    2,      969,    837,    3,      969,
+    769,    837,    3,      969,    834,    837,    2,      927,    768,    2,      937,    768,
+    2,      937,    837,    1,      180,    1,      8194,   1,      8195,   2,      8592,   824,
+    2,      8594,   824,    2,      8596,   824,    2,      8656,   824,    2,      8660,   824,
+    2,      8658,   824,    2,      8707,   824,    2,      8712,   824,    2,      8715,   824,
+    2,      8739,   824,    2,      8741,   824,    2,      8764,   824,    2,      8771,   824,
+    2,      8773,   824,    2,      8776,   824,    2,      61,     824,    2,      8801,   824,
+    2,      8781,   824,    2,      60,     824,    2,      62,     824,    2,      8804,   824,
+    2,      8805,   824,    2,      8818,   824,    2,      8819,   824,    2,      8822,   824,
+    2,      8823,   824,    2,      8826,   824,    2,      8827,   824,    2,      8834,   824,
+    2,      8835,   824,    2,      8838,   824,    2,      8839,   824,    2,      8866,   824,
+    2,      8872,   824,    2,      8873,   824,    2,      8875,   824,    2,      8828,   824,
+    2,      8829,   824,    2,      8849,   824,    2,      8850,   824,    2,      8882,   824,
+    2,      8883,   824,    2,      8884,   824,    2,      8885,   824,    1,      12296,  1,
+    12297,  2,      10973,  824,    2,      12363,  12441,  2,      12365,  12441,  2,      12367,
+    12441,  2,      12369,  12441,  2,      12371,  12441,  2,      12373,  12441,  2,      12375,
+    12441,  2,      12377,  12441,  2,      12379,  12441,  2,      12381,  12441,  2,      12383,
+    12441,  2,      12385,  12441,  2,      12388,  12441,  2,      12390,  12441,  2,      12392,
+    12441,  2,      12399,  12441,  2,      12399,  12442,  2,      12402,  12441,  2,      12402,
+    12442,  2,      12405,  12441,  2,      12405,  12442,  2,      12408,  12441,  2,      12408,
+    12442,  2,      12411,  12441,  2,      12411,  12442,  2,      12358,  12441,  2,      12445,
+    12441,  2,      12459,  12441,  2,      12461,  12441,  2,      12463,  12441,  2,      12465,
+    12441,  2,      12467,  12441,  2,      12469,  12441,  2,      12471,  12441,  2,      12473,
+    12441,  2,      12475,  12441,  2,      12477,  12441,  2,      12479,  12441,  2,      12481,
+    12441,  2,      12484,  12441,  2,      12486,  12441,  2,      12488,  12441,  2,      12495,
+    12441,  2,      12495,  12442,  2,      12498,  12441,  2,      12498,  12442,  2,      12501,
+    12441,  2,      12501,  12442,  2,      12504,  12441,  2,      12504,  12442,  2,      12507,
+    12441,  2,      12507,  12442,  2,      12454,  12441,  2,      12527,  12441,  2,      12528,
+    12441,  2,      12529,  12441,  2,      12530,  12441,  2,      12541,  12441,  1,      35912,
+    1,      26356,  1,      36554,  1,      36040,  1,      28369,  1,      20018,  1,      21477,
+    1,      40860,  1,      22865,  1,      37329,  1,      21895,  1,      22856,  1,      25078,
+    1,      30313,  1,      32645,  1,      34367,  1,      34746,  1,      35064,  1,      37007,
+    1,      27138,  1,      27931,  1,      28889,  1,      29662,  1,      33853,  1,      37226,
+    1,      39409,  1,      20098,  1,      21365,  1,      27396,  1,      29211,  1,      34349,
+    1,      40478,  1,      23888,  1,      28651,  1,      34253,  1,      35172,  1,      25289,
+    1,      33240,  1,      34847,  1,      24266,  1,      26391,  1,      28010,  1,      29436,
+    1,      37070,  1,      20358,  1,      20919,  1,      21214,  1,      25796,  1,      27347,
+    1,      29200,  1,      30439,  1,      32769,  1,      34310,  1,      34396,  1,      36335,
+    1,      38706,  1,      39791,  1,      40442,  1,      30860,  1,      31103,  1,      32160,
+    1,      33737,  1,      37636,  1,      40575,  1,      35542,  1,      22751,  1,      24324,
+    1,      31840,  1,      32894,  1,      29282,  1,      30922,  1,      36034,  1,      38647,
+    1,      22744,  1,      23650,  1,      27155,  1,      28122,  1,      28431,  1,      32047,
+    1,      32311,  1,      38475,  1,      21202,  1,      32907,  1,      20956,  1,      20940,
+    1,      31260,  1,      32190,  1,      33777,  1,      38517,  1,      35712,  1,      25295,
+    1,      35582,  1,      20025,  1,      23527,  1,      24594,  1,      29575,  1,      30064,
+    1,      21271,  1,      30971,  1,      20415,  1,      24489,  1,      19981,  1,      27852,
+    1,      25976,  1,      32034,  1,      21443,  1,      22622,  1,      30465,  1,      33865,
+    1,      35498,  1,      27578,  1,      36784,  1,      27784,  1,      25342,  1,      33509,
+    1,      25504,  1,      30053,  1,      20142,  1,      20841,  1,      20937,  1,      26753,
+    1,      31975,  1,      33391,  1,      35538,  1,      37327,  1,      21237,  1,      21570,
+    1,      22899,  1,      24300,  1,      26053,  1,      28670,  1,      31018,  1,      38317,
+    1,      39530,  1,      40599,  1,      40654,  1,      21147,  1,      26310,  1,      27511,
+    1,      36706,  1,      24180,  1,      24976,  1,      25088,  1,      25754,  1,      28451,
+    1,      29001,  1,      29833,  1,      31178,  1,      32244,  1,      32879,  1,      36646,
+    1,      34030,  1,      36899,  1,      37706,  1,      21015,  1,      21155,  1,      21693,
+    1,      28872,  1,      35010,  1,      24265,  1,      24565,  1,      25467,  1,      27566,
+    1,      31806,  1,      29557,  1,      20196,  1,      22265,  1,      23994,  1,      24604,
+    1,      29618,  1,      29801,  1,      32666,  1,      32838,  1,      37428,  1,      38646,
+    1,      38728,  1,      38936,  1,      20363,  1,      31150,  1,      37300,  1,      38584,
+    1,      24801,  1,      20102,  1,      20698,  1,      23534,  1,      23615,  1,      26009,
+    1,      29134,  1,      30274,  1,      34044,  1,      36988,  1,      40845,  1,      26248,
+    1,      38446,  1,      21129,  1,      26491,  1,      26611,  1,      27969,  1,      28316,
+    1,      29705,  1,      30041,  1,      30827,  1,      32016,  1,      39006,  1,      20845,
+    1,      25134,  1,      38520,  1,      20523,  1,      23833,  1,      28138,  1,      36650,
+    1,      24459,  1,      24900,  1,      26647,  1,      38534,  1,      21033,  1,      21519,
+    1,      23653,  1,      26131,  1,      26446,  1,      26792,  1,      27877,  1,      29702,
+    1,      30178,  1,      32633,  1,      35023,  1,      35041,  1,      37324,  1,      38626,
+    1,      21311,  1,      28346,  1,      21533,  1,      29136,  1,      29848,  1,      34298,
+    1,      38563,  1,      40023,  1,      40607,  1,      26519,  1,      28107,  1,      33256,
+    1,      31435,  1,      31520,  1,      31890,  1,      29376,  1,      28825,  1,      35672,
+    1,      20160,  1,      33590,  1,      21050,  1,      20999,  1,      24230,  1,      25299,
+    1,      31958,  1,      23429,  1,      27934,  1,      26292,  1,      36667,  1,      34892,
+    1,      38477,  1,      35211,  1,      24275,  1,      20800,  1,      21952,  1,      22618,
+    1,      26228,  1,      20958,  1,      29482,  1,      30410,  1,      31036,  1,      31070,
+    1,      31077,  1,      31119,  1,      38742,  1,      31934,  1,      32701,  1,      34322,
+    1,      35576,  1,      36920,  1,      37117,  1,      39151,  1,      39164,  1,      39208,
+    1,      40372,  1,      37086,  1,      38583,  1,      20398,  1,      20711,  1,      20813,
+    1,      21193,  1,      21220,  1,      21329,  1,      21917,  1,      22022,  1,      22120,
+    1,      22592,  1,      22696,  1,      23652,  1,      23662,  1,      24724,  1,      24936,
+    1,      24974,  1,      25074,  1,      25935,  1,      26082,  1,      26257,  1,      26757,
+    1,      28023,  1,      28186,  1,      28450,  1,      29038,  1,      29227,  1,      29730,
+    1,      30865,  1,      31038,  1,      31049,  1,      31048,  1,      31056,  1,      31062,
+    1,      31069,  1,      31117,  1,      31118,  1,      31296,  1,      31361,  1,      31680,
+    1,      32265,  1,      32321,  1,      32626,  1,      32773,  1,      33261,  1,      33401,
+    1,      33879,  1,      35088,  1,      35222,  1,      35585,  1,      35641,  1,      36051,
+    1,      36104,  1,      36790,  1,      38627,  1,      38911,  1,      38971,  1,      24693,
+    1,      148206, 1,      33304,  1,      20006,  1,      20917,  1,      20840,  1,      20352,
+    1,      20805,  1,      20864,  1,      21191,  1,      21242,  1,      21845,  1,      21913,
+    1,      21986,  1,      22707,  1,      22852,  1,      22868,  1,      23138,  1,      23336,
+    1,      24274,  1,      24281,  1,      24425,  1,      24493,  1,      24792,  1,      24910,
+    1,      24840,  1,      24928,  1,      25140,  1,      25540,  1,      25628,  1,      25682,
+    1,      25942,  1,      26395,  1,      26454,  1,      27513,  1,      28379,  1,      28363,
+    1,      28702,  1,      30631,  1,      29237,  1,      29359,  1,      29809,  1,      29958,
+    1,      30011,  1,      30237,  1,      30239,  1,      30427,  1,      30452,  1,      30538,
+    1,      30528,  1,      30924,  1,      31409,  1,      31867,  1,      32091,  1,      32574,
+    1,      33618,  1,      33775,  1,      34681,  1,      35137,  1,      35206,  1,      35519,
+    1,      35531,  1,      35565,  1,      35722,  1,      36664,  1,      36978,  1,      37273,
+    1,      37494,  1,      38524,  1,      38875,  1,      38923,  1,      39698,  1,      141386,
+    1,      141380, 1,      144341, 1,      15261,  1,      16408,  1,      16441,  1,      152137,
+    1,      154832, 1,      163539, 1,      40771,  1,      40846,  2,      1497,   1460,   2,
+    1522,   1463,   2,      1513,   1473,   2,      1513,   1474,   3,      1513,   1468,   1473,
+    3,      1513,   1468,   1474,   2,      1488,   1463,   2,      1488,   1464,   2,      1488,
+    1468,   2,      1489,   1468,   2,      1490,   1468,   2,      1491,   1468,   2,      1492,
+    1468,   2,      1493,   1468,   2,      1494,   1468,   2,      1496,   1468,   2,      1497,
+    1468,   2,      1498,   1468,   2,      1499,   1468,   2,      1500,   1468,   2,      1502,
+    1468,   2,      1504,   1468,   2,      1505,   1468,   2,      1507,   1468,   2,      1508,
+    1468,   2,      1510,   1468,   2,      1511,   1468,   2,      1512,   1468,   2,      1513,
+    1468,   2,      1514,   1468,   2,      1493,   1465,   2,      1489,   1471,   2,      1499,
+    1471,   2,      1508,   1471,   2,      69785,  69818,  2,      69787,  69818,  2,      69797,
+    69818,  2,      69937,  69927,  2,      69938,  69927,  2,      70471,  70462,  2,      70471,
+    70487,  2,      70841,  70842,  2,      70841,  70832,  2,      70841,  70845,  2,      71096,
+    71087,  2,      71097,  71087,  2,      71989,  71984,  2,      119127, 119141, 2,      119128,
+    119141, 3,      119128, 119141, 119150, 3,      119128, 119141, 119151, 3,      119128, 119141,
+    119152, 3,      119128, 119141, 119153, 3,      119128, 119141, 119154, 2,      119225, 119141,
+    2,      119226, 119141, 3,      119225, 119141, 119150, 3,      119226, 119141, 119150, 3,
+    119225, 119141, 119151, 3,      119226, 119141, 119151, 1,      20029,  1,      20024,  1,
+    20033,  1,      131362, 1,      20320,  1,      20411,  1,      20482,  1,      20602,  1,
+    20633,  1,      20687,  1,      13470,  1,      132666, 1,      20820,  1,      20836,  1,
+    20855,  1,      132380, 1,      13497,  1,      20839,  1,      20877,  1,      132427, 1,
+    20887,  1,      20900,  1,      20172,  1,      20908,  1,      168415, 1,      20981,  1,
+    20995,  1,      13535,  1,      21051,  1,      21062,  1,      21106,  1,      21111,  1,
+    13589,  1,      21253,  1,      21254,  1,      21321,  1,      21338,  1,      21363,  1,
+    21373,  1,      21375,  1,      133676, 1,      28784,  1,      21450,  1,      21471,  1,
+    133987, 1,      21483,  1,      21489,  1,      21510,  1,      21662,  1,      21560,  1,
+    21576,  1,      21608,  1,      21666,  1,      21750,  1,      21776,  1,      21843,  1,
+    21859,  1,      21892,  1,      21931,  1,      21939,  1,      21954,  1,      22294,  1,
+    22295,  1,      22097,  1,      22132,  1,      22766,  1,      22478,  1,      22516,  1,
+    22541,  1,      22411,  1,      22578,  1,      22577,  1,      22700,  1,      136420, 1,
+    22770,  1,      22775,  1,      22790,  1,      22810,  1,      22818,  1,      22882,  1,
+    136872, 1,      136938, 1,      23020,  1,      23067,  1,      23079,  1,      23000,  1,
+    23142,  1,      14062,  1,      14076,  1,      23304,  1,      23358,  1,      137672, 1,
+    23491,  1,      23512,  1,      23539,  1,      138008, 1,      23551,  1,      23558,  1,
+    24403,  1,      23586,  1,      14209,  1,      23648,  1,      23744,  1,      23693,  1,
+    138724, 1,      23875,  1,      138726, 1,      23918,  1,      23915,  1,      23932,  1,
+    24033,  1,      24034,  1,      14383,  1,      24061,  1,      24104,  1,      24125,  1,
+    24169,  1,      14434,  1,      139651, 1,      14460,  1,      24240,  1,      24243,  1,
+    24246,  1,      172946, 1,      24318,  1,      140081, 1,      33281,  1,      24354,  1,
+    14535,  1,      144056, 1,      156122, 1,      24418,  1,      24427,  1,      14563,  1,
+    24474,  1,      24525,  1,      24535,  1,      24569,  1,      24705,  1,      14650,  1,
+    14620,  1,      141012, 1,      24775,  1,      24904,  1,      24908,  1,      24954,  1,
+    25010,  1,      24996,  1,      25007,  1,      25054,  1,      25104,  1,      25115,  1,
+    25181,  1,      25265,  1,      25300,  1,      25424,  1,      142092, 1,      25405,  1,
+    25340,  1,      25448,  1,      25475,  1,      25572,  1,      142321, 1,      25634,  1,
+    25541,  1,      25513,  1,      14894,  1,      25705,  1,      25726,  1,      25757,  1,
+    25719,  1,      14956,  1,      25964,  1,      143370, 1,      26083,  1,      26360,  1,
+    26185,  1,      15129,  1,      15112,  1,      15076,  1,      20882,  1,      20885,  1,
+    26368,  1,      26268,  1,      32941,  1,      17369,  1,      26401,  1,      26462,  1,
+    26451,  1,      144323, 1,      15177,  1,      26618,  1,      26501,  1,      26706,  1,
+    144493, 1,      26766,  1,      26655,  1,      26900,  1,      26946,  1,      27043,  1,
+    27114,  1,      27304,  1,      145059, 1,      27355,  1,      15384,  1,      27425,  1,
+    145575, 1,      27476,  1,      15438,  1,      27506,  1,      27551,  1,      27579,  1,
+    146061, 1,      138507, 1,      146170, 1,      27726,  1,      146620, 1,      27839,  1,
+    27853,  1,      27751,  1,      27926,  1,      27966,  1,      28009,  1,      28024,  1,
+    28037,  1,      146718, 1,      27956,  1,      28207,  1,      28270,  1,      15667,  1,
+    28359,  1,      147153, 1,      28153,  1,      28526,  1,      147294, 1,      147342, 1,
+    28614,  1,      28729,  1,      28699,  1,      15766,  1,      28746,  1,      28797,  1,
+    28791,  1,      28845,  1,      132389, 1,      28997,  1,      148067, 1,      29084,  1,
+    148395, 1,      29224,  1,      29264,  1,      149000, 1,      29312,  1,      29333,  1,
+    149301, 1,      149524, 1,      29562,  1,      29579,  1,      16044,  1,      29605,  1,
+    16056,  1,      29767,  1,      29788,  1,      29829,  1,      29898,  1,      16155,  1,
+    29988,  1,      150582, 1,      30014,  1,      150674, 1,      139679, 1,      30224,  1,
+    151457, 1,      151480, 1,      151620, 1,      16380,  1,      16392,  1,      151795, 1,
+    151794, 1,      151833, 1,      151859, 1,      30494,  1,      30495,  1,      30603,  1,
+    16454,  1,      16534,  1,      152605, 1,      30798,  1,      16611,  1,      153126, 1,
+    153242, 1,      153285, 1,      31211,  1,      16687,  1,      31306,  1,      31311,  1,
+    153980, 1,      154279, 1,      31470,  1,      16898,  1,      154539, 1,      31686,  1,
+    31689,  1,      16935,  1,      154752, 1,      31954,  1,      17056,  1,      31976,  1,
+    31971,  1,      32000,  1,      155526, 1,      32099,  1,      17153,  1,      32199,  1,
+    32258,  1,      32325,  1,      17204,  1,      156200, 1,      156231, 1,      17241,  1,
+    156377, 1,      32634,  1,      156478, 1,      32661,  1,      32762,  1,      156890, 1,
+    156963, 1,      32864,  1,      157096, 1,      32880,  1,      144223, 1,      17365,  1,
+    32946,  1,      33027,  1,      17419,  1,      33086,  1,      23221,  1,      157607, 1,
+    157621, 1,      144275, 1,      144284, 1,      33284,  1,      36766,  1,      17515,  1,
+    33425,  1,      33419,  1,      33437,  1,      21171,  1,      33457,  1,      33459,  1,
+    33469,  1,      33510,  1,      158524, 1,      33565,  1,      33635,  1,      33709,  1,
+    33571,  1,      33725,  1,      33767,  1,      33619,  1,      33738,  1,      33740,  1,
+    33756,  1,      158774, 1,      159083, 1,      158933, 1,      17707,  1,      34033,  1,
+    34035,  1,      34070,  1,      160714, 1,      34148,  1,      159532, 1,      17757,  1,
+    17761,  1,      159665, 1,      159954, 1,      17771,  1,      34384,  1,      34407,  1,
+    34409,  1,      34473,  1,      34440,  1,      34574,  1,      34530,  1,      34600,  1,
+    34667,  1,      34694,  1,      17879,  1,      34785,  1,      34817,  1,      17913,  1,
+    34912,  1,      34915,  1,      161383, 1,      35031,  1,      35038,  1,      17973,  1,
+    35066,  1,      13499,  1,      161966, 1,      162150, 1,      18110,  1,      18119,  1,
+    35488,  1,      35925,  1,      162984, 1,      36011,  1,      36033,  1,      36123,  1,
+    36215,  1,      163631, 1,      133124, 1,      36299,  1,      36284,  1,      36336,  1,
+    133342, 1,      36564,  1,      165330, 1,      165357, 1,      37012,  1,      37105,  1,
+    37137,  1,      165678, 1,      37147,  1,      37432,  1,      37591,  1,      37592,  1,
+    37500,  1,      37881,  1,      37909,  1,      166906, 1,      38283,  1,      18837,  1,
+    38327,  1,      167287, 1,      18918,  1,      38595,  1,      23986,  1,      38691,  1,
+    168261, 1,      168474, 1,      19054,  1,      19062,  1,      38880,  1,      168970, 1,
+    19122,  1,      169110, 1,      38953,  1,      169398, 1,      39138,  1,      19251,  1,
+    39209,  1,      39335,  1,      39362,  1,      39422,  1,      19406,  1,      170800, 1,
+    40000,  1,      40189,  1,      19662,  1,      19693,  1,      40295,  1,      172238, 1,
+    19704,  1,      172293, 1,      172558, 1,      172689, 1,      40635,  1,      19798,  1,
+    40697,  1,      40702,  1,      40709,  1,      40719,  1,      40726,  1,      40763,  1,
+    173568,
+};
+
+const compositions: [941]Composition = [_]Composition{
+    .{ .first = 0x003C, .second = 0x0338, .composite = 0x226E },
+    .{ .first = 0x003D, .second = 0x0338, .composite = 0x2260 },
+    .{ .first = 0x003E, .second = 0x0338, .composite = 0x226F },
+    .{ .first = 0x0041, .second = 0x0300, .composite = 0x00C0 },
+    .{ .first = 0x0041, .second = 0x0301, .composite = 0x00C1 },
+    .{ .first = 0x0041, .second = 0x0302, .composite = 0x00C2 },
+    .{ .first = 0x0041, .second = 0x0303, .composite = 0x00C3 },
+    .{ .first = 0x0041, .second = 0x0304, .composite = 0x0100 },
+    .{ .first = 0x0041, .second = 0x0306, .composite = 0x0102 },
+    .{ .first = 0x0041, .second = 0x0307, .composite = 0x0226 },
+    .{ .first = 0x0041, .second = 0x0308, .composite = 0x00C4 },
+    .{ .first = 0x0041, .second = 0x0309, .composite = 0x1EA2 },
+    .{ .first = 0x0041, .second = 0x030A, .composite = 0x00C5 },
+    .{ .first = 0x0041, .second = 0x030C, .composite = 0x01CD },
+    .{ .first = 0x0041, .second = 0x030F, .composite = 0x0200 },
+    .{ .first = 0x0041, .second = 0x0311, .composite = 0x0202 },
+    .{ .first = 0x0041, .second = 0x0323, .composite = 0x1EA0 },
+    .{ .first = 0x0041, .second = 0x0325, .composite = 0x1E00 },
+    .{ .first = 0x0041, .second = 0x0328, .composite = 0x0104 },
+    .{ .first = 0x0042, .second = 0x0307, .composite = 0x1E02 },
+    .{ .first = 0x0042, .second = 0x0323, .composite = 0x1E04 },
+    .{ .first = 0x0042, .second = 0x0331, .composite = 0x1E06 },
+    .{ .first = 0x0043, .second = 0x0301, .composite = 0x0106 },
+    .{ .first = 0x0043, .second = 0x0302, .composite = 0x0108 },
+    .{ .first = 0x0043, .second = 0x0307, .composite = 0x010A },
+    .{ .first = 0x0043, .second = 0x030C, .composite = 0x010C },
+    .{ .first = 0x0043, .second = 0x0327, .composite = 0x00C7 },
+    .{ .first = 0x0044, .second = 0x0307, .composite = 0x1E0A },
+    .{ .first = 0x0044, .second = 0x030C, .composite = 0x010E },
+    .{ .first = 0x0044, .second = 0x0323, .composite = 0x1E0C },
+    .{ .first = 0x0044, .second = 0x0327, .composite = 0x1E10 },
+    .{ .first = 0x0044, .second = 0x032D, .composite = 0x1E12 },
+    .{ .first = 0x0044, .second = 0x0331, .composite = 0x1E0E },
+    .{ .first = 0x0045, .second = 0x0300, .composite = 0x00C8 },
+    .{ .first = 0x0045, .second = 0x0301, .composite = 0x00C9 },
+    .{ .first = 0x0045, .second = 0x0302, .composite = 0x00CA },
+    .{ .first = 0x0045, .second = 0x0303, .composite = 0x1EBC },
+    .{ .first = 0x0045, .second = 0x0304, .composite = 0x0112 },
+    .{ .first = 0x0045, .second = 0x0306, .composite = 0x0114 },
+    .{ .first = 0x0045, .second = 0x0307, .composite = 0x0116 },
+    .{ .first = 0x0045, .second = 0x0308, .composite = 0x00CB },
+    .{ .first = 0x0045, .second = 0x0309, .composite = 0x1EBA },
+    .{ .first = 0x0045, .second = 0x030C, .composite = 0x011A },
+    .{ .first = 0x0045, .second = 0x030F, .composite = 0x0204 },
+    .{ .first = 0x0045, .second = 0x0311, .composite = 0x0206 },
+    .{ .first = 0x0045, .second = 0x0323, .composite = 0x1EB8 },
+    .{ .first = 0x0045, .second = 0x0327, .composite = 0x0228 },
+    .{ .first = 0x0045, .second = 0x0328, .composite = 0x0118 },
+    .{ .first = 0x0045, .second = 0x032D, .composite = 0x1E18 },
+    .{ .first = 0x0045, .second = 0x0330, .composite = 0x1E1A },
+    .{ .first = 0x0046, .second = 0x0307, .composite = 0x1E1E },
+    .{ .first = 0x0047, .second = 0x0301, .composite = 0x01F4 },
+    .{ .first = 0x0047, .second = 0x0302, .composite = 0x011C },
+    .{ .first = 0x0047, .second = 0x0304, .composite = 0x1E20 },
+    .{ .first = 0x0047, .second = 0x0306, .composite = 0x011E },
+    .{ .first = 0x0047, .second = 0x0307, .composite = 0x0120 },
+    .{ .first = 0x0047, .second = 0x030C, .composite = 0x01E6 },
+    .{ .first = 0x0047, .second = 0x0327, .composite = 0x0122 },
+    .{ .first = 0x0048, .second = 0x0302, .composite = 0x0124 },
+    .{ .first = 0x0048, .second = 0x0307, .composite = 0x1E22 },
+    .{ .first = 0x0048, .second = 0x0308, .composite = 0x1E26 },
+    .{ .first = 0x0048, .second = 0x030C, .composite = 0x021E },
+    .{ .first = 0x0048, .second = 0x0323, .composite = 0x1E24 },
+    .{ .first = 0x0048, .second = 0x0327, .composite = 0x1E28 },
+    .{ .first = 0x0048, .second = 0x032E, .composite = 0x1E2A },
+    .{ .first = 0x0049, .second = 0x0300, .composite = 0x00CC },
+    .{ .first = 0x0049, .second = 0x0301, .composite = 0x00CD },
+    .{ .first = 0x0049, .second = 0x0302, .composite = 0x00CE },
+    .{ .first = 0x0049, .second = 0x0303, .composite = 0x0128 },
+    .{ .first = 0x0049, .second = 0x0304, .composite = 0x012A },
+    .{ .first = 0x0049, .second = 0x0306, .composite = 0x012C },
+    .{ .first = 0x0049, .second = 0x0307, .composite = 0x0130 },
+    .{ .first = 0x0049, .second = 0x0308, .composite = 0x00CF },
+    .{ .first = 0x0049, .second = 0x0309, .composite = 0x1EC8 },
+    .{ .first = 0x0049, .second = 0x030C, .composite = 0x01CF },
+    .{ .first = 0x0049, .second = 0x030F, .composite = 0x0208 },
+    .{ .first = 0x0049, .second = 0x0311, .composite = 0x020A },
+    .{ .first = 0x0049, .second = 0x0323, .composite = 0x1ECA },
+    .{ .first = 0x0049, .second = 0x0328, .composite = 0x012E },
+    .{ .first = 0x0049, .second = 0x0330, .composite = 0x1E2C },
+    .{ .first = 0x004A, .second = 0x0302, .composite = 0x0134 },
+    .{ .first = 0x004B, .second = 0x0301, .composite = 0x1E30 },
+    .{ .first = 0x004B, .second = 0x030C, .composite = 0x01E8 },
+    .{ .first = 0x004B, .second = 0x0323, .composite = 0x1E32 },
+    .{ .first = 0x004B, .second = 0x0327, .composite = 0x0136 },
+    .{ .first = 0x004B, .second = 0x0331, .composite = 0x1E34 },
+    .{ .first = 0x004C, .second = 0x0301, .composite = 0x0139 },
+    .{ .first = 0x004C, .second = 0x030C, .composite = 0x013D },
+    .{ .first = 0x004C, .second = 0x0323, .composite = 0x1E36 },
+    .{ .first = 0x004C, .second = 0x0327, .composite = 0x013B },
+    .{ .first = 0x004C, .second = 0x032D, .composite = 0x1E3C },
+    .{ .first = 0x004C, .second = 0x0331, .composite = 0x1E3A },
+    .{ .first = 0x004D, .second = 0x0301, .composite = 0x1E3E },
+    .{ .first = 0x004D, .second = 0x0307, .composite = 0x1E40 },
+    .{ .first = 0x004D, .second = 0x0323, .composite = 0x1E42 },
+    .{ .first = 0x004E, .second = 0x0300, .composite = 0x01F8 },
+    .{ .first = 0x004E, .second = 0x0301, .composite = 0x0143 },
+    .{ .first = 0x004E, .second = 0x0303, .composite = 0x00D1 },
+    .{ .first = 0x004E, .second = 0x0307, .composite = 0x1E44 },
+    .{ .first = 0x004E, .second = 0x030C, .composite = 0x0147 },
+    .{ .first = 0x004E, .second = 0x0323, .composite = 0x1E46 },
+    .{ .first = 0x004E, .second = 0x0327, .composite = 0x0145 },
+    .{ .first = 0x004E, .second = 0x032D, .composite = 0x1E4A },
+    .{ .first = 0x004E, .second = 0x0331, .composite = 0x1E48 },
+    .{ .first = 0x004F, .second = 0x0300, .composite = 0x00D2 },
+    .{ .first = 0x004F, .second = 0x0301, .composite = 0x00D3 },
+    .{ .first = 0x004F, .second = 0x0302, .composite = 0x00D4 },
+    .{ .first = 0x004F, .second = 0x0303, .composite = 0x00D5 },
+    .{ .first = 0x004F, .second = 0x0304, .composite = 0x014C },
+    .{ .first = 0x004F, .second = 0x0306, .composite = 0x014E },
+    .{ .first = 0x004F, .second = 0x0307, .composite = 0x022E },
+    .{ .first = 0x004F, .second = 0x0308, .composite = 0x00D6 },
+    .{ .first = 0x004F, .second = 0x0309, .composite = 0x1ECE },
+    .{ .first = 0x004F, .second = 0x030B, .composite = 0x0150 },
+    .{ .first = 0x004F, .second = 0x030C, .composite = 0x01D1 },
+    .{ .first = 0x004F, .second = 0x030F, .composite = 0x020C },
+    .{ .first = 0x004F, .second = 0x0311, .composite = 0x020E },
+    .{ .first = 0x004F, .second = 0x031B, .composite = 0x01A0 },
+    .{ .first = 0x004F, .second = 0x0323, .composite = 0x1ECC },
+    .{ .first = 0x004F, .second = 0x0328, .composite = 0x01EA },
+    .{ .first = 0x0050, .second = 0x0301, .composite = 0x1E54 },
+    .{ .first = 0x0050, .second = 0x0307, .composite = 0x1E56 },
+    .{ .first = 0x0052, .second = 0x0301, .composite = 0x0154 },
+    .{ .first = 0x0052, .second = 0x0307, .composite = 0x1E58 },
+    .{ .first = 0x0052, .second = 0x030C, .composite = 0x0158 },
+    .{ .first = 0x0052, .second = 0x030F, .composite = 0x0210 },
+    .{ .first = 0x0052, .second = 0x0311, .composite = 0x0212 },
+    .{ .first = 0x0052, .second = 0x0323, .composite = 0x1E5A },
+    .{ .first = 0x0052, .second = 0x0327, .composite = 0x0156 },
+    .{ .first = 0x0052, .second = 0x0331, .composite = 0x1E5E },
+    .{ .first = 0x0053, .second = 0x0301, .composite = 0x015A },
+    .{ .first = 0x0053, .second = 0x0302, .composite = 0x015C },
+    .{ .first = 0x0053, .second = 0x0307, .composite = 0x1E60 },
+    .{ .first = 0x0053, .second = 0x030C, .composite = 0x0160 },
+    .{ .first = 0x0053, .second = 0x0323, .composite = 0x1E62 },
+    .{ .first = 0x0053, .second = 0x0326, .composite = 0x0218 },
+    .{ .first = 0x0053, .second = 0x0327, .composite = 0x015E },
+    .{ .first = 0x0054, .second = 0x0307, .composite = 0x1E6A },
+    .{ .first = 0x0054, .second = 0x030C, .composite = 0x0164 },
+    .{ .first = 0x0054, .second = 0x0323, .composite = 0x1E6C },
+    .{ .first = 0x0054, .second = 0x0326, .composite = 0x021A },
+    .{ .first = 0x0054, .second = 0x0327, .composite = 0x0162 },
+    .{ .first = 0x0054, .second = 0x032D, .composite = 0x1E70 },
+    .{ .first = 0x0054, .second = 0x0331, .composite = 0x1E6E },
+    .{ .first = 0x0055, .second = 0x0300, .composite = 0x00D9 },
+    .{ .first = 0x0055, .second = 0x0301, .composite = 0x00DA },
+    .{ .first = 0x0055, .second = 0x0302, .composite = 0x00DB },
+    .{ .first = 0x0055, .second = 0x0303, .composite = 0x0168 },
+    .{ .first = 0x0055, .second = 0x0304, .composite = 0x016A },
+    .{ .first = 0x0055, .second = 0x0306, .composite = 0x016C },
+    .{ .first = 0x0055, .second = 0x0308, .composite = 0x00DC },
+    .{ .first = 0x0055, .second = 0x0309, .composite = 0x1EE6 },
+    .{ .first = 0x0055, .second = 0x030A, .composite = 0x016E },
+    .{ .first = 0x0055, .second = 0x030B, .composite = 0x0170 },
+    .{ .first = 0x0055, .second = 0x030C, .composite = 0x01D3 },
+    .{ .first = 0x0055, .second = 0x030F, .composite = 0x0214 },
+    .{ .first = 0x0055, .second = 0x0311, .composite = 0x0216 },
+    .{ .first = 0x0055, .second = 0x031B, .composite = 0x01AF },
+    .{ .first = 0x0055, .second = 0x0323, .composite = 0x1EE4 },
+    .{ .first = 0x0055, .second = 0x0324, .composite = 0x1E72 },
+    .{ .first = 0x0055, .second = 0x0328, .composite = 0x0172 },
+    .{ .first = 0x0055, .second = 0x032D, .composite = 0x1E76 },
+    .{ .first = 0x0055, .second = 0x0330, .composite = 0x1E74 },
+    .{ .first = 0x0056, .second = 0x0303, .composite = 0x1E7C },
+    .{ .first = 0x0056, .second = 0x0323, .composite = 0x1E7E },
+    .{ .first = 0x0057, .second = 0x0300, .composite = 0x1E80 },
+    .{ .first = 0x0057, .second = 0x0301, .composite = 0x1E82 },
+    .{ .first = 0x0057, .second = 0x0302, .composite = 0x0174 },
+    .{ .first = 0x0057, .second = 0x0307, .composite = 0x1E86 },
+    .{ .first = 0x0057, .second = 0x0308, .composite = 0x1E84 },
+    .{ .first = 0x0057, .second = 0x0323, .composite = 0x1E88 },
+    .{ .first = 0x0058, .second = 0x0307, .composite = 0x1E8A },
+    .{ .first = 0x0058, .second = 0x0308, .composite = 0x1E8C },
+    .{ .first = 0x0059, .second = 0x0300, .composite = 0x1EF2 },
+    .{ .first = 0x0059, .second = 0x0301, .composite = 0x00DD },
+    .{ .first = 0x0059, .second = 0x0302, .composite = 0x0176 },
+    .{ .first = 0x0059, .second = 0x0303, .composite = 0x1EF8 },
+    .{ .first = 0x0059, .second = 0x0304, .composite = 0x0232 },
+    .{ .first = 0x0059, .second = 0x0307, .composite = 0x1E8E },
+    .{ .first = 0x0059, .second = 0x0308, .composite = 0x0178 },
+    .{ .first = 0x0059, .second = 0x0309, .composite = 0x1EF6 },
+    .{ .first = 0x0059, .second = 0x0323, .composite = 0x1EF4 },
+    .{ .first = 0x005A, .second = 0x0301, .composite = 0x0179 },
+    .{ .first = 0x005A, .second = 0x0302, .composite = 0x1E90 },
+    .{ .first = 0x005A, .second = 0x0307, .composite = 0x017B },
+    .{ .first = 0x005A, .second = 0x030C, .composite = 0x017D },
+    .{ .first = 0x005A, .second = 0x0323, .composite = 0x1E92 },
+    .{ .first = 0x005A, .second = 0x0331, .composite = 0x1E94 },
+    .{ .first = 0x0061, .second = 0x0300, .composite = 0x00E0 },
+    .{ .first = 0x0061, .second = 0x0301, .composite = 0x00E1 },
+    .{ .first = 0x0061, .second = 0x0302, .composite = 0x00E2 },
+    .{ .first = 0x0061, .second = 0x0303, .composite = 0x00E3 },
+    .{ .first = 0x0061, .second = 0x0304, .composite = 0x0101 },
+    .{ .first = 0x0061, .second = 0x0306, .composite = 0x0103 },
+    .{ .first = 0x0061, .second = 0x0307, .composite = 0x0227 },
+    .{ .first = 0x0061, .second = 0x0308, .composite = 0x00E4 },
+    .{ .first = 0x0061, .second = 0x0309, .composite = 0x1EA3 },
+    .{ .first = 0x0061, .second = 0x030A, .composite = 0x00E5 },
+    .{ .first = 0x0061, .second = 0x030C, .composite = 0x01CE },
+    .{ .first = 0x0061, .second = 0x030F, .composite = 0x0201 },
+    .{ .first = 0x0061, .second = 0x0311, .composite = 0x0203 },
+    .{ .first = 0x0061, .second = 0x0323, .composite = 0x1EA1 },
+    .{ .first = 0x0061, .second = 0x0325, .composite = 0x1E01 },
+    .{ .first = 0x0061, .second = 0x0328, .composite = 0x0105 },
+    .{ .first = 0x0062, .second = 0x0307, .composite = 0x1E03 },
+    .{ .first = 0x0062, .second = 0x0323, .composite = 0x1E05 },
+    .{ .first = 0x0062, .second = 0x0331, .composite = 0x1E07 },
+    .{ .first = 0x0063, .second = 0x0301, .composite = 0x0107 },
+    .{ .first = 0x0063, .second = 0x0302, .composite = 0x0109 },
+    .{ .first = 0x0063, .second = 0x0307, .composite = 0x010B },
+    .{ .first = 0x0063, .second = 0x030C, .composite = 0x010D },
+    .{ .first = 0x0063, .second = 0x0327, .composite = 0x00E7 },
+    .{ .first = 0x0064, .second = 0x0307, .composite = 0x1E0B },
+    .{ .first = 0x0064, .second = 0x030C, .composite = 0x010F },
+    .{ .first = 0x0064, .second = 0x0323, .composite = 0x1E0D },
+    .{ .first = 0x0064, .second = 0x0327, .composite = 0x1E11 },
+    .{ .first = 0x0064, .second = 0x032D, .composite = 0x1E13 },
+    .{ .first = 0x0064, .second = 0x0331, .composite = 0x1E0F },
+    .{ .first = 0x0065, .second = 0x0300, .composite = 0x00E8 },
+    .{ .first = 0x0065, .second = 0x0301, .composite = 0x00E9 },
+    .{ .first = 0x0065, .second = 0x0302, .composite = 0x00EA },
+    .{ .first = 0x0065, .second = 0x0303, .composite = 0x1EBD },
+    .{ .first = 0x0065, .second = 0x0304, .composite = 0x0113 },
+    .{ .first = 0x0065, .second = 0x0306, .composite = 0x0115 },
+    .{ .first = 0x0065, .second = 0x0307, .composite = 0x0117 },
+    .{ .first = 0x0065, .second = 0x0308, .composite = 0x00EB },
+    .{ .first = 0x0065, .second = 0x0309, .composite = 0x1EBB },
+    .{ .first = 0x0065, .second = 0x030C, .composite = 0x011B },
+    .{ .first = 0x0065, .second = 0x030F, .composite = 0x0205 },
+    .{ .first = 0x0065, .second = 0x0311, .composite = 0x0207 },
+    .{ .first = 0x0065, .second = 0x0323, .composite = 0x1EB9 },
+    .{ .first = 0x0065, .second = 0x0327, .composite = 0x0229 },
+    .{ .first = 0x0065, .second = 0x0328, .composite = 0x0119 },
+    .{ .first = 0x0065, .second = 0x032D, .composite = 0x1E19 },
+    .{ .first = 0x0065, .second = 0x0330, .composite = 0x1E1B },
+    .{ .first = 0x0066, .second = 0x0307, .composite = 0x1E1F },
+    .{ .first = 0x0067, .second = 0x0301, .composite = 0x01F5 },
+    .{ .first = 0x0067, .second = 0x0302, .composite = 0x011D },
+    .{ .first = 0x0067, .second = 0x0304, .composite = 0x1E21 },
+    .{ .first = 0x0067, .second = 0x0306, .composite = 0x011F },
+    .{ .first = 0x0067, .second = 0x0307, .composite = 0x0121 },
+    .{ .first = 0x0067, .second = 0x030C, .composite = 0x01E7 },
+    .{ .first = 0x0067, .second = 0x0327, .composite = 0x0123 },
+    .{ .first = 0x0068, .second = 0x0302, .composite = 0x0125 },
+    .{ .first = 0x0068, .second = 0x0307, .composite = 0x1E23 },
+    .{ .first = 0x0068, .second = 0x0308, .composite = 0x1E27 },
+    .{ .first = 0x0068, .second = 0x030C, .composite = 0x021F },
+    .{ .first = 0x0068, .second = 0x0323, .composite = 0x1E25 },
+    .{ .first = 0x0068, .second = 0x0327, .composite = 0x1E29 },
+    .{ .first = 0x0068, .second = 0x032E, .composite = 0x1E2B },
+    .{ .first = 0x0068, .second = 0x0331, .composite = 0x1E96 },
+    .{ .first = 0x0069, .second = 0x0300, .composite = 0x00EC },
+    .{ .first = 0x0069, .second = 0x0301, .composite = 0x00ED },
+    .{ .first = 0x0069, .second = 0x0302, .composite = 0x00EE },
+    .{ .first = 0x0069, .second = 0x0303, .composite = 0x0129 },
+    .{ .first = 0x0069, .second = 0x0304, .composite = 0x012B },
+    .{ .first = 0x0069, .second = 0x0306, .composite = 0x012D },
+    .{ .first = 0x0069, .second = 0x0308, .composite = 0x00EF },
+    .{ .first = 0x0069, .second = 0x0309, .composite = 0x1EC9 },
+    .{ .first = 0x0069, .second = 0x030C, .composite = 0x01D0 },
+    .{ .first = 0x0069, .second = 0x030F, .composite = 0x0209 },
+    .{ .first = 0x0069, .second = 0x0311, .composite = 0x020B },
+    .{ .first = 0x0069, .second = 0x0323, .composite = 0x1ECB },
+    .{ .first = 0x0069, .second = 0x0328, .composite = 0x012F },
+    .{ .first = 0x0069, .second = 0x0330, .composite = 0x1E2D },
+    .{ .first = 0x006A, .second = 0x0302, .composite = 0x0135 },
+    .{ .first = 0x006A, .second = 0x030C, .composite = 0x01F0 },
+    .{ .first = 0x006B, .second = 0x0301, .composite = 0x1E31 },
+    .{ .first = 0x006B, .second = 0x030C, .composite = 0x01E9 },
+    .{ .first = 0x006B, .second = 0x0323, .composite = 0x1E33 },
+    .{ .first = 0x006B, .second = 0x0327, .composite = 0x0137 },
+    .{ .first = 0x006B, .second = 0x0331, .composite = 0x1E35 },
+    .{ .first = 0x006C, .second = 0x0301, .composite = 0x013A },
+    .{ .first = 0x006C, .second = 0x030C, .composite = 0x013E },
+    .{ .first = 0x006C, .second = 0x0323, .composite = 0x1E37 },
+    .{ .first = 0x006C, .second = 0x0327, .composite = 0x013C },
+    .{ .first = 0x006C, .second = 0x032D, .composite = 0x1E3D },
+    .{ .first = 0x006C, .second = 0x0331, .composite = 0x1E3B },
+    .{ .first = 0x006D, .second = 0x0301, .composite = 0x1E3F },
+    .{ .first = 0x006D, .second = 0x0307, .composite = 0x1E41 },
+    .{ .first = 0x006D, .second = 0x0323, .composite = 0x1E43 },
+    .{ .first = 0x006E, .second = 0x0300, .composite = 0x01F9 },
+    .{ .first = 0x006E, .second = 0x0301, .composite = 0x0144 },
+    .{ .first = 0x006E, .second = 0x0303, .composite = 0x00F1 },
+    .{ .first = 0x006E, .second = 0x0307, .composite = 0x1E45 },
+    .{ .first = 0x006E, .second = 0x030C, .composite = 0x0148 },
+    .{ .first = 0x006E, .second = 0x0323, .composite = 0x1E47 },
+    .{ .first = 0x006E, .second = 0x0327, .composite = 0x0146 },
+    .{ .first = 0x006E, .second = 0x032D, .composite = 0x1E4B },
+    .{ .first = 0x006E, .second = 0x0331, .composite = 0x1E49 },
+    .{ .first = 0x006F, .second = 0x0300, .composite = 0x00F2 },
+    .{ .first = 0x006F, .second = 0x0301, .composite = 0x00F3 },
+    .{ .first = 0x006F, .second = 0x0302, .composite = 0x00F4 },
+    .{ .first = 0x006F, .second = 0x0303, .composite = 0x00F5 },
+    .{ .first = 0x006F, .second = 0x0304, .composite = 0x014D },
+    .{ .first = 0x006F, .second = 0x0306, .composite = 0x014F },
+    .{ .first = 0x006F, .second = 0x0307, .composite = 0x022F },
+    .{ .first = 0x006F, .second = 0x0308, .composite = 0x00F6 },
+    .{ .first = 0x006F, .second = 0x0309, .composite = 0x1ECF },
+    .{ .first = 0x006F, .second = 0x030B, .composite = 0x0151 },
+    .{ .first = 0x006F, .second = 0x030C, .composite = 0x01D2 },
+    .{ .first = 0x006F, .second = 0x030F, .composite = 0x020D },
+    .{ .first = 0x006F, .second = 0x0311, .composite = 0x020F },
+    .{ .first = 0x006F, .second = 0x031B, .composite = 0x01A1 },
+    .{ .first = 0x006F, .second = 0x0323, .composite = 0x1ECD },
+    .{ .first = 0x006F, .second = 0x0328, .composite = 0x01EB },
+    .{ .first = 0x0070, .second = 0x0301, .composite = 0x1E55 },
+    .{ .first = 0x0070, .second = 0x0307, .composite = 0x1E57 },
+    .{ .first = 0x0072, .second = 0x0301, .composite = 0x0155 },
+    .{ .first = 0x0072, .second = 0x0307, .composite = 0x1E59 },
+    .{ .first = 0x0072, .second = 0x030C, .composite = 0x0159 },
+    .{ .first = 0x0072, .second = 0x030F, .composite = 0x0211 },
+    .{ .first = 0x0072, .second = 0x0311, .composite = 0x0213 },
+    .{ .first = 0x0072, .second = 0x0323, .composite = 0x1E5B },
+    .{ .first = 0x0072, .second = 0x0327, .composite = 0x0157 },
+    .{ .first = 0x0072, .second = 0x0331, .composite = 0x1E5F },
+    .{ .first = 0x0073, .second = 0x0301, .composite = 0x015B },
+    .{ .first = 0x0073, .second = 0x0302, .composite = 0x015D },
+    .{ .first = 0x0073, .second = 0x0307, .composite = 0x1E61 },
+    .{ .first = 0x0073, .second = 0x030C, .composite = 0x0161 },
+    .{ .first = 0x0073, .second = 0x0323, .composite = 0x1E63 },
+    .{ .first = 0x0073, .second = 0x0326, .composite = 0x0219 },
+    .{ .first = 0x0073, .second = 0x0327, .composite = 0x015F },
+    .{ .first = 0x0074, .second = 0x0307, .composite = 0x1E6B },
+    .{ .first = 0x0074, .second = 0x0308, .composite = 0x1E97 },
+    .{ .first = 0x0074, .second = 0x030C, .composite = 0x0165 },
+    .{ .first = 0x0074, .second = 0x0323, .composite = 0x1E6D },
+    .{ .first = 0x0074, .second = 0x0326, .composite = 0x021B },
+    .{ .first = 0x0074, .second = 0x0327, .composite = 0x0163 },
+    .{ .first = 0x0074, .second = 0x032D, .composite = 0x1E71 },
+    .{ .first = 0x0074, .second = 0x0331, .composite = 0x1E6F },
+    .{ .first = 0x0075, .second = 0x0300, .composite = 0x00F9 },
+    .{ .first = 0x0075, .second = 0x0301, .composite = 0x00FA },
+    .{ .first = 0x0075, .second = 0x0302, .composite = 0x00FB },
+    .{ .first = 0x0075, .second = 0x0303, .composite = 0x0169 },
+    .{ .first = 0x0075, .second = 0x0304, .composite = 0x016B },
+    .{ .first = 0x0075, .second = 0x0306, .composite = 0x016D },
+    .{ .first = 0x0075, .second = 0x0308, .composite = 0x00FC },
+    .{ .first = 0x0075, .second = 0x0309, .composite = 0x1EE7 },
+    .{ .first = 0x0075, .second = 0x030A, .composite = 0x016F },
+    .{ .first = 0x0075, .second = 0x030B, .composite = 0x0171 },
+    .{ .first = 0x0075, .second = 0x030C, .composite = 0x01D4 },
+    .{ .first = 0x0075, .second = 0x030F, .composite = 0x0215 },
+    .{ .first = 0x0075, .second = 0x0311, .composite = 0x0217 },
+    .{ .first = 0x0075, .second = 0x031B, .composite = 0x01B0 },
+    .{ .first = 0x0075, .second = 0x0323, .composite = 0x1EE5 },
+    .{ .first = 0x0075, .second = 0x0324, .composite = 0x1E73 },
+    .{ .first = 0x0075, .second = 0x0328, .composite = 0x0173 },
+    .{ .first = 0x0075, .second = 0x032D, .composite = 0x1E77 },
+    .{ .first = 0x0075, .second = 0x0330, .composite = 0x1E75 },
+    .{ .first = 0x0076, .second = 0x0303, .composite = 0x1E7D },
+    .{ .first = 0x0076, .second = 0x0323, .composite = 0x1E7F },
+    .{ .first = 0x0077, .second = 0x0300, .composite = 0x1E81 },
+    .{ .first = 0x0077, .second = 0x0301, .composite = 0x1E83 },
+    .{ .first = 0x0077, .second = 0x0302, .composite = 0x0175 },
+    .{ .first = 0x0077, .second = 0x0307, .composite = 0x1E87 },
+    .{ .first = 0x0077, .second = 0x0308, .composite = 0x1E85 },
+    .{ .first = 0x0077, .second = 0x030A, .composite = 0x1E98 },
+    .{ .first = 0x0077, .second = 0x0323, .composite = 0x1E89 },
+    .{ .first = 0x0078, .second = 0x0307, .composite = 0x1E8B },
+    .{ .first = 0x0078, .second = 0x0308, .composite = 0x1E8D },
+    .{ .first = 0x0079, .second = 0x0300, .composite = 0x1EF3 },
+    .{ .first = 0x0079, .second = 0x0301, .composite = 0x00FD },
+    .{ .first = 0x0079, .second = 0x0302, .composite = 0x0177 },
+    .{ .first = 0x0079, .second = 0x0303, .composite = 0x1EF9 },
+    .{ .first = 0x0079, .second = 0x0304, .composite = 0x0233 },
+    .{ .first = 0x0079, .second = 0x0307, .composite = 0x1E8F },
+    .{ .first = 0x0079, .second = 0x0308, .composite = 0x00FF },
+    .{ .first = 0x0079, .second = 0x0309, .composite = 0x1EF7 },
+    .{ .first = 0x0079, .second = 0x030A, .composite = 0x1E99 },
+    .{ .first = 0x0079, .second = 0x0323, .composite = 0x1EF5 },
+    .{ .first = 0x007A, .second = 0x0301, .composite = 0x017A },
+    .{ .first = 0x007A, .second = 0x0302, .composite = 0x1E91 },
+    .{ .first = 0x007A, .second = 0x0307, .composite = 0x017C },
+    .{ .first = 0x007A, .second = 0x030C, .composite = 0x017E },
+    .{ .first = 0x007A, .second = 0x0323, .composite = 0x1E93 },
+    .{ .first = 0x007A, .second = 0x0331, .composite = 0x1E95 },
+    .{ .first = 0x00A8, .second = 0x0300, .composite = 0x1FED },
+    .{ .first = 0x00A8, .second = 0x0301, .composite = 0x0385 },
+    .{ .first = 0x00A8, .second = 0x0342, .composite = 0x1FC1 },
+    .{ .first = 0x00C2, .second = 0x0300, .composite = 0x1EA6 },
+    .{ .first = 0x00C2, .second = 0x0301, .composite = 0x1EA4 },
+    .{ .first = 0x00C2, .second = 0x0303, .composite = 0x1EAA },
+    .{ .first = 0x00C2, .second = 0x0309, .composite = 0x1EA8 },
+    .{ .first = 0x00C4, .second = 0x0304, .composite = 0x01DE },
+    .{ .first = 0x00C5, .second = 0x0301, .composite = 0x01FA },
+    .{ .first = 0x00C6, .second = 0x0301, .composite = 0x01FC },
+    .{ .first = 0x00C6, .second = 0x0304, .composite = 0x01E2 },
+    .{ .first = 0x00C7, .second = 0x0301, .composite = 0x1E08 },
+    .{ .first = 0x00CA, .second = 0x0300, .composite = 0x1EC0 },
+    .{ .first = 0x00CA, .second = 0x0301, .composite = 0x1EBE },
+    .{ .first = 0x00CA, .second = 0x0303, .composite = 0x1EC4 },
+    .{ .first = 0x00CA, .second = 0x0309, .composite = 0x1EC2 },
+    .{ .first = 0x00CF, .second = 0x0301, .composite = 0x1E2E },
+    .{ .first = 0x00D4, .second = 0x0300, .composite = 0x1ED2 },
+    .{ .first = 0x00D4, .second = 0x0301, .composite = 0x1ED0 },
+    .{ .first = 0x00D4, .second = 0x0303, .composite = 0x1ED6 },
+    .{ .first = 0x00D4, .second = 0x0309, .composite = 0x1ED4 },
+    .{ .first = 0x00D5, .second = 0x0301, .composite = 0x1E4C },
+    .{ .first = 0x00D5, .second = 0x0304, .composite = 0x022C },
+    .{ .first = 0x00D5, .second = 0x0308, .composite = 0x1E4E },
+    .{ .first = 0x00D6, .second = 0x0304, .composite = 0x022A },
+    .{ .first = 0x00D8, .second = 0x0301, .composite = 0x01FE },
+    .{ .first = 0x00DC, .second = 0x0300, .composite = 0x01DB },
+    .{ .first = 0x00DC, .second = 0x0301, .composite = 0x01D7 },
+    .{ .first = 0x00DC, .second = 0x0304, .composite = 0x01D5 },
+    .{ .first = 0x00DC, .second = 0x030C, .composite = 0x01D9 },
+    .{ .first = 0x00E2, .second = 0x0300, .composite = 0x1EA7 },
+    .{ .first = 0x00E2, .second = 0x0301, .composite = 0x1EA5 },
+    .{ .first = 0x00E2, .second = 0x0303, .composite = 0x1EAB },
+    .{ .first = 0x00E2, .second = 0x0309, .composite = 0x1EA9 },
+    .{ .first = 0x00E4, .second = 0x0304, .composite = 0x01DF },
+    .{ .first = 0x00E5, .second = 0x0301, .composite = 0x01FB },
+    .{ .first = 0x00E6, .second = 0x0301, .composite = 0x01FD },
+    .{ .first = 0x00E6, .second = 0x0304, .composite = 0x01E3 },
+    .{ .first = 0x00E7, .second = 0x0301, .composite = 0x1E09 },
+    .{ .first = 0x00EA, .second = 0x0300, .composite = 0x1EC1 },
+    .{ .first = 0x00EA, .second = 0x0301, .composite = 0x1EBF },
+    .{ .first = 0x00EA, .second = 0x0303, .composite = 0x1EC5 },
+    .{ .first = 0x00EA, .second = 0x0309, .composite = 0x1EC3 },
+    .{ .first = 0x00EF, .second = 0x0301, .composite = 0x1E2F },
+    .{ .first = 0x00F4, .second = 0x0300, .composite = 0x1ED3 },
+    .{ .first = 0x00F4, .second = 0x0301, .composite = 0x1ED1 },
+    .{ .first = 0x00F4, .second = 0x0303, .composite = 0x1ED7 },
+    .{ .first = 0x00F4, .second = 0x0309, .composite = 0x1ED5 },
+    .{ .first = 0x00F5, .second = 0x0301, .composite = 0x1E4D },
+    .{ .first = 0x00F5, .second = 0x0304, .composite = 0x022D },
+    .{ .first = 0x00F5, .second = 0x0308, .composite = 0x1E4F },
+    .{ .first = 0x00F6, .second = 0x0304, .composite = 0x022B },
+    .{ .first = 0x00F8, .second = 0x0301, .composite = 0x01FF },
+    .{ .first = 0x00FC, .second = 0x0300, .composite = 0x01DC },
+    .{ .first = 0x00FC, .second = 0x0301, .composite = 0x01D8 },
+    .{ .first = 0x00FC, .second = 0x0304, .composite = 0x01D6 },
+    .{ .first = 0x00FC, .second = 0x030C, .composite = 0x01DA },
+    .{ .first = 0x0102, .second = 0x0300, .composite = 0x1EB0 },
+    .{ .first = 0x0102, .second = 0x0301, .composite = 0x1EAE },
+    .{ .first = 0x0102, .second = 0x0303, .composite = 0x1EB4 },
+    .{ .first = 0x0102, .second = 0x0309, .composite = 0x1EB2 },
+    .{ .first = 0x0103, .second = 0x0300, .composite = 0x1EB1 },
+    .{ .first = 0x0103, .second = 0x0301, .composite = 0x1EAF },
+    .{ .first = 0x0103, .second = 0x0303, .composite = 0x1EB5 },
+    .{ .first = 0x0103, .second = 0x0309, .composite = 0x1EB3 },
+    .{ .first = 0x0112, .second = 0x0300, .composite = 0x1E14 },
+    .{ .first = 0x0112, .second = 0x0301, .composite = 0x1E16 },
+    .{ .first = 0x0113, .second = 0x0300, .composite = 0x1E15 },
+    .{ .first = 0x0113, .second = 0x0301, .composite = 0x1E17 },
+    .{ .first = 0x014C, .second = 0x0300, .composite = 0x1E50 },
+    .{ .first = 0x014C, .second = 0x0301, .composite = 0x1E52 },
+    .{ .first = 0x014D, .second = 0x0300, .composite = 0x1E51 },
+    .{ .first = 0x014D, .second = 0x0301, .composite = 0x1E53 },
+    .{ .first = 0x015A, .second = 0x0307, .composite = 0x1E64 },
+    .{ .first = 0x015B, .second = 0x0307, .composite = 0x1E65 },
+    .{ .first = 0x0160, .second = 0x0307, .composite = 0x1E66 },
+    .{ .first = 0x0161, .second = 0x0307, .composite = 0x1E67 },
+    .{ .first = 0x0168, .second = 0x0301, .composite = 0x1E78 },
+    .{ .first = 0x0169, .second = 0x0301, .composite = 0x1E79 },
+    .{ .first = 0x016A, .second = 0x0308, .composite = 0x1E7A },
+    .{ .first = 0x016B, .second = 0x0308, .composite = 0x1E7B },
+    .{ .first = 0x017F, .second = 0x0307, .composite = 0x1E9B },
+    .{ .first = 0x01A0, .second = 0x0300, .composite = 0x1EDC },
+    .{ .first = 0x01A0, .second = 0x0301, .composite = 0x1EDA },
+    .{ .first = 0x01A0, .second = 0x0303, .composite = 0x1EE0 },
+    .{ .first = 0x01A0, .second = 0x0309, .composite = 0x1EDE },
+    .{ .first = 0x01A0, .second = 0x0323, .composite = 0x1EE2 },
+    .{ .first = 0x01A1, .second = 0x0300, .composite = 0x1EDD },
+    .{ .first = 0x01A1, .second = 0x0301, .composite = 0x1EDB },
+    .{ .first = 0x01A1, .second = 0x0303, .composite = 0x1EE1 },
+    .{ .first = 0x01A1, .second = 0x0309, .composite = 0x1EDF },
+    .{ .first = 0x01A1, .second = 0x0323, .composite = 0x1EE3 },
+    .{ .first = 0x01AF, .second = 0x0300, .composite = 0x1EEA },
+    .{ .first = 0x01AF, .second = 0x0301, .composite = 0x1EE8 },
+    .{ .first = 0x01AF, .second = 0x0303, .composite = 0x1EEE },
+    .{ .first = 0x01AF, .second = 0x0309, .composite = 0x1EEC },
+    .{ .first = 0x01AF, .second = 0x0323, .composite = 0x1EF0 },
+    .{ .first = 0x01B0, .second = 0x0300, .composite = 0x1EEB },
+    .{ .first = 0x01B0, .second = 0x0301, .composite = 0x1EE9 },
+    .{ .first = 0x01B0, .second = 0x0303, .composite = 0x1EEF },
+    .{ .first = 0x01B0, .second = 0x0309, .composite = 0x1EED },
+    .{ .first = 0x01B0, .second = 0x0323, .composite = 0x1EF1 },
+    .{ .first = 0x01B7, .second = 0x030C, .composite = 0x01EE },
+    .{ .first = 0x01EA, .second = 0x0304, .composite = 0x01EC },
+    .{ .first = 0x01EB, .second = 0x0304, .composite = 0x01ED },
+    .{ .first = 0x0226, .second = 0x0304, .composite = 0x01E0 },
+    .{ .first = 0x0227, .second = 0x0304, .composite = 0x01E1 },
+    .{ .first = 0x0228, .second = 0x0306, .composite = 0x1E1C },
+    .{ .first = 0x0229, .second = 0x0306, .composite = 0x1E1D },
+    .{ .first = 0x022E, .second = 0x0304, .composite = 0x0230 },
+    .{ .first = 0x022F, .second = 0x0304, .composite = 0x0231 },
+    .{ .first = 0x0292, .second = 0x030C, .composite = 0x01EF },
+    .{ .first = 0x0391, .second = 0x0300, .composite = 0x1FBA },
+    .{ .first = 0x0391, .second = 0x0301, .composite = 0x0386 },
+    .{ .first = 0x0391, .second = 0x0304, .composite = 0x1FB9 },
+    .{ .first = 0x0391, .second = 0x0306, .composite = 0x1FB8 },
+    .{ .first = 0x0391, .second = 0x0313, .composite = 0x1F08 },
+    .{ .first = 0x0391, .second = 0x0314, .composite = 0x1F09 },
+    .{ .first = 0x0391, .second = 0x0345, .composite = 0x1FBC },
+    .{ .first = 0x0395, .second = 0x0300, .composite = 0x1FC8 },
+    .{ .first = 0x0395, .second = 0x0301, .composite = 0x0388 },
+    .{ .first = 0x0395, .second = 0x0313, .composite = 0x1F18 },
+    .{ .first = 0x0395, .second = 0x0314, .composite = 0x1F19 },
+    .{ .first = 0x0397, .second = 0x0300, .composite = 0x1FCA },
+    .{ .first = 0x0397, .second = 0x0301, .composite = 0x0389 },
+    .{ .first = 0x0397, .second = 0x0313, .composite = 0x1F28 },
+    .{ .first = 0x0397, .second = 0x0314, .composite = 0x1F29 },
+    .{ .first = 0x0397, .second = 0x0345, .composite = 0x1FCC },
+    .{ .first = 0x0399, .second = 0x0300, .composite = 0x1FDA },
+    .{ .first = 0x0399, .second = 0x0301, .composite = 0x038A },
+    .{ .first = 0x0399, .second = 0x0304, .composite = 0x1FD9 },
+    .{ .first = 0x0399, .second = 0x0306, .composite = 0x1FD8 },
+    .{ .first = 0x0399, .second = 0x0308, .composite = 0x03AA },
+    .{ .first = 0x0399, .second = 0x0313, .composite = 0x1F38 },
+    .{ .first = 0x0399, .second = 0x0314, .composite = 0x1F39 },
+    .{ .first = 0x039F, .second = 0x0300, .composite = 0x1FF8 },
+    .{ .first = 0x039F, .second = 0x0301, .composite = 0x038C },
+    .{ .first = 0x039F, .second = 0x0313, .composite = 0x1F48 },
+    .{ .first = 0x039F, .second = 0x0314, .composite = 0x1F49 },
+    .{ .first = 0x03A1, .second = 0x0314, .composite = 0x1FEC },
+    .{ .first = 0x03A5, .second = 0x0300, .composite = 0x1FEA },
+    .{ .first = 0x03A5, .second = 0x0301, .composite = 0x038E },
+    .{ .first = 0x03A5, .second = 0x0304, .composite = 0x1FE9 },
+    .{ .first = 0x03A5, .second = 0x0306, .composite = 0x1FE8 },
+    .{ .first = 0x03A5, .second = 0x0308, .composite = 0x03AB },
+    .{ .first = 0x03A5, .second = 0x0314, .composite = 0x1F59 },
+    .{ .first = 0x03A9, .second = 0x0300, .composite = 0x1FFA },
+    .{ .first = 0x03A9, .second = 0x0301, .composite = 0x038F },
+    .{ .first = 0x03A9, .second = 0x0313, .composite = 0x1F68 },
+    .{ .first = 0x03A9, .second = 0x0314, .composite = 0x1F69 },
+    .{ .first = 0x03A9, .second = 0x0345, .composite = 0x1FFC },
+    .{ .first = 0x03AC, .second = 0x0345, .composite = 0x1FB4 },
+    .{ .first = 0x03AE, .second = 0x0345, .composite = 0x1FC4 },
+    .{ .first = 0x03B1, .second = 0x0300, .composite = 0x1F70 },
+    .{ .first = 0x03B1, .second = 0x0301, .composite = 0x03AC },
+    .{ .first = 0x03B1, .second = 0x0304, .composite = 0x1FB1 },
+    .{ .first = 0x03B1, .second = 0x0306, .composite = 0x1FB0 },
+    .{ .first = 0x03B1, .second = 0x0313, .composite = 0x1F00 },
+    .{ .first = 0x03B1, .second = 0x0314, .composite = 0x1F01 },
+    .{ .first = 0x03B1, .second = 0x0342, .composite = 0x1FB6 },
+    .{ .first = 0x03B1, .second = 0x0345, .composite = 0x1FB3 },
+    .{ .first = 0x03B5, .second = 0x0300, .composite = 0x1F72 },
+    .{ .first = 0x03B5, .second = 0x0301, .composite = 0x03AD },
+    .{ .first = 0x03B5, .second = 0x0313, .composite = 0x1F10 },
+    .{ .first = 0x03B5, .second = 0x0314, .composite = 0x1F11 },
+    .{ .first = 0x03B7, .second = 0x0300, .composite = 0x1F74 },
+    .{ .first = 0x03B7, .second = 0x0301, .composite = 0x03AE },
+    .{ .first = 0x03B7, .second = 0x0313, .composite = 0x1F20 },
+    .{ .first = 0x03B7, .second = 0x0314, .composite = 0x1F21 },
+    .{ .first = 0x03B7, .second = 0x0342, .composite = 0x1FC6 },
+    .{ .first = 0x03B7, .second = 0x0345, .composite = 0x1FC3 },
+    .{ .first = 0x03B9, .second = 0x0300, .composite = 0x1F76 },
+    .{ .first = 0x03B9, .second = 0x0301, .composite = 0x03AF },
+    .{ .first = 0x03B9, .second = 0x0304, .composite = 0x1FD1 },
+    .{ .first = 0x03B9, .second = 0x0306, .composite = 0x1FD0 },
+    .{ .first = 0x03B9, .second = 0x0308, .composite = 0x03CA },
+    .{ .first = 0x03B9, .second = 0x0313, .composite = 0x1F30 },
+    .{ .first = 0x03B9, .second = 0x0314, .composite = 0x1F31 },
+    .{ .first = 0x03B9, .second = 0x0342, .composite = 0x1FD6 },
+    .{ .first = 0x03BF, .second = 0x0300, .composite = 0x1F78 },
+    .{ .first = 0x03BF, .second = 0x0301, .composite = 0x03CC },
+    .{ .first = 0x03BF, .second = 0x0313, .composite = 0x1F40 },
+    .{ .first = 0x03BF, .second = 0x0314, .composite = 0x1F41 },
+    .{ .first = 0x03C1, .second = 0x0313, .composite = 0x1FE4 },
+    .{ .first = 0x03C1, .second = 0x0314, .composite = 0x1FE5 },
+    .{ .first = 0x03C5, .second = 0x0300, .composite = 0x1F7A },
+    .{ .first = 0x03C5, .second = 0x0301, .composite = 0x03CD },
+    .{ .first = 0x03C5, .second = 0x0304, .composite = 0x1FE1 },
+    .{ .first = 0x03C5, .second = 0x0306, .composite = 0x1FE0 },
+    .{ .first = 0x03C5, .second = 0x0308, .composite = 0x03CB },
+    .{ .first = 0x03C5, .second = 0x0313, .composite = 0x1F50 },
+    .{ .first = 0x03C5, .second = 0x0314, .composite = 0x1F51 },
+    .{ .first = 0x03C5, .second = 0x0342, .composite = 0x1FE6 },
+    .{ .first = 0x03C9, .second = 0x0300, .composite = 0x1F7C },
+    .{ .first = 0x03C9, .second = 0x0301, .composite = 0x03CE },
+    .{ .first = 0x03C9, .second = 0x0313, .composite = 0x1F60 },
+    .{ .first = 0x03C9, .second = 0x0314, .composite = 0x1F61 },
+    .{ .first = 0x03C9, .second = 0x0342, .composite = 0x1FF6 },
+    .{ .first = 0x03C9, .second = 0x0345, .composite = 0x1FF3 },
+    .{ .first = 0x03CA, .second = 0x0300, .composite = 0x1FD2 },
+    .{ .first = 0x03CA, .second = 0x0301, .composite = 0x0390 },
+    .{ .first = 0x03CA, .second = 0x0342, .composite = 0x1FD7 },
+    .{ .first = 0x03CB, .second = 0x0300, .composite = 0x1FE2 },
+    .{ .first = 0x03CB, .second = 0x0301, .composite = 0x03B0 },
+    .{ .first = 0x03CB, .second = 0x0342, .composite = 0x1FE7 },
+    .{ .first = 0x03CE, .second = 0x0345, .composite = 0x1FF4 },
+    .{ .first = 0x03D2, .second = 0x0301, .composite = 0x03D3 },
+    .{ .first = 0x03D2, .second = 0x0308, .composite = 0x03D4 },
+    .{ .first = 0x0406, .second = 0x0308, .composite = 0x0407 },
+    .{ .first = 0x0410, .second = 0x0306, .composite = 0x04D0 },
+    .{ .first = 0x0410, .second = 0x0308, .composite = 0x04D2 },
+    .{ .first = 0x0413, .second = 0x0301, .composite = 0x0403 },
+    .{ .first = 0x0415, .second = 0x0300, .composite = 0x0400 },
+    .{ .first = 0x0415, .second = 0x0306, .composite = 0x04D6 },
+    .{ .first = 0x0415, .second = 0x0308, .composite = 0x0401 },
+    .{ .first = 0x0416, .second = 0x0306, .composite = 0x04C1 },
+    .{ .first = 0x0416, .second = 0x0308, .composite = 0x04DC },
+    .{ .first = 0x0417, .second = 0x0308, .composite = 0x04DE },
+    .{ .first = 0x0418, .second = 0x0300, .composite = 0x040D },
+    .{ .first = 0x0418, .second = 0x0304, .composite = 0x04E2 },
+    .{ .first = 0x0418, .second = 0x0306, .composite = 0x0419 },
+    .{ .first = 0x0418, .second = 0x0308, .composite = 0x04E4 },
+    .{ .first = 0x041A, .second = 0x0301, .composite = 0x040C },
+    .{ .first = 0x041E, .second = 0x0308, .composite = 0x04E6 },
+    .{ .first = 0x0423, .second = 0x0304, .composite = 0x04EE },
+    .{ .first = 0x0423, .second = 0x0306, .composite = 0x040E },
+    .{ .first = 0x0423, .second = 0x0308, .composite = 0x04F0 },
+    .{ .first = 0x0423, .second = 0x030B, .composite = 0x04F2 },
+    .{ .first = 0x0427, .second = 0x0308, .composite = 0x04F4 },
+    .{ .first = 0x042B, .second = 0x0308, .composite = 0x04F8 },
+    .{ .first = 0x042D, .second = 0x0308, .composite = 0x04EC },
+    .{ .first = 0x0430, .second = 0x0306, .composite = 0x04D1 },
+    .{ .first = 0x0430, .second = 0x0308, .composite = 0x04D3 },
+    .{ .first = 0x0433, .second = 0x0301, .composite = 0x0453 },
+    .{ .first = 0x0435, .second = 0x0300, .composite = 0x0450 },
+    .{ .first = 0x0435, .second = 0x0306, .composite = 0x04D7 },
+    .{ .first = 0x0435, .second = 0x0308, .composite = 0x0451 },
+    .{ .first = 0x0436, .second = 0x0306, .composite = 0x04C2 },
+    .{ .first = 0x0436, .second = 0x0308, .composite = 0x04DD },
+    .{ .first = 0x0437, .second = 0x0308, .composite = 0x04DF },
+    .{ .first = 0x0438, .second = 0x0300, .composite = 0x045D },
+    .{ .first = 0x0438, .second = 0x0304, .composite = 0x04E3 },
+    .{ .first = 0x0438, .second = 0x0306, .composite = 0x0439 },
+    .{ .first = 0x0438, .second = 0x0308, .composite = 0x04E5 },
+    .{ .first = 0x043A, .second = 0x0301, .composite = 0x045C },
+    .{ .first = 0x043E, .second = 0x0308, .composite = 0x04E7 },
+    .{ .first = 0x0443, .second = 0x0304, .composite = 0x04EF },
+    .{ .first = 0x0443, .second = 0x0306, .composite = 0x045E },
+    .{ .first = 0x0443, .second = 0x0308, .composite = 0x04F1 },
+    .{ .first = 0x0443, .second = 0x030B, .composite = 0x04F3 },
+    .{ .first = 0x0447, .second = 0x0308, .composite = 0x04F5 },
+    .{ .first = 0x044B, .second = 0x0308, .composite = 0x04F9 },
+    .{ .first = 0x044D, .second = 0x0308, .composite = 0x04ED },
+    .{ .first = 0x0456, .second = 0x0308, .composite = 0x0457 },
+    .{ .first = 0x0474, .second = 0x030F, .composite = 0x0476 },
+    .{ .first = 0x0475, .second = 0x030F, .composite = 0x0477 },
+    .{ .first = 0x04D8, .second = 0x0308, .composite = 0x04DA },
+    .{ .first = 0x04D9, .second = 0x0308, .composite = 0x04DB },
+    .{ .first = 0x04E8, .second = 0x0308, .composite = 0x04EA },
+    .{ .first = 0x04E9, .second = 0x0308, .composite = 0x04EB },
+    .{ .first = 0x0627, .second = 0x0653, .composite = 0x0622 },
+    .{ .first = 0x0627, .second = 0x0654, .composite = 0x0623 },
+    .{ .first = 0x0627, .second = 0x0655, .composite = 0x0625 },
+    .{ .first = 0x0648, .second = 0x0654, .composite = 0x0624 },
+    .{ .first = 0x064A, .second = 0x0654, .composite = 0x0626 },
+    .{ .first = 0x06C1, .second = 0x0654, .composite = 0x06C2 },
+    .{ .first = 0x06D2, .second = 0x0654, .composite = 0x06D3 },
+    .{ .first = 0x06D5, .second = 0x0654, .composite = 0x06C0 },
+    .{ .first = 0x0928, .second = 0x093C, .composite = 0x0929 },
+    .{ .first = 0x0930, .second = 0x093C, .composite = 0x0931 },
+    .{ .first = 0x0933, .second = 0x093C, .composite = 0x0934 },
+    .{ .first = 0x09C7, .second = 0x09BE, .composite = 0x09CB },
+    .{ .first = 0x09C7, .second = 0x09D7, .composite = 0x09CC },
+    .{ .first = 0x0B47, .second = 0x0B3E, .composite = 0x0B4B },
+    .{ .first = 0x0B47, .second = 0x0B56, .composite = 0x0B48 },
+    .{ .first = 0x0B47, .second = 0x0B57, .composite = 0x0B4C },
+    .{ .first = 0x0B92, .second = 0x0BD7, .composite = 0x0B94 },
+    .{ .first = 0x0BC6, .second = 0x0BBE, .composite = 0x0BCA },
+    .{ .first = 0x0BC6, .second = 0x0BD7, .composite = 0x0BCC },
+    .{ .first = 0x0BC7, .second = 0x0BBE, .composite = 0x0BCB },
+    .{ .first = 0x0C46, .second = 0x0C56, .composite = 0x0C48 },
+    .{ .first = 0x0CBF, .second = 0x0CD5, .composite = 0x0CC0 },
+    .{ .first = 0x0CC6, .second = 0x0CC2, .composite = 0x0CCA },
+    .{ .first = 0x0CC6, .second = 0x0CD5, .composite = 0x0CC7 },
+    .{ .first = 0x0CC6, .second = 0x0CD6, .composite = 0x0CC8 },
+    .{ .first = 0x0CCA, .second = 0x0CD5, .composite = 0x0CCB },
+    .{ .first = 0x0D46, .second = 0x0D3E, .composite = 0x0D4A },
+    .{ .first = 0x0D46, .second = 0x0D57, .composite = 0x0D4C },
+    .{ .first = 0x0D47, .second = 0x0D3E, .composite = 0x0D4B },
+    .{ .first = 0x0DD9, .second = 0x0DCA, .composite = 0x0DDA },
+    .{ .first = 0x0DD9, .second = 0x0DCF, .composite = 0x0DDC },
+    .{ .first = 0x0DD9, .second = 0x0DDF, .composite = 0x0DDE },
+    .{ .first = 0x0DDC, .second = 0x0DCA, .composite = 0x0DDD },
+    .{ .first = 0x1025, .second = 0x102E, .composite = 0x1026 },
+    .{ .first = 0x1B05, .second = 0x1B35, .composite = 0x1B06 },
+    .{ .first = 0x1B07, .second = 0x1B35, .composite = 0x1B08 },
+    .{ .first = 0x1B09, .second = 0x1B35, .composite = 0x1B0A },
+    .{ .first = 0x1B0B, .second = 0x1B35, .composite = 0x1B0C },
+    .{ .first = 0x1B0D, .second = 0x1B35, .composite = 0x1B0E },
+    .{ .first = 0x1B11, .second = 0x1B35, .composite = 0x1B12 },
+    .{ .first = 0x1B3A, .second = 0x1B35, .composite = 0x1B3B },
+    .{ .first = 0x1B3C, .second = 0x1B35, .composite = 0x1B3D },
+    .{ .first = 0x1B3E, .second = 0x1B35, .composite = 0x1B40 },
+    .{ .first = 0x1B3F, .second = 0x1B35, .composite = 0x1B41 },
+    .{ .first = 0x1B42, .second = 0x1B35, .composite = 0x1B43 },
+    .{ .first = 0x1E36, .second = 0x0304, .composite = 0x1E38 },
+    .{ .first = 0x1E37, .second = 0x0304, .composite = 0x1E39 },
+    .{ .first = 0x1E5A, .second = 0x0304, .composite = 0x1E5C },
+    .{ .first = 0x1E5B, .second = 0x0304, .composite = 0x1E5D },
+    .{ .first = 0x1E62, .second = 0x0307, .composite = 0x1E68 },
+    .{ .first = 0x1E63, .second = 0x0307, .composite = 0x1E69 },
+    .{ .first = 0x1EA0, .second = 0x0302, .composite = 0x1EAC },
+    .{ .first = 0x1EA0, .second = 0x0306, .composite = 0x1EB6 },
+    .{ .first = 0x1EA1, .second = 0x0302, .composite = 0x1EAD },
+    .{ .first = 0x1EA1, .second = 0x0306, .composite = 0x1EB7 },
+    .{ .first = 0x1EB8, .second = 0x0302, .composite = 0x1EC6 },
+    .{ .first = 0x1EB9, .second = 0x0302, .composite = 0x1EC7 },
+    .{ .first = 0x1ECC, .second = 0x0302, .composite = 0x1ED8 },
+    .{ .first = 0x1ECD, .second = 0x0302, .composite = 0x1ED9 },
+    .{ .first = 0x1F00, .second = 0x0300, .composite = 0x1F02 },
+    .{ .first = 0x1F00, .second = 0x0301, .composite = 0x1F04 },
+    .{ .first = 0x1F00, .second = 0x0342, .composite = 0x1F06 },
+    .{ .first = 0x1F00, .second = 0x0345, .composite = 0x1F80 },
+    .{ .first = 0x1F01, .second = 0x0300, .composite = 0x1F03 },
+    .{ .first = 0x1F01, .second = 0x0301, .composite = 0x1F05 },
+    .{ .first = 0x1F01, .second = 0x0342, .composite = 0x1F07 },
+    .{ .first = 0x1F01, .second = 0x0345, .composite = 0x1F81 },
+    .{ .first = 0x1F02, .second = 0x0345, .composite = 0x1F82 },
+    .{ .first = 0x1F03, .second = 0x0345, .composite = 0x1F83 },
+    .{ .first = 0x1F04, .second = 0x0345, .composite = 0x1F84 },
+    .{ .first = 0x1F05, .second = 0x0345, .composite = 0x1F85 },
+    .{ .first = 0x1F06, .second = 0x0345, .composite = 0x1F86 },
+    .{ .first = 0x1F07, .second = 0x0345, .composite = 0x1F87 },
+    .{ .first = 0x1F08, .second = 0x0300, .composite = 0x1F0A },
+    .{ .first = 0x1F08, .second = 0x0301, .composite = 0x1F0C },
+    .{ .first = 0x1F08, .second = 0x0342, .composite = 0x1F0E },
+    .{ .first = 0x1F08, .second = 0x0345, .composite = 0x1F88 },
+    .{ .first = 0x1F09, .second = 0x0300, .composite = 0x1F0B },
+    .{ .first = 0x1F09, .second = 0x0301, .composite = 0x1F0D },
+    .{ .first = 0x1F09, .second = 0x0342, .composite = 0x1F0F },
+    .{ .first = 0x1F09, .second = 0x0345, .composite = 0x1F89 },
+    .{ .first = 0x1F0A, .second = 0x0345, .composite = 0x1F8A },
+    .{ .first = 0x1F0B, .second = 0x0345, .composite = 0x1F8B },
+    .{ .first = 0x1F0C, .second = 0x0345, .composite = 0x1F8C },
+    .{ .first = 0x1F0D, .second = 0x0345, .composite = 0x1F8D },
+    .{ .first = 0x1F0E, .second = 0x0345, .composite = 0x1F8E },
+    .{ .first = 0x1F0F, .second = 0x0345, .composite = 0x1F8F },
+    .{ .first = 0x1F10, .second = 0x0300, .composite = 0x1F12 },
+    .{ .first = 0x1F10, .second = 0x0301, .composite = 0x1F14 },
+    .{ .first = 0x1F11, .second = 0x0300, .composite = 0x1F13 },
+    .{ .first = 0x1F11, .second = 0x0301, .composite = 0x1F15 },
+    .{ .first = 0x1F18, .second = 0x0300, .composite = 0x1F1A },
+    .{ .first = 0x1F18, .second = 0x0301, .composite = 0x1F1C },
+    .{ .first = 0x1F19, .second = 0x0300, .composite = 0x1F1B },
+    .{ .first = 0x1F19, .second = 0x0301, .composite = 0x1F1D },
+    .{ .first = 0x1F20, .second = 0x0300, .composite = 0x1F22 },
+    .{ .first = 0x1F20, .second = 0x0301, .composite = 0x1F24 },
+    .{ .first = 0x1F20, .second = 0x0342, .composite = 0x1F26 },
+    .{ .first = 0x1F20, .second = 0x0345, .composite = 0x1F90 },
+    .{ .first = 0x1F21, .second = 0x0300, .composite = 0x1F23 },
+    .{ .first = 0x1F21, .second = 0x0301, .composite = 0x1F25 },
+    .{ .first = 0x1F21, .second = 0x0342, .composite = 0x1F27 },
+    .{ .first = 0x1F21, .second = 0x0345, .composite = 0x1F91 },
+    .{ .first = 0x1F22, .second = 0x0345, .composite = 0x1F92 },
+    .{ .first = 0x1F23, .second = 0x0345, .composite = 0x1F93 },
+    .{ .first = 0x1F24, .second = 0x0345, .composite = 0x1F94 },
+    .{ .first = 0x1F25, .second = 0x0345, .composite = 0x1F95 },
+    .{ .first = 0x1F26, .second = 0x0345, .composite = 0x1F96 },
+    .{ .first = 0x1F27, .second = 0x0345, .composite = 0x1F97 },
+    .{ .first = 0x1F28, .second = 0x0300, .composite = 0x1F2A },
+    .{ .first = 0x1F28, .second = 0x0301, .composite = 0x1F2C },
+    .{ .first = 0x1F28, .second = 0x0342, .composite = 0x1F2E },
+    .{ .first = 0x1F28, .second = 0x0345, .composite = 0x1F98 },
+    .{ .first = 0x1F29, .second = 0x0300, .composite = 0x1F2B },
+    .{ .first = 0x1F29, .second = 0x0301, .composite = 0x1F2D },
+    .{ .first = 0x1F29, .second = 0x0342, .composite = 0x1F2F },
+    .{ .first = 0x1F29, .second = 0x0345, .composite = 0x1F99 },
+    .{ .first = 0x1F2A, .second = 0x0345, .composite = 0x1F9A },
+    .{ .first = 0x1F2B, .second = 0x0345, .composite = 0x1F9B },
+    .{ .first = 0x1F2C, .second = 0x0345, .composite = 0x1F9C },
+    .{ .first = 0x1F2D, .second = 0x0345, .composite = 0x1F9D },
+    .{ .first = 0x1F2E, .second = 0x0345, .composite = 0x1F9E },
+    .{ .first = 0x1F2F, .second = 0x0345, .composite = 0x1F9F },
+    .{ .first = 0x1F30, .second = 0x0300, .composite = 0x1F32 },
+    .{ .first = 0x1F30, .second = 0x0301, .composite = 0x1F34 },
+    .{ .first = 0x1F30, .second = 0x0342, .composite = 0x1F36 },
+    .{ .first = 0x1F31, .second = 0x0300, .composite = 0x1F33 },
+    .{ .first = 0x1F31, .second = 0x0301, .composite = 0x1F35 },
+    .{ .first = 0x1F31, .second = 0x0342, .composite = 0x1F37 },
+    .{ .first = 0x1F38, .second = 0x0300, .composite = 0x1F3A },
+    .{ .first = 0x1F38, .second = 0x0301, .composite = 0x1F3C },
+    .{ .first = 0x1F38, .second = 0x0342, .composite = 0x1F3E },
+    .{ .first = 0x1F39, .second = 0x0300, .composite = 0x1F3B },
+    .{ .first = 0x1F39, .second = 0x0301, .composite = 0x1F3D },
+    .{ .first = 0x1F39, .second = 0x0342, .composite = 0x1F3F },
+    .{ .first = 0x1F40, .second = 0x0300, .composite = 0x1F42 },
+    .{ .first = 0x1F40, .second = 0x0301, .composite = 0x1F44 },
+    .{ .first = 0x1F41, .second = 0x0300, .composite = 0x1F43 },
+    .{ .first = 0x1F41, .second = 0x0301, .composite = 0x1F45 },
+    .{ .first = 0x1F48, .second = 0x0300, .composite = 0x1F4A },
+    .{ .first = 0x1F48, .second = 0x0301, .composite = 0x1F4C },
+    .{ .first = 0x1F49, .second = 0x0300, .composite = 0x1F4B },
+    .{ .first = 0x1F49, .second = 0x0301, .composite = 0x1F4D },
+    .{ .first = 0x1F50, .second = 0x0300, .composite = 0x1F52 },
+    .{ .first = 0x1F50, .second = 0x0301, .composite = 0x1F54 },
+    .{ .first = 0x1F50, .second = 0x0342, .composite = 0x1F56 },
+    .{ .first = 0x1F51, .second = 0x0300, .composite = 0x1F53 },
+    .{ .first = 0x1F51, .second = 0x0301, .composite = 0x1F55 },
+    .{ .first = 0x1F51, .second = 0x0342, .composite = 0x1F57 },
+    .{ .first = 0x1F59, .second = 0x0300, .composite = 0x1F5B },
+    .{ .first = 0x1F59, .second = 0x0301, .composite = 0x1F5D },
+    .{ .first = 0x1F59, .second = 0x0342, .composite = 0x1F5F },
+    .{ .first = 0x1F60, .second = 0x0300, .composite = 0x1F62 },
+    .{ .first = 0x1F60, .second = 0x0301, .composite = 0x1F64 },
+    .{ .first = 0x1F60, .second = 0x0342, .composite = 0x1F66 },
+    .{ .first = 0x1F60, .second = 0x0345, .composite = 0x1FA0 },
+    .{ .first = 0x1F61, .second = 0x0300, .composite = 0x1F63 },
+    .{ .first = 0x1F61, .second = 0x0301, .composite = 0x1F65 },
+    .{ .first = 0x1F61, .second = 0x0342, .composite = 0x1F67 },
+    .{ .first = 0x1F61, .second = 0x0345, .composite = 0x1FA1 },
+    .{ .first = 0x1F62, .second = 0x0345, .composite = 0x1FA2 },
+    .{ .first = 0x1F63, .second = 0x0345, .composite = 0x1FA3 },
+    .{ .first = 0x1F64, .second = 0x0345, .composite = 0x1FA4 },
+    .{ .first = 0x1F65, .second = 0x0345, .composite = 0x1FA5 },
+    .{ .first = 0x1F66, .second = 0x0345, .composite = 0x1FA6 },
+    .{ .first = 0x1F67, .second = 0x0345, .composite = 0x1FA7 },
+    .{ .first = 0x1F68, .second = 0x0300, .composite = 0x1F6A },
+    .{ .first = 0x1F68, .second = 0x0301, .composite = 0x1F6C },
+    .{ .first = 0x1F68, .second = 0x0342, .composite = 0x1F6E },
+    .{ .first = 0x1F68, .second = 0x0345, .composite = 0x1FA8 },
+    .{ .first = 0x1F69, .second = 0x0300, .composite = 0x1F6B },
+    .{ .first = 0x1F69, .second = 0x0301, .composite = 0x1F6D },
+    .{ .first = 0x1F69, .second = 0x0342, .composite = 0x1F6F },
+    .{ .first = 0x1F69, .second = 0x0345, .composite = 0x1FA9 },
+    .{ .first = 0x1F6A, .second = 0x0345, .composite = 0x1FAA },
+    .{ .first = 0x1F6B, .second = 0x0345, .composite = 0x1FAB },
+    .{ .first = 0x1F6C, .second = 0x0345, .composite = 0x1FAC },
+    .{ .first = 0x1F6D, .second = 0x0345, .composite = 0x1FAD },
+    .{ .first = 0x1F6E, .second = 0x0345, .composite = 0x1FAE },
+    .{ .first = 0x1F6F, .second = 0x0345, .composite = 0x1FAF },
+    .{ .first = 0x1F70, .second = 0x0345, .composite = 0x1FB2 },
+    .{ .first = 0x1F74, .second = 0x0345, .composite = 0x1FC2 },
+    .{ .first = 0x1F7C, .second = 0x0345, .composite = 0x1FF2 },
+    .{ .first = 0x1FB6, .second = 0x0345, .composite = 0x1FB7 },
+    .{ .first = 0x1FBF, .second = 0x0300, .composite = 0x1FCD },
+    .{ .first = 0x1FBF, .second = 0x0301, .composite = 0x1FCE },
+    .{ .first = 0x1FBF, .second = 0x0342, .composite = 0x1FCF },
+    .{ .first = 0x1FC6, .second = 0x0345, .composite = 0x1FC7 },
+    .{ .first = 0x1FF6, .second = 0x0345, .composite = 0x1FF7 },
+    .{ .first = 0x1FFE, .second = 0x0300, .composite = 0x1FDD },
+    .{ .first = 0x1FFE, .second = 0x0301, .composite = 0x1FDE },
+    .{ .first = 0x1FFE, .second = 0x0342, .composite = 0x1FDF },
+    .{ .first = 0x2190, .second = 0x0338, .composite = 0x219A },
+    .{ .first = 0x2192, .second = 0x0338, .composite = 0x219B },
+    .{ .first = 0x2194, .second = 0x0338, .composite = 0x21AE },
+    .{ .first = 0x21D0, .second = 0x0338, .composite = 0x21CD },
+    .{ .first = 0x21D2, .second = 0x0338, .composite = 0x21CF },
+    .{ .first = 0x21D4, .second = 0x0338, .composite = 0x21CE },
+    .{ .first = 0x2203, .second = 0x0338, .composite = 0x2204 },
+    .{ .first = 0x2208, .second = 0x0338, .composite = 0x2209 },
+    .{ .first = 0x220B, .second = 0x0338, .composite = 0x220C },
+    .{ .first = 0x2223, .second = 0x0338, .composite = 0x2224 },
+    .{ .first = 0x2225, .second = 0x0338, .composite = 0x2226 },
+    .{ .first = 0x223C, .second = 0x0338, .composite = 0x2241 },
+    .{ .first = 0x2243, .second = 0x0338, .composite = 0x2244 },
+    .{ .first = 0x2245, .second = 0x0338, .composite = 0x2247 },
+    .{ .first = 0x2248, .second = 0x0338, .composite = 0x2249 },
+    .{ .first = 0x224D, .second = 0x0338, .composite = 0x226D },
+    .{ .first = 0x2261, .second = 0x0338, .composite = 0x2262 },
+    .{ .first = 0x2264, .second = 0x0338, .composite = 0x2270 },
+    .{ .first = 0x2265, .second = 0x0338, .composite = 0x2271 },
+    .{ .first = 0x2272, .second = 0x0338, .composite = 0x2274 },
+    .{ .first = 0x2273, .second = 0x0338, .composite = 0x2275 },
+    .{ .first = 0x2276, .second = 0x0338, .composite = 0x2278 },
+    .{ .first = 0x2277, .second = 0x0338, .composite = 0x2279 },
+    .{ .first = 0x227A, .second = 0x0338, .composite = 0x2280 },
+    .{ .first = 0x227B, .second = 0x0338, .composite = 0x2281 },
+    .{ .first = 0x227C, .second = 0x0338, .composite = 0x22E0 },
+    .{ .first = 0x227D, .second = 0x0338, .composite = 0x22E1 },
+    .{ .first = 0x2282, .second = 0x0338, .composite = 0x2284 },
+    .{ .first = 0x2283, .second = 0x0338, .composite = 0x2285 },
+    .{ .first = 0x2286, .second = 0x0338, .composite = 0x2288 },
+    .{ .first = 0x2287, .second = 0x0338, .composite = 0x2289 },
+    .{ .first = 0x2291, .second = 0x0338, .composite = 0x22E2 },
+    .{ .first = 0x2292, .second = 0x0338, .composite = 0x22E3 },
+    .{ .first = 0x22A2, .second = 0x0338, .composite = 0x22AC },
+    .{ .first = 0x22A8, .second = 0x0338, .composite = 0x22AD },
+    .{ .first = 0x22A9, .second = 0x0338, .composite = 0x22AE },
+    .{ .first = 0x22AB, .second = 0x0338, .composite = 0x22AF },
+    .{ .first = 0x22B2, .second = 0x0338, .composite = 0x22EA },
+    .{ .first = 0x22B3, .second = 0x0338, .composite = 0x22EB },
+    .{ .first = 0x22B4, .second = 0x0338, .composite = 0x22EC },
+    .{ .first = 0x22B5, .second = 0x0338, .composite = 0x22ED },
+    .{ .first = 0x3046, .second = 0x3099, .composite = 0x3094 },
+    .{ .first = 0x304B, .second = 0x3099, .composite = 0x304C },
+    .{ .first = 0x304D, .second = 0x3099, .composite = 0x304E },
+    .{ .first = 0x304F, .second = 0x3099, .composite = 0x3050 },
+    .{ .first = 0x3051, .second = 0x3099, .composite = 0x3052 },
+    .{ .first = 0x3053, .second = 0x3099, .composite = 0x3054 },
+    .{ .first = 0x3055, .second = 0x3099, .composite = 0x3056 },
+    .{ .first = 0x3057, .second = 0x3099, .composite = 0x3058 },
+    .{ .first = 0x3059, .second = 0x3099, .composite = 0x305A },
+    .{ .first = 0x305B, .second = 0x3099, .composite = 0x305C },
+    .{ .first = 0x305D, .second = 0x3099, .composite = 0x305E },
+    .{ .first = 0x305F, .second = 0x3099, .composite = 0x3060 },
+    .{ .first = 0x3061, .second = 0x3099, .composite = 0x3062 },
+    .{ .first = 0x3064, .second = 0x3099, .composite = 0x3065 },
+    .{ .first = 0x3066, .second = 0x3099, .composite = 0x3067 },
+    .{ .first = 0x3068, .second = 0x3099, .composite = 0x3069 },
+    .{ .first = 0x306F, .second = 0x3099, .composite = 0x3070 },
+    .{ .first = 0x306F, .second = 0x309A, .composite = 0x3071 },
+    .{ .first = 0x3072, .second = 0x3099, .composite = 0x3073 },
+    .{ .first = 0x3072, .second = 0x309A, .composite = 0x3074 },
+    .{ .first = 0x3075, .second = 0x3099, .composite = 0x3076 },
+    .{ .first = 0x3075, .second = 0x309A, .composite = 0x3077 },
+    .{ .first = 0x3078, .second = 0x3099, .composite = 0x3079 },
+    .{ .first = 0x3078, .second = 0x309A, .composite = 0x307A },
+    .{ .first = 0x307B, .second = 0x3099, .composite = 0x307C },
+    .{ .first = 0x307B, .second = 0x309A, .composite = 0x307D },
+    .{ .first = 0x309D, .second = 0x3099, .composite = 0x309E },
+    .{ .first = 0x30A6, .second = 0x3099, .composite = 0x30F4 },
+    .{ .first = 0x30AB, .second = 0x3099, .composite = 0x30AC },
+    .{ .first = 0x30AD, .second = 0x3099, .composite = 0x30AE },
+    .{ .first = 0x30AF, .second = 0x3099, .composite = 0x30B0 },
+    .{ .first = 0x30B1, .second = 0x3099, .composite = 0x30B2 },
+    .{ .first = 0x30B3, .second = 0x3099, .composite = 0x30B4 },
+    .{ .first = 0x30B5, .second = 0x3099, .composite = 0x30B6 },
+    .{ .first = 0x30B7, .second = 0x3099, .composite = 0x30B8 },
+    .{ .first = 0x30B9, .second = 0x3099, .composite = 0x30BA },
+    .{ .first = 0x30BB, .second = 0x3099, .composite = 0x30BC },
+    .{ .first = 0x30BD, .second = 0x3099, .composite = 0x30BE },
+    .{ .first = 0x30BF, .second = 0x3099, .composite = 0x30C0 },
+    .{ .first = 0x30C1, .second = 0x3099, .composite = 0x30C2 },
+    .{ .first = 0x30C4, .second = 0x3099, .composite = 0x30C5 },
+    .{ .first = 0x30C6, .second = 0x3099, .composite = 0x30C7 },
+    .{ .first = 0x30C8, .second = 0x3099, .composite = 0x30C9 },
+    .{ .first = 0x30CF, .second = 0x3099, .composite = 0x30D0 },
+    .{ .first = 0x30CF, .second = 0x309A, .composite = 0x30D1 },
+    .{ .first = 0x30D2, .second = 0x3099, .composite = 0x30D3 },
+    .{ .first = 0x30D2, .second = 0x309A, .composite = 0x30D4 },
+    .{ .first = 0x30D5, .second = 0x3099, .composite = 0x30D6 },
+    .{ .first = 0x30D5, .second = 0x309A, .composite = 0x30D7 },
+    .{ .first = 0x30D8, .second = 0x3099, .composite = 0x30D9 },
+    .{ .first = 0x30D8, .second = 0x309A, .composite = 0x30DA },
+    .{ .first = 0x30DB, .second = 0x3099, .composite = 0x30DC },
+    .{ .first = 0x30DB, .second = 0x309A, .composite = 0x30DD },
+    .{ .first = 0x30EF, .second = 0x3099, .composite = 0x30F7 },
+    .{ .first = 0x30F0, .second = 0x3099, .composite = 0x30F8 },
+    .{ .first = 0x30F1, .second = 0x3099, .composite = 0x30F9 },
+    .{ .first = 0x30F2, .second = 0x3099, .composite = 0x30FA },
+    .{ .first = 0x30FD, .second = 0x3099, .composite = 0x30FE },
+    .{ .first = 0x11099, .second = 0x110BA, .composite = 0x1109A },
+    .{ .first = 0x1109B, .second = 0x110BA, .composite = 0x1109C },
+    .{ .first = 0x110A5, .second = 0x110BA, .composite = 0x110AB },
+    .{ .first = 0x11131, .second = 0x11127, .composite = 0x1112E },
+    .{ .first = 0x11132, .second = 0x11127, .composite = 0x1112F },
+    .{ .first = 0x11347, .second = 0x1133E, .composite = 0x1134B },
+    .{ .first = 0x11347, .second = 0x11357, .composite = 0x1134C },
+    .{ .first = 0x114B9, .second = 0x114B0, .composite = 0x114BC },
+    .{ .first = 0x114B9, .second = 0x114BA, .composite = 0x114BB },
+    .{ .first = 0x114B9, .second = 0x114BD, .composite = 0x114BE },
+    .{ .first = 0x115B8, .second = 0x115AF, .composite = 0x115BA },
+    .{ .first = 0x115B9, .second = 0x115AF, .composite = 0x115BB },
+    .{ .first = 0x11935, .second = 0x11930, .composite = 0x11938 },
+};
+
+// https://www.unicode.org/Public/13.0.0/ucd/DerivedCoreProperties.txt
+
+const cased_ranges: [141]Range = [_]Range{
+    .{ .first = 0x0041, .last = 0x005A },
+    .{ .first = 0x0061, .last = 0x007A },
+    .{ .first = 0x00AA, .last = 0x00AA },
+    .{ .first = 0x00B5, .last = 0x00B5 },
+    .{ .first = 0x00BA, .last = 0x00BA },
+    .{ .first = 0x00C0, .last = 0x00D6 },
+    .{ .first = 0x00D8, .last = 0x00F6 },
+    .{ .first = 0x00F8, .last = 0x01BA },
+    .{ .first = 0x01BC, .last = 0x01BF },
+    .{ .first = 0x01C4, .last = 0x0293 },
+    .{ .first = 0x0295, .last = 0x02B8 },
+    .{ .first = 0x02C0, .last = 0x02C1 },
+    .{ .first = 0x02E0, .last = 0x02E4 },
+    .{ .first = 0x0345, .last = 0x0345 },
+    .{ .first = 0x0370, .last = 0x0373 },
+    .{ .first = 0x0376, .last = 0x0377 },
+    .{ .first = 0x037A, .last = 0x037D },
+    .{ .first = 0x037F, .last = 0x037F },
+    .{ .first = 0x0386, .last = 0x0386 },
+    .{ .first = 0x0388, .last = 0x038A },
+    .{ .first = 0x038C, .last = 0x038C },
+    .{ .first = 0x038E, .last = 0x03A1 },
+    .{ .first = 0x03A3, .last = 0x03F5 },
+    .{ .first = 0x03F7, .last = 0x0481 },
+    .{ .first = 0x048A, .last = 0x052F },
+    .{ .first = 0x0531, .last = 0x0556 },
+    .{ .first = 0x0560, .last = 0x0588 },
+    .{ .first = 0x10A0, .last = 0x10C5 },
+    .{ .first = 0x10C7, .last = 0x10C7 },
+    .{ .first = 0x10CD, .last = 0x10CD },
+    .{ .first = 0x10D0, .last = 0x10FA },
+    .{ .first = 0x10FD, .last = 0x10FF },
+    .{ .first = 0x13A0, .last = 0x13F5 },
+    .{ .first = 0x13F8, .last = 0x13FD },
+    .{ .first = 0x1C80, .last = 0x1C88 },
+    .{ .first = 0x1C90, .last = 0x1CBA },
+    .{ .first = 0x1CBD, .last = 0x1CBF },
+    .{ .first = 0x1D00, .last = 0x1DBF },
+    .{ .first = 0x1E00, .last = 0x1F15 },
+    .{ .first = 0x1F18, .last = 0x1F1D },
+    .{ .first = 0x1F20, .last = 0x1F45 },
+    .{ .first = 0x1F48, .last = 0x1F4D },
+    .{ .first = 0x1F50, .last = 0x1F57 },
+    .{ .first = 0x1F59, .last = 0x1F59 },
+    .{ .first = 0x1F5B, .last = 0x1F5B },
+    .{ .first = 0x1F5D, .last = 0x1F5D },
+    .{ .first = 0x1F5F, .last = 0x1F7D },
+    .{ .first = 0x1F80, .last = 0x1FB4 },
+    .{ .first = 0x1FB6, .last = 0x1FBC },
+    .{ .first = 0x1FBE, .last = 0x1FBE },
+    .{ .first = 0x1FC2, .last = 0x1FC4 },
+    .{ .first = 0x1FC6, .last = 0x1FCC },
+    .{ .first = 0x1FD0, .last = 0x1FD3 },
+    .{ .first = 0x1FD6, .last = 0x1FDB },
+    .{ .first = 0x1FE0, .last = 0x1FEC },
+    .{ .first = 0x1FF2, .last = 0x1FF4 },
+    .{ .first = 0x1FF6, .last = 0x1FFC },
+    .{ .first = 0x2071, .last = 0x2071 },
+    .{ .first = 0x207F, .last = 0x207F },
+    .{ .first = 0x2090, .last = 0x209C },
+    .{ .first = 0x2102, .last = 0x2102 },
+    .{ .first = 0x2107, .last = 0x2107 },
+    .{ .first = 0x210A, .last = 0x2113 },
+    .{ .first = 0x2115, .last = 0x2115 },
+    .{ .first = 0x2119, .last = 0x211D },
+    .{ .first = 0x2124, .last = 0x2124 },
+    .{ .first = 0x2126, .last = 0x2126 },
+    .{ .first = 0x2128, .last = 0x2128 },
+    .{ .first = 0x212A, .last = 0x212D },
+    .{ .first = 0x212F, .last = 0x2134 },
+    .{ .first = 0x2139, .last = 0x2139 },
+    .{ .first = 0x213C, .last = 0x213F },
+    .{ .first = 0x2145, .last = 0x2149 },
+    .{ .first = 0x214E, .last = 0x214E },
+    .{ .first = 0x2160, .last = 0x217F },
+    .{ .first = 0x2183, .last = 0x2184 },
+    .{ .first = 0x24B6, .last = 0x24E9 },
+    .{ .first = 0x2C00, .last = 0x2C2E },
+    .{ .first = 0x2C30, .last = 0x2C5E },
+    .{ .first = 0x2C60, .last = 0x2CE4 },
+    .{ .first = 0x2CEB, .last = 0x2CEE },
+    .{ .first = 0x2CF2, .last = 0x2CF3 },
+    .{ .first = 0x2D00, .last = 0x2D25 },
+    .{ .first = 0x2D27, .last = 0x2D27 },
+    .{ .first = 0x2D2D, .last = 0x2D2D },
+    .{ .first = 0xA640, .last = 0xA66D },
+    .{ .first = 0xA680, .last = 0xA69D },
+    .{ .first = 0xA722, .last = 0xA787 },
+    .{ .first = 0xA78B, .last = 0xA78E },
+    .{ .first = 0xA790, .last = 0xA7BF },
+    .{ .first = 0xA7C2, .last = 0xA7CA },
+    .{ .first = 0xA7F5, .last = 0xA7F6 },
+    .{ .first = 0xA7F8, .last = 0xA7FA },
+    .{ .first = 0xAB30, .last = 0xAB5A },
+    .{ .first = 0xAB5C, .last = 0xAB68 },
+    .{ .first = 0xAB70, .last = 0xABBF },
+    .{ .first = 0xFB00, .last = 0xFB06 },
+    .{ .first = 0xFB13, .last = 0xFB17 },
+    .{ .first = 0xFF21, .last = 0xFF3A },
+    .{ .first = 0xFF41, .last = 0xFF5A },
+    .{ .first = 0x10400, .last = 0x1044F },
+    .{ .first = 0x104B0, .last = 0x104D3 },
+    .{ .first = 0x104D8, .last = 0x104FB },
+    .{ .first = 0x10C80, .last = 0x10CB2 },
+    .{ .first = 0x10CC0, .last = 0x10CF2 },
+    .{ .first = 0x118A0, .last = 0x118DF },
+    .{ .first = 0x16E40, .last = 0x16E7F },
+    .{ .first = 0x1D400, .last = 0x1D454 },
+    .{ .first = 0x1D456, .last = 0x1D49C },
+    .{ .first = 0x1D49E, .last = 0x1D49F },
+    .{ .first = 0x1D4A2, .last = 0x1D4A2 },
+    .{ .first = 0x1D4A5, .last = 0x1D4A6 },
+    .{ .first = 0x1D4A9, .last = 0x1D4AC },
+    .{ .first = 0x1D4AE, .last = 0x1D4B9 },
+    .{ .first = 0x1D4BB, .last = 0x1D4BB },
+    .{ .first = 0x1D4BD, .last = 0x1D4C3 },
+    .{ .first = 0x1D4C5, .last = 0x1D505 },
+    .{ .first = 0x1D507, .last = 0x1D50A },
+    .{ .first = 0x1D50D, .last = 0x1D514 },
+    .{ .first = 0x1D516, .last = 0x1D51C },
+    .{ .first = 0x1D51E, .last = 0x1D539 },
+    .{ .first = 0x1D53B, .last = 0x1D53E },
+    .{ .first = 0x1D540, .last = 0x1D544 },
+    .{ .first = 0x1D546, .last = 0x1D546 },
+    .{ .first = 0x1D54A, .last = 0x1D550 },
+    .{ .first = 0x1D552, .last = 0x1D6A5 },
+    .{ .first = 0x1D6A8, .last = 0x1D6C0 },
+    .{ .first = 0x1D6C2, .last = 0x1D6DA },
+    .{ .first = 0x1D6DC, .last = 0x1D6FA },
+    .{ .first = 0x1D6FC, .last = 0x1D714 },
+    .{ .first = 0x1D716, .last = 0x1D734 },
+    .{ .first = 0x1D736, .last = 0x1D74E },
+    .{ .first = 0x1D750, .last = 0x1D76E },
+    .{ .first = 0x1D770, .last = 0x1D788 },
+    .{ .first = 0x1D78A, .last = 0x1D7A8 },
+    .{ .first = 0x1D7AA, .last = 0x1D7C2 },
+    .{ .first = 0x1D7C4, .last = 0x1D7CB },
+    .{ .first = 0x1E900, .last = 0x1E943 },
+    .{ .first = 0x1F130, .last = 0x1F149 },
+    .{ .first = 0x1F150, .last = 0x1F169 },
+    .{ .first = 0x1F170, .last = 0x1F189 },
+};
+
+const case_ignorable_ranges: [410]Range = [_]Range{
+    .{ .first = 0x0027, .last = 0x0027 },
+    .{ .first = 0x002E, .last = 0x002E },
+    .{ .first = 0x003A, .last = 0x003A },
+    .{ .first = 0x005E, .last = 0x005E },
+    .{ .first = 0x0060, .last = 0x0060 },
+    .{ .first = 0x00A8, .last = 0x00A8 },
+    .{ .first = 0x00AD, .last = 0x00AD },
+    .{ .first = 0x00AF, .last = 0x00AF },
+    .{ .first = 0x00B4, .last = 0x00B4 },
+    .{ .first = 0x00B7, .last = 0x00B8 },
+    .{ .first = 0x02B0, .last = 0x036F },
+    .{ .first = 0x0374, .last = 0x0375 },
+    .{ .first = 0x037A, .last = 0x037A },
+    .{ .first = 0x0384, .last = 0x0385 },
+    .{ .first = 0x0387, .last = 0x0387 },
+    .{ .first = 0x0483, .last = 0x0489 },
+    .{ .first = 0x0559, .last = 0x0559 },
+    .{ .first = 0x055F, .last = 0x055F },
+    .{ .first = 0x0591, .last = 0x05BD },
+    .{ .first = 0x05BF, .last = 0x05BF },
+    .{ .first = 0x05C1, .last = 0x05C2 },
+    .{ .first = 0x05C4, .last = 0x05C5 },
+    .{ .first = 0x05C7, .last = 0x05C7 },
+    .{ .first = 0x05F4, .last = 0x05F4 },
+    .{ .first = 0x0600, .last = 0x0605 },
+    .{ .first = 0x0610, .last = 0x061A },
+    .{ .first = 0x061C, .last = 0x061C },
+    .{ .first = 0x0640, .last = 0x0640 },
+    .{ .first = 0x064B, .last = 0x065F },
+    .{ .first = 0x0670, .last = 0x0670 },
+    .{ .first = 0x06D6, .last = 0x06DD },
+    .{ .first = 0x06DF, .last = 0x06E8 },
+    .{ .first = 0x06EA, .last = 0x06ED },
+    .{ .first = 0x070F, .last = 0x070F },
+    .{ .first = 0x0711, .last = 0x0711 },
+    .{ .first = 0x0730, .last = 0x074A },
+    .{ .first = 0x07A6, .last = 0x07B0 },
+    .{ .first = 0x07EB, .last = 0x07F5 },
+    .{ .first = 0x07FA, .last = 0x07FA },
+    .{ .first = 0x07FD, .last = 0x07FD },
+    .{ .first = 0x0816, .last = 0x082D },
+    .{ .first = 0x0859, .last = 0x085B },
+    .{ .first = 0x08D3, .last = 0x0902 },
+    .{ .first = 0x093A, .last = 0x093A },
+    .{ .first = 0x093C, .last = 0x093C },
+    .{ .first = 0x0941, .last = 0x0948 },
+    .{ .first = 0x094D, .last = 0x094D },
+    .{ .first = 0x0951, .last = 0x0957 },
+    .{ .first = 0x0962, .last = 0x0963 },
+    .{ .first = 0x0971, .last = 0x0971 },
+    .{ .first = 0x0981, .last = 0x0981 },
+    .{ .first = 0x09BC, .last = 0x09BC },
+    .{ .first = 0x09C1, .last = 0x09C4 },
+    .{ .first = 0x09CD, .last = 0x09CD },
+    .{ .first = 0x09E2, .last = 0x09E3 },
+    .{ .first = 0x09FE, .last = 0x09FE },
+    .{ .first = 0x0A01, .last = 0x0A02 },
+    .{ .first = 0x0A3C, .last = 0x0A3C },
+    .{ .first = 0x0A41, .last = 0x0A42 },
+    .{ .first = 0x0A47, .last = 0x0A48 },
+    .{ .first = 0x0A4B, .last = 0x0A4D },
+    .{ .first = 0x0A51, .last = 0x0A51 },
+    .{ .first = 0x0A70, .last = 0x0A71 },
+    .{ .first = 0x0A75, .last = 0x0A75 },
+    .{ .first = 0x0A81, .last = 0x0A82 },
+    .{ .first = 0x0ABC, .last = 0x0ABC },
+    .{ .first = 0x0AC1, .last = 0x0AC5 },
+    .{ .first = 0x0AC7, .last = 0x0AC8 },
+    .{ .first = 0x0ACD, .last = 0x0ACD },
+    .{ .first = 0x0AE2, .last = 0x0AE3 },
+    .{ .first = 0x0AFA, .last = 0x0AFF },
+    .{ .first = 0x0B01, .last = 0x0B01 },
+    .{ .first = 0x0B3C, .last = 0x0B3C },
+    .{ .first = 0x0B3F, .last = 0x0B3F },
+    .{ .first = 0x0B41, .last = 0x0B44 },
+    .{ .first = 0x0B4D, .last = 0x0B4D },
+    .{ .first = 0x0B55, .last = 0x0B56 },
+    .{ .first = 0x0B62, .last = 0x0B63 },
+    .{ .first = 0x0B82, .last = 0x0B82 },
+    .{ .first = 0x0BC0, .last = 0x0BC0 },
+    .{ .first = 0x0BCD, .last = 0x0BCD },
+    .{ .first = 0x0C00, .last = 0x0C00 },
+    .{ .first = 0x0C04, .last = 0x0C04 },
+    .{ .first = 0x0C3E, .last = 0x0C40 },
+    .{ .first = 0x0C46, .last = 0x0C48 },
+    .{ .first = 0x0C4A, .last = 0x0C4D },
+    .{ .first = 0x0C55, .last = 0x0C56 },
+    .{ .first = 0x0C62, .last = 0x0C63 },
+    .{ .first = 0x0C81, .last = 0x0C81 },
+    .{ .first = 0x0CBC, .last = 0x0CBC },
+    .{ .first = 0x0CBF, .last = 0x0CBF },
+    .{ .first = 0x0CC6, .last = 0x0CC6 },
+    .{ .first = 0x0CCC, .last = 0x0CCD },
+    .{ .first = 0x0CE2, .last = 0x0CE3 },
+    .{ .first = 0x0D00, .last = 0x0D01 },
+    .{ .first = 0x0D3B, .last = 0x0D3C },
+    .{ .first = 0x0D41, .last = 0x0D44 },
+    .{ .first = 0x0D4D, .last = 0x0D4D },
+    .{ .first = 0x0D62, .last = 0x0D63 },
+    .{ .first = 0x0D81, .last = 0x0D81 },
+    .{ .first = 0x0DCA, .last = 0x0DCA },
+    .{ .first = 0x0DD2, .last = 0x0DD4 },
+    .{ .first = 0x0DD6, .last = 0x0DD6 },
+    .{ .first = 0x0E31, .last = 0x0E31 },
+    .{ .first = 0x0E34, .last = 0x0E3A },
+    .{ .first = 0x0E46, .last = 0x0E4E },
+    .{ .first = 0x0EB1, .last = 0x0EB1 },
+    .{ .first = 0x0EB4, .last = 0x0EBC },
+    .{ .first = 0x0EC6, .last = 0x0EC6 },
+    .{ .first = 0x0EC8, .last = 0x0ECD },
+    .{ .first = 0x0F18, .last = 0x0F19 },
+    .{ .first = 0x0F35, .last = 0x0F35 },
+    .{ .first = 0x0F37, .last = 0x0F37 },
+    .{ .first = 0x0F39, .last = 0x0F39 },
+    .{ .first = 0x0F71, .last = 0x0F7E },
+    .{ .first = 0x0F80, .last = 0x0F84 },
+    .{ .first = 0x0F86, .last = 0x0F87 },
+    .{ .first = 0x0F8D, .last = 0x0F97 },
+    .{ .first = 0x0F99, .last = 0x0FBC },
+    .{ .first = 0x0FC6, .last = 0x0FC6 },
+    .{ .first = 0x102D, .last = 0x1030 },
+    .{ .first = 0x1032, .last = 0x1037 },
+    .{ .first = 0x1039, .last = 0x103A },
+    .{ .first = 0x103D, .last = 0x103E },
+    .{ .first = 0x1058, .last = 0x1059 },
+    .{ .first = 0x105E, .last = 0x1060 },
+    .{ .first = 0x1071, .last = 0x1074 },
+    .{ .first = 0x1082, .last = 0x1082 },
+    .{ .first = 0x1085, .last = 0x1086 },
+    .{ .first = 0x108D, .last = 0x108D },
+    .{ .first = 0x109D, .last = 0x109D },
+    .{ .first = 0x10FC, .last = 0x10FC },
+    .{ .first = 0x135D, .last = 0x135F },
+    .{ .first = 0x1712, .last = 0x1714 },
+    .{ .first = 0x1732, .last = 0x1734 },
+    .{ .first = 0x1752, .last = 0x1753 },
+    .{ .first = 0x1772, .last = 0x1773 },
+    .{ .first = 0x17B4, .last = 0x17B5 },
+    .{ .first = 0x17B7, .last = 0x17BD },
+    .{ .first = 0x17C6, .last = 0x17C6 },
+    .{ .first = 0x17C9, .last = 0x17D3 },
+    .{ .first = 0x17D7, .last = 0x17D7 },
+    .{ .first = 0x17DD, .last = 0x17DD },
+    .{ .first = 0x180B, .last = 0x180E },
+    .{ .first = 0x1843, .last = 0x1843 },
+    .{ .first = 0x1885, .last = 0x1886 },
+    .{ .first = 0x18A9, .last = 0x18A9 },
+    .{ .first = 0x1920, .last = 0x1922 },
+    .{ .first = 0x1927, .last = 0x1928 },
+    .{ .first = 0x1932, .last = 0x1932 },
+    .{ .first = 0x1939, .last = 0x193B },
+    .{ .first = 0x1A17, .last = 0x1A18 },
+    .{ .first = 0x1A1B, .last = 0x1A1B },
+    .{ .first = 0x1A56, .last = 0x1A56 },
+    .{ .first = 0x1A58, .last = 0x1A5E },
+    .{ .first = 0x1A60, .last = 0x1A60 },
+    .{ .first = 0x1A62, .last = 0x1A62 },
+    .{ .first = 0x1A65, .last = 0x1A6C },
+    .{ .first = 0x1A73, .last = 0x1A7C },
+    .{ .first = 0x1A7F, .last = 0x1A7F },
+    .{ .first = 0x1AA7, .last = 0x1AA7 },
+    .{ .first = 0x1AB0, .last = 0x1AC0 },
+    .{ .first = 0x1B00, .last = 0x1B03 },
+    .{ .first = 0x1B34, .last = 0x1B34 },
+    .{ .first = 0x1B36, .last = 0x1B3A },
+    .{ .first = 0x1B3C, .last = 0x1B3C },
+    .{ .first = 0x1B42, .last = 0x1B42 },
+    .{ .first = 0x1B6B, .last = 0x1B73 },
+    .{ .first = 0x1B80, .last = 0x1B81 },
+    .{ .first = 0x1BA2, .last = 0x1BA5 },
+    .{ .first = 0x1BA8, .last = 0x1BA9 },
+    .{ .first = 0x1BAB, .last = 0x1BAD },
+    .{ .first = 0x1BE6, .last = 0x1BE6 },
+    .{ .first = 0x1BE8, .last = 0x1BE9 },
+    .{ .first = 0x1BED, .last = 0x1BED },
+    .{ .first = 0x1BEF, .last = 0x1BF1 },
+    .{ .first = 0x1C2C, .last = 0x1C33 },
+    .{ .first = 0x1C36, .last = 0x1C37 },
+    .{ .first = 0x1C78, .last = 0x1C7D },
+    .{ .first = 0x1CD0, .last = 0x1CD2 },
+    .{ .first = 0x1CD4, .last = 0x1CE0 },
+    .{ .first = 0x1CE2, .last = 0x1CE8 },
+    .{ .first = 0x1CED, .last = 0x1CED },
+    .{ .first = 0x1CF4, .last = 0x1CF4 },
+    .{ .first = 0x1CF8, .last = 0x1CF9 },
+    .{ .first = 0x1D2C, .last = 0x1D6A },
+    .{ .first = 0x1D78, .last = 0x1D78 },
+    .{ .first = 0x1D9B, .last = 0x1DF9 },
+    .{ .first = 0x1DFB, .last = 0x1DFF },
+    .{ .first = 0x1FBD, .last = 0x1FBD },
+    .{ .first = 0x1FBF, .last = 0x1FC1 },
+    .{ .first = 0x1FCD, .last = 0x1FCF },
+    .{ .first = 0x1FDD, .last = 0x1FDF },
+    .{ .first = 0x1FED, .last = 0x1FEF },
+    .{ .first = 0x1FFD, .last = 0x1FFE },
+    .{ .first = 0x200B, .last = 0x200F },
+    .{ .first = 0x2018, .last = 0x2019 },
+    .{ .first = 0x2024, .last = 0x2024 },
+    .{ .first = 0x2027, .last = 0x2027 },
+    .{ .first = 0x202A, .last = 0x202E },
+    .{ .first = 0x2060, .last = 0x2064 },
+    .{ .first = 0x2066, .last = 0x206F },
+    .{ .first = 0x2071, .last = 0x2071 },
+    .{ .first = 0x207F, .last = 0x207F },
+    .{ .first = 0x2090, .last = 0x209C },
+    .{ .first = 0x20D0, .last = 0x20F0 },
+    .{ .first = 0x2C7C, .last = 0x2C7D },
+    .{ .first = 0x2CEF, .last = 0x2CF1 },
+    .{ .first = 0x2D6F, .last = 0x2D6F },
+    .{ .first = 0x2D7F, .last = 0x2D7F },
+    .{ .first = 0x2DE0, .last = 0x2DFF },
+    .{ .first = 0x2E2F, .last = 0x2E2F },
+    .{ .first = 0x3005, .last = 0x3005 },
+    .{ .first = 0x302A, .last = 0x302D },
+    .{ .first = 0x3031, .last = 0x3035 },
+    .{ .first = 0x303B, .last = 0x303B },
+    .{ .first = 0x3099, .last = 0x309E },
+    .{ .first = 0x30FC, .last = 0x30FE },
+    .{ .first = 0xA015, .last = 0xA015 },
+    .{ .first = 0xA4F8, .last = 0xA4FD },
+    .{ .first = 0xA60C, .last = 0xA60C },
+    .{ .first = 0xA66F, .last = 0xA672 },
+    .{ .first = 0xA674, .last = 0xA67D },
+    .{ .first = 0xA67F, .last = 0xA67F },
+    .{ .first = 0xA69C, .last = 0xA69F },
+    .{ .first = 0xA6F0, .last = 0xA6F1 },
+    .{ .first = 0xA700, .last = 0xA721 },
+    .{ .first = 0xA770, .last = 0xA770 },
+    .{ .first = 0xA788, .last = 0xA78A },
+    .{ .first = 0xA7F8, .last = 0xA7F9 },
+    .{ .first = 0xA802, .last = 0xA802 },
+    .{ .first = 0xA806, .last = 0xA806 },
+    .{ .first = 0xA80B, .last = 0xA80B },
+    .{ .first = 0xA825, .last = 0xA826 },
+    .{ .first = 0xA82C, .last = 0xA82C },
+    .{ .first = 0xA8C4, .last = 0xA8C5 },
+    .{ .first = 0xA8E0, .last = 0xA8F1 },
+    .{ .first = 0xA8FF, .last = 0xA8FF },
+    .{ .first = 0xA926, .last = 0xA92D },
+    .{ .first = 0xA947, .last = 0xA951 },
+    .{ .first = 0xA980, .last = 0xA982 },
+    .{ .first = 0xA9B3, .last = 0xA9B3 },
+    .{ .first = 0xA9B6, .last = 0xA9B9 },
+    .{ .first = 0xA9BC, .last = 0xA9BD },
+    .{ .first = 0xA9CF, .last = 0xA9CF },
+    .{ .first = 0xA9E5, .last = 0xA9E6 },
+    .{ .first = 0xAA29, .last = 0xAA2E },
+    .{ .first = 0xAA31, .last = 0xAA32 },
+    .{ .first = 0xAA35, .last = 0xAA36 },
+    .{ .first = 0xAA43, .last = 0xAA43 },
+    .{ .first = 0xAA4C, .last = 0xAA4C },
+    .{ .first = 0xAA70, .last = 0xAA70 },
+    .{ .first = 0xAA7C, .last = 0xAA7C },
+    .{ .first = 0xAAB0, .last = 0xAAB0 },
+    .{ .first = 0xAAB2, .last = 0xAAB4 },
+    .{ .first = 0xAAB7, .last = 0xAAB8 },
+    .{ .first = 0xAABE, .last = 0xAABF },
+    .{ .first = 0xAAC1, .last = 0xAAC1 },
+    .{ .first = 0xAADD, .last = 0xAADD },
+    .{ .first = 0xAAEC, .last = 0xAAED },
+    .{ .first = 0xAAF3, .last = 0xAAF4 },
+    .{ .first = 0xAAF6, .last = 0xAAF6 },
+    .{ .first = 0xAB5B, .last = 0xAB5F },
+    .{ .first = 0xAB69, .last = 0xAB6B },
+    .{ .first = 0xABE5, .last = 0xABE5 },
+    .{ .first = 0xABE8, .last = 0xABE8 },
+    .{ .first = 0xABED, .last = 0xABED },
+    .{ .first = 0xFB1E, .last = 0xFB1E },
+    .{ .first = 0xFBB2, .last = 0xFBC1 },
+    .{ .first = 0xFE00, .last = 0xFE0F },
+    .{ .first = 0xFE13, .last = 0xFE13 },
+    .{ .first = 0xFE20, .last = 0xFE2F },
+    .{ .first = 0xFE52, .last = 0xFE52 },
+    .{ .first = 0xFE55, .last = 0xFE55 },
+    .{ .first = 0xFEFF, .last = 0xFEFF },
+    .{ .first = 0xFF07, .last = 0xFF07 },
+    .{ .first = 0xFF0E, .last = 0xFF0E },
+    .{ .first = 0xFF1A, .last = 0xFF1A },
+    .{ .first = 0xFF3E, .last = 0xFF3E },
+    .{ .first = 0xFF40, .last = 0xFF40 },
+    .{ .first = 0xFF70, .last = 0xFF70 },
+    .{ .first = 0xFF9E, .last = 0xFF9F },
+    .{ .first = 0xFFE3, .last = 0xFFE3 },
+    .{ .first = 0xFFF9, .last = 0xFFFB },
+    .{ .first = 0x101FD, .last = 0x101FD },
+    .{ .first = 0x102E0, .last = 0x102E0 },
+    .{ .first = 0x10376, .last = 0x1037A },
+    .{ .first = 0x10A01, .last = 0x10A03 },
+    .{ .first = 0x10A05, .last = 0x10A06 },
+    .{ .first = 0x10A0C, .last = 0x10A0F },
+    .{ .first = 0x10A38, .last = 0x10A3A },
+    .{ .first = 0x10A3F, .last = 0x10A3F },
+    .{ .first = 0x10AE5, .last = 0x10AE6 },
+    .{ .first = 0x10D24, .last = 0x10D27 },
+    .{ .first = 0x10EAB, .last = 0x10EAC },
+    .{ .first = 0x10F46, .last = 0x10F50 },
+    .{ .first = 0x11001, .last = 0x11001 },
+    .{ .first = 0x11038, .last = 0x11046 },
+    .{ .first = 0x1107F, .last = 0x11081 },
+    .{ .first = 0x110B3, .last = 0x110B6 },
+    .{ .first = 0x110B9, .last = 0x110BA },
+    .{ .first = 0x110BD, .last = 0x110BD },
+    .{ .first = 0x110CD, .last = 0x110CD },
+    .{ .first = 0x11100, .last = 0x11102 },
+    .{ .first = 0x11127, .last = 0x1112B },
+    .{ .first = 0x1112D, .last = 0x11134 },
+    .{ .first = 0x11173, .last = 0x11173 },
+    .{ .first = 0x11180, .last = 0x11181 },
+    .{ .first = 0x111B6, .last = 0x111BE },
+    .{ .first = 0x111C9, .last = 0x111CC },
+    .{ .first = 0x111CF, .last = 0x111CF },
+    .{ .first = 0x1122F, .last = 0x11231 },
+    .{ .first = 0x11234, .last = 0x11234 },
+    .{ .first = 0x11236, .last = 0x11237 },
+    .{ .first = 0x1123E, .last = 0x1123E },
+    .{ .first = 0x112DF, .last = 0x112DF },
+    .{ .first = 0x112E3, .last = 0x112EA },
+    .{ .first = 0x11300, .last = 0x11301 },
+    .{ .first = 0x1133B, .last = 0x1133C },
+    .{ .first = 0x11340, .last = 0x11340 },
+    .{ .first = 0x11366, .last = 0x1136C },
+    .{ .first = 0x11370, .last = 0x11374 },
+    .{ .first = 0x11438, .last = 0x1143F },
+    .{ .first = 0x11442, .last = 0x11444 },
+    .{ .first = 0x11446, .last = 0x11446 },
+    .{ .first = 0x1145E, .last = 0x1145E },
+    .{ .first = 0x114B3, .last = 0x114B8 },
+    .{ .first = 0x114BA, .last = 0x114BA },
+    .{ .first = 0x114BF, .last = 0x114C0 },
+    .{ .first = 0x114C2, .last = 0x114C3 },
+    .{ .first = 0x115B2, .last = 0x115B5 },
+    .{ .first = 0x115BC, .last = 0x115BD },
+    .{ .first = 0x115BF, .last = 0x115C0 },
+    .{ .first = 0x115DC, .last = 0x115DD },
+    .{ .first = 0x11633, .last = 0x1163A },
+    .{ .first = 0x1163D, .last = 0x1163D },
+    .{ .first = 0x1163F, .last = 0x11640 },
+    .{ .first = 0x116AB, .last = 0x116AB },
+    .{ .first = 0x116AD, .last = 0x116AD },
+    .{ .first = 0x116B0, .last = 0x116B5 },
+    .{ .first = 0x116B7, .last = 0x116B7 },
+    .{ .first = 0x1171D, .last = 0x1171F },
+    .{ .first = 0x11722, .last = 0x11725 },
+    .{ .first = 0x11727, .last = 0x1172B },
+    .{ .first = 0x1182F, .last = 0x11837 },
+    .{ .first = 0x11839, .last = 0x1183A },
+    .{ .first = 0x1193B, .last = 0x1193C },
+    .{ .first = 0x1193E, .last = 0x1193E },
+    .{ .first = 0x11943, .last = 0x11943 },
+    .{ .first = 0x119D4, .last = 0x119D7 },
+    .{ .first = 0x119DA, .last = 0x119DB },
+    .{ .first = 0x119E0, .last = 0x119E0 },
+    .{ .first = 0x11A01, .last = 0x11A0A },
+    .{ .first = 0x11A33, .last = 0x11A38 },
+    .{ .first = 0x11A3B, .last = 0x11A3E },
+    .{ .first = 0x11A47, .last = 0x11A47 },
+    .{ .first = 0x11A51, .last = 0x11A56 },
+    .{ .first = 0x11A59, .last = 0x11A5B },
+    .{ .first = 0x11A8A, .last = 0x11A96 },
+    .{ .first = 0x11A98, .last = 0x11A99 },
+    .{ .first = 0x11C30, .last = 0x11C36 },
+    .{ .first = 0x11C38, .last = 0x11C3D },
+    .{ .first = 0x11C3F, .last = 0x11C3F },
+    .{ .first = 0x11C92, .last = 0x11CA7 },
+    .{ .first = 0x11CAA, .last = 0x11CB0 },
+    .{ .first = 0x11CB2, .last = 0x11CB3 },
+    .{ .first = 0x11CB5, .last = 0x11CB6 },
+    .{ .first = 0x11D31, .last = 0x11D36 },
+    .{ .first = 0x11D3A, .last = 0x11D3A },
+    .{ .first = 0x11D3C, .last = 0x11D3D },
+    .{ .first = 0x11D3F, .last = 0x11D45 },
+    .{ .first = 0x11D47, .last = 0x11D47 },
+    .{ .first = 0x11D90, .last = 0x11D91 },
+    .{ .first = 0x11D95, .last = 0x11D95 },
+    .{ .first = 0x11D97, .last = 0x11D97 },
+    .{ .first = 0x11EF3, .last = 0x11EF4 },
+    .{ .first = 0x13430, .last = 0x13438 },
+    .{ .first = 0x16AF0, .last = 0x16AF4 },
+    .{ .first = 0x16B30, .last = 0x16B36 },
+    .{ .first = 0x16B40, .last = 0x16B43 },
+    .{ .first = 0x16F4F, .last = 0x16F4F },
+    .{ .first = 0x16F8F, .last = 0x16F9F },
+    .{ .first = 0x16FE0, .last = 0x16FE1 },
+    .{ .first = 0x16FE3, .last = 0x16FE4 },
+    .{ .first = 0x1BC9D, .last = 0x1BC9E },
+    .{ .first = 0x1BCA0, .last = 0x1BCA3 },
+    .{ .first = 0x1D167, .last = 0x1D169 },
+    .{ .first = 0x1D173, .last = 0x1D182 },
+    .{ .first = 0x1D185, .last = 0x1D18B },
+    .{ .first = 0x1D1AA, .last = 0x1D1AD },
+    .{ .first = 0x1D242, .last = 0x1D244 },
+    .{ .first = 0x1DA00, .last = 0x1DA36 },
+    .{ .first = 0x1DA3B, .last = 0x1DA6C },
+    .{ .first = 0x1DA75, .last = 0x1DA75 },
+    .{ .first = 0x1DA84, .last = 0x1DA84 },
+    .{ .first = 0x1DA9B, .last = 0x1DA9F },
+    .{ .first = 0x1DAA1, .last = 0x1DAAF },
+    .{ .first = 0x1E000, .last = 0x1E006 },
+    .{ .first = 0x1E008, .last = 0x1E018 },
+    .{ .first = 0x1E01B, .last = 0x1E021 },
+    .{ .first = 0x1E023, .last = 0x1E024 },
+    .{ .first = 0x1E026, .last = 0x1E02A },
+    .{ .first = 0x1E130, .last = 0x1E13D },
+    .{ .first = 0x1E2EC, .last = 0x1E2EF },
+    .{ .first = 0x1E8D0, .last = 0x1E8D6 },
+    .{ .first = 0x1E944, .last = 0x1E94B },
+    .{ .first = 0x1F3FB, .last = 0x1F3FF },
+    .{ .first = 0xE0001, .last = 0xE0001 },
+    .{ .first = 0xE0020, .last = 0xE007F },
+    .{ .first = 0xE0100, .last = 0xE01EF },
+};
@@ -1,6 +1,7 @@
 const utils = @import("utils.zig");
 const RocList = @import("list.zig").RocList;
 const grapheme = @import("helpers/grapheme.zig");
+const unicode_data = @import("helpers/unicode.zig");
 const UpdateMode = utils.UpdateMode;
 const std = @import("std");
 const mem = std.mem;
@@ -1560,6 +1561,55 @@ test "startsWith: 12345678912345678910 starts with 123456789123456789" {
     try expect(startsWith(str, prefix));
 }
 
+// Str.compare
+pub fn strCompare(left: RocStr, right: RocStr) callconv(.C) utils.Ordering {
+    const left_bytes = left.asSlice();
+    const right_bytes = right.asSlice();
+
+    const common_len = std.math.min(left_bytes.len, right_bytes.len);
+
+    // memcmp over the shared prefix, then the shorter string sorts first
+    var i: usize = 0;
+    while (i < common_len) : (i += 1) {
+        if (left_bytes[i] != right_bytes[i]) {
+            return if (left_bytes[i] < right_bytes[i]) utils.Ordering.LT else utils.Ordering.GT;
+        }
+    }
+
+    if (left_bytes.len < right_bytes.len) {
+        return utils.Ordering.LT;
+    } else if (left_bytes.len > right_bytes.len) {
+        return utils.Ordering.GT;
+    } else {
+        return utils.Ordering.EQ;
+    }
+}
+
+test "strCompare: equal" {
+    const str = RocStr.fromSlice("123456789123456789");
+    defer str.deinit();
+
+    try expectEqual(utils.Ordering.EQ, strCompare(str, str));
+}
+
+test "strCompare: prefix sorts first" {
+    const long = RocStr.fromSlice("12345678912345678910");
+    defer long.deinit();
+    const prefix = RocStr.fromSlice("123456789123456789");
+    defer prefix.deinit();
+
+    try expectEqual(utils.Ordering.LT, strCompare(prefix, long));
+    try expectEqual(utils.Ordering.GT, strCompare(long, prefix));
+}
+
+test "strCompare: multi-byte" {
+    const e_acute = RocStr.fromSlice("é");
+    const z = RocStr.fromSlice("z");
+
+    try expectEqual(utils.Ordering.GT, strCompare(e_acute, z));
+    try expectEqual(utils.Ordering.LT, strCompare(z, e_acute));
+}
+
 // Str.endsWith
 pub fn endsWith(string: RocStr, suffix: RocStr) callconv(.C) bool {
     const bytes_len = string.len();
@@ -2657,6 +2707,327 @@ test "capacity: big string" {
     try expect(data.getCapacity() >= data_bytes.len);
 }
 
+// Str.toUpper
+pub fn strToUpper(string: RocStr) callconv(.C) RocStr {
+    return mapScalars(string, withoutContext(unicode_data.uppercaseMapping));
+}
+
+// Str.toLower
+pub fn strToLower(string: RocStr) callconv(.C) RocStr {
+    return mapScalars(string, lowercaseInContext);
+}
+
+// Str.caseFold
+pub fn strCaseFold(string: RocStr) callconv(.C) RocStr {
+    return mapScalars(string, withoutContext(unicode_data.caseFoldMapping));
+}
+
+// A mapping that is given the whole string and the position of the scalar it maps
+const ScalarMapping = fn ([]const u8, usize, usize, u21) ?[]const u21;
+
+fn withoutContext(comptime mapping: fn (u21) ?[]const u21) ScalarMapping {
+    return struct {
+        fn map(_: []const u8, _: usize, _: usize, scalar: u21) ?[]const u21 {
+            return mapping(scalar);
+        }
+    }.map;
+}
+
+// Like `lowercaseMapping`, but also applies the Final_Sigma rule
+fn lowercaseInContext(bytes: []const u8, index: usize, width: usize, scalar: u21) ?[]const u21 {
+    if (scalar == 0x3A3 and unicode_data.isFinalSigma(bytes, index, width)) {
+        return &unicode_data.final_sigma;
+    }
+
+    return unicode_data.lowercaseMapping(scalar);
+}
+
+// Replaces every scalar that has a mapping with the scalars it maps to
+fn mapScalars(string: RocStr, comptime mapping: ScalarMapping) RocStr {
+    const bytes = string.asSlice();
+
+    // Measure the result first, so that it is allocated exactly once
+    var new_len: usize = 0;
+    var changed = false;
+    var index: usize = 0;
+
+    while (index < bytes.len) {
+        const width = @intCast(usize, unicode.utf8ByteSequenceLength(bytes[index]) catch unreachable);
+        const scalar = unicode.utf8Decode(bytes[index .. index + width]) catch unreachable;
+
+        if (mapping(bytes, index, width, scalar)) |mapped| {
+            changed = true;
+
+            for (mapped) |mapped_scalar| {
+                new_len += unicode.utf8CodepointSequenceLength(mapped_scalar) catch unreachable;
+            }
+        } else {
+            new_len += width;
+        }
+
+        index += width;
+    }
+
+    if (!changed) {
+        // the string is owned, so we can return it without cloning
+        return string;
+    }
+
+    var result = RocStr.allocate(new_len);
+    const dest = result.asU8ptrMut();
+    var dest_index: usize = 0;
+    index = 0;
+
+    while (index < bytes.len) {
+        const width = @intCast(usize, unicode.utf8ByteSequenceLength(bytes[index]) catch unreachable);
+        const scalar = unicode.utf8Decode(bytes[index .. index + width]) catch unreachable;
+
+        if (mapping(bytes, index, width, scalar)) |mapped| {
+            for (mapped) |mapped_scalar| {
+                dest_index += unicode.utf8Encode(mapped_scalar, dest[dest_index..new_len]) catch unreachable;
+            }
+        } else {
+            @memcpy(dest + dest_index, bytes.ptr + index, width);
+            dest_index += width;
+        }
+
+        index += width;
+    }
+
+    string.decref();
+
+    return result;
+}
+
+test "strToUpper: ASCII" {
+    const original = RocStr.fromSlice("Hello, World!");
+    const expected = RocStr.fromSlice("HELLO, WORLD!");
+    defer expected.deinit();
+
+    const actual = strToUpper(original);
+    defer actual.deinit();
+
+    try expect(actual.eq(expected));
+}
+
+test "strToUpper: grows the string" {
+    const original = RocStr.fromSlice("straße");
+    const expected = RocStr.fromSlice("STRASSE");
+    defer expected.deinit();
+
+    const actual = strToUpper(original);
+    defer actual.deinit();
+
+    try expect(actual.eq(expected));
+}
+
+test "strToLower: big string" {
+    const original = RocStr.fromSlice("A STRING SO LARGE THAT IT MUST BE HEAP-ALLOCATED ΣΑΣ");
+    const expected = RocStr.fromSlice("a string so large that it must be heap-allocated σας");
+    defer expected.deinit();
+
+    const actual = strToLower(original);
+    defer actual.deinit();
+
+    try expect(actual.eq(expected));
+}
+
+test "strToLower: final sigma" {
+    const original = RocStr.fromSlice("ΣΑΣ ΟΔΟΣ. Σ");
+    const expected = RocStr.fromSlice("σας οδος. σ");
+    defer expected.deinit();
+
+    const actual = strToLower(original);
+    defer actual.deinit();
+
+    try expect(actual.eq(expected));
+}
+
+test "strCaseFold" {
+    const original = RocStr.fromSlice("Straße ΜΆΪΟΣ");
+    const expected = RocStr.fromSlice("strasse μάϊοσ");
+    defer expected.deinit();
+
+    const actual = strCaseFold(original);
+    defer actual.deinit();
+
+    try expect(actual.eq(expected));
+}
+
+// Str.toNfd
+pub fn strToNfd(string: RocStr) callconv(.C) RocStr {
+    return normalize(string, false);
+}
+
+// Str.toNfc
+pub fn strToNfc(string: RocStr) callconv(.C) RocStr {
+    return normalize(string, true);
+}
+
+// https://www.unicode.org/reports/tr15/#Description_Norm
+fn normalize(string: RocStr, compose: bool) RocStr {
+    const bytes = string.asSlice();
+
+    for (bytes) |byte| {
+        if (byte >= 0b1000_0000) {
+            break;
+        }
+    } else {
+        // ASCII text is already in every normalization form
+        return string;
+    }
+
+    // Fully decompose the string into a buffer of scalars
+    var decomposition: [unicode_data.max_decomposition_length]u21 = undefined;
+    var scalars_len: usize = 0;
+    var index: usize = 0;
+
+    while (index < bytes.len) {
+        const width = @intCast(usize, unicode.utf8ByteSequenceLength(bytes[index]) catch unreachable);
+        const scalar = unicode.utf8Decode(bytes[index .. index + width]) catch unreachable;
+
+        scalars_len += unicode_data.decompose(scalar, &decomposition);
+        index += width;
+    }
+
+    const buffer = utils.alloc(scalars_len * @sizeOf(u21), @alignOf(u21)) orelse unreachable;
+    defer utils.dealloc(buffer, @alignOf(u21));
+
+    var scalars = @ptrCast([*]u21, @alignCast(@alignOf(u21), buffer))[0..scalars_len];
+    var scalar_index: usize = 0;
+    index = 0;
+
+    while (index < bytes.len) {
+        const width = @intCast(usize, unicode.utf8ByteSequenceLength(bytes[index]) catch unreachable);
+        const scalar = unicode.utf8Decode(bytes[index .. index + width]) catch unreachable;
+
+        scalar_index += unicode_data.decompose(scalar, scalars[scalar_index..]);
+        index += width;
+    }
+
+    // Put each run of combining marks in canonical order. This is an insertion sort, because
+    // the runs are short and marks with equal combining classes must keep their relative order.
+    var i: usize = 1;
+    while (i < scalars.len) : (i += 1) {
+        const scalar = scalars[i];
+        const class = unicode_data.combiningClass(scalar);
+
+        if (class == 0) {
+            continue;
+        }
+
+        var j = i;
+        while (j > 0 and unicode_data.combiningClass(scalars[j - 1]) > class) : (j -= 1) {
+            scalars[j] = scalars[j - 1];
+        }
+
+        scalars[j] = scalar;
+    }
+
+    if (compose) {
+        // Combine each scalar with the last starter, unless something in between blocks it
+        var starter_index: ?usize = null;
+        var write_index: usize = 0;
+
+        for (scalars) |scalar| {
+            const class = unicode_data.combiningClass(scalar);
+
+            if (starter_index) |starter| {
+                const last_class = unicode_data.combiningClass(scalars[write_index - 1]);
+                const blocked = write_index > starter + 1 and (last_class == 0 or last_class >= class);
+
+                if (!blocked) {
+                    if (unicode_data.compose(scalars[starter], scalar)) |composite| {
+                        scalars[starter] = composite;
+                        continue;
+                    }
+                }
+            }
+
+            if (class == 0) {
+                starter_index = write_index;
+            }
+
+            scalars[write_index] = scalar;
+            write_index += 1;
+        }
+
+        scalars = scalars[0..write_index];
+    }
+
+    var new_len: usize = 0;
+    for (scalars) |scalar| {
+        new_len += unicode.utf8CodepointSequenceLength(scalar) catch unreachable;
+    }
+
+    var result = RocStr.allocate(new_len);
+    const dest = result.asU8ptrMut();
+    var dest_index: usize = 0;
+
+    for (scalars) |scalar| {
+        dest_index += unicode.utf8Encode(scalar, dest[dest_index..new_len]) catch unreachable;
+    }
+
+    string.decref();
+
+    return result;
+}
+
+test "strToNfd: precomposed" {
+    const original = RocStr.fromSlice("caf\u{E9}");
+    const expected = RocStr.fromSlice("cafe\u{301}");
+    defer expected.deinit();
+
+    const actual = strToNfd(original);
+    defer actual.deinit();
+
+    try expect(actual.eq(expected));
+}
+
+test "strToNfd: reorders combining marks" {
+    const original = RocStr.fromSlice("s\u{307}\u{323}");
+    const expected = RocStr.fromSlice("s\u{323}\u{307}");
+    defer expected.deinit();
+
+    const actual = strToNfd(original);
+    defer actual.deinit();
+
+    try expect(actual.eq(expected));
+}
+
+test "strToNfc: composes" {
+    const original = RocStr.fromSlice("cafe\u{301} s\u{307}\u{323}");
+    const expected = RocStr.fromSlice("caf\u{E9} \u{1E69}");
+    defer expected.deinit();
+
+    const actual = strToNfc(original);
+    defer actual.deinit();
+
+    try expect(actual.eq(expected));
+}
+
+test "strToNfc: hangul" {
+    const original = RocStr.fromSlice("\u{1111}\u{1171}\u{11B6}");
+    const expected = RocStr.fromSlice("\u{D4DB}");
+    defer expected.deinit();
+
+    const actual = strToNfc(original);
+    defer actual.deinit();
+
+    try expect(actual.eq(expected));
+}
+
+test "strToNfc: composition exclusion" {
+    const original = RocStr.fromSlice("\u{958}");
+    const expected = RocStr.fromSlice("\u{915}\u{93C}");
+    defer expected.deinit();
+
+    const actual = strToNfc(original);
+    defer actual.deinit();
+
+    try expect(actual.eq(expected));
+}
+
 pub fn appendScalar(string: RocStr, scalar_u32: u32) callconv(.C) RocStr {
     const scalar = @intCast(u21, scalar_u32);
     const width = std.unicode.utf8CodepointSequenceLength(scalar) catch unreachable;
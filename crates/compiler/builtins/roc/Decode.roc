@@ -56,6 +56,10 @@ DecodeResult val : { result : Result val DecodeError, rest : List U8 }
 
 Decoder val fmt := List U8, fmt -> DecodeResult val | fmt has DecoderFormatting
 
+# A derived record decoder decodes each field that is missing from the input from empty input
+# instead. To give a field a default, give it a type whose decoder returns that default when it
+# has no bytes to decode. Optional record fields can't be decoded, since whether a record has one
+# is decided at compile time, not by the input.
 Decoding has
     decoder : Decoder val fmt | val has Decoding, fmt has DecoderFormatting
 
@@ -0,0 +1,182 @@
+interface Inspect
+    exposes [
+        Inspect,
+        inspect,
+        toStr,
+        str,
+        bool,
+        u8,
+        u16,
+        u32,
+        u64,
+        u128,
+        i8,
+        i16,
+        i32,
+        i64,
+        i128,
+        nat,
+        f32,
+        f64,
+        dec,
+        list,
+        dict,
+        set,
+        payload,
+        opaque,
+        literal,
+    ]
+    imports [
+        Bool.{ Bool, Eq },
+        Str,
+        List,
+        Result,
+        Dict.{ Dict },
+        Set.{ Set },
+        Hash.{ Hash },
+        Num.{ U8, U16, U32, U64, U128, I8, I16, I32, I64, I128, Nat, F32, F64, Dec },
+    ]
+
+## A value that can be turned into a human-readable [Str], for example to be
+## written to a log.
+##
+## [Inspect] is derived for records, tag unions, lists, strings, numbers,
+## [Dict]s and [Set]s whose contents implement [Inspect]. Opaque types are
+## shown as `<opaque>` unless they implement [Inspect] themselves.
+Inspect has
+    ## Appends a human-readable representation of a value to a [Str].
+    inspect : Str, val -> Str | val has Inspect
+
+## Turns a value into a human-readable [Str].
+##
+##     expect Inspect.toStr { name: "Roc", tags: [Fast, Friendly] } == "{ name: \"Roc\", tags: [Fast, Friendly] }"
+toStr : val -> Str | val has Inspect
+toStr = \val -> inspect "" val
+
+## Appends a [Str] in double quotes, escaping quotes, backslashes and whitespace
+## control characters.
+str : Str, Str -> Str
+str = \buf, s ->
+    escaped = Str.walkScalars s (Str.concat buf "\"") \acc, scalar ->
+        when scalar is
+            '"' -> Str.concat acc "\\\""
+            '\\' -> Str.concat acc "\\\\"
+            '\n' -> Str.concat acc "\\n"
+            '\r' -> Str.concat acc "\\r"
+            '\t' -> Str.concat acc "\\t"
+            _ -> Str.appendScalar acc scalar |> Result.withDefault acc
+
+    Str.concat escaped "\""
+
+## Appends a [Bool] as `Bool.true` or `Bool.false`.
+bool : Str, Bool -> Str
+bool = \buf, b ->
+    if b then
+        Str.concat buf "Bool.true"
+    else
+        Str.concat buf "Bool.false"
+
+u8 : Str, U8 -> Str
+u8 = \buf, n -> Str.concat buf (Num.toStr n)
+
+u16 : Str, U16 -> Str
+u16 = \buf, n -> Str.concat buf (Num.toStr n)
+
+u32 : Str, U32 -> Str
+u32 = \buf, n -> Str.concat buf (Num.toStr n)
+
+u64 : Str, U64 -> Str
+u64 = \buf, n -> Str.concat buf (Num.toStr n)
+
+u128 : Str, U128 -> Str
+u128 = \buf, n -> Str.concat buf (Num.toStr n)
+
+i8 : Str, I8 -> Str
+i8 = \buf, n -> Str.concat buf (Num.toStr n)
+
+i16 : Str, I16 -> Str
+i16 = \buf, n -> Str.concat buf (Num.toStr n)
+
+i32 : Str, I32 -> Str
+i32 = \buf, n -> Str.concat buf (Num.toStr n)
+
+i64 : Str, I64 -> Str
+i64 = \buf, n -> Str.concat buf (Num.toStr n)
+
+i128 : Str, I128 -> Str
+i128 = \buf, n -> Str.concat buf (Num.toStr n)
+
+nat : Str, Nat -> Str
+nat = \buf, n -> Str.concat buf (Num.toStr n)
+
+f32 : Str, F32 -> Str
+f32 = \buf, n -> Str.concat buf (Num.toStr n)
+
+f64 : Str, F64 -> Str
+f64 = \buf, n -> Str.concat buf (Num.toStr n)
+
+dec : Str, Dec -> Str
+dec = \buf, n -> Str.concat buf (Num.toStr n)
+
+## Appends a list of [Inspect]able elements as `[a, b, c]`.
+list : Str, List elem -> Str | elem has Inspect
+list = \buf, lst ->
+    walked = List.walk lst { buffer: Str.concat buf "[", first: Bool.true } \{ buffer, first }, elem ->
+        withSeparator = if first then buffer else Str.concat buffer ", "
+
+        { buffer: inspect withSeparator elem, first: Bool.false }
+
+    Str.concat walked.buffer "]"
+
+## Appends a [Dict] as the `Dict.fromList` call that would build it.
+dict : Str, Dict k v -> Str | k has Inspect & Hash & Eq, v has Inspect
+dict = \buf, d ->
+    walked = Dict.walk d { buffer: Str.concat buf "Dict.fromList [", first: Bool.true } \{ buffer, first }, k, v ->
+        withSeparator = if first then buffer else Str.concat buffer ", "
+        withEntry =
+            Str.concat withSeparator "T"
+            |> payload k
+            |> payload v
+
+        { buffer: withEntry, first: Bool.false }
+
+    Str.concat walked.buffer "]"
+
+## Appends a [Set] as the `Set.fromList` call that would build it.
+set : Str, Set k -> Str | k has Inspect & Hash & Eq
+set = \buf, s ->
+    Str.concat buf "Set.fromList "
+    |> list (Set.toList s)
+
+## Appends a tag payload, preceded by a space. Payloads that read as a tag
+## applied to arguments are wrapped in parentheses, so `A (B 1)` stays
+## distinguishable from `A B 1`.
+payload : Str, val -> Str | val has Inspect
+payload = \buf, val ->
+    rendered = inspect "" val
+
+    if needsParens rendered then
+        Str.concat buf " ("
+        |> Str.concat rendered
+        |> Str.concat ")"
+    else
+        Str.concat buf " "
+        |> Str.concat rendered
+
+needsParens : Str -> Bool
+needsParens = \rendered ->
+    bytes = Str.toUtf8 rendered
+
+    when List.first bytes is
+        Ok first if first >= 'A' && first <= 'Z' -> List.contains bytes ' '
+        _ -> Bool.false
+
+## Appends `<opaque>`. This is how opaque types which don't implement
+## [Inspect] are shown.
+opaque : Str, val -> Str
+opaque = \buf, _ -> Str.concat buf "<opaque>"
+
+## Appends text to a [Str] as-is. Derived implementations use this for field
+## names, tag names and punctuation.
+literal : Str, Str -> Str
+literal = \buf, text -> Str.concat buf text
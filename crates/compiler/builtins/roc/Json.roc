@@ -1,8 +1,10 @@
 interface Json
     exposes [
         Json,
+        FieldNameMapping,
         toUtf8,
         fromUtf8,
+        withOptions,
     ]
     imports [
         List,
@@ -32,12 +34,14 @@ interface Json
             F32,
             F64,
             Dec,
+            Nat,
         },
         Bool.{ Bool, Eq },
         Result,
     ]
 
-Json := {} has [
+## A JSON format for [Encode] and [Decode], together with the options it was created with.
+Json := Options has [
          EncoderFormatting {
              u8: encodeU8,
              u16: encodeU16,
@@ -79,40 +83,72 @@ Json := {} has [
          },
      ]
 
-toUtf8 = @Json {}
-
-fromUtf8 = @Json {}
+## How record field names are written in JSON, given that Roc field names are camelCase.
+##
+## - `Default` keeps field names as they are.
+## - `SnakeCase` writes `firstName` as `first_name`.
+## - `PascalCase` writes `firstName` as `FirstName`.
+## - `KebabCase` writes `firstName` as `first-name`.
+## - `CamelCase` is for Roc field names written in snake_case, and writes `first_name` as `firstName`.
+##
+## Decoding applies the reverse mapping to the field names it reads.
+FieldNameMapping : [Default, SnakeCase, PascalCase, KebabCase, CamelCase]
+
+# `depth` is how deeply nested the value being encoded is, for pretty-printing.
+Options : {
+    fieldNameMapping : FieldNameMapping,
+    indent : U8,
+    depth : Nat,
+    nullDecodeAsEmpty : Bool,
+    emptyEncodeAsNull : Bool,
+}
+
+toUtf8 = withOptions {}
+
+fromUtf8 = withOptions {}
+
+## Creates a [Json] format with the given options.
+##
+## - `fieldNameMapping` converts record field names, see [FieldNameMapping].
+## - `indent` pretty-prints objects and arrays with this many spaces per level; `0` keeps
+##   everything on one line.
+## - `nullDecodeAsEmpty` decodes a `null` value as if it were empty input, so record fields
+##   whose type decodes empty input to a default can be set to `null`.
+## - `emptyEncodeAsNull` writes `null` for record fields and list elements whose value encodes
+##   to nothing; otherwise such record fields are left out.
+withOptions = \{ fieldNameMapping ? Default, indent ? 0, nullDecodeAsEmpty ? Bool.true, emptyEncodeAsNull ? Bool.false } ->
+    @Json { fieldNameMapping, indent, depth: 0, nullDecodeAsEmpty, emptyEncodeAsNull }
 
 numToBytes = \n ->
     n |> Num.toStr |> Str.toUtf8
 
-encodeU8 = \n -> Encode.custom \bytes, @Json {} -> List.concat bytes (numToBytes n)
+encodeU8 = \n -> Encode.custom \bytes, @Json _ -> List.concat bytes (numToBytes n)
 
-encodeU16 = \n -> Encode.custom \bytes, @Json {} -> List.concat bytes (numToBytes n)
+encodeU16 = \n -> Encode.custom \bytes, @Json _ -> List.concat bytes (numToBytes n)
 
-encodeU32 = \n -> Encode.custom \bytes, @Json {} -> List.concat bytes (numToBytes n)
+encodeU32 = \n -> Encode.custom \bytes, @Json _ -> List.concat bytes (numToBytes n)
 
-encodeU64 = \n -> Encode.custom \bytes, @Json {} -> List.concat bytes (numToBytes n)
+encodeU64 = \n -> Encode.custom \bytes, @Json _ -> List.concat bytes (numToBytes n)
 
-encodeU128 = \n -> Encode.custom \bytes, @Json {} -> List.concat bytes (numToBytes n)
+encodeU128 = \n -> Encode.custom \bytes, @Json _ -> List.concat bytes (numToBytes n)
 
-encodeI8 = \n -> Encode.custom \bytes, @Json {} -> List.concat bytes (numToBytes n)
+encodeI8 = \n -> Encode.custom \bytes, @Json _ -> List.concat bytes (numToBytes n)
 
-encodeI16 = \n -> Encode.custom \bytes, @Json {} -> List.concat bytes (numToBytes n)
+encodeI16 = \n -> Encode.custom \bytes, @Json _ -> List.concat bytes (numToBytes n)
 
-encodeI32 = \n -> Encode.custom \bytes, @Json {} -> List.concat bytes (numToBytes n)
+encodeI32 = \n -> Encode.custom \bytes, @Json _ -> List.concat bytes (numToBytes n)
 
-encodeI64 = \n -> Encode.custom \bytes, @Json {} -> List.concat bytes (numToBytes n)
+encodeI64 = \n -> Encode.custom \bytes, @Json _ -> List.concat bytes (numToBytes n)
 
-encodeI128 = \n -> Encode.custom \bytes, @Json {} -> List.concat bytes (numToBytes n)
+encodeI128 = \n -> Encode.custom \bytes, @Json _ -> List.concat bytes (numToBytes n)
 
-encodeF32 = \n -> Encode.custom \bytes, @Json {} -> List.concat bytes (numToBytes n)
+encodeF32 = \n -> Encode.custom \bytes, @Json _ -> List.concat bytes (numToBytes n)
 
-encodeF64 = \n -> Encode.custom \bytes, @Json {} -> List.concat bytes (numToBytes n)
+encodeF64 = \n -> Encode.custom \bytes, @Json _ -> List.concat bytes (numToBytes n)
 
-encodeDec = \n -> Encode.custom \bytes, @Json {} -> List.concat bytes (numToBytes n)
+encodeDec = \n -> Encode.custom \bytes, @Json _ -> List.concat bytes (numToBytes n)
 
-encodeBool = \b -> Encode.custom \bytes, @Json {} ->
+encodeBool = \b -> Encode.custom \bytes, @Json _ ->
         if
             b
         then
@@ -120,15 +156,19 @@ encodeBool = \b -> Encode.custom \bytes, @Json {} ->
         else
             List.concat bytes (Str.toUtf8 "false")
 
-encodeString = \s -> Encode.custom \bytes, @Json {} ->
+encodeString = \s -> Encode.custom \bytes, @Json _ ->
         List.append bytes (Num.toU8 '"')
         |> List.concat (Str.toUtf8 s)
         |> List.append (Num.toU8 '"')
 
 encodeList = \lst, encodeElem ->
-    Encode.custom \bytes, @Json {} ->
+    Encode.custom \bytes, @Json opts ->
+        elemOpts = { opts & depth: opts.depth + 1 }
+
         writeList = \{ buffer, elemsLeft }, elem ->
-            bufferWithElem = appendWith buffer (encodeElem elem) (@Json {})
+            bufferWithElem =
+                appendNewline buffer elemOpts
+                |> appendValue (encodeElem elem) elemOpts
             bufferWithSuffix =
                 if elemsLeft > 1 then
                     List.append bufferWithElem (Num.toU8 ',')
@@ -140,36 +180,57 @@ encodeList = \lst, encodeElem ->
         head = List.append bytes (Num.toU8 '[')
         { buffer: withList } = List.walk lst { buffer: head, elemsLeft: List.len lst } writeList
 
-        List.append withList (Num.toU8 ']')
+        if List.isEmpty lst then
+            List.append withList (Num.toU8 ']')
+        else
+            appendNewline withList opts
+            |> List.append (Num.toU8 ']')
 
 encodeRecord = \fields ->
-    Encode.custom \bytes, @Json {} ->
-        writeRecord = \{ buffer, fieldsLeft }, { key, value } ->
-            bufferWithKeyValue =
-                List.append buffer (Num.toU8 '"')
-                |> List.concat (Str.toUtf8 key)
-                |> List.append (Num.toU8 '"')
-                |> List.append (Num.toU8 ':')
-                |> appendWith value (@Json {})
+    Encode.custom \bytes, @Json opts ->
+        fieldOpts = { opts & depth: opts.depth + 1 }
 
-            bufferWithSuffix =
-                if fieldsLeft > 1 then
-                    List.append bufferWithKeyValue (Num.toU8 ',')
+        writeRecord = \{ buffer, fieldsWritten }, { key, value } ->
+            bufferWithSeparator =
+                if fieldsWritten > 0 then
+                    List.append buffer (Num.toU8 ',')
                 else
-                    bufferWithKeyValue
+                    buffer
+
+            bufferWithKey =
+                appendNewline bufferWithSeparator fieldOpts
+                |> List.append (Num.toU8 '"')
+                |> appendConvertedCase (Str.toUtf8 key) 0 (toJsonCase opts.fieldNameMapping) Bool.false
+                |> List.append (Num.toU8 '"')
+                |> appendColon opts
+
+            bufferWithKeyValue = appendValue bufferWithKey value fieldOpts
 
-            { buffer: bufferWithSuffix, fieldsLeft: fieldsLeft - 1 }
+            if List.len bufferWithKeyValue == List.len bufferWithKey then
+                # Leave out fields without a value, rather than writing invalid JSON.
+                { buffer: List.takeFirst bufferWithKeyValue (List.len buffer), fieldsWritten }
+            else
+                { buffer: bufferWithKeyValue, fieldsWritten: fieldsWritten + 1 }
 
         bytesHead = List.append bytes (Num.toU8 '{')
-        { buffer: bytesWithRecord } = List.walk fields { buffer: bytesHead, fieldsLeft: List.len fields } writeRecord
+        { buffer: bytesWithRecord, fieldsWritten: fieldCount } = List.walk fields { buffer: bytesHead, fieldsWritten: 0 } writeRecord
 
-        List.append bytesWithRecord (Num.toU8 '}')
+        if fieldCount == 0 then
+            List.append bytesWithRecord (Num.toU8 '}')
+        else
+            appendNewline bytesWithRecord opts
+            |> List.append (Num.toU8 '}')
 
 encodeTag = \name, payload ->
-    Encode.custom \bytes, @Json {} ->
+    Encode.custom \bytes, @Json opts ->
         # Idea: encode `A v1 v2` as `{"A": [v1, v2]}`
+        payloadOpts = { opts & depth: opts.depth + 1 }
+        itemOpts = { opts & depth: opts.depth + 2 }
+
         writePayload = \{ buffer, itemsLeft }, encoder ->
-            bufferWithValue = appendWith buffer encoder (@Json {})
+            bufferWithValue =
+                appendNewline buffer itemOpts
+                |> appendValue encoder itemOpts
             bufferWithSuffix =
                 if itemsLeft > 1 then
                     List.append bufferWithValue (Num.toU8 ',')
@@ -180,17 +241,103 @@ encodeTag = \name, payload ->
 
         bytesHead =
             List.append bytes (Num.toU8 '{')
+            |> appendNewline payloadOpts
             |> List.append (Num.toU8 '"')
             |> List.concat (Str.toUtf8 name)
             |> List.append (Num.toU8 '"')
-            |> List.append (Num.toU8 ':')
+            |> appendColon opts
             |> List.append (Num.toU8 '[')
 
         { buffer: bytesWithPayload } = List.walk payload { buffer: bytesHead, itemsLeft: List.len payload } writePayload
 
-        List.append bytesWithPayload (Num.toU8 ']')
+        bytesWithClosedPayload =
+            if List.isEmpty payload then
+                List.append bytesWithPayload (Num.toU8 ']')
+            else
+                appendNewline bytesWithPayload payloadOpts
+                |> List.append (Num.toU8 ']')
+
+        appendNewline bytesWithClosedPayload opts
         |> List.append (Num.toU8 '}')
 
+# Encodes a value straight into `bytes`, writing `null` instead if it encodes to nothing and
+# `emptyEncodeAsNull` is set.
+appendValue = \bytes, encoder, opts ->
+    bytesWithValue = appendWith bytes encoder (@Json opts)
+
+    if List.len bytesWithValue == List.len bytes && opts.emptyEncodeAsNull then
+        List.concat bytesWithValue (Str.toUtf8 "null")
+    else
+        bytesWithValue
+
+appendColon = \bytes, { indent } ->
+    if indent == 0 then
+        List.append bytes (Num.toU8 ':')
+    else
+        List.append bytes (Num.toU8 ':')
+        |> List.append (Num.toU8 ' ')
+
+# When pretty-printing, starts a new line indented to the current nesting depth.
+appendNewline = \bytes, { indent, depth } ->
+    if indent == 0 then
+        bytes
+    else
+        List.append bytes (Num.toU8 '\n')
+        |> List.concat (List.repeat (Num.toU8 ' ') (Num.toNat indent * depth))
+
+# How the casing of a field name changes between Roc and JSON.
+CaseConversion : [KeepCase, SplitWords U8, JoinWords U8, Capitalize, Uncapitalize]
+
+toJsonCase : FieldNameMapping -> CaseConversion
+toJsonCase = \mapping ->
+    when mapping is
+        Default -> KeepCase
+        SnakeCase -> SplitWords '_'
+        PascalCase -> Capitalize
+        KebabCase -> SplitWords '-'
+        CamelCase -> JoinWords '_'
+
+fromJsonCase : FieldNameMapping -> CaseConversion
+fromJsonCase = \mapping ->
+    when mapping is
+        Default -> KeepCase
+        SnakeCase -> JoinWords '_'
+        PascalCase -> Uncapitalize
+        KebabCase -> JoinWords '-'
+        CamelCase -> SplitWords '_'
+
+# Appends a field name to the bytes with its casing converted, starting at the given index. Only
+# ASCII letters and separators are changed, so the result stays valid UTF-8.
+#
+# This is a plain recursive function rather than a `List.walk` with helpers, since every nested
+# call deepens the recursion needed to specialize the encoders that use it.
+appendConvertedCase : List U8, List U8, Nat, CaseConversion, Bool -> List U8
+appendConvertedCase = \bytes, name, index, conversion, afterSeparator ->
+    when List.get name index is
+        Ok byte ->
+            isUpper = byte >= 'A' && byte <= 'Z'
+            isLower = byte >= 'a' && byte <= 'z'
+
+            converted =
+                when conversion is
+                    SplitWords separator if isUpper ->
+                        List.append bytes separator |> List.append (byte + 32)
+
+                    JoinWords separator if byte == separator -> bytes
+                    JoinWords _ if afterSeparator && isLower -> List.append bytes (byte - 32)
+                    Capitalize if index == 0 && isLower -> List.append bytes (byte - 32)
+                    Uncapitalize if index == 0 && isUpper -> List.append bytes (byte + 32)
+                    _ -> List.append bytes byte
+
+            isSeparator =
+                when conversion is
+                    JoinWords separator -> byte == separator
+                    _ -> Bool.false
+
+            appendConvertedCase converted name (index + 1) conversion isSeparator
+
+        Err _ -> bytes
+
 takeWhile = \list, predicate ->
     helper = \{ taken, rest } ->
         when List.first rest is
@@ -224,98 +371,98 @@ takeFloat = \bytes ->
         _ ->
             { taken: intPart, rest }
 
-decodeU8 = Decode.custom \bytes, @Json {} ->
+decodeU8 = Decode.custom \bytes, @Json _ ->
     { taken, rest } = takeDigits bytes
 
     when Str.fromUtf8 taken |> Result.try Str.toU8 is
         Ok n -> { result: Ok n, rest }
         Err _ -> { result: Err TooShort, rest }
 
-decodeU16 = Decode.custom \bytes, @Json {} ->
+decodeU16 = Decode.custom \bytes, @Json _ ->
     { taken, rest } = takeDigits bytes
 
     when Str.fromUtf8 taken |> Result.try Str.toU16 is
         Ok n -> { result: Ok n, rest }
         Err _ -> { result: Err TooShort, rest }
 
-decodeU32 = Decode.custom \bytes, @Json {} ->
+decodeU32 = Decode.custom \bytes, @Json _ ->
     { taken, rest } = takeDigits bytes
 
     when Str.fromUtf8 taken |> Result.try Str.toU32 is
         Ok n -> { result: Ok n, rest }
         Err _ -> { result: Err TooShort, rest }
 
-decodeU64 = Decode.custom \bytes, @Json {} ->
+decodeU64 = Decode.custom \bytes, @Json _ ->
     { taken, rest } = takeDigits bytes
 
     when Str.fromUtf8 taken |> Result.try Str.toU64 is
         Ok n -> { result: Ok n, rest }
         Err _ -> { result: Err TooShort, rest }
 
-decodeU128 = Decode.custom \bytes, @Json {} ->
+decodeU128 = Decode.custom \bytes, @Json _ ->
     { taken, rest } = takeDigits bytes
 
     when Str.fromUtf8 taken |> Result.try Str.toU128 is
         Ok n -> { result: Ok n, rest }
         Err _ -> { result: Err TooShort, rest }
 
-decodeI8 = Decode.custom \bytes, @Json {} ->
+decodeI8 = Decode.custom \bytes, @Json _ ->
     { taken, rest } = takeDigits bytes
 
     when Str.fromUtf8 taken |> Result.try Str.toI8 is
         Ok n -> { result: Ok n, rest }
         Err _ -> { result: Err TooShort, rest }
 
-decodeI16 = Decode.custom \bytes, @Json {} ->
+decodeI16 = Decode.custom \bytes, @Json _ ->
     { taken, rest } = takeDigits bytes
 
     when Str.fromUtf8 taken |> Result.try Str.toI16 is
         Ok n -> { result: Ok n, rest }
         Err _ -> { result: Err TooShort, rest }
 
-decodeI32 = Decode.custom \bytes, @Json {} ->
+decodeI32 = Decode.custom \bytes, @Json _ ->
     { taken, rest } = takeDigits bytes
 
     when Str.fromUtf8 taken |> Result.try Str.toI32 is
         Ok n -> { result: Ok n, rest }
         Err _ -> { result: Err TooShort, rest }
 
-decodeI64 = Decode.custom \bytes, @Json {} ->
+decodeI64 = Decode.custom \bytes, @Json _ ->
     { taken, rest } = takeDigits bytes
 
     when Str.fromUtf8 taken |> Result.try Str.toI64 is
         Ok n -> { result: Ok n, rest }
         Err _ -> { result: Err TooShort, rest }
 
-decodeI128 = Decode.custom \bytes, @Json {} ->
+decodeI128 = Decode.custom \bytes, @Json _ ->
     { taken, rest } = takeDigits bytes
 
     when Str.fromUtf8 taken |> Result.try Str.toI128 is
         Ok n -> { result: Ok n, rest }
         Err _ -> { result: Err TooShort, rest }
 
-decodeF32 = Decode.custom \bytes, @Json {} ->
+decodeF32 = Decode.custom \bytes, @Json _ ->
     { taken, rest } = takeFloat bytes
 
     when Str.fromUtf8 taken |> Result.try Str.toF32 is
         Ok n -> { result: Ok n, rest }
         Err _ -> { result: Err TooShort, rest }
 
-decodeF64 = Decode.custom \bytes, @Json {} ->
+decodeF64 = Decode.custom \bytes, @Json _ ->
     { taken, rest } = takeFloat bytes
 
     when Str.fromUtf8 taken |> Result.try Str.toF64 is
         Ok n -> { result: Ok n, rest }
         Err _ -> { result: Err TooShort, rest }
 
-decodeDec = Decode.custom \bytes, @Json {} ->
+decodeDec = Decode.custom \bytes, @Json _ ->
     { taken, rest } = takeFloat bytes
 
     when Str.fromUtf8 taken |> Result.try Str.toDec is
         Ok n -> { result: Ok n, rest }
         Err _ -> { result: Err TooShort, rest }
 
-decodeBool = Decode.custom \bytes, @Json {} ->
+decodeBool = Decode.custom \bytes, @Json _ ->
     { before: maybeFalse, others: afterFalse } = List.split bytes 5
 
     # Note: this could be more performant by traversing both branches char-by-char.
@@ -354,12 +501,12 @@ jsonString = \bytes ->
     else
         { result: Err TooShort, rest: bytes }
 
-decodeString = Decode.custom \bytes, @Json {} ->
+decodeString = Decode.custom \bytes, @Json _ ->
     jsonString bytes
 
-decodeList = \decodeElem -> Decode.custom \bytes, @Json {} ->
+decodeList = \decodeElem -> Decode.custom \bytes, @Json opts ->
         decodeElems = \chunk, accum ->
-            when Decode.decodeWith chunk decodeElem (@Json {}) is
+            when Decode.decodeWith chunk decodeElem (@Json opts) is
                 { result, rest } ->
                     when result is
                         Ok val ->
@@ -414,8 +561,20 @@ openBrace = \bytes -> parseExactChar bytes '{'
 closingBrace : List U8 -> DecodeResult {}
 closingBrace = \bytes -> parseExactChar bytes '}'
 
-recordKey : List U8 -> DecodeResult Str
-recordKey = \bytes -> jsonString bytes
+recordKey : List U8, CaseConversion -> DecodeResult Str
+recordKey = \bytes, conversion ->
+    when conversion is
+        KeepCase -> jsonString bytes
+        _ ->
+            { result, rest } = jsonString bytes
+
+            when result is
+                Ok key ->
+                    when Str.fromUtf8 (appendConvertedCase [] (Str.toUtf8 key) 0 conversion Bool.false) is
+                        Ok convertedKey -> { result: Ok convertedKey, rest }
+                        Err _ -> { result: Err TooShort, rest }
+
+                Err e -> { result: Err e, rest }
 
 anything : List U8 -> DecodeResult {}
 anything = \bytes -> { result: Err TooShort, rest: bytes }
@@ -426,16 +585,25 @@ colon = \bytes -> parseExactChar bytes ':'
 comma : List U8 -> DecodeResult {}
 comma = \bytes -> parseExactChar bytes ','
 
+# A `null` field value is decoded like a missing field when `nullDecodeAsEmpty` is set.
+decodeFieldValue = \bytes, decoder, opts ->
+    if opts.nullDecodeAsEmpty && List.startsWith bytes ['n', 'u', 'l', 'l'] then
+        { result } = Decode.decodeWith [] decoder (@Json opts)
+
+        { result, rest: List.sublist bytes { start: 4, len: List.len bytes - 4 } }
+    else
+        Decode.decodeWith bytes decoder (@Json opts)
+
 tryDecode : DecodeResult a, ({ val : a, rest : List U8 } -> DecodeResult b) -> DecodeResult b
 tryDecode = \{ result, rest }, mapper ->
     when result is
         Ok val -> mapper { val, rest }
         Err e -> { result: Err e, rest }
 
-decodeRecord = \initialState, stepField, finalizer -> Decode.custom \bytes, @Json {} ->
-        # NB: the stepper function must be passed explicitly until #2894 is resolved.
-        decodeFields = \stepper, state, kvBytes ->
-            { val: key, rest } <- recordKey kvBytes |> tryDecode
+decodeRecord = \initialState, stepField, finalizer -> Decode.custom \bytes, @Json opts ->
+        # NB: the stepper function and options must be passed explicitly until #2894 is resolved.
+        decodeFields = \stepper, fieldOpts, state, kvBytes ->
+            { val: key, rest } <- recordKey kvBytes (fromJsonCase fieldOpts.fieldNameMapping) |> tryDecode
             { rest: afterColonBytes } <- colon rest |> tryDecode
             { val: newState, rest: beforeCommaOrBreak } <- tryDecode
                     (
@@ -445,18 +613,18 @@ decodeRecord = \initialState, stepField, finalizer -> Decode.custom \bytes, @Jso
                                 { result: Ok state, rest: beforeCommaOrBreak }
 
                             Keep decoder ->
-                                Decode.decodeWith afterColonBytes decoder (@Json {})
+                                decodeFieldValue afterColonBytes decoder fieldOpts
                     )
 
             { result: commaResult, rest: nextBytes } = comma beforeCommaOrBreak
 
             when commaResult is
-                Ok {} -> decodeFields stepField newState nextBytes
+                Ok {} -> decodeFields stepField fieldOpts newState nextBytes
                 Err _ -> { result: Ok newState, rest: nextBytes }
 
         { rest: afterBraceBytes } <- bytes |> openBrace |> tryDecode
 
-        { val: endStateResult, rest: beforeClosingBraceBytes } <- decodeFields stepField initialState afterBraceBytes |> tryDecode
+        { val: endStateResult, rest: beforeClosingBraceBytes } <- decodeFields stepField opts initialState afterBraceBytes |> tryDecode
 
         { rest: afterRecordBytes } <- beforeClosingBraceBytes |> closingBrace |> tryDecode
 
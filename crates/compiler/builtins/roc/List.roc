@@ -61,6 +61,7 @@ interface List
         dropIf,
         sortAsc,
         sortDesc,
+        sort,
         reserve,
         walkBackwardsUntil,
         countIf,
@@ -69,6 +70,7 @@ interface List
         Bool.{ Bool, Eq },
         Result.{ Result },
         Num.{ Nat, Num, Int },
+        Sort.{ Sort },
     ]
 
 ## Types
@@ -771,6 +773,14 @@ sortAsc = \list -> List.sortWith list Num.compare
 sortDesc : List (Num a) -> List (Num a)
 sortDesc = \list -> List.sortWith list (\a, b -> Num.compare b a)
 
+## Sorts a list in ascending order, using the [Sort] ability of its elements.
+##
+##     expect List.sort ["banana", "apple", "cherry"] == ["apple", "banana", "cherry"]
+##
+## To sort with a custom comparison function, use [List.sortWith] instead.
+sort : List a -> List a | a has Sort
+sort = \list -> List.sortWith list Sort.compare
+
 swap : List a, Nat, Nat -> List a
 
 ## Returns the first element in the list, or `ListWasEmpty` if it was empty.
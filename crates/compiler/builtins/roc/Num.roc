@@ -77,6 +77,9 @@ interface Num
         shiftLeftBy,
         shiftRightBy,
         shiftRightZfBy,
+        countLeadingZeroBits,
+        countTrailingZeroBits,
+        countOneBits,
         subWrap,
         subChecked,
         subSaturated,
@@ -142,6 +145,13 @@ interface Num
         toF32Checked,
         toF64,
         toF64Checked,
+        isNaN,
+        isInfinite,
+        isFinite,
+        f32ToBits,
+        f64ToBits,
+        f32FromBits,
+        f64FromBits,
     ]
     imports [
         Bool.{ Bool },
@@ -895,6 +905,27 @@ shiftRightBy : Int a, U8 -> Int a
 ## In some languages `shiftRightBy` is implemented as a binary operator `>>`.
 shiftRightZfBy : Int a, U8 -> Int a
 
+## Counts the number of most-significant (leading in a big-Endian sense) zeroes in an integer.
+##
+## >>> Num.countLeadingZeroBits 0b0001_1100u8
+##
+## >>> Num.countLeadingZeroBits 0u8
+countLeadingZeroBits : Int a -> Nat
+
+## Counts the number of least-significant (trailing in a big-Endian sense) zeroes in an integer.
+##
+## >>> Num.countTrailingZeroBits 0b0001_1100u8
+##
+## >>> Num.countTrailingZeroBits 0u8
+countTrailingZeroBits : Int a -> Nat
+
+## Counts the number of set bits in an integer.
+##
+## >>> Num.countOneBits 0b0001_1100u8
+##
+## >>> Num.countOneBits 0b1111_1111u8
+countOneBits : Int a -> Nat
+
 ## Round off the given fraction to the nearest integer.
 round : Frac * -> Int *
 floor : Frac * -> Int *
@@ -1270,7 +1301,8 @@ toF64Checked : Num * -> Result F64 [OutOfBounds]
 ##
 ## This is the opposite of #isInfinite, except when given [*NaN*](Num.isNaN). Both
 ## #isFinite and #isInfinite return `Bool.false` for [*NaN*](Num.isNaN).
-# isFinite : Frac * -> Bool
+isFinite : Frac * -> Bool
+
 ## When given a [F64] or [F32] value, returns `Bool.true` if that value is either
 ## ∞ or -∞, and `Bool.false` otherwise.
 ##
@@ -1278,7 +1310,8 @@ toF64Checked : Num * -> Result F64 [OutOfBounds]
 ##
 ## This is the opposite of #isFinite, except when given [*NaN*](Num.isNaN). Both
 ## #isFinite and #isInfinite return `Bool.false` for [*NaN*](Num.isNaN).
-# isInfinite : Frac * -> Bool
+isInfinite : Frac * -> Bool
+
 ## When given a [F64] or [F32] value, returns `Bool.true` if that value is
 ## *NaN* ([not a number](https://en.wikipedia.org/wiki/NaN)), and `Bool.false` otherwise.
 ##
@@ -1301,7 +1334,30 @@ toF64Checked : Num * -> Result F64 [OutOfBounds]
 ## Note that you should never put a *NaN* into a [Set], or use it as the key in
 ## a [Dict]. The result is entries that can never be removed from those
 ## collections! See the documentation for [Set.insert] and [Dict.insert] for details.
-# isNaN : Frac * -> Bool
+isNaN : Frac * -> Bool
+
+## Returns the bits of an [F32], as laid out in memory by the
+## [IEEE-754](https://en.wikipedia.org/wiki/IEEE_754) standard.
+##
+## >>> Num.f32ToBits 1.0f32
+f32ToBits : F32 -> U32
+
+## Returns the bits of an [F64], as laid out in memory by the
+## [IEEE-754](https://en.wikipedia.org/wiki/IEEE_754) standard.
+##
+## >>> Num.f64ToBits 1.0f64
+f64ToBits : F64 -> U64
+
+## Reinterprets the given bits as an [F32]. This is the inverse of [Num.f32ToBits].
+##
+## >>> Num.f32FromBits 0x3f80_0000
+f32FromBits : U32 -> F32
+
+## Reinterprets the given bits as an [F64]. This is the inverse of [Num.f64ToBits].
+##
+## >>> Num.f64FromBits 0x3ff0_0000_0000_0000
+f64FromBits : U64 -> F64
+
 ## Returns the higher of two numbers.
 ##
 ## If either argument is [*NaN*](Num.isNaN), returns `Bool.false` no matter what. (*NaN*
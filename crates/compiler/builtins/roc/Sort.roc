@@ -0,0 +1,18 @@
+interface Sort
+    exposes [
+        Sort,
+        compare,
+    ]
+    imports []
+
+## A value that can be put in order relative to other values of the same type.
+##
+## [Sort] is derived for records, tuples and tag unions whose contents implement
+## [Sort], and is implemented for numbers and strings. Records are compared
+## field by field, in alphabetical order of the field names. Tag unions are
+## compared first by tag, in alphabetical order of the tag names, and then by
+## their payloads.
+Sort has
+    ## Compares two values, returning whether the first is less than ([LT]),
+    ## equal to ([EQ]) or greater than ([GT]) the second.
+    compare : a, a -> [LT, EQ, GT] | a has Sort
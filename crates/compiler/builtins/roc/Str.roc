@@ -66,9 +66,13 @@
 ##
 ## Here, `greeting` will become the string `"Hello there, Lee! Welcome to Roctown."`.
 ## This is known as [string interpolation](https://en.wikipedia.org/wiki/String_interpolation),
-## and you can use it as many times as you like inside a string. The name
-## between the parentheses must refer to a `Str` value that is currently in
-## scope, and it must be a name - it can't be an arbitrary expression like a function call.
+## and you can use it as many times as you like inside a string. The expression
+## between the parentheses can be any expression which evaluates to a `Str`, including
+## function calls, field accesses, and pipelines:
+##
+##     "Total: \(Num.toStr (price * quantity))"
+##     "Welcome back, \(user.name)!"
+##     "Items: \(items |> List.map .name |> Str.joinWith ", ")"
 interface Str
     exposes [
         Utf8Problem,
@@ -117,6 +121,12 @@ interface Str
         withCapacity,
         withPrefix,
         graphemes,
+        compare,
+        toUpper,
+        toLower,
+        caseFold,
+        toNfc,
+        toNfd,
     ]
     imports [
         Bool.{ Bool, Eq },
@@ -317,6 +327,46 @@ trimLeft : Str -> Str
 ##      expect Str.trimRight "   Hello      \n\n" == "   Hello"
 trimRight : Str -> Str
 
+## Convert every letter in the [Str] to uppercase.
+##
+## This uses the full Unicode case mappings, so the result can be longer than
+## the original. It does not apply any language-specific rules.
+##
+##     expect Str.toUpper "Hello, World!" == "HELLO, WORLD!"
+##     expect Str.toUpper "straße" == "STRASSE"
+toUpper : Str -> Str
+
+## Convert every letter in the [Str] to lowercase.
+##
+## This uses the full Unicode case mappings, so the result can be longer than
+## the original. It does not apply any language-specific rules, but it does
+## turn a `Σ` that ends a word into `ς`.
+##
+##     expect Str.toLower "Hello, World!" == "hello, world!"
+##     expect Str.toLower "ΣΑΣ" == "σας"
+toLower : Str -> Str
+
+## Fold the case of every letter in the [Str], for comparing strings without
+## regard to case. The result is meant for comparisons, not for display.
+##
+##     expect Str.caseFold "Straße" == Str.caseFold "STRASSE"
+##
+## Strings which look the same can still differ in how their accents are
+## encoded, so normalize them with [Str.toNfc] or [Str.toNfd] first if needed.
+caseFold : Str -> Str
+
+## Convert the [Str] to Unicode [Normalization Form C](https://www.unicode.org/reports/tr15/),
+## in which accented letters are composed into single code points wherever possible.
+##
+##     expect Str.toNfc "e\u(301)" == "\u(E9)"
+toNfc : Str -> Str
+
+## Convert the [Str] to Unicode [Normalization Form D](https://www.unicode.org/reports/tr15/),
+## in which accented letters are decomposed into a base letter followed by combining marks.
+##
+##     expect Str.toNfd "\u(E9)" == "e\u(301)"
+toNfd : Str -> Str
+
 ## Encode a [Str] to a [Dec]. A [Dec] value is a 128-bit decimal
 ## [fixed-point number](https://en.wikipedia.org/wiki/Fixed-point_arithmetic).
 ##
@@ -799,3 +849,14 @@ strToNumHelp = \string ->
 ##     expect Str.withPrefix "Awesome" "Roc" == "RocAwesome"
 withPrefix : Str, Str -> Str
 withPrefix = \str, prefix -> Str.concat prefix str
+
+## Compares two strings by their UTF-8 bytes, so that strings sort in
+## lexicographic order of their Unicode code points. A string sorts before any
+## longer string that starts with it.
+##
+##     expect Str.compare "Apple" "Banana" == LT
+##     expect Str.compare "Roc" "Rocket" == LT
+##
+## Note that this does not take the conventions of any language into account;
+## for example, every uppercase ASCII letter sorts before every lowercase one.
+compare : Str, Str -> [LT, EQ, GT]
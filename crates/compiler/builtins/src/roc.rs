@@ -14,6 +14,8 @@ pub fn module_source(module_id: ModuleId) -> &'static str {
         ModuleId::ENCODE => ENCODE,
         ModuleId::DECODE => DECODE,
         ModuleId::HASH => HASH,
+        ModuleId::INSPECT => INSPECT,
+        ModuleId::SORT => SORT,
         ModuleId::JSON => JSON,
         _ => panic!(
             "ModuleId {:?} is not part of the standard library",
@@ -33,4 +35,6 @@ const BOOL: &str = include_str!("../roc/Bool.roc");
 const ENCODE: &str = include_str!("../roc/Encode.roc");
 const DECODE: &str = include_str!("../roc/Decode.roc");
 const HASH: &str = include_str!("../roc/Hash.roc");
+const INSPECT: &str = include_str!("../roc/Inspect.roc");
+const SORT: &str = include_str!("../roc/Sort.roc");
 const JSON: &str = include_str!("../roc/Json.roc");
@@ -91,7 +91,6 @@ macro_rules! map_symbol_to_lowlevel_and_arity {
                 // these are not implemented, not sure why
                 LowLevel::StrFromInt => unimplemented!(),
                 LowLevel::StrFromFloat => unimplemented!(),
-                LowLevel::NumIsFinite => unimplemented!(),
             }
         }
     };
@@ -126,6 +125,12 @@ map_symbol_to_lowlevel_and_arity! {
     StrGetCapacity; STR_CAPACITY; 1,
     StrWithCapacity; STR_WITH_CAPACITY; 1,
     StrGraphemes; STR_GRAPHEMES; 1,
+    StrToUpper; STR_TO_UPPER; 1,
+    StrToLower; STR_TO_LOWER; 1,
+    StrCaseFold; STR_CASE_FOLD; 1,
+    StrToNfc; STR_TO_NFC; 1,
+    StrToNfd; STR_TO_NFD; 1,
+    StrCompare; STR_COMPARE; 2,
 
     ListLen; LIST_LEN; 1,
     ListWithCapacity; LIST_WITH_CAPACITY; 1,
@@ -182,6 +187,9 @@ map_symbol_to_lowlevel_and_arity! {
     NumCeiling; NUM_CEILING; 1,
     NumPowInt; NUM_POW_INT; 2,
     NumFloor; NUM_FLOOR; 1,
+    NumIsNan; NUM_IS_NAN; 1,
+    NumIsInfinite; NUM_IS_INFINITE; 1,
+    NumIsFinite; NUM_IS_FINITE; 1,
     NumAtan; NUM_ATAN; 1,
     NumAcos; NUM_ACOS; 1,
     NumAsin; NUM_ASIN; 1,
@@ -193,6 +201,13 @@ map_symbol_to_lowlevel_and_arity! {
     NumShiftLeftBy; NUM_SHIFT_LEFT; 2,
     NumShiftRightBy; NUM_SHIFT_RIGHT; 2,
     NumShiftRightZfBy; NUM_SHIFT_RIGHT_ZERO_FILL; 2,
+    NumCountLeadingZeroBits; NUM_COUNT_LEADING_ZERO_BITS; 1,
+    NumCountTrailingZeroBits; NUM_COUNT_TRAILING_ZERO_BITS; 1,
+    NumCountOneBits; NUM_COUNT_ONE_BITS; 1,
+    NumF32ToBits; NUM_F32_TO_BITS; 1,
+    NumF64ToBits; NUM_F64_TO_BITS; 1,
+    NumF32FromBits; NUM_F32_FROM_BITS; 1,
+    NumF64FromBits; NUM_F64_FROM_BITS; 1,
     NumToStr; NUM_TO_STR; 1,
 
     Eq; BOOL_STRUCTURAL_EQ; 2,
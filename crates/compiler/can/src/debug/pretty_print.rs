@@ -5,7 +5,7 @@ use crate::expr::Expr::{self, *};
 use crate::expr::{
     ClosureData, DeclarationTag, Declarations, FunctionDef, OpaqueWrapFunctionData, WhenBranch,
 };
-use crate::pattern::{Pattern, RecordDestruct};
+use crate::pattern::{ListPatterns, Pattern, RecordDestruct};
 
 use roc_module::symbol::{Interns, ModuleId, Symbol};
 
@@ -32,10 +32,16 @@ pub fn pretty_print_declarations(c: &Ctx, declarations: &Declarations) -> String
                 let function_def = &declarations.function_bodies[f_index.index()].value;
                 toplevel_function(c, &f, symbol, function_def, &body.value)
             }
-            DeclarationTag::Expectation => todo!(),
-            DeclarationTag::ExpectationFx => todo!(),
-            DeclarationTag::Destructure(_) => todo!(),
-            DeclarationTag::MutualRecursion { .. } => todo!(),
+            DeclarationTag::Expectation => toplevel_expect(c, &f, "expect", &body.value),
+            DeclarationTag::ExpectationFx => toplevel_expect(c, &f, "expect-fx", &body.value),
+            DeclarationTag::Destructure(d_index) => {
+                let destructure_def = &declarations.destructs[d_index.index()];
+                def_help(c, &f, &destructure_def.loc_pattern.value, &body.value)
+            }
+            DeclarationTag::MutualRecursion { .. } => {
+                // Only marks the start of a group of mutually recursive defs, which follow it.
+                continue;
+            }
         };
 
         defs.push(def);
@@ -130,6 +136,36 @@ fn toplevel_function<'a>(
         .group()
 }
 
+fn toplevel_expect<'a>(
+    c: &Ctx,
+    f: &'a Arena<'a>,
+    keyword: &'static str,
+    condition: &'a Expr,
+) -> DocBuilder<'a, Arena<'a>> {
+    f.text(keyword)
+        .append(f.line())
+        .append(expr(c, EPrec::Free, f, condition))
+        .nest(2)
+        .group()
+}
+
+/// `keyword condition` followed by the continuation, as in `expect` and `dbg`
+fn statement<'a>(
+    c: &Ctx,
+    f: &'a Arena<'a>,
+    keyword: &'static str,
+    condition: &'a Expr,
+    continuation: &'a Expr,
+) -> DocBuilder<'a, Arena<'a>> {
+    f.text(keyword)
+        .append(f.space())
+        .append(expr(c, EPrec::Free, f, condition))
+        .group()
+        .nest(2)
+        .append(f.hardline())
+        .append(expr(c, EPrec::Free, f, continuation))
+}
+
 #[derive(PartialEq, PartialOrd)]
 enum EPrec {
     Free,
@@ -141,6 +177,10 @@ fn expr<'a>(c: &Ctx, p: EPrec, f: &'a Arena<'a>, e: &'a Expr) -> DocBuilder<'a,
     match e {
         Num(_, n, _, _) | Int(_, _, n, _, _) | Float(_, _, n, _, _) => f.text(&**n),
         Str(s) => f.text(format!(r#""{}""#, s)),
+        StrInterpolation(loc_expr) => f
+            .text("\"\\(")
+            .append(expr(c, Free, f, &loc_expr.value))
+            .append(f.text(")\"")),
         SingleQuote(_, _, c, _) => f.text(format!("'{}'", c)),
         List {
             elem_var: _,
@@ -216,7 +256,10 @@ fn expr<'a>(c: &Ctx, p: EPrec, f: &'a Arena<'a>, e: &'a Expr) -> DocBuilder<'a,
                     .nest(2),
             )
             .group(),
-        LetRec(_, _, _) => todo!(),
+        LetRec(defs, body, _) => f
+            .concat(defs.iter().map(|d| def(c, f, d).append(f.hardline())))
+            .append(expr(c, Free, f, &body.value))
+            .group(),
         LetNonRec(loc_def, body) => def(c, f, loc_def)
             .append(f.hardline())
             .append(expr(c, Free, f, &body.value))
@@ -258,7 +301,24 @@ fn expr<'a>(c: &Ctx, p: EPrec, f: &'a Arena<'a>, e: &'a Expr) -> DocBuilder<'a,
                     .nest(2)
             )
         }
-        ForeignCall { .. } => todo!(),
+        ForeignCall {
+            foreign_symbol,
+            args,
+            ..
+        } => maybe_paren!(
+            Free,
+            p,
+            f.text(foreign_symbol.as_str().to_owned())
+                .append(
+                    f.concat(
+                        args.iter()
+                            .map(|le| f.line().append(expr(c, AppArg, f, &le.1)))
+                    )
+                    .group()
+                )
+                .group()
+                .nest(2)
+        ),
         Closure(ClosureData {
             arguments,
             loc_body,
@@ -330,12 +390,16 @@ fn expr<'a>(c: &Ctx, p: EPrec, f: &'a Arena<'a>, e: &'a Expr) -> DocBuilder<'a,
         } => expr(c, AppArg, f, &loc_expr.value)
             .append(f.text(format!(".{}", field.as_str())))
             .group(),
-        TupleAccess { .. } => todo!(),
+        TupleAccess {
+            loc_expr, index, ..
+        } => expr(c, AppArg, f, &loc_expr.value)
+            .append(f.text(format!(".{}", index)))
+            .group(),
         OpaqueWrapFunction(OpaqueWrapFunctionData { opaque_name, .. }) => {
             f.text(format!("@{}", opaque_name.as_str(c.interns)))
         }
-        RecordAccessor(_) => todo!(),
-        TupleAccessor(_) => todo!(),
+        RecordAccessor(data) => f.text(format!(".{}", data.field.as_str())),
+        TupleAccessor(data) => f.text(format!(".{}", data.index)),
         RecordUpdate {
             symbol, updates, ..
         } => f
@@ -384,14 +448,54 @@ fn expr<'a>(c: &Ctx, p: EPrec, f: &'a Arena<'a>, e: &'a Expr) -> DocBuilder<'a,
                 )
                 .group()
         ),
-        Crash { .. } => todo!(),
-        ZeroArgumentTag { .. } => todo!(),
-        OpaqueRef { .. } => todo!(),
-        Dbg { .. } => todo!(),
-        Expect { .. } => todo!(),
-        ExpectFx { .. } => todo!(),
-        TypedHole(_) => todo!(),
-        RuntimeError(_) => todo!(),
+        Crash { msg, .. } => maybe_paren!(
+            Free,
+            p,
+            f.text("crash")
+                .append(f.line())
+                .append(expr(c, AppArg, f, &msg.value))
+                .group()
+                .nest(2)
+        ),
+        ZeroArgumentTag { name, .. } => f.text(name.0.as_str()),
+        OpaqueRef { name, argument, .. } => maybe_paren!(
+            Free,
+            p,
+            f.text(format!("@{}", name.as_str(c.interns)))
+                .append(f.line())
+                .append(expr(c, AppArg, f, &argument.1.value))
+                .group()
+                .nest(2)
+        ),
+        Dbg {
+            loc_condition,
+            loc_continuation,
+            ..
+        } => statement(c, f, "dbg", &loc_condition.value, &loc_continuation.value),
+        Expect {
+            loc_condition,
+            loc_continuation,
+            ..
+        } => statement(
+            c,
+            f,
+            "expect",
+            &loc_condition.value,
+            &loc_continuation.value,
+        ),
+        ExpectFx {
+            loc_condition,
+            loc_continuation,
+            ..
+        } => statement(
+            c,
+            f,
+            "expect-fx",
+            &loc_condition.value,
+            &loc_continuation.value,
+        ),
+        TypedHole(_) => f.text("_"),
+        RuntimeError(_) => f.text("<runtime error>"),
     }
 }
 
@@ -505,7 +609,29 @@ fn pattern<'a>(
             )
             .append(f.text("}"))
             .group(),
-        List { .. } => todo!(),
+        List {
+            patterns: ListPatterns { patterns, opt_rest },
+            ..
+        } => {
+            let mut docs: Vec<_> = patterns
+                .iter()
+                .map(|lp| pattern(c, Free, f, &lp.value))
+                .collect();
+
+            if let Some((index, opt_as)) = opt_rest {
+                let rest = match opt_as {
+                    Some(symbol) => f.text(".. as ").append(pp_sym(c, f, *symbol)),
+                    None => f.text(".."),
+                };
+
+                docs.insert(*index, rest);
+            }
+
+            f.text("[")
+                .append(f.intersperse(docs, f.text(", ")))
+                .append(f.text("]"))
+                .group()
+        }
         NumLiteral(_, n, _, _) | IntLiteral(_, _, n, _, _) | FloatLiteral(_, _, n, _, _) => {
             f.text(&**n)
         }
@@ -513,9 +639,9 @@ fn pattern<'a>(
         SingleQuote(_, _, c, _) => f.text(format!("'{}'", c)),
         Underscore => f.text("_"),
 
-        Shadowed(_, _, _) => todo!(),
-        OpaqueNotInScope(_) => todo!(),
-        UnsupportedPattern(_) => todo!(),
-        MalformedPattern(_, _) => todo!(),
+        Shadowed(_, _, symbol) => pp_sym(c, f, *symbol),
+        OpaqueNotInScope(_) | UnsupportedPattern(_) | MalformedPattern(_, _) => {
+            f.text("<malformed pattern>")
+        }
     }
 }
@@ -1032,7 +1032,7 @@ fn canonicalize_value_defs<'a>(
                 // that get would have gotten added later in the defs list!
                 pending_value_defs.push(pending_def);
             }
-            PendingValue::SignatureDefMismatch => { /* skip */ }
+            PendingValue::SignatureDefMismatch | PendingValue::Malformed => { /* skip */ }
             PendingValue::Dbg(pending_dbg) => {
                 pending_dbgs.push(pending_dbg);
             }
@@ -2670,6 +2670,8 @@ enum PendingValue<'a> {
     Expect(PendingExpectOrDbg<'a>),
     ExpectFx(PendingExpectOrDbg<'a>),
     SignatureDefMismatch,
+    /// The parser already reported the syntax error
+    Malformed,
 }
 
 struct PendingExpectOrDbg<'a> {
@@ -2793,6 +2795,8 @@ fn to_pending_value_def<'a>(
             condition,
             preceding_comment: *preceding_comment,
         }),
+
+        Malformed(_) => PendingValue::Malformed,
     }
 }
 
@@ -541,8 +541,12 @@ fn to_nonredundant_rows(
         overall_region,
     } = rows;
     let mut checked_rows = Vec::with_capacity(rows.len());
+    // Rows of branches without a guard. A guarded branch is only redundant if these cover it,
+    // since any earlier guarded branch might not match at runtime.
+    let mut guard_free_rows = Vec::with_capacity(rows.len());
 
     let mut redundancies = vec![];
+    let mut non_redundant_marks = vec![];
     let mut errors = vec![];
 
     for (
@@ -566,9 +570,13 @@ fn to_nonredundant_rows(
                 branch_region: region,
                 index: HumanIndex::zero_based(row_number),
             })
-        } else if !(matches!(guard, Guard::HasGuard)
-            || is_useful(checked_rows.clone(), next_row.clone()))
-        {
+        } else if !is_useful(
+            match guard {
+                Guard::HasGuard => guard_free_rows.clone(),
+                Guard::NoGuard => checked_rows.clone(),
+            },
+            next_row.clone(),
+        ) {
             Some(Error::Redundant {
                 overall_region,
                 branch_region: region,
@@ -580,7 +588,11 @@ fn to_nonredundant_rows(
 
         match redundant_err {
             None => {
+                if guard == Guard::NoGuard {
+                    guard_free_rows.push(next_row.clone());
+                }
                 checked_rows.push(next_row);
+                non_redundant_marks.push(redundant_mark);
             }
             Some(err) => {
                 redundancies.push(redundant_mark);
@@ -589,6 +601,10 @@ fn to_nonredundant_rows(
         }
     }
 
+    // A branch with several patterns, like `A | B -> ...`, is only redundant if all of them are;
+    // otherwise it is still needed for the patterns that are not.
+    redundancies.retain(|mark| !non_redundant_marks.contains(mark));
+
     Ok(NonRedundantSummary {
         non_redundant_rows: checked_rows,
         redundancies,
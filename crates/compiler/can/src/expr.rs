@@ -92,6 +92,9 @@ pub enum Expr {
     Int(Variable, Variable, Box<str>, IntValue, IntBound),
     Float(Variable, Variable, Box<str>, f64, FloatBound),
     Str(Box<str>),
+    /// A string that's nothing but an interpolation, like "\(name)". There's nothing to
+    /// concatenate the interpolated expression with, but it still has to be a Str.
+    StrInterpolation(Box<Loc<Expr>>),
     // Number variable, precision variable, value, bound
     SingleQuote(Variable, Variable, char, SingleQuoteBound),
     List {
@@ -299,6 +302,7 @@ impl Expr {
             Self::Int(..) => Category::Int,
             Self::Float(..) => Category::Frac,
             Self::Str(..) => Category::Str,
+            Self::StrInterpolation(..) => Category::Str,
             Self::SingleQuote(..) => Category::Character,
             Self::List { .. } => Category::List,
             &Self::Var(sym, _) => Category::Lookup(sym),
@@ -608,6 +612,14 @@ impl WhenBranch {
                 .chain([self.value.region].iter()),
         )
     }
+
+    /// Whether the parser could not parse this branch, and skipped over it
+    pub fn is_malformed(&self) -> bool {
+        matches!(
+            self.value.value,
+            Expr::RuntimeError(RuntimeError::MalformedSyntax(_))
+        )
+    }
 }
 
 pub fn canonicalize_expr<'a>(
@@ -1012,6 +1024,9 @@ pub fn canonicalize_expr<'a>(
         ast::Expr::Backpassing(_, _, _) => {
             unreachable!("Backpassing should have been desugared by now")
         }
+        ast::Expr::RecordBuilder(_) => {
+            unreachable!("RecordBuilder should have been desugared by now")
+        }
         ast::Expr::Closure(loc_arg_patterns, loc_body_expr) => {
             let (closure_data, output) =
                 canonicalize_closure(env, var_store, scope, loc_arg_patterns, loc_body_expr, None);
@@ -1314,6 +1329,27 @@ pub fn canonicalize_expr<'a>(
             use roc_problem::can::RuntimeError::*;
             (RuntimeError(MalformedClosure(region)), Output::default())
         }
+        ast::Expr::Malformed(_) => {
+            // The parser already reported the syntax error
+            use roc_problem::can::RuntimeError::*;
+            (RuntimeError(MalformedSyntax(region)), Output::default())
+        }
+        ast::Expr::MultipleRecordBuilders(sub_expr) => {
+            use roc_problem::can::RuntimeError::*;
+
+            let problem = MultipleRecordBuilders(sub_expr.region);
+            env.problem(Problem::RuntimeError(problem.clone()));
+
+            (RuntimeError(problem), Output::default())
+        }
+        ast::Expr::UnappliedRecordBuilder(sub_expr) => {
+            use roc_problem::can::RuntimeError::*;
+
+            let problem = UnappliedRecordBuilder(sub_expr.region);
+            env.problem(Problem::RuntimeError(problem.clone()));
+
+            (RuntimeError(problem), Output::default())
+        }
         ast::Expr::MalformedIdent(name, bad_ident) => {
             use roc_problem::can::RuntimeError::*;
 
@@ -1884,6 +1920,15 @@ pub fn inline_calls(var_store: &mut VarStore, expr: Expr) -> Expr {
         | other @ OpaqueWrapFunction(_)
         | other @ Crash { .. } => other,
 
+        StrInterpolation(loc_expr) => {
+            let value = inline_calls(var_store, loc_expr.value);
+
+            StrInterpolation(Box::new(Loc {
+                value,
+                region: loc_expr.region,
+            }))
+        }
+
         List {
             elem_var,
             loc_elems,
@@ -2315,14 +2360,6 @@ fn flatten_str_literal<'a>(
     }
 }
 
-pub fn is_valid_interpolation(expr: &ast::Expr<'_>) -> bool {
-    match expr {
-        ast::Expr::Var { .. } => true,
-        ast::Expr::RecordAccess(sub_expr, _) => is_valid_interpolation(sub_expr),
-        _ => false,
-    }
-}
-
 enum StrSegment {
     Interpolation(Loc<Expr>),
     Plaintext(Box<str>),
@@ -2374,35 +2411,21 @@ fn flatten_str_lines<'a>(
                     }
                 },
                 Interpolated(loc_expr) => {
-                    if is_valid_interpolation(loc_expr.value) {
-                        // Interpolations desugar to Str.concat calls
-                        output.references.insert_call(Symbol::STR_CONCAT);
+                    // Interpolations desugar to Str.concat calls
+                    output.references.insert_call(Symbol::STR_CONCAT);
 
-                        if !buf.is_empty() {
-                            segments.push(StrSegment::Plaintext(buf.into()));
+                    if !buf.is_empty() {
+                        segments.push(StrSegment::Plaintext(buf.into()));
 
-                            buf = String::new();
-                        }
-
-                        let (loc_expr, new_output) = canonicalize_expr(
-                            env,
-                            var_store,
-                            scope,
-                            loc_expr.region,
-                            loc_expr.value,
-                        );
+                        buf = String::new();
+                    }
 
-                        output.union(new_output);
+                    let (loc_expr, new_output) =
+                        canonicalize_expr(env, var_store, scope, loc_expr.region, loc_expr.value);
 
-                        segments.push(StrSegment::Interpolation(loc_expr));
-                    } else {
-                        env.problem(Problem::InvalidInterpolation(loc_expr.region));
+                    output.union(new_output);
 
-                        return (
-                            Expr::RuntimeError(RuntimeError::InvalidInterpolation(loc_expr.region)),
-                            output,
-                        );
-                    }
+                    segments.push(StrSegment::Interpolation(loc_expr));
                 }
                 EscapedChar(escaped) => buf.push(escaped.unescape()),
             }
@@ -2424,6 +2447,10 @@ fn desugar_str_segments(var_store: &mut VarStore, segments: Vec<StrSegment>) ->
     let mut iter = segments.into_iter().rev();
     let mut loc_expr = match iter.next() {
         Some(Plaintext(string)) => Loc::at(Region::zero(), Expr::Str(string)),
+        Some(Interpolation(loc_expr)) if iter.len() == 0 => {
+            // There's nothing to concatenate "\(x)" with, but x still has to be a Str
+            return Expr::StrInterpolation(Box::new(loc_expr));
+        }
         Some(Interpolation(loc_expr)) => loc_expr,
         None => {
             // No segments? Empty string!
@@ -2998,6 +3025,7 @@ pub(crate) fn get_lookup_symbols(expr: &Expr) -> Vec<ExpectLookup> {
                 // because they couldn't possibly influence the outcome of this `expect`!
             }
             Expr::Crash { msg, .. } => stack.push(&msg.value),
+            Expr::StrInterpolation(loc_expr) => stack.push(&loc_expr.value),
             Expr::Num(_, _, _, _)
             | Expr::Float(_, _, _, _, _)
             | Expr::Int(_, _, _, _, _)
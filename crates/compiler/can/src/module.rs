@@ -1012,6 +1012,14 @@ fn fix_values_captured_in_closure_expr(
             );
         }
 
+        StrInterpolation(loc_expr) => {
+            fix_values_captured_in_closure_expr(
+                &mut loc_expr.value,
+                no_capture_symbols,
+                closure_captures,
+            );
+        }
+
         Closure(ClosureData {
             captured_symbols,
             name,
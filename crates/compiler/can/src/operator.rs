@@ -6,7 +6,10 @@ use roc_module::called_via::BinOp::Pizza;
 use roc_module::called_via::{BinOp, CalledVia};
 use roc_module::ident::ModuleName;
 use roc_parse::ast::Expr::{self, *};
-use roc_parse::ast::{AssignedField, ValueDef, WhenBranch};
+use roc_parse::ast::{
+    AssignedField, Collection, Pattern, RecordBuilderField, StrLiteral, StrSegment, ValueDef,
+    WhenBranch,
+};
 use roc_region::all::{Loc, Region};
 
 // BinOp precedence logic adapted from Gluon by Markus Westerlind
@@ -69,6 +72,7 @@ fn desugar_value_def<'a>(arena: &'a Bump, def: &'a ValueDef<'a>) -> ValueDef<'a>
     match def {
         Body(loc_pattern, loc_expr) => Body(loc_pattern, desugar_expr(arena, loc_expr)),
         ann @ Annotation(_, _) => *ann,
+        malformed @ Malformed(_) => *malformed,
         AnnotatedBody {
             ann_pattern,
             ann_type,
@@ -128,7 +132,7 @@ pub fn desugar_expr<'a>(arena: &'a Bump, loc_expr: &'a Loc<Expr<'a>>) -> &'a Loc
         Float(..)
         | Num(..)
         | NonBase10Int { .. }
-        | Str(_)
+        | Str(StrLiteral::PlainLine(_))
         | SingleQuote(_)
         | RecordAccessorFunction(_)
         | TupleAccessorFunction(_)
@@ -136,11 +140,30 @@ pub fn desugar_expr<'a>(arena: &'a Bump, loc_expr: &'a Loc<Expr<'a>>) -> &'a Loc
         | Underscore { .. }
         | MalformedIdent(_, _)
         | MalformedClosure
+        | Malformed(_)
         | PrecedenceConflict { .. }
+        | MultipleRecordBuilders { .. }
+        | UnappliedRecordBuilder { .. }
         | Tag(_)
         | OpaqueRef(_)
         | Crash => loc_expr,
 
+        Str(StrLiteral::Line(segments)) => arena.alloc(Loc {
+            region: loc_expr.region,
+            value: Str(StrLiteral::Line(desugar_str_segments(arena, segments))),
+        }),
+        Str(StrLiteral::Block(lines)) => {
+            let mut new_lines = Vec::with_capacity_in(lines.len(), arena);
+
+            for segments in lines.iter() {
+                new_lines.push(desugar_str_segments(arena, segments));
+            }
+
+            arena.alloc(Loc {
+                region: loc_expr.region,
+                value: Str(StrLiteral::Block(new_lines.into_bump_slice())),
+            })
+        }
         TupleAccess(sub_expr, paths) => {
             let region = loc_expr.region;
             let loc_sub_expr = Loc {
@@ -189,6 +212,10 @@ pub fn desugar_expr<'a>(arena: &'a Bump, loc_expr: &'a Loc<Expr<'a>>) -> &'a Loc
             region: loc_expr.region,
             value: Tuple(fields.map_items(arena, |field| desugar_expr(arena, field))),
         }),
+        RecordBuilder(_) => arena.alloc(Loc {
+            value: UnappliedRecordBuilder(loc_expr),
+            region: loc_expr.region,
+        }),
         RecordUpdate { fields, update } => {
             // NOTE the `update` field is always a `Var { .. }`, we only desugar it to get rid of
             // any spaces before/after
@@ -262,17 +289,58 @@ pub fn desugar_expr<'a>(arena: &'a Bump, loc_expr: &'a Loc<Expr<'a>>) -> &'a Loc
         }
         Apply(loc_fn, loc_args, called_via) => {
             let mut desugared_args = Vec::with_capacity_in(loc_args.len(), arena);
+            let mut builder_apply_exprs = None;
 
             for loc_arg in loc_args.iter() {
-                desugared_args.push(desugar_expr(arena, loc_arg));
+                let mut current = loc_arg.value;
+                let arg = loop {
+                    match current {
+                        RecordBuilder(fields) => {
+                            if builder_apply_exprs.is_some() {
+                                return arena.alloc(Loc {
+                                    value: MultipleRecordBuilders(loc_expr),
+                                    region: loc_expr.region,
+                                });
+                            }
+
+                            let builder_arg = record_builder_arg(arena, loc_arg.region, fields);
+                            builder_apply_exprs = Some(builder_arg.apply_exprs);
+
+                            break builder_arg.closure;
+                        }
+                        SpaceBefore(expr, _) | SpaceAfter(expr, _) | ParensAround(expr) => {
+                            current = *expr;
+                        }
+                        _ => break loc_arg,
+                    }
+                };
+
+                desugared_args.push(desugar_expr(arena, arg));
             }
 
             let desugared_args = desugared_args.into_bump_slice();
 
-            arena.alloc(Loc {
+            let mut apply: &Loc<Expr> = arena.alloc(Loc {
                 value: Apply(desugar_expr(arena, loc_fn), desugared_args, *called_via),
                 region: loc_expr.region,
-            })
+            });
+
+            // Apply each field's function to the record builder's closure in turn, e.g.
+            // `succeed { a: <- x, b: <- y }` becomes `y (x (succeed \#a -> \#b -> { a: #a, b: #b }))`
+            if let Some(apply_exprs) = builder_apply_exprs {
+                for expr in apply_exprs {
+                    let desugared_expr = desugar_expr(arena, expr);
+
+                    let args = std::slice::from_ref(arena.alloc(apply));
+
+                    apply = arena.alloc(Loc {
+                        value: Apply(desugared_expr, args, CalledVia::RecordBuilder),
+                        region: loc_expr.region,
+                    });
+                }
+            }
+
+            apply
         }
         When(loc_cond_expr, branches) => {
             let loc_desugared_cond = &*arena.alloc(desugar_expr(arena, loc_cond_expr));
@@ -380,6 +448,123 @@ pub fn desugar_expr<'a>(arena: &'a Bump, loc_expr: &'a Loc<Expr<'a>>) -> &'a Loc
     }
 }
 
+struct RecordBuilderArg<'a> {
+    closure: &'a Loc<Expr<'a>>,
+    apply_exprs: Vec<'a, &'a Loc<Expr<'a>>>,
+}
+
+/// Turns the fields of a record builder into a curried closure that builds the record, along with
+/// the expressions to apply to it, one per `<-` field.
+fn record_builder_arg<'a>(
+    arena: &'a Bump,
+    region: Region,
+    fields: Collection<'a, Loc<RecordBuilderField<'a>>>,
+) -> RecordBuilderArg<'a> {
+    let mut record_fields = Vec::with_capacity_in(fields.len(), arena);
+    let mut apply_exprs = Vec::with_capacity_in(fields.len(), arena);
+    let mut apply_field_names = Vec::with_capacity_in(fields.len(), arena);
+
+    // Build the record that the closure will return and gather apply expressions
+
+    for field in fields.iter() {
+        let mut current = field.value;
+
+        let new_field = loop {
+            match current {
+                RecordBuilderField::Value(label, spaces, expr) => {
+                    break AssignedField::RequiredValue(label, spaces, expr)
+                }
+                RecordBuilderField::ApplyValue(label, _, _, expr) => {
+                    apply_field_names.push(label);
+                    apply_exprs.push(expr);
+
+                    let var = arena.alloc(Loc {
+                        region: label.region,
+                        value: Expr::Var {
+                            module_name: "",
+                            ident: arena.alloc_str(&format!("#{}", label.value)),
+                        },
+                    });
+
+                    break AssignedField::RequiredValue(label, &[], var);
+                }
+                RecordBuilderField::LabelOnly(label) => break AssignedField::LabelOnly(label),
+                RecordBuilderField::SpaceBefore(sub_field, _)
+                | RecordBuilderField::SpaceAfter(sub_field, _) => {
+                    current = *sub_field;
+                }
+                RecordBuilderField::Malformed(malformed) => {
+                    break AssignedField::Malformed(malformed)
+                }
+            }
+        };
+
+        record_fields.push(Loc {
+            value: new_field,
+            region: field.region,
+        });
+    }
+
+    let record_fields = fields.replace_items(record_fields.into_bump_slice());
+
+    let mut body = arena.alloc(Loc {
+        value: Record(record_fields),
+        region,
+    });
+
+    // Construct the builder's closure: \#a -> \#b -> { a: #a, b: #b, c: 1 }
+
+    for label in apply_field_names.iter().rev() {
+        let ident = arena.alloc_str(&format!("#{}", label.value));
+        let pattern = arena.alloc(Loc {
+            region: label.region,
+            value: Pattern::Identifier(ident),
+        });
+
+        body = arena.alloc(Loc {
+            value: Closure(std::slice::from_ref(pattern), body),
+            region,
+        });
+    }
+
+    RecordBuilderArg {
+        closure: body,
+        apply_exprs,
+    }
+}
+
+/// Interpolations can contain any expression, including ones with operators in them
+fn desugar_str_segments<'a>(
+    arena: &'a Bump,
+    segments: &'a [StrSegment<'a>],
+) -> &'a [StrSegment<'a>] {
+    let mut new_segments = Vec::with_capacity_in(segments.len(), arena);
+
+    for segment in segments.iter() {
+        new_segments.push(match segment {
+            StrSegment::Interpolated(loc_expr) => {
+                let loc_desugared = desugar_expr(
+                    arena,
+                    arena.alloc(Loc {
+                        region: loc_expr.region,
+                        value: *loc_expr.value,
+                    }),
+                );
+
+                StrSegment::Interpolated(Loc {
+                    region: loc_desugared.region,
+                    value: &loc_desugared.value,
+                })
+            }
+            StrSegment::Plaintext(_) | StrSegment::Unicode(_) | StrSegment::EscapedChar(_) => {
+                *segment
+            }
+        });
+    }
+
+    new_segments.into_bump_slice()
+}
+
 fn desugar_field<'a>(
     arena: &'a Bump,
     field: &'a AssignedField<'a, Expr<'a>>,
@@ -176,6 +176,9 @@ pub fn walk_expr<V: Visitor>(visitor: &mut V, expr: &Expr, var: Variable) {
         Expr::Int(..) => { /* terminal */ }
         Expr::Float(..) => { /* terminal */ }
         Expr::Str(..) => { /* terminal */ }
+        Expr::StrInterpolation(loc_expr) => {
+            visitor.visit_expr(&loc_expr.value, loc_expr.region, Variable::STR);
+        }
         Expr::SingleQuote(..) => { /* terminal */ }
         Expr::List {
             elem_var,
@@ -24,6 +24,7 @@ use roc_can::traverse::symbols_introduced_from_pattern;
 use roc_collections::all::{HumanIndex, MutMap, SendMap};
 use roc_collections::soa::{Index, Slice};
 use roc_collections::VecMap;
+use roc_module::called_via::CalledVia;
 use roc_module::ident::Lowercase;
 use roc_module::symbol::{ModuleId, Symbol};
 use roc_region::all::{Loc, Region};
@@ -375,6 +376,26 @@ pub fn constrain_expr(
             let expected_index = expected;
             constraints.equal_types(str_index, expected_index, Category::Str, region)
         }
+        StrInterpolation(loc_expr) => {
+            let str_index = constraints.push_type(types, Types::STR);
+            let expected_str = constraints.push_expected_type(ForReason(
+                Reason::StrInterpolation,
+                str_index,
+                loc_expr.region,
+            ));
+
+            let expr_is_str = constrain_expr(
+                types,
+                constraints,
+                env,
+                loc_expr.region,
+                &loc_expr.value,
+                expected_str,
+            );
+            let str_con = constraints.equal_types(str_index, expected, Category::Str, region);
+
+            constraints.and_constraint([expr_is_str, str_con])
+        }
         SingleQuote(num_var, precision_var, _, bound) => single_quote_literal(
             types,
             constraints,
@@ -484,9 +505,14 @@ pub fn constrain_expr(
                 let arg_type = Variable(*arg_var);
                 let arg_type_index = constraints.push_variable(*arg_var);
 
-                let reason = Reason::FnArg {
-                    name: opt_symbol,
-                    arg_index: HumanIndex::zero_based(index),
+                let reason = match called_via {
+                    // Interpolations are desugared into Str.concat calls, but errors about them
+                    // should talk about the interpolation, not the call.
+                    CalledVia::StringInterpolation => Reason::StrInterpolation,
+                    _ => Reason::FnArg {
+                        name: opt_symbol,
+                        arg_index: HumanIndex::zero_based(index),
+                    },
                 };
                 let expected_arg =
                     constraints.push_expected_type(ForReason(reason, arg_type_index, region));
@@ -1074,20 +1100,32 @@ pub fn constrain_expr(
             pattern_cons.push(cond_constraint);
 
             // Now check the condition against the type expected by the branches.
-            let sketched_rows = sketch_when_branches(branches_region, branches);
             let expected_by_branches = constraints.push_expected_type(Expected::ForReason(
                 Reason::WhenBranches,
                 branches_cond_index,
                 branches_region,
             ));
-            let cond_matches_branches_constraint = constraints.exhaustive(
-                real_cond_var,
-                loc_cond.region,
-                Ok((loc_cond.value.category(), expected_by_branches)),
-                sketched_rows,
-                ExhaustiveContext::BadCase,
-                *exhaustive,
-            );
+            let has_malformed_branch = branches.iter().any(WhenBranch::is_malformed);
+            let cond_matches_branches_constraint = if has_malformed_branch {
+                // We can't know which patterns a branch with a syntax error was meant to
+                // cover, so don't report any as missing or redundant.
+                constraints.equal_types_var(
+                    real_cond_var,
+                    expected_by_branches,
+                    loc_cond.value.category(),
+                    loc_cond.region,
+                )
+            } else {
+                let sketched_rows = sketch_when_branches(branches_region, branches);
+                constraints.exhaustive(
+                    real_cond_var,
+                    loc_cond.region,
+                    Ok((loc_cond.value.category(), expected_by_branches)),
+                    sketched_rows,
+                    ExhaustiveContext::BadCase,
+                    *exhaustive,
+                )
+            };
             pattern_cons.push(cond_matches_branches_constraint);
 
             // Solve all the pattern constraints together, introducing variables in the pattern as
@@ -4016,6 +4054,7 @@ fn is_generalizable_expr(mut expr: &Expr) -> bool {
             }
             OpaqueRef { argument, .. } => expr = &argument.1.value,
             Str(_)
+            | StrInterpolation(_)
             | List { .. }
             | SingleQuote(_, _, _, _)
             | When { .. }
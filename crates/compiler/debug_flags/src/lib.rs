@@ -21,6 +21,7 @@
 //!              ROC_PRINT_UNIFICATIONS=0 \
 //!                ROC_PRINT_MISMATCHES=0 \
 //!   ROC_PRINT_IR_AFTER_SPECIALIZATION=0 \
+//!         ROC_PRINT_IR_AFTER_INLINING=0 \
 //!      ROC_PRINT_IR_AFTER_RESET_REUSE=0 \
 //!         ROC_PRINT_IR_AFTER_REFCOUNT=0 \
 //!         ROC_PRETTY_PRINT_IR_SYMBOLS=0 \
@@ -125,6 +126,10 @@ flags! {
     /// Writes a pretty-printed mono IR to stderr after function specialization.
     ROC_PRINT_IR_AFTER_SPECIALIZATION
 
+    /// Writes a pretty-printed mono IR to stderr after small procs are inlined and constants
+    /// are folded (only in development and optimized builds).
+    ROC_PRINT_IR_AFTER_INLINING
+
     /// Writes a pretty-printed mono IR to stderr after insertion of reset/reuse
     /// instructions.
     ROC_PRINT_IR_AFTER_RESET_REUSE
@@ -0,0 +1,599 @@
+//! Derivers for the `Sort` ability.
+
+use std::iter::once;
+
+use roc_can::{
+    expr::{AnnotatedMark, ClosureData, Expr, Recursive, WhenBranch, WhenBranchPattern},
+    pattern::Pattern,
+};
+use roc_derive_key::compare::FlatCompareKey;
+use roc_module::{
+    called_via::CalledVia,
+    ident::{Lowercase, TagName},
+    symbol::Symbol,
+};
+use roc_region::all::{Loc, Region};
+use roc_types::{
+    subs::{
+        Content, ExhaustiveMark, FlatType, GetSubsSlice, LambdaSet, OptVariable, RecordFields,
+        RedundantMark, SubsSlice, TagExt, TupleElems, UnionLambdas, UnionTags, Variable,
+        VariableSubsSlice,
+    },
+    types::RecordField,
+};
+
+use crate::{
+    synth_var,
+    util::{Env, ExtensionKind},
+    DerivedBody,
+};
+
+pub(crate) fn derive_compare(
+    env: &mut Env<'_>,
+    key: FlatCompareKey,
+    def_symbol: Symbol,
+) -> DerivedBody {
+    let (body_type, body) = match key {
+        FlatCompareKey::Record(fields) => compare_record(env, def_symbol, fields),
+        FlatCompareKey::Tuple(arity) => compare_tuple(env, def_symbol, arity),
+        FlatCompareKey::TagUnion(tags) => compare_tag_union(env, def_symbol, tags),
+    };
+
+    let specialization_lambda_sets =
+        env.get_specialization_lambda_sets(body_type, Symbol::SORT_COMPARE);
+
+    DerivedBody {
+        body,
+        body_type,
+        specialization_lambda_sets,
+    }
+}
+
+fn compare_record(env: &mut Env<'_>, fn_name: Symbol, fields: Vec<Lowercase>) -> (Variable, Expr) {
+    // Suppose rcd = { f1, ..., fn }.
+    // Build a generalized type t_rcd = { f1: t1, ..., fn: tn }, with fresh t1, ..., tn,
+    // so that we can re-use the derived impl for many records of the same fields.
+    let (record_var, record_fields) = {
+        let flex_fields = fields
+            .into_iter()
+            .map(|name| {
+                (
+                    name,
+                    RecordField::Required(env.subs.fresh_unnamed_flex_var()),
+                )
+            })
+            .collect::<Vec<(Lowercase, _)>>();
+        let fields = RecordFields::insert_into_subs(env.subs, flex_fields);
+        let record_var = synth_var(
+            env.subs,
+            Content::Structure(FlatType::Record(fields, Variable::EMPTY_RECORD)),
+        );
+
+        (record_var, fields)
+    };
+
+    // Now, a comparison for this record is
+    //
+    // compare_rcd : { f1: t1, ..., fn: tn }, { f1: t1, ..., fn: tn } -> [LT, EQ, GT]
+    //                 | t1 has Sort, ..., tn has Sort
+    // compare_rcd = \rcd1, rcd2 ->
+    //   when Sort.compare rcd1.f1 rcd2.f1 is
+    //     EQ ->
+    //       ...
+    //         Sort.compare rcd1.fn rcd2.fn
+    //     order -> order
+    //
+    // That is, the fields are compared in order of their names, and the first unequal field
+    // decides the order. The empty record is always `EQ`.
+    let rcd1_sym = env.new_symbol("rcd1");
+    let rcd2_sym = env.new_symbol("rcd2");
+
+    let access_field = |env: &mut Env<'_>, rcd_sym, field_var, field_name| Expr::RecordAccess {
+        record_var,
+        field_var,
+        ext_var: env.subs.fresh_unnamed_flex_var(),
+        loc_expr: Box::new(Loc::at_zero(Expr::Var(
+            rcd_sym,
+            env.subs.fresh_unnamed_flex_var(),
+        ))),
+        field: field_name,
+    };
+
+    let comparisons = record_fields
+        .iter_all()
+        .map(|(field_name, field_var, _)| {
+            let field_name = env.subs[field_name].clone();
+            let field_var = env.subs[field_var];
+
+            (
+                field_var,
+                access_field(env, rcd1_sym, field_var, field_name.clone()),
+                access_field(env, rcd2_sym, field_var, field_name),
+            )
+        })
+        .collect();
+
+    let ordering_var = ordering_var(env);
+    let body = compare_lexicographic(env, ordering_var, comparisons);
+
+    // Finally, build the closure
+    // \rcd1, rcd2 -> body
+    build_outer_derived_closure(
+        env,
+        fn_name,
+        record_var,
+        (Pattern::Identifier(rcd1_sym), Pattern::Identifier(rcd2_sym)),
+        (ordering_var, body),
+    )
+}
+
+fn compare_tuple(env: &mut Env<'_>, fn_name: Symbol, arity: u32) -> (Variable, Expr) {
+    // Suppose tup = (e0, ..., en).
+    // Build a generalized type t_tup = (t0, ..., tn)*, with fresh t0, ..., tn,
+    // so that we can re-use the derived impl for many tuples of the same arity.
+    let (tuple_var, tuple_elems) = {
+        let flex_elems = (0..arity as usize)
+            .map(|idx| (idx, env.subs.fresh_unnamed_flex_var()))
+            .collect::<Vec<_>>();
+        let elems = TupleElems::insert_into_subs(env.subs, flex_elems);
+        let ext = env.subs.fresh_unnamed_flex_var();
+        let tuple_var = synth_var(env.subs, Content::Structure(FlatType::Tuple(elems, ext)));
+
+        (tuple_var, elems)
+    };
+
+    // Now, a comparison for this tuple is
+    //
+    // compare_tup : (t0, ..., tn), (t0, ..., tn) -> [LT, EQ, GT] | t0 has Sort, ..., tn has Sort
+    // compare_tup = \tup1, tup2 ->
+    //   when Sort.compare tup1.0 tup2.0 is
+    //     EQ ->
+    //       ...
+    //         Sort.compare tup1.n tup2.n
+    //     order -> order
+    let tup1_sym = env.new_symbol("tup1");
+    let tup2_sym = env.new_symbol("tup2");
+
+    let access_elem = |env: &mut Env<'_>, tup_sym, elem_var, index| Expr::TupleAccess {
+        tuple_var,
+        elem_var,
+        ext_var: env.subs.fresh_unnamed_flex_var(),
+        loc_expr: Box::new(Loc::at_zero(Expr::Var(
+            tup_sym,
+            env.subs.fresh_unnamed_flex_var(),
+        ))),
+        index,
+    };
+
+    let comparisons = tuple_elems
+        .iter_all()
+        .map(|(elem_index, elem_var)| {
+            let index = env.subs[elem_index];
+            let elem_var = env.subs[elem_var];
+
+            (
+                elem_var,
+                access_elem(env, tup1_sym, elem_var, index),
+                access_elem(env, tup2_sym, elem_var, index),
+            )
+        })
+        .collect();
+
+    let ordering_var = ordering_var(env);
+    let body = compare_lexicographic(env, ordering_var, comparisons);
+
+    // Finally, build the closure
+    // \tup1, tup2 -> body
+    build_outer_derived_closure(
+        env,
+        fn_name,
+        tuple_var,
+        (Pattern::Identifier(tup1_sym), Pattern::Identifier(tup2_sym)),
+        (ordering_var, body),
+    )
+}
+
+fn compare_tag_union(
+    env: &mut Env<'_>,
+    fn_name: Symbol,
+    tags: Vec<(TagName, u16)>,
+) -> (Variable, Expr) {
+    // Suppose tags = [ A p11 .. p1n, ..., Q pq1 .. pqm ]
+    // Build a generalized type t_tags = [ A t11 .. t1n, ..., Q tq1 .. tqm ],
+    // with fresh t1, ..., tqm, so that we can re-use the derived impl for many
+    // unions of the same tags and payloads.
+    let (union_var, union_tags) = {
+        let flex_tag_labels = tags
+            .into_iter()
+            .map(|(label, arity)| {
+                let variables_slice = VariableSubsSlice::reserve_into_subs(env.subs, arity.into());
+                for var_index in variables_slice {
+                    env.subs[var_index] = env.subs.fresh_unnamed_flex_var();
+                }
+                (label, variables_slice)
+            })
+            .collect::<Vec<_>>();
+        let union_tags = UnionTags::insert_slices_into_subs(env.subs, flex_tag_labels);
+        let tag_union_var = synth_var(
+            env.subs,
+            Content::Structure(FlatType::TagUnion(
+                union_tags,
+                TagExt::Any(Variable::EMPTY_TAG_UNION),
+            )),
+        );
+
+        (tag_union_var, union_tags)
+    };
+
+    // Now, a comparison for this tag union is
+    //
+    // compare_union : [ A t11 .. t1n, ..., Q tq1 .. tqm ], [ A t11 .. t1n, ..., Q tq1 .. tqm ]
+    //                   -> [LT, EQ, GT] | t11 has Sort, ..., tqm has Sort
+    // compare_union = \union1, union2 ->
+    //   when union1 is
+    //     A x11 .. x1n ->
+    //       when union2 is
+    //         A y11 .. y1n -> <compare x11 .. x1n with y11 .. y1n, as for records>
+    //         B _ .. _ | ... | Q _ .. _ -> LT
+    //     ...
+    //     I xi1 .. xik ->
+    //       when union2 is
+    //         A _ .. _ | ... | H _ .. _ -> GT
+    //         I yi1 .. yik -> <compare xi1 .. xik with yi1 .. yik>
+    //         J _ .. _ | ... | Q _ .. _ -> LT
+    //     ...
+    //
+    // That is, tags are ordered by their names, and payloads of the same tag are compared in
+    // order.
+    let union1_sym = env.new_symbol("union1");
+    let union2_sym = env.new_symbol("union2");
+
+    let ordering_var = ordering_var(env);
+
+    let all_tags: Vec<_> = union_tags
+        .iter_all()
+        .map(|(tag, payloads)| {
+            let tag_name = env.subs[tag].clone();
+            let payload_vars = env.subs.get_subs_slice(env.subs[payloads]).to_vec();
+            (tag_name, payload_vars)
+        })
+        .collect();
+
+    let tag_pattern =
+        |tag_name: &TagName, arguments: Vec<(Variable, Loc<Pattern>)>| WhenBranchPattern {
+            pattern: Loc::at_zero(Pattern::AppliedTag {
+                whole_var: union_var,
+                tag_name: tag_name.clone(),
+                ext_var: Variable::EMPTY_TAG_UNION,
+                arguments,
+            }),
+            degenerate: false,
+        };
+
+    let bind_payloads = |env: &mut Env<'_>, payload_vars: &[Variable]| {
+        payload_vars
+            .iter()
+            .map(|var| (*var, env.unique_symbol()))
+            .collect::<Vec<_>>()
+    };
+
+    let branches = all_tags
+        .iter()
+        .enumerate()
+        .map(|(tag_index, (tag_name, payload_vars))| {
+            // `A x11 .. x1n` and `A y11 .. y1n`
+            let left_payloads = bind_payloads(env, payload_vars);
+            let right_payloads = bind_payloads(env, payload_vars);
+
+            let to_arguments = |payloads: &[(Variable, Symbol)]| {
+                payloads
+                    .iter()
+                    .map(|(var, sym)| (*var, Loc::at_zero(Pattern::Identifier(*sym))))
+                    .collect()
+            };
+            let left_pattern = tag_pattern(tag_name, to_arguments(&left_payloads));
+            let right_pattern = tag_pattern(tag_name, to_arguments(&right_payloads));
+
+            let comparisons = (left_payloads.into_iter())
+                .zip(right_payloads)
+                .map(|((var, left_sym), (_, right_sym))| {
+                    (var, Expr::Var(left_sym, var), Expr::Var(right_sym, var))
+                })
+                .collect();
+            let same_tag_body = compare_lexicographic(env, ordering_var, comparisons);
+
+            let wildcard_patterns = |tags: &[(TagName, Vec<Variable>)]| {
+                tags.iter()
+                    .map(|(tag_name, payload_vars)| {
+                        let arguments = payload_vars
+                            .iter()
+                            .map(|var| (*var, Loc::at_zero(Pattern::Underscore)))
+                            .collect();
+                        tag_pattern(tag_name, arguments)
+                    })
+                    .collect::<Vec<_>>()
+            };
+
+            let mut inner_branches: Vec<WhenBranch> = Vec::with_capacity(3);
+
+            // A _ .. _ | ... | H _ .. _ -> GT
+            let lesser_tags = wildcard_patterns(&all_tags[..tag_index]);
+            if !lesser_tags.is_empty() {
+                inner_branches.push(WhenBranch {
+                    patterns: lesser_tags,
+                    value: Loc::at_zero(ordering_tag(env, ordering_var, "GT")),
+                    guard: None,
+                    redundant: RedundantMark::known_non_redundant(),
+                });
+            }
+
+            // I yi1 .. yik -> <compare xi1 .. xik with yi1 .. yik>
+            inner_branches.push(WhenBranch {
+                patterns: vec![right_pattern],
+                value: Loc::at_zero(same_tag_body),
+                guard: None,
+                redundant: RedundantMark::known_non_redundant(),
+            });
+
+            // J _ .. _ | ... | Q _ .. _ -> LT
+            let greater_tags = wildcard_patterns(&all_tags[tag_index + 1..]);
+            if !greater_tags.is_empty() {
+                inner_branches.push(WhenBranch {
+                    patterns: greater_tags,
+                    value: Loc::at_zero(ordering_tag(env, ordering_var, "LT")),
+                    guard: None,
+                    redundant: RedundantMark::known_non_redundant(),
+                });
+            }
+
+            // when union2 is
+            //   ...
+            let inner_when = Expr::When {
+                loc_cond: Box::new(Loc::at_zero(Expr::Var(union2_sym, union_var))),
+                cond_var: union_var,
+                expr_var: ordering_var,
+                region: Region::zero(),
+                branches: inner_branches,
+                branches_cond_var: union_var,
+                exhaustive: ExhaustiveMark::known_exhaustive(),
+            };
+
+            WhenBranch {
+                patterns: vec![left_pattern],
+                value: Loc::at_zero(inner_when),
+                guard: None,
+                redundant: RedundantMark::known_non_redundant(),
+            }
+        })
+        .collect();
+
+    // when union1 is
+    //   ...
+    let when_expr = Expr::When {
+        loc_cond: Box::new(Loc::at_zero(Expr::Var(union1_sym, union_var))),
+        cond_var: union_var,
+        expr_var: ordering_var,
+        region: Region::zero(),
+        branches,
+        branches_cond_var: union_var,
+        exhaustive: ExhaustiveMark::known_exhaustive(),
+    };
+
+    // Finally, build the closure
+    // \union1, union2 -> body
+    build_outer_derived_closure(
+        env,
+        fn_name,
+        union_var,
+        (
+            Pattern::Identifier(union1_sym),
+            Pattern::Identifier(union2_sym),
+        ),
+        (ordering_var, when_expr),
+    )
+}
+
+/// Builds the comparison of a sequence of pairs of values, where the first unequal pair decides
+/// the order:
+///
+/// ```text
+/// when Sort.compare l1 r1 is
+///   EQ ->
+///     ...
+///       Sort.compare ln rn
+///   order -> order
+/// ```
+///
+/// An empty sequence is always `EQ`.
+fn compare_lexicographic(
+    env: &mut Env<'_>,
+    ordering_var: Variable,
+    comparisons: Vec<(Variable, Expr, Expr)>,
+) -> Expr {
+    let mut comparisons = comparisons.into_iter().rev();
+
+    let last = match comparisons.next() {
+        Some((var, left, right)) => call_sort_compare(env, ordering_var, var, left, right),
+        None => return ordering_tag(env, ordering_var, "EQ"),
+    };
+
+    comparisons.fold(last, |rest, (var, left, right)| {
+        let order_sym = env.new_symbol("order");
+
+        let branches = vec![
+            // EQ -> rest
+            WhenBranch {
+                patterns: vec![WhenBranchPattern {
+                    pattern: Loc::at_zero(Pattern::AppliedTag {
+                        whole_var: ordering_var,
+                        tag_name: "EQ".into(),
+                        ext_var: Variable::EMPTY_TAG_UNION,
+                        arguments: vec![],
+                    }),
+                    degenerate: false,
+                }],
+                value: Loc::at_zero(rest),
+                guard: None,
+                redundant: RedundantMark::known_non_redundant(),
+            },
+            // order -> order
+            WhenBranch {
+                patterns: vec![WhenBranchPattern {
+                    pattern: Loc::at_zero(Pattern::Identifier(order_sym)),
+                    degenerate: false,
+                }],
+                value: Loc::at_zero(Expr::Var(order_sym, ordering_var)),
+                guard: None,
+                redundant: RedundantMark::known_non_redundant(),
+            },
+        ];
+
+        let compare_call = call_sort_compare(env, ordering_var, var, left, right);
+
+        Expr::When {
+            loc_cond: Box::new(Loc::at_zero(compare_call)),
+            cond_var: ordering_var,
+            expr_var: ordering_var,
+            region: Region::zero(),
+            branches,
+            branches_cond_var: ordering_var,
+            exhaustive: ExhaustiveMark::known_exhaustive(),
+        }
+    })
+}
+
+/// The type `[EQ, GT, LT]`, the result of `Sort.compare`.
+fn ordering_var(env: &mut Env<'_>) -> Variable {
+    let tags = UnionTags::insert_into_subs(
+        env.subs,
+        [
+            (TagName("EQ".into()), []),
+            (TagName("GT".into()), []),
+            (TagName("LT".into()), []),
+        ],
+    );
+
+    synth_var(
+        env.subs,
+        Content::Structure(FlatType::TagUnion(
+            tags,
+            TagExt::Any(Variable::EMPTY_TAG_UNION),
+        )),
+    )
+}
+
+fn ordering_tag(env: &mut Env<'_>, ordering_var: Variable, tag: &str) -> Expr {
+    Expr::Tag {
+        tag_union_var: ordering_var,
+        ext_var: env.new_ext_var(ExtensionKind::TagUnion),
+        name: tag.into(),
+        arguments: vec![],
+    }
+}
+
+fn call_sort_compare(
+    env: &mut Env<'_>,
+    ordering_var: Variable,
+    val_var: Variable,
+    left: Expr,
+    right: Expr,
+) -> Expr {
+    // build `Sort.compare ...` function type.
+    //
+    // val, val -[uls]-> [LT, EQ, GT] | val has Sort
+    let exposed_compare_fn_var = env.import_builtin_symbol_var(Symbol::SORT_COMPARE);
+
+    // (typeof val), (typeof val) -[clos]-> ordering
+    let this_arguments_slice = VariableSubsSlice::insert_into_subs(env.subs, [val_var, val_var]);
+    let this_compare_clos_var = env.subs.fresh_unnamed_flex_var();
+    let this_compare_fn_var = synth_var(
+        env.subs,
+        Content::Structure(FlatType::Func(
+            this_arguments_slice,
+            this_compare_clos_var,
+            ordering_var,
+        )),
+    );
+
+    //   val,          val          -[uls]->  [LT, EQ, GT] | val has Sort
+    // ~ (typeof val), (typeof val) -[clos]-> ordering
+    env.unify(exposed_compare_fn_var, this_compare_fn_var);
+
+    // Sort.compare : (typeof val), (typeof val) -[clos]-> [LT, EQ, GT] | (typeof val) has Sort
+    let compare_fn_head = Expr::AbilityMember(Symbol::SORT_COMPARE, None, this_compare_fn_var);
+    let compare_fn_data = Box::new((
+        this_compare_fn_var,
+        Loc::at_zero(compare_fn_head),
+        this_compare_clos_var,
+        ordering_var,
+    ));
+
+    let compare_arguments = vec![
+        (val_var, Loc::at_zero(left)),
+        (val_var, Loc::at_zero(right)),
+    ];
+
+    Expr::Call(compare_fn_data, compare_arguments, CalledVia::Space)
+}
+
+fn build_outer_derived_closure(
+    env: &mut Env<'_>,
+    fn_name: Symbol,
+    val_var: Variable,
+    patterns: (Pattern, Pattern),
+    body: (Variable, Expr),
+) -> (Variable, Expr) {
+    let (left_pattern, right_pattern) = patterns;
+    let (body_var, body_expr) = body;
+
+    let (fn_var, fn_clos_var) = {
+        // Create fn_var for ambient capture; we fix it up below.
+        let fn_var = synth_var(env.subs, Content::Error);
+
+        // -[fn_name]->
+        let fn_captures = vec![];
+        let fn_name_labels = UnionLambdas::insert_into_subs(env.subs, once((fn_name, fn_captures)));
+        let fn_clos_var = synth_var(
+            env.subs,
+            Content::LambdaSet(LambdaSet {
+                solved: fn_name_labels,
+                recursion_var: OptVariable::NONE,
+                unspecialized: SubsSlice::default(),
+                ambient_function: fn_var,
+            }),
+        );
+
+        // val_var, val_var -[fn_name]-> ([LT, EQ, GT] = body_var)
+        let args_slice = SubsSlice::insert_into_subs(env.subs, [val_var, val_var]);
+        env.subs.set_content(
+            fn_var,
+            Content::Structure(FlatType::Func(args_slice, fn_clos_var, body_var)),
+        );
+
+        (fn_var, fn_clos_var)
+    };
+
+    let clos_expr = Expr::Closure(ClosureData {
+        function_type: fn_var,
+        closure_type: fn_clos_var,
+        return_type: body_var,
+        name: fn_name,
+        captured_symbols: vec![],
+        recursive: Recursive::NotRecursive,
+        arguments: vec![
+            (
+                val_var,
+                AnnotatedMark::known_exhaustive(),
+                Loc::at_zero(left_pattern),
+            ),
+            (
+                val_var,
+                AnnotatedMark::known_exhaustive(),
+                Loc::at_zero(right_pattern),
+            ),
+        ],
+        loc_body: Box::new(Loc::at_zero(body_expr)),
+    });
+
+    (fn_var, clos_expr)
+}
@@ -65,13 +65,20 @@ pub(crate) fn derive_decoder(
 //                             {result: Result.map result \val -> {state & f1: Ok val}, rest})
 //             _ -> Skip
 //
+//     # A missing field is decoded from an empty input, which lets its type supply a default.
+//     # `fmt` is captured from the enclosing `Decode.custom` below.
 //     finalizer = \{f0, f1} ->
-//         when f0 is
+//         when (fieldOrDecodeEmpty f0 fmt) is
 //             Ok first ->
-//                 when f1 is
+//                 when (fieldOrDecodeEmpty f1 fmt) is
 //                     Ok second -> Ok {first, second}
-//                     Err NoField -> Err TooShort
-//             Err NoField -> Err TooShort
+//                     Err _ -> Err TooShort
+//             Err _ -> Err TooShort
+//
+//     fieldOrDecodeEmpty = \field, fmt ->
+//         when field is
+//             Ok val -> Ok val
+//             Err NoField -> (Decode.decodeWith [] Decode.decoder fmt).result
 //
 //     Decode.custom \bytes, fmt -> Decode.decodeWith bytes (Decode.record initialState stepField finalizer) fmt
 fn decoder_record(env: &mut Env, _def_symbol: Symbol, fields: Vec<Lowercase>) -> (Expr, Variable) {
@@ -84,10 +91,15 @@ fn decoder_record(env: &mut Env, _def_symbol: Symbol, fields: Vec<Lowercase>) ->
     let (initial_state_var, initial_state) =
         decoder_record_initial_state(env, &fields, &mut field_vars, &mut result_field_vars);
 
+    // The `fmt` argument of the `Decode.custom` callback that wraps the whole decoder
+    let fmt_sym = env.new_symbol("fmt");
+    let fmt_var = env.subs.fresh_unnamed_flex_var();
+
     // finalizer = ...
     let (finalizer, finalizer_var, decode_err_var) = decoder_record_finalizer(
         env,
         initial_state_var,
+        (fmt_sym, fmt_var),
         &fields,
         &field_vars,
         &result_field_vars,
@@ -141,8 +153,6 @@ fn decoder_record(env: &mut Env, _def_symbol: Symbol, fields: Vec<Lowercase>) ->
 
     let (call_decode_custom, decode_custom_ret_var) = {
         let bytes_sym = env.new_symbol("bytes");
-        let fmt_sym = env.new_symbol("fmt");
-        let fmt_var = env.subs.fresh_unnamed_flex_var();
 
         let (decode_custom, decode_custom_var) = wrap_in_decode_custom_decode_with(
             env,
@@ -740,15 +750,32 @@ fn decoder_record_step_field(
 
 // Example:
 // finalizer = \rec ->
-//     when rec.first is
+//     when
+//         when rec.first is
+//             Ok first -> Ok first
+//             Err NoField ->
+//                 when Decode.decodeWith [] Decode.decoder fmt is
+//                     decRec -> decRec.result
+//     is
 //         Ok first ->
-//             when rec.second is
+//             when
+//                 when rec.second is
+//                     Ok second -> Ok second
+//                     Err NoField ->
+//                         when Decode.decodeWith [] Decode.decoder fmt is
+//                             decRec -> decRec.result
+//             is
 //                 Ok second -> Ok {first, second}
-//                 Err NoField -> Err TooShort
-//         Err NoField -> Err TooShort
+//                 _ -> Err TooShort
+//         _ -> Err TooShort
+//
+// A field missing from the input is decoded from no bytes at all, so that types whose decoder
+// accepts empty input can provide a default value for it. The finalizer captures the `fmt` to
+// decode with, so that `Decode.record` can keep calling it with just the state.
 fn decoder_record_finalizer(
     env: &mut Env,
     state_record_var: Variable,
+    (fmt_arg_symbol, fmt_arg_var): (Symbol, Variable),
     fields: &[Lowercase],
     field_vars: &[Variable],
     result_field_vars: &[Variable],
@@ -818,9 +845,13 @@ fn decoder_record_finalizer(
 
     // Unwrap each result in the decoded state
     //
-    // when rec.first is
+    // when
+    //     when rec.first is
+    //         Ok first -> Ok first
+    //         Err NoField -> ...decode from no bytes...
+    // is
     //     Ok first -> ...happy path...
-    //     Err NoField -> Err TooShort
+    //     _ -> Err TooShort
     for (((symbol, field_name), &field_var), &result_field_var) in pattern_symbols
         .iter()
         .rev()
@@ -828,20 +859,21 @@ fn decoder_record_finalizer(
         .zip(field_vars.iter().rev())
         .zip(result_field_vars.iter().rev())
     {
-        // when rec.first is
-        let cond_expr = Expr::RecordAccess {
-            record_var: state_record_var,
-            ext_var: env.new_ext_var(ExtensionKind::Record),
-            field_var: result_field_var,
-            loc_expr: Box::new(Loc::at_zero(Expr::Var(state_arg_symbol, state_record_var))),
-            field: field_name.clone(),
-        };
+        let (cond_expr, cond_var) = decoder_record_finalizer_field(
+            env,
+            (state_arg_symbol, state_record_var),
+            (fmt_arg_symbol, fmt_arg_var),
+            field_name,
+            field_var,
+            result_field_var,
+            decode_err_var,
+        );
 
         // Example: `Ok x -> expr`
         let ok_branch = WhenBranch {
             patterns: vec![WhenBranchPattern {
                 pattern: Loc::at_zero(Pattern::AppliedTag {
-                    whole_var: result_field_var,
+                    whole_var: cond_var,
                     ext_var: Variable::EMPTY_TAG_UNION,
                     tag_name: "Ok".into(),
                     arguments: vec![(field_var, Loc::at_zero(Pattern::Identifier(*symbol)))],
@@ -879,11 +911,11 @@ fn decoder_record_finalizer(
 
         body = Expr::When {
             loc_cond: Box::new(Loc::at_zero(cond_expr)),
-            cond_var: result_field_var,
+            cond_var,
             expr_var: return_type_var,
             region: Region::zero(),
             branches: vec![ok_branch, err_branch],
-            branches_cond_var: result_field_var,
+            branches_cond_var: cond_var,
             exhaustive: ExhaustiveMark::known_exhaustive(),
         };
     }
@@ -891,7 +923,7 @@ fn decoder_record_finalizer(
     let function_var = synth_var(env.subs, Content::Error); // We'll fix this up in subs later.
     let function_symbol = env.new_symbol("finalizer");
     let lambda_set = LambdaSet {
-        solved: UnionLambdas::tag_without_arguments(env.subs, function_symbol),
+        solved: UnionLambdas::insert_into_subs(env.subs, [(function_symbol, [fmt_arg_var])]),
         recursion_var: OptVariable::NONE,
         unspecialized: Default::default(),
         ambient_function: function_var,
@@ -912,7 +944,7 @@ fn decoder_record_finalizer(
         closure_type,
         return_type: return_type_var,
         name: function_symbol,
-        captured_symbols: Vec::new(),
+        captured_symbols: vec![(fmt_arg_symbol, fmt_arg_var)],
         recursive: Recursive::NotRecursive,
         arguments: vec![(
             state_record_var,
@@ -925,6 +957,186 @@ fn decoder_record_finalizer(
     (finalizer, function_var, decode_err_var)
 }
 
+// Example:
+// when rec.first is
+//     Ok first -> Ok first
+//     Err NoField ->
+//         when Decode.decodeWith [] Decode.decoder fmt is
+//             decRec -> decRec.result
+fn decoder_record_finalizer_field(
+    env: &mut Env,
+    (state_arg_symbol, state_record_var): (Symbol, Variable),
+    (fmt_arg_symbol, fmt_arg_var): (Symbol, Variable),
+    field_name: &Lowercase,
+    field_var: Variable,
+    result_field_var: Variable,
+    decode_err_var: Variable,
+) -> (Expr, Variable) {
+    // The decoded field value, or the error from decoding it out of no bytes.
+    // [Ok field_var, Err DecodeError]
+    let field_result_var = {
+        let flat_type = FlatType::TagUnion(
+            UnionTags::for_result(env.subs, field_var, decode_err_var),
+            TagExt::Any(Variable::EMPTY_TAG_UNION),
+        );
+
+        synth_var(env.subs, Content::Structure(flat_type))
+    };
+
+    // decRec : { rest: List U8, result: (typeof decRec.result) }
+    let dec_rec_var = {
+        let fields = RecordFields::insert_into_subs(
+            env.subs,
+            [
+                ("rest".into(), RecordField::Required(Variable::LIST_U8)),
+                ("result".into(), RecordField::Required(field_result_var)),
+            ],
+        );
+        let record = FlatType::Record(fields, Variable::EMPTY_RECORD);
+
+        synth_var(env.subs, Content::Structure(record))
+    };
+
+    // Ok first -> Ok first
+    let ok_branch = {
+        let ok_val_symbol = env.new_symbol(field_name.as_str());
+
+        WhenBranch {
+            patterns: vec![WhenBranchPattern {
+                pattern: Loc::at_zero(Pattern::AppliedTag {
+                    whole_var: result_field_var,
+                    ext_var: Variable::EMPTY_TAG_UNION,
+                    tag_name: "Ok".into(),
+                    arguments: vec![(field_var, Loc::at_zero(Pattern::Identifier(ok_val_symbol)))],
+                }),
+                degenerate: false,
+            }],
+            value: Loc::at_zero(Expr::Tag {
+                tag_union_var: field_result_var,
+                ext_var: env.new_ext_var(ExtensionKind::TagUnion),
+                name: "Ok".into(),
+                arguments: vec![(field_var, Loc::at_zero(Expr::Var(ok_val_symbol, field_var)))],
+            }),
+            guard: None,
+            redundant: RedundantMark::known_non_redundant(),
+        }
+    };
+
+    // Err NoField ->
+    //     when Decode.decodeWith [] Decode.decoder fmt is
+    //         decRec -> decRec.result
+    let err_branch = {
+        let dec_rec_symbol = env.new_symbol("decRec");
+
+        // `Decode.decoder` for the field's value
+        let decoder_var = env.import_builtin_symbol_var(Symbol::DECODE_DECODER);
+        let decode_with_var = env.import_builtin_symbol_var(Symbol::DECODE_DECODE_WITH);
+        let lambda_set_var = env.subs.fresh_unnamed_flex_var();
+        let this_decode_with_var = {
+            let subs_slice = SubsSlice::insert_into_subs(
+                env.subs,
+                [Variable::LIST_U8, decoder_var, fmt_arg_var],
+            );
+            let this_decode_with_var = synth_var(
+                env.subs,
+                Content::Structure(FlatType::Func(subs_slice, lambda_set_var, dec_rec_var)),
+            );
+
+            env.unify(decode_with_var, this_decode_with_var);
+
+            this_decode_with_var
+        };
+
+        // Decode.decodeWith [] Decode.decoder fmt
+        let condition_expr = Expr::Call(
+            Box::new((
+                this_decode_with_var,
+                Loc::at_zero(Expr::Var(Symbol::DECODE_DECODE_WITH, this_decode_with_var)),
+                lambda_set_var,
+                dec_rec_var,
+            )),
+            vec![
+                (
+                    Variable::LIST_U8,
+                    Loc::at_zero(Expr::List {
+                        elem_var: Variable::U8,
+                        loc_elems: Vec::new(),
+                    }),
+                ),
+                (
+                    decoder_var,
+                    Loc::at_zero(Expr::AbilityMember(
+                        Symbol::DECODE_DECODER,
+                        None,
+                        decoder_var,
+                    )),
+                ),
+                (
+                    fmt_arg_var,
+                    Loc::at_zero(Expr::Var(fmt_arg_symbol, fmt_arg_var)),
+                ),
+            ],
+            CalledVia::Space,
+        );
+
+        // # Uses a single-branch `when` for the same reason as in `stepField`.
+        // when Decode.decodeWith [] Decode.decoder fmt is
+        //     decRec -> decRec.result
+        let decode_from_nothing = Expr::When {
+            loc_cond: Box::new(Loc::at_zero(condition_expr)),
+            cond_var: dec_rec_var,
+            expr_var: field_result_var,
+            region: Region::zero(),
+            branches: vec![WhenBranch {
+                patterns: vec![WhenBranchPattern {
+                    pattern: Loc::at_zero(Pattern::Identifier(dec_rec_symbol)),
+                    degenerate: false,
+                }],
+                value: Loc::at_zero(Expr::RecordAccess {
+                    record_var: dec_rec_var,
+                    ext_var: env.new_ext_var(ExtensionKind::Record),
+                    field_var: field_result_var,
+                    loc_expr: Box::new(Loc::at_zero(Expr::Var(dec_rec_symbol, dec_rec_var))),
+                    field: "result".into(),
+                }),
+                guard: None,
+                redundant: RedundantMark::known_non_redundant(),
+            }],
+            branches_cond_var: dec_rec_var,
+            exhaustive: ExhaustiveMark::known_exhaustive(),
+        };
+
+        WhenBranch {
+            patterns: vec![WhenBranchPattern {
+                pattern: Loc::at_zero(Pattern::Underscore),
+                degenerate: false,
+            }],
+            value: Loc::at_zero(decode_from_nothing),
+            guard: None,
+            redundant: RedundantMark::known_non_redundant(),
+        }
+    };
+
+    // when rec.first is
+    let when_expr = Expr::When {
+        loc_cond: Box::new(Loc::at_zero(Expr::RecordAccess {
+            record_var: state_record_var,
+            ext_var: env.new_ext_var(ExtensionKind::Record),
+            field_var: result_field_var,
+            loc_expr: Box::new(Loc::at_zero(Expr::Var(state_arg_symbol, state_record_var))),
+            field: field_name.clone(),
+        })),
+        cond_var: result_field_var,
+        expr_var: field_result_var,
+        region: Region::zero(),
+        branches: vec![ok_branch, err_branch],
+        branches_cond_var: result_field_var,
+        exhaustive: ExhaustiveMark::known_exhaustive(),
+    };
+
+    (when_expr, field_result_var)
+}
+
 // Example:
 // initialState : {first: Result a [NoField], second: Result b [NoField]}
 // initialState = {first: Err NoField, second: Err NoField}
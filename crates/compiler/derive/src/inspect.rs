@@ -0,0 +1,406 @@
+//! Derivers for the `Inspect` ability.
+
+use std::iter::once;
+
+use roc_can::{
+    expr::{AnnotatedMark, ClosureData, Expr, Recursive, WhenBranch, WhenBranchPattern},
+    pattern::Pattern,
+};
+use roc_derive_key::inspect::FlatInspectableKey;
+use roc_module::{
+    called_via::CalledVia,
+    ident::{Lowercase, TagName},
+    symbol::Symbol,
+};
+use roc_region::all::{Loc, Region};
+use roc_types::{
+    subs::{
+        Content, ExhaustiveMark, FlatType, GetSubsSlice, LambdaSet, OptVariable, RecordFields,
+        RedundantMark, SubsSlice, TagExt, UnionLambdas, UnionTags, Variable, VariableSubsSlice,
+    },
+    types::RecordField,
+};
+
+use crate::{synth_var, util::Env, DerivedBody};
+
+pub(crate) fn derive_inspect(
+    env: &mut Env<'_>,
+    key: FlatInspectableKey,
+    def_symbol: Symbol,
+) -> DerivedBody {
+    let (body_type, body) = match key {
+        FlatInspectableKey::Record(fields) => inspect_record(env, def_symbol, fields),
+        FlatInspectableKey::TagUnion(tags) => inspect_tag_union(env, def_symbol, tags),
+    };
+
+    let specialization_lambda_sets =
+        env.get_specialization_lambda_sets(body_type, Symbol::INSPECT_INSPECT);
+
+    DerivedBody {
+        body,
+        body_type,
+        specialization_lambda_sets,
+    }
+}
+
+fn inspect_record(env: &mut Env<'_>, fn_name: Symbol, fields: Vec<Lowercase>) -> (Variable, Expr) {
+    // Suppose rcd = { f1, ..., fn }.
+    // Build a generalized type t_rcd = { f1: t1, ..., fn: tn }, with fresh t1, ..., tn,
+    // so that we can re-use the derived impl for many records of the same fields.
+    let (record_var, record_fields) = {
+        let flex_fields = fields
+            .into_iter()
+            .map(|name| {
+                (
+                    name,
+                    RecordField::Required(env.subs.fresh_unnamed_flex_var()),
+                )
+            })
+            .collect::<Vec<(Lowercase, _)>>();
+        let fields = RecordFields::insert_into_subs(env.subs, flex_fields);
+        let record_var = synth_var(
+            env.subs,
+            Content::Structure(FlatType::Record(fields, Variable::EMPTY_RECORD)),
+        );
+
+        (record_var, fields)
+    };
+
+    // Now, an inspector for this record is
+    //
+    // inspect_rcd : Str, { f1: t1, ..., fn: tn } -> Str | t1 has Inspect, ..., tn has Inspect
+    // inspect_rcd = \buf, rcd ->
+    //   Inspect.literal
+    //      (Inspect.inspect
+    //          (Inspect.literal
+    //              ...
+    //                  (Inspect.inspect (Inspect.literal buf "{ f1: ") rcd.f1)
+    //              ...
+    //              ", fn: ")
+    //          rcd.fn)
+    //      " }"
+    //
+    // So, just a build a fold travelling up the fields. The empty record is just `{}`.
+    let rcd_sym = env.new_symbol("rcd");
+
+    let buf_sym = env.new_symbol("buf");
+    let buf_var = str_var(env);
+
+    let (body_var, body) = if record_fields.is_empty() {
+        call_literal(env, (buf_var, Expr::Var(buf_sym, buf_var)), "{}")
+    } else {
+        let inspected_fields = record_fields.iter_all().enumerate().fold(
+            (buf_var, Expr::Var(buf_sym, buf_var)),
+            |total_buf, (index, (field_name, field_var, _))| {
+                let field_name = env.subs[field_name].clone();
+                let field_var = env.subs[field_var];
+
+                let prefix = if index == 0 { "{ " } else { ", " };
+                let label = format!("{}{}: ", prefix, field_name);
+                let labelled_buf = call_literal(env, total_buf, &label);
+
+                let field_access = Expr::RecordAccess {
+                    record_var,
+                    field_var,
+                    ext_var: env.subs.fresh_unnamed_flex_var(),
+                    loc_expr: Box::new(Loc::at_zero(Expr::Var(
+                        rcd_sym,
+                        env.subs.fresh_unnamed_flex_var(),
+                    ))),
+                    field: field_name,
+                };
+
+                call_inspect_builtin(
+                    env,
+                    Symbol::INSPECT_INSPECT,
+                    labelled_buf,
+                    (field_var, field_access),
+                )
+            },
+        );
+
+        call_literal(env, inspected_fields, " }")
+    };
+
+    // Finally, build the closure
+    // \buf, rcd -> body
+    build_outer_derived_closure(
+        env,
+        fn_name,
+        (buf_var, buf_sym),
+        (record_var, Pattern::Identifier(rcd_sym)),
+        (body_var, body),
+    )
+}
+
+fn inspect_tag_union(
+    env: &mut Env<'_>,
+    fn_name: Symbol,
+    tags: Vec<(TagName, u16)>,
+) -> (Variable, Expr) {
+    // Suppose tags = [ A p11 .. p1n, ..., Q pq1 .. pqm ]
+    // Build a generalized type t_tags = [ A t11 .. t1n, ..., Q tq1 .. tqm ],
+    // with fresh t1, ..., tqm, so that we can re-use the derived impl for many
+    // unions of the same tags and payloads.
+    let (union_var, union_tags) = {
+        let flex_tag_labels = tags
+            .into_iter()
+            .map(|(label, arity)| {
+                let variables_slice = VariableSubsSlice::reserve_into_subs(env.subs, arity.into());
+                for var_index in variables_slice {
+                    env.subs[var_index] = env.subs.fresh_unnamed_flex_var();
+                }
+                (label, variables_slice)
+            })
+            .collect::<Vec<_>>();
+        let union_tags = UnionTags::insert_slices_into_subs(env.subs, flex_tag_labels);
+        let tag_union_var = synth_var(
+            env.subs,
+            Content::Structure(FlatType::TagUnion(
+                union_tags,
+                TagExt::Any(Variable::EMPTY_TAG_UNION),
+            )),
+        );
+
+        (tag_union_var, union_tags)
+    };
+
+    // Now, an inspector for this tag union is
+    //
+    // inspect_union : Str, [ A t11 .. t1n, ..., Q tq1 .. tqm ] -> Str | t11 has Inspect, ...
+    // inspect_union = \buf, union ->
+    //   when union is
+    //      A x11 .. x1n ->
+    //          Inspect.payload (... (Inspect.payload (Inspect.literal buf "A") x11) ...) x1n
+    //      ...
+    //      Q xq1 .. xqm ->
+    //          Inspect.payload (... (Inspect.payload (Inspect.literal buf "Q") xq1) ...) xqm
+    let union_sym = env.new_symbol("union");
+
+    let buf_sym = env.new_symbol("buf");
+    let buf_var = str_var(env);
+
+    // Build the branches of the body
+    let whole_buf_var = env.subs.fresh_unnamed_flex_var();
+    let branches = union_tags
+        .iter_all()
+        .map(|(tag, payloads)| {
+            // A
+            let tag_name = env.subs[tag].clone();
+            // t11 .. t1n
+            let payload_vars = env.subs.get_subs_slice(env.subs[payloads]).to_vec();
+            // x11 .. x1n
+            let payload_syms: Vec<_> = std::iter::repeat_with(|| env.unique_symbol())
+                .take(payload_vars.len())
+                .collect();
+
+            // (Inspect.literal buf "A")
+            let tag_buf = call_literal(
+                env,
+                (buf_var, Expr::Var(buf_sym, buf_var)),
+                tag_name.0.as_str(),
+            );
+
+            // `A x1 .. x1n` pattern
+            let pattern = Pattern::AppliedTag {
+                whole_var: union_var,
+                tag_name,
+                ext_var: Variable::EMPTY_TAG_UNION,
+                // (t1, v1) (t2, v2)
+                arguments: (payload_vars.iter())
+                    .zip(payload_syms.iter())
+                    .map(|(var, sym)| (*var, Loc::at_zero(Pattern::Identifier(*sym))))
+                    .collect(),
+            };
+            let branch_pattern = WhenBranchPattern {
+                pattern: Loc::at_zero(pattern),
+                degenerate: false,
+            };
+
+            // Fold up `Inspect.payload (... (Inspect.payload tagBuf x11) ...) x1n`
+            let (body_var, body_expr) = (payload_vars.into_iter()).zip(payload_syms).fold(
+                tag_buf,
+                |total_buf, (payload_var, payload_sym)| {
+                    call_inspect_builtin(
+                        env,
+                        Symbol::INSPECT_PAYLOAD,
+                        total_buf,
+                        (payload_var, Expr::Var(payload_sym, payload_var)),
+                    )
+                },
+            );
+
+            env.unify(whole_buf_var, body_var);
+
+            WhenBranch {
+                patterns: vec![branch_pattern],
+                value: Loc::at_zero(body_expr),
+                guard: None,
+                redundant: RedundantMark::known_non_redundant(),
+            }
+        })
+        .collect();
+
+    // when union is
+    //   ...
+    let when_var = whole_buf_var;
+    let when_expr = Expr::When {
+        loc_cond: Box::new(Loc::at_zero(Expr::Var(union_sym, union_var))),
+        cond_var: union_var,
+        expr_var: when_var,
+        region: Region::zero(),
+        branches,
+        branches_cond_var: union_var,
+        exhaustive: ExhaustiveMark::known_exhaustive(),
+    };
+
+    // Finally, build the closure
+    // \buf, union -> body
+    build_outer_derived_closure(
+        env,
+        fn_name,
+        (buf_var, buf_sym),
+        (union_var, Pattern::Identifier(union_sym)),
+        (when_var, when_expr),
+    )
+}
+
+fn str_var(env: &mut Env<'_>) -> Variable {
+    synth_var(
+        env.subs,
+        Content::Structure(FlatType::Apply(
+            Symbol::STR_STR,
+            VariableSubsSlice::default(),
+        )),
+    )
+}
+
+/// Build `Inspect.literal buf "<text>"`.
+fn call_literal(env: &mut Env<'_>, buf: (Variable, Expr), text: &str) -> (Variable, Expr) {
+    let text_var = str_var(env);
+
+    call_inspect_builtin(
+        env,
+        Symbol::INSPECT_LITERAL,
+        buf,
+        (text_var, Expr::Str(text.into())),
+    )
+}
+
+fn call_inspect_builtin(
+    env: &mut Env<'_>,
+    callee: Symbol,
+    buf: (Variable, Expr),
+    val: (Variable, Expr),
+) -> (Variable, Expr) {
+    let (in_buf_var, in_buf_expr) = buf;
+    let (in_val_var, in_val_expr) = val;
+
+    // build `callee ...` function type. `callee` here is `Inspect.inspect`, `Inspect.payload`,
+    // or `Inspect.literal`.
+    //
+    // Str, val -[uls]-> Str | val has Inspect
+    let exposed_fn_var = env.import_builtin_symbol_var(callee);
+
+    // (typeof buf), (typeof val) -[clos]-> buf_result
+    let this_arguments_slice =
+        VariableSubsSlice::insert_into_subs(env.subs, [in_buf_var, in_val_var]);
+    let this_clos_var = env.subs.fresh_unnamed_flex_var();
+    let this_out_buf_var = env.subs.fresh_unnamed_flex_var();
+    let this_fn_var = synth_var(
+        env.subs,
+        Content::Structure(FlatType::Func(
+            this_arguments_slice,
+            this_clos_var,
+            this_out_buf_var,
+        )),
+    );
+
+    //   Str,          val           -[uls]->  Str | val has Inspect
+    // ~ (typeof buf), (typeof val)  -[clos]-> buf_result
+    env.unify(exposed_fn_var, this_fn_var);
+
+    // Only `Inspect.inspect` is an ability member; the others are plain functions.
+    let fn_head = if callee == Symbol::INSPECT_INSPECT {
+        Expr::AbilityMember(callee, None, this_fn_var)
+    } else {
+        Expr::Var(callee, this_fn_var)
+    };
+    let fn_data = Box::new((
+        this_fn_var,
+        Loc::at_zero(fn_head),
+        this_clos_var,
+        this_out_buf_var,
+    ));
+
+    let arguments = vec![
+        (in_buf_var, Loc::at_zero(in_buf_expr)),
+        (in_val_var, Loc::at_zero(in_val_expr)),
+    ];
+    let call = Expr::Call(fn_data, arguments, CalledVia::Space);
+
+    (this_out_buf_var, call)
+}
+
+fn build_outer_derived_closure(
+    env: &mut Env<'_>,
+    fn_name: Symbol,
+    buf: (Variable, Symbol),
+    val: (Variable, Pattern),
+    body: (Variable, Expr),
+) -> (Variable, Expr) {
+    let (buf_var, buf_sym) = buf;
+    let (val_var, val_pattern) = val;
+    let (body_var, body_expr) = body;
+
+    let (fn_var, fn_clos_var) = {
+        // Create fn_var for ambient capture; we fix it up below.
+        let fn_var = synth_var(env.subs, Content::Error);
+
+        // -[fn_name]->
+        let fn_captures = vec![];
+        let fn_name_labels = UnionLambdas::insert_into_subs(env.subs, once((fn_name, fn_captures)));
+        let fn_clos_var = synth_var(
+            env.subs,
+            Content::LambdaSet(LambdaSet {
+                solved: fn_name_labels,
+                recursion_var: OptVariable::NONE,
+                unspecialized: SubsSlice::default(),
+                ambient_function: fn_var,
+            }),
+        );
+
+        // buf, val_var -[fn_name]-> (Str = body_var)
+        let args_slice = SubsSlice::insert_into_subs(env.subs, [buf_var, val_var]);
+        env.subs.set_content(
+            fn_var,
+            Content::Structure(FlatType::Func(args_slice, fn_clos_var, body_var)),
+        );
+
+        (fn_var, fn_clos_var)
+    };
+
+    let clos_expr = Expr::Closure(ClosureData {
+        function_type: fn_var,
+        closure_type: fn_clos_var,
+        return_type: body_var,
+        name: fn_name,
+        captured_symbols: vec![],
+        recursive: Recursive::NotRecursive,
+        arguments: vec![
+            (
+                buf_var,
+                AnnotatedMark::known_exhaustive(),
+                Loc::at_zero(Pattern::Identifier(buf_sym)),
+            ),
+            (
+                val_var,
+                AnnotatedMark::known_exhaustive(),
+                Loc::at_zero(val_pattern),
+            ),
+        ],
+        loc_body: Box::new(Loc::at_zero(body_expr)),
+    });
+
+    (fn_var, clos_expr)
+}
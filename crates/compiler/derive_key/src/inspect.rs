@@ -0,0 +1,195 @@
+use roc_module::{
+    ident::{Lowercase, TagName},
+    symbol::{ModuleId, Symbol},
+};
+use roc_types::{
+    subs::{Content, FlatType, GetSubsSlice, Subs, Variable},
+    types::AliasKind,
+};
+
+use crate::{
+    util::{check_derivable_ext_var, debug_name_record, debug_name_tag},
+    DeriveError,
+};
+
+#[derive(Hash)]
+pub enum FlatInspectable {
+    // `inspect` is always of form `Str, a -> Str` where `a` is opaque, so all immediates must
+    // have exactly one lambda set!
+    SingleLambdaSetImmediate(Symbol),
+    Key(FlatInspectableKey),
+}
+
+#[derive(Hash, PartialEq, Eq, Debug, Clone)]
+pub enum FlatInspectableKey {
+    // Unfortunate that we must allocate here, c'est la vie
+    Record(Vec<Lowercase>),
+    TagUnion(Vec<(TagName, u16)>),
+}
+
+impl FlatInspectableKey {
+    pub(crate) fn debug_name(&self) -> String {
+        match self {
+            FlatInspectableKey::Record(fields) => debug_name_record(fields),
+            FlatInspectableKey::TagUnion(tags) => debug_name_tag(tags),
+        }
+    }
+}
+
+impl FlatInspectable {
+    pub(crate) fn from_var(subs: &Subs, var: Variable) -> Result<FlatInspectable, DeriveError> {
+        use DeriveError::*;
+        use FlatInspectable::*;
+        match *subs.get_content_without_compacting(var) {
+            Content::Structure(flat_type) => match flat_type {
+                FlatType::Apply(sym, _) => match sym {
+                    Symbol::LIST_LIST => Ok(SingleLambdaSetImmediate(Symbol::INSPECT_LIST)),
+                    Symbol::STR_STR => Ok(SingleLambdaSetImmediate(Symbol::INSPECT_STR)),
+                    _ => Err(Underivable),
+                },
+                FlatType::Record(fields, ext) => {
+                    let (fields_iter, ext) = fields.unsorted_iterator_and_ext(subs, ext);
+
+                    check_derivable_ext_var(subs, ext, |ext| {
+                        matches!(ext, Content::Structure(FlatType::EmptyRecord))
+                    })?;
+
+                    let mut field_names = Vec::with_capacity(fields.len());
+                    for (field_name, record_field) in fields_iter {
+                        if record_field.is_optional() {
+                            // Can't derive a concrete inspector for optional fields, since those
+                            // are compile-time-polymorphic
+                            return Err(Underivable);
+                        }
+                        field_names.push(field_name.clone());
+                    }
+
+                    field_names.sort();
+
+                    Ok(Key(FlatInspectableKey::Record(field_names)))
+                }
+                FlatType::Tuple(_elems, _ext) => {
+                    todo!();
+                }
+                FlatType::TagUnion(tags, ext) | FlatType::RecursiveTagUnion(_, tags, ext) => {
+                    // As with `Hash`, the recursion var doesn't matter, because the derived
+                    // implementation only looks at the surface of the tag union type.
+                    let (tags_iter, ext) = tags.unsorted_tags_and_ext(subs, ext);
+
+                    check_derivable_ext_var(subs, ext.var(), |ext| {
+                        matches!(ext, Content::Structure(FlatType::EmptyTagUnion))
+                    })?;
+
+                    let mut tag_names_and_payload_sizes: Vec<_> = tags_iter
+                        .tags
+                        .into_iter()
+                        .map(|(name, payload_slice)| {
+                            let payload_size = payload_slice.len();
+                            (name.clone(), payload_size as _)
+                        })
+                        .collect();
+
+                    tag_names_and_payload_sizes.sort_by(|(t1, _), (t2, _)| t1.cmp(t2));
+
+                    Ok(Key(FlatInspectableKey::TagUnion(
+                        tag_names_and_payload_sizes,
+                    )))
+                }
+                FlatType::FunctionOrTagUnion(names_index, _, _) => {
+                    Ok(Key(FlatInspectableKey::TagUnion(
+                        subs.get_subs_slice(names_index)
+                            .iter()
+                            .map(|t| (t.clone(), 0))
+                            .collect(),
+                    )))
+                }
+                FlatType::EmptyRecord => Ok(Key(FlatInspectableKey::Record(vec![]))),
+                FlatType::EmptyTuple => todo!(),
+                FlatType::EmptyTagUnion => Ok(Key(FlatInspectableKey::TagUnion(vec![]))),
+                //
+                FlatType::Func(..) => Err(Underivable),
+            },
+            Content::Alias(sym, _, real_var, kind) => match num_symbol_to_inspect_lambda(sym) {
+                Some(lambda) => Ok(lambda),
+                None if kind == AliasKind::Opaque && sym.module_id() != ModuleId::NUM => {
+                    // We only get here for opaques that don't implement `Inspect` themselves.
+                    Ok(SingleLambdaSetImmediate(opaque_inspector(sym)))
+                }
+                None => Self::from_var(subs, real_var),
+            },
+            Content::RangedNumber(range) => {
+                // As with `Hash`, pick the number type we're going to compile to.
+                let chosen_width = range.default_compilation_width();
+                let lambda = num_symbol_to_inspect_lambda(chosen_width.symbol()).unwrap();
+                Ok(lambda)
+            }
+            //
+            Content::RecursionVar { structure, .. } => Self::from_var(subs, structure),
+            //
+            Content::Error => Err(Underivable),
+            Content::FlexVar(_)
+            | Content::RigidVar(_)
+            | Content::FlexAbleVar(_, _)
+            | Content::RigidAbleVar(_, _) => Err(UnboundVar),
+            Content::LambdaSet(_) => Err(Underivable),
+        }
+    }
+}
+
+/// The `Inspect` implementation used for an opaque type that doesn't implement `Inspect` itself.
+/// Builtin opaques get a readable implementation; all others are shown as `<opaque>`.
+pub fn opaque_inspector(opaque: Symbol) -> Symbol {
+    match opaque {
+        Symbol::BOOL_BOOL => Symbol::INSPECT_BOOL,
+        Symbol::DICT_DICT => Symbol::INSPECT_DICT,
+        Symbol::SET_SET => Symbol::INSPECT_SET,
+        _ => Symbol::INSPECT_OPAQUE,
+    }
+}
+
+const fn num_symbol_to_inspect_lambda(symbol: Symbol) -> Option<FlatInspectable> {
+    use FlatInspectable::*;
+    match symbol {
+        Symbol::NUM_U8 | Symbol::NUM_UNSIGNED8 => {
+            Some(SingleLambdaSetImmediate(Symbol::INSPECT_U8))
+        }
+        Symbol::NUM_U16 | Symbol::NUM_UNSIGNED16 => {
+            Some(SingleLambdaSetImmediate(Symbol::INSPECT_U16))
+        }
+        Symbol::NUM_U32 | Symbol::NUM_UNSIGNED32 => {
+            Some(SingleLambdaSetImmediate(Symbol::INSPECT_U32))
+        }
+        Symbol::NUM_U64 | Symbol::NUM_UNSIGNED64 => {
+            Some(SingleLambdaSetImmediate(Symbol::INSPECT_U64))
+        }
+        Symbol::NUM_U128 | Symbol::NUM_UNSIGNED128 => {
+            Some(SingleLambdaSetImmediate(Symbol::INSPECT_U128))
+        }
+        Symbol::NUM_I8 | Symbol::NUM_SIGNED8 => Some(SingleLambdaSetImmediate(Symbol::INSPECT_I8)),
+        Symbol::NUM_I16 | Symbol::NUM_SIGNED16 => {
+            Some(SingleLambdaSetImmediate(Symbol::INSPECT_I16))
+        }
+        Symbol::NUM_I32 | Symbol::NUM_SIGNED32 => {
+            Some(SingleLambdaSetImmediate(Symbol::INSPECT_I32))
+        }
+        Symbol::NUM_I64 | Symbol::NUM_SIGNED64 => {
+            Some(SingleLambdaSetImmediate(Symbol::INSPECT_I64))
+        }
+        Symbol::NUM_I128 | Symbol::NUM_SIGNED128 => {
+            Some(SingleLambdaSetImmediate(Symbol::INSPECT_I128))
+        }
+        Symbol::NUM_NAT | Symbol::NUM_NATURAL => {
+            Some(SingleLambdaSetImmediate(Symbol::INSPECT_NAT))
+        }
+        Symbol::NUM_F32 | Symbol::NUM_BINARY32 => {
+            Some(SingleLambdaSetImmediate(Symbol::INSPECT_F32))
+        }
+        Symbol::NUM_F64 | Symbol::NUM_BINARY64 => {
+            Some(SingleLambdaSetImmediate(Symbol::INSPECT_F64))
+        }
+        Symbol::NUM_DEC | Symbol::NUM_DECIMAL => {
+            Some(SingleLambdaSetImmediate(Symbol::INSPECT_DEC))
+        }
+        _ => None,
+    }
+}
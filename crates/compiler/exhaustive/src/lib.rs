@@ -187,13 +187,94 @@ pub fn check(
         // TODO i suspect this is like a concat in in practice? code below can panic
         // if this debug_assert! ever fails, the theory is disproven
         debug_assert!(bad_patterns.iter().map(|v| v.len()).sum::<usize>() == bad_patterns.len());
-        let heads = bad_patterns.into_iter().map(|mut v| v.remove(0)).collect();
+        let heads = match unwrap_guards(&matrix) {
+            Some(guarded) => missing_patterns_with_guards(guarded),
+            None => bad_patterns.into_iter().map(|mut v| v.remove(0)).collect(),
+        };
         errors.push(Error::Incomplete(region, context, heads));
         return Err(errors);
     }
     Ok(())
 }
 
+/// The rows of a `when` with guards, unwrapped from the guard constructor.
+struct GuardedBranches {
+    guard_union: Union,
+    guard_tag_id: TagId,
+    branches: Vec<(Pattern, Guard)>,
+}
+
+/// When any branch of a `when` has a guard, every row is wrapped in a constructor rendered as
+/// [RenderAs::Guard], whose first argument is `Anything` exactly when the branch has no guard.
+/// Splits such a matrix back into the pattern of each branch and whether the branch has a guard.
+fn unwrap_guards(matrix: &RefPatternMatrix) -> Option<GuardedBranches> {
+    let mut guard_ctor = None;
+    let mut branches = Vec::with_capacity(matrix.len());
+
+    for row in matrix {
+        match row.as_slice() {
+            [Ctor(union, tag_id, args)] if union.render_as == RenderAs::Guard => {
+                let guard = match args[0] {
+                    Anything => Guard::NoGuard,
+                    _ => Guard::HasGuard,
+                };
+
+                guard_ctor = Some((union.clone(), *tag_id));
+                branches.push((args[1].clone(), guard));
+            }
+            _ => return None,
+        }
+    }
+
+    guard_ctor.map(|(guard_union, guard_tag_id)| GuardedBranches {
+        guard_union,
+        guard_tag_id,
+        branches,
+    })
+}
+
+/// Finds the patterns missing from a `when` whose branches have guards.
+///
+/// Values that no branch matches at all are reported as they are. Values that only guarded
+/// branches match are reported as the pattern of the first such branch, wrapped in the guard
+/// constructor, so that the report can point out that a guard is all that is missing.
+fn missing_patterns_with_guards(guarded: GuardedBranches) -> Vec<Pattern> {
+    let GuardedBranches {
+        guard_union,
+        guard_tag_id,
+        branches,
+    } = guarded;
+
+    let all_rows: PatternMatrix = branches
+        .iter()
+        .map(|(pattern, _)| vec![pattern.clone()])
+        .collect();
+
+    let mut missing: Vec<Pattern> = is_exhaustive(&all_rows, 1)
+        .into_iter()
+        .map(|mut row| row.remove(0))
+        .collect();
+
+    let mut guard_free_rows: PatternMatrix = branches
+        .iter()
+        .filter(|(_, guard)| *guard == Guard::NoGuard)
+        .map(|(pattern, _)| vec![pattern.clone()])
+        .collect();
+
+    for (pattern, guard) in branches {
+        if guard == Guard::HasGuard && is_useful(guard_free_rows.clone(), vec![pattern.clone()]) {
+            guard_free_rows.push(vec![pattern.clone()]);
+            missing.push(Ctor(
+                guard_union.clone(),
+                guard_tag_id,
+                vec![Anything, pattern],
+            ));
+        }
+    }
+
+    missing
+}
+
 /// EXHAUSTIVE PATTERNS
 
 /// INVARIANTS:
@@ -647,38 +728,31 @@ enum CollectedCtors {
 }
 
 fn collect_ctors(matrix: &RefPatternMatrix) -> CollectedCtors {
-    if matrix.is_empty() {
-        return CollectedCtors::NonExhaustiveAny;
-    }
-
-    let first_row = &matrix[0];
-
-    if let Some(ctor) = first_row.last() {
-        match ctor {
-            Anything => CollectedCtors::NonExhaustiveAny,
-            Pattern::Literal(_) => CollectedCtors::NonExhaustiveAny,
-            List(_, _) => {
+    // Look past rows that start with a wildcard, so that missing patterns are described in terms
+    // of the constructors that the other rows do match on.
+    let mut ctors = MutMap::default();
+
+    for row in matrix {
+        match row.last() {
+            Some(Ctor(union, id, _)) => {
+                ctors.insert(*id, union.clone());
+            }
+            Some(List(_, _)) => {
                 let list_ctors = build_list_ctors_covering_patterns(
                     ListArity::ANY,
                     filter_matrix_list_ctors(matrix),
                 );
 
-                CollectedCtors::NonExhaustiveList(list_ctors)
-            }
-            Pattern::Ctor(_, _, _) => {
-                let mut ctors = MutMap::default();
-
-                for row in matrix {
-                    if let Some(Ctor(union, id, _)) = row.last() {
-                        ctors.insert(*id, union.clone());
-                    }
-                }
-
-                CollectedCtors::Ctors(ctors)
+                return CollectedCtors::NonExhaustiveList(list_ctors);
             }
+            Some(Anything) | Some(Pattern::Literal(_)) | None => {}
         }
-    } else {
+    }
+
+    if ctors.is_empty() {
         CollectedCtors::NonExhaustiveAny
+    } else {
+        CollectedCtors::Ctors(ctors)
     }
 }
 
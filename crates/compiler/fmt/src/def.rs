@@ -180,6 +180,7 @@ impl<'a> Formattable for ValueDef<'a> {
             Expect { condition, .. } => condition.is_multiline(),
             ExpectFx { condition, .. } => condition.is_multiline(),
             Dbg { condition, .. } => condition.is_multiline(),
+            Malformed(raw) => raw.contains('\n'),
         }
     }
 
@@ -259,6 +260,10 @@ impl<'a> Formattable for ValueDef<'a> {
             ExpectFx { condition, .. } => {
                 fmt_expect_fx(buf, condition, self.is_multiline(), indent)
             }
+            Malformed(raw) => {
+                buf.indent(indent);
+                buf.push_str_allow_spaces(raw)
+            }
             AnnotatedBody {
                 ann_pattern,
                 ann_type,
@@ -453,6 +453,85 @@ trait Backend<'a> {
                 arg_layouts,
                 ret_layout,
             ),
+            LowLevel::NumIsNan | LowLevel::NumIsInfinite | LowLevel::NumIsFinite => {
+                debug_assert_eq!(
+                    1,
+                    args.len(),
+                    "{:?}: expected to have exactly one argument",
+                    lowlevel
+                );
+                match self.interner().get(arg_layouts[0]) {
+                    Layout::Builtin(Builtin::Float(float_width)) => {
+                        let intrinsic = match lowlevel {
+                            LowLevel::NumIsNan => &bitcode::NUM_IS_NAN,
+                            LowLevel::NumIsInfinite => &bitcode::NUM_IS_INFINITE,
+                            _ => &bitcode::NUM_IS_FINITE,
+                        };
+                        self.build_fn_call(
+                            sym,
+                            intrinsic[float_width].to_string(),
+                            args,
+                            arg_layouts,
+                            ret_layout,
+                        )
+                    }
+                    Layout::Builtin(Builtin::Decimal) => {
+                        // A Dec is always a finite number
+                        let is_finite = *lowlevel == LowLevel::NumIsFinite;
+                        self.load_literal(sym, ret_layout, &Literal::Bool(is_finite))
+                    }
+                    layout => internal_error!("{:?} on a non-fraction: {:?}", lowlevel, layout),
+                }
+            }
+            LowLevel::NumCountLeadingZeroBits => self.build_fn_call(
+                sym,
+                bitcode::NUM_COUNT_LEADING_ZERO_BITS[arg_layouts[0].to_int_width()].to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::NumCountTrailingZeroBits => self.build_fn_call(
+                sym,
+                bitcode::NUM_COUNT_TRAILING_ZERO_BITS[arg_layouts[0].to_int_width()].to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::NumCountOneBits => self.build_fn_call(
+                sym,
+                bitcode::NUM_COUNT_ONE_BITS[arg_layouts[0].to_int_width()].to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::NumF32ToBits => self.build_fn_call(
+                sym,
+                bitcode::NUM_F32_TO_BITS.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::NumF64ToBits => self.build_fn_call(
+                sym,
+                bitcode::NUM_F64_TO_BITS.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::NumF32FromBits => self.build_fn_call(
+                sym,
+                bitcode::NUM_F32_FROM_BITS.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::NumF64FromBits => self.build_fn_call(
+                sym,
+                bitcode::NUM_F64_FROM_BITS.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
             LowLevel::NumMul => {
                 debug_assert_eq!(
                     2,
@@ -769,6 +848,48 @@ trait Backend<'a> {
                 arg_layouts,
                 ret_layout,
             ),
+            LowLevel::StrToUpper => self.build_fn_call(
+                sym,
+                bitcode::STR_TO_UPPER.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrToLower => self.build_fn_call(
+                sym,
+                bitcode::STR_TO_LOWER.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrCaseFold => self.build_fn_call(
+                sym,
+                bitcode::STR_CASE_FOLD.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrToNfc => self.build_fn_call(
+                sym,
+                bitcode::STR_TO_NFC.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrToNfd => self.build_fn_call(
+                sym,
+                bitcode::STR_TO_NFD.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrCompare => self.build_fn_call(
+                sym,
+                bitcode::STR_COMPARE.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
             LowLevel::PtrCast => {
                 debug_assert_eq!(
                     1,
@@ -625,6 +625,78 @@ pub(crate) fn run_low_level<'a, 'ctx, 'env>(
                 bitcode::STR_GRAPHEMES,
             )
         }
+        StrToUpper => {
+            // Str.toUpper : Str -> Str
+            arguments!(string);
+
+            call_str_bitcode_fn(
+                env,
+                &[string],
+                &[],
+                BitcodeReturns::Str,
+                bitcode::STR_TO_UPPER,
+            )
+        }
+        StrToLower => {
+            // Str.toLower : Str -> Str
+            arguments!(string);
+
+            call_str_bitcode_fn(
+                env,
+                &[string],
+                &[],
+                BitcodeReturns::Str,
+                bitcode::STR_TO_LOWER,
+            )
+        }
+        StrCaseFold => {
+            // Str.caseFold : Str -> Str
+            arguments!(string);
+
+            call_str_bitcode_fn(
+                env,
+                &[string],
+                &[],
+                BitcodeReturns::Str,
+                bitcode::STR_CASE_FOLD,
+            )
+        }
+        StrToNfc => {
+            // Str.toNfc : Str -> Str
+            arguments!(string);
+
+            call_str_bitcode_fn(
+                env,
+                &[string],
+                &[],
+                BitcodeReturns::Str,
+                bitcode::STR_TO_NFC,
+            )
+        }
+        StrToNfd => {
+            // Str.toNfd : Str -> Str
+            arguments!(string);
+
+            call_str_bitcode_fn(
+                env,
+                &[string],
+                &[],
+                BitcodeReturns::Str,
+                bitcode::STR_TO_NFD,
+            )
+        }
+        StrCompare => {
+            // Str.compare : Str, Str -> [LT, EQ, GT]
+            arguments!(left, right);
+
+            call_str_bitcode_fn(
+                env,
+                &[left, right],
+                &[],
+                BitcodeReturns::Basic,
+                bitcode::STR_COMPARE,
+            )
+        }
         ListLen => {
             // List.len : List * -> Nat
             arguments!(list);
@@ -848,9 +920,30 @@ pub(crate) fn run_low_level<'a, 'ctx, 'env>(
                 _ => unreachable!(),
             }
         }
-        NumAbs | NumNeg | NumRound | NumSqrtUnchecked | NumLogUnchecked | NumSin | NumCos
-        | NumCeiling | NumFloor | NumToFrac | NumIsFinite | NumAtan | NumAcos | NumAsin
-        | NumToIntChecked => {
+        NumAbs
+        | NumNeg
+        | NumRound
+        | NumSqrtUnchecked
+        | NumLogUnchecked
+        | NumSin
+        | NumCos
+        | NumCeiling
+        | NumFloor
+        | NumToFrac
+        | NumIsNan
+        | NumIsInfinite
+        | NumIsFinite
+        | NumAtan
+        | NumAcos
+        | NumAsin
+        | NumToIntChecked
+        | NumCountLeadingZeroBits
+        | NumCountTrailingZeroBits
+        | NumCountOneBits
+        | NumF32ToBits
+        | NumF64ToBits
+        | NumF32FromBits
+        | NumF64FromBits => {
             arguments_with_layouts!((arg, arg_layout));
 
             match layout_interner.get(arg_layout) {
@@ -879,6 +972,7 @@ pub(crate) fn run_low_level<'a, 'ctx, 'env>(
                             op,
                             float_width,
                         ),
+                        Decimal => build_dec_unary_op(env, op),
                         _ => {
                             unreachable!("Compiler bug: tried to run numeric operation {:?} on invalid builtin layout: ({:?})", op, arg_layout);
                         }
@@ -2045,6 +2139,21 @@ fn build_int_unary_op<'a, 'ctx, 'env>(
                 complex_bitcast_check_size(env, result, return_type.into(), "cast_bitpacked")
             }
         }
+        NumCountLeadingZeroBits => call_bitcode_fn(
+            env,
+            &[arg.into()],
+            &bitcode::NUM_COUNT_LEADING_ZERO_BITS[arg_width],
+        ),
+        NumCountTrailingZeroBits => call_bitcode_fn(
+            env,
+            &[arg.into()],
+            &bitcode::NUM_COUNT_TRAILING_ZERO_BITS[arg_width],
+        ),
+        NumCountOneBits => {
+            call_bitcode_fn(env, &[arg.into()], &bitcode::NUM_COUNT_ONE_BITS[arg_width])
+        }
+        NumF32FromBits => bd.build_bitcast(arg, env.context.f32_type(), "f32_from_bits"),
+        NumF64FromBits => bd.build_bitcast(arg, env.context.f64_type(), "f64_from_bits"),
         _ => {
             unreachable!("Unrecognized int unary operation: {:?}", op);
         }
@@ -2156,6 +2265,24 @@ fn int_abs_with_overflow<'a, 'ctx, 'env>(
     )
 }
 
+fn build_dec_unary_op<'a, 'ctx, 'env>(
+    env: &Env<'a, 'ctx, 'env>,
+    op: LowLevel,
+) -> BasicValueEnum<'ctx> {
+    use roc_module::low_level::LowLevel::*;
+
+    let bool_type = env.context.bool_type();
+
+    match op {
+        // A Dec is always a finite number
+        NumIsFinite => bool_type.const_int(1, false).into(),
+        NumIsNan | NumIsInfinite => bool_type.const_zero().into(),
+        _ => {
+            unreachable!("Unrecognized dec unary operation: {:?}", op);
+        }
+    }
+}
+
 fn build_float_unary_op<'a, 'ctx, 'env>(
     env: &Env<'a, 'ctx, 'env>,
     layout_interner: &STLayoutInterner<'a>,
@@ -2256,7 +2383,13 @@ fn build_float_unary_op<'a, 'ctx, 'env>(
                 "num_round",
             )
         }
+        NumIsNan => call_bitcode_fn(env, &[arg.into()], &bitcode::NUM_IS_NAN[float_width]),
+        NumIsInfinite => {
+            call_bitcode_fn(env, &[arg.into()], &bitcode::NUM_IS_INFINITE[float_width])
+        }
         NumIsFinite => call_bitcode_fn(env, &[arg.into()], &bitcode::NUM_IS_FINITE[float_width]),
+        NumF32ToBits => bd.build_bitcast(arg, env.context.i32_type(), "f32_to_bits"),
+        NumF64ToBits => bd.build_bitcast(arg, env.context.i64_type(), "f64_to_bits"),
 
         // trigonometry
         NumSin => env.call_intrinsic(&LLVM_SIN[float_width], &[arg.into()]),
@@ -296,6 +296,12 @@ impl<'a> LowLevelCall<'a> {
             }
             StrWithCapacity => self.load_args_and_call_zig(backend, bitcode::STR_WITH_CAPACITY),
             StrGraphemes => self.load_args_and_call_zig(backend, bitcode::STR_GRAPHEMES),
+            StrToUpper => self.load_args_and_call_zig(backend, bitcode::STR_TO_UPPER),
+            StrToLower => self.load_args_and_call_zig(backend, bitcode::STR_TO_LOWER),
+            StrCaseFold => self.load_args_and_call_zig(backend, bitcode::STR_CASE_FOLD),
+            StrToNfc => self.load_args_and_call_zig(backend, bitcode::STR_TO_NFC),
+            StrToNfd => self.load_args_and_call_zig(backend, bitcode::STR_TO_NFD),
+            StrCompare => self.load_args_and_call_zig(backend, bitcode::STR_COMPARE),
 
             // List
             ListLen => match backend.storage.get(&self.arguments[0]) {
@@ -1555,6 +1561,8 @@ impl<'a> LowLevelCall<'a> {
                 self.load_args_and_call_zig(backend, &bitcode::NUM_POW_INT[width])
             }
 
+            NumIsNan => num_is_nan(backend, self.arguments[0]),
+            NumIsInfinite => num_is_infinite(backend, self.arguments[0]),
             NumIsFinite => num_is_finite(backend, self.arguments[0]),
 
             NumAtan => match self.ret_layout_raw {
@@ -1577,6 +1585,34 @@ impl<'a> LowLevelCall<'a> {
             },
             NumBytesToU16 => self.load_args_and_call_zig(backend, bitcode::NUM_BYTES_TO_U16),
             NumBytesToU32 => self.load_args_and_call_zig(backend, bitcode::NUM_BYTES_TO_U32),
+            NumCountLeadingZeroBits => {
+                let width = backend.storage.symbol_layouts[&self.arguments[0]].to_int_width();
+                self.load_args_and_call_zig(backend, &bitcode::NUM_COUNT_LEADING_ZERO_BITS[width])
+            }
+            NumCountTrailingZeroBits => {
+                let width = backend.storage.symbol_layouts[&self.arguments[0]].to_int_width();
+                self.load_args_and_call_zig(backend, &bitcode::NUM_COUNT_TRAILING_ZERO_BITS[width])
+            }
+            NumCountOneBits => {
+                let width = backend.storage.symbol_layouts[&self.arguments[0]].to_int_width();
+                self.load_args_and_call_zig(backend, &bitcode::NUM_COUNT_ONE_BITS[width])
+            }
+            NumF32ToBits => {
+                self.load_args(backend);
+                backend.code_builder.i32_reinterpret_f32();
+            }
+            NumF64ToBits => {
+                self.load_args(backend);
+                backend.code_builder.i64_reinterpret_f64();
+            }
+            NumF32FromBits => {
+                self.load_args(backend);
+                backend.code_builder.f32_reinterpret_i32();
+            }
+            NumF64FromBits => {
+                self.load_args(backend);
+                backend.code_builder.f64_reinterpret_i64();
+            }
             NumBitwiseAnd => {
                 self.load_args(backend);
                 match CodeGenNumType::from(self.ret_layout) {
@@ -2098,6 +2134,92 @@ impl<'a> LowLevelCall<'a> {
     }
 }
 
+/// Helper for NumIsNan op
+fn num_is_nan(backend: &mut WasmBackend<'_, '_>, argument: Symbol) {
+    use StoredValue::*;
+    let stored = backend.storage.get(&argument).to_owned();
+    match stored {
+        VirtualMachineStack { value_type, .. } | Local { value_type, .. } => {
+            backend
+                .storage
+                .load_symbols(&mut backend.code_builder, &[argument]);
+            match value_type {
+                // A NaN has all exponent bits set and a non-zero mantissa
+                ValueType::F32 => {
+                    backend.code_builder.i32_reinterpret_f32();
+                    backend.code_builder.i32_const(0x7fff_ffff);
+                    backend.code_builder.i32_and();
+                    backend.code_builder.i32_const(0x7f80_0000);
+                    backend.code_builder.i32_gt_u();
+                }
+                ValueType::F64 => {
+                    backend.code_builder.i64_reinterpret_f64();
+                    backend.code_builder.i64_const(0x7fff_ffff_ffff_ffff);
+                    backend.code_builder.i64_and();
+                    backend.code_builder.i64_const(0x7ff0_0000_0000_0000);
+                    backend.code_builder.i64_gt_u();
+                }
+                ValueType::I32 | ValueType::I64 => {
+                    internal_error!("Tried to perform NumIsNan on an integer")
+                }
+            }
+        }
+        StackMemory { format, .. } => {
+            match format {
+                // Fixed-point numbers are never NaN. Just return False.
+                StackMemoryFormat::Decimal => backend.code_builder.i32_const(0),
+
+                StackMemoryFormat::Int128 | StackMemoryFormat::DataStructure => {
+                    internal_error!("Tried to perform NumIsNan on {:?}", format)
+                }
+            }
+        }
+    }
+}
+
+/// Helper for NumIsInfinite op
+fn num_is_infinite(backend: &mut WasmBackend<'_, '_>, argument: Symbol) {
+    use StoredValue::*;
+    let stored = backend.storage.get(&argument).to_owned();
+    match stored {
+        VirtualMachineStack { value_type, .. } | Local { value_type, .. } => {
+            backend
+                .storage
+                .load_symbols(&mut backend.code_builder, &[argument]);
+            match value_type {
+                // An infinity has all exponent bits set and a zero mantissa
+                ValueType::F32 => {
+                    backend.code_builder.i32_reinterpret_f32();
+                    backend.code_builder.i32_const(0x7fff_ffff);
+                    backend.code_builder.i32_and();
+                    backend.code_builder.i32_const(0x7f80_0000);
+                    backend.code_builder.i32_eq();
+                }
+                ValueType::F64 => {
+                    backend.code_builder.i64_reinterpret_f64();
+                    backend.code_builder.i64_const(0x7fff_ffff_ffff_ffff);
+                    backend.code_builder.i64_and();
+                    backend.code_builder.i64_const(0x7ff0_0000_0000_0000);
+                    backend.code_builder.i64_eq();
+                }
+                ValueType::I32 | ValueType::I64 => {
+                    internal_error!("Tried to perform NumIsInfinite on an integer")
+                }
+            }
+        }
+        StackMemory { format, .. } => {
+            match format {
+                // Fixed-point numbers are never infinite. Just return False.
+                StackMemoryFormat::Decimal => backend.code_builder.i32_const(0),
+
+                StackMemoryFormat::Int128 | StackMemoryFormat::DataStructure => {
+                    internal_error!("Tried to perform NumIsInfinite on {:?}", format)
+                }
+            }
+        }
+    }
+}
+
 /// Helper for NumIsFinite op, and also part of Eq/NotEq
 fn num_is_finite(backend: &mut WasmBackend<'_, '_>, argument: Symbol) {
     use StoredValue::*;
@@ -25,6 +25,8 @@ const MODULES: &[(ModuleId, &str)] = &[
     (ModuleId::ENCODE, "Encode.roc"),
     (ModuleId::DECODE, "Decode.roc"),
     (ModuleId::HASH, "Hash.roc"),
+    (ModuleId::INSPECT, "Inspect.roc"),
+    (ModuleId::SORT, "Sort.roc"),
     (ModuleId::JSON, "Json.roc"),
 ];
 
@@ -18,25 +18,34 @@ const SKIP_SUBS_CACHE: bool = {
 
 pub use roc_load_internal::docs;
 pub use roc_load_internal::file::{
-    EntryPoint, ExecutionMode, ExpectMetadata, Expectations, LoadConfig, LoadResult, LoadStart,
-    LoadedModule, LoadingProblem, MonomorphizedModule, Phase, Threading,
+    report_bytes, EntryPoint, ExecutionMode, ExpectMetadata, Expectations, LoadConfig, LoadResult,
+    LoadStart, LoadedModule, LoadingProblem, MonomorphizedModule, OptLevel, Phase, Threading,
 };
 
+/// The builtins' types, which every load starts from. A load uses them up, so commands which
+/// load over and over (like `roc check --watch`) can deserialize the next load's while they wait.
+pub struct CachedTypes(MutMap<ModuleId, TypeState>);
+
+impl CachedTypes {
+    pub fn deserialize() -> Self {
+        CachedTypes(read_cached_types())
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn load<'a>(
     arena: &'a Bump,
     load_start: LoadStart<'a>,
     exposed_types: ExposedByModule,
+    cached_types: CachedTypes,
     roc_cache_dir: RocCacheDir<'_>,
     load_config: LoadConfig,
 ) -> Result<LoadResult<'a>, LoadingProblem<'a>> {
-    let cached_types = read_cached_types();
-
     roc_load_internal::file::load(
         arena,
         load_start,
         exposed_types,
-        cached_types,
+        cached_types.0,
         roc_cache_dir,
         load_config,
     )
@@ -52,6 +61,7 @@ pub fn load_single_threaded<'a>(
     palette: Palette,
     roc_cache_dir: RocCacheDir<'_>,
     exec_mode: ExecutionMode,
+    opt_level: OptLevel,
 ) -> Result<LoadResult<'a>, LoadingProblem<'a>> {
     let cached_subs = read_cached_types();
     let exposed_types = ExposedByModule::default();
@@ -65,6 +75,7 @@ pub fn load_single_threaded<'a>(
         render,
         palette,
         exec_mode,
+        opt_level,
         roc_cache_dir,
     )
 }
@@ -105,8 +116,16 @@ pub fn load_and_monomorphize_from_str<'a>(
 
     let load_start = LoadStart::from_str(arena, filename, src, roc_cache_dir, src_dir)?;
     let exposed_types = ExposedByModule::default();
+    let cached_types = CachedTypes::deserialize();
 
-    match load(arena, load_start, exposed_types, roc_cache_dir, load_config)? {
+    match load(
+        arena,
+        load_start,
+        exposed_types,
+        cached_types,
+        roc_cache_dir,
+        load_config,
+    )? {
         Monomorphized(module) => Ok(module),
         TypeChecked(module) => Err(LoadMonomorphizedError::ErrorModule(module)),
     }
@@ -117,6 +136,23 @@ pub fn load_and_monomorphize<'a>(
     filename: PathBuf,
     roc_cache_dir: RocCacheDir<'_>,
     load_config: LoadConfig,
+) -> Result<MonomorphizedModule<'a>, LoadMonomorphizedError<'a>> {
+    load_and_monomorphize_with_types(
+        arena,
+        filename,
+        roc_cache_dir,
+        load_config,
+        CachedTypes::deserialize(),
+    )
+}
+
+/// Like `load_and_monomorphize`, but starting from builtins' types which are already deserialized
+pub fn load_and_monomorphize_with_types<'a>(
+    arena: &'a Bump,
+    filename: PathBuf,
+    roc_cache_dir: RocCacheDir<'_>,
+    load_config: LoadConfig,
+    cached_types: CachedTypes,
 ) -> Result<MonomorphizedModule<'a>, LoadMonomorphizedError<'a>> {
     use LoadResult::*;
 
@@ -130,7 +166,14 @@ pub fn load_and_monomorphize<'a>(
 
     let exposed_types = ExposedByModule::default();
 
-    match load(arena, load_start, exposed_types, roc_cache_dir, load_config)? {
+    match load(
+        arena,
+        load_start,
+        exposed_types,
+        cached_types,
+        roc_cache_dir,
+        load_config,
+    )? {
         Monomorphized(module) => Ok(module),
         TypeChecked(module) => Err(LoadMonomorphizedError::ErrorModule(module)),
     }
@@ -141,6 +184,23 @@ pub fn load_and_typecheck<'a>(
     filename: PathBuf,
     roc_cache_dir: RocCacheDir<'_>,
     load_config: LoadConfig,
+) -> Result<LoadedModule, LoadingProblem<'a>> {
+    load_and_typecheck_with_types(
+        arena,
+        filename,
+        roc_cache_dir,
+        load_config,
+        CachedTypes::deserialize(),
+    )
+}
+
+/// Like `load_and_typecheck`, but starting from builtins' types which are already deserialized
+pub fn load_and_typecheck_with_types<'a>(
+    arena: &'a Bump,
+    filename: PathBuf,
+    roc_cache_dir: RocCacheDir<'_>,
+    load_config: LoadConfig,
+    cached_types: CachedTypes,
 ) -> Result<LoadedModule, LoadingProblem<'a>> {
     use LoadResult::*;
 
@@ -154,7 +214,14 @@ pub fn load_and_typecheck<'a>(
 
     let exposed_types = ExposedByModule::default();
 
-    match load(arena, load_start, exposed_types, roc_cache_dir, load_config)? {
+    match load(
+        arena,
+        load_start,
+        exposed_types,
+        cached_types,
+        roc_cache_dir,
+        load_config,
+    )? {
         Monomorphized(_) => unreachable!(""),
         TypeChecked(module) => Ok(module),
     }
@@ -186,6 +253,7 @@ pub fn load_and_typecheck_str<'a>(
         palette,
         roc_cache_dir,
         ExecutionMode::Check,
+        OptLevel::Normal,
     )? {
         Monomorphized(_) => unreachable!(""),
         TypeChecked(module) => Ok(module),
@@ -204,6 +272,8 @@ const BOX: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/Box.dat")) as &[_];
 const ENCODE: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/Encode.dat")) as &[_];
 const DECODE: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/Decode.dat")) as &[_];
 const HASH: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/Hash.dat")) as &[_];
+const INSPECT: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/Inspect.dat")) as &[_];
+const SORT: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/Sort.dat")) as &[_];
 
 fn deserialize_help(bytes: &[u8]) -> TypeState {
     let (state, _offset) = TypeState::deserialize(bytes);
@@ -234,6 +304,8 @@ fn read_cached_types() -> MutMap<ModuleId, TypeState> {
         output.insert(ModuleId::DECODE, deserialize_help(DECODE));
 
         output.insert(ModuleId::HASH, deserialize_help(HASH));
+        output.insert(ModuleId::INSPECT, deserialize_help(INSPECT));
+        output.insert(ModuleId::SORT, deserialize_help(SORT));
     }
 
     output
@@ -0,0 +1,250 @@
+//! Turns the `>>>` examples in doc comments into top-level `expect`s, so that
+//! `roc test --doctests` can check that they still produce the documented results.
+//!
+//! An example is a run of consecutive `## >>>` lines, followed directly by a doc comment
+//! line containing the expected result:
+//!
+//! ```text
+//! ## >>> Result.withDefault (Ok 7) 42
+//! ## 7
+//! ```
+//!
+//! A longer expected result can continue on the following lines. It ends at the first blank
+//! line, or at a line starting with a word, so prose written right after it is not mistaken for
+//! part of the result.
+//!
+//! Examples usually refer to the module's own values qualified (like `Result.withDefault` in
+//! `Result.roc`), so those qualifiers are dropped before the example is compiled in the
+//! module's scope. Examples without an expected result are only there for illustration, so
+//! they are skipped, as are examples which don't parse.
+use bumpalo::Bump;
+use roc_module::called_via::BinOp;
+use roc_parse::ast::{CommentOrNewline, Defs, Expr, ValueDef};
+use roc_parse::state::State;
+use roc_region::all::{LineInfo, Loc, Position, Region};
+
+const EXAMPLE_PREFIX: &str = ">>>";
+
+/// A `##` comment line, along with where its text begins in the module's source.
+#[derive(Debug, Clone, Copy)]
+struct DocLine<'a> {
+    offset: usize,
+    line: u32,
+    text: &'a str,
+}
+
+impl<'a> DocLine<'a> {
+    fn is_example(&self) -> bool {
+        self.text.starts_with(EXAMPLE_PREFIX)
+    }
+
+    fn is_prose(&self) -> bool {
+        self.text.starts_with(char::is_alphabetic)
+    }
+
+    fn end(&self) -> usize {
+        self.offset + self.text.len()
+    }
+}
+
+/// Append an `expect` to the given defs for every doc comment example which has an expected
+/// result. The `expect`'s regions point into the doc comment, so failures are reported there.
+pub(crate) fn add_doctests<'a>(
+    arena: &'a Bump,
+    src: &'a str,
+    module_name: Option<&str>,
+    defs: &mut Defs<'a>,
+) {
+    let lines = doc_lines(src, defs);
+    let mut index = 0;
+
+    while index < lines.len() {
+        if !lines[index].is_example() {
+            index += 1;
+            continue;
+        }
+
+        let first = index;
+
+        while index + 1 < lines.len()
+            && lines[index + 1].is_example()
+            && lines[index + 1].line == lines[index].line + 1
+        {
+            index += 1;
+        }
+
+        let last = index;
+
+        index += 1;
+
+        let expected = expected_lines(&lines[index..], lines[last].line);
+
+        if expected.is_empty() {
+            // No expected result; this example is only for illustration.
+            continue;
+        }
+
+        index += expected.len();
+
+        if let Some((condition, region)) =
+            example_to_condition(arena, src, module_name, &lines[first..=last], &expected)
+        {
+            let value_def = ValueDef::Expect {
+                condition: arena.alloc(condition),
+                preceding_comment: region,
+            };
+
+            defs.push_value_def(value_def, region, &[], &[]);
+        }
+    }
+}
+
+/// The lines of an example's expected result, which start on the line right after the example.
+/// A result can continue on further lines; it ends at the first blank line, the next example,
+/// or a line of prose, which starts with a word rather than being indented like code.
+fn expected_lines<'a>(lines: &[DocLine<'a>], example_end: u32) -> Vec<DocLine<'a>> {
+    let mut expected: Vec<DocLine<'a>> = Vec::new();
+
+    for line in lines {
+        let previous = expected.last().map_or(example_end, |line| line.line);
+
+        if line.line != previous + 1
+            || line.is_example()
+            || line.text.trim().is_empty()
+            || (!expected.is_empty() && line.is_prose())
+        {
+            break;
+        }
+
+        expected.push(*line);
+    }
+
+    expected
+}
+
+/// All the `##` comments among the module's top-level defs, in source order.
+fn doc_lines<'a>(src: &'a str, defs: &Defs<'a>) -> Vec<DocLine<'a>> {
+    let line_info = LineInfo::new(src);
+    let src_start = src.as_ptr() as usize;
+    let src_end = src_start + src.len();
+
+    let mut lines: Vec<DocLine<'a>> = defs
+        .spaces
+        .iter()
+        .filter_map(|space| match space {
+            CommentOrNewline::DocComment(text) => {
+                // Doc comments are slices of the module's source, so their address tells us
+                // where in the file they are.
+                let address = text.as_ptr() as usize;
+
+                if (src_start..=src_end).contains(&address) {
+                    let offset = address - src_start;
+                    let line = line_info.convert_offset(offset as u32).line;
+
+                    Some(DocLine { offset, line, text })
+                } else {
+                    None
+                }
+            }
+            CommentOrNewline::Newline | CommentOrNewline::LineComment(_) => None,
+        })
+        .collect();
+
+    lines.sort_by_key(|line| line.offset);
+    lines.dedup_by_key(|line| line.offset);
+
+    lines
+}
+
+/// Parse the example and its expected result into `actual == expected`, along with the region
+/// of the whole example in the doc comment.
+fn example_to_condition<'a>(
+    arena: &'a Bump,
+    src: &'a str,
+    module_name: Option<&str>,
+    example: &[DocLine<'a>],
+    expected: &[DocLine<'a>],
+) -> Option<(Loc<Expr<'a>>, Region)> {
+    let actual = parse_masked(
+        arena,
+        src,
+        module_name,
+        example.iter().map(|line| {
+            let start = line.offset + EXAMPLE_PREFIX.len();
+
+            (start, line.end())
+        }),
+    )?;
+    let expected_expr = parse_masked(
+        arena,
+        src,
+        module_name,
+        expected.iter().map(|line| (line.offset, line.end())),
+    )?;
+
+    let region = Region::new(
+        Position::new(example[0].offset as u32),
+        Position::new(expected[expected.len() - 1].end() as u32),
+    );
+    let op_region = Region::new(expected_expr.region.start(), expected_expr.region.start());
+    let condition_region = Region::span_across(&actual.region, &expected_expr.region);
+
+    let condition = Expr::BinOps(
+        arena.alloc([(actual, Loc::at(op_region, BinOp::Equals))]),
+        arena.alloc(expected_expr),
+    );
+
+    Some((Loc::at(condition_region, condition), region))
+}
+
+/// Parse the code in the given byte ranges of the source as a single expression.
+///
+/// Everything outside those ranges (such as the `## >>>` prefixes) is replaced by spaces, which
+/// keeps every byte of the code at its original offset. That way the parsed expression's
+/// regions line up with the module's source, and reports can show the doc comment itself.
+fn parse_masked<'a>(
+    arena: &'a Bump,
+    src: &'a str,
+    module_name: Option<&str>,
+    ranges: impl Iterator<Item = (usize, usize)>,
+) -> Option<Loc<Expr<'a>>> {
+    let mut masked: Vec<u8> = src
+        .bytes()
+        .map(|byte| if byte == b'\n' { b'\n' } else { b' ' })
+        .collect();
+
+    for (start, end) in ranges {
+        masked[start..end].copy_from_slice(&src.as_bytes()[start..end]);
+
+        if let Some(module_name) = module_name {
+            unqualify_own_values(&mut masked[start..end], module_name);
+        }
+    }
+
+    let masked = arena.alloc_slice_copy(&masked);
+
+    roc_parse::expr::test_parse_expr(0, arena, State::new(masked)).ok()
+}
+
+/// Replace `ModuleName.` with spaces wherever it qualifies a lowercase name, so that
+/// `Result.withDefault` in `Result.roc` refers to the `withDefault` in scope.
+fn unqualify_own_values(code: &mut [u8], module_name: &str) {
+    let qualifier = format!("{}.", module_name);
+    let qualifier = qualifier.as_bytes();
+    let is_ident_byte = |byte: u8| byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'.';
+    let mut index = 0;
+
+    while index + qualifier.len() < code.len() {
+        let rest = &code[index..];
+
+        if rest.starts_with(qualifier)
+            && rest[qualifier.len()].is_ascii_lowercase()
+            && (index == 0 || !is_ident_byte(code[index - 1]))
+        {
+            code[index..index + qualifier.len()].fill(b' ');
+            index += qualifier.len();
+        } else {
+            index += 1;
+        }
+    }
+}
@@ -19,8 +19,8 @@ use roc_constrain::module::constrain_module;
 use roc_debug_flags::dbg_do;
 #[cfg(debug_assertions)]
 use roc_debug_flags::{
-    ROC_CHECK_MONO_IR, ROC_PRINT_IR_AFTER_REFCOUNT, ROC_PRINT_IR_AFTER_RESET_REUSE,
-    ROC_PRINT_IR_AFTER_SPECIALIZATION, ROC_PRINT_LOAD_LOG,
+    ROC_CHECK_MONO_IR, ROC_PRINT_IR_AFTER_INLINING, ROC_PRINT_IR_AFTER_REFCOUNT,
+    ROC_PRINT_IR_AFTER_RESET_REUSE, ROC_PRINT_IR_AFTER_SPECIALIZATION, ROC_PRINT_LOAD_LOG,
 };
 use roc_derive::SharedDerivedModule;
 use roc_error_macros::internal_error;
@@ -73,6 +73,7 @@ use {
 
 pub use crate::work::Phase;
 use crate::work::{DepCycle, Dependencies};
+pub use roc_mono::ir::OptLevel;
 
 #[cfg(target_family = "wasm")]
 use crate::wasm_instant::{Duration, Instant};
@@ -101,6 +102,8 @@ pub struct LoadConfig {
     pub palette: Palette,
     pub threading: Threading,
     pub exec_mode: ExecutionMode,
+    /// Development and optimized builds inline small procs and fold constants in the mono IR
+    pub opt_level: OptLevel,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -112,20 +115,31 @@ pub enum ExecutionMode {
     /// Test is like [`ExecutionMode::ExecutableIfCheck`], but rather than producing a proper
     /// executable, run tests.
     Test,
+    /// Like [`ExecutionMode::Test`], but also turns the `>>>` examples in doc comments into
+    /// top-level expects.
+    TestWithDoctests,
 }
 
 impl ExecutionMode {
     fn goal_phase(&self) -> Phase {
         match self {
             ExecutionMode::Executable => Phase::MakeSpecializations,
-            ExecutionMode::Check | ExecutionMode::ExecutableIfCheck | ExecutionMode::Test => {
-                Phase::SolveTypes
-            }
+            ExecutionMode::Check
+            | ExecutionMode::ExecutableIfCheck
+            | ExecutionMode::Test
+            | ExecutionMode::TestWithDoctests => Phase::SolveTypes,
         }
     }
 
     fn build_if_checks(&self) -> bool {
-        matches!(self, Self::ExecutableIfCheck | Self::Test)
+        matches!(
+            self,
+            Self::ExecutableIfCheck | Self::Test | Self::TestWithDoctests
+        )
+    }
+
+    fn is_test(&self) -> bool {
+        matches!(self, Self::Test | Self::TestWithDoctests)
     }
 }
 
@@ -151,6 +165,9 @@ struct ModuleCache<'a> {
     documentation: VecMap<ModuleId, ModuleDocumentation>,
     can_problems: MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
     type_problems: MutMap<ModuleId, Vec<TypeError>>,
+    /// Syntax errors the parser recovered from, so that the rest of the module could still be
+    /// canonicalized and type checked
+    syntax_errors: MutMap<ModuleId, Vec<SyntaxError<'a>>>,
 
     sources: MutMap<ModuleId, (PathBuf, &'a str)>,
 }
@@ -171,7 +188,7 @@ impl<'a> ModuleCache<'a> {
     }
 
     pub fn has_errors(&self) -> bool {
-        self.has_can_errors() || self.has_type_errors()
+        !self.syntax_errors.is_empty() || self.has_can_errors() || self.has_type_errors()
     }
 }
 
@@ -200,6 +217,8 @@ impl Default for ModuleCache<'_> {
             ENCODE,
             DECODE,
             HASH,
+            INSPECT,
+            SORT,
             JSON,
         }
 
@@ -219,6 +238,7 @@ impl Default for ModuleCache<'_> {
             documentation: Default::default(),
             can_problems: Default::default(),
             type_problems: Default::default(),
+            syntax_errors: Default::default(),
             sources: Default::default(),
         }
     }
@@ -278,7 +298,11 @@ fn start_phase<'a>(
                 // parse the file
                 let header = state.module_cache.headers.remove(&module_id).unwrap();
 
-                BuildTask::Parse { header }
+                // Only the examples in the user's own modules are run, not the builtins' ones.
+                let doctests = matches!(state.exec_mode, ExecutionMode::TestWithDoctests)
+                    && !module_id.is_builtin();
+
+                BuildTask::Parse { header, doctests }
             }
             Phase::CanonicalizeAndConstrain => {
                 // canonicalize the file
@@ -457,8 +481,7 @@ fn start_phase<'a>(
 
                 let derived_module = SharedDerivedModule::clone(&state.derived_module);
 
-                let build_expects =
-                    matches!(state.exec_mode, ExecutionMode::Test) && expectations.is_some();
+                let build_expects = state.exec_mode.is_test() && expectations.is_some();
 
                 BuildTask::BuildPendingSpecializations {
                     layout_cache,
@@ -514,7 +537,10 @@ fn start_phase<'a>(
                         None, // no expectations for derived module
                         ProcsBase::default(),
                         LayoutCache::new(state.layout_interner.fork(), state.target_info),
-                        ModuleTiming::new(Instant::now()),
+                        ModuleTiming {
+                            trace_name: "#Derived_gen".to_string(),
+                            ..ModuleTiming::new(Instant::now())
+                        },
                     )
                 } else if state.make_specializations_pass.current_pass() == 1 {
                     let found_specializations = state
@@ -635,6 +661,9 @@ pub struct LoadedModule {
     pub sources: MutMap<ModuleId, (PathBuf, Box<str>)>,
     pub timings: MutMap<ModuleId, ModuleTiming>,
     pub docs_by_module: VecMap<ModuleId, ModuleDocumentation>,
+    /// For modules from another package, the name that package declares in its header,
+    /// e.g. `json` for a module from `package "json"`
+    pub package_names: MutMap<ModuleId, String>,
     pub abilities_store: AbilitiesStore,
 }
 
@@ -765,6 +794,8 @@ pub struct MonomorphizedModule<'a> {
     pub timings: MutMap<ModuleId, ModuleTiming>,
     pub expectations: VecMap<ModuleId, Expectations>,
     pub uses_prebuilt_platform: bool,
+    /// The root module's canonical declarations, e.g. for `roc build --emit=can`
+    pub declarations_by_id: MutMap<ModuleId, Declarations>,
 }
 
 /// Values used to render expect output
@@ -827,6 +858,7 @@ struct ParsedModule<'a> {
     exposed_ident_ids: IdentIds,
     exposed_imports: MutMap<Ident, (Symbol, Region)>,
     parsed_defs: Defs<'a>,
+    syntax_errors: Vec<SyntaxError<'a>>,
     symbols_from_requires: Vec<(Loc<Symbol>, Loc<TypeAnnotation<'a>>)>,
     header_type: HeaderType<'a>,
     header_comments: &'a [CommentOrNewline<'a>],
@@ -875,6 +907,7 @@ enum Msg<'a> {
         abilities_store: AbilitiesStore,
         toplevel_expects: ToplevelExpects,
         expectations: Option<Expectations>,
+        decls: Declarations,
     },
     MadeSpecializations {
         module_id: ModuleId,
@@ -987,6 +1020,8 @@ struct State<'a> {
     /// From now on, these will be used by multiple threads; time to make an Arc<Mutex<_>>!
     pub arc_modules: Arc<Mutex<PackageModuleIds<'a>>>,
     pub arc_shorthands: Arc<Mutex<MutMap<&'a str, ShorthandPath>>>,
+    /// The name each package declares in its header, by the shorthand it was imported under
+    pub package_names: MutMap<&'a str, &'a str>,
     #[allow(unused)]
     pub derived_module: SharedDerivedModule,
 
@@ -1008,6 +1043,7 @@ struct State<'a> {
     pub render: RenderTarget,
     pub palette: Palette,
     pub exec_mode: ExecutionMode,
+    pub opt_level: OptLevel,
 
     /// All abilities across all modules.
     pub world_abilities: WorldAbilities,
@@ -1039,6 +1075,7 @@ impl<'a> State<'a> {
         palette: Palette,
         number_of_workers: usize,
         exec_mode: ExecutionMode,
+        opt_level: OptLevel,
     ) -> Self {
         let arc_shorthands = Arc::new(Mutex::new(MutMap::default()));
         let cache_dir = roc_packaging::cache::roc_cache_dir();
@@ -1062,6 +1099,7 @@ impl<'a> State<'a> {
             exposed_types,
             arc_modules,
             arc_shorthands,
+            package_names: MutMap::default(),
             derived_module: Default::default(),
             constrained_ident_ids: IdentIds::exposed_builtins(0),
             ident_ids_by_module,
@@ -1073,6 +1111,7 @@ impl<'a> State<'a> {
             render,
             palette,
             exec_mode,
+            opt_level,
             make_specializations_pass: MakeSpecializationsPass::Pass(1),
             world_abilities: Default::default(),
             layout_interner: GlobalLayoutInterner::with_capacity(128, target_info),
@@ -1096,6 +1135,32 @@ pub struct ModuleTiming {
     /// to things like state lookups in between phases, waiting on other threads, etc.
     start_time: Instant,
     end_time: Instant,
+    /// How this module is labeled in `--time=<file>` traces. Empty until its header is parsed.
+    trace_name: String,
+    /// Not a timing, but it's recorded at the same points in each phase
+    pub memory: ModuleMemory,
+}
+
+/// How much memory a module used in each phase, for `--mem-stats`.
+///
+/// The arena figures are how many bytes the phase allocated in its thread's arena. Nothing in
+/// those arenas is freed until loading is done, so together they make up the arenas' high-water
+/// marks.
+#[derive(Debug, Default)]
+pub struct ModuleMemory {
+    pub parse_body: usize,
+    pub canonicalize: usize,
+    pub find_specializations: usize,
+    // indexed by make specializations pass
+    pub make_specializations: Vec<usize>,
+    /// The size of the module's `Subs` once it's solved
+    pub subs: usize,
+}
+
+/// How many bytes of the arena are in use. This counts the end of a chunk that was too small
+/// for the next allocation as used, which is fine for a high-water mark.
+fn arena_bytes_used(arena: &Bump) -> usize {
+    arena.allocated_bytes() - arena.chunk_capacity()
 }
 
 impl ModuleTiming {
@@ -1111,9 +1176,26 @@ impl ModuleTiming {
             make_specializations: Vec::with_capacity(2),
             start_time,
             end_time: start_time, // just for now; we'll overwrite this at the end
+            trace_name: String::new(),
+            memory: ModuleMemory::default(),
         }
     }
 
+    /// Records a phase this module spent from `start` to `end` in, for `--time=<file>` traces
+    fn trace(&self, phase: &str, start: Instant, end: Instant) {
+        roc_tracing::chrome_trace::record(phase, Some(&self.trace_name), start, end);
+    }
+
+    /// Reading the file and parsing the header happen before we know the module's name,
+    /// so they're traced afterwards, from the durations recorded at the time.
+    fn trace_header(&self) {
+        let read_end = self.start_time + self.read_roc_file;
+        let parse_end = read_end + self.parse_header;
+
+        self.trace("read .roc file", self.start_time, read_end);
+        self.trace("parse header", read_end, parse_end);
+    }
+
     pub fn total(&self) -> Duration {
         self.end_time.duration_since(self.start_time)
     }
@@ -1131,6 +1213,8 @@ impl ModuleTiming {
             make_specializations,
             start_time,
             end_time,
+            trace_name: _,
+            memory: _,
         } = self;
 
         let calculate = |d: Option<Duration>| -> Option<Duration> {
@@ -1150,6 +1234,11 @@ impl ModuleTiming {
     }
 }
 
+/// Mono passes run over every module's procs at once, so they aren't traced per module
+fn trace_mono_pass(pass: &str, start: Instant) {
+    roc_tracing::chrome_trace::record(pass, None, start, Instant::now());
+}
+
 fn report_timing(
     buf: &mut impl std::fmt::Write,
     label: &str,
@@ -1163,6 +1252,40 @@ fn report_timing(
     )
 }
 
+/// Writes a line of `--mem-stats` output
+pub fn report_bytes(buf: &mut impl std::fmt::Write, label: &str, bytes: usize) -> std::fmt::Result {
+    writeln!(
+        buf,
+        "        {:9.3} MiB  {}",
+        bytes as f64 / (1024.0 * 1024.0),
+        label,
+    )
+}
+
+impl std::fmt::Display for ModuleMemory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        report_bytes(f, "Parse body (arena)", self.parse_body)?;
+        report_bytes(f, "Canonicalize (arena)", self.canonicalize)?;
+        report_bytes(f, "Subs", self.subs)?;
+        report_bytes(f, "Find Specializations (arena)", self.find_specializations)?;
+        let multiple_make_specializations_passes = self.make_specializations.len() > 1;
+        for (i, pass_bytes) in self.make_specializations.iter().enumerate() {
+            let suffix = if multiple_make_specializations_passes {
+                format!(" (Pass {})", i)
+            } else {
+                String::new()
+            };
+            report_bytes(
+                f,
+                &format!("Make Specializations{} (arena)", suffix),
+                *pass_bytes,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
 impl std::fmt::Display for ModuleTiming {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let module_timing = self;
@@ -1207,6 +1330,7 @@ enum BuildTask<'a> {
     },
     Parse {
         header: ModuleHeader<'a>,
+        doctests: bool,
     },
     CanonicalizeAndConstrain {
         parsed: ParsedModule<'a>,
@@ -1349,6 +1473,7 @@ pub fn load_and_typecheck_str<'a>(
         palette,
         threading,
         exec_mode: ExecutionMode::Check,
+        opt_level: OptLevel::Normal,
     };
 
     match load(
@@ -1608,6 +1733,7 @@ pub fn load<'a>(
             load_config.render,
             load_config.palette,
             load_config.exec_mode,
+            load_config.opt_level,
             roc_cache_dir,
         ),
         Threads::Many(threads) => load_multi_threaded(
@@ -1620,6 +1746,7 @@ pub fn load<'a>(
             load_config.palette,
             threads,
             load_config.exec_mode,
+            load_config.opt_level,
             roc_cache_dir,
         ),
     }
@@ -1635,6 +1762,7 @@ pub fn load_single_threaded<'a>(
     render: RenderTarget,
     palette: Palette,
     exec_mode: ExecutionMode,
+    opt_level: OptLevel,
     roc_cache_dir: RocCacheDir<'_>,
 ) -> Result<LoadResult<'a>, LoadingProblem<'a>> {
     let LoadStart {
@@ -1666,6 +1794,7 @@ pub fn load_single_threaded<'a>(
         palette,
         number_of_workers,
         exec_mode,
+        opt_level,
     );
 
     // We'll add tasks to this, and then worker threads will take tasks from it.
@@ -1722,7 +1851,7 @@ pub fn load_single_threaded<'a>(
 
 fn state_thread_step<'a>(
     arena: &'a Bump,
-    state: State<'a>,
+    mut state: State<'a>,
     src_dir: &Path,
     worker_listeners: &'a [Sender<WorkerMsg>],
     injector: &Injector<BuildTask<'a>>,
@@ -1750,6 +1879,9 @@ fn state_thread_step<'a>(
                         .map(|(k, (_, v))| (k, v))
                         .collect();
 
+                    let syntax_errors = std::mem::take(&mut state.module_cache.syntax_errors);
+                    let (render, palette) = (state.render, state.palette);
+
                     let typechecked = finish(
                         state,
                         solved_subs,
@@ -1762,6 +1894,20 @@ fn state_thread_step<'a>(
                         abilities_store,
                     );
 
+                    if !syntax_errors.is_empty() {
+                        let buf = to_syntax_problems_report(
+                            syntax_errors,
+                            &typechecked.sources,
+                            &typechecked.interns,
+                            &typechecked.can_problems,
+                            &typechecked.type_problems,
+                            render,
+                            palette,
+                        );
+
+                        return Err(LoadingProblem::FormattedReport(buf));
+                    }
+
                     Ok(ControlFlow::Break(LoadResult::TypeChecked(typechecked)))
                 }
                 Msg::FinishedAllSpecialization {
@@ -1773,6 +1919,9 @@ fn state_thread_step<'a>(
                     // We're done! There should be no more messages pending.
                     debug_assert!(msg_rx.is_empty());
 
+                    let syntax_errors = std::mem::take(&mut state.module_cache.syntax_errors);
+                    let (render, palette) = (state.render, state.palette);
+
                     let monomorphized = finish_specialization(
                         arena,
                         state,
@@ -1782,6 +1931,20 @@ fn state_thread_step<'a>(
                         module_expectations,
                     )?;
 
+                    if !syntax_errors.is_empty() {
+                        let buf = to_syntax_problems_report(
+                            syntax_errors,
+                            &monomorphized.sources,
+                            &monomorphized.interns,
+                            &monomorphized.can_problems,
+                            &monomorphized.type_problems,
+                            render,
+                            palette,
+                        );
+
+                        return Err(LoadingProblem::FormattedReport(buf));
+                    }
+
                     Ok(ControlFlow::Break(LoadResult::Monomorphized(monomorphized)))
                 }
                 Msg::FailedToReadFile { filename, error } => {
@@ -1948,6 +2111,7 @@ fn load_multi_threaded<'a>(
     palette: Palette,
     available_threads: usize,
     exec_mode: ExecutionMode,
+    opt_level: OptLevel,
     roc_cache_dir: RocCacheDir<'_>,
 ) -> Result<LoadResult<'a>, LoadingProblem<'a>> {
     let LoadStart {
@@ -1994,6 +2158,7 @@ fn load_multi_threaded<'a>(
         palette,
         num_workers,
         exec_mode,
+        opt_level,
     );
 
     // an arena for every worker, stored in an arena-allocated bumpalo vec to make the lifetimes work
@@ -2027,7 +2192,7 @@ fn load_multi_threaded<'a>(
             let mut worker_listeners =
                 bumpalo::collections::Vec::with_capacity_in(num_workers, arena);
 
-            for worker_arena in it {
+            for (index, worker_arena) in it.enumerate() {
                 let msg_tx = msg_tx.clone();
                 let worker = worker_queues.pop().unwrap();
 
@@ -2043,6 +2208,7 @@ fn load_multi_threaded<'a>(
                 // Record this thread's handle so the main thread can join it later.
                 let res_join_handle = thread_scope
                     .builder()
+                    .name(format!("load worker {}", index))
                     .stack_size(EXPANDED_STACK_SIZE)
                     .spawn(move |_| {
                         // will process messages until we run out
@@ -2227,7 +2393,7 @@ fn worker_task<'a>(
                             BuildTask::LoadModule { module_name, .. } => {
                                 format!("BuildTask::LoadModule({:?})", module_name)
                             }
-                            BuildTask::Parse { header } => {
+                            BuildTask::Parse { header, .. } => {
                                 format!("BuildTask::Parse({})", header.module_path.display())
                             }
                             BuildTask::CanonicalizeAndConstrain { parsed, .. } => format!(
@@ -2494,17 +2660,21 @@ fn update<'a>(
                         state.platform_path = PlatformPath::Valid(to_platform);
                     }
                     Package {
+                        name,
                         config_shorthand,
                         exposes_ids,
                         ..
                     } => {
                         if header.is_root_module {
                             state.exposed_modules = exposes_ids;
+                        } else {
+                            state.package_names.insert(config_shorthand, name.as_str());
                         }
 
                         work.extend(state.dependencies.notify_package(config_shorthand));
                     }
                     Platform {
+                        name,
                         config_shorthand,
                         provides,
                         exposes_ids,
@@ -2512,6 +2682,10 @@ fn update<'a>(
                     } => {
                         work.extend(state.dependencies.notify_package(config_shorthand));
 
+                        if !header.is_root_module {
+                            state.package_names.insert(config_shorthand, name.as_str());
+                        }
+
                         let is_prebuilt = if header.is_root_module {
                             debug_assert!(matches!(
                                 state.platform_path,
@@ -2605,6 +2779,8 @@ fn update<'a>(
                 extend_header_with_builtin(header, ModuleId::ENCODE);
                 extend_header_with_builtin(header, ModuleId::DECODE);
                 extend_header_with_builtin(header, ModuleId::HASH);
+                extend_header_with_builtin(header, ModuleId::INSPECT);
+                extend_header_with_builtin(header, ModuleId::SORT);
             }
 
             state
@@ -2647,12 +2823,21 @@ fn update<'a>(
 
             Ok(state)
         }
-        Parsed(parsed) => {
+        Parsed(mut parsed) => {
             state
                 .module_cache
                 .sources
                 .insert(parsed.module_id, (parsed.module_path.clone(), parsed.src));
 
+            if !parsed.syntax_errors.is_empty() {
+                let syntax_errors = std::mem::take(&mut parsed.syntax_errors);
+
+                state
+                    .module_cache
+                    .syntax_errors
+                    .insert(parsed.module_id, syntax_errors);
+            }
+
             // If this was an app module, set the output path to be
             // the module's declared "name".
             //
@@ -2772,7 +2957,7 @@ fn update<'a>(
 
             let add_to_host_exposed = is_host_exposed &&
                 // During testing, we don't need to expose anything to the host.
-                !matches!(state.exec_mode, ExecutionMode::Test);
+                !state.exec_mode.is_test();
 
             if add_to_host_exposed {
                 state.exposed_to_host.values.extend(
@@ -2913,11 +3098,18 @@ fn update<'a>(
             abilities_store,
             toplevel_expects,
             expectations,
+            decls,
         } => {
             log!("found specializations for {:?}", module_id);
 
             let subs = solved_subs.into_inner();
 
+            // Specialization only reads the declarations, so the root module's can be kept
+            // around for printing without cloning them.
+            if module_id == state.root_id {
+                state.declarations_by_id.insert(module_id, decls);
+            }
+
             state.toplevel_expects.pure.extend(toplevel_expects.pure);
             state.toplevel_expects.fx.extend(toplevel_expects.fx);
 
@@ -3090,6 +3282,32 @@ fn update<'a>(
 
                     let ident_ids = state.constrained_ident_ids.get_mut(&module_id).unwrap();
 
+                    let pass_start = Instant::now();
+                    Proc::make_mutually_tail_recursive(
+                        arena,
+                        &mut layout_interner,
+                        module_id,
+                        ident_ids,
+                        &mut state.procedures,
+                    );
+                    trace_mono_pass("merge mutually tail recursive procs", pass_start);
+
+                    if matches!(state.opt_level, OptLevel::Development | OptLevel::Optimize) {
+                        let pass_start = Instant::now();
+                        Proc::inline_and_fold(
+                            arena,
+                            &layout_interner,
+                            module_id,
+                            ident_ids,
+                            &mut update_mode_ids,
+                            &mut state.procedures,
+                        );
+                        trace_mono_pass("inline and fold", pass_start);
+
+                        debug_print_ir!(state, &layout_interner, ROC_PRINT_IR_AFTER_INLINING);
+                    }
+
+                    let pass_start = Instant::now();
                     Proc::insert_reset_reuse_operations(
                         arena,
                         &mut layout_interner,
@@ -3098,6 +3316,7 @@ fn update<'a>(
                         &mut update_mode_ids,
                         &mut state.procedures,
                     );
+                    trace_mono_pass("insert reset/reuse", pass_start);
 
                     debug_print_ir!(state, &layout_interner, ROC_PRINT_IR_AFTER_RESET_REUSE);
 
@@ -3106,6 +3325,7 @@ fn update<'a>(
                         arena,
                     );
 
+                    let pass_start = Instant::now();
                     Proc::insert_refcount_operations(
                         arena,
                         &layout_interner,
@@ -3115,6 +3335,7 @@ fn update<'a>(
                         &mut state.procedures,
                         &host_exposed_procs,
                     );
+                    trace_mono_pass("insert refcounts", pass_start);
 
                     debug_print_ir!(state, &layout_interner, ROC_PRINT_IR_AFTER_REFCOUNT);
 
@@ -3311,6 +3532,7 @@ fn finish_specialization<'a>(
         platform_path,
         platform_data,
         exec_mode,
+        declarations_by_id,
         ..
     } = state;
 
@@ -3328,7 +3550,7 @@ fn finish_specialization<'a>(
 
     let entry_point = {
         match exec_mode {
-            ExecutionMode::Test => EntryPoint::Test,
+            ExecutionMode::Test | ExecutionMode::TestWithDoctests => EntryPoint::Test,
             ExecutionMode::Executable | ExecutionMode::ExecutableIfCheck => {
                 use PlatformPath::*;
 
@@ -3417,6 +3639,7 @@ fn finish_specialization<'a>(
         timings: state.timings,
         toplevel_expects,
         uses_prebuilt_platform,
+        declarations_by_id,
     })
 }
 
@@ -3449,10 +3672,20 @@ fn finish(
     documentation: VecMap<ModuleId, ModuleDocumentation>,
     abilities_store: AbilitiesStore,
 ) -> LoadedModule {
-    let module_ids = Arc::try_unwrap(state.arc_modules)
+    let package_module_ids = Arc::try_unwrap(state.arc_modules)
         .unwrap_or_else(|_| panic!("There were still outstanding Arc references to module_ids"))
-        .into_inner()
-        .into_module_ids();
+        .into_inner();
+
+    let package_names = package_module_ids
+        .package_shorthands()
+        .filter_map(|(module_id, shorthand)| {
+            let name = state.package_names.get(shorthand)?;
+
+            Some((module_id, name.to_string()))
+        })
+        .collect();
+
+    let module_ids = package_module_ids.into_module_ids();
 
     // Associate the ident IDs from the derived synth module
     let (_, derived_synth_ident_ids) = Arc::try_unwrap(state.derived_module)
@@ -3495,6 +3728,7 @@ fn finish(
         sources,
         timings: state.timings,
         docs_by_module: documentation,
+        package_names,
         abilities_store,
     }
 }
@@ -3772,6 +4006,8 @@ fn load_module<'a>(
         "Encode", ModuleId::ENCODE
         "Decode", ModuleId::DECODE
         "Hash", ModuleId::HASH
+        "Inspect", ModuleId::INSPECT
+        "Sort", ModuleId::SORT
         "Json", ModuleId::JSON
     }
 
@@ -4253,10 +4489,18 @@ fn load_packages<'a>(
                 // TODO we should do this async; however, with the current
                 // architecture of file.rs (which doesn't use async/await),
                 // this would be very difficult!
-                let (package_dir, opt_root_module) = cache::install_package(roc_cache_dir, src)
-                    .unwrap_or_else(|err| {
-                        todo!("TODO gracefully handle package install error {:?}", err);
-                    });
+                let (package_dir, opt_root_module) =
+                    match cache::install_package(roc_cache_dir, src) {
+                        Ok(installed) => installed,
+                        Err(problem) => {
+                            let report = to_package_install_report(src, &problem);
+
+                            load_messages
+                                .push(Msg::FailedToLoad(LoadingProblem::FormattedReport(report)));
+
+                            continue;
+                        }
+                    };
 
                 // You can optionally specify the root module using the URL fragment,
                 // e.g. #foo.roc
@@ -4375,7 +4619,7 @@ fn build_header<'a>(
     parse_state: roc_parse::state::State<'a>,
     module_ids: Arc<Mutex<PackageModuleIds<'a>>>,
     ident_ids_by_module: SharedIdentIdsByModule,
-    module_timing: ModuleTiming,
+    mut module_timing: ModuleTiming,
 ) -> (ModuleId, PQModuleName<'a>, ModuleHeader<'a>) {
     let HeaderInfo {
         filename,
@@ -4663,6 +4907,14 @@ fn build_header<'a>(
         }
     };
 
+    // Platform and package modules don't have names, so go by their filenames instead.
+    module_timing.trace_name = match &name {
+        _ if name.as_inner().as_str().is_empty() => filename.display().to_string(),
+        PQModuleName::Unqualified(name) => name.as_str().to_string(),
+        PQModuleName::Qualified(shorthand, name) => format!("{}.{}", shorthand, name.as_str()),
+    };
+    module_timing.trace_header();
+
     (
         home,
         name,
@@ -5173,6 +5425,8 @@ fn run_solve<'a>(
     // Record the final timings
     let solve_end = Instant::now();
     module_timing.solve = solve_end.duration_since(solve_start);
+    module_timing.trace("solve", solve_start, solve_end);
+    module_timing.memory.subs = solved_subs.inner().memory_usage();
 
     // Send the subs to the main thread for processing,
     Msg::SolvedTypes {
@@ -5223,6 +5477,7 @@ fn build_package_header<'a>(
         &ident_ids_by_module,
     );
     let header_type = HeaderType::Package {
+        name: header.name.value,
         // A config_shorthand of "" should be fine
         config_shorthand: opt_shorthand.unwrap_or_default(),
         exposes: exposes.into_bump_slice(),
@@ -5284,6 +5539,7 @@ fn build_platform_header<'a>(
     let imports = unspace(arena, header.imports.item.items);
 
     let header_type = HeaderType::Platform {
+        name: header.name.value,
         // A config_shorthand of "" should be fine
         config_shorthand: opt_shorthand.unwrap_or_default(),
         exposes_ids,
@@ -5326,6 +5582,7 @@ fn canonicalize_and_constrain<'a>(
     exposed_module_ids: &[ModuleId],
 ) -> CanAndCon {
     let canonicalize_start = Instant::now();
+    let arena_start = arena_bytes_used(arena);
 
     let ParsedModule {
         module_id,
@@ -5377,6 +5634,7 @@ fn canonicalize_and_constrain<'a>(
     let canonicalize_end = Instant::now();
 
     module_timing.canonicalize = canonicalize_end.duration_since(canonicalize_start);
+    module_timing.trace("canonicalize", canonicalize_start, canonicalize_end);
 
     // Generate documentation information
     // TODO: store timing information?
@@ -5416,6 +5674,7 @@ fn canonicalize_and_constrain<'a>(
     let _before = roc_types::types::get_type_clone_count();
 
     let mut constraints = Constraints::new();
+    let constrain_start = Instant::now();
 
     let constraint = if skip_constraint_gen {
         roc_can::constraint::Constraint::True
@@ -5430,6 +5689,12 @@ fn canonicalize_and_constrain<'a>(
         )
     };
 
+    let constrain_end = Instant::now();
+
+    module_timing.constrain = constrain_end.duration_since(constrain_start);
+    module_timing.trace("constrain", constrain_start, constrain_end);
+    module_timing.memory.canonicalize = arena_bytes_used(arena) - arena_start;
+
     // _after has an underscore because it's unused in --release builds
     let _after = roc_types::types::get_type_clone_count();
 
@@ -5462,6 +5727,8 @@ fn canonicalize_and_constrain<'a>(
                         | ModuleId::DICT
                         | ModuleId::SET
                         | ModuleId::HASH
+                        | ModuleId::INSPECT
+                        | ModuleId::SORT
                 );
 
                 if !name.is_builtin() || should_include_builtin {
@@ -5505,13 +5772,19 @@ fn canonicalize_and_constrain<'a>(
     }
 }
 
-fn parse<'a>(arena: &'a Bump, header: ModuleHeader<'a>) -> Result<Msg<'a>, LoadingProblem<'a>> {
+fn parse<'a>(
+    arena: &'a Bump,
+    header: ModuleHeader<'a>,
+    doctests: bool,
+) -> Result<Msg<'a>, LoadingProblem<'a>> {
     let mut module_timing = header.module_timing;
     let parse_start = Instant::now();
+    let arena_start = arena_bytes_used(arena);
     let source = header.parse_state.original_bytes();
     let parse_state = header.parse_state;
-    let parsed_defs = match module_defs().parse(arena, parse_state.clone(), 0) {
-        Ok((_, success, _state)) => success,
+    let defs_state = parse_state.clone().with_error_recovery();
+    let (mut parsed_defs, syntax_errors) = match module_defs().parse(arena, defs_state, 0) {
+        Ok((_, success, state)) => (success, state.recovered_errors()),
         Err((_, fail)) => {
             return Err(LoadingProblem::ParsingFailed(
                 fail.into_file_error(header.module_path, &parse_state),
@@ -5524,6 +5797,8 @@ fn parse<'a>(arena: &'a Bump, header: ModuleHeader<'a>) -> Result<Msg<'a>, Loadi
     let parse_end = Instant::now();
 
     module_timing.parse_body = parse_end.duration_since(parse_start);
+    module_timing.trace("parse body", parse_start, parse_end);
+    module_timing.memory.parse_body = arena_bytes_used(arena) - arena_start;
 
     let imported_modules = header.imported_modules;
 
@@ -5532,6 +5807,19 @@ fn parse<'a>(arena: &'a Bump, header: ModuleHeader<'a>) -> Result<Msg<'a>, Loadi
     // we'd have bailed out before now.
     let src = unsafe { from_utf8_unchecked(source) };
 
+    if doctests {
+        let module_name = match &header.header_type {
+            HeaderType::Interface { name, .. }
+            | HeaderType::Builtin { name, .. }
+            | HeaderType::Hosted { name, .. } => Some(name.as_str()),
+            HeaderType::App { .. } | HeaderType::Package { .. } | HeaderType::Platform { .. } => {
+                None
+            }
+        };
+
+        crate::doctest::add_doctests(arena, src, module_name, &mut parsed_defs);
+    }
+
     let ModuleHeader {
         module_id,
         deps_by_name,
@@ -5554,6 +5842,7 @@ fn parse<'a>(arena: &'a Bump, header: ModuleHeader<'a>) -> Result<Msg<'a>, Loadi
         exposed_ident_ids,
         exposed_imports,
         parsed_defs,
+        syntax_errors,
         symbols_from_requires,
         header_type,
         header_comments: header_docs,
@@ -5618,6 +5907,7 @@ fn make_specializations<'a>(
     mut expectations: Option<Expectations>,
 ) -> Msg<'a> {
     let make_specializations_start = Instant::now();
+    let arena_start = arena_bytes_used(arena);
     let mut update_mode_ids = UpdateModeIds::new();
     // do the thing
     let mut mono_env = roc_mono::ir::Env {
@@ -5666,6 +5956,18 @@ fn make_specializations<'a>(
     module_timing
         .make_specializations
         .push(make_specializations_end.duration_since(make_specializations_start));
+    module_timing.trace(
+        &format!(
+            "make specializations (pass {})",
+            module_timing.make_specializations.len()
+        ),
+        make_specializations_start,
+        make_specializations_end,
+    );
+    module_timing
+        .memory
+        .make_specializations
+        .push(arena_bytes_used(arena) - arena_start);
 
     Msg::MadeSpecializations {
         module_id: home,
@@ -5699,6 +6001,7 @@ fn build_pending_specializations<'a>(
     build_expects: bool,
 ) -> Msg<'a> {
     let find_specializations_start = Instant::now();
+    let arena_start = arena_bytes_used(arena);
 
     let mut module_thunks = bumpalo::collections::Vec::new_in(arena);
     let mut toplevel_expects = ToplevelExpects::default();
@@ -6117,6 +6420,12 @@ fn build_pending_specializations<'a>(
     let find_specializations_end = Instant::now();
     module_timing.find_specializations =
         find_specializations_end.duration_since(find_specializations_start);
+    module_timing.trace(
+        "find specializations",
+        find_specializations_start,
+        find_specializations_end,
+    );
+    module_timing.memory.find_specializations = arena_bytes_used(arena) - arena_start;
 
     Msg::FoundSpecializations {
         module_id: home,
@@ -6128,6 +6437,7 @@ fn build_pending_specializations<'a>(
         abilities_store,
         toplevel_expects,
         expectations,
+        decls: declarations,
     }
 }
 
@@ -6177,6 +6487,7 @@ fn load_derived_partial_procs<'a>(
     debug_assert_eq!(home, ModuleId::DERIVED_GEN);
 
     let load_derived_procs_start = Instant::now();
+    let arena_start = arena_bytes_used(arena);
 
     let mut new_module_thunks = bumpalo::collections::Vec::new_in(arena);
 
@@ -6265,6 +6576,12 @@ fn load_derived_partial_procs<'a>(
 
     module_timing.find_specializations =
         load_derived_procs_end.duration_since(load_derived_procs_start);
+    module_timing.trace(
+        "find specializations",
+        load_derived_procs_start,
+        load_derived_procs_end,
+    );
+    module_timing.memory.find_specializations = arena_bytes_used(arena) - arena_start;
 }
 
 fn run_task<'a>(
@@ -6293,7 +6610,7 @@ fn run_task<'a>(
             ident_ids_by_module,
         )
         .map(|HeaderOutput { msg, .. }| msg),
-        Parse { header } => parse(arena, header),
+        Parse { header, doctests } => parse(arena, header, doctests),
         CanonicalizeAndConstrain {
             parsed,
             module_ids,
@@ -6495,6 +6812,92 @@ fn to_file_problem_report(filename: &Path, error: io::ErrorKind) -> String {
     buf
 }
 
+#[cfg(not(target_family = "wasm"))]
+fn to_package_install_report(url: &str, problem: &roc_packaging::https::Problem) -> String {
+    use roc_packaging::https::Problem;
+    use roc_reporting::report::{Report, RocDocAllocator, DEFAULT_PALETTE};
+    use ven_pretty::DocAllocator;
+
+    let src_lines: Vec<&str> = Vec::new();
+    let mut module_ids = ModuleIds::default();
+    let module_id = module_ids.get_or_insert(&"find module name somehow?".into());
+    let interns = Interns::default();
+    let alloc = RocDocAllocator::new(&src_lines, module_id, &interns);
+
+    let download_failed = |reason: String| {
+        (
+            "PACKAGE DOWNLOAD FAILED",
+            alloc.stack([
+                alloc.reflow(r"I could not download this package:"),
+                alloc.parser_suggestion(url).indent(4),
+                alloc.string(reason),
+            ]),
+        )
+    };
+
+    let (title, doc) = match problem {
+        Problem::NotCachedWhileOffline(_) => (
+            "PACKAGE NOT CACHED",
+            alloc.stack([
+                alloc.reflow(r"I need this package, but it isn't in the cache yet:"),
+                alloc.parser_suggestion(url).indent(4),
+                alloc.concat([
+                    alloc.reflow(r"Because of "),
+                    alloc.parser_suggestion("--offline"),
+                    alloc.reflow(r", I am not allowed to download it. Run "),
+                    alloc.parser_suggestion("roc fetch"),
+                    alloc.reflow(
+                        r" while online to put every package this app needs into the cache.",
+                    ),
+                ]),
+            ]),
+        ),
+        Problem::InvalidContentHash { expected, actual } => (
+            "PACKAGE HASH MISMATCH",
+            alloc.stack([
+                alloc.reflow(r"I downloaded this package:"),
+                alloc.parser_suggestion(url).indent(4),
+                alloc.concat([
+                    alloc.reflow(r"Its URL says its hash should be "),
+                    alloc.parser_suggestion(expected),
+                    alloc.reflow(r", but the hash of what I downloaded was "),
+                    alloc.parser_suggestion(actual),
+                    alloc.reflow(r"."),
+                ]),
+                alloc.reflow(r"The package may have been tampered with, so I did not use it."),
+            ]),
+        ),
+        Problem::InvalidUrl(url_problem) => {
+            download_failed(format!("This is not a valid package URL: {url_problem:?}"))
+        }
+        Problem::DownloadTooBig(bytes) => download_failed(format!(
+            "It is {bytes} bytes, which is more than I am allowed to download."
+        )),
+        Problem::UnsupportedEncoding(encoding) => download_failed(format!(
+            "The server sent it with the {encoding} encoding, which I do not support."
+        )),
+        Problem::MultipleEncodings(encodings) => download_failed(format!(
+            "The server sent it with more than one encoding ({encodings}), which I do not support."
+        )),
+        Problem::IoErr(err) => download_failed(format!("The error was: {err}")),
+        Problem::FsExtraErr(err) => download_failed(format!("The error was: {err}")),
+        Problem::HttpErr(err) => download_failed(format!("The error was: {err}")),
+    };
+
+    let report = Report {
+        filename: "UNKNOWN.roc".into(),
+        doc,
+        title: title.to_string(),
+        severity: Severity::RuntimeError,
+    };
+
+    let mut buf = String::new();
+    let palette = DEFAULT_PALETTE;
+    report.render_color_terminal(&mut buf, &alloc, &palette);
+
+    buf
+}
+
 fn to_import_cycle_report(
     module_ids: ModuleIds,
     all_ident_ids: IdentIdsByModule,
@@ -6647,6 +7050,67 @@ fn to_parse_problem_report<'a>(
     buf
 }
 
+/// Reports every syntax error the parser recovered from, followed by the other errors found
+/// while canonicalizing and type checking the rest of the program.
+fn to_syntax_problems_report(
+    syntax_errors: MutMap<ModuleId, Vec<SyntaxError<'_>>>,
+    sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
+    interns: &Interns,
+    can_problems: &MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
+    type_problems: &MutMap<ModuleId, Vec<TypeError>>,
+    render: RenderTarget,
+    palette: Palette,
+) -> String {
+    use roc_reporting::report::{can_problem, syntax_problem, type_problem, RocDocAllocator};
+
+    let mut syntax_reports = Vec::new();
+    let mut other_reports = Vec::new();
+
+    // A report borrows its allocator for as long as it is rendered, so every report gets its own.
+    for (home, (module_path, src)) in sources.iter() {
+        let src_lines: Vec<&str> = src.split('\n').collect();
+        let lines = LineInfo::new(src);
+
+        for error in syntax_errors.get(home).into_iter().flatten() {
+            let alloc = RocDocAllocator::new(&src_lines, *home, interns);
+            let report = syntax_problem(&alloc, &lines, module_path.clone(), error);
+
+            let mut buf = String::new();
+            report.render(render, &mut buf, &alloc, &palette);
+            syntax_reports.push(buf);
+        }
+
+        for problem in can_problems.get(home).into_iter().flatten() {
+            let alloc = RocDocAllocator::new(&src_lines, *home, interns);
+            let report = can_problem(&alloc, &lines, module_path.clone(), problem.clone());
+
+            if report.severity == Severity::RuntimeError {
+                let mut buf = String::new();
+                report.render(render, &mut buf, &alloc, &palette);
+                other_reports.push(buf);
+            }
+        }
+
+        for problem in type_problems.get(home).into_iter().flatten() {
+            let alloc = RocDocAllocator::new(&src_lines, *home, interns);
+
+            let report = match type_problem(&alloc, &lines, module_path.clone(), problem.clone()) {
+                Some(report) => report,
+                None => continue,
+            };
+
+            if report.severity == Severity::RuntimeError {
+                let mut buf = String::new();
+                report.render(render, &mut buf, &alloc, &palette);
+                other_reports.push(buf);
+            }
+        }
+    }
+
+    syntax_reports.extend(other_reports);
+    syntax_reports.join("\n\n")
+}
+
 fn to_missing_platform_report(module_id: ModuleId, other: PlatformPath) -> String {
     use roc_reporting::report::{Report, RocDocAllocator, DEFAULT_PALETTE};
     use ven_pretty::DocAllocator;
@@ -6730,3 +7194,51 @@ fn to_missing_platform_report(module_id: ModuleId, other: PlatformPath) -> Strin
 
     buf
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn arena_bytes_used_grows_with_allocations() {
+        let arena = Bump::new();
+        assert_eq!(0, arena_bytes_used(&arena));
+
+        arena.alloc([0u8; 100]);
+        let used = arena_bytes_used(&arena);
+        assert!(used >= 100, "{} bytes used", used);
+
+        // More than is left in the first chunk, so this one goes in a new chunk
+        arena.alloc_slice_fill_copy(1 << 20, 0u8);
+        assert!(
+            arena_bytes_used(&arena) >= used + (1 << 20),
+            "{} bytes used",
+            arena_bytes_used(&arena)
+        );
+    }
+
+    #[test]
+    fn module_memory_display() {
+        let memory = ModuleMemory {
+            parse_body: 1024 * 1024,
+            canonicalize: 512 * 1024,
+            find_specializations: 0,
+            make_specializations: vec![3 * 1024 * 1024, 0],
+            subs: 2 * 1024 * 1024,
+        };
+
+        assert_eq!(
+            memory.to_string(),
+            [
+                "            1.000 MiB  Parse body (arena)",
+                "            0.500 MiB  Canonicalize (arena)",
+                "            2.000 MiB  Subs",
+                "            0.000 MiB  Find Specializations (arena)",
+                "            3.000 MiB  Make Specializations (Pass 0) (arena)",
+                "            0.000 MiB  Make Specializations (Pass 1) (arena)",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+}
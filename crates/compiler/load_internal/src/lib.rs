@@ -5,6 +5,7 @@
 
 use roc_module::symbol::ModuleId;
 pub mod docs;
+mod doctest;
 pub mod file;
 mod work;
 
@@ -23,5 +24,7 @@ pub const BUILTIN_MODULES: &[(ModuleId, &str)] = &[
     (ModuleId::ENCODE, "Encode"),
     (ModuleId::DECODE, "Decode"),
     (ModuleId::HASH, "Hash"),
+    (ModuleId::INSPECT, "Inspect"),
+    (ModuleId::SORT, "Sort"),
     (ModuleId::JSON, "Json"),
 ];
@@ -17,7 +17,7 @@ mod helpers;
 use crate::helpers::fixtures_dir;
 use bumpalo::Bump;
 use roc_can::module::ExposedByModule;
-use roc_load_internal::file::{ExecutionMode, LoadConfig, Threading};
+use roc_load_internal::file::{ExecutionMode, LoadConfig, OptLevel, Threading};
 use roc_load_internal::file::{LoadResult, LoadStart, LoadedModule, LoadingProblem};
 use roc_module::ident::ModuleName;
 use roc_module::symbol::{Interns, ModuleId};
@@ -39,13 +39,29 @@ fn load_and_typecheck(
     exposed_types: ExposedByModule,
     target_info: TargetInfo,
 ) -> Result<LoadedModule, LoadingProblem> {
+    load_and_typecheck_with_cache_dir(
+        arena,
+        filename,
+        exposed_types,
+        target_info,
+        RocCacheDir::Disallowed,
+    )
+}
+
+fn load_and_typecheck_with_cache_dir<'a>(
+    arena: &'a Bump,
+    filename: PathBuf,
+    exposed_types: ExposedByModule,
+    target_info: TargetInfo,
+    roc_cache_dir: RocCacheDir<'_>,
+) -> Result<LoadedModule, LoadingProblem<'a>> {
     use LoadResult::*;
 
     let load_start = LoadStart::from_path(
         arena,
         filename,
         RenderTarget::Generic,
-        RocCacheDir::Disallowed,
+        roc_cache_dir,
         DEFAULT_PALETTE,
     )?;
     let load_config = LoadConfig {
@@ -54,6 +70,7 @@ fn load_and_typecheck(
         palette: DEFAULT_PALETTE,
         threading: Threading::Single,
         exec_mode: ExecutionMode::Check,
+        opt_level: OptLevel::Normal,
     };
 
     match roc_load_internal::file::load(
@@ -61,7 +78,7 @@ fn load_and_typecheck(
         load_start,
         exposed_types,
         Default::default(), // these tests will re-compile the builtins
-        RocCacheDir::Disallowed,
+        roc_cache_dir,
         load_config,
     )? {
         Monomorphized(_) => unreachable!(""),
@@ -669,6 +686,45 @@ fn platform_does_not_exist() {
     }
 }
 
+#[test]
+fn package_not_cached_while_offline() {
+    let arena = Bump::new();
+    let dir = roc_test_utils::TmpDir::new("tmp/package_not_cached_while_offline");
+    let cache_dir = dir.path().join("cache");
+    let app_path = dir.path().join("Main.roc");
+
+    std::fs::create_dir_all(&cache_dir).unwrap();
+    std::fs::write(
+        &app_path,
+        indoc!(
+            r#"
+                app "example"
+                    packages { pf: "https://example.com/jDRlAFAA3738vu3-vMpLUoyxtA86Z7CaZneoOKrihbE.tar.br" }
+                    imports []
+                    provides [main] to pf
+
+                main = ""
+                "#
+        ),
+    )
+    .unwrap();
+
+    match load_and_typecheck_with_cache_dir(
+        &arena,
+        app_path,
+        Default::default(),
+        TARGET_INFO,
+        RocCacheDir::Offline(&cache_dir),
+    ) {
+        Err(LoadingProblem::FormattedReport(report)) => {
+            assert!(report.contains("PACKAGE NOT CACHED"), "report=({})", report);
+            assert!(report.contains("roc fetch"), "report=({})", report);
+        }
+        Err(problem) => panic!("expected a formatted report, got {:?}", problem),
+        Ok(_) => unreachable!("we expect failure here"),
+    }
+}
+
 #[test]
 fn platform_parse_error() {
     let modules = vec![
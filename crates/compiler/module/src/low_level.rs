@@ -32,6 +32,12 @@ pub enum LowLevel {
     StrGetCapacity,
     StrWithCapacity,
     StrGraphemes,
+    StrToUpper,
+    StrToLower,
+    StrCaseFold,
+    StrToNfc,
+    StrToNfd,
+    StrCompare,
     ListLen,
     ListWithCapacity,
     ListReserve,
@@ -84,6 +90,8 @@ pub enum LowLevel {
     NumCeiling,
     NumPowInt,
     NumFloor,
+    NumIsNan,
+    NumIsInfinite,
     NumIsFinite,
     NumAtan,
     NumAcos,
@@ -96,6 +104,13 @@ pub enum LowLevel {
     NumShiftLeftBy,
     NumShiftRightBy,
     NumShiftRightZfBy,
+    NumCountLeadingZeroBits,
+    NumCountTrailingZeroBits,
+    NumCountOneBits,
+    NumF32ToBits,
+    NumF64ToBits,
+    NumF32FromBits,
+    NumF64FromBits,
     NumIntCast,
     NumToFloatCast,
     NumToIntChecked,
@@ -218,7 +233,6 @@ macro_rules! map_symbol_to_lowlevel {
                 // these are not implemented, not sure why
                 LowLevel::StrFromInt => unimplemented!(),
                 LowLevel::StrFromFloat => unimplemented!(),
-                LowLevel::NumIsFinite => unimplemented!(),
             }
         }
     };
@@ -253,6 +267,12 @@ map_symbol_to_lowlevel! {
     StrGetCapacity <= STR_CAPACITY,
     StrWithCapacity <= STR_WITH_CAPACITY,
     StrGraphemes <= STR_GRAPHEMES,
+    StrToUpper <= STR_TO_UPPER,
+    StrToLower <= STR_TO_LOWER,
+    StrCaseFold <= STR_CASE_FOLD,
+    StrToNfc <= STR_TO_NFC,
+    StrToNfd <= STR_TO_NFD,
+    StrCompare <= STR_COMPARE,
     ListLen <= LIST_LEN,
     ListGetCapacity <= LIST_CAPACITY,
     ListWithCapacity <= LIST_WITH_CAPACITY,
@@ -300,6 +320,9 @@ map_symbol_to_lowlevel! {
     NumCeiling <= NUM_CEILING,
     NumPowInt <= NUM_POW_INT,
     NumFloor <= NUM_FLOOR,
+    NumIsNan <= NUM_IS_NAN,
+    NumIsInfinite <= NUM_IS_INFINITE,
+    NumIsFinite <= NUM_IS_FINITE,
     NumAtan <= NUM_ATAN,
     NumAcos <= NUM_ACOS,
     NumAsin <= NUM_ASIN,
@@ -311,6 +334,13 @@ map_symbol_to_lowlevel! {
     NumShiftLeftBy <= NUM_SHIFT_LEFT,
     NumShiftRightBy <= NUM_SHIFT_RIGHT,
     NumShiftRightZfBy <= NUM_SHIFT_RIGHT_ZERO_FILL,
+    NumCountLeadingZeroBits <= NUM_COUNT_LEADING_ZERO_BITS,
+    NumCountTrailingZeroBits <= NUM_COUNT_TRAILING_ZERO_BITS,
+    NumCountOneBits <= NUM_COUNT_ONE_BITS,
+    NumF32ToBits <= NUM_F32_TO_BITS,
+    NumF64ToBits <= NUM_F64_TO_BITS,
+    NumF32FromBits <= NUM_F32_FROM_BITS,
+    NumF64FromBits <= NUM_F64_FROM_BITS,
     NumToStr <= NUM_TO_STR,
     Eq <= BOOL_STRUCTURAL_EQ,
     NotEq <= BOOL_STRUCTURAL_NOT_EQ,
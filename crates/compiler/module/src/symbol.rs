@@ -52,6 +52,8 @@ pub const DERIVABLE_ABILITIES: &[(Symbol, &[Symbol])] = &[
     (Symbol::DECODE_DECODING, &[Symbol::DECODE_DECODER]),
     (Symbol::HASH_HASH_ABILITY, &[Symbol::HASH_HASH]),
     (Symbol::BOOL_EQ, &[Symbol::BOOL_IS_EQ]),
+    (Symbol::INSPECT_INSPECT_ABILITY, &[Symbol::INSPECT_INSPECT]),
+    (Symbol::SORT_SORT_ABILITY, &[Symbol::SORT_COMPARE]),
 ];
 
 /// In Debug builds only, Symbol has a name() method that lets
@@ -524,6 +526,20 @@ impl<'a> PackageModuleIds<'a> {
         };
         Some(result)
     }
+
+    /// The shorthand of the package each package-qualified module came from,
+    /// e.g. `pf` for `pf.Task`.
+    pub fn package_shorthands(&self) -> impl Iterator<Item = (ModuleId, &'a str)> + '_ {
+        self.by_id
+            .iter()
+            .enumerate()
+            .filter_map(|(index, name)| match name {
+                PQModuleName::Unqualified(_) => None,
+                PQModuleName::Qualified(shorthand, _) => {
+                    Some((ModuleId::from_zero_indexed(index), *shorthand))
+                }
+            })
+    }
 }
 
 /// Stores a mapping between ModuleId and InlinableString.
@@ -1246,6 +1262,16 @@ define_builtins! {
         145 NUM_MUL_CHECKED_LOWLEVEL: "mulCheckedLowlevel"
         146 NUM_BYTES_TO_U16_LOWLEVEL: "bytesToU16Lowlevel"
         147 NUM_BYTES_TO_U32_LOWLEVEL: "bytesToU32Lowlevel"
+        148 NUM_IS_NAN: "isNaN"
+        149 NUM_IS_INFINITE: "isInfinite"
+        150 NUM_IS_FINITE: "isFinite"
+        151 NUM_COUNT_LEADING_ZERO_BITS: "countLeadingZeroBits"
+        152 NUM_COUNT_TRAILING_ZERO_BITS: "countTrailingZeroBits"
+        153 NUM_COUNT_ONE_BITS: "countOneBits"
+        154 NUM_F32_TO_BITS: "f32ToBits"
+        155 NUM_F64_TO_BITS: "f64ToBits"
+        156 NUM_F32_FROM_BITS: "f32FromBits"
+        157 NUM_F64_FROM_BITS: "f64FromBits"
     }
     4 BOOL: "Bool" => {
         0 BOOL_BOOL: "Bool" exposed_type=true // the Bool.Bool type alias
@@ -1319,6 +1345,12 @@ define_builtins! {
         53 STR_WITH_CAPACITY: "withCapacity"
         54 STR_WITH_PREFIX: "withPrefix"
         55 STR_GRAPHEMES: "graphemes"
+        56 STR_COMPARE: "compare"
+        57 STR_TO_UPPER: "toUpper"
+        58 STR_TO_LOWER: "toLower"
+        59 STR_CASE_FOLD: "caseFold"
+        60 STR_TO_NFC: "toNfc"
+        61 STR_TO_NFD: "toNfd"
     }
     6 LIST: "List" => {
         0 LIST_LIST: "List" exposed_apply_type=true // the List.List type alias
@@ -1401,6 +1433,7 @@ define_builtins! {
         77 LIST_COUNT_IF: "countIf"
         78 LIST_WALK_FROM: "walkFrom"
         79 LIST_WALK_FROM_UNTIL: "walkFromUntil"
+        80 LIST_SORT: "sort"
     }
     7 RESULT: "Result" => {
         0 RESULT_RESULT: "Result" exposed_type=true // the Result.Result type alias
@@ -1546,9 +1579,40 @@ define_builtins! {
         17 HASH_HASH_LIST: "hashList"
         18 HASH_HASH_UNORDERED: "hashUnordered"
     }
-    14 JSON: "Json" => {
+    14 INSPECT: "Inspect" => {
+        0 INSPECT_INSPECT_ABILITY: "Inspect" exposed_type=true
+        1 INSPECT_INSPECT: "inspect"
+        2 INSPECT_TO_STR: "toStr"
+        3 INSPECT_STR: "str"
+        4 INSPECT_BOOL: "bool"
+        5 INSPECT_U8: "u8"
+        6 INSPECT_U16: "u16"
+        7 INSPECT_U32: "u32"
+        8 INSPECT_U64: "u64"
+        9 INSPECT_U128: "u128"
+        10 INSPECT_I8: "i8"
+        11 INSPECT_I16: "i16"
+        12 INSPECT_I32: "i32"
+        13 INSPECT_I64: "i64"
+        14 INSPECT_I128: "i128"
+        15 INSPECT_NAT: "nat"
+        16 INSPECT_F32: "f32"
+        17 INSPECT_F64: "f64"
+        18 INSPECT_DEC: "dec"
+        19 INSPECT_LIST: "list"
+        20 INSPECT_DICT: "dict"
+        21 INSPECT_SET: "set"
+        22 INSPECT_PAYLOAD: "payload"
+        23 INSPECT_OPAQUE: "opaque"
+        24 INSPECT_LITERAL: "literal"
+    }
+    15 SORT: "Sort" => {
+        0 SORT_SORT_ABILITY: "Sort" exposed_type=true
+        1 SORT_COMPARE: "compare"
+    }
+    16 JSON: "Json" => {
         0 JSON_JSON: "Json"
     }
 
-    num_modules: 15 // Keep this count up to date by hand! (TODO: see the mut_map! macro for how we could determine this count correctly in the macro)
+    num_modules: 17 // Keep this count up to date by hand! (TODO: see the mut_map! macro for how we could determine this count correctly in the macro)
 }
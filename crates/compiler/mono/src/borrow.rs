@@ -958,6 +958,9 @@ pub fn lowlevel_borrow_signature(arena: &Bump, op: LowLevel) -> &[bool] {
         StrGetScalarUnsafe => arena.alloc_slice_copy(&[borrowed, irrelevant]),
         StrTrim => arena.alloc_slice_copy(&[owned]),
         StrTrimLeft => arena.alloc_slice_copy(&[owned]),
+        StrToUpper | StrToLower | StrCaseFold | StrToNfc | StrToNfd => {
+            arena.alloc_slice_copy(&[owned])
+        }
         StrTrimRight => arena.alloc_slice_copy(&[owned]),
         StrSplit => arena.alloc_slice_copy(&[borrowed, borrowed]),
         StrToNum => arena.alloc_slice_copy(&[borrowed]),
@@ -989,9 +992,18 @@ pub fn lowlevel_borrow_signature(arena: &Bump, op: LowLevel) -> &[bool] {
         | NumAsin | NumIntCast | NumToIntChecked | NumToFloatCast | NumToFloatChecked => {
             arena.alloc_slice_copy(&[irrelevant])
         }
+        NumIsNan
+        | NumIsInfinite
+        | NumCountLeadingZeroBits
+        | NumCountTrailingZeroBits
+        | NumCountOneBits
+        | NumF32ToBits
+        | NumF64ToBits
+        | NumF32FromBits
+        | NumF64FromBits => arena.alloc_slice_copy(&[irrelevant]),
         NumBytesToU16 => arena.alloc_slice_copy(&[borrowed, irrelevant]),
         NumBytesToU32 => arena.alloc_slice_copy(&[borrowed, irrelevant]),
-        StrStartsWith | StrEndsWith => arena.alloc_slice_copy(&[borrowed, borrowed]),
+        StrStartsWith | StrEndsWith | StrCompare => arena.alloc_slice_copy(&[borrowed, borrowed]),
         StrStartsWithScalar => arena.alloc_slice_copy(&[borrowed, irrelevant]),
         StrFromUtf8Range => arena.alloc_slice_copy(&[owned, irrelevant, irrelevant]),
         StrToUtf8 => arena.alloc_slice_copy(&[owned]),
@@ -0,0 +1,1111 @@
+//! Simplify the specialized procs before reset/reuse and refcounts are inserted:
+//!
+//! - calls to small, non-recursive procs are inlined into their callers
+//! - arithmetic and comparisons on integer (and boolean) literals are evaluated at compile time,
+//!   as are `switch`es on a known condition
+//! - lets whose value is never used, and that have no side effects, are removed
+//!
+//! The dev backends don't optimize the code they generate at all, so this is where development
+//! builds get their (cheap) optimizations from. Optimized builds get it too, because LLVM has an
+//! easier time with code that was simplified up front.
+use crate::borrow::Ownership;
+use crate::inc_dec::occurring_variables_expr;
+use crate::ir::{
+    BranchInfo, Call, CallSpecId, CallType, Expr, JoinPointId, ListLiteralElement, Literal,
+    ModifyRc, Param, Proc, ProcLayout, SelfRecursive, Stmt, UpdateModeIds,
+};
+use crate::layout::{Builtin, InLayout, Layout, LayoutInterner, STLayoutInterner};
+use crate::low_level::HigherOrder;
+use bumpalo::collections::Vec;
+use bumpalo::Bump;
+use roc_builtins::bitcode::IntWidth;
+use roc_collections::all::{MutMap, MutSet};
+use roc_collections::ReferenceMatrix;
+use roc_module::low_level::LowLevel;
+use roc_module::symbol::{IdentIds, ModuleId, Symbol};
+
+/// Procs whose body is at most this many statements are inlined into their callers.
+const INLINE_THRESHOLD: usize = 8;
+
+pub(crate) type ProcKey<'a> = (Symbol, ProcLayout<'a>);
+
+pub fn inline_and_fold<'a, 'i>(
+    arena: &'a Bump,
+    interner: &'i STLayoutInterner<'a>,
+    home: ModuleId,
+    ident_ids: &'i mut IdentIds,
+    update_mode_ids: &'i mut UpdateModeIds,
+    procs: &mut MutMap<ProcKey<'a>, Proc<'a>>,
+) {
+    let (keys, matrix) = call_graph(procs);
+
+    // The components come out with callees before their callers, so by the time we get to a
+    // proc, everything it calls has already been simplified (and possibly marked inlinable).
+    let sccs = matrix.strongly_connected_components_all();
+    let mut inlinable = MutSet::default();
+
+    for (group, _) in sccs.groups() {
+        let is_recursive = group.count_ones() > 1 || {
+            let index = group.first_one().unwrap();
+            matrix.get_row_col(index, index)
+        };
+
+        for index in group.iter_ones() {
+            let key = keys[index];
+
+            let new_body = {
+                let mut env = Env {
+                    arena,
+                    interner,
+                    home,
+                    ident_ids,
+                    update_mode_ids,
+                    procs,
+                    inlinable: &inlinable,
+                    inlined_any: false,
+                    next_call_spec_id: CallSpecId::FIRST,
+                };
+
+                env.simplify_proc(&procs[&key])
+            };
+
+            let proc = procs.get_mut(&key).unwrap();
+            proc.body = new_body;
+
+            if !is_recursive && is_inlinable(proc) {
+                inlinable.insert(key);
+            }
+        }
+    }
+}
+
+struct Env<'a, 'i> {
+    arena: &'a Bump,
+    interner: &'i STLayoutInterner<'a>,
+    home: ModuleId,
+    ident_ids: &'i mut IdentIds,
+    update_mode_ids: &'i mut UpdateModeIds,
+    procs: &'i MutMap<ProcKey<'a>, Proc<'a>>,
+    inlinable: &'i MutSet<ProcKey<'a>>,
+    /// Whether any call in the current proc was inlined
+    inlined_any: bool,
+    /// Calls copied from other procs need a specialization id that is unique in this proc,
+    /// so once something is inlined, all the calls in the proc get numbered again.
+    next_call_spec_id: CallSpecId,
+}
+
+/// What to do with the symbols of a statement while copying it
+#[derive(Default)]
+struct Rewrite {
+    substitutions: MutMap<Symbol, Symbol>,
+    join_points: MutMap<JoinPointId, JoinPointId>,
+    /// The body of an inlined proc: everything it binds gets a fresh name, so that the same proc
+    /// can be inlined more than once into a caller.
+    is_inlined_copy: bool,
+    /// For inlined procs with more than one `ret`, where those should jump to instead
+    ret_join_point: Option<JoinPointId>,
+}
+
+impl Rewrite {
+    fn symbol(&self, symbol: Symbol) -> Symbol {
+        *self.substitutions.get(&symbol).unwrap_or(&symbol)
+    }
+}
+
+impl<'a, 'i> Env<'a, 'i> {
+    fn unique_symbol(&mut self) -> Symbol {
+        let ident_id = self.ident_ids.gen_unique();
+
+        Symbol::new(self.home, ident_id)
+    }
+
+    fn simplify_proc(&mut self, proc: &Proc<'a>) -> Stmt<'a> {
+        let inlined = self.inline_stmt(&mut Rewrite::default(), &proc.body);
+
+        // If nothing was inlined, keep the original call specialization ids.
+        let body = if self.inlined_any {
+            self.arena.alloc(inlined)
+        } else {
+            self.arena.alloc(proc.body.clone())
+        };
+
+        let folded = fold_stmt(self.arena, self.interner, &mut MutMap::default(), body);
+
+        remove_dead_lets(self.arena, self.arena.alloc(folded), &mut MutSet::default())
+    }
+
+    fn bind(&mut self, rewrite: &mut Rewrite, symbol: Symbol) -> Symbol {
+        if rewrite.is_inlined_copy {
+            let fresh = self.unique_symbol();
+            rewrite.substitutions.insert(symbol, fresh);
+
+            fresh
+        } else {
+            symbol
+        }
+    }
+
+    fn symbols(&self, rewrite: &Rewrite, symbols: &[Symbol]) -> &'a [Symbol] {
+        let it = symbols.iter().map(|symbol| rewrite.symbol(*symbol));
+
+        Vec::from_iter_in(it, self.arena).into_bump_slice()
+    }
+
+    fn inline_stmt(&mut self, rewrite: &mut Rewrite, stmt: &Stmt<'a>) -> Stmt<'a> {
+        use Stmt::*;
+
+        match stmt {
+            Let(symbol, expr, layout, cont) => {
+                let expr = self.rewrite_expr(rewrite, expr);
+                let symbol = self.bind(rewrite, *symbol);
+
+                // The body of an inlined proc has already had its own calls inlined.
+                if !rewrite.is_inlined_copy {
+                    if let Some(callee) = self.inlinable_callee(&expr) {
+                        let arguments = match &expr {
+                            Expr::Call(call) => call.arguments,
+                            _ => unreachable!(),
+                        };
+
+                        self.inlined_any = true;
+
+                        return self.inline_call(rewrite, symbol, *layout, callee, arguments, cont);
+                    }
+                }
+
+                let cont = self.inline_stmt(rewrite, cont);
+
+                Let(symbol, expr, *layout, self.arena.alloc(cont))
+            }
+            Switch {
+                cond_symbol,
+                cond_layout,
+                branches,
+                default_branch,
+                ret_layout,
+            } => {
+                let mut new_branches = Vec::with_capacity_in(branches.len(), self.arena);
+
+                for (value, info, branch) in branches.iter() {
+                    let info = rewrite_branch_info(rewrite, info);
+                    let branch = self.inline_stmt(rewrite, branch);
+
+                    new_branches.push((*value, info, branch));
+                }
+
+                let default_info = rewrite_branch_info(rewrite, &default_branch.0);
+                let default_stmt = self.inline_stmt(rewrite, default_branch.1);
+
+                Switch {
+                    cond_symbol: rewrite.symbol(*cond_symbol),
+                    cond_layout: *cond_layout,
+                    branches: new_branches.into_bump_slice(),
+                    default_branch: (default_info, self.arena.alloc(default_stmt)),
+                    ret_layout: *ret_layout,
+                }
+            }
+            Ret(symbol) => {
+                let symbol = rewrite.symbol(*symbol);
+
+                match rewrite.ret_join_point {
+                    Some(id) => Jump(id, self.arena.alloc([symbol])),
+                    None => Ret(symbol),
+                }
+            }
+            Refcounting(modify, cont) => {
+                let modify = match modify {
+                    ModifyRc::Inc(symbol, n) => ModifyRc::Inc(rewrite.symbol(*symbol), *n),
+                    ModifyRc::Dec(symbol) => ModifyRc::Dec(rewrite.symbol(*symbol)),
+                    ModifyRc::DecRef(symbol) => ModifyRc::DecRef(rewrite.symbol(*symbol)),
+                };
+                let cont = self.inline_stmt(rewrite, cont);
+
+                Refcounting(modify, self.arena.alloc(cont))
+            }
+            Expect {
+                condition,
+                region,
+                lookups,
+                variables,
+                remainder,
+            } => {
+                let remainder = self.inline_stmt(rewrite, remainder);
+
+                Expect {
+                    condition: rewrite.symbol(*condition),
+                    region: *region,
+                    lookups: self.symbols(rewrite, lookups),
+                    variables,
+                    remainder: self.arena.alloc(remainder),
+                }
+            }
+            ExpectFx {
+                condition,
+                region,
+                lookups,
+                variables,
+                remainder,
+            } => {
+                let remainder = self.inline_stmt(rewrite, remainder);
+
+                ExpectFx {
+                    condition: rewrite.symbol(*condition),
+                    region: *region,
+                    lookups: self.symbols(rewrite, lookups),
+                    variables,
+                    remainder: self.arena.alloc(remainder),
+                }
+            }
+            Dbg {
+                symbol,
+                variable,
+                remainder,
+            } => {
+                let remainder = self.inline_stmt(rewrite, remainder);
+
+                Dbg {
+                    symbol: rewrite.symbol(*symbol),
+                    variable: *variable,
+                    remainder: self.arena.alloc(remainder),
+                }
+            }
+            Join {
+                id,
+                parameters,
+                body,
+                remainder,
+            } => {
+                let id = if rewrite.is_inlined_copy {
+                    let fresh = JoinPointId(self.unique_symbol());
+                    rewrite.join_points.insert(*id, fresh);
+
+                    fresh
+                } else {
+                    *id
+                };
+
+                let mut new_parameters = Vec::with_capacity_in(parameters.len(), self.arena);
+
+                for param in parameters.iter() {
+                    new_parameters.push(Param {
+                        symbol: self.bind(rewrite, param.symbol),
+                        ..*param
+                    });
+                }
+
+                let body = self.inline_stmt(rewrite, body);
+                let remainder = self.inline_stmt(rewrite, remainder);
+
+                Join {
+                    id,
+                    parameters: new_parameters.into_bump_slice(),
+                    body: self.arena.alloc(body),
+                    remainder: self.arena.alloc(remainder),
+                }
+            }
+            Jump(id, arguments) => {
+                let id = *rewrite.join_points.get(id).unwrap_or(id);
+
+                Jump(id, self.symbols(rewrite, arguments))
+            }
+            Crash(symbol, tag) => Crash(rewrite.symbol(*symbol), *tag),
+        }
+    }
+
+    fn inlinable_callee(&self, expr: &Expr<'a>) -> Option<&'i Proc<'a>> {
+        match expr {
+            Expr::Call(Call {
+                call_type:
+                    CallType::ByName {
+                        name,
+                        ret_layout,
+                        arg_layouts,
+                        ..
+                    },
+                arguments,
+            }) => {
+                let key = (
+                    name.name(),
+                    ProcLayout {
+                        arguments: arg_layouts,
+                        result: *ret_layout,
+                        niche: name.niche(),
+                    },
+                );
+
+                if !self.inlinable.contains(&key) {
+                    return None;
+                }
+
+                let procs = self.procs;
+                let callee = &procs[&key];
+
+                (callee.args.len() == arguments.len()).then_some(callee)
+            }
+            _ => None,
+        }
+    }
+
+    /// Inline `let symbol = callee arguments` followed by `cont`
+    fn inline_call(
+        &mut self,
+        rewrite: &mut Rewrite,
+        symbol: Symbol,
+        layout: InLayout<'a>,
+        callee: &Proc<'a>,
+        arguments: &[Symbol],
+        cont: &Stmt<'a>,
+    ) -> Stmt<'a> {
+        let mut callee_rewrite = Rewrite {
+            is_inlined_copy: true,
+            ..Default::default()
+        };
+
+        for ((_, param), argument) in callee.args.iter().zip(arguments) {
+            callee_rewrite.substitutions.insert(*param, *argument);
+        }
+
+        if is_straight_line(&callee.body) {
+            // Splice the callee's lets in before the continuation, which then uses the value the
+            // callee would have returned instead of the result of the call.
+            let mut lets = std::vec::Vec::new();
+            let mut stmt = &callee.body;
+
+            while let Stmt::Let(callee_symbol, expr, callee_layout, callee_cont) = stmt {
+                let expr = self.rewrite_expr(&callee_rewrite, expr);
+                let callee_symbol = self.bind(&mut callee_rewrite, *callee_symbol);
+
+                lets.push((callee_symbol, expr, *callee_layout));
+                stmt = *callee_cont;
+            }
+
+            match stmt {
+                Stmt::Ret(returned) => {
+                    rewrite
+                        .substitutions
+                        .insert(symbol, callee_rewrite.symbol(*returned));
+                }
+                _ => unreachable!(),
+            }
+
+            let mut result = self.inline_stmt(rewrite, cont);
+
+            for (callee_symbol, expr, callee_layout) in lets.into_iter().rev() {
+                result = Stmt::Let(callee_symbol, expr, callee_layout, self.arena.alloc(result));
+            }
+
+            result
+        } else {
+            // Every `ret` in the callee becomes a jump to a join point, which binds the result
+            // and continues with the rest of the caller.
+            let id = JoinPointId(self.unique_symbol());
+            callee_rewrite.ret_join_point = Some(id);
+
+            let remainder = self.inline_stmt(&mut callee_rewrite, &callee.body);
+            let body = self.inline_stmt(rewrite, cont);
+
+            Stmt::Join {
+                id,
+                parameters: self.arena.alloc([Param {
+                    symbol,
+                    ownership: Ownership::Owned,
+                    layout,
+                }]),
+                body: self.arena.alloc(body),
+                remainder: self.arena.alloc(remainder),
+            }
+        }
+    }
+
+    fn rewrite_expr(&mut self, rewrite: &Rewrite, expr: &Expr<'a>) -> Expr<'a> {
+        match expr {
+            Expr::Literal(_) | Expr::EmptyArray | Expr::RuntimeErrorFunction(_) => expr.clone(),
+            Expr::Call(call) => Expr::Call(self.rewrite_call(rewrite, call)),
+            Expr::Tag {
+                tag_layout,
+                tag_id,
+                arguments,
+            } => Expr::Tag {
+                tag_layout: *tag_layout,
+                tag_id: *tag_id,
+                arguments: self.symbols(rewrite, arguments),
+            },
+            Expr::Struct(fields) => Expr::Struct(self.symbols(rewrite, fields)),
+            Expr::StructAtIndex {
+                index,
+                field_layouts,
+                structure,
+            } => Expr::StructAtIndex {
+                index: *index,
+                field_layouts,
+                structure: rewrite.symbol(*structure),
+            },
+            Expr::GetTagId {
+                structure,
+                union_layout,
+            } => Expr::GetTagId {
+                structure: rewrite.symbol(*structure),
+                union_layout: *union_layout,
+            },
+            Expr::UnionAtIndex {
+                structure,
+                tag_id,
+                union_layout,
+                index,
+            } => Expr::UnionAtIndex {
+                structure: rewrite.symbol(*structure),
+                tag_id: *tag_id,
+                union_layout: *union_layout,
+                index: *index,
+            },
+            Expr::Array { elem_layout, elems } => {
+                let it = elems.iter().map(|elem| match elem {
+                    ListLiteralElement::Literal(_) => *elem,
+                    ListLiteralElement::Symbol(symbol) => {
+                        ListLiteralElement::Symbol(rewrite.symbol(*symbol))
+                    }
+                });
+
+                Expr::Array {
+                    elem_layout: *elem_layout,
+                    elems: Vec::from_iter_in(it, self.arena).into_bump_slice(),
+                }
+            }
+            Expr::ExprBox { symbol } => Expr::ExprBox {
+                symbol: rewrite.symbol(*symbol),
+            },
+            Expr::ExprUnbox { symbol } => Expr::ExprUnbox {
+                symbol: rewrite.symbol(*symbol),
+            },
+            Expr::Reuse {
+                symbol,
+                update_tag_id,
+                update_mode,
+                tag_layout,
+                tag_id,
+                arguments,
+            } => Expr::Reuse {
+                symbol: rewrite.symbol(*symbol),
+                update_tag_id: *update_tag_id,
+                update_mode: *update_mode,
+                tag_layout: *tag_layout,
+                tag_id: *tag_id,
+                arguments: self.symbols(rewrite, arguments),
+            },
+            Expr::Reset {
+                symbol,
+                update_mode,
+            } => Expr::Reset {
+                symbol: rewrite.symbol(*symbol),
+                update_mode: *update_mode,
+            },
+        }
+    }
+
+    fn rewrite_call(&mut self, rewrite: &Rewrite, call: &Call<'a>) -> Call<'a> {
+        let call_type = match &call.call_type {
+            CallType::ByName {
+                name,
+                ret_layout,
+                arg_layouts,
+                ..
+            } => CallType::ByName {
+                name: *name,
+                ret_layout: *ret_layout,
+                arg_layouts,
+                specialization_id: self.next_call_spec_id(),
+            },
+            CallType::Foreign { .. } => call.call_type.clone(),
+            CallType::LowLevel { op, update_mode } => CallType::LowLevel {
+                op: *op,
+                update_mode: if rewrite.is_inlined_copy {
+                    self.update_mode_ids.next_id()
+                } else {
+                    *update_mode
+                },
+            },
+            CallType::HigherOrder(higher_order) => {
+                let mut higher_order = (*higher_order).clone();
+
+                if rewrite.is_inlined_copy {
+                    higher_order.update_mode = self.update_mode_ids.next_id();
+                }
+
+                higher_order.op = rewrite_higher_order(rewrite, &higher_order.op);
+
+                higher_order.passed_function.specialization_id = self.next_call_spec_id();
+                higher_order.passed_function.captured_environment =
+                    rewrite.symbol(higher_order.passed_function.captured_environment);
+
+                CallType::HigherOrder(self.arena.alloc(higher_order))
+            }
+        };
+
+        Call {
+            call_type,
+            arguments: self.symbols(rewrite, call.arguments),
+        }
+    }
+
+    fn next_call_spec_id(&mut self) -> CallSpecId {
+        let id = self.next_call_spec_id;
+        self.next_call_spec_id = id.next();
+
+        id
+    }
+}
+
+/// The op names the lists it works on, which are also among the call's arguments
+fn rewrite_higher_order(rewrite: &Rewrite, op: &HigherOrder) -> HigherOrder {
+    use HigherOrder::*;
+
+    match op {
+        ListMap { xs } => ListMap {
+            xs: rewrite.symbol(*xs),
+        },
+        ListMap2 { xs, ys } => ListMap2 {
+            xs: rewrite.symbol(*xs),
+            ys: rewrite.symbol(*ys),
+        },
+        ListMap3 { xs, ys, zs } => ListMap3 {
+            xs: rewrite.symbol(*xs),
+            ys: rewrite.symbol(*ys),
+            zs: rewrite.symbol(*zs),
+        },
+        ListMap4 { xs, ys, zs, ws } => ListMap4 {
+            xs: rewrite.symbol(*xs),
+            ys: rewrite.symbol(*ys),
+            zs: rewrite.symbol(*zs),
+            ws: rewrite.symbol(*ws),
+        },
+        ListSortWith { xs } => ListSortWith {
+            xs: rewrite.symbol(*xs),
+        },
+    }
+}
+
+fn rewrite_branch_info<'a>(rewrite: &Rewrite, info: &BranchInfo<'a>) -> BranchInfo<'a> {
+    match info {
+        BranchInfo::None => BranchInfo::None,
+        BranchInfo::Constructor {
+            scrutinee,
+            layout,
+            tag_id,
+        } => BranchInfo::Constructor {
+            scrutinee: rewrite.symbol(*scrutinee),
+            layout: *layout,
+            tag_id: *tag_id,
+        },
+    }
+}
+
+/// Which procs call which: row `i`, column `j` is set when the `i`th key calls the `j`th one
+/// (directly, or by passing it to a higher-order lowlevel).
+pub(crate) fn call_graph<'a>(
+    procs: &MutMap<ProcKey<'a>, Proc<'a>>,
+) -> (std::vec::Vec<ProcKey<'a>>, ReferenceMatrix) {
+    let keys: std::vec::Vec<ProcKey<'a>> = procs.keys().copied().collect();
+    let index: MutMap<ProcKey<'a>, usize> = keys
+        .iter()
+        .enumerate()
+        .map(|(index, key)| (*key, index))
+        .collect();
+    let mut matrix = ReferenceMatrix::new(keys.len());
+
+    for (row, key) in keys.iter().enumerate() {
+        let mut callees = MutSet::default();
+        collect_callees(&procs[key].body, &mut callees);
+
+        // Only calls to procs in the map get a column.
+        for callee in callees {
+            if let Some(col) = index.get(&callee) {
+                matrix.set_row_col(row, *col, true);
+            }
+        }
+    }
+
+    (keys, matrix)
+}
+
+fn collect_callees<'a>(stmt: &Stmt<'a>, callees: &mut MutSet<ProcKey<'a>>) {
+    use Stmt::*;
+
+    match stmt {
+        Let(_, expr, _, cont) => {
+            if let Expr::Call(Call { call_type, .. }) = expr {
+                match call_type {
+                    CallType::ByName {
+                        name,
+                        ret_layout,
+                        arg_layouts,
+                        ..
+                    } => {
+                        let layout = ProcLayout {
+                            arguments: arg_layouts,
+                            result: *ret_layout,
+                            niche: name.niche(),
+                        };
+
+                        callees.insert((name.name(), layout));
+                    }
+                    CallType::HigherOrder(higher_order) => {
+                        let passed_function = &higher_order.passed_function;
+                        let layout = ProcLayout {
+                            arguments: passed_function.argument_layouts,
+                            result: passed_function.return_layout,
+                            niche: passed_function.name.niche(),
+                        };
+
+                        callees.insert((passed_function.name.name(), layout));
+                    }
+                    CallType::Foreign { .. } | CallType::LowLevel { .. } => {}
+                }
+            }
+
+            collect_callees(cont, callees);
+        }
+        Switch {
+            branches,
+            default_branch,
+            ..
+        } => {
+            for (_, _, branch) in branches.iter() {
+                collect_callees(branch, callees);
+            }
+
+            collect_callees(default_branch.1, callees);
+        }
+        Join {
+            body, remainder, ..
+        } => {
+            collect_callees(body, callees);
+            collect_callees(remainder, callees);
+        }
+        Refcounting(_, cont)
+        | Expect {
+            remainder: cont, ..
+        }
+        | ExpectFx {
+            remainder: cont, ..
+        }
+        | Dbg {
+            remainder: cont, ..
+        } => collect_callees(cont, callees),
+        Ret(_) | Jump(_, _) | Crash(_, _) => {}
+    }
+}
+
+fn is_inlinable(proc: &Proc<'_>) -> bool {
+    matches!(proc.is_self_recursive, SelfRecursive::NotSelfRecursive)
+        && inline_cost(&proc.body) <= INLINE_THRESHOLD
+}
+
+/// The number of statements, or `usize::MAX` for statements that can't be inlined: expects
+/// and dbgs refer to type variables of the proc they're in.
+fn inline_cost(stmt: &Stmt<'_>) -> usize {
+    use Stmt::*;
+
+    match stmt {
+        Let(_, _, _, cont) | Refcounting(_, cont) => 1usize.saturating_add(inline_cost(cont)),
+        Switch {
+            branches,
+            default_branch,
+            ..
+        } => branches
+            .iter()
+            .map(|(_, _, branch)| inline_cost(branch))
+            .fold(
+                1usize.saturating_add(inline_cost(default_branch.1)),
+                usize::saturating_add,
+            ),
+        Join {
+            body, remainder, ..
+        } => 1usize
+            .saturating_add(inline_cost(body))
+            .saturating_add(inline_cost(remainder)),
+        Ret(_) | Jump(_, _) | Crash(_, _) => 1,
+        Expect { .. } | ExpectFx { .. } | Dbg { .. } => usize::MAX,
+    }
+}
+
+/// A chain of lets, followed by a `ret`
+fn is_straight_line(stmt: &Stmt<'_>) -> bool {
+    match stmt {
+        Stmt::Let(_, _, _, cont) => is_straight_line(cont),
+        Stmt::Ret(_) => true,
+        _ => false,
+    }
+}
+
+fn fold_stmt<'a>(
+    arena: &'a Bump,
+    interner: &STLayoutInterner<'a>,
+    literals: &mut MutMap<Symbol, Literal<'a>>,
+    stmt: &'a Stmt<'a>,
+) -> Stmt<'a> {
+    use Stmt::*;
+
+    match stmt {
+        Let(symbol, expr, layout, cont) => {
+            let expr = match expr {
+                Expr::Literal(literal) => {
+                    literals.insert(*symbol, *literal);
+
+                    expr.clone()
+                }
+                Expr::Call(Call {
+                    call_type: CallType::LowLevel { op, .. },
+                    arguments,
+                }) => {
+                    let arguments: Option<std::vec::Vec<_>> = arguments
+                        .iter()
+                        .map(|argument| literals.get(argument).copied())
+                        .collect();
+
+                    match arguments.and_then(|arguments| {
+                        fold_low_level(*op, &arguments, interner.get(*layout))
+                    }) {
+                        Some(literal) => {
+                            literals.insert(*symbol, literal);
+
+                            Expr::Literal(literal)
+                        }
+                        None => expr.clone(),
+                    }
+                }
+                _ => expr.clone(),
+            };
+
+            let cont = fold_stmt(arena, interner, literals, cont);
+
+            Let(*symbol, expr, *layout, arena.alloc(cont))
+        }
+        Switch {
+            cond_symbol,
+            cond_layout,
+            branches,
+            default_branch,
+            ret_layout,
+        } => {
+            let known_value = literals.get(cond_symbol).and_then(|literal| match literal {
+                Literal::Bool(b) => Some(*b as u64),
+                Literal::Byte(b) => Some(*b as u64),
+                _ => None,
+            });
+
+            if let Some(value) = known_value {
+                let taken = branches
+                    .iter()
+                    .find(|(branch_value, _, _)| *branch_value == value)
+                    .map(|(_, _, branch)| branch)
+                    .unwrap_or(default_branch.1);
+
+                return fold_stmt(arena, interner, literals, taken);
+            }
+
+            let mut new_branches = Vec::with_capacity_in(branches.len(), arena);
+
+            for (value, info, branch) in branches.iter() {
+                let branch = fold_stmt(arena, interner, literals, branch);
+
+                new_branches.push((*value, info.clone(), branch));
+            }
+
+            let default_stmt = fold_stmt(arena, interner, literals, default_branch.1);
+
+            Switch {
+                cond_symbol: *cond_symbol,
+                cond_layout: *cond_layout,
+                branches: new_branches.into_bump_slice(),
+                default_branch: (default_branch.0.clone(), arena.alloc(default_stmt)),
+                ret_layout: *ret_layout,
+            }
+        }
+        Refcounting(modify, cont) => {
+            let cont = fold_stmt(arena, interner, literals, cont);
+
+            Refcounting(*modify, arena.alloc(cont))
+        }
+        Expect {
+            condition,
+            region,
+            lookups,
+            variables,
+            remainder,
+        } => Expect {
+            condition: *condition,
+            region: *region,
+            lookups,
+            variables,
+            remainder: arena.alloc(fold_stmt(arena, interner, literals, remainder)),
+        },
+        ExpectFx {
+            condition,
+            region,
+            lookups,
+            variables,
+            remainder,
+        } => ExpectFx {
+            condition: *condition,
+            region: *region,
+            lookups,
+            variables,
+            remainder: arena.alloc(fold_stmt(arena, interner, literals, remainder)),
+        },
+        Dbg {
+            symbol,
+            variable,
+            remainder,
+        } => Dbg {
+            symbol: *symbol,
+            variable: *variable,
+            remainder: arena.alloc(fold_stmt(arena, interner, literals, remainder)),
+        },
+        Join {
+            id,
+            parameters,
+            body,
+            remainder,
+        } => {
+            let body = fold_stmt(arena, interner, literals, body);
+            let remainder = fold_stmt(arena, interner, literals, remainder);
+
+            Join {
+                id: *id,
+                parameters,
+                body: arena.alloc(body),
+                remainder: arena.alloc(remainder),
+            }
+        }
+        Ret(_) | Jump(_, _) | Crash(_, _) => stmt.clone(),
+    }
+}
+
+/// Evaluate a lowlevel on literal arguments. Integer arithmetic is only folded when the result
+/// fits in the result's layout; anything that would overflow is left for the runtime to handle.
+fn fold_low_level<'a>(
+    op: LowLevel,
+    arguments: &[Literal<'a>],
+    result_layout: Layout<'a>,
+) -> Option<Literal<'a>> {
+    use LowLevel::*;
+
+    let int = |literal: &Literal| match literal {
+        Literal::Int(bytes) => Some(i128::from_ne_bytes(*bytes)),
+        _ => None,
+    };
+    let int_result = |value: Option<i128>| match result_layout {
+        Layout::Builtin(Builtin::Int(width)) => {
+            let value = value?;
+
+            fits_in(width, value).then_some(Literal::Int(value.to_ne_bytes()))
+        }
+        _ => None,
+    };
+
+    match (op, arguments) {
+        (NumAdd | NumAddWrap, [a, b]) => int_result(int(a)?.checked_add(int(b)?)),
+        (NumSub | NumSubWrap, [a, b]) => int_result(int(a)?.checked_sub(int(b)?)),
+        (NumMul | NumMulWrap, [a, b]) => int_result(int(a)?.checked_mul(int(b)?)),
+        (NumNeg, [a]) => int_result(int(a)?.checked_neg()),
+        (NumGt | NumGte | NumLt | NumLte, [a, b]) => {
+            let (a, b) = (int(a)?, int(b)?);
+
+            let result = match op {
+                NumGt => a > b,
+                NumGte => a >= b,
+                NumLt => a < b,
+                _ => a <= b,
+            };
+
+            Some(Literal::Bool(result))
+        }
+        (Eq | NotEq, [a, b]) => {
+            let equal = match (a, b) {
+                (Literal::Int(a), Literal::Int(b)) => a == b,
+                (Literal::Bool(a), Literal::Bool(b)) => a == b,
+                (Literal::Byte(a), Literal::Byte(b)) => a == b,
+                (Literal::Str(a), Literal::Str(b)) => a == b,
+                _ => return None,
+            };
+
+            Some(Literal::Bool(equal == matches!(op, Eq)))
+        }
+        (And, [Literal::Bool(a), Literal::Bool(b)]) => Some(Literal::Bool(*a && *b)),
+        (Or, [Literal::Bool(a), Literal::Bool(b)]) => Some(Literal::Bool(*a || *b)),
+        (Not, [Literal::Bool(a)]) => Some(Literal::Bool(!*a)),
+        _ => None,
+    }
+}
+
+fn fits_in(width: IntWidth, value: i128) -> bool {
+    use IntWidth::*;
+
+    match width {
+        U8 => u8::try_from(value).is_ok(),
+        U16 => u16::try_from(value).is_ok(),
+        U32 => u32::try_from(value).is_ok(),
+        U64 => u64::try_from(value).is_ok(),
+        I8 => i8::try_from(value).is_ok(),
+        I16 => i16::try_from(value).is_ok(),
+        I32 => i32::try_from(value).is_ok(),
+        I64 => i64::try_from(value).is_ok(),
+        I128 => true,
+        // large U128 values are a different kind of literal
+        U128 => false,
+    }
+}
+
+/// Remove lets that are never used and have no side effects. Symbols are unique within a proc,
+/// so one set of used symbols is enough for the whole body; every use of a symbol comes after
+/// its definition, so it's in the set by the time we get back up to the let.
+fn remove_dead_lets<'a>(
+    arena: &'a Bump,
+    stmt: &'a Stmt<'a>,
+    used: &mut MutSet<Symbol>,
+) -> Stmt<'a> {
+    use Stmt::*;
+
+    match stmt {
+        Let(symbol, expr, layout, cont) => {
+            let cont = remove_dead_lets(arena, cont, used);
+
+            if !used.contains(symbol) && !matches!(expr, Expr::Call(_) | Expr::Reset { .. }) {
+                cont
+            } else {
+                occurring_variables_expr(expr, used);
+
+                Let(*symbol, expr.clone(), *layout, arena.alloc(cont))
+            }
+        }
+        Switch {
+            cond_symbol,
+            cond_layout,
+            branches,
+            default_branch,
+            ret_layout,
+        } => {
+            let mut new_branches = Vec::with_capacity_in(branches.len(), arena);
+
+            for (value, info, branch) in branches.iter() {
+                let branch = remove_dead_lets(arena, branch, used);
+
+                new_branches.push((*value, info.clone(), branch));
+            }
+
+            let default_stmt = remove_dead_lets(arena, default_branch.1, used);
+
+            for info in branches
+                .iter()
+                .map(|(_, info, _)| info)
+                .chain([&default_branch.0])
+            {
+                if let BranchInfo::Constructor { scrutinee, .. } = info {
+                    used.insert(*scrutinee);
+                }
+            }
+
+            used.insert(*cond_symbol);
+
+            Switch {
+                cond_symbol: *cond_symbol,
+                cond_layout: *cond_layout,
+                branches: new_branches.into_bump_slice(),
+                default_branch: (default_branch.0.clone(), arena.alloc(default_stmt)),
+                ret_layout: *ret_layout,
+            }
+        }
+        Refcounting(modify, cont) => {
+            let cont = remove_dead_lets(arena, cont, used);
+
+            match modify {
+                ModifyRc::Inc(symbol, _) | ModifyRc::Dec(symbol) | ModifyRc::DecRef(symbol) => {
+                    used.insert(*symbol);
+                }
+            }
+
+            Refcounting(*modify, arena.alloc(cont))
+        }
+        Expect {
+            condition,
+            region,
+            lookups,
+            variables,
+            remainder,
+        } => {
+            let remainder = remove_dead_lets(arena, remainder, used);
+
+            used.insert(*condition);
+            used.extend(lookups.iter().copied());
+
+            Expect {
+                condition: *condition,
+                region: *region,
+                lookups,
+                variables,
+                remainder: arena.alloc(remainder),
+            }
+        }
+        ExpectFx {
+            condition,
+            region,
+            lookups,
+            variables,
+            remainder,
+        } => {
+            let remainder = remove_dead_lets(arena, remainder, used);
+
+            used.insert(*condition);
+            used.extend(lookups.iter().copied());
+
+            ExpectFx {
+                condition: *condition,
+                region: *region,
+                lookups,
+                variables,
+                remainder: arena.alloc(remainder),
+            }
+        }
+        Dbg {
+            symbol,
+            variable,
+            remainder,
+        } => {
+            let remainder = remove_dead_lets(arena, remainder, used);
+
+            used.insert(*symbol);
+
+            Dbg {
+                symbol: *symbol,
+                variable: *variable,
+                remainder: arena.alloc(remainder),
+            }
+        }
+        Join {
+            id,
+            parameters,
+            body,
+            remainder,
+        } => {
+            let body = remove_dead_lets(arena, body, used);
+            let remainder = remove_dead_lets(arena, remainder, used);
+
+            Join {
+                id: *id,
+                parameters,
+                body: arena.alloc(body),
+                remainder: arena.alloc(remainder),
+            }
+        }
+        Ret(symbol) | Crash(symbol, _) => {
+            used.insert(*symbol);
+
+            stmt.clone()
+        }
+        Jump(_, arguments) => {
+            used.extend(arguments.iter().copied());
+
+            stmt.clone()
+        }
+    }
+}
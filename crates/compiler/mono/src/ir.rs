@@ -17,8 +17,8 @@ use roc_collections::VecMap;
 use roc_debug_flags::dbg_do;
 #[cfg(debug_assertions)]
 use roc_debug_flags::{
-    ROC_PRINT_IR_AFTER_REFCOUNT, ROC_PRINT_IR_AFTER_RESET_REUSE, ROC_PRINT_IR_AFTER_SPECIALIZATION,
-    ROC_PRINT_RUNTIME_ERROR_GEN,
+    ROC_PRINT_IR_AFTER_INLINING, ROC_PRINT_IR_AFTER_REFCOUNT, ROC_PRINT_IR_AFTER_RESET_REUSE,
+    ROC_PRINT_IR_AFTER_SPECIALIZATION, ROC_PRINT_RUNTIME_ERROR_GEN,
 };
 use roc_derive::SharedDerivedModule;
 use roc_error_macros::{internal_error, todo_abilities};
@@ -44,6 +44,9 @@ pub fn pretty_print_ir_symbols() -> bool {
     dbg_do!(ROC_PRINT_IR_AFTER_SPECIALIZATION, {
         return true;
     });
+    dbg_do!(ROC_PRINT_IR_AFTER_INLINING, {
+        return true;
+    });
     dbg_do!(ROC_PRINT_IR_AFTER_RESET_REUSE, {
         return true;
     });
@@ -436,6 +439,40 @@ impl<'a> Proc<'a> {
         }
     }
 
+    pub fn inline_and_fold<'i>(
+        arena: &'a Bump,
+        layout_interner: &'i STLayoutInterner<'a>,
+        home: ModuleId,
+        ident_ids: &'i mut IdentIds,
+        update_mode_ids: &'i mut UpdateModeIds,
+        procs: &mut MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+    ) {
+        crate::inline::inline_and_fold(
+            arena,
+            layout_interner,
+            home,
+            ident_ids,
+            update_mode_ids,
+            procs,
+        );
+    }
+
+    pub fn make_mutually_tail_recursive(
+        arena: &'a Bump,
+        layout_interner: &mut STLayoutInterner<'a>,
+        home: ModuleId,
+        ident_ids: &mut IdentIds,
+        procs: &mut MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+    ) {
+        crate::tail_recursion::make_mutually_tail_recursive(
+            arena,
+            layout_interner,
+            home,
+            ident_ids,
+            procs,
+        );
+    }
+
     fn make_tail_recursive(&mut self, env: &mut Env<'a, '_>) {
         let mut args = Vec::with_capacity_in(self.args.len(), env.arena);
         let mut proc_args = Vec::with_capacity_in(self.args.len(), env.arena);
@@ -1789,6 +1826,14 @@ impl CallSpecId {
     /// Dummy value for generating refcount helper procs in the backends
     /// This happens *after* specialization so it's safe
     pub const BACKEND_DUMMY: Self = Self { id: 0 };
+
+    /// Ids only need to be unique within a proc, so passes that copy calls from one proc into
+    /// another can number the calls in the resulting proc again, starting from here.
+    pub(crate) const FIRST: Self = Self { id: 1 };
+
+    pub(crate) fn next(self) -> Self {
+        Self { id: self.id + 1 }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -4073,6 +4118,16 @@ pub fn with_hole<'a>(
             hole,
         ),
 
+        StrInterpolation(loc_expr) => with_hole(
+            env,
+            loc_expr.value,
+            variable,
+            procs,
+            layout_cache,
+            assigned,
+            hole,
+        ),
+
         SingleQuote(_, _, character, _) => {
             let layout = layout_cache
                 .from_var(env.arena, variable, env.subs)
@@ -6749,7 +6804,7 @@ fn from_can_when<'a>(
     cond_var: Variable,
     expr_var: Variable,
     cond_symbol: Symbol,
-    branches: std::vec::Vec<roc_can::expr::WhenBranch>,
+    mut branches: std::vec::Vec<roc_can::expr::WhenBranch>,
     exhaustive_mark: ExhaustiveMark,
     layout_cache: &mut LayoutCache<'a>,
     procs: &mut Procs<'a>,
@@ -6760,6 +6815,10 @@ fn from_can_when<'a>(
         // We can't know what to return!
         return runtime_error(env, "Hit a 0-branch when expression");
     }
+
+    // A branch the parser skipped over matches anything, so try it last, where it cannot
+    // shadow the branches that follow it.
+    branches.sort_by_key(roc_can::expr::WhenBranch::is_malformed);
     let opt_branches = to_opt_branches(env, procs, branches, exhaustive_mark, layout_cache);
 
     let cond_layout = return_on_layout_error!(
@@ -840,6 +840,17 @@ impl<'a> STLayoutInterner<'a> {
     pub fn is_empty(&self) -> bool {
         self.vec.is_empty()
     }
+
+    /// Roughly how many bytes the interned layouts and their lookup tables take up, for
+    /// `--mem-stats`. This doesn't count what the layouts point to, which is in arenas.
+    pub fn memory_usage(&self) -> usize {
+        let layout_size = std::mem::size_of::<Layout<'a>>();
+        let lambda_set_size = std::mem::size_of::<LambdaSet<'a>>();
+
+        self.vec.capacity() * layout_size
+            + self.map.capacity() * (layout_size + std::mem::size_of::<InLayout<'a>>())
+            + self.normalized_lambda_set_map.capacity() * 2 * lambda_set_size
+    }
 }
 
 macro_rules! st_impl {
@@ -10,6 +10,7 @@
 pub mod borrow;
 pub mod code_gen_help;
 pub mod inc_dec;
+pub mod inline;
 pub mod ir;
 pub mod layout;
 pub mod layout_soa;
@@ -1,11 +1,19 @@
 #![allow(clippy::manual_map)]
 
 use crate::borrow::Ownership;
-use crate::ir::{CallType, Expr, JoinPointId, Param, Stmt};
-use crate::layout::{InLayout, LambdaName};
+use crate::inline::{call_graph, ProcKey};
+use crate::ir::{
+    BranchInfo, Call, CallSpecId, CallType, Expr, HostExposedLayouts, JoinPointId, Param, Proc,
+    ProcLayout, SelfRecursive, Stmt,
+};
+use crate::layout::{
+    InLayout, LambdaName, Layout, LayoutInterner, Niche, STLayoutInterner, TagIdIntType,
+    UnionLayout,
+};
 use bumpalo::collections::Vec;
 use bumpalo::Bump;
-use roc_module::symbol::Symbol;
+use roc_collections::all::{MutMap, MutSet};
+use roc_module::symbol::{IdentIds, ModuleId, Symbol};
 
 /// Make tail calls into loops (using join points)
 ///
@@ -323,3 +331,519 @@ fn insert_jumps<'a>(
         Crash(..) => None,
     }
 }
+
+/// Make mutually recursive tail calls into loops
+///
+/// `make_tail_recursive` only handles procs that call themselves. For a group of procs that
+/// call each other in tail position, e.g.
+///
+/// > isEven = \n -> if n == 0 then Bool.true else isOdd (n - 1)
+/// > isOdd = \n -> if n == 0 then Bool.false else isEven (n - 1)
+///
+/// the bodies are moved into one new proc. It takes a tag union with a tag for every proc in
+/// the group (holding that proc's arguments), and dispatches on it in a join point:
+///
+/// ```elm
+/// merged u1 =
+///     let joinpoint j u =
+///             when u is
+///                 IsEven n -> if n == 0 then Bool.true else jump j (IsOdd (n - 1))
+///                 IsOdd n -> if n == 0 then Bool.false else jump j (IsEven (n - 1))
+///     in
+///         jump j u1
+///
+/// isEven n = merged (IsEven n)
+/// isOdd n = merged (IsOdd n)
+/// ```
+///
+/// A group is only merged if every call between its procs is a tail call, and they all
+/// return the same layout.
+pub fn make_mutually_tail_recursive<'a>(
+    arena: &'a Bump,
+    interner: &mut STLayoutInterner<'a>,
+    home: ModuleId,
+    ident_ids: &mut IdentIds,
+    procs: &mut MutMap<ProcKey<'a>, Proc<'a>>,
+) {
+    let (keys, matrix) = call_graph(procs);
+    let sccs = matrix.strongly_connected_components_all();
+
+    let groups: std::vec::Vec<std::vec::Vec<ProcKey<'a>>> = sccs
+        .groups()
+        .filter(|(group, _)| group.count_ones() > 1)
+        .map(|(group, _)| group.iter_ones().map(|index| keys[index]).collect())
+        .collect();
+
+    for members in groups {
+        if can_merge(procs, &members) {
+            merge_group(arena, interner, home, ident_ids, procs, &members);
+        }
+    }
+}
+
+fn can_merge<'a>(procs: &MutMap<ProcKey<'a>, Proc<'a>>, members: &[ProcKey<'a>]) -> bool {
+    let result = members[0].1.result;
+
+    // The bodies end up in the same proc, so they can't bind the same symbols. That can happen
+    // when a function is specialized more than once.
+    let mut bound = MutSet::default();
+
+    members.iter().all(|key| {
+        let proc = &procs[key];
+
+        key.1.result == result
+            && proc.args.iter().all(|(_, symbol)| bound.insert(*symbol))
+            && binds_unique_symbols(&proc.body, &mut bound)
+            && calls_members_only_in_tail_position(&proc.body, members)
+    })
+}
+
+fn merge_group<'a>(
+    arena: &'a Bump,
+    interner: &mut STLayoutInterner<'a>,
+    home: ModuleId,
+    ident_ids: &mut IdentIds,
+    procs: &mut MutMap<ProcKey<'a>, Proc<'a>>,
+    members: &[ProcKey<'a>],
+) {
+    let tags = Vec::from_iter_in(
+        members.iter().map(|key| {
+            let it = procs[key].args.iter().map(|(layout, _)| *layout);
+
+            Vec::from_iter_in(it, arena).into_bump_slice()
+        }),
+        arena,
+    )
+    .into_bump_slice();
+
+    let union_layout = UnionLayout::NonRecursive(tags);
+    let tag_id_layout = union_layout.tag_id_layout();
+    let ret_layout = members[0].1.result;
+
+    let mut unique_symbol = || Symbol::new(home, ident_ids.gen_unique());
+
+    let dispatch = JoinPointId(unique_symbol());
+    let merged_name = LambdaName::no_niche(unique_symbol());
+    let argument = unique_symbol();
+    let current = unique_symbol();
+    let tag_id = unique_symbol();
+
+    let mut env = MergeEnv {
+        arena,
+        home,
+        ident_ids,
+        members,
+        union_layout,
+        union_in_layout: interner.insert(Layout::Union(union_layout)),
+        dispatch,
+        next_call_spec_id: CallSpecId::FIRST,
+    };
+
+    let mut branches = Vec::with_capacity_in(members.len(), arena);
+
+    for (index, key) in members.iter().enumerate() {
+        let proc = &procs[key];
+        let mut branch = env.dispatch_tail_calls(&proc.body);
+
+        for (field, (layout, symbol)) in proc.args.iter().enumerate().rev() {
+            let expr = Expr::UnionAtIndex {
+                structure: current,
+                tag_id: index as TagIdIntType,
+                union_layout,
+                index: field as u64,
+            };
+
+            branch = Stmt::Let(*symbol, expr, *layout, arena.alloc(branch));
+        }
+
+        branches.push((index as u64, BranchInfo::None, branch));
+    }
+
+    let (_, _, last_branch) = branches.pop().unwrap();
+
+    let switch = Stmt::Switch {
+        cond_symbol: tag_id,
+        cond_layout: tag_id_layout,
+        branches: branches.into_bump_slice(),
+        default_branch: (BranchInfo::None, arena.alloc(last_branch)),
+        ret_layout,
+    };
+
+    let get_tag_id = Expr::GetTagId {
+        structure: current,
+        union_layout,
+    };
+
+    let body = Stmt::Join {
+        id: env.dispatch,
+        parameters: arena.alloc([Param {
+            symbol: current,
+            ownership: Ownership::Owned,
+            layout: env.union_in_layout,
+        }]),
+        body: arena.alloc(Stmt::Let(
+            tag_id,
+            get_tag_id,
+            tag_id_layout,
+            arena.alloc(switch),
+        )),
+        remainder: arena.alloc(Stmt::Jump(env.dispatch, arena.alloc([argument]))),
+    };
+
+    let merged = Proc {
+        name: merged_name,
+        args: arena.alloc([(env.union_in_layout, argument)]),
+        body,
+        closure_data_layout: None,
+        ret_layout,
+        is_self_recursive: SelfRecursive::NotSelfRecursive,
+        must_own_arguments: false,
+        host_exposed_layouts: HostExposedLayouts::NotHostExposed,
+    };
+
+    let merged_layout = ProcLayout {
+        arguments: arena.alloc([env.union_in_layout]),
+        result: ret_layout,
+        niche: Niche::NONE,
+    };
+
+    // Every proc in the group now wraps its arguments in its tag, and calls the merged proc.
+    for (index, key) in members.iter().enumerate() {
+        let tag = env.unique_symbol();
+        let result = env.unique_symbol();
+        let proc = procs.get_mut(key).unwrap();
+
+        let arguments = Vec::from_iter_in(proc.args.iter().map(|(_, symbol)| *symbol), arena);
+        let tag_expr = Expr::Tag {
+            tag_layout: union_layout,
+            tag_id: index as TagIdIntType,
+            arguments: arguments.into_bump_slice(),
+        };
+
+        let call = Expr::Call(Call {
+            call_type: CallType::ByName {
+                name: merged_name,
+                ret_layout,
+                arg_layouts: merged_layout.arguments,
+                specialization_id: CallSpecId::FIRST,
+            },
+            arguments: arena.alloc([tag]),
+        });
+
+        let ret = arena.alloc(Stmt::Ret(result));
+        let call_stmt = arena.alloc(Stmt::Let(result, call, ret_layout, ret));
+
+        proc.body = Stmt::Let(tag, tag_expr, env.union_in_layout, call_stmt);
+        proc.is_self_recursive = SelfRecursive::NotSelfRecursive;
+    }
+
+    procs.insert((merged_name.name(), merged_layout), merged);
+}
+
+struct MergeEnv<'a, 'r> {
+    arena: &'a Bump,
+    home: ModuleId,
+    ident_ids: &'r mut IdentIds,
+    members: &'r [ProcKey<'a>],
+    union_layout: UnionLayout<'a>,
+    union_in_layout: InLayout<'a>,
+    dispatch: JoinPointId,
+    /// The merged proc has the calls of all the procs in the group, so they're numbered again
+    next_call_spec_id: CallSpecId,
+}
+
+impl<'a, 'r> MergeEnv<'a, 'r> {
+    fn unique_symbol(&mut self) -> Symbol {
+        let ident_id = self.ident_ids.gen_unique();
+
+        Symbol::new(self.home, ident_id)
+    }
+
+    fn next_call_spec_id(&mut self) -> CallSpecId {
+        let id = self.next_call_spec_id;
+        self.next_call_spec_id = id.next();
+
+        id
+    }
+
+    /// Replace tail calls to procs in the group with jumps to the dispatching join point
+    fn dispatch_tail_calls(&mut self, stmt: &Stmt<'a>) -> Stmt<'a> {
+        use Stmt::*;
+
+        match stmt {
+            Let(symbol, expr, layout, cont) => {
+                if let (Expr::Call(call), Ret(returned)) = (expr, cont) {
+                    let opt_index = member_key(&call.call_type)
+                        .and_then(|key| self.members.iter().position(|member| *member == key));
+
+                    if let (true, Some(index)) = (symbol == returned, opt_index) {
+                        let tag = self.unique_symbol();
+                        let tag_expr = Expr::Tag {
+                            tag_layout: self.union_layout,
+                            tag_id: index as TagIdIntType,
+                            arguments: call.arguments,
+                        };
+                        let jump = Jump(self.dispatch, self.arena.alloc([tag]));
+
+                        return Let(tag, tag_expr, self.union_in_layout, self.arena.alloc(jump));
+                    }
+                }
+
+                let expr = match expr {
+                    Expr::Call(call) => Expr::Call(self.renumber_call(call)),
+                    _ => expr.clone(),
+                };
+                let cont = self.dispatch_tail_calls(cont);
+
+                Let(*symbol, expr, *layout, self.arena.alloc(cont))
+            }
+            Switch {
+                cond_symbol,
+                cond_layout,
+                branches,
+                default_branch,
+                ret_layout,
+            } => {
+                let mut new_branches = Vec::with_capacity_in(branches.len(), self.arena);
+
+                for (value, info, branch) in branches.iter() {
+                    let branch = self.dispatch_tail_calls(branch);
+
+                    new_branches.push((*value, info.clone(), branch));
+                }
+
+                let default_stmt = self.dispatch_tail_calls(default_branch.1);
+
+                Switch {
+                    cond_symbol: *cond_symbol,
+                    cond_layout: *cond_layout,
+                    branches: new_branches.into_bump_slice(),
+                    default_branch: (default_branch.0.clone(), self.arena.alloc(default_stmt)),
+                    ret_layout: *ret_layout,
+                }
+            }
+            Join {
+                id,
+                parameters,
+                body,
+                remainder,
+            } => {
+                let body = self.dispatch_tail_calls(body);
+                let remainder = self.dispatch_tail_calls(remainder);
+
+                Join {
+                    id: *id,
+                    parameters,
+                    body: self.arena.alloc(body),
+                    remainder: self.arena.alloc(remainder),
+                }
+            }
+            Refcounting(modify, cont) => {
+                let cont = self.dispatch_tail_calls(cont);
+
+                Refcounting(*modify, self.arena.alloc(cont))
+            }
+            Expect {
+                condition,
+                region,
+                lookups,
+                variables,
+                remainder,
+            } => {
+                let remainder = self.dispatch_tail_calls(remainder);
+
+                Expect {
+                    condition: *condition,
+                    region: *region,
+                    lookups,
+                    variables,
+                    remainder: self.arena.alloc(remainder),
+                }
+            }
+            ExpectFx {
+                condition,
+                region,
+                lookups,
+                variables,
+                remainder,
+            } => {
+                let remainder = self.dispatch_tail_calls(remainder);
+
+                ExpectFx {
+                    condition: *condition,
+                    region: *region,
+                    lookups,
+                    variables,
+                    remainder: self.arena.alloc(remainder),
+                }
+            }
+            Dbg {
+                symbol,
+                variable,
+                remainder,
+            } => {
+                let remainder = self.dispatch_tail_calls(remainder);
+
+                Dbg {
+                    symbol: *symbol,
+                    variable: *variable,
+                    remainder: self.arena.alloc(remainder),
+                }
+            }
+            Ret(_) | Jump(_, _) | Crash(_, _) => stmt.clone(),
+        }
+    }
+
+    fn renumber_call(&mut self, call: &Call<'a>) -> Call<'a> {
+        let call_type = match &call.call_type {
+            CallType::ByName {
+                name,
+                ret_layout,
+                arg_layouts,
+                ..
+            } => CallType::ByName {
+                name: *name,
+                ret_layout: *ret_layout,
+                arg_layouts,
+                specialization_id: self.next_call_spec_id(),
+            },
+            CallType::HigherOrder(higher_order) => {
+                let mut higher_order = (*higher_order).clone();
+                higher_order.passed_function.specialization_id = self.next_call_spec_id();
+
+                CallType::HigherOrder(self.arena.alloc(higher_order))
+            }
+            CallType::Foreign { .. } | CallType::LowLevel { .. } => call.call_type.clone(),
+        };
+
+        Call {
+            call_type,
+            arguments: call.arguments,
+        }
+    }
+}
+
+/// The proc that a call calls by name
+fn member_key<'a>(call_type: &CallType<'a>) -> Option<ProcKey<'a>> {
+    match call_type {
+        CallType::ByName {
+            name,
+            ret_layout,
+            arg_layouts,
+            ..
+        } => {
+            let layout = ProcLayout {
+                arguments: arg_layouts,
+                result: *ret_layout,
+                niche: name.niche(),
+            };
+
+            Some((name.name(), layout))
+        }
+        _ => None,
+    }
+}
+
+fn calls_members_only_in_tail_position<'a>(stmt: &Stmt<'a>, members: &[ProcKey<'a>]) -> bool {
+    use Stmt::*;
+
+    let is_member = |key: Option<ProcKey<'a>>| matches!(key, Some(key) if members.contains(&key));
+
+    match stmt {
+        Let(symbol, Expr::Call(call), _, Ret(returned))
+            if symbol == returned && is_member(member_key(&call.call_type)) =>
+        {
+            true
+        }
+        Let(_, expr, _, cont) => {
+            let calls_member = match expr {
+                Expr::Call(Call {
+                    call_type: CallType::HigherOrder(higher_order),
+                    ..
+                }) => {
+                    let passed_function = &higher_order.passed_function;
+                    let layout = ProcLayout {
+                        arguments: passed_function.argument_layouts,
+                        result: passed_function.return_layout,
+                        niche: passed_function.name.niche(),
+                    };
+
+                    is_member(Some((passed_function.name.name(), layout)))
+                }
+                Expr::Call(call) => is_member(member_key(&call.call_type)),
+                _ => false,
+            };
+
+            !calls_member && calls_members_only_in_tail_position(cont, members)
+        }
+        Switch {
+            branches,
+            default_branch,
+            ..
+        } => {
+            branches
+                .iter()
+                .all(|(_, _, branch)| calls_members_only_in_tail_position(branch, members))
+                && calls_members_only_in_tail_position(default_branch.1, members)
+        }
+        Join {
+            body, remainder, ..
+        } => {
+            calls_members_only_in_tail_position(body, members)
+                && calls_members_only_in_tail_position(remainder, members)
+        }
+        Refcounting(_, cont)
+        | Expect {
+            remainder: cont, ..
+        }
+        | ExpectFx {
+            remainder: cont, ..
+        }
+        | Dbg {
+            remainder: cont, ..
+        } => calls_members_only_in_tail_position(cont, members),
+        Ret(_) | Jump(_, _) | Crash(_, _) => true,
+    }
+}
+
+/// Adds the symbols (and join points) bound in the statement to `bound`, returning false if any
+/// of them was already there.
+fn binds_unique_symbols(stmt: &Stmt<'_>, bound: &mut MutSet<Symbol>) -> bool {
+    use Stmt::*;
+
+    match stmt {
+        Let(symbol, _, _, cont) => bound.insert(*symbol) && binds_unique_symbols(cont, bound),
+        Switch {
+            branches,
+            default_branch,
+            ..
+        } => {
+            branches
+                .iter()
+                .all(|(_, _, branch)| binds_unique_symbols(branch, bound))
+                && binds_unique_symbols(default_branch.1, bound)
+        }
+        Join {
+            id,
+            parameters,
+            body,
+            remainder,
+        } => {
+            bound.insert(id.0)
+                && parameters.iter().all(|param| bound.insert(param.symbol))
+                && binds_unique_symbols(body, bound)
+                && binds_unique_symbols(remainder, bound)
+        }
+        Refcounting(_, cont)
+        | Expect {
+            remainder: cont, ..
+        }
+        | ExpectFx {
+            remainder: cont, ..
+        }
+        | Dbg {
+            remainder: cont, ..
+        } => binds_unique_symbols(cont, bound),
+        Ret(_) | Jump(_, _) | Crash(_, _) => true,
+    }
+}
@@ -261,6 +261,9 @@ pub enum Expr<'a> {
 
     Record(Collection<'a, Loc<AssignedField<'a, Expr<'a>>>>),
 
+    /// A record whose fields are built by applying a function, e.g. `{ name: <- apply getName }`
+    RecordBuilder(Collection<'a, Loc<RecordBuilderField<'a>>>),
+
     Tuple(Collection<'a, &'a Loc<Expr<'a>>>),
 
     // Lookups
@@ -317,9 +320,15 @@ pub enum Expr<'a> {
     // Problems
     MalformedIdent(&'a str, crate::ident::BadIdent),
     MalformedClosure,
+    /// Source text the parser skipped while recovering from a syntax error
+    Malformed(&'a str),
     // Both operators were non-associative, e.g. (True == False == False).
     // We should tell the author to disambiguate by grouping them with parens.
     PrecedenceConflict(&'a PrecedenceConflict<'a>),
+    // A function was applied to more than one record builder, e.g. `succeed { a: <- x } { b: <- y }`
+    MultipleRecordBuilders(&'a Loc<Expr<'a>>),
+    // A record builder was used without applying a function to it
+    UnappliedRecordBuilder(&'a Loc<Expr<'a>>),
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -432,6 +441,10 @@ pub enum ValueDef<'a> {
         condition: &'a Loc<Expr<'a>>,
         preceding_comment: Region,
     },
+
+    /// A top-level def with a syntax error, which the parser recovered from by skipping
+    /// ahead to the next def. Holds the source text that was skipped.
+    Malformed(&'a str),
 }
 
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -680,6 +693,30 @@ pub enum AssignedField<'a, Val> {
     Malformed(&'a str),
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RecordBuilderField<'a> {
+    // A field with a value, e.g. `{ name: "blah" }`
+    Value(Loc<&'a str>, &'a [CommentOrNewline<'a>], &'a Loc<Expr<'a>>),
+
+    // A field with a function we can apply to build part of the record, e.g. `{ name: <- apply getName }`
+    ApplyValue(
+        Loc<&'a str>,
+        &'a [CommentOrNewline<'a>],
+        &'a [CommentOrNewline<'a>],
+        &'a Loc<Expr<'a>>,
+    ),
+
+    // A label with no value, e.g. `{ name }` (this is sugar for { name: name })
+    LabelOnly(Loc<&'a str>),
+
+    // We preserve this for the formatter; canonicalization ignores it.
+    SpaceBefore(&'a RecordBuilderField<'a>, &'a [CommentOrNewline<'a>]),
+    SpaceAfter(&'a RecordBuilderField<'a>, &'a [CommentOrNewline<'a>]),
+
+    /// A malformed assigned field, which will code gen to a runtime error
+    Malformed(&'a str),
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CommentOrNewline<'a> {
     Newline,
@@ -1194,6 +1231,15 @@ impl<'a, Val> Spaceable<'a> for AssignedField<'a, Val> {
     }
 }
 
+impl<'a> Spaceable<'a> for RecordBuilderField<'a> {
+    fn before(&'a self, spaces: &'a [CommentOrNewline<'a>]) -> Self {
+        RecordBuilderField::SpaceBefore(self, spaces)
+    }
+    fn after(&'a self, spaces: &'a [CommentOrNewline<'a>]) -> Self {
+        RecordBuilderField::SpaceAfter(self, spaces)
+    }
+}
+
 impl<'a> Spaceable<'a> for Tag<'a> {
     fn before(&'a self, spaces: &'a [CommentOrNewline<'a>]) -> Self {
         Tag::SpaceBefore(self, spaces)
@@ -1477,6 +1523,7 @@ impl<'a> Malformed for Expr<'a> {
 
             RecordUpdate { update, fields } => update.is_malformed() || fields.is_malformed(),
             Record(items) => items.is_malformed(),
+            RecordBuilder(items) => items.is_malformed(),
             Tuple(items) => items.is_malformed(),
 
             Closure(args, body) => args.iter().any(|arg| arg.is_malformed()) || body.is_malformed(),
@@ -1496,7 +1543,10 @@ impl<'a> Malformed for Expr<'a> {
 
             MalformedIdent(_, _) |
             MalformedClosure |
-            PrecedenceConflict(_) => true,
+            Malformed(_) |
+            PrecedenceConflict(_) |
+            MultipleRecordBuilders(_) |
+            UnappliedRecordBuilder(_) => true,
         }
     }
 }
@@ -1571,6 +1621,19 @@ impl<'a, T: Malformed> Malformed for AssignedField<'a, T> {
     }
 }
 
+impl<'a> Malformed for RecordBuilderField<'a> {
+    fn is_malformed(&self) -> bool {
+        match self {
+            RecordBuilderField::Value(_, _, expr)
+            | RecordBuilderField::ApplyValue(_, _, _, expr) => expr.is_malformed(),
+            RecordBuilderField::LabelOnly(_) => false,
+            RecordBuilderField::SpaceBefore(field, _)
+            | RecordBuilderField::SpaceAfter(field, _) => field.is_malformed(),
+            RecordBuilderField::Malformed(_) => true,
+        }
+    }
+}
+
 impl<'a> Malformed for Pattern<'a> {
     fn is_malformed(&self) -> bool {
         use Pattern::*;
@@ -1710,6 +1773,7 @@ impl<'a> Malformed for ValueDef<'a> {
                 condition,
                 preceding_comment: _,
             } => condition.is_malformed(),
+            ValueDef::Malformed(_) => true,
         }
     }
 }
@@ -1,6 +1,6 @@
 use crate::ast::{
     AssignedField, Collection, CommentOrNewline, Defs, Expr, ExtractSpaces, Has, HasAbilities,
-    Pattern, Spaceable, TypeAnnotation, TypeDef, TypeHeader, ValueDef,
+    Pattern, RecordBuilderField, Spaceable, TypeAnnotation, TypeDef, TypeHeader, ValueDef,
 };
 use crate::blankspace::{
     space0_after_e, space0_around_e_no_after_indent_check, space0_around_ee, space0_before_e,
@@ -12,7 +12,7 @@ use crate::parser::{
     self, backtrackable, increment_min_indent, line_min_indent, optional, reset_min_indent,
     sep_by1, sep_by1_e, set_min_indent, specialize, specialize_ref, then, word1, word1_indent,
     word2, EClosure, EExpect, EExpr, EIf, EInParens, EList, ENumber, EPattern, ERecord, EString,
-    EType, EWhen, Either, ParseResult, Parser,
+    EType, EWhen, Either, ParseResult, Parser, SyntaxError,
 };
 use crate::pattern::{closure_param, loc_has_parser};
 use crate::state::State;
@@ -937,83 +937,76 @@ fn parse_defs_end<'a>(
 
         global_state = match parse_single_def(options, min_indent, arena, state) {
             Ok((_, Some(single_def), next_state)) => {
-                let region = single_def.region;
-                let spaces_before_current = single_def.spaces_before;
+                push_single_def(arena, &mut defs, single_def);
 
-                match single_def.type_or_value {
-                    Either::First(type_def) => {
-                        defs.push_type_def(type_def, region, spaces_before_current, &[]);
-                    }
-                    Either::Second(value_def) => {
-                        // If we got a ValueDef::Body, check if a type annotation preceded it.
-                        // If so, we may need to combine them into an AnnotatedBody.
-                        let joined = match value_def {
-                            ValueDef::Body(loc_pattern, loc_def_expr)
-                                if spaces_before_current.len() <= 1 =>
-                            {
-                                let region =
-                                    Region::span_across(&loc_pattern.region, &loc_def_expr.region);
-
-                                match defs.last() {
-                                    Some(Err(ValueDef::Annotation(ann_pattern, ann_type))) => {
-                                        let (value_def, region) = join_ann_to_body!(
-                                            arena,
-                                            loc_pattern,
-                                            loc_def_expr,
-                                            ann_pattern,
-                                            ann_type,
-                                            spaces_before_current,
-                                            region
-                                        );
-
-                                        defs.replace_with_value_def(
-                                            defs.tags.len() - 1,
-                                            value_def,
-                                            region,
-                                        );
-
-                                        true
-                                    }
-                                    Some(Ok(TypeDef::Alias {
-                                        header,
-                                        ann: ann_type,
-                                    })) => {
-                                        let (value_def, region) = join_alias_to_body!(
-                                            arena,
-                                            loc_pattern,
-                                            loc_def_expr,
-                                            header,
-                                            ann_type,
-                                            spaces_before_current,
-                                            region
-                                        );
-
-                                        defs.replace_with_value_def(
-                                            defs.tags.len() - 1,
-                                            value_def,
-                                            region,
-                                        );
-
-                                        true
-                                    }
-                                    _ => false,
-                                }
-                            }
-                            _ => false,
-                        };
+                next_state
+            }
+            Ok((progress, None, s)) => return Ok((progress, defs, s)),
+            Err((progress, err)) => return Err((progress, err)),
+        };
+    }
+}
+
+fn push_single_def<'a>(arena: &'a Bump, defs: &mut Defs<'a>, single_def: SingleDef<'a>) {
+    let region = single_def.region;
+    let spaces_before_current = single_def.spaces_before;
 
-                        if !joined {
-                            // the previous and current def can't be joined up
-                            defs.push_value_def(value_def, region, spaces_before_current, &[]);
+    match single_def.type_or_value {
+        Either::First(type_def) => {
+            defs.push_type_def(type_def, region, spaces_before_current, &[]);
+        }
+        Either::Second(value_def) => {
+            // If we got a ValueDef::Body, check if a type annotation preceded it.
+            // If so, we may need to combine them into an AnnotatedBody.
+            let joined = match value_def {
+                ValueDef::Body(loc_pattern, loc_def_expr) if spaces_before_current.len() <= 1 => {
+                    let region = Region::span_across(&loc_pattern.region, &loc_def_expr.region);
+
+                    match defs.last() {
+                        Some(Err(ValueDef::Annotation(ann_pattern, ann_type))) => {
+                            let (value_def, region) = join_ann_to_body!(
+                                arena,
+                                loc_pattern,
+                                loc_def_expr,
+                                ann_pattern,
+                                ann_type,
+                                spaces_before_current,
+                                region
+                            );
+
+                            defs.replace_with_value_def(defs.tags.len() - 1, value_def, region);
+
+                            true
                         }
+                        Some(Ok(TypeDef::Alias {
+                            header,
+                            ann: ann_type,
+                        })) => {
+                            let (value_def, region) = join_alias_to_body!(
+                                arena,
+                                loc_pattern,
+                                loc_def_expr,
+                                header,
+                                ann_type,
+                                spaces_before_current,
+                                region
+                            );
+
+                            defs.replace_with_value_def(defs.tags.len() - 1, value_def, region);
+
+                            true
+                        }
+                        _ => false,
                     }
                 }
+                _ => false,
+            };
 
-                next_state
+            if !joined {
+                // the previous and current def can't be joined up
+                defs.push_value_def(value_def, region, spaces_before_current, &[]);
             }
-            Ok((progress, None, s)) => return Ok((progress, defs, s)),
-            Err((progress, err)) => return Err((progress, err)),
-        };
+        }
     }
 }
 
@@ -1886,8 +1879,12 @@ fn expr_to_pattern_help<'a>(arena: &'a Bump, expr: &Expr<'a>) -> Result<Pattern<
         | Expr::Expect(_, _)
         | Expr::Dbg(_, _)
         | Expr::MalformedClosure
+        | Expr::Malformed(_)
         | Expr::PrecedenceConflict { .. }
+        | Expr::MultipleRecordBuilders { .. }
+        | Expr::UnappliedRecordBuilder { .. }
         | Expr::RecordUpdate { .. }
+        | Expr::RecordBuilder { .. }
         | Expr::UnaryOp(_, _)
         | Expr::Crash => Err(()),
 
@@ -1960,7 +1957,13 @@ pub fn toplevel_defs<'a>() -> impl Parser<'a, Defs<'a>, EExpr<'a>> {
         let mut output = Defs::default();
         let before = Slice::extend_new(&mut output.spaces, initial_space.iter().copied());
 
-        let (_, mut output, state) = parse_defs_end(options, start_column, output, arena, state)?;
+        let state = if state.recovers_from_errors() {
+            parse_toplevel_defs_recovering(options, start_column, &mut output, arena, state)
+        } else {
+            let (_, defs, state) = parse_defs_end(options, start_column, output, arena, state)?;
+            output = defs;
+            state
+        };
 
         let (_, final_space, state) =
             space0_e(EExpr::IndentEnd).parse(arena, state, start_column)?;
@@ -1981,6 +1984,170 @@ pub fn toplevel_defs<'a>() -> impl Parser<'a, Defs<'a>, EExpr<'a>> {
     }
 }
 
+/// Like [parse_defs_end], but rather than stopping at a syntax error, records it along with a
+/// malformed def, and carries on parsing at the next line that starts a def.
+fn parse_toplevel_defs_recovering<'a>(
+    options: ExprParseOptions,
+    min_indent: u32,
+    defs: &mut Defs<'a>,
+    arena: &'a Bump,
+    mut state: State<'a>,
+) -> State<'a> {
+    loop {
+        let error = match parse_single_def(options, min_indent, arena, state.clone()) {
+            Ok((_, Some(single_def), next_state)) => {
+                push_single_def(arena, defs, single_def);
+                state = next_state;
+
+                continue;
+            }
+            Ok((_, None, next_state)) => {
+                let at_end = match space0_e(EExpr::IndentEnd).parse(arena, next_state.clone(), 0) {
+                    Ok((_, _, end_state)) => end_state.has_reached_end(),
+                    Err(_) => false,
+                };
+
+                if at_end {
+                    return next_state;
+                }
+
+                // This line is not a def, so it's no use parsing any further in the module
+                // until we find one.
+                None
+            }
+            Err((_, error)) => Some(error),
+        };
+
+        let next_state = recover_toplevel_def(arena, min_indent, defs, state.clone(), error);
+
+        if next_state.pos() == state.pos() {
+            // we could not skip anything, so give up on the rest of the module
+            return next_state;
+        }
+
+        state = next_state;
+    }
+}
+
+fn recover_toplevel_def<'a>(
+    arena: &'a Bump,
+    min_indent: u32,
+    defs: &mut Defs<'a>,
+    state: State<'a>,
+    error: Option<EExpr<'a>>,
+) -> State<'a> {
+    let (spaces_before, def_state) =
+        match space0_e(EExpr::IndentStart).parse(arena, state.clone(), 0) {
+            Ok((_, spaces, def_state)) => (spaces, def_state),
+            Err(_) => (&[] as &[_], state),
+        };
+
+    let start = def_state.pos();
+    let mut end_state = def_state.clone().skip_indented_lines(min_indent);
+    let region = Region::new(start, end_state.pos());
+
+    end_state.record_error(
+        arena,
+        match &error {
+            Some(error) => SyntaxError::Expr(error.clone(), start),
+            None => SyntaxError::NotEndOfFile(start),
+        },
+    );
+
+    // Indented lines the previous def could not take are really part of its body, so that body
+    // is malformed rather than this being a def of its own.
+    if error.is_none()
+        && def_state.column() > min_indent
+        && malform_last_body(arena, defs, &end_state)
+    {
+        return end_state;
+    }
+
+    // If we can make out the name the def is for, keep it, so that the rest of the
+    // module can still refer to it.
+    let value_def = match malformed_def_body(arena, min_indent, def_state, &end_state) {
+        Some((loc_pattern, loc_body)) => {
+            ValueDef::Body(arena.alloc(loc_pattern), arena.alloc(loc_body))
+        }
+        None => ValueDef::Malformed(end_state.source_text(region)),
+    };
+
+    let single_def = SingleDef {
+        type_or_value: Either::Second(value_def),
+        region,
+        spaces_before,
+    };
+
+    push_single_def(arena, defs, single_def);
+
+    end_state
+}
+
+/// Replaces the body of the last def with a malformed expression reaching up to `end_state`.
+/// Returns false when the last def has no body to replace.
+fn malform_last_body<'a>(arena: &'a Bump, defs: &mut Defs<'a>, end_state: &State<'a>) -> bool {
+    let last = match defs.tags.last() {
+        Some(tag) => tag.split(),
+        None => return false,
+    };
+
+    let value_def = match last {
+        Err(value_index) => &mut defs.value_defs[value_index.index()],
+        Ok(_) => return false,
+    };
+
+    let body_expr = match value_def {
+        ValueDef::Body(_, body_expr) | ValueDef::AnnotatedBody { body_expr, .. } => body_expr,
+        _ => return false,
+    };
+
+    let region = Region::new(body_expr.region.start(), end_state.pos());
+    let malformed = Loc::at(region, Expr::Malformed(end_state.source_text(region)));
+
+    *body_expr = arena.alloc(malformed);
+
+    let last_region = defs.regions.last_mut().unwrap();
+    *last_region = Region::span_across(last_region, &region);
+
+    true
+}
+
+/// For a malformed def of the form `pattern = ...`, returns the pattern along with a malformed
+/// expression standing in for the body.
+fn malformed_def_body<'a>(
+    arena: &'a Bump,
+    min_indent: u32,
+    def_state: State<'a>,
+    end_state: &State<'a>,
+) -> Option<(Loc<Pattern<'a>>, Loc<Expr<'a>>)> {
+    let (_, loc_pattern, state) =
+        space0_after_e(crate::pattern::loc_pattern_help(), EPattern::IndentEnd)
+            .parse(arena, def_state, min_indent)
+            .ok()?;
+
+    match operator().parse(arena, state, min_indent) {
+        Ok((_, BinOp::Assignment, state)) if state.pos() <= end_state.pos() => {
+            let loc_body = malformed_after(state, end_state);
+
+            Some((loc_pattern, loc_body))
+        }
+        _ => None,
+    }
+}
+
+/// A malformed expression standing in for the source between the two states, ignoring
+/// leading whitespace.
+fn malformed_after<'a>(state: State<'a>, end_state: &State<'a>) -> Loc<Expr<'a>> {
+    let text = end_state.source_text(Region::new(state.pos(), end_state.pos()));
+    let trimmed = text.trim_start();
+    let start = state.pos().bump_column((text.len() - trimmed.len()) as u32);
+
+    Loc::at(
+        Region::new(start, end_state.pos()),
+        Expr::Malformed(trimmed),
+    )
+}
+
 // PARSER HELPERS
 
 fn closure_help<'a>(options: ExprParseOptions) -> impl Parser<'a, Expr<'a>, EClosure<'a>> {
@@ -2029,32 +2196,43 @@ mod when {
 
     /// Parser for when expressions.
     pub fn expr_help<'a>(options: ExprParseOptions) -> impl Parser<'a, Expr<'a>, EWhen<'a>> {
-        map_with_arena!(
-            and!(
-                indented_seq!(
-                    parser::keyword_e(keyword::WHEN, EWhen::When),
-                    space0_around_e_no_after_indent_check(
-                        specialize_ref(EWhen::Condition, expr_start(options)),
-                        EWhen::IndentCondition,
-                    )
-                ),
-                // Note that we allow the `is` to be at any indent level, since this doesn't introduce any
-                // ambiguity. The formatter will fix it up.
-                //
-                // We require that branches are indented relative to the line containing the `is`.
-                indented_seq!(
-                    parser::keyword_e(keyword::IS, EWhen::Is),
-                    branches(options)
-                )
-            ),
-            move |arena: &'a Bump, (loc_condition, branches): (Loc<Expr<'a>>, Vec<'a, &'a WhenBranch<'a>>)| {
-                Expr::When(arena.alloc(loc_condition), branches.into_bump_slice())
-            }
-        )
+        move |arena, state: State<'a>, min_indent: u32| {
+            let when_start = state.pos();
+
+            let parser = map_with_arena!(
+                    and!(
+                        indented_seq!(
+                            parser::keyword_e(keyword::WHEN, EWhen::When),
+                            space0_around_e_no_after_indent_check(
+                                specialize_ref(EWhen::Condition, expr_start(options)),
+                                EWhen::IndentCondition,
+                            )
+                        ),
+                        // Note that we allow the `is` to be at any indent level, since this doesn't introduce any
+                        // ambiguity. The formatter will fix it up.
+                        //
+                        // We require that branches are indented relative to the line containing the `is`.
+                        indented_seq!(
+                            parser::keyword_e(keyword::IS, EWhen::Is),
+                            branches(options, when_start)
+                        )
+                    ),
+                    move |arena: &'a Bump,
+                          (loc_condition, branches): (
+                        Loc<Expr<'a>>,
+                        Vec<'a, &'a WhenBranch<'a>>
+                    )| {
+                        Expr::When(arena.alloc(loc_condition), branches.into_bump_slice())
+                    }
+                );
+
+            parser.parse(arena, state, min_indent)
+        }
     }
 
     fn branches<'a>(
         options: ExprParseOptions,
+        when_start: Position,
     ) -> impl Parser<'a, Vec<'a, &'a WhenBranch<'a>>, EWhen<'a>> {
         move |arena, state: State<'a>, min_indent: u32| {
             let mut branches: Vec<'a, &'a WhenBranch<'a>> = Vec::with_capacity_in(2, arena);
@@ -2062,24 +2240,32 @@ mod when {
             // 1. Parse the first branch and get its indentation level. (It must be >= min_indent.)
             // 2. Parse the other branches. Their indentation levels must be == the first branch's.
 
-            let (_, ((pattern_indent_level, loc_first_patterns), loc_first_guard), state): (
-                _,
-                ((_, _), _),
-                State<'a>,
-            ) = branch_alternatives(options, None).parse(arena, state, min_indent)?;
+            let (pattern_indent_level, mut state) =
+                match first_branch(options).parse(arena, state.clone(), min_indent) {
+                    Ok((_, (pattern_indent_level, branch), state)) => {
+                        // Record this as the first branch, then optionally parse additional branches.
+                        branches.push(arena.alloc(branch));
 
-            let original_indent = pattern_indent_level;
+                        (pattern_indent_level, state)
+                    }
+                    Err((MadeProgress, problem)) if state.recovers_from_errors() => {
+                        // The first branch sets the indentation of the others
+                        let pattern_indent_level =
+                            match space0_e(EWhen::IndentPattern).parse(arena, state.clone(), 0) {
+                                Ok((_, _, pattern_state)) => pattern_state.column(),
+                                Err(_) => return Err((MadeProgress, problem)),
+                            };
 
-            // Parse the first "->" and the expression after it.
-            let (_, loc_first_expr, mut state) =
-                branch_result(original_indent + 1).parse(arena, state, original_indent + 1)?;
+                        let (branch, state) =
+                            recover_branch(pattern_indent_level, when_start, problem, arena, state);
+                        branches.push(branch);
 
-            // Record this as the first branch, then optionally parse additional branches.
-            branches.push(arena.alloc(WhenBranch {
-                patterns: loc_first_patterns.into_bump_slice(),
-                value: loc_first_expr,
-                guard: loc_first_guard,
-            }));
+                        (pattern_indent_level, state)
+                    }
+                    Err(fail) => return Err(fail),
+                };
+
+            let original_indent = pattern_indent_level;
 
             let branch_parser = map!(
                 and!(
@@ -2113,6 +2299,14 @@ mod when {
 
                         branches.push(arena.alloc(next_output));
                     }
+                    Err((MadeProgress, problem)) if state.recovers_from_errors() => {
+                        let (branch, next_state) =
+                            recover_branch(pattern_indent_level, when_start, problem, arena, state);
+
+                        state = next_state;
+
+                        branches.push(branch);
+                    }
                     Err((MadeProgress, problem)) => {
                         return Err((MadeProgress, problem));
                     }
@@ -2126,6 +2320,71 @@ mod when {
         }
     }
 
+    fn first_branch<'a>(
+        options: ExprParseOptions,
+    ) -> impl Parser<'a, (u32, WhenBranch<'a>), EWhen<'a>> {
+        move |arena, state: State<'a>, min_indent: u32| {
+            let (_, ((pattern_indent_level, loc_first_patterns), loc_first_guard), state): (
+                _,
+                ((_, _), _),
+                State<'a>,
+            ) = branch_alternatives(options, None).parse(arena, state, min_indent)?;
+
+            let original_indent = pattern_indent_level;
+
+            // Parse the first "->" and the expression after it.
+            let (_, loc_first_expr, state) =
+                branch_result(original_indent + 1).parse(arena, state, original_indent + 1)?;
+
+            let branch = WhenBranch {
+                patterns: loc_first_patterns.into_bump_slice(),
+                value: loc_first_expr,
+                guard: loc_first_guard,
+            };
+
+            Ok((MadeProgress, (pattern_indent_level, branch), state))
+        }
+    }
+
+    /// Records a syntax error in a `when` branch, and skips ahead to the next branch. The
+    /// branch becomes a `_` branch with a malformed body, which is tried after all the other
+    /// branches, and the `when` is not checked for missing or redundant patterns.
+    fn recover_branch<'a>(
+        pattern_indent_level: u32,
+        when_start: Position,
+        problem: EWhen<'a>,
+        arena: &'a Bump,
+        state: State<'a>,
+    ) -> (&'a WhenBranch<'a>, State<'a>) {
+        let (spaces, branch_state) =
+            match space0_e(EWhen::IndentPattern).parse(arena, state.clone(), 0) {
+                Ok((_, spaces, branch_state)) => (spaces, branch_state),
+                Err(_) => (&[] as &[_], state),
+            };
+
+        let mut end_state = branch_state
+            .clone()
+            .skip_indented_lines(pattern_indent_level);
+
+        end_state.record_error(
+            arena,
+            SyntaxError::Expr(EExpr::When(problem, when_start), when_start),
+        );
+
+        let region = Region::new(branch_state.pos(), end_state.pos());
+        let pattern = arena
+            .alloc(Pattern::Underscore(""))
+            .with_spaces_before(spaces, region);
+
+        let branch = WhenBranch {
+            patterns: arena.alloc([pattern]),
+            value: Loc::at(region, Expr::Malformed(end_state.source_text(region))),
+            guard: None,
+        };
+
+        (arena.alloc(branch), end_state)
+    }
+
     /// Parsing alternative patterns in `when` branches.
     fn branch_alternatives<'a>(
         options: ExprParseOptions,
@@ -2530,9 +2789,178 @@ fn record_updateable_identifier<'a>() -> impl Parser<'a, Expr<'a>, ERecord<'a>>
     )
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RecordField<'a> {
+    RequiredValue(Loc<&'a str>, &'a [CommentOrNewline<'a>], &'a Loc<Expr<'a>>),
+    OptionalValue(Loc<&'a str>, &'a [CommentOrNewline<'a>], &'a Loc<Expr<'a>>),
+    LabelOnly(Loc<&'a str>),
+    SpaceBefore(&'a RecordField<'a>, &'a [CommentOrNewline<'a>]),
+    SpaceAfter(&'a RecordField<'a>, &'a [CommentOrNewline<'a>]),
+    ApplyValue(
+        Loc<&'a str>,
+        &'a [CommentOrNewline<'a>],
+        &'a [CommentOrNewline<'a>],
+        &'a Loc<Expr<'a>>,
+    ),
+}
+
+#[derive(Debug)]
+struct FoundApplyValue;
+
+#[derive(Debug)]
+struct FoundOptionalValue;
+
+impl<'a> RecordField<'a> {
+    fn to_assigned_field(
+        self,
+        arena: &'a Bump,
+    ) -> Result<AssignedField<'a, Expr<'a>>, FoundApplyValue> {
+        use AssignedField::*;
+
+        match self {
+            RecordField::RequiredValue(loc_label, spaces, loc_expr) => {
+                Ok(RequiredValue(loc_label, spaces, loc_expr))
+            }
+
+            RecordField::OptionalValue(loc_label, spaces, loc_expr) => {
+                Ok(OptionalValue(loc_label, spaces, loc_expr))
+            }
+
+            RecordField::LabelOnly(loc_label) => Ok(LabelOnly(loc_label)),
+
+            RecordField::ApplyValue(_, _, _, _) => Err(FoundApplyValue),
+
+            RecordField::SpaceBefore(field, spaces) => {
+                let assigned_field = field.to_assigned_field(arena)?;
+
+                Ok(SpaceBefore(arena.alloc(assigned_field), spaces))
+            }
+
+            RecordField::SpaceAfter(field, spaces) => {
+                let assigned_field = field.to_assigned_field(arena)?;
+
+                Ok(SpaceAfter(arena.alloc(assigned_field), spaces))
+            }
+        }
+    }
+
+    fn to_builder_field(
+        self,
+        arena: &'a Bump,
+    ) -> Result<RecordBuilderField<'a>, FoundOptionalValue> {
+        use RecordBuilderField::*;
+
+        match self {
+            RecordField::RequiredValue(loc_label, spaces, loc_expr) => {
+                Ok(Value(loc_label, spaces, loc_expr))
+            }
+
+            RecordField::OptionalValue(_, _, _) => Err(FoundOptionalValue),
+
+            RecordField::LabelOnly(loc_label) => Ok(LabelOnly(loc_label)),
+
+            RecordField::ApplyValue(loc_label, colon_spaces, arrow_spaces, loc_expr) => {
+                Ok(ApplyValue(loc_label, colon_spaces, arrow_spaces, loc_expr))
+            }
+
+            RecordField::SpaceBefore(field, spaces) => {
+                let builder_field = field.to_builder_field(arena)?;
+
+                Ok(SpaceBefore(arena.alloc(builder_field), spaces))
+            }
+
+            RecordField::SpaceAfter(field, spaces) => {
+                let builder_field = field.to_builder_field(arena)?;
+
+                Ok(SpaceAfter(arena.alloc(builder_field), spaces))
+            }
+        }
+    }
+}
+
+impl<'a> Spaceable<'a> for RecordField<'a> {
+    fn before(&'a self, spaces: &'a [CommentOrNewline<'a>]) -> Self {
+        RecordField::SpaceBefore(self, spaces)
+    }
+    fn after(&'a self, spaces: &'a [CommentOrNewline<'a>]) -> Self {
+        RecordField::SpaceAfter(self, spaces)
+    }
+}
+
+fn record_field<'a>() -> impl Parser<'a, RecordField<'a>, ERecord<'a>> {
+    use RecordField::*;
+
+    map_with_arena!(
+        and!(
+            specialize(|_, pos| ERecord::Field(pos), loc!(lowercase_ident())),
+            and!(
+                spaces(),
+                optional(either!(
+                    skip_first!(
+                        word1(b':', ERecord::Colon),
+                        and!(
+                            spaces(),
+                            and!(
+                                // a `<-` after the colon means the field is built by
+                                // applying a function, e.g. `{ name: <- apply getName }`
+                                optional(skip_first!(word2(b'<', b'-', ERecord::Arrow), spaces())),
+                                specialize_ref(ERecord::Expr, loc_expr(false))
+                            )
+                        )
+                    ),
+                    skip_first!(
+                        word1(b'?', ERecord::QuestionMark),
+                        spaces_before(specialize_ref(ERecord::Expr, loc_expr(false)))
+                    )
+                ))
+            )
+        ),
+        |arena: &'a bumpalo::Bump, (loc_label, (spaces, opt_loc_val))| {
+            let with_spaces = |loc_val: Loc<Expr<'a>>, val_spaces: &'a [CommentOrNewline<'a>]| {
+                if val_spaces.is_empty() {
+                    &*arena.alloc(loc_val)
+                } else {
+                    &*arena.alloc(
+                        arena
+                            .alloc(loc_val.value)
+                            .with_spaces_before(val_spaces, loc_val.region),
+                    )
+                }
+            };
+
+            match opt_loc_val {
+                Some(Either::First((colon_spaces, (Some(arrow_spaces), loc_val)))) => ApplyValue(
+                    loc_label,
+                    spaces,
+                    colon_spaces,
+                    with_spaces(loc_val, arrow_spaces),
+                ),
+
+                Some(Either::First((colon_spaces, (None, loc_val)))) => {
+                    RequiredValue(loc_label, spaces, with_spaces(loc_val, colon_spaces))
+                }
+
+                Some(Either::Second(loc_val)) => {
+                    OptionalValue(loc_label, spaces, arena.alloc(loc_val))
+                }
+
+                // If no value was provided, record it as a Var.
+                // Canonicalize will know what to do with a Var later.
+                None => {
+                    if !spaces.is_empty() {
+                        SpaceAfter(arena.alloc(LabelOnly(loc_label)), spaces)
+                    } else {
+                        LabelOnly(loc_label)
+                    }
+                }
+            }
+        }
+    )
+}
+
 struct RecordHelp<'a> {
     update: Option<Loc<Expr<'a>>>,
-    fields: Collection<'a, Loc<AssignedField<'a, Expr<'a>>>>,
+    fields: Collection<'a, Loc<RecordField<'a>>>,
 }
 
 fn record_help<'a>() -> impl Parser<'a, RecordHelp<'a>, ERecord<'a>> {
@@ -2552,9 +2980,9 @@ fn record_help<'a>() -> impl Parser<'a, RecordHelp<'a>, ERecord<'a>> {
                 word1(b'&', ERecord::Ampersand)
             ))),
             fields: collection_inner!(
-                loc!(record_value_field()),
+                loc!(record_field()),
                 word1(b',', ERecord::End),
-                AssignedField::SpaceBefore
+                RecordField::SpaceBefore
             ),
         })),
         word1(b'}', ERecord::End)
@@ -2564,18 +2992,44 @@ fn record_help<'a>() -> impl Parser<'a, RecordHelp<'a>, ERecord<'a>> {
 fn record_literal_help<'a>() -> impl Parser<'a, Expr<'a>, EExpr<'a>> {
     then(
         and!(
-            specialize(EExpr::Record, record_help()),
+            specialize(EExpr::Record, loc!(record_help())),
             // there can be field access, e.g. `{ x : 4 }.x`
             record_field_access_chain()
         ),
-        move |arena, state, _, (record, accessors)| {
+        move |arena, state, _, (loc_record, accessors)| {
+            let record = loc_record.value;
+
+            let fields = record.fields.map_items_result(arena, |loc_field| {
+                loc_field
+                    .value
+                    .to_assigned_field(arena)
+                    .map(|field| loc_field.with_value(field))
+            });
+
             // This is a record literal, not a destructure.
-            let value = match record.update {
-                Some(update) => Expr::RecordUpdate {
+            let value = match (record.update, fields) {
+                (Some(update), Ok(fields)) => Expr::RecordUpdate {
                     update: &*arena.alloc(update),
-                    fields: record.fields,
+                    fields,
                 },
-                None => Expr::Record(record.fields),
+                (None, Ok(fields)) => Expr::Record(fields),
+                (Some(_), Err(FoundApplyValue)) => {
+                    return Err((MadeProgress, EExpr::RecordUpdateBuilder(loc_record.region)));
+                }
+                (None, Err(FoundApplyValue)) => {
+                    let fields =
+                        record.fields.map_items_result(arena, |loc_field| {
+                            match loc_field.value.to_builder_field(arena) {
+                                Ok(field) => Ok(loc_field.with_value(field)),
+                                Err(FoundOptionalValue) => Err((
+                                    MadeProgress,
+                                    EExpr::OptionalValueInRecordBuilder(loc_field.region),
+                                )),
+                            }
+                        })?;
+
+                    Expr::RecordBuilder(fields)
+                }
             };
 
             let value = apply_expr_access_chain(arena, value, accessors);
@@ -44,12 +44,16 @@ pub enum HeaderType<'a> {
         generates_with: &'a [Symbol],
     },
     Package {
+        /// the name the package declares in its header, e.g. `"json"` in `package "json"`
+        name: PackageName<'a>,
         /// usually something other than `pf`
         config_shorthand: &'a str,
         exposes: &'a [Loc<ModuleName<'a>>],
         exposes_ids: &'a [ModuleId],
     },
     Platform {
+        /// the name the platform declares in its header, e.g. `"cli"` in `platform "cli"`
+        name: PackageName<'a>,
         opt_app_module_id: Option<ModuleId>,
         /// the name and type scheme of the main function (required by the platform)
         /// (type scheme is currently unused)
@@ -342,6 +342,8 @@ pub enum EExpr<'a> {
     QualifiedTag(Position),
     BackpassComma(Position),
     BackpassArrow(Position),
+    RecordUpdateBuilder(Region),
+    OptionalValueInRecordBuilder(Region),
 
     When(EWhen<'a>, Position),
     If(EIf<'a>, Position),
@@ -407,6 +409,7 @@ pub enum ERecord<'a> {
     Field(Position),
     Colon(Position),
     QuestionMark(Position),
+    Arrow(Position),
     Ampersand(Position),
 
     // TODO remove
@@ -680,6 +683,7 @@ pub enum ETypeAbilityImpl<'a> {
 
     Updateable(Position),
     QuestionMark(Position),
+    Arrow(Position),
     Ampersand(Position),
     Expr(&'a EExpr<'a>, Position),
     IndentBar(Position),
@@ -696,6 +700,7 @@ impl<'a> From<ERecord<'a>> for ETypeAbilityImpl<'a> {
             ERecord::Space(s, p) => ETypeAbilityImpl::Space(s, p),
             ERecord::Updateable(p) => ETypeAbilityImpl::Updateable(p),
             ERecord::QuestionMark(p) => ETypeAbilityImpl::QuestionMark(p),
+            ERecord::Arrow(p) => ETypeAbilityImpl::Arrow(p),
             ERecord::Ampersand(p) => ETypeAbilityImpl::Ampersand(p),
             ERecord::Expr(e, p) => ETypeAbilityImpl::Expr(e, p),
         }
@@ -1,7 +1,8 @@
+use bumpalo::Bump;
 use roc_region::all::{Position, Region};
 use std::fmt;
 
-use crate::parser::Progress;
+use crate::parser::{Progress, SyntaxError};
 
 /// A position in a source file.
 // NB: [Copy] is explicitly NOT derived to reduce the chance of bugs due to accidentally re-using
@@ -21,6 +22,18 @@ pub struct State<'a> {
 
     /// Position of the first non-whitespace character on the current line
     pub(crate) line_start_after_whitespace: Position,
+
+    /// Whether to recover from syntax errors in top-level defs and `when` branches,
+    /// rather than stopping at the first one
+    recover_from_errors: bool,
+
+    /// The syntax errors recovered from so far, most recent first
+    recovered_errors: Option<&'a RecoveredError<'a>>,
+}
+
+struct RecoveredError<'a> {
+    error: SyntaxError<'a>,
+    previous: Option<&'a RecoveredError<'a>>,
 }
 
 impl<'a> State<'a> {
@@ -33,9 +46,47 @@ impl<'a> State<'a> {
             // Technically not correct.
             // We don't know the position of the first non-whitespace character yet.
             line_start_after_whitespace: Position::zero(),
+
+            recover_from_errors: false,
+            recovered_errors: None,
         }
     }
 
+    /// Keep parsing after a syntax error in a top-level def or a `when` branch, so that one
+    /// pass finds as many syntax errors as possible. The parser records each error (see
+    /// [State::recovered_errors]) along with a malformed node standing in for the broken
+    /// source, and carries on at the next def or branch.
+    #[must_use]
+    pub fn with_error_recovery(mut self) -> State<'a> {
+        self.recover_from_errors = true;
+        self
+    }
+
+    pub(crate) fn recovers_from_errors(&self) -> bool {
+        self.recover_from_errors
+    }
+
+    pub(crate) fn record_error(&mut self, arena: &'a Bump, error: SyntaxError<'a>) {
+        self.recovered_errors = Some(arena.alloc(RecoveredError {
+            error,
+            previous: self.recovered_errors,
+        }));
+    }
+
+    /// The syntax errors recovered from so far, in the order they were found
+    pub fn recovered_errors(&self) -> std::vec::Vec<SyntaxError<'a>> {
+        let mut errors = std::vec::Vec::new();
+        let mut current = self.recovered_errors;
+
+        while let Some(recovered) = current {
+            errors.push(recovered.error.clone());
+            current = recovered.previous;
+        }
+
+        errors.reverse();
+        errors
+    }
+
     pub fn original_bytes(&self) -> &'a [u8] {
         self.original_bytes
     }
@@ -112,6 +163,62 @@ impl<'a> State<'a> {
         self
     }
 
+    /// Skips the rest of the current line, along with any following lines that are blank or
+    /// indented by more than `max_indent`. The new state is at the end of the last non-blank
+    /// line skipped, so that the whitespace after it is parsed as usual.
+    #[must_use]
+    pub(crate) fn skip_indented_lines(self, max_indent: u32) -> State<'a> {
+        fn trimmed_len(line: &[u8]) -> usize {
+            line.iter()
+                .rposition(|byte| !byte.is_ascii_whitespace())
+                .map_or(0, |index| index + 1)
+        }
+
+        let bytes = self.original_bytes;
+        let line_end = |start: usize| {
+            bytes[start..]
+                .iter()
+                .position(|byte| *byte == b'\n')
+                .map_or(bytes.len(), |index| start + index)
+        };
+
+        let mut index = line_end(self.offset);
+        let mut state = State {
+            offset: self.offset + trimmed_len(&bytes[self.offset..index]),
+            ..self
+        };
+
+        while index < bytes.len() {
+            let start = index + 1;
+            let end = line_end(start);
+            let line = &bytes[start..end];
+            let indent = line.iter().take_while(|byte| **byte == b' ').count();
+            let content_len = trimmed_len(line);
+
+            if content_len > indent {
+                if indent as u32 <= max_indent {
+                    break;
+                }
+
+                state.offset = start + content_len;
+                state.line_start = Position::new(start as u32);
+                state.line_start_after_whitespace = Position::new((start + indent) as u32);
+            }
+
+            index = end;
+        }
+
+        state
+    }
+
+    /// The source text within the given region
+    pub(crate) fn source_text(&self, region: Region) -> &'a str {
+        let bytes =
+            &self.original_bytes[region.start().offset as usize..region.end().offset as usize];
+
+        std::str::from_utf8(bytes).unwrap_or_default()
+    }
+
     /// Returns the current position
     pub const fn pos(&self) -> Position {
         Position::new(self.offset as u32)
@@ -1,13 +1,15 @@
-use crate::ast::{EscapedChar, SingleQuoteLiteral, StrLiteral, StrSegment};
+use crate::ast::{EscapedChar, Expr, SingleQuoteLiteral, StrLiteral, StrSegment};
+use crate::blankspace::spaces_around;
 use crate::expr;
 use crate::parser::Progress::{self, *};
 use crate::parser::{
-    allocated, loc, reset_min_indent, specialize_ref, then, word1, BadInputError, ESingleQuote,
-    EString, Parser,
+    loc, map_with_arena, reset_min_indent, specialize_ref, then, word1, BadInputError,
+    ESingleQuote, EString, Parser,
 };
 use crate::state::State;
 use bumpalo::collections::vec::Vec;
 use bumpalo::Bump;
+use roc_region::all::Loc;
 
 /// One or more ASCII hex digits. (Useful when parsing unicode escape codes,
 /// which must consist entirely of ASCII hex digits.)
@@ -368,14 +370,17 @@ pub fn parse_str_like_literal<'a>() -> impl Parser<'a, StrLikeLiteral<'a>, EStri
 
                             let original_byte_count = state.bytes().len();
 
-                            // This is an interpolated variable.
-                            // Parse an arbitrary expression, then give a
-                            // canonicalization error if that expression variant
-                            // is not allowed inside a string interpolation.
+                            // This is an interpolation, which can contain
+                            // an arbitrary expression.
                             let (_progress, loc_expr, new_state) = skip_second!(
-                                specialize_ref(
-                                    EString::Format,
-                                    loc(allocated(reset_min_indent(expr::expr_help())))
+                                map_with_arena(
+                                    spaces_around(specialize_ref(
+                                        EString::Format,
+                                        loc(reset_min_indent(expr::expr_help()))
+                                    )),
+                                    |arena: &'a Bump, loc_expr: Loc<Expr<'a>>| {
+                                        Loc::at(loc_expr.region, &*arena.alloc(loc_expr.value))
+                                    }
                                 ),
                                 word1(b')', EString::FormatEnd)
                             )
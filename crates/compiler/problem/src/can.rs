@@ -98,7 +98,6 @@ pub enum Problem {
         alias_name: Symbol,
         region: Region,
     },
-    InvalidInterpolation(Region),
     InvalidHexadecimal(Region),
     InvalidUnicodeCodePt(Region),
     NestedDatatype {
@@ -232,7 +231,6 @@ impl Problem {
             Problem::RuntimeError(_) => RuntimeError,
             Problem::SignatureDefMismatch { .. } => RuntimeError,
             Problem::InvalidAliasRigid { .. } => RuntimeError,
-            Problem::InvalidInterpolation(_) => RuntimeError,
             Problem::InvalidHexadecimal(_) => RuntimeError,
             Problem::InvalidUnicodeCodePt(_) => RuntimeError,
             Problem::NestedDatatype { .. } => RuntimeError,
@@ -344,17 +342,18 @@ impl Problem {
             | Problem::RuntimeError(RuntimeError::MalformedIdentifier(_, _, region))
             | Problem::RuntimeError(RuntimeError::MalformedTypeName(_, region))
             | Problem::RuntimeError(RuntimeError::MalformedClosure(region))
+            | Problem::RuntimeError(RuntimeError::MalformedSyntax(region))
             | Problem::RuntimeError(RuntimeError::InvalidRecordUpdate { region })
             | Problem::RuntimeError(RuntimeError::InvalidFloat(_, region, _))
             | Problem::RuntimeError(RuntimeError::InvalidInt(_, _, region, _))
-            | Problem::RuntimeError(RuntimeError::InvalidInterpolation(region))
             | Problem::RuntimeError(RuntimeError::InvalidHexadecimal(region))
             | Problem::RuntimeError(RuntimeError::InvalidUnicodeCodePt(region))
             | Problem::RuntimeError(RuntimeError::EmptySingleQuote(region))
             | Problem::RuntimeError(RuntimeError::MultipleCharsInSingleQuote(region))
             | Problem::RuntimeError(RuntimeError::DegenerateBranch(region))
+            | Problem::RuntimeError(RuntimeError::MultipleRecordBuilders(region))
+            | Problem::RuntimeError(RuntimeError::UnappliedRecordBuilder(region))
             | Problem::InvalidAliasRigid { region, .. }
-            | Problem::InvalidInterpolation(region)
             | Problem::InvalidHexadecimal(region)
             | Problem::InvalidUnicodeCodePt(region)
             | Problem::NestedDatatype {
@@ -549,6 +548,8 @@ pub enum RuntimeError {
     MalformedIdentifier(Box<str>, roc_parse::ident::BadIdent, Region),
     MalformedTypeName(Box<str>, Region),
     MalformedClosure(Region),
+    /// Source the parser skipped while recovering from a syntax error
+    MalformedSyntax(Region),
     InvalidRecordUpdate {
         region: Region,
     },
@@ -558,7 +559,6 @@ pub enum RuntimeError {
 
     NonExhaustivePattern,
 
-    InvalidInterpolation(Region),
     InvalidHexadecimal(Region),
     InvalidUnicodeCodePt(Region),
 
@@ -579,6 +579,9 @@ pub enum RuntimeError {
     MultipleCharsInSingleQuote(Region),
 
     DegenerateBranch(Region),
+
+    MultipleRecordBuilders(Region),
+    UnappliedRecordBuilder(Region),
 }
 
 impl RuntimeError {
@@ -251,7 +251,15 @@ impl ObligationCache {
 
         match typ {
             Obligated::Adhoc(var) => self.check_adhoc(subs, abilities_store, var, ability),
-            Obligated::Opaque(opaque) => self
+            Obligated::Opaque(opaque, var)
+                if Self::has_opaque_fallback_impl(ability, opaque)
+                    && !abilities_store.has_declared_implementation(opaque, ability) =>
+            {
+                // The fallback implementation may need the opaque's type arguments to implement
+                // the ability too, so check it like a structural type.
+                self.check_adhoc(subs, abilities_store, var, ability)
+            }
+            Obligated::Opaque(opaque, _) => self
                 .check_opaque_and_read(abilities_store, opaque, ability)
                 .clone(),
         }
@@ -259,7 +267,11 @@ impl ObligationCache {
 
     fn has_cached(&self, mia: MustImplementAbility) -> bool {
         match mia.typ {
-            Obligated::Opaque(opaque) => self.impl_cache.contains_key(&ImplKey {
+            Obligated::Opaque(opaque, _) if Self::has_opaque_fallback_impl(mia.ability, opaque) => {
+                // checked like ad-hoc obligations, so never cached either
+                false
+            }
+            Obligated::Opaque(opaque, _) => self.impl_cache.contains_key(&ImplKey {
                 opaque,
                 ability: mia.ability,
             }),
@@ -301,6 +313,17 @@ impl ObligationCache {
 
             Symbol::BOOL_EQ => Some(DeriveEq::is_derivable(self, abilities_store, subs, var)),
 
+            Symbol::INSPECT_INSPECT_ABILITY => Some(DeriveInspect::is_derivable(
+                self,
+                abilities_store,
+                subs,
+                var,
+            )),
+
+            Symbol::SORT_SORT_ABILITY => {
+                Some(DeriveSort::is_derivable(self, abilities_store, subs, var))
+            }
+
             _ => None,
         };
 
@@ -340,6 +363,29 @@ impl ObligationCache {
         }
     }
 
+    /// The builtin implementation of an ability member to use for an opaque which doesn't
+    /// implement the member's ability itself, if the ability has one.
+    pub fn opaque_fallback_impl(ability_member: Symbol, opaque: Symbol) -> Option<Symbol> {
+        match ability_member {
+            Symbol::ENCODE_TO_ENCODER => DeriveEncoding::opaque_fallback_impl(opaque),
+            Symbol::DECODE_DECODER => DeriveDecoding::opaque_fallback_impl(opaque),
+            Symbol::HASH_HASH => DeriveHash::opaque_fallback_impl(opaque),
+            Symbol::BOOL_IS_EQ => DeriveEq::opaque_fallback_impl(opaque),
+            Symbol::INSPECT_INSPECT => DeriveInspect::opaque_fallback_impl(opaque),
+            Symbol::SORT_COMPARE => DeriveSort::opaque_fallback_impl(opaque),
+            _ => None,
+        }
+    }
+
+    fn has_opaque_fallback_impl(ability: Symbol, opaque: Symbol) -> bool {
+        match ability.derivable_ability() {
+            Some((_, members)) => members
+                .iter()
+                .all(|member| Self::opaque_fallback_impl(*member, opaque).is_some()),
+            None => false,
+        }
+    }
+
     fn check_opaque(
         &mut self,
         abilities_store: &AbilitiesStore,
@@ -370,8 +416,9 @@ impl ObligationCache {
 
         let ImplKey { opaque, ability } = impl_key;
         let has_declared_impl = abilities_store.has_declared_implementation(opaque, ability);
+        let has_fallback_impl = Self::has_opaque_fallback_impl(ability, opaque);
 
-        let obligation_result = if !has_declared_impl {
+        let obligation_result = if !has_declared_impl && !has_fallback_impl {
             Err(Unfulfilled::OpaqueDoesNotImplement {
                 typ: opaque,
                 ability,
@@ -509,6 +556,20 @@ trait DerivableVisitor {
         false
     }
 
+    /// The builtin implementation of the ability's member for opaques that don't implement the
+    /// ability themselves.
+    #[inline(always)]
+    fn opaque_fallback_impl(_opaque: Symbol) -> Option<Symbol> {
+        None
+    }
+
+    /// Whether the type arguments of an opaque must also be derivable, for example the keys and
+    /// values of a `Dict`.
+    #[inline(always)]
+    fn descend_into_opaque(_symbol: Symbol) -> bool {
+        false
+    }
+
     #[inline(always)]
     fn visit_recursion(var: Variable) -> Result<Descend, NotDerivable> {
         Err(NotDerivable {
@@ -783,7 +844,7 @@ trait DerivableVisitor {
                         stack.push(real_var)
                     }
                 }
-                Alias(opaque, _alias_variables, _real_var, AliasKind::Opaque) => {
+                Alias(opaque, alias_variables, _real_var, AliasKind::Opaque) => {
                     if obligation_cache
                         .check_opaque_and_read(abilities_store, opaque, Self::ABILITY)
                         .is_err()
@@ -794,6 +855,9 @@ trait DerivableVisitor {
                             context: NotDerivableContext::Opaque(opaque),
                         });
                     }
+                    if Self::descend_into_opaque(opaque) {
+                        push_var_slice!(alias_variables.type_variables());
+                    }
                 }
                 Alias(symbol, _alias_variables, real_var, AliasKind::Structural) => {
                     let descend = Self::visit_alias(var, symbol)?;
@@ -1251,6 +1315,215 @@ impl DerivableVisitor for DeriveEq {
     }
 }
 
+struct DeriveInspect;
+impl DerivableVisitor for DeriveInspect {
+    const ABILITY: Symbol = Symbol::INSPECT_INSPECT_ABILITY;
+    const ABILITY_SLICE: SubsSlice<Symbol> = Subs::AB_INSPECT;
+
+    #[inline(always)]
+    fn opaque_fallback_impl(opaque: Symbol) -> Option<Symbol> {
+        // Opaques that don't implement `Inspect` are shown as `<opaque>`, and the builtin
+        // collections inspect their elements.
+        Some(roc_derive_key::inspect::opaque_inspector(opaque))
+    }
+
+    #[inline(always)]
+    fn descend_into_opaque(symbol: Symbol) -> bool {
+        matches!(symbol, Symbol::DICT_DICT | Symbol::SET_SET)
+    }
+
+    #[inline(always)]
+    fn visit_recursion(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_apply(var: Variable, symbol: Symbol) -> Result<Descend, NotDerivable> {
+        if matches!(symbol, Symbol::LIST_LIST | Symbol::STR_STR) {
+            Ok(Descend(true))
+        } else {
+            Err(NotDerivable {
+                var,
+                context: NotDerivableContext::NoContext,
+            })
+        }
+    }
+
+    #[inline(always)]
+    fn visit_record(
+        subs: &Subs,
+        var: Variable,
+        fields: RecordFields,
+    ) -> Result<Descend, NotDerivable> {
+        for (field_name, _, field) in fields.iter_all() {
+            if subs[field].is_optional() {
+                return Err(NotDerivable {
+                    var,
+                    context: NotDerivableContext::OptionalRecordField(subs[field_name].clone()),
+                });
+            }
+        }
+
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_tag_union(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_recursive_tag_union(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_function_or_tag_union(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_empty_record(_var: Variable) -> Result<(), NotDerivable> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn visit_empty_tag_union(_var: Variable) -> Result<(), NotDerivable> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn visit_alias(_var: Variable, symbol: Symbol) -> Result<Descend, NotDerivable> {
+        if is_builtin_number_alias(symbol) {
+            Ok(Descend(false))
+        } else {
+            Ok(Descend(true))
+        }
+    }
+
+    #[inline(always)]
+    fn visit_ranged_number(_var: Variable, _range: NumericRange) -> Result<(), NotDerivable> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn visit_floating_point_content(
+        _var: Variable,
+        _subs: &mut Subs,
+        _content_var: Variable,
+    ) -> Result<Descend, NotDerivable> {
+        Ok(Descend(false))
+    }
+}
+
+struct DeriveSort;
+impl DerivableVisitor for DeriveSort {
+    const ABILITY: Symbol = Symbol::SORT_SORT_ABILITY;
+    const ABILITY_SLICE: SubsSlice<Symbol> = Subs::AB_SORT;
+
+    #[inline(always)]
+    fn is_derivable_builtin_opaque(symbol: Symbol) -> bool {
+        is_builtin_number_alias(symbol)
+    }
+
+    #[inline(always)]
+    fn visit_recursion(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_apply(var: Variable, symbol: Symbol) -> Result<Descend, NotDerivable> {
+        if matches!(symbol, Symbol::STR_STR) {
+            Ok(Descend(true))
+        } else {
+            Err(NotDerivable {
+                var,
+                context: NotDerivableContext::NoContext,
+            })
+        }
+    }
+
+    #[inline(always)]
+    fn visit_record(
+        subs: &Subs,
+        var: Variable,
+        fields: RecordFields,
+    ) -> Result<Descend, NotDerivable> {
+        for (field_name, _, field) in fields.iter_all() {
+            if subs[field].is_optional() {
+                return Err(NotDerivable {
+                    var,
+                    context: NotDerivableContext::OptionalRecordField(subs[field_name].clone()),
+                });
+            }
+        }
+
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_tuple(
+        _subs: &Subs,
+        _var: Variable,
+        _elems: TupleElems,
+    ) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_tag_union(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_recursive_tag_union(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_function_or_tag_union(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_empty_record(_var: Variable) -> Result<(), NotDerivable> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn visit_empty_tuple(_var: Variable) -> Result<(), NotDerivable> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn visit_empty_tag_union(_var: Variable) -> Result<(), NotDerivable> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn visit_alias(_var: Variable, symbol: Symbol) -> Result<Descend, NotDerivable> {
+        if is_builtin_number_alias(symbol) {
+            Ok(Descend(false))
+        } else {
+            Ok(Descend(true))
+        }
+    }
+
+    #[inline(always)]
+    fn visit_ranged_number(_var: Variable, _range: NumericRange) -> Result<(), NotDerivable> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn visit_floating_point_content(
+        _var: Variable,
+        _subs: &mut Subs,
+        _content_var: Variable,
+    ) -> Result<Descend, NotDerivable> {
+        Ok(Descend(false))
+    }
+}
+
 /// Determines what type implements an ability member of a specialized signature, given the
 /// [MustImplementAbility] constraints of the signature.
 pub fn type_implementing_specialization(
@@ -1356,19 +1629,25 @@ pub fn resolve_ability_specialization<R: AbilityResolver>(
     let obligated = type_implementing_specialization(&must_implement_ability, parent_ability)?;
 
     let resolved = match obligated {
-        Obligated::Opaque(symbol) => {
+        Obligated::Opaque(symbol, _) => {
             let impl_key = roc_can::abilities::ImplKey {
                 opaque: symbol,
                 ability_member,
             };
 
-            match resolver.get_implementation(impl_key)? {
-                roc_types::types::MemberImpl::Impl(spec_symbol) => {
+            match resolver.get_implementation(impl_key) {
+                Some(roc_types::types::MemberImpl::Impl(spec_symbol)) => {
                     Resolved::Specialization(spec_symbol)
                 }
                 // TODO this is not correct. We can replace `Resolved` with `MemberImpl` entirely,
                 // which will make this simpler.
-                roc_types::types::MemberImpl::Error => Resolved::Specialization(Symbol::UNDERSCORE),
+                Some(roc_types::types::MemberImpl::Error) => {
+                    Resolved::Specialization(Symbol::UNDERSCORE)
+                }
+                None => Resolved::Specialization(ObligationCache::opaque_fallback_impl(
+                    ability_member,
+                    symbol,
+                )?),
             }
         }
         Obligated::Adhoc(variable) => {
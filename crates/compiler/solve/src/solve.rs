@@ -1993,7 +1993,7 @@ fn check_ability_specialization(
                     type_implementing_specialization(&must_implement_ability, parent_ability);
 
                 match specialization_type {
-                    Some(Obligated::Opaque(opaque)) => {
+                    Some(Obligated::Opaque(opaque, _)) => {
                         // This is a specialization for an opaque - but is it the opaque the
                         // specialization was claimed to be for?
                         if opaque == impl_key.opaque {
@@ -24,6 +24,7 @@ use roc_types::{
 };
 use roc_unify::unify::{unify, Env as UEnv, Mode, MustImplementConstraints};
 
+use crate::ability::ObligationCache;
 use crate::solve::{deep_copy_var_in, introduce, Pools};
 
 /// What phase in the compiler is reaching out to specialize lambda sets?
@@ -626,6 +627,19 @@ fn make_specialization_decision<P: Phase>(
     use SpecializationTypeKey::*;
     match subs.get_content_without_compacting(var) {
         Alias(opaque, _, _, AliasKind::Opaque) if opaque.module_id() != ModuleId::NUM => {
+            if let Some(fallback) = ObligationCache::opaque_fallback_impl(ability_member, *opaque) {
+                let impl_key = ImplKey {
+                    opaque: *opaque,
+                    ability_member,
+                };
+                let has_impl = phase.with_module_abilities_store(opaque.module_id(), |store| {
+                    store.get_implementation(impl_key).is_some()
+                });
+                if !has_impl {
+                    return SpecializeDecision::Specialize(SingleLambdaSetImmediate(fallback));
+                }
+            }
+
             if P::IS_LATE {
                 SpecializeDecision::Specialize(Opaque(*opaque))
             } else {
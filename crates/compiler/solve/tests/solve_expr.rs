@@ -751,6 +751,48 @@ mod solve_expr {
         );
     }
 
+    #[test]
+    fn infer_interpolated_call() {
+        infer_eq(
+            indoc!(
+                r#"
+                f = \n -> "\(Num.toStr (n + 1)) items"
+
+                f
+            "#
+            ),
+            "Num * -> Str",
+        );
+    }
+
+    #[test]
+    fn infer_interpolated_pipeline() {
+        infer_eq(
+            indoc!(
+                r#"
+                f = \names -> "Hi, \(names |> List.map Str.trim |> Str.joinWith ", ")!"
+
+                f
+            "#
+            ),
+            "List Str -> Str",
+        );
+    }
+
+    #[test]
+    fn infer_lone_interpolation() {
+        infer_eq(
+            indoc!(
+                r#"
+                f = \x -> "\(x)"
+
+                f
+            "#
+            ),
+            "Str -> Str",
+        );
+    }
+
     // LIST MISMATCH
 
     #[test]
@@ -3877,7 +3919,7 @@ mod solve_expr {
                     when x is
                         2 | 3 -> 0
                         a if a < 20 ->  1
-                        3 | 4 if Bool.false -> 2
+                        4 | 5 if Bool.false -> 2
                         _ -> 3
                 "#
             ),
@@ -8216,6 +8258,55 @@ mod solve_expr {
         );
     }
 
+    #[test]
+    fn derive_inspect_for_opaque() {
+        infer_queries!(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                N := U8 has [Inspect]
+
+                main = Inspect.inspect "" (@N 15)
+                #      ^^^^^^^^^^^^^^^
+                "#
+            ),
+            @"N#Inspect.inspect(3) : Str, N -[[#N_inspect(3)]]-> Str"
+        );
+    }
+
+    #[test]
+    fn derive_sort_for_opaque() {
+        infer_queries!(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                N := U8 has [Sort]
+
+                main = Sort.compare (@N 15) (@N 23)
+                #      ^^^^^^^^^^^^
+                "#
+            ),
+            @"N#Sort.compare(3) : N, N -[[#N_compare(3)]]-> [EQ, GT, LT]w_a"
+        );
+    }
+
+    #[test]
+    fn derive_sort_for_record() {
+        infer_queries!(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                main = List.sort [{ a: 1u8, b: "" }]
+                #      ^^^^^^^^^
+                "#
+            ),
+            @"List.sort : List { a : U8, b : Str } -[[List.sort(80)]]-> List { a : U8, b : Str }"
+        );
+    }
+
     #[test]
     fn multiple_variables_bound_to_an_ability_from_type_def() {
         infer_queries!(
@@ -8764,4 +8855,26 @@ mod solve_expr {
         @"main : List w_a"
         );
     }
+
+    #[test]
+    fn infer_record_builder() {
+        infer_eq_without_problem(
+            indoc!(
+                r#"
+                succeed = \x -> \_ -> x
+
+                apply = \pa -> \pf -> \s -> (pf s) (pa s)
+
+                get = \name -> \s -> Str.concat s name
+
+                succeed {
+                    a: <- get "a" |> apply,
+                    b: <- Str.countGraphemes |> apply,
+                    c: 3.0,
+                }
+                "#
+            ),
+            "Str -> { a : Str, b : Nat, c : Float a }",
+        );
+    }
 }
@@ -87,6 +87,7 @@ pub enum NotDerivableContext {
     Function,
     UnboundVar,
     Opaque(Symbol),
+    OptionalRecordField(Lowercase),
     Encode(NotDerivableEncode),
     Decode(NotDerivableDecode),
     Eq(NotDerivableEq),
@@ -0,0 +1,296 @@
+#![cfg(test)]
+// Even with #[allow(non_snake_case)] on individual idents, rust-analyzer issues diagnostics.
+// See https://github.com/rust-lang/rust-analyzer/issues/6541.
+// For the `v!` macro we use uppercase variables when constructing tag unions.
+#![allow(non_snake_case)]
+
+use crate::{
+    test_key_eq, test_key_neq,
+    util::{check_derivable, check_single_lset_immediate, check_underivable, derive_test},
+    v,
+};
+use insta::assert_snapshot;
+use roc_module::symbol::Symbol;
+use roc_types::subs::Variable;
+
+use roc_derive_key::{compare::FlatCompareKey, DeriveBuiltin::Compare, DeriveError, DeriveKey};
+
+test_key_eq! {
+    Compare,
+
+    same_record:
+        v!({ a: v!(U8), }), v!({ a: v!(U8), })
+    same_record_fields_diff_types:
+        v!({ a: v!(U8), }), v!({ a: v!(STR), })
+    same_record_fields_any_order:
+        v!({ a: v!(U8), b: v!(U8), c: v!(U8), }),
+        v!({ c: v!(U8), a: v!(U8), b: v!(U8), })
+    explicit_empty_record_and_implicit_empty_record:
+        v!(EMPTY_RECORD), v!({})
+
+    same_tuple:
+        v!((v!(U8), v!(U16),)), v!((v!(U8), v!(U16),))
+    same_tuple_elems_diff_types:
+        v!((v!(U8), v!(U16),)), v!((v!(STR), v!(U64),))
+
+    same_tag_union:
+        v!([ A v!(U8) v!(STR), B v!(STR) ]), v!([ A v!(U8) v!(STR), B v!(STR) ])
+    same_tag_union_tags_diff_types:
+        v!([ A v!(U8) v!(U8), B v!(U8) ]), v!([ A v!(STR) v!(STR), B v!(STR) ])
+    same_tag_union_tags_any_order:
+        v!([ A v!(U8) v!(U8), B v!(U8), C ]), v!([ C, B v!(STR), A v!(STR) v!(STR) ])
+    explicit_empty_tag_union_and_implicit_empty_tag_union:
+        v!(EMPTY_TAG_UNION), v!([])
+
+    same_recursive_tag_union:
+        v!([ Nil, Cons v!(^lst)] as lst), v!([ Nil, Cons v!(^lst)] as lst)
+    same_tag_union_and_recursive_tag_union_fields:
+        v!([ Nil, Cons v!(STR)]), v!([ Nil, Cons v!(^lst)] as lst)
+}
+
+test_key_neq! {
+    Compare,
+
+    different_record_fields:
+        v!({ a: v!(U8), }), v!({ b: v!(U8), })
+    record_empty_vs_nonempty:
+        v!(EMPTY_RECORD), v!({ a: v!(U8), })
+
+    different_tuple_arities:
+        v!((v!(U8), v!(U16),)), v!((v!(U8), v!(U16), v!(U32),))
+
+    different_tag_union_tags:
+        v!([ A v!(U8) ]), v!([ B v!(U8) ])
+    tag_union_empty_vs_nonempty:
+        v!(EMPTY_TAG_UNION), v!([ B v!(U8) ])
+    different_recursive_tag_union_tags:
+        v!([ Nil, Cons v!(^lst) ] as lst), v!([ Nil, Next v!(^lst) ] as lst)
+}
+
+#[test]
+fn immediates() {
+    check_single_lset_immediate(Compare, v!(U8), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(U16), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(U32), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(U64), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(U128), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(I8), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(I16), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(I32), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(I64), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(I128), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(F32), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(F64), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(DEC), Symbol::NUM_COMPARE);
+    check_single_lset_immediate(Compare, v!(STR), Symbol::STR_COMPARE);
+}
+
+#[test]
+fn optional_record_field_derive_error() {
+    check_underivable(Compare, v!({ ?a: v!(U8), }), DeriveError::Underivable);
+}
+
+#[test]
+fn derivable_record_ext_flex_var() {
+    check_derivable(
+        Compare,
+        v!({ a: v!(STR), }* ),
+        DeriveKey::Compare(FlatCompareKey::Record(vec!["a".into()])),
+    );
+}
+
+#[test]
+fn derivable_record_with_record_ext() {
+    check_derivable(
+        Compare,
+        v!({ b: v!(STR), }{ a: v!(STR), } ),
+        DeriveKey::Compare(FlatCompareKey::Record(vec!["a".into(), "b".into()])),
+    );
+}
+
+#[test]
+fn derivable_tuple_ext_flex_var() {
+    check_derivable(
+        Compare,
+        v!((v!(STR), v!(U8),)* ),
+        DeriveKey::Compare(FlatCompareKey::Tuple(2)),
+    );
+}
+
+#[test]
+fn derivable_tag_ext_flex_var() {
+    check_derivable(
+        Compare,
+        v!([ A v!(STR) ]* ),
+        DeriveKey::Compare(FlatCompareKey::TagUnion(vec![("A".into(), 1)])),
+    );
+}
+
+#[test]
+fn derivable_tag_with_tag_ext() {
+    check_derivable(
+        Compare,
+        v!([ B v!(STR) v!(U8) ][ A v!(STR) ]),
+        DeriveKey::Compare(FlatCompareKey::TagUnion(vec![
+            ("A".into(), 1),
+            ("B".into(), 2),
+        ])),
+    );
+}
+
+#[test]
+fn empty_record() {
+    derive_test(Compare, v!(EMPTY_RECORD), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for {}
+        # {}, {} -[[compare_{}(0)]]-> [EQ, GT, LT]
+        # {}, {} -[[compare_{}(0)]]-> [EQ, GT, LT]
+        # Specialization lambda sets:
+        #   @<1>: [[compare_{}(0)]]
+        #Derived.compare_{} = \#Derived.rcd1, #Derived.rcd2 -> EQ
+        "###
+        )
+    })
+}
+
+#[test]
+fn one_field_record() {
+    derive_test(Compare, v!({ a: v!(U8), }), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for { a : U8 }
+        # { a : a }, { a : a } -[[compare_{a}(0)]]-> [EQ, GT, LT] | a has Sort
+        # { a : a }, { a : a } -[[compare_{a}(0)]]-> [EQ, GT, LT] | a has Sort
+        # Specialization lambda sets:
+        #   @<1>: [[compare_{a}(0)]]
+        #Derived.compare_{a} =
+          \#Derived.rcd1, #Derived.rcd2 -> compare #Derived.rcd1.a #Derived.rcd2.a
+        "###
+        )
+    })
+}
+
+#[test]
+fn three_field_record() {
+    derive_test(
+        Compare,
+        v!({ a: v!(U8), b: v!(STR), c: v!(U16), }),
+        |golden| {
+            assert_snapshot!(golden, @r###"
+        # derived for { a : U8, b : Str, c : U16 }
+        # { a : a, b : a1, c : a2 }, { a : a, b : a1, c : a2 } -[[compare_{a,b,c}(0)]]-> [EQ, GT, LT] | a has Sort, a1 has Sort, a2 has Sort
+        # { a : a, b : a1, c : a2 }, { a : a, b : a1, c : a2 } -[[compare_{a,b,c}(0)]]-> [EQ, GT, LT] | a has Sort, a1 has Sort, a2 has Sort
+        # Specialization lambda sets:
+        #   @<1>: [[compare_{a,b,c}(0)]]
+        #Derived.compare_{a,b,c} =
+          \#Derived.rcd1, #Derived.rcd2 ->
+            when compare #Derived.rcd1.a #Derived.rcd2.a is
+              EQ ->
+                when compare #Derived.rcd1.b #Derived.rcd2.b is
+                  EQ -> compare #Derived.rcd1.c #Derived.rcd2.c
+                  #Derived.order -> #Derived.order
+              #Derived.order2 -> #Derived.order2
+        "###
+            )
+        },
+    )
+}
+
+#[test]
+fn two_elem_tuple() {
+    derive_test(Compare, v!((v!(U8), v!(STR),)), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for ( U8, Str )*
+        # ( a, a1 )a2, ( a, a1 )a2 -[[compare_(arity:2)(0)]]-> [EQ, GT, LT] | a has Sort, a1 has Sort
+        # ( a, a1 )a2, ( a, a1 )a2 -[[compare_(arity:2)(0)]]-> [EQ, GT, LT] | a has Sort, a1 has Sort
+        # Specialization lambda sets:
+        #   @<1>: [[compare_(arity:2)(0)]]
+        #Derived.compare_(arity:2) =
+          \#Derived.tup1, #Derived.tup2 ->
+            when compare #Derived.tup1.0 #Derived.tup2.0 is
+              EQ -> compare #Derived.tup1.1 #Derived.tup2.1
+              #Derived.order -> #Derived.order
+        "###
+        )
+    })
+}
+
+#[test]
+fn tag_one_label_no_payloads() {
+    derive_test(Compare, v!([A]), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for [A]
+        # [A], [A] -[[compare_[A 0](0)]]-> [EQ, GT, LT]
+        # [A], [A] -[[compare_[A 0](0)]]-> [EQ, GT, LT]
+        # Specialization lambda sets:
+        #   @<1>: [[compare_[A 0](0)]]
+        #Derived.compare_[A 0] =
+          \#Derived.union1, #Derived.union2 ->
+            when #Derived.union1 is
+              A ->
+                when #Derived.union2 is
+                  A -> EQ
+        "###
+        )
+    })
+}
+
+#[test]
+fn tag_three_labels() {
+    derive_test(Compare, v!([A v!(U8) v!(STR), B, C v!(STR)]), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for [A U8 Str, B, C Str]
+        # [A a a1, B, C a2], [A a a1, B, C a2] -[[compare_[A 2,B 0,C 1](0)]]-> [EQ, GT, LT] | a has Sort, a1 has Sort, a2 has Sort
+        # [A a a1, B, C a2], [A a a1, B, C a2] -[[compare_[A 2,B 0,C 1](0)]]-> [EQ, GT, LT] | a has Sort, a1 has Sort, a2 has Sort
+        # Specialization lambda sets:
+        #   @<1>: [[compare_[A 2,B 0,C 1](0)]]
+        #Derived.compare_[A 2,B 0,C 1] =
+          \#Derived.union1, #Derived.union2 ->
+            when #Derived.union1 is
+              A #Derived.3 #Derived.4 ->
+                when #Derived.union2 is
+                  A #Derived.5 #Derived.6 ->
+                    when compare #Derived.3 #Derived.5 is
+                      EQ -> compare #Derived.4 #Derived.6
+                      #Derived.order -> #Derived.order
+                  B | C _ -> LT
+              B ->
+                when #Derived.union2 is
+                  A _ _ -> GT
+                  B -> EQ
+                  C _ -> LT
+              C #Derived.8 ->
+                when #Derived.union2 is
+                  A _ _ | B -> GT
+                  C #Derived.9 -> compare #Derived.8 #Derived.9
+        "###
+        )
+    })
+}
+
+#[test]
+fn recursive_tag_union() {
+    derive_test(Compare, v!([Nil, Cons v!(U8) v!(^lst) ] as lst), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for [Cons U8 $rec, Nil] as $rec
+        # [Cons a a1, Nil], [Cons a a1, Nil] -[[compare_[Cons 2,Nil 0](0)]]-> [EQ, GT, LT] | a has Sort, a1 has Sort
+        # [Cons a a1, Nil], [Cons a a1, Nil] -[[compare_[Cons 2,Nil 0](0)]]-> [EQ, GT, LT] | a has Sort, a1 has Sort
+        # Specialization lambda sets:
+        #   @<1>: [[compare_[Cons 2,Nil 0](0)]]
+        #Derived.compare_[Cons 2,Nil 0] =
+          \#Derived.union1, #Derived.union2 ->
+            when #Derived.union1 is
+              Cons #Derived.3 #Derived.4 ->
+                when #Derived.union2 is
+                  Cons #Derived.5 #Derived.6 ->
+                    when compare #Derived.3 #Derived.5 is
+                      EQ -> compare #Derived.4 #Derived.6
+                      #Derived.order -> #Derived.order
+                  Nil -> LT
+              Nil ->
+                when #Derived.union2 is
+                  Cons _ _ -> GT
+                  Nil -> EQ
+        "###
+        )
+    })
+}
@@ -117,12 +117,12 @@ fn record_2_fields() {
         assert_snapshot!(golden, @r###"
         # derived for { first : Str, second : Str }
         # Decoder { first : val, second : val1 } fmt | fmt has DecoderFormatting, val has Decoding, val1 has Decoding
-        # List U8, fmt -[[custom(22)]]-> { rest : List U8, result : [Err [TooShort], Ok { first : val, second : val1 }] } | fmt has DecoderFormatting, val has Decoding, val1 has Decoding
+        # List U8, fmt -[[custom(26)]]-> { rest : List U8, result : [Err [TooShort], Ok { first : val, second : val1 }] } | fmt has DecoderFormatting, val has Decoding, val1 has Decoding
         # Specialization lambda sets:
-        #   @<1>: [[custom(22)]]
+        #   @<1>: [[custom(26)]]
         #Derived.decoder_{first,second} =
           custom
-            \#Derived.bytes3, #Derived.fmt3 ->
+            \#Derived.bytes3, #Derived.fmt ->
               decodeWith
                 #Derived.bytes3
                 (record
@@ -131,8 +131,8 @@ fn record_2_fields() {
                     when #Derived.field is
                       "first" ->
                         Keep (custom
-                          \#Derived.bytes, #Derived.fmt ->
-                            when decodeWith #Derived.bytes decoder #Derived.fmt is
+                          \#Derived.bytes, #Derived.fmt2 ->
+                            when decodeWith #Derived.bytes decoder #Derived.fmt2 is
                               #Derived.rec ->
                                 {
                                   result: when #Derived.rec.result is
@@ -143,8 +143,8 @@ fn record_2_fields() {
                                 })
                       "second" ->
                         Keep (custom
-                          \#Derived.bytes2, #Derived.fmt2 ->
-                            when decodeWith #Derived.bytes2 decoder #Derived.fmt2 is
+                          \#Derived.bytes2, #Derived.fmt3 ->
+                            when decodeWith #Derived.bytes2 decoder #Derived.fmt3 is
                               #Derived.rec2 ->
                                 {
                                   result: when #Derived.rec2.result is
@@ -155,14 +155,22 @@ fn record_2_fields() {
                                 })
                       _ -> Skip
                   \#Derived.stateRecord ->
-                    when #Derived.stateRecord.first is
+                    when when #Derived.stateRecord.first is
+                        Ok #Derived.first2 -> Ok #Derived.first2
+                        _ ->
+                          when decodeWith [] decoder #Derived.fmt is
+                            #Derived.decRec2 -> #Derived.decRec2.result is
                       Ok #Derived.first ->
-                        when #Derived.stateRecord.second is
+                        when when #Derived.stateRecord.second is
+                            Ok #Derived.second2 -> Ok #Derived.second2
+                            _ ->
+                              when decodeWith [] decoder #Derived.fmt is
+                                #Derived.decRec -> #Derived.decRec.result is
                           Ok #Derived.second ->
                             Ok { second: #Derived.second, first: #Derived.first }
                           _ -> Err TooShort
                       _ -> Err TooShort)
-                #Derived.fmt3
+                #Derived.fmt
         "###
         )
     })
@@ -0,0 +1,220 @@
+#![cfg(test)]
+// Even with #[allow(non_snake_case)] on individual idents, rust-analyzer issues diagnostics.
+// See https://github.com/rust-lang/rust-analyzer/issues/6541.
+// For the `v!` macro we use uppercase variables when constructing tag unions.
+#![allow(non_snake_case)]
+
+use crate::{
+    test_key_eq, test_key_neq,
+    util::{check_derivable, check_single_lset_immediate, check_underivable, derive_test},
+    v,
+};
+use insta::assert_snapshot;
+use roc_module::symbol::Symbol;
+use roc_types::subs::Variable;
+
+use roc_derive_key::{inspect::FlatInspectableKey, DeriveBuiltin::Inspect, DeriveError, DeriveKey};
+
+test_key_eq! {
+    Inspect,
+
+    same_record:
+        v!({ a: v!(U8), }), v!({ a: v!(U8), })
+    same_record_fields_diff_types:
+        v!({ a: v!(U8), }), v!({ a: v!(STR), })
+    same_record_fields_any_order:
+        v!({ a: v!(U8), b: v!(U8), c: v!(U8), }),
+        v!({ c: v!(U8), a: v!(U8), b: v!(U8), })
+    explicit_empty_record_and_implicit_empty_record:
+        v!(EMPTY_RECORD), v!({})
+
+    same_tag_union:
+        v!([ A v!(U8) v!(STR), B v!(STR) ]), v!([ A v!(U8) v!(STR), B v!(STR) ])
+    same_tag_union_tags_diff_types:
+        v!([ A v!(U8) v!(U8), B v!(U8) ]), v!([ A v!(STR) v!(STR), B v!(STR) ])
+    same_tag_union_tags_any_order:
+        v!([ A v!(U8) v!(U8), B v!(U8), C ]), v!([ C, B v!(STR), A v!(STR) v!(STR) ])
+    explicit_empty_tag_union_and_implicit_empty_tag_union:
+        v!(EMPTY_TAG_UNION), v!([])
+
+    same_recursive_tag_union:
+        v!([ Nil, Cons v!(^lst)] as lst), v!([ Nil, Cons v!(^lst)] as lst)
+    same_tag_union_and_recursive_tag_union_fields:
+        v!([ Nil, Cons v!(STR)]), v!([ Nil, Cons v!(^lst)] as lst)
+}
+
+test_key_neq! {
+    Inspect,
+
+    different_record_fields:
+        v!({ a: v!(U8), }), v!({ b: v!(U8), })
+    record_empty_vs_nonempty:
+        v!(EMPTY_RECORD), v!({ a: v!(U8), })
+
+    different_tag_union_tags:
+        v!([ A v!(U8) ]), v!([ B v!(U8) ])
+    tag_union_empty_vs_nonempty:
+        v!(EMPTY_TAG_UNION), v!([ B v!(U8) ])
+    different_recursive_tag_union_tags:
+        v!([ Nil, Cons v!(^lst) ] as lst), v!([ Nil, Next v!(^lst) ] as lst)
+}
+
+#[test]
+fn immediates() {
+    check_single_lset_immediate(Inspect, v!(U8), Symbol::INSPECT_U8);
+    check_single_lset_immediate(Inspect, v!(U16), Symbol::INSPECT_U16);
+    check_single_lset_immediate(Inspect, v!(U32), Symbol::INSPECT_U32);
+    check_single_lset_immediate(Inspect, v!(U64), Symbol::INSPECT_U64);
+    check_single_lset_immediate(Inspect, v!(U128), Symbol::INSPECT_U128);
+    check_single_lset_immediate(Inspect, v!(I8), Symbol::INSPECT_I8);
+    check_single_lset_immediate(Inspect, v!(I16), Symbol::INSPECT_I16);
+    check_single_lset_immediate(Inspect, v!(I32), Symbol::INSPECT_I32);
+    check_single_lset_immediate(Inspect, v!(I64), Symbol::INSPECT_I64);
+    check_single_lset_immediate(Inspect, v!(I128), Symbol::INSPECT_I128);
+    check_single_lset_immediate(Inspect, v!(F32), Symbol::INSPECT_F32);
+    check_single_lset_immediate(Inspect, v!(F64), Symbol::INSPECT_F64);
+    check_single_lset_immediate(Inspect, v!(DEC), Symbol::INSPECT_DEC);
+    check_single_lset_immediate(Inspect, v!(STR), Symbol::INSPECT_STR);
+    check_single_lset_immediate(Inspect, v!(Symbol::LIST_LIST v!(U8)), Symbol::INSPECT_LIST);
+    check_single_lset_immediate(Inspect, v!(Symbol::LIST_LIST v!(STR)), Symbol::INSPECT_LIST);
+}
+
+#[test]
+fn optional_record_field_derive_error() {
+    check_underivable(Inspect, v!({ ?a: v!(U8), }), DeriveError::Underivable);
+}
+
+#[test]
+fn derivable_record_ext_flex_var() {
+    check_derivable(
+        Inspect,
+        v!({ a: v!(STR), }* ),
+        DeriveKey::Inspect(FlatInspectableKey::Record(vec!["a".into()])),
+    );
+}
+
+#[test]
+fn derivable_record_with_record_ext() {
+    check_derivable(
+        Inspect,
+        v!({ b: v!(STR), }{ a: v!(STR), } ),
+        DeriveKey::Inspect(FlatInspectableKey::Record(vec!["a".into(), "b".into()])),
+    );
+}
+
+#[test]
+fn derivable_tag_ext_flex_var() {
+    check_derivable(
+        Inspect,
+        v!([ A v!(STR) ]* ),
+        DeriveKey::Inspect(FlatInspectableKey::TagUnion(vec![("A".into(), 1)])),
+    );
+}
+
+#[test]
+fn derivable_tag_with_tag_ext() {
+    check_derivable(
+        Inspect,
+        v!([ B v!(STR) v!(U8) ][ A v!(STR) ]),
+        DeriveKey::Inspect(FlatInspectableKey::TagUnion(vec![
+            ("A".into(), 1),
+            ("B".into(), 2),
+        ])),
+    );
+}
+
+#[test]
+fn empty_record() {
+    derive_test(Inspect, v!(EMPTY_RECORD), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for {}
+        # Str, {} -[[inspect_{}(0)]]-> Str
+        # Str, {} -[[inspect_{}(0)]]-> Str
+        # Specialization lambda sets:
+        #   @<1>: [[inspect_{}(0)]]
+        #Derived.inspect_{} = \#Derived.buf, #Derived.rcd -> literal #Derived.buf "{}"
+        "###
+        )
+    })
+}
+
+#[test]
+fn two_field_record() {
+    derive_test(Inspect, v!({ a: v!(U8), b: v!(STR), }), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for { a : U8, b : Str }
+        # Str, { a : val, b : val1 } -[[inspect_{a,b}(0)]]-> Str | val has Inspect, val1 has Inspect
+        # Str, { a : val, b : val1 } -[[inspect_{a,b}(0)]]-> Str | val has Inspect, val1 has Inspect
+        # Specialization lambda sets:
+        #   @<1>: [[inspect_{a,b}(0)]]
+        #Derived.inspect_{a,b} =
+          \#Derived.buf, #Derived.rcd ->
+            literal
+              (inspect
+                (literal
+                  (inspect (literal #Derived.buf "{ a: ") #Derived.rcd.a)
+                  ", b: ")
+                #Derived.rcd.b)
+              " }"
+        "###
+        )
+    })
+}
+
+#[test]
+fn tag_one_label_no_payloads() {
+    derive_test(Inspect, v!([A]), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for [A]
+        # Str, [A] -[[inspect_[A 0](0)]]-> Str
+        # Str, [A] -[[inspect_[A 0](0)]]-> Str
+        # Specialization lambda sets:
+        #   @<1>: [[inspect_[A 0](0)]]
+        #Derived.inspect_[A 0] =
+          \#Derived.buf, #Derived.union ->
+            when #Derived.union is
+              A -> literal #Derived.buf "A"
+        "###
+        )
+    })
+}
+
+#[test]
+fn tag_two_labels() {
+    derive_test(Inspect, v!([A v!(U8) v!(STR), B v!(STR)]), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for [A U8 Str, B Str]
+        # Str, [A val val1, B val1] -[[inspect_[A 2,B 1](0)]]-> Str | val has Inspect, val1 has Inspect
+        # Str, [A val val1, B val1] -[[inspect_[A 2,B 1](0)]]-> Str | val has Inspect, val1 has Inspect
+        # Specialization lambda sets:
+        #   @<1>: [[inspect_[A 2,B 1](0)]]
+        #Derived.inspect_[A 2,B 1] =
+          \#Derived.buf, #Derived.union ->
+            when #Derived.union is
+              A #Derived.3 #Derived.4 ->
+                payload (payload (literal #Derived.buf "A") #Derived.3) #Derived.4
+              B #Derived.5 -> payload (literal #Derived.buf "B") #Derived.5
+        "###
+        )
+    })
+}
+
+#[test]
+fn recursive_tag_union() {
+    derive_test(Inspect, v!([Nil, Cons v!(U8) v!(^lst) ] as lst), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for [Cons U8 $rec, Nil] as $rec
+        # Str, [Cons val val1, Nil] -[[inspect_[Cons 2,Nil 0](0)]]-> Str | val has Inspect, val1 has Inspect
+        # Str, [Cons val val1, Nil] -[[inspect_[Cons 2,Nil 0](0)]]-> Str | val has Inspect, val1 has Inspect
+        # Specialization lambda sets:
+        #   @<1>: [[inspect_[Cons 2,Nil 0](0)]]
+        #Derived.inspect_[Cons 2,Nil 0] =
+          \#Derived.buf, #Derived.union ->
+            when #Derived.union is
+              Cons #Derived.3 #Derived.4 ->
+                payload (payload (literal #Derived.buf "Cons") #Derived.3) #Derived.4
+              Nil -> literal #Derived.buf "Nil"
+        "###
+        )
+    })
+}
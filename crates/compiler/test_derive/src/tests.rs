@@ -1,8 +1,10 @@
 #![cfg(test)]
 
+mod compare;
 mod decoding;
 mod encoding;
 mod eq;
 mod hash;
+mod inspect;
 
 mod util;
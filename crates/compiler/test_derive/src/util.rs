@@ -63,6 +63,16 @@ fn module_source_and_path(builtin: DeriveBuiltin) -> (ModuleId, &'static str, Pa
             module_source(ModuleId::BOOL),
             builtins_path.join("Bool.roc"),
         ),
+        DeriveBuiltin::Inspect => (
+            ModuleId::INSPECT,
+            module_source(ModuleId::INSPECT),
+            builtins_path.join("Inspect.roc"),
+        ),
+        DeriveBuiltin::Compare => (
+            ModuleId::SORT,
+            module_source(ModuleId::SORT),
+            builtins_path.join("Sort.roc"),
+        ),
     }
 }
 
@@ -89,6 +99,22 @@ macro_rules! v {
              roc_derive::synth_var(subs, Content::Structure(FlatType::Record(fields, ext)))
          }
      }};
+     (( $($make_v:expr,)* )$( $($ext:tt)+ )?) => {{
+         #[allow(unused)]
+         use roc_types::subs::{Subs, TupleElems, Content, FlatType, Variable};
+         |subs: &mut Subs| {
+             #[allow(unused_mut)]
+             let mut elems = vec![];
+             $( elems.push($make_v(subs)); )*
+             let elems = TupleElems::insert_into_subs(subs, elems.into_iter().enumerate());
+
+             #[allow(unused_mut, unused)]
+             let mut ext = Variable::EMPTY_TUPLE;
+             $( ext = $crate::v!($($ext)+)(subs); )?
+
+             roc_derive::synth_var(subs, Content::Structure(FlatType::Tuple(elems, ext)))
+         }
+     }};
      ([ $($tag:ident $($payload:expr)*),* ] as $rec_var:ident) => {{
          use roc_types::subs::{Subs, SubsIndex, Variable, Content, FlatType, TagExt, UnionTags};
          use roc_module::ident::TagName;
@@ -787,6 +787,85 @@ fn encode_derived_record_with_many_types() {
     )
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn encode_record_with_field_name_mapping() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test"
+                imports [Encode, Json]
+                provides [main] to "./platform"
+
+            main =
+                rcd = {firstName: "a", lastName: "b"}
+                fmt = Json.withOptions {fieldNameMapping: SnakeCase}
+                result = Str.fromUtf8 (Encode.toBytes rcd fmt)
+                when result is
+                    Ok s -> s
+                    _ -> "<bad>"
+            "#
+        ),
+        RocStr::from(r#"{"first_name":"a","last_name":"b"}"#),
+        RocStr
+    )
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn encode_record_pretty_printed() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test"
+                imports [Encode, Json]
+                provides [main] to "./platform"
+
+            main =
+                empty : List Str
+                empty = []
+                rcd = {a: "foo", b: ["x"], c: empty}
+                fmt = Json.withOptions {indent: 2}
+                result = Str.fromUtf8 (Encode.toBytes rcd fmt)
+                when result is
+                    Ok s -> s
+                    _ -> "<bad>"
+            "#
+        ),
+        RocStr::from("{\n  \"a\": \"foo\",\n  \"b\": [\n    \"x\"\n  ],\n  \"c\": []\n}"),
+        RocStr
+    )
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn encode_empty_field_as_null() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test"
+                imports [Encode, Json]
+                provides [main] to "./platform"
+
+            Nothing := {} has [Encoding {toEncoder: nothingToEncoder}]
+
+            nothingToEncoder = \@Nothing {} -> Encode.custom \bytes, _ -> bytes
+
+            main =
+                rcd = {a: "foo", b: @Nothing {}}
+                skipped = Encode.toBytes rcd Json.toUtf8
+                nulled = Encode.toBytes rcd (Json.withOptions {emptyEncodeAsNull: Bool.true})
+                result = Str.fromUtf8 (List.concat skipped (List.prepend nulled ' '))
+                when result is
+                    Ok s -> s
+                    _ -> "<bad>"
+            "#
+        ),
+        RocStr::from(r#"{"a":"foo"} {"a":"foo","b":null}"#),
+        RocStr
+    )
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
 fn decode_use_stdlib() {
@@ -1180,6 +1259,131 @@ fn decode_record_of_record() {
     )
 }
 
+#[test]
+#[cfg(all(
+    any(feature = "gen-llvm", feature = "gen-wasm"),
+    not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
+))]
+fn decode_record_missing_field_decodes_from_empty_input() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Json] provides [main] to "./platform"
+
+            MaybeStr := [Missing, Present Str] has [Decoding {decoder: maybeStrDecoder}]
+
+            maybeStrDecoder =
+                Decode.custom \bytes, fmt ->
+                    if List.isEmpty bytes then
+                        {result: Ok (@MaybeStr Missing), rest: []}
+                    else
+                        when Decode.decodeWith bytes Decode.string fmt is
+                            {result, rest} ->
+                                when result is
+                                    Ok s -> {result: Ok (@MaybeStr (Present s)), rest}
+                                    Err e -> {result: Err e, rest}
+
+            main =
+                when Str.toUtf8 "{\"first\":\"a\"}" |> Decode.fromBytes Json.fromUtf8 is
+                    Ok {first, second: @MaybeStr Missing} -> first
+                    _ -> "something went wrong"
+            "#
+        ),
+        RocStr::from("a"),
+        RocStr
+    )
+}
+
+#[test]
+#[cfg(all(
+    any(feature = "gen-llvm", feature = "gen-wasm"),
+    not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
+))]
+fn decode_record_with_field_name_mapping() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Json] provides [main] to "./platform"
+
+            main =
+                fmt = Json.withOptions {fieldNameMapping: PascalCase}
+                when Str.toUtf8 "{\"FirstName\":\"ab\",\"LastName\":\"cd\"}" |> Decode.fromBytes fmt is
+                    Ok {firstName, lastName} -> Str.concat firstName lastName
+                    _ -> "something went wrong"
+            "#
+        ),
+        RocStr::from("abcd"),
+        RocStr
+    )
+}
+
+#[test]
+#[cfg(all(
+    any(feature = "gen-llvm", feature = "gen-wasm"),
+    not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
+))]
+fn decode_record_null_field_decodes_from_empty_input() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Json] provides [main] to "./platform"
+
+            MaybeStr := [Missing, Present Str] has [Decoding {decoder: maybeStrDecoder}]
+
+            maybeStrDecoder =
+                Decode.custom \bytes, fmt ->
+                    if List.isEmpty bytes then
+                        {result: Ok (@MaybeStr Missing), rest: []}
+                    else
+                        when Decode.decodeWith bytes Decode.string fmt is
+                            {result, rest} ->
+                                when result is
+                                    Ok s -> {result: Ok (@MaybeStr (Present s)), rest}
+                                    Err e -> {result: Err e, rest}
+
+            main =
+                when Str.toUtf8 "{\"first\":\"a\",\"second\":null}" |> Decode.fromBytes Json.fromUtf8 is
+                    Ok {first, second: @MaybeStr Missing} -> first
+                    _ -> "something went wrong"
+            "#
+        ),
+        RocStr::from("a"),
+        RocStr
+    )
+}
+
+#[test]
+#[cfg(all(
+    any(feature = "gen-llvm", feature = "gen-wasm"),
+    not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
+))]
+fn decode_record_missing_field_uses_default() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" imports [Json] provides [main] to "./platform"
+
+            StrOrDefault := Str has [Decoding {decoder: strOrDefaultDecoder}]
+
+            strOrDefaultDecoder =
+                Decode.custom \bytes, fmt ->
+                    if List.isEmpty bytes then
+                        {result: Ok (@StrOrDefault "default"), rest: []}
+                    else
+                        Decode.decodeWith bytes Decode.string fmt
+                        |> Decode.mapResult @StrOrDefault
+
+            main =
+                when Str.toUtf8 "{\"first\":\"a\"}" |> Decode.fromBytes Json.fromUtf8 is
+                    Ok {first, second: @StrOrDefault second} -> Str.concat first second
+                    _ -> "something went wrong"
+            "#
+        ),
+        RocStr::from("adefault"),
+        RocStr
+    )
+}
+
 #[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
 mod hash {
     #[cfg(feature = "gen-llvm")]
@@ -1806,6 +2010,134 @@ mod eq {
     }
 }
 
+#[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
+mod inspect {
+    #[cfg(feature = "gen-llvm")]
+    use crate::helpers::llvm::assert_evals_to;
+
+    #[cfg(feature = "gen-wasm")]
+    use crate::helpers::wasm::assert_evals_to;
+
+    use indoc::indoc;
+    use roc_std::RocStr;
+
+    fn build_test(input: &str) -> String {
+        format!(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                main = Inspect.toStr ({})
+                "#
+            ),
+            input,
+        )
+    }
+
+    #[test]
+    fn record() {
+        assert_evals_to!(
+            &build_test(r#"{ a: "x", b: 1u8 }"#),
+            RocStr::from(r#"{ a: "x", b: 1 }"#),
+            RocStr
+        )
+    }
+
+    #[test]
+    fn empty_record() {
+        assert_evals_to!(&build_test("{}"), RocStr::from("{}"), RocStr)
+    }
+
+    #[test]
+    fn nested_tags() {
+        assert_evals_to!(
+            &build_test("A (B 1u8) C"),
+            RocStr::from("A (B 1) C"),
+            RocStr
+        )
+    }
+
+    #[test]
+    fn tag_with_record_payload() {
+        assert_evals_to!(
+            &build_test("Ok { x: 1u8 }"),
+            RocStr::from("Ok { x: 1 }"),
+            RocStr
+        )
+    }
+
+    #[test]
+    fn list() {
+        assert_evals_to!(
+            &build_test("[[1u8, 2], []]"),
+            RocStr::from("[[1, 2], []]"),
+            RocStr
+        )
+    }
+
+    #[test]
+    fn dict() {
+        assert_evals_to!(
+            &build_test(r#"Dict.single "k" (Set.single 1u8)"#),
+            RocStr::from(r#"Dict.fromList [T "k" (Set.fromList [1])]"#),
+            RocStr
+        )
+    }
+
+    #[test]
+    fn set() {
+        assert_evals_to!(
+            &build_test("Set.fromList [1u8, 2]"),
+            RocStr::from("Set.fromList [1, 2]"),
+            RocStr
+        )
+    }
+
+    #[test]
+    fn numbers() {
+        assert_evals_to!(
+            &build_test("{ a: -3i8, b: 1.5f64, c: 2.5dec, d: 7u64 }"),
+            RocStr::from("{ a: -3, b: 1.5, c: 2.5, d: 7 }"),
+            RocStr
+        )
+    }
+
+    #[test]
+    fn bool() {
+        assert_evals_to!(
+            &build_test("[Bool.true, Bool.false]"),
+            RocStr::from("[Bool.true, Bool.false]"),
+            RocStr
+        )
+    }
+
+    #[test]
+    fn string_with_escapes() {
+        assert_evals_to!(
+            &build_test(r#""a\"b\\c\nd""#),
+            RocStr::from(r#""a\"b\\c\nd""#),
+            RocStr
+        )
+    }
+
+    #[test]
+    fn opaque() {
+        assert_evals_to!(
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                Id := U8
+
+                main = Inspect.toStr { id: @Id 1 }
+                "#
+            ),
+            RocStr::from("{ id: <opaque> }"),
+            RocStr
+        )
+    }
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
 fn issue_4772_weakened_monomorphic_destructure() {
@@ -2900,6 +2900,57 @@ fn list_sort_desc() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn list_sort_str() {
+    assert_evals_to!(
+        r#"List.sort ["banana", "apple", "cherry", "", "app"]"#,
+        RocList::from_slice(&[
+            RocStr::from(""),
+            RocStr::from("app"),
+            RocStr::from("apple"),
+            RocStr::from("banana"),
+            RocStr::from("cherry"),
+        ]),
+        RocList<RocStr>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn list_sort_record() {
+    // fields are compared in alphabetical order, so `a` decides before `b`
+    assert_evals_to!(
+        indoc!(
+            r#"
+            List.sort [{ b: 1, a: 2 }, { b: 5, a: 1 }, { b: 0, a: 2 }]
+            |> List.map .b
+            "#
+        ),
+        RocList::from_slice(&[5, 0, 1]),
+        RocList<i64>
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn list_sort_tag_union() {
+    // tags are compared in alphabetical order first, then by payload
+    assert_evals_to!(
+        indoc!(
+            r#"
+            List.sort [Zed 1, Alpha 3, Zed 0, Alpha 1]
+            |> List.map \tag ->
+                when tag is
+                    Alpha n -> n
+                    Zed n -> n + 10
+            "#
+        ),
+        RocList::from_slice(&[1, 3, 10, 11]),
+        RocList<i64>
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
 fn list_any() {
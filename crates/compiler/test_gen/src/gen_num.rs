@@ -1604,6 +1604,28 @@ fn tail_call_elimination() {
     );
 }
 
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
+fn mutual_tail_call_elimination() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+                app "test" provides [main] to "./platform"
+
+                isEven : U64 -> Bool
+                isEven = \n -> if n == 0 then Bool.true else isOdd (n - 1)
+
+                isOdd : U64 -> Bool
+                isOdd = \n -> if n == 0 then Bool.false else isEven (n - 1)
+
+                main = isEven 1_000_000
+                "#
+        ),
+        true,
+        bool
+    );
+}
+
 #[test]
 #[cfg(any(feature = "gen-dev"))]
 fn int_negate_dev() {
@@ -3864,3 +3886,111 @@ fn condition_polymorphic_num_becomes_float() {
         f32
     );
 }
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn is_nan() {
+    assert_evals_to!("Num.isNaN (0.0f64 / 0.0)", true, bool);
+    assert_evals_to!("Num.isNaN (1.0f64 / 0.0)", false, bool);
+    assert_evals_to!("Num.isNaN 12.3f64", false, bool);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn is_infinite() {
+    assert_evals_to!("Num.isInfinite (1.0f64 / 0.0)", true, bool);
+    assert_evals_to!("Num.isInfinite (-1.0f64 / 0.0)", true, bool);
+    assert_evals_to!("Num.isInfinite (0.0f64 / 0.0)", false, bool);
+    assert_evals_to!("Num.isInfinite 12.3f64", false, bool);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn is_finite() {
+    assert_evals_to!("Num.isFinite 12.3f64", true, bool);
+    assert_evals_to!("Num.isFinite (1.0f64 / 0.0)", false, bool);
+    assert_evals_to!("Num.isFinite (0.0f64 / 0.0)", false, bool);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn float_classification_f32() {
+    assert_evals_to!("Num.isNaN (0.0f32 / 0.0)", true, bool);
+    assert_evals_to!("Num.isInfinite (1.0f32 / 0.0)", true, bool);
+    assert_evals_to!("Num.isFinite 12.3f32", true, bool);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn float_classification_dec() {
+    assert_evals_to!("Num.isNaN 12.3dec", false, bool);
+    assert_evals_to!("Num.isInfinite 12.3dec", false, bool);
+    assert_evals_to!("Num.isFinite 12.3dec", true, bool);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn f64_to_bits() {
+    assert_evals_to!("Num.f64ToBits 1.0f64", 0x3ff0_0000_0000_0000, u64);
+    assert_evals_to!("Num.f64ToBits -2.0f64", 0xc000_0000_0000_0000, u64);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn f64_from_bits() {
+    assert_evals_to!("Num.f64FromBits 0x3ff0_0000_0000_0000", 1.0, f64);
+    assert_evals_to!("Num.f64FromBits (Num.f64ToBits 1.5f64)", 1.5, f64);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn f32_to_bits() {
+    assert_evals_to!("Num.f32ToBits 1.0f32", 0x3f80_0000, u32);
+    assert_evals_to!("Num.f32ToBits -2.0f32", 0xc000_0000, u32);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn f32_from_bits() {
+    assert_evals_to!("Num.f32FromBits 0x3f80_0000", 1.0, f32);
+    assert_evals_to!("Num.f32FromBits (Num.f32ToBits 1.5f32)", 1.5, f32);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn count_leading_zero_bits() {
+    assert_evals_to!("Num.countLeadingZeroBits 0b0010_1000u8", 2, usize);
+    assert_evals_to!("Num.countLeadingZeroBits 0u8", 8, usize);
+    assert_evals_to!("Num.countLeadingZeroBits 0b0010_1000u16", 10, usize);
+    assert_evals_to!("Num.countLeadingZeroBits 0b0010_1000u32", 26, usize);
+    assert_evals_to!("Num.countLeadingZeroBits 0b0010_1000u64", 58, usize);
+    assert_evals_to!("Num.countLeadingZeroBits -1i64", 0, usize);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn count_trailing_zero_bits() {
+    assert_evals_to!("Num.countTrailingZeroBits 0b0010_1000u8", 3, usize);
+    assert_evals_to!("Num.countTrailingZeroBits 0u8", 8, usize);
+    assert_evals_to!("Num.countTrailingZeroBits 0b0010_0000u16", 5, usize);
+    assert_evals_to!("Num.countTrailingZeroBits 0u32", 32, usize);
+    assert_evals_to!("Num.countTrailingZeroBits 0b0010_1000u64", 3, usize);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn count_one_bits() {
+    assert_evals_to!("Num.countOneBits 0b0010_1000u8", 2, usize);
+    assert_evals_to!("Num.countOneBits 0b0010_1010u16", 3, usize);
+    assert_evals_to!("Num.countOneBits 0b0010_1010u32", 3, usize);
+    assert_evals_to!("Num.countOneBits 0b0010_1010u64", 3, usize);
+    assert_evals_to!("Num.countOneBits -1i64", 64, usize);
+}
+
+#[test]
+#[cfg(feature = "gen-llvm")]
+fn count_bits_u128() {
+    assert_evals_to!("Num.countLeadingZeroBits 1u128", 127, usize);
+    assert_evals_to!("Num.countTrailingZeroBits 0u128", 128, usize);
+    assert_evals_to!("Num.countOneBits 0xFFu128", 8, usize);
+}
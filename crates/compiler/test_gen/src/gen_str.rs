@@ -13,7 +13,7 @@ use crate::helpers::dev::assert_evals_to as assert_llvm_evals_to;
 #[allow(unused_imports)]
 use indoc::indoc;
 #[allow(unused_imports)]
-use roc_std::{RocList, RocResult, RocStr};
+use roc_std::{RocList, RocOrder, RocResult, RocStr};
 
 #[test]
 #[cfg(any(feature = "gen-llvm"))]
@@ -2044,3 +2044,115 @@ fn destructure_pattern_assigned_from_thunk_tag() {
         RocStr
     );
 }
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_to_upper() {
+    assert_evals_to!(
+        r#"Str.toUpper "Hello, World!""#,
+        RocStr::from("HELLO, WORLD!"),
+        RocStr
+    );
+    assert_evals_to!(
+        r#"Str.toUpper "a string so large that it must be heap-allocated, straße""#,
+        RocStr::from("A STRING SO LARGE THAT IT MUST BE HEAP-ALLOCATED, STRASSE"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_to_lower() {
+    assert_evals_to!(
+        r#"Str.toLower "Hello, World!""#,
+        RocStr::from("hello, world!"),
+        RocStr
+    );
+    assert_evals_to!(
+        r#"Str.toLower "ÀÉÎ İ""#,
+        RocStr::from("àéî i\u{307}"),
+        RocStr
+    );
+    assert_evals_to!(
+        r#"Str.toLower "ΣΑΣ ΟΔΟΣ. Σ""#,
+        RocStr::from("σας οδος. σ"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_case_fold() {
+    assert_evals_to!(
+        r#"Str.caseFold "Straße" == Str.caseFold "STRASSE""#,
+        true,
+        bool
+    );
+    assert_evals_to!(r#"Str.caseFold "ΜΆΪΟΣ""#, RocStr::from("μάϊοσ"), RocStr);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_to_nfc() {
+    assert_evals_to!(
+        r#"Str.toNfc "cafe\u(301)""#,
+        RocStr::from("caf\u{E9}"),
+        RocStr
+    );
+    assert_evals_to!(
+        r#"Str.toNfc "\u(1111)\u(1171)\u(11B6)""#,
+        RocStr::from("\u{D4DB}"),
+        RocStr
+    );
+    assert_evals_to!(
+        r#"Str.toNfc "plain ASCII text""#,
+        RocStr::from("plain ASCII text"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_to_nfd() {
+    assert_evals_to!(
+        r#"Str.toNfd "caf\u(E9)""#,
+        RocStr::from("cafe\u{301}"),
+        RocStr
+    );
+    assert_evals_to!(
+        r#"Str.toNfd "s\u(307)\u(323)""#,
+        RocStr::from("s\u{323}\u{307}"),
+        RocStr
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_compare_equal() {
+    assert_evals_to!(r#"Str.compare "" """#, RocOrder::Eq, RocOrder);
+    assert_evals_to!(r#"Str.compare "Roc" "Roc""#, RocOrder::Eq, RocOrder);
+    assert_evals_to!(
+        r#"Str.compare "A string so long that it must be heap-allocated" "A string so long that it must be heap-allocated""#,
+        RocOrder::Eq,
+        RocOrder
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_compare_prefix() {
+    assert_evals_to!(r#"Str.compare "" "a""#, RocOrder::Lt, RocOrder);
+    assert_evals_to!(r#"Str.compare "Roc" "Rocket""#, RocOrder::Lt, RocOrder);
+    assert_evals_to!(r#"Str.compare "Rocket" "Roc""#, RocOrder::Gt, RocOrder);
+    assert_evals_to!(r#"Str.compare "Apple" "Banana""#, RocOrder::Lt, RocOrder);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+fn str_compare_multi_byte() {
+    // é is 0xC3 0xA9 in UTF-8, which sorts after every ASCII byte
+    assert_evals_to!(r#"Str.compare "caf\u(E9)" "caff""#, RocOrder::Gt, RocOrder);
+    assert_evals_to!(r#"Str.compare "z" "\u(E9)""#, RocOrder::Lt, RocOrder);
+    assert_evals_to!(r#"Str.compare "💖" "😀""#, RocOrder::Lt, RocOrder);
+    assert_evals_to!(r#"Str.compare "😀" "😀""#, RocOrder::Eq, RocOrder);
+}
@@ -18,6 +18,7 @@ use roc_collections::all::MutMap;
 use roc_load::ExecutionMode;
 use roc_load::LoadConfig;
 use roc_load::LoadMonomorphizedError;
+use roc_load::OptLevel;
 use roc_load::Threading;
 use roc_module::symbol::Interns;
 use roc_module::symbol::Symbol;
@@ -76,7 +77,7 @@ fn promote_expr_to_module(src: &str) -> String {
     buffer
 }
 
-fn compiles_to_ir(test_name: &str, src: &str, mode: &str, no_check: bool) {
+fn compiles_to_ir(test_name: &str, src: &str, mode: &str, opt_level: &str, no_check: bool) {
     use roc_packaging::cache::RocCacheDir;
     use std::path::PathBuf;
 
@@ -86,6 +87,13 @@ fn compiles_to_ir(test_name: &str, src: &str, mode: &str, no_check: bool) {
         _ => panic!("Invalid test_mono exec mode {mode}"),
     };
 
+    let opt_level = match opt_level {
+        "normal" => OptLevel::Normal,
+        "development" => OptLevel::Development,
+        "optimize" => OptLevel::Optimize,
+        _ => panic!("Invalid test_mono opt level {opt_level}"),
+    };
+
     let arena = &Bump::new();
 
     let filename = PathBuf::from("Test.roc");
@@ -108,6 +116,7 @@ fn compiles_to_ir(test_name: &str, src: &str, mode: &str, no_check: bool) {
         render: roc_reporting::report::RenderTarget::Generic,
         palette: roc_reporting::report::DEFAULT_PALETTE,
         exec_mode,
+        opt_level,
     };
     let loaded = roc_load::load_and_monomorphize_from_str(
         arena,
@@ -2537,3 +2546,146 @@ fn recursively_build_effect() {
         "#
     )
 }
+
+#[mono_test(opt_level = "optimize")]
+fn inline_and_fold_arithmetic() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        double : I64 -> I64
+        double = \x -> x * 2
+
+        main = double 21 + 1
+        "#
+    )
+}
+
+#[mono_test(opt_level = "optimize")]
+fn inline_and_fold_if_on_constant() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        isBig : I64 -> Bool
+        isBig = \x -> x > 2
+
+        main = if isBig 3 then "big" else "small"
+        "#
+    )
+}
+
+#[mono_test(opt_level = "development")]
+fn inline_branching_function() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        clamp : I64 -> I64
+        clamp = \n -> if n > 10 then 10 else n
+
+        main : I64 -> I64
+        main = \n -> clamp n + clamp (n + 1)
+        "#
+    )
+}
+
+#[mono_test(opt_level = "optimize")]
+fn recursive_function_not_inlined() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        sum : I64 -> I64
+        sum = \n -> if n == 0 then 0 else n + sum (n - 1)
+
+        main = sum 3
+        "#
+    )
+}
+
+#[mono_test(opt_level = "optimize")]
+fn overflowing_arithmetic_not_folded() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        x : U8
+        x = 255
+
+        main = x + 1
+        "#
+    )
+}
+
+#[mono_test(opt_level = "development")]
+fn inline_higher_order_lowlevel() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        doubleAll : List I64 -> List I64
+        doubleAll = \xs -> List.map xs \x -> x * 2
+
+        main = doubleAll [1, 2, 3]
+        "#
+    )
+}
+
+#[mono_test]
+fn mutual_tail_recursion() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        isEven : U64 -> Bool
+        isEven = \n -> if n == 0 then Bool.true else isOdd (n - 1)
+
+        isOdd : U64 -> Bool
+        isOdd = \n -> if n == 0 then Bool.false else isEven (n - 1)
+
+        main = isEven 10
+        "#
+    )
+}
+
+#[mono_test]
+fn mutual_recursion_not_in_tail_position() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        ping : U64 -> U64
+        ping = \n -> if n == 0 then 0 else 1 + pong (n - 1)
+
+        pong : U64 -> U64
+        pong = \n -> if n == 0 then 0 else ping (n - 1)
+
+        main = ping 10
+        "#
+    )
+}
+
+#[mono_test]
+fn inspect_derived_record() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        main = Inspect.toStr { name: "Roc", tags: [Fast, Friendly (Id 1u8)], ok: Bool.true }
+        "#
+    )
+}
+
+#[mono_test]
+fn inspect_opaque_without_impl() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        Password := Str
+
+        main = Inspect.toStr { user: "roc", password: @Password "hunter2" }
+        "#
+    )
+}
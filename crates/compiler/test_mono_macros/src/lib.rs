@@ -8,6 +8,7 @@ use quote::quote;
 pub fn mono_test(args: TokenStream, item: TokenStream) -> TokenStream {
     let mut no_check = false;
     let mut mode = "exec".to_owned();
+    let mut opt_level = "normal".to_owned();
     for arg in syn::parse_macro_input!(args as syn::AttributeArgs) {
         use syn::{Lit, Meta, MetaNameValue, NestedMeta};
         if let NestedMeta::Meta(Meta::NameValue(MetaNameValue {
@@ -19,6 +20,9 @@ pub fn mono_test(args: TokenStream, item: TokenStream) -> TokenStream {
             if path.is_ident("mode") {
                 mode = s.value();
             }
+            if path.is_ident("opt_level") {
+                opt_level = s.value();
+            }
             if path.is_ident("no_check") {
                 no_check = true;
             }
@@ -40,7 +44,7 @@ pub fn mono_test(args: TokenStream, item: TokenStream) -> TokenStream {
         #[test]
         #(#attributes)*
         #visibility fn #name(#args) {
-            compiles_to_ir(#name_str, #body, &#mode, #no_check);
+            compiles_to_ir(#name_str, #body, &#mode, &#opt_level, #no_check);
 
         }
     };
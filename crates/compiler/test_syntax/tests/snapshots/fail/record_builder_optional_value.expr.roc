@@ -0,0 +1 @@
+{ a: <- get "a", b? 5 }
\ No newline at end of file
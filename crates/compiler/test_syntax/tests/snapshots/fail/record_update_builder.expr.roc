@@ -0,0 +1 @@
+{ foo & a: <- get "a" }
\ No newline at end of file
@@ -0,0 +1,6 @@
+succeed {
+    a: <- get "a",
+    b: <- get "b",
+    c: 3,
+    d,
+}
@@ -0,0 +1 @@
+"Total: \(Num.toStr (price * quantity))"
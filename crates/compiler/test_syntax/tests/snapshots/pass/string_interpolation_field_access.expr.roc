@@ -0,0 +1 @@
+"Welcome back, \(user.name)!"
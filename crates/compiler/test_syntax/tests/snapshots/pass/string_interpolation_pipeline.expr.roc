@@ -0,0 +1 @@
+"Items: \(items |> List.map .name |> Str.joinWith ", ")"
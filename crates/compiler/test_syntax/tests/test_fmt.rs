@@ -1047,6 +1047,40 @@ mod test_fmt {
         ));
     }
 
+    #[test]
+    fn string_interpolation_with_call() {
+        expr_formats_same(indoc!(
+            r#"
+            "Total: \(Num.toStr (price * quantity))"
+            "#
+        ));
+    }
+
+    #[test]
+    fn string_interpolation_with_pipeline() {
+        expr_formats_same(indoc!(
+            r#"
+            "Items: \(items |> List.map .name |> Str.joinWith ", ")"
+            "#
+        ));
+    }
+
+    #[test]
+    fn string_interpolation_with_spaces() {
+        expr_formats_to(
+            indoc!(
+                r#"
+                "Hi, \(  user.name  )!"
+                "#
+            ),
+            indoc!(
+                r#"
+                "Hi, \(user.name)!"
+                "#
+            ),
+        );
+    }
+
     #[test]
     fn empty_block_string() {
         expr_formats_same(indoc!(
@@ -1926,6 +1960,56 @@ mod test_fmt {
         );
     }
 
+    #[test]
+    fn record_builder() {
+        expr_formats_same(indoc!(
+            r#"
+            { a: 1, b: <- get "b" |> batch, c: <- get "c" |> batch, d }
+            "#
+        ));
+
+        expr_formats_to(
+            indoc!(
+                r#"
+                {   a: 1, b:  <-  get "b" |> batch,  c:<- get "c" |> batch }
+                "#
+            ),
+            indoc!(
+                r#"
+                { a: 1, b: <- get "b" |> batch, c: <- get "c" |> batch }
+                "#
+            ),
+        );
+
+        expr_formats_same(indoc!(
+            r#"
+            succeed {
+                a: <- get "a" |> batch,
+                b: <- get "b" |> batch,
+            }
+            "#
+        ));
+
+        expr_formats_to(
+            indoc!(
+                r#"
+                succeed {
+                    a:  <-   get "a" |> batch,
+                    b: 2
+                }
+                "#
+            ),
+            indoc!(
+                r#"
+                succeed {
+                    a: <- get "a" |> batch,
+                    b: 2,
+                }
+                "#
+            ),
+        );
+    }
+
     #[test]
     fn final_comments_in_records() {
         expr_formats_same(indoc!(
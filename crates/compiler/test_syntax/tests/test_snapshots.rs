@@ -217,12 +217,14 @@ mod test_snapshots {
         fail/pattern_in_parens_end_comma.expr,
         fail/pattern_in_parens_indent_open.expr,
         fail/pattern_in_parens_open.expr,
+        fail/record_builder_optional_value.expr,
         fail/record_type_end.expr,
         fail/record_type_keyword_field_name.expr,
         fail/record_type_missing_comma.expr,
         fail/record_type_open.expr,
         fail/record_type_open_indent.expr,
         fail/record_type_tab.expr,
+        fail/record_update_builder.expr,
         fail/single_no_end.expr,
         fail/tab_crash.header,
         fail/tag_union_end.expr,
@@ -408,6 +410,7 @@ mod test_snapshots {
         pass/qualified_field.expr,
         pass/qualified_var.expr,
         pass/record_access_after_tuple.expr,
+        pass/record_builder.expr,
         pass/record_destructure_def.expr,
         pass/record_func_type_decl.expr,
         pass/record_type_with_function.expr,
@@ -420,6 +423,9 @@ mod test_snapshots {
         pass/spaced_singleton_list.expr,
         pass/spaces_inside_empty_list.expr,
         pass/standalone_module_defs.moduledefs,
+        pass/string_interpolation_call.expr,
+        pass/string_interpolation_field_access.expr,
+        pass/string_interpolation_pipeline.expr,
         pass/string_without_escape.expr,
         pass/sub_var_with_spaces.expr,
         pass/sub_with_spaces.expr,
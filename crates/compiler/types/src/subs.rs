@@ -1716,6 +1716,10 @@ impl Subs {
     pub const AB_HASH: SubsSlice<Symbol>     = SubsSlice::new(3, 1);
     #[rustfmt::skip]
     pub const AB_EQ: SubsSlice<Symbol>       = SubsSlice::new(4, 1);
+    #[rustfmt::skip]
+    pub const AB_INSPECT: SubsSlice<Symbol>  = SubsSlice::new(5, 1);
+    #[rustfmt::skip]
+    pub const AB_SORT: SubsSlice<Symbol>     = SubsSlice::new(6, 1);
 
     pub fn new() -> Self {
         Self::with_capacity(0)
@@ -1740,6 +1744,8 @@ impl Subs {
         symbol_names.push(Symbol::HASH_HASHER);
         symbol_names.push(Symbol::HASH_HASH_ABILITY);
         symbol_names.push(Symbol::BOOL_EQ);
+        symbol_names.push(Symbol::INSPECT_INSPECT_ABILITY);
+        symbol_names.push(Symbol::SORT_SORT_ABILITY);
 
         let mut subs = Subs {
             utable: UnificationTable::default(),
@@ -2116,6 +2122,24 @@ impl Subs {
         self.utable.is_empty()
     }
 
+    /// Roughly how many bytes this takes up, for `--mem-stats`. This doesn't count what the
+    /// entries own themselves, like the heap-allocated names of tags and record fields.
+    pub fn memory_usage(&self) -> usize {
+        fn vec_bytes<T>(vec: &Vec<T>) -> usize {
+            vec.capacity() * std::mem::size_of::<T>()
+        }
+
+        self.utable.memory_usage()
+            + vec_bytes(&self.variables)
+            + vec_bytes(&self.tuple_elem_indices)
+            + vec_bytes(&self.tag_names)
+            + vec_bytes(&self.symbol_names)
+            + vec_bytes(&self.field_names)
+            + vec_bytes(&self.record_fields)
+            + vec_bytes(&self.variable_slices)
+            + vec_bytes(&self.unspecialized_lambda_sets)
+    }
+
     pub fn contains(&self, var: Variable) -> bool {
         (var.index() as usize) < self.len()
     }
@@ -53,6 +53,11 @@ impl UnificationTable {
         self.contents.is_empty()
     }
 
+    pub fn memory_usage(&self) -> usize {
+        self.contents.capacity() * std::mem::size_of::<Content>()
+            + self.metadata.capacity() * std::mem::size_of::<Combine>()
+    }
+
     pub fn reserve(&mut self, extra_length: usize) -> VariableSubsSlice {
         use std::iter::repeat;
 
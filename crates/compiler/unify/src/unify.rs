@@ -254,8 +254,9 @@ impl<M: MetaCollector> Unified<M> {
 pub enum Obligated {
     /// Opaque types can either define custom implementations for an ability, or ask the compiler
     /// to generate an implementation of a builtin ability for them. In any case they have unique
-    /// obligation rules for abilities.
-    Opaque(Symbol),
+    /// obligation rules for abilities. The variable holds the opaque type, for abilities whose
+    /// obligations also depend on the opaque's type arguments.
+    Opaque(Symbol, Variable),
     /// A structural type for which the compiler can at most generate an adhoc implementation of
     /// a builtin ability.
     Adhoc(Variable),
@@ -1002,7 +1003,7 @@ fn opaque_obligation(opaque: Symbol, opaque_var: Variable) -> Obligated {
     match opaque.module_id() {
         // Numbers should be treated as ad-hoc obligations for ability checking.
         ModuleId::NUM => Obligated::Adhoc(opaque_var),
-        _ => Obligated::Opaque(opaque),
+        _ => Obligated::Opaque(opaque, opaque_var),
     }
 }
 
@@ -0,0 +1,180 @@
+//! Generates `docs.json`, a machine-readable description of every exposed definition in the
+//! package, for editors and other tools which want to show Roc documentation.
+use crate::{ability_member_type_to_plain_text, type_annotation_to_plain_text};
+use roc_collections::VecSet;
+use roc_load::docs::{AbilityMember, DocDef, DocEntry, ModuleDocumentation, TypeAnnotation};
+use roc_module::symbol::Symbol;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+struct ModuleJson<'a> {
+    name: &'a str,
+    entries: Vec<EntryJson<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+enum EntryJson<'a> {
+    #[serde(rename_all = "camelCase")]
+    Def {
+        name: &'a str,
+        type_vars: &'a [String],
+        /// The rendered type annotation, if the def has one
+        #[serde(rename = "type")]
+        type_signature: Option<String>,
+        docs: Option<&'a str>,
+        /// Only abilities have members
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        ability_members: Vec<AbilityMemberJson<'a>>,
+    },
+    DetachedDoc {
+        docs: &'a str,
+    },
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AbilityMemberJson<'a> {
+    name: &'a str,
+    /// The member's full signature, including its `has` clause
+    #[serde(rename = "type")]
+    type_signature: String,
+    able_variables: Vec<AbleVariableJson<'a>>,
+    docs: Option<&'a str>,
+}
+
+#[derive(Debug, Serialize)]
+struct AbleVariableJson<'a> {
+    name: &'a str,
+    abilities: Vec<String>,
+}
+
+pub fn docs_json<'a, I: Iterator<Item = &'a ModuleDocumentation>>(
+    modules: I,
+    all_exposed_symbols: &VecSet<Symbol>,
+) -> String {
+    let modules: Vec<ModuleJson> = modules
+        .map(|module| ModuleJson {
+            name: module.name.as_str(),
+            entries: module
+                .entries
+                .iter()
+                .filter_map(|entry| match entry {
+                    DocEntry::DocDef(doc_def) => {
+                        // Only include entries that are exposed
+                        if all_exposed_symbols.contains(&doc_def.symbol) {
+                            Some(doc_def_to_json(doc_def))
+                        } else {
+                            None
+                        }
+                    }
+                    DocEntry::DetachedDoc(docs) => Some(EntryJson::DetachedDoc {
+                        docs: docs.as_str(),
+                    }),
+                })
+                .collect(),
+        })
+        .collect();
+
+    serde_json::to_string_pretty(&modules).expect("docs are always serializable")
+}
+
+fn doc_def_to_json(doc_def: &DocDef) -> EntryJson {
+    let (type_signature, ability_members) = match &doc_def.type_annotation {
+        TypeAnnotation::NoTypeAnn => (None, Vec::new()),
+        TypeAnnotation::Ability { members } => {
+            (None, members.iter().map(ability_member_to_json).collect())
+        }
+        type_ann => {
+            let mut buf = String::new();
+
+            type_annotation_to_plain_text(&mut buf, type_ann, false);
+
+            (Some(buf), Vec::new())
+        }
+    };
+
+    EntryJson::Def {
+        name: doc_def.name.as_str(),
+        type_vars: &doc_def.type_vars,
+        type_signature,
+        docs: doc_def.docs.as_deref(),
+        ability_members,
+    }
+}
+
+fn ability_member_to_json(member: &AbilityMember) -> AbilityMemberJson {
+    let mut type_signature = String::new();
+
+    ability_member_type_to_plain_text(&mut type_signature, member);
+
+    AbilityMemberJson {
+        name: member.name.as_str(),
+        type_signature,
+        able_variables: member
+            .able_variables
+            .iter()
+            .map(|(name, abilities)| AbleVariableJson {
+                name: name.as_str(),
+                abilities: abilities
+                    .iter()
+                    .map(|ability| {
+                        let mut buf = String::new();
+
+                        type_annotation_to_plain_text(&mut buf, ability, false);
+
+                        buf
+                    })
+                    .collect(),
+            })
+            .collect(),
+        docs: member.docs.as_deref(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::docs_json;
+    use crate::test_fixtures::module_documentation;
+
+    #[test]
+    fn exposed_defs_and_module_docs() {
+        let (module, exposed) = module_documentation();
+
+        let json = docs_json(std::iter::once(&module), &exposed);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            value,
+            serde_json::json!([{
+                "name": "Str",
+                "entries": [
+                    {
+                        "kind": "detachedDoc",
+                        "docs": "Functions for working with strings.\n",
+                    },
+                    {
+                        "kind": "def",
+                        "name": "isEmpty",
+                        "typeVars": [],
+                        "type": "Str -> Bool",
+                        "docs": "\n  Returns `Bool.true` if the string is empty.\n\nMore details.",
+                    },
+                    {
+                        "kind": "def",
+                        "name": "Encoding",
+                        "typeVars": [],
+                        "type": null,
+                        "docs": null,
+                        "abilityMembers": [{
+                            "name": "toEncoder",
+                            "type": "val -> Encoder | val has Encoding",
+                            "ableVariables": [{ "name": "val", "abilities": ["Encoding"] }],
+                            "docs": "Creates an encoder for the value.",
+                        }],
+                    },
+                ],
+            }])
+        );
+    }
+}
@@ -1,5 +1,8 @@
 //! Generates html documentation from Roc files. Used for
 //! [roc-lang.org/builtins/Num](https://www.roc-lang.org/builtins/Num).
+//!
+//! The same documentation can also be generated as JSON or Markdown, for tools and
+//! for hosting docs somewhere other than a static site.
 extern crate pulldown_cmark;
 extern crate roc_load;
 use bumpalo::Bump;
@@ -10,10 +13,10 @@ use roc_code_markup::markup::nodes::MarkupNode;
 use roc_code_markup::slow_pool::SlowPool;
 use roc_collections::VecSet;
 use roc_highlight::highlight_parser::{highlight_defs, highlight_expr};
-use roc_load::docs::{DocEntry, TypeAnnotation};
+use roc_load::docs::{AbilityMember, DocEntry, TypeAnnotation};
 use roc_load::docs::{ModuleDocumentation, RecordField};
-use roc_load::{ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, Threading};
-use roc_module::symbol::{Interns, Symbol};
+use roc_load::{ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, OptLevel, Threading};
+use roc_module::symbol::Symbol;
 use roc_packaging::cache::{self, RocCacheDir};
 use roc_parse::ident::{parse_ident, Accessor, Ident};
 use roc_parse::state::State;
@@ -23,9 +26,53 @@ use std::path::{Path, PathBuf};
 
 mod docs_error;
 mod html;
+mod json;
+mod markdown;
+mod search_index;
+#[cfg(test)]
+mod test_fixtures;
 
 const BUILD_DIR: &str = "./generated-docs";
 
+/// The kinds of output `roc docs` can generate.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DocsFormat {
+    /// A static website, with one page per module
+    Html,
+    /// A single `docs.json` file describing every exposed definition
+    Json,
+    /// One Markdown file per module
+    Markdown,
+}
+
+impl DocsFormat {
+    pub const NAMES: [&'static str; 3] = ["html", "json", "markdown"];
+}
+
+impl std::str::FromStr for DocsFormat {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "html" => Ok(DocsFormat::Html),
+            "json" => Ok(DocsFormat::Json),
+            "markdown" => Ok(DocsFormat::Markdown),
+            _ => Err(format!(
+                "Roc does not know how to generate docs as {}",
+                string
+            )),
+        }
+    }
+}
+
+pub fn generate_docs(root_file: PathBuf, format: DocsFormat) {
+    match format {
+        DocsFormat::Html => generate_docs_html(root_file),
+        DocsFormat::Json => generate_docs_json(root_file),
+        DocsFormat::Markdown => generate_docs_markdown(root_file),
+    }
+}
+
 pub fn generate_docs_html(root_file: PathBuf) {
     let build_dir = Path::new(BUILD_DIR);
     let loaded_module = load_module_for_docs(root_file);
@@ -34,12 +81,9 @@ pub fn generate_docs_html(root_file: PathBuf) {
     let package_name = "Documentation".to_string();
     let version = String::new();
 
-    // Clear out the generated-docs dir (we'll create a fresh one at the end)
-    if build_dir.exists() {
-        fs::remove_dir_all(build_dir)
-            .expect("TODO gracefully handle being unable to delete build dir");
-    }
-    fs::create_dir_all(build_dir).expect("TODO gracefully handle being unable to create build dir");
+    clear_build_dir(build_dir);
+
+    let all_exposed_symbols = all_exposed_symbols(&loaded_module);
 
     // Copy over the assets
     fs::write(
@@ -60,6 +104,15 @@ pub fn generate_docs_html(root_file: PathBuf) {
     )
     .expect("TODO gracefully handle failing to make the favicon");
 
+    fs::write(
+        build_dir.join("search-index.json"),
+        search_index::search_index_json(
+            loaded_module.docs_by_module.values(),
+            &all_exposed_symbols,
+        ),
+    )
+    .expect("TODO gracefully handle failing to make the search index");
+
     let template_html = include_str!("./static/index.html")
         .replace("<!-- search.js -->", "/search.js")
         .replace("<!-- styles.css -->", "/styles.css")
@@ -83,16 +136,6 @@ pub fn generate_docs_html(root_file: PathBuf) {
             render_sidebar(loaded_module.docs_by_module.values()).as_str(),
         );
 
-    let all_exposed_symbols = {
-        let mut set = VecSet::default();
-
-        for docs in loaded_module.docs_by_module.values() {
-            set.insert_all(docs.exposed_symbols.iter().copied());
-        }
-
-        set
-    };
-
     // Write each package's module docs html file
     for module_docs in loaded_module.docs_by_module.values() {
         let module_name = module_docs.name.as_str();
@@ -123,6 +166,63 @@ pub fn generate_docs_html(root_file: PathBuf) {
     println!("🎉 Docs generated in {}", build_dir.display());
 }
 
+pub fn generate_docs_json(root_file: PathBuf) {
+    let build_dir = Path::new(BUILD_DIR);
+    let loaded_module = load_module_for_docs(root_file);
+
+    clear_build_dir(build_dir);
+
+    let all_exposed_symbols = all_exposed_symbols(&loaded_module);
+
+    fs::write(
+        build_dir.join("docs.json"),
+        json::docs_json(loaded_module.docs_by_module.values(), &all_exposed_symbols),
+    )
+    .expect("TODO gracefully handle failing to write docs.json");
+
+    println!("🎉 Docs generated in {}", build_dir.display());
+}
+
+pub fn generate_docs_markdown(root_file: PathBuf) {
+    let build_dir = Path::new(BUILD_DIR);
+    let loaded_module = load_module_for_docs(root_file);
+
+    clear_build_dir(build_dir);
+
+    let all_exposed_symbols = all_exposed_symbols(&loaded_module);
+
+    for module_docs in loaded_module.docs_by_module.values() {
+        let rendered_module = markdown::render_module(module_docs, &all_exposed_symbols);
+
+        fs::write(
+            build_dir.join(format!("{}.md", module_docs.name)),
+            rendered_module,
+        )
+        .expect("TODO gracefully handle failing to write a module's markdown file");
+    }
+
+    println!("🎉 Docs generated in {}", build_dir.display());
+}
+
+/// Clear out the generated-docs dir, so that we start from a fresh one
+fn clear_build_dir(build_dir: &Path) {
+    if build_dir.exists() {
+        fs::remove_dir_all(build_dir)
+            .expect("TODO gracefully handle being unable to delete build dir");
+    }
+    fs::create_dir_all(build_dir).expect("TODO gracefully handle being unable to create build dir");
+}
+
+fn all_exposed_symbols(loaded_module: &LoadedModule) -> VecSet<Symbol> {
+    let mut set = VecSet::default();
+
+    for docs in loaded_module.docs_by_module.values() {
+        set.insert_all(docs.exposed_symbols.iter().copied());
+    }
+
+    set
+}
+
 fn sidebar_link_url(module_name: &str) -> String {
     format!("{}{}", base_url(), module_name)
 }
@@ -194,6 +294,12 @@ fn render_module_documentation(
         link_buf
     });
 
+    let linker = TypeLinker {
+        scope: &module.scope,
+        loaded_module: root_module,
+        all_exposed_symbols,
+    };
+
     for entry in &module.entries {
         match entry {
             DocEntry::DocDef(doc_def) => {
@@ -216,7 +322,7 @@ fn render_module_documentation(
 
                     if !matches!(type_ann, TypeAnnotation::NoTypeAnn) {
                         content.push_str(" : ");
-                        type_annotation_to_html(0, &mut content, type_ann, false);
+                        type_annotation_to_html(0, &mut content, type_ann, false, &linker);
                     }
 
                     push_html(
@@ -309,6 +415,18 @@ fn base_url() -> String {
     }
 }
 
+/// The root of the docs for the package with the given name, e.g. "/json/" for the package
+/// whose header is `package "json"`. Every package's docs are published under its own name,
+/// so this is the same no matter which shorthand a module imported the package under.
+fn package_base_url(package_name: &str) -> String {
+    let mut url_str = base_url();
+
+    url_str.push_str(package_name);
+    url_str.push('/');
+
+    url_str
+}
+
 fn render_name_and_version(name: &str, version: &str) -> String {
     let mut buf = String::new();
     let mut url_str = base_url();
@@ -405,6 +523,7 @@ pub fn load_module_for_docs(filename: PathBuf) -> LoadedModule {
         palette: roc_reporting::report::DEFAULT_PALETTE,
         threading: Threading::AllAvailable,
         exec_mode: ExecutionMode::Check,
+        opt_level: OptLevel::Normal,
     };
     match roc_load::load_and_typecheck(
         &arena,
@@ -439,6 +558,7 @@ fn type_annotation_to_html(
     buf: &mut String,
     type_ann: &TypeAnnotation,
     needs_parens: bool,
+    linker: &TypeLinker,
 ) {
     let is_multiline = should_be_multiline(type_ann);
     match type_ann {
@@ -473,7 +593,7 @@ fn type_annotation_to_html(
 
                     for type_value in &tag.values {
                         buf.push(' ');
-                        type_annotation_to_html(next_indent_level, buf, type_value, true);
+                        type_annotation_to_html(next_indent_level, buf, type_value, true, linker);
                     }
 
                     if is_multiline {
@@ -492,23 +612,23 @@ fn type_annotation_to_html(
                 buf.push(']');
             }
 
-            type_annotation_to_html(indent_level, buf, extension, true);
+            type_annotation_to_html(indent_level, buf, extension, true, linker);
         }
         TypeAnnotation::BoundVariable(var_name) => {
             buf.push_str(var_name);
         }
         TypeAnnotation::Apply { name, parts } => {
             if parts.is_empty() {
-                buf.push_str(name);
+                push_type_name_html(buf, name, linker);
             } else {
                 if needs_parens {
                     buf.push('(');
                 }
 
-                buf.push_str(name);
+                push_type_name_html(buf, name, linker);
                 for part in parts {
                     buf.push(' ');
-                    type_annotation_to_html(indent_level, buf, part, true);
+                    type_annotation_to_html(indent_level, buf, part, true, linker);
                 }
 
                 if needs_parens {
@@ -556,13 +676,25 @@ fn type_annotation_to_html(
                             type_annotation, ..
                         } => {
                             buf.push_str(" : ");
-                            type_annotation_to_html(next_indent_level, buf, type_annotation, false);
+                            type_annotation_to_html(
+                                next_indent_level,
+                                buf,
+                                type_annotation,
+                                false,
+                                linker,
+                            );
                         }
                         RecordField::OptionalField {
                             type_annotation, ..
                         } => {
                             buf.push_str(" ? ");
-                            type_annotation_to_html(next_indent_level, buf, type_annotation, false);
+                            type_annotation_to_html(
+                                next_indent_level,
+                                buf,
+                                type_annotation,
+                                false,
+                                linker,
+                            );
                         }
                         RecordField::LabelOnly { .. } => {}
                     }
@@ -585,7 +717,7 @@ fn type_annotation_to_html(
                 buf.push('}');
             }
 
-            type_annotation_to_html(indent_level, buf, extension, true);
+            type_annotation_to_html(indent_level, buf, extension, true, linker);
         }
         TypeAnnotation::Function { args, output } => {
             let mut peekable_args = args.iter().peekable();
@@ -597,7 +729,7 @@ fn type_annotation_to_html(
                     indent(buf, indent_level + 1);
                 }
 
-                type_annotation_to_html(indent_level, buf, arg, false);
+                type_annotation_to_html(indent_level, buf, arg, false, linker);
 
                 if peekable_args.peek().is_some() {
                     buf.push_str(", ");
@@ -617,7 +749,7 @@ fn type_annotation_to_html(
                 next_indent_level += 1;
             }
 
-            type_annotation_to_html(next_indent_level, buf, output, false);
+            type_annotation_to_html(next_indent_level, buf, output, false, linker);
         }
         TypeAnnotation::Ability { members: _ } => {
             // TODO(abilities): fill me in
@@ -633,6 +765,151 @@ fn type_annotation_to_html(
     }
 }
 
+/// Renders a type annotation on a single line, without any markup (e.g. for the search index).
+fn type_annotation_to_plain_text(buf: &mut String, type_ann: &TypeAnnotation, needs_parens: bool) {
+    match type_ann {
+        TypeAnnotation::TagUnion { tags, extension } => {
+            buf.push('[');
+
+            for (index, tag) in tags.iter().enumerate() {
+                if index > 0 {
+                    buf.push_str(", ");
+                }
+
+                buf.push_str(tag.name.as_str());
+
+                for type_value in &tag.values {
+                    buf.push(' ');
+                    type_annotation_to_plain_text(buf, type_value, true);
+                }
+            }
+
+            buf.push(']');
+
+            type_annotation_to_plain_text(buf, extension, true);
+        }
+        TypeAnnotation::BoundVariable(var_name) => {
+            buf.push_str(var_name);
+        }
+        TypeAnnotation::Apply { name, parts } => {
+            let needs_parens = needs_parens && !parts.is_empty();
+
+            if needs_parens {
+                buf.push('(');
+            }
+
+            buf.push_str(name);
+
+            for part in parts {
+                buf.push(' ');
+                type_annotation_to_plain_text(buf, part, true);
+            }
+
+            if needs_parens {
+                buf.push(')');
+            }
+        }
+        TypeAnnotation::Record { fields, extension } => {
+            if fields.is_empty() {
+                buf.push_str("{}");
+            } else {
+                buf.push_str("{ ");
+
+                for (index, field) in fields.iter().enumerate() {
+                    if index > 0 {
+                        buf.push_str(", ");
+                    }
+
+                    match field {
+                        RecordField::RecordField {
+                            name,
+                            type_annotation,
+                        } => {
+                            buf.push_str(name);
+                            buf.push_str(" : ");
+                            type_annotation_to_plain_text(buf, type_annotation, false);
+                        }
+                        RecordField::OptionalField {
+                            name,
+                            type_annotation,
+                        } => {
+                            buf.push_str(name);
+                            buf.push_str(" ? ");
+                            type_annotation_to_plain_text(buf, type_annotation, false);
+                        }
+                        RecordField::LabelOnly { name } => {
+                            buf.push_str(name);
+                        }
+                    }
+                }
+
+                buf.push_str(" }");
+            }
+
+            type_annotation_to_plain_text(buf, extension, true);
+        }
+        TypeAnnotation::Function { args, output } => {
+            if needs_parens {
+                buf.push('(');
+            }
+
+            for (index, arg) in args.iter().enumerate() {
+                if index > 0 {
+                    buf.push_str(", ");
+                }
+
+                type_annotation_to_plain_text(buf, arg, true);
+            }
+
+            buf.push_str(" -> ");
+
+            type_annotation_to_plain_text(buf, output, false);
+
+            if needs_parens {
+                buf.push(')');
+            }
+        }
+        TypeAnnotation::Ability { members: _ } => {
+            // TODO(abilities): fill me in
+        }
+        TypeAnnotation::ObscuredTagUnion => {
+            buf.push_str("[@..]");
+        }
+        TypeAnnotation::ObscuredRecord => {
+            buf.push_str("{ @.. }");
+        }
+        TypeAnnotation::NoTypeAnn => {}
+        TypeAnnotation::Wildcard => buf.push('*'),
+    }
+}
+
+/// Renders an ability member's type on a single line, along with the abilities its variables
+/// must have, e.g. `a, hasher -> hasher | a has Hash`
+fn ability_member_type_to_plain_text(buf: &mut String, member: &AbilityMember) {
+    type_annotation_to_plain_text(buf, &member.type_annotation, false);
+
+    for (index, (var_name, abilities)) in member.able_variables.iter().enumerate() {
+        buf.push_str(if index == 0 { " | " } else { ", " });
+        buf.push_str(var_name);
+        buf.push_str(" has ");
+
+        for (ability_index, ability) in abilities.iter().enumerate() {
+            if ability_index > 0 {
+                buf.push_str(" & ");
+            }
+
+            type_annotation_to_plain_text(buf, ability, false);
+        }
+    }
+}
+
+fn push_type_name_html(buf: &mut String, name: &str, linker: &TypeLinker) {
+    match linker.url(name) {
+        Some(url) => push_html(buf, "a", vec![("href", url.as_str())], name),
+        None => buf.push_str(name),
+    }
+}
+
 fn should_be_multiline(type_ann: &TypeAnnotation) -> bool {
     match type_ann {
         TypeAnnotation::TagUnion { tags, extension } => {
@@ -712,21 +989,21 @@ struct DocUrl {
 fn doc_url<'a>(
     all_exposed_symbols: &VecSet<Symbol>,
     scope: &Scope,
-    interns: &'a Interns,
-    mut module_name: &'a str,
+    loaded_module: &'a LoadedModule,
+    module_name: &'a str,
     ident: &str,
 ) -> DocUrl {
-    if module_name.is_empty() {
+    let interns = &loaded_module.interns;
+
+    let symbol = if module_name.is_empty() {
         // This is an unqualified lookup, so look for the ident
         // in scope!
         match scope.lookup_str(ident, Region::zero()) {
-            Ok(symbol) => {
-                // Get the exact module_name from scope. It could be the
-                // current module's name, but it also could be a different
-                // module - for example, if this is in scope from an
-                // unqualified import.
-                module_name = symbol.module_string(interns);
-            }
+            // Get the exact module from scope. It could be the
+            // current module, but it also could be a different
+            // module - for example, if this is in scope from an
+            // unqualified import.
+            Ok(symbol) => symbol,
             Err(_) => {
                 dbg!(scope);
                 // TODO return Err here
@@ -741,37 +1018,62 @@ fn doc_url<'a>(
             Some(module_id) => {
                 let symbol = interns.symbol(module_id, ident.into());
 
-                if symbol.is_builtin() {
-                    // We can always generate links for builtin modules.
-                    // TODO add a `--include-builtins` CLI flag for generating offline docs locally
-                    // which include builtins; if that flag is omitted, have this code path generate
-                    // a link directly to the builtin docs on roc-lang.org instead of to a localhost
-                    // URL that will 404.
-                    module_name = symbol.module_string(interns);
-                }
+                // We can always generate links for builtin modules, and for modules from
+                // other packages (which link to that package's docs).
+                // TODO add a `--include-builtins` CLI flag for generating offline docs locally
+                // which include builtins; if that flag is omitted, have this code path generate
+                // a link directly to the builtin docs on roc-lang.org instead of to a localhost
+                // URL that will 404.
+                //
                 // Note: You can do qualified lookups on your own module, e.g.
                 // if I'm in the Foo module, I can do a `Foo.bar` lookup.
-                else if !all_exposed_symbols.contains(&symbol) {
+                if !symbol.is_builtin()
+                    && !loaded_module.package_names.contains_key(&module_id)
+                    && !all_exposed_symbols.contains(&symbol)
+                {
                     // TODO return Err here
                     panic!(
                             "Tried to generate an automatic link in docs for `{}.{}`, but `{}` does not expose `{}`.",
                             module_name, ident, module_name, ident);
                 }
 
-                // This is a valid symbol for this dependency,
-                // so proceed using the current module's name.
-                //
-                // TODO: In the future, this is where we'll
-                // incorporate the package name into the link.
+                symbol
             }
             None => {
                 // TODO return Err here
                 panic!("Tried to generate a doc link for `{}.{}` but the `{}` module was not imported!", module_name, ident, module_name);
             }
         }
+    };
+
+    let module_name = symbol.module_string(interns).as_str();
+
+    DocUrl {
+        url: symbol_url(loaded_module, symbol),
+        title: format!("Docs for {}.{}", module_name, ident),
     }
+}
+
+/// The URL of the docs for the given symbol. Symbols from other packages link to the docs of
+/// the package they came from, and everything else links into the docs being generated.
+fn symbol_url(loaded_module: &LoadedModule, symbol: Symbol) -> String {
+    let interns = &loaded_module.interns;
+
+    item_url(
+        loaded_module
+            .package_names
+            .get(&symbol.module_id())
+            .map(String::as_str),
+        symbol.module_string(interns).as_str(),
+        symbol.as_str(interns),
+    )
+}
 
-    let mut url = base_url();
+fn item_url(opt_package_name: Option<&str>, module_name: &str, ident: &str) -> String {
+    let mut url = match opt_package_name {
+        Some(package_name) => package_base_url(package_name),
+        None => base_url(),
+    };
 
     // Example:
     //
@@ -780,9 +1082,43 @@ fn doc_url<'a>(
     url.push('#');
     url.push_str(ident);
 
-    DocUrl {
-        url,
-        title: format!("Docs for {}.{}", module_name, ident),
+    url
+}
+
+/// Resolves the type names in signatures (e.g. `Task` or `Json.Json`) to links to their docs.
+struct TypeLinker<'a> {
+    scope: &'a Scope,
+    loaded_module: &'a LoadedModule,
+    all_exposed_symbols: &'a VecSet<Symbol>,
+}
+
+impl<'a> TypeLinker<'a> {
+    fn url(&self, type_name: &str) -> Option<String> {
+        let interns = &self.loaded_module.interns;
+
+        let symbol = match type_name.rsplit_once('.') {
+            None => self.scope.lookup_str(type_name, Region::zero()).ok()?,
+            Some((module_name, ident)) => {
+                let module_id = interns.module_ids.get_id(&module_name.into())?;
+                let ident_id = interns.all_ident_ids.get(&module_id)?.get_id(ident)?;
+
+                Symbol::new(module_id, ident_id)
+            }
+        };
+
+        // Only link to types which actually have docs somewhere.
+        let is_documented = symbol.is_builtin()
+            || self.all_exposed_symbols.contains(&symbol)
+            || self
+                .loaded_module
+                .package_names
+                .contains_key(&symbol.module_id());
+
+        if is_documented {
+            Some(symbol_url(self.loaded_module, symbol))
+        } else {
+            None
+        }
     }
 }
 
@@ -820,7 +1156,7 @@ fn markdown_to_html(
                                 let DocUrl { url, title } = doc_url(
                                     all_exposed_symbols,
                                     scope,
-                                    &loaded_module.interns,
+                                    loaded_module,
                                     module_name,
                                     symbol_name,
                                 );
@@ -838,13 +1174,8 @@ fn markdown_to_html(
                     Ok((_, Ident::Tag(type_name), _)) => {
                         // This looks like a tag name, but it could
                         // be a type alias that's in scope, e.g. [I64]
-                        let DocUrl { url, title } = doc_url(
-                            all_exposed_symbols,
-                            scope,
-                            &loaded_module.interns,
-                            "",
-                            type_name,
-                        );
+                        let DocUrl { url, title } =
+                            doc_url(all_exposed_symbols, scope, loaded_module, "", type_name);
 
                         Some((url.into(), title.into()))
                     }
@@ -967,3 +1298,22 @@ fn markdown_to_html(
 
     pulldown_cmark::html::push_html(buf, docs_parser.into_iter());
 }
+
+#[cfg(test)]
+mod test {
+    use super::item_url;
+
+    #[test]
+    fn links_into_the_docs_being_generated() {
+        assert_eq!(item_url(None, "Str", "join"), "/Str#join");
+    }
+
+    #[test]
+    fn links_to_other_packages_by_their_declared_name() {
+        assert_eq!(
+            item_url(Some("json"), "Json", "toUtf8"),
+            "/json/Json#toUtf8"
+        );
+        assert_eq!(item_url(Some("cli"), "Task", "Task"), "/cli/Task#Task");
+    }
+}
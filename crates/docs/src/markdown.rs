@@ -0,0 +1,131 @@
+//! Renders a module's documentation as a Markdown file, e.g. for a repository's wiki.
+use crate::{ability_member_type_to_plain_text, type_annotation_to_plain_text};
+use roc_collections::VecSet;
+use roc_load::docs::{DocDef, DocEntry, ModuleDocumentation, TypeAnnotation};
+use roc_module::symbol::Symbol;
+
+pub fn render_module(module: &ModuleDocumentation, all_exposed_symbols: &VecSet<Symbol>) -> String {
+    let mut buf = String::new();
+
+    buf.push_str("# ");
+    buf.push_str(module.name.as_str());
+    buf.push_str("\n\n");
+
+    for entry in &module.entries {
+        match entry {
+            DocEntry::DocDef(doc_def) => {
+                // Only render entries that are exposed
+                if all_exposed_symbols.contains(&doc_def.symbol) {
+                    render_doc_def(&mut buf, doc_def);
+                }
+            }
+            DocEntry::DetachedDoc(docs) => {
+                push_docs(&mut buf, docs);
+            }
+        }
+    }
+
+    buf
+}
+
+fn render_doc_def(buf: &mut String, doc_def: &DocDef) {
+    buf.push_str("## ");
+    buf.push_str(doc_def.name.as_str());
+    buf.push_str("\n\n```roc\n");
+    buf.push_str(doc_def.name.as_str());
+
+    for type_var in &doc_def.type_vars {
+        buf.push(' ');
+        buf.push_str(type_var.as_str());
+    }
+
+    match &doc_def.type_annotation {
+        TypeAnnotation::NoTypeAnn => {}
+        TypeAnnotation::Ability { members } => {
+            buf.push_str(" has");
+
+            for member in members {
+                buf.push_str("\n    ");
+                buf.push_str(member.name.as_str());
+                buf.push_str(" : ");
+                ability_member_type_to_plain_text(buf, member);
+            }
+        }
+        type_ann => {
+            buf.push_str(" : ");
+            type_annotation_to_plain_text(buf, type_ann, false);
+        }
+    }
+
+    buf.push_str("\n```\n\n");
+
+    if let Some(docs) = &doc_def.docs {
+        push_docs(buf, docs);
+    }
+
+    if let TypeAnnotation::Ability { members } = &doc_def.type_annotation {
+        for member in members {
+            if let Some(docs) = &member.docs {
+                buf.push_str("### ");
+                buf.push_str(member.name.as_str());
+                buf.push_str("\n\n");
+
+                push_docs(buf, docs);
+            }
+        }
+    }
+}
+
+fn push_docs(buf: &mut String, docs: &str) {
+    let docs = docs.trim();
+
+    if !docs.is_empty() {
+        buf.push_str(docs);
+        buf.push_str("\n\n");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::render_module;
+    use crate::test_fixtures::module_documentation;
+    use indoc::indoc;
+
+    #[test]
+    fn exposed_defs_and_module_docs() {
+        let (module, exposed) = module_documentation();
+
+        assert_eq!(
+            render_module(&module, &exposed),
+            indoc!(
+                r#"
+                # Str
+
+                Functions for working with strings.
+
+                ## isEmpty
+
+                ```roc
+                isEmpty : Str -> Bool
+                ```
+
+                Returns `Bool.true` if the string is empty.
+
+                More details.
+
+                ## Encoding
+
+                ```roc
+                Encoding has
+                    toEncoder : val -> Encoder | val has Encoding
+                ```
+
+                ### toEncoder
+
+                Creates an encoder for the value.
+
+                "#
+            )
+        );
+    }
+}
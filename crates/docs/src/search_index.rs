@@ -0,0 +1,91 @@
+//! Generates `search-index.json`, which lists every exposed name in the package so that
+//! search.js can search by name as well as by type signature (e.g. `Str -> Bool`).
+use crate::{item_url, type_annotation_to_plain_text};
+use roc_collections::VecSet;
+use roc_load::docs::{DocEntry, ModuleDocumentation, TypeAnnotation};
+use roc_module::symbol::Symbol;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+struct SearchEntry<'a> {
+    module: &'a str,
+    name: &'a str,
+    #[serde(rename = "type")]
+    type_signature: String,
+    doc: &'a str,
+    url: String,
+}
+
+pub fn search_index_json<'a, I: Iterator<Item = &'a ModuleDocumentation>>(
+    modules: I,
+    all_exposed_symbols: &VecSet<Symbol>,
+) -> String {
+    let mut entries = Vec::new();
+
+    for module in modules {
+        for entry in &module.entries {
+            if let DocEntry::DocDef(doc_def) = entry {
+                if !all_exposed_symbols.contains(&doc_def.symbol) {
+                    continue;
+                }
+
+                let mut type_signature = String::new();
+
+                if !matches!(doc_def.type_annotation, TypeAnnotation::NoTypeAnn) {
+                    type_annotation_to_plain_text(
+                        &mut type_signature,
+                        &doc_def.type_annotation,
+                        false,
+                    );
+                }
+
+                entries.push(SearchEntry {
+                    module: module.name.as_str(),
+                    name: doc_def.name.as_str(),
+                    type_signature,
+                    doc: first_doc_line(doc_def.docs.as_deref()),
+                    url: item_url(None, module.name.as_str(), doc_def.name.as_str()),
+                });
+            }
+        }
+    }
+
+    serde_json::to_string(&entries).expect("search index entries are always serializable")
+}
+
+/// The first line of prose in a doc comment, which search results show as a summary.
+fn first_doc_line(docs: Option<&str>) -> &str {
+    docs.unwrap_or_default()
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_fixtures::module_documentation;
+
+    #[test]
+    fn lists_exposed_defs_with_signature_and_summary() {
+        let (module, exposed) = module_documentation();
+
+        let json = search_index_json(std::iter::once(&module), &exposed);
+
+        assert_eq!(
+            json,
+            concat!(
+                r#"[{"module":"Str","name":"isEmpty","type":"Str -> Bool","doc":"Returns `Bool.true` if the string is empty.","url":"/Str#isEmpty"},"#,
+                r#"{"module":"Str","name":"Encoding","type":"","doc":"","url":"/Str#Encoding"}]"#
+            )
+        );
+    }
+
+    #[test]
+    fn summary_is_first_non_blank_line() {
+        assert_eq!(first_doc_line(Some("\n\n  first\nsecond")), "first");
+        assert_eq!(first_doc_line(Some("   ")), "");
+        assert_eq!(first_doc_line(None), "");
+    }
+}
@@ -1,8 +1,78 @@
 (() => {
   let sidebar = document.getElementById("sidebar-nav");
   let searchBox = document.getElementById("module-search");
+  let searchResults = document.getElementById("search-results");
+  let searchIndex = [];
+  const maxSearchResults = 50;
+
+  // search-index.json is generated next to this script. It lists every exposed name
+  // along with its type signature, so we can also search by type (e.g. "Str -> Bool").
+  fetch(new URL("search-index.json", document.currentScript.src))
+    .then((response) => response.json())
+    .then((index) => {
+      searchIndex = index;
+      search();
+    })
+    .catch(() => {
+      // Without the index, we can still search the sidebar.
+    });
+
+  // Ignore case and whitespace, so that e.g. "str->bool" matches "Str -> Bool"
+  function normalize(str) {
+    return str.toLowerCase().replace(/\s+/g, "");
+  }
+
+  function looksLikeType(text) {
+    return text.startsWith(":") || text.includes("->") || /^[A-Z]/.test(text);
+  }
+
+  function matchesEntry(entry, query, typeQuery) {
+    if (normalize(`${entry.module}.${entry.name}`).includes(query)) {
+      return true;
+    }
+
+    return typeQuery !== null && normalize(entry.type).includes(typeQuery);
+  }
+
+  function renderSearchResults(text) {
+    searchResults.replaceChildren();
+
+    if (text === "" || searchIndex.length === 0) {
+      searchResults.classList.add("hidden");
+      return;
+    }
+
+    let query = normalize(text);
+    let typeQuery = looksLikeType(text.trim()) ? normalize(text.trim().replace(/^:/, "")) : null;
+    let matches = searchIndex.filter((entry) => matchesEntry(entry, query, typeQuery));
+
+    matches.slice(0, maxSearchResults).forEach((entry) => {
+      let link = document.createElement("a");
+      link.href = entry.url;
+
+      let signature = document.createElement("code");
+      signature.textContent = entry.type === ""
+        ? `${entry.module}.${entry.name}`
+        : `${entry.module}.${entry.name} : ${entry.type}`;
+      link.appendChild(signature);
+
+      if (entry.doc !== "") {
+        let doc = document.createElement("p");
+        doc.textContent = entry.doc;
+        link.appendChild(doc);
+      }
+
+      let item = document.createElement("li");
+      item.appendChild(link);
+      searchResults.appendChild(item);
+    });
+
+    searchResults.classList.toggle("hidden", matches.length === 0);
+  }
 
   function search() {
+    renderSearchResults(searchBox.value);
+
     let text = searchBox.value.toLowerCase(); // Search is case-insensitive.
 
     if (text === "") {
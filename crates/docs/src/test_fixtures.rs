@@ -0,0 +1,76 @@
+//! Module documentation for the renderers' tests to share.
+use roc_can::scope::Scope;
+use roc_collections::VecSet;
+use roc_load::docs::{AbilityMember, DocDef, DocEntry, ModuleDocumentation, TypeAnnotation};
+use roc_module::symbol::{IdentIds, ModuleId, Symbol};
+
+fn apply(name: &str) -> TypeAnnotation {
+    TypeAnnotation::Apply {
+        name: name.to_string(),
+        parts: Vec::new(),
+    }
+}
+
+fn doc_def(
+    name: &str,
+    symbol: Symbol,
+    type_annotation: TypeAnnotation,
+    docs: Option<&str>,
+) -> DocEntry {
+    DocEntry::DocDef(DocDef {
+        name: name.to_string(),
+        symbol,
+        type_vars: Vec::new(),
+        type_annotation,
+        docs: docs.map(str::to_string),
+    })
+}
+
+/// A module with module docs, an exposed function, an exposed ability, and a def which
+/// isn't exposed, along with the symbols which are exposed.
+pub fn module_documentation() -> (ModuleDocumentation, VecSet<Symbol>) {
+    let is_empty = TypeAnnotation::Function {
+        args: vec![apply("Str")],
+        output: Box::new(apply("Bool")),
+    };
+
+    let encoding = TypeAnnotation::Ability {
+        members: vec![AbilityMember {
+            name: "toEncoder".to_string(),
+            type_annotation: TypeAnnotation::Function {
+                args: vec![TypeAnnotation::BoundVariable("val".to_string())],
+                output: Box::new(apply("Encoder")),
+            },
+            able_variables: vec![("val".to_string(), vec![apply("Encoding")])],
+            docs: Some("Creates an encoder for the value.".to_string()),
+        }],
+    };
+
+    let module = ModuleDocumentation {
+        name: "Str".to_string(),
+        entries: vec![
+            DocEntry::DetachedDoc("Functions for working with strings.\n".to_string()),
+            doc_def(
+                "isEmpty",
+                Symbol::STR_IS_EMPTY,
+                is_empty,
+                Some("\n  Returns `Bool.true` if the string is empty.\n\nMore details."),
+            ),
+            doc_def("Encoding", Symbol::ENCODE_ENCODING, encoding, None),
+            doc_def(
+                "concat",
+                Symbol::STR_CONCAT,
+                TypeAnnotation::NoTypeAnn,
+                None,
+            ),
+        ],
+        scope: Scope::new(ModuleId::STR, IdentIds::default(), Default::default()),
+        exposed_symbols: VecSet::default(),
+    };
+
+    let mut exposed = VecSet::default();
+    exposed.insert(Symbol::STR_IS_EMPTY);
+    exposed.insert(Symbol::ENCODE_ENCODING);
+
+    (module, exposed)
+}
@@ -1,10 +1,11 @@
 //! Provides a binary that is only used for static build servers.
 use clap::{Arg, Command};
-use roc_docs::generate_docs_html;
+use roc_docs::{generate_docs, DocsFormat};
 use std::io;
 use std::path::PathBuf;
 
 pub const ROC_FILE: &str = "ROC_FILE";
+pub const FLAG_FORMAT: &str = "format";
 const DEFAULT_ROC_FILENAME: &str = "main.roc";
 
 fn main() -> io::Result<()> {
@@ -18,10 +19,24 @@ fn main() -> io::Result<()> {
                 .required(false)
                 .default_value(DEFAULT_ROC_FILENAME),
         )
+        .arg(
+            Arg::new(FLAG_FORMAT)
+                .long(FLAG_FORMAT)
+                .help("Generate the docs as a website, a JSON file, or Markdown files")
+                .takes_value(true)
+                .possible_values(DocsFormat::NAMES)
+                .default_value("html")
+                .required(false),
+        )
         .get_matches();
 
+    let format: DocsFormat = matches.value_of_t_or_exit(FLAG_FORMAT);
+
     // Populate roc_files
-    generate_docs_html(PathBuf::from(matches.value_of_os(ROC_FILE).unwrap()));
+    generate_docs(
+        PathBuf::from(matches.value_of_os(ROC_FILE).unwrap()),
+        format,
+    );
 
     Ok(())
 }
@@ -0,0 +1,267 @@
+//! Generates a C header for a Roc library (from `roc build --lib`), which declares the
+//! entry points the library exposes, along with the types they use.
+//!
+//! Records become C structs. Other types whose memory layout is Roc's business (like tag unions
+//! with payloads) are declared as opaque structs of the right size and alignment.
+use crate::types::{RocNum, RocTagUnion, RocType, TypeId, Types};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+pub static HEADER: &[u8] = include_bytes!("../templates/header.h");
+const INDENT: &str = "    ";
+
+const FOOTER: &str = r#"
+#ifdef __cplusplus
+}
+#endif
+"#;
+
+/// Identifiers which are fine in Roc, but are keywords in C or C++
+const C_KEYWORDS: &[&str] = &[
+    "auto", "bool", "break", "case", "char", "class", "const", "continue", "default", "delete",
+    "do", "double", "else", "enum", "extern", "float", "for", "goto", "if", "inline", "int",
+    "long", "new", "private", "public", "register", "restrict", "return", "short", "signed",
+    "sizeof", "static", "struct", "switch", "template", "this", "typedef", "union", "unsigned",
+    "void", "volatile", "while",
+];
+
+/// `entry_points` are the names of the values the platform exposes to the host, with their types.
+pub fn emit(types: &Types, entry_points: &[(String, TypeId)]) -> String {
+    let mut buf = std::str::from_utf8(HEADER).unwrap().to_string();
+    let names = anonymous_type_names(types);
+
+    // Types are always added after the types they contain, so in this order, C sees the
+    // declaration of each struct's fields before the struct itself.
+    for id in types.ids() {
+        add_type(&mut buf, id, types, &names);
+    }
+
+    for (name, type_id) in entry_points {
+        add_entry_point(&mut buf, name, *type_id, types, &names);
+    }
+
+    buf.push_str(FOOTER);
+
+    buf
+}
+
+/// Types like `Result` don't have a name, but C needs one to declare them.
+fn anonymous_type_names(types: &Types) -> HashMap<TypeId, String> {
+    let mut names = HashMap::default();
+
+    for id in types.ids() {
+        let kind = match types.get_type(id) {
+            RocType::RocResult(_, _) => "RocResult",
+            RocType::RocDict(_, _) => "RocDict",
+            RocType::RocSet(_) => "RocSet",
+            RocType::Function { .. } => "RocFunction",
+            _ => continue,
+        };
+
+        names.insert(id, format!("{}{}", kind, names.len() + 1));
+    }
+
+    names
+}
+
+fn add_type(buf: &mut String, id: TypeId, types: &Types, names: &HashMap<TypeId, String>) {
+    match types.get_type(id) {
+        RocType::Struct { name, fields } => {
+            let fields = fields
+                .iter()
+                .map(|(label, field_id)| (escape_keyword(label), *field_id));
+
+            add_struct(buf, name, fields, types, names);
+        }
+        RocType::TagUnionPayload { name, fields } => {
+            let fields = fields
+                .iter()
+                .map(|(index, field_id)| (format!("f{}", index), *field_id));
+
+            add_struct(buf, name, fields, types, names);
+        }
+        RocType::TagUnion(RocTagUnion::Enumeration { name, tags, size }) => {
+            // C enums are int-sized, so use an integer of the right size instead, and give the
+            // tags names as constants
+            writeln!(buf, "\ntypedef uint{}_t {};\n\nenum {{", size * 8, name).unwrap();
+
+            for (index, tag) in tags.iter().enumerate() {
+                writeln!(buf, "{}{}_{} = {},", INDENT, name, tag, index).unwrap();
+            }
+
+            buf.push_str("};\n");
+        }
+        RocType::TagUnion(
+            RocTagUnion::NonRecursive { name, .. }
+            | RocTagUnion::Recursive { name, .. }
+            | RocTagUnion::NonNullableUnwrapped { name, .. }
+            | RocTagUnion::SingleTagStruct { name, .. }
+            | RocTagUnion::NullableWrapped { name, .. }
+            | RocTagUnion::NullableUnwrapped { name, .. },
+        ) => {
+            add_opaque(buf, name, id, types);
+        }
+        RocType::RocResult(_, _)
+        | RocType::RocDict(_, _)
+        | RocType::RocSet(_)
+        | RocType::Function { .. } => {
+            add_opaque(buf, &names[&id], id, types);
+        }
+        RocType::RocStr
+        | RocType::Bool
+        | RocType::Num(_)
+        | RocType::RocList(_)
+        | RocType::RocBox(_)
+        | RocType::RecursivePointer(_)
+        | RocType::EmptyTagUnion
+        | RocType::Unit => {
+            // These are either declared in the header, or don't need declaring
+        }
+    }
+}
+
+fn add_struct<I: Iterator<Item = (String, TypeId)>>(
+    buf: &mut String,
+    name: &str,
+    fields: I,
+    types: &Types,
+    names: &HashMap<TypeId, String>,
+) {
+    writeln!(buf, "\nstruct {} {{", name).unwrap();
+
+    for (label, field_id) in fields {
+        // C doesn't have zero-sized types
+        if !is_zero_sized(field_id, types) {
+            writeln!(
+                buf,
+                "{}{} {};",
+                INDENT,
+                c_type_name(field_id, types, names),
+                label
+            )
+            .unwrap();
+        }
+    }
+
+    buf.push_str("};\n");
+}
+
+fn add_opaque(buf: &mut String, name: &str, id: TypeId, types: &Types) {
+    if is_zero_sized(id, types) {
+        return;
+    }
+
+    writeln!(
+        buf,
+        "\nstruct {} {{\n{}ROC_ALIGNAS({}) unsigned char bytes[{}];\n}};",
+        name,
+        INDENT,
+        types.align(id),
+        types.size_rounded_to_alignment(id)
+    )
+    .unwrap();
+}
+
+fn add_entry_point(
+    buf: &mut String,
+    name: &str,
+    id: TypeId,
+    types: &Types,
+    names: &HashMap<TypeId, String>,
+) {
+    let (args, ret) = match types.get_type(id) {
+        RocType::Function { args, ret, .. } => (args.as_slice(), *ret),
+        _ => (&[][..], id),
+    };
+
+    let ret_type = if is_zero_sized(ret, types) {
+        "void".to_string()
+    } else {
+        c_type_name(ret, types, names)
+    };
+
+    // The 1 is the entry point's layout id. It's always 1, since it has only one specialization.
+    // This is the `_generic` variant, which always writes the result to the given pointer.
+    write!(
+        buf,
+        "\nvoid roc__{}_1_exposed_generic({} *ret",
+        name, ret_type
+    )
+    .unwrap();
+
+    for (index, arg_id) in args.iter().enumerate() {
+        if is_zero_sized(*arg_id, types) {
+            continue;
+        }
+
+        let arg_type = c_type_name(*arg_id, types, names);
+
+        // Strings and lists are passed by pointer
+        match types.get_type(*arg_id) {
+            RocType::RocStr | RocType::RocList(_) => {
+                write!(buf, ", {} *arg{}", arg_type, index).unwrap()
+            }
+            _ => write!(buf, ", {} arg{}", arg_type, index).unwrap(),
+        }
+    }
+
+    buf.push_str(");\n");
+
+    writeln!(buf, "int64_t roc__{}_size(void);", name).unwrap();
+}
+
+fn c_type_name(id: TypeId, types: &Types, names: &HashMap<TypeId, String>) -> String {
+    match types.get_type(id) {
+        RocType::RocStr => "struct RocStr".to_string(),
+        RocType::Bool => "bool".to_string(),
+        RocType::Num(num) => num_type_name(*num).to_string(),
+        RocType::RocList(_) => "struct RocList".to_string(),
+        RocType::RocBox(_) | RocType::RecursivePointer(_) => "void *".to_string(),
+        RocType::TagUnion(RocTagUnion::Enumeration { name, .. }) => name.clone(),
+        RocType::Struct { name, .. }
+        | RocType::TagUnionPayload { name, .. }
+        | RocType::TagUnion(
+            RocTagUnion::NonRecursive { name, .. }
+            | RocTagUnion::Recursive { name, .. }
+            | RocTagUnion::NonNullableUnwrapped { name, .. }
+            | RocTagUnion::SingleTagStruct { name, .. }
+            | RocTagUnion::NullableWrapped { name, .. }
+            | RocTagUnion::NullableUnwrapped { name, .. },
+        ) => format!("struct {}", name),
+        RocType::RocResult(_, _)
+        | RocType::RocDict(_, _)
+        | RocType::RocSet(_)
+        | RocType::Function { .. } => format!("struct {}", names[&id]),
+        RocType::EmptyTagUnion | RocType::Unit => "void".to_string(),
+    }
+}
+
+fn num_type_name(num: RocNum) -> &'static str {
+    match num {
+        RocNum::I8 => "int8_t",
+        RocNum::U8 => "uint8_t",
+        RocNum::I16 => "int16_t",
+        RocNum::U16 => "uint16_t",
+        RocNum::I32 => "int32_t",
+        RocNum::U32 => "uint32_t",
+        RocNum::I64 => "int64_t",
+        RocNum::U64 => "uint64_t",
+        RocNum::I128 => "struct RocI128",
+        RocNum::U128 => "struct RocU128",
+        RocNum::F32 => "float",
+        RocNum::F64 => "double",
+        RocNum::Dec => "struct RocDec",
+    }
+}
+
+fn is_zero_sized(id: TypeId, types: &Types) -> bool {
+    types.size_ignoring_alignment(id) == 0
+}
+
+fn escape_keyword(label: &str) -> String {
+    if C_KEYWORDS.contains(&label) {
+        format!("{}_", label)
+    } else {
+        label.to_string()
+    }
+}
@@ -1,11 +1,12 @@
 use crate::rust_glue;
-use crate::types::{Env, Types};
+use crate::types::{Env, TypeId, Types};
 use bumpalo::Bump;
-use roc_load::{ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, Threading};
+use roc_load::{ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, OptLevel, Threading};
 use roc_mono::layout::GlobalLayoutInterner;
 use roc_packaging::cache::{self, RocCacheDir};
 use roc_reporting::report::{RenderTarget, DEFAULT_PALETTE};
 use roc_target::{Architecture, OperatingSystem, TargetInfo};
+use roc_types::subs::Variable;
 use std::fs::File;
 use std::io::{self, ErrorKind, Write};
 use std::path::{Path, PathBuf};
@@ -92,17 +93,12 @@ pub fn load_types(
         mut solved,
         interns,
         ..
-    } = roc_load::load_and_typecheck(
+    } = load_typechecked(
         arena,
         full_file_path,
+        threading,
+        target_info,
         RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
-        LoadConfig {
-            target_info,
-            render: RenderTarget::Generic,
-            palette: DEFAULT_PALETTE,
-            threading,
-            exec_mode: ExecutionMode::Check,
-        },
     )
     .unwrap_or_else(|problem| match problem {
         LoadingProblem::FormattedReport(report) => {
@@ -171,3 +167,87 @@ pub fn load_types(
 
     Ok(types_and_targets)
 }
+
+/// Loads a platform, and returns the types of the values it exposes to the host (e.g.
+/// `mainForHost`) on the given target, sorted by name. If that doesn't work, the error says why.
+pub fn load_entry_points(
+    platform_main_roc: PathBuf,
+    threading: Threading,
+    target_info: TargetInfo,
+    roc_cache_dir: RocCacheDir<'_>,
+) -> Result<(Types, Vec<(String, TypeId)>), String> {
+    let arena = &Bump::new();
+    let LoadedModule {
+        type_problems,
+        mut solved,
+        interns,
+        exposed_to_host,
+        ..
+    } = load_typechecked(
+        arena,
+        platform_main_roc,
+        threading,
+        target_info,
+        roc_cache_dir,
+    )
+    .map_err(|problem| match problem {
+        LoadingProblem::FormattedReport(report) => report,
+        problem => format!("{:?}", problem),
+    })?;
+
+    // The entry points' types can't be written in C if they have errors in them, and the errors
+    // themselves are reported along with the rest of the platform's problems.
+    if type_problems.values().any(|problems| !problems.is_empty()) {
+        return Err(
+            "The platform has type errors, so I can't write its entry points' types in C."
+                .to_string(),
+        );
+    }
+
+    let mut entry_points: Vec<(String, Variable)> = exposed_to_host
+        .into_iter()
+        .map(|(symbol, var)| (symbol.as_str(&interns).to_string(), var))
+        .collect();
+
+    entry_points.sort();
+
+    let layout_interner = GlobalLayoutInterner::with_capacity(128, target_info);
+    let mut env = Env::new(
+        arena,
+        solved.inner_mut(),
+        &interns,
+        layout_interner.fork(),
+        target_info,
+    );
+    let (types, type_ids) = env.vars_to_type_ids(entry_points.iter().map(|(_, var)| *var));
+
+    let entry_points = entry_points
+        .into_iter()
+        .zip(type_ids)
+        .map(|((name, _), type_id)| (name, type_id))
+        .collect();
+
+    Ok((types, entry_points))
+}
+
+fn load_typechecked<'a>(
+    arena: &'a Bump,
+    full_file_path: PathBuf,
+    threading: Threading,
+    target_info: TargetInfo,
+    roc_cache_dir: RocCacheDir<'_>,
+) -> Result<LoadedModule, LoadingProblem<'a>> {
+    roc_load::load_and_typecheck(
+        arena,
+        full_file_path,
+        roc_cache_dir,
+        LoadConfig {
+            target_info,
+            render: RenderTarget::Generic,
+            palette: DEFAULT_PALETTE,
+            threading,
+            exec_mode: ExecutionMode::Check,
+            opt_level: OptLevel::Normal,
+        },
+    )
+}
@@ -688,18 +688,27 @@ impl<'a> Env<'a> {
     }
 
     pub fn vars_to_types<I>(&mut self, variables: I) -> Types
+    where
+        I: Iterator<Item = Variable>,
+    {
+        self.vars_to_type_ids(variables).0
+    }
+
+    /// Like `vars_to_types`, but also returns the TypeId of each variable, in the same order.
+    pub fn vars_to_type_ids<I>(&mut self, variables: I) -> (Types, Vec<TypeId>)
     where
         I: Iterator<Item = Variable>,
     {
         let mut types = Types::with_capacity(variables.size_hint().0);
+        let mut type_ids = Vec::with_capacity(variables.size_hint().0);
 
         for var in variables {
-            self.add_type(var, &mut types);
+            type_ids.push(self.add_type(var, &mut types));
         }
 
         self.resolve_pending_recursive_types(&mut types);
 
-        types
+        (types, type_ids)
     }
 
     fn add_type(&mut self, var: Variable, types: &mut Types) -> TypeId {
@@ -0,0 +1,50 @@
+// ⚠️ GENERATED CODE ⚠️ - this entire file was generated by `roc build --lib`
+
+#pragma once
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+#define ROC_ALIGNAS(n) alignas(n)
+extern "C" {
+#else
+#define ROC_ALIGNAS(n) _Alignas(n)
+#endif
+
+// Small strings are stored inline, in which case the last byte of the struct
+// has its high bit set, and the length is in the rest of that byte.
+struct RocStr {
+    char *bytes;
+    size_t len;
+    size_t capacity;
+};
+
+struct RocList {
+    void *elements;
+    size_t len;
+    size_t capacity;
+};
+
+// 128-bit integers are always 16-byte aligned, and stored little-endian
+struct RocI128 {
+    ROC_ALIGNAS(16) uint64_t halves[2];
+};
+
+struct RocU128 {
+    ROC_ALIGNAS(16) uint64_t halves[2];
+};
+
+// A fixed-point decimal, stored as an I128 which is the number times 10^18
+struct RocDec {
+    ROC_ALIGNAS(16) uint64_t halves[2];
+};
+
+// The Roc code calls these, so the program it's linked into has to define them.
+void *roc_alloc(size_t size, unsigned int alignment);
+void *roc_realloc(void *ptr, size_t new_size, size_t old_size, unsigned int alignment);
+void roc_dealloc(void *ptr, unsigned int alignment);
+void roc_panic(void *ptr, unsigned int alignment);
+void *roc_memcpy(void *dest, const void *src, size_t n);
+void *roc_memset(void *str, int c, size_t n);
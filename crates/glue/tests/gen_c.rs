@@ -0,0 +1,145 @@
+#[macro_use]
+extern crate pretty_assertions;
+
+#[macro_use]
+extern crate indoc;
+
+mod helpers;
+
+#[cfg(test)]
+mod test_gen_c {
+    use crate::helpers::generate_c_header;
+
+    #[test]
+    fn record() {
+        let module = indoc!(
+            r#"
+            MyRcd : { a : U64, b : I128, int : Str }
+
+            main : MyRcd
+            main = { a: 1u64, b: 2i128, int: "" }
+        "#
+        );
+
+        assert_eq!(
+            generate_c_header(module)
+                .strip_prefix('\n')
+                .unwrap_or_default(),
+            indoc!(
+                r#"
+                struct MyRcd {
+                    struct RocI128 b;
+                    uint64_t a;
+                    struct RocStr int_;
+                };
+
+                void roc__main_1_exposed_generic(struct MyRcd *ret);
+                int64_t roc__main_size(void);
+
+                #ifdef __cplusplus
+                }
+                #endif
+            "#
+            )
+        );
+    }
+
+    #[test]
+    fn function() {
+        let module = indoc!(
+            r#"
+            main : Str, U8 -> List U8
+            main = \str, n -> List.repeat n (Str.countUtf8Bytes str)
+        "#
+        );
+
+        assert_eq!(
+            generate_c_header(module)
+                .strip_prefix('\n')
+                .unwrap_or_default(),
+            indoc!(
+                r#"
+                void roc__main_1_exposed_generic(struct RocList *ret, struct RocStr *arg0, uint8_t arg1);
+                int64_t roc__main_size(void);
+
+                #ifdef __cplusplus
+                }
+                #endif
+            "#
+            )
+        );
+    }
+
+    #[test]
+    fn enumeration() {
+        let module = indoc!(
+            r#"
+            Color : [Red, Green, Blue]
+
+            main : Color
+            main = Green
+        "#
+        );
+
+        assert_eq!(
+            generate_c_header(module)
+                .strip_prefix('\n')
+                .unwrap_or_default(),
+            indoc!(
+                r#"
+                typedef uint8_t Color;
+
+                enum {
+                    Color_Blue = 0,
+                    Color_Green = 1,
+                    Color_Red = 2,
+                };
+
+                void roc__main_1_exposed_generic(Color *ret);
+                int64_t roc__main_size(void);
+
+                #ifdef __cplusplus
+                }
+                #endif
+            "#
+            )
+        );
+    }
+
+    #[test]
+    fn tag_union_with_payload() {
+        let module = indoc!(
+            r#"
+            Shape : [Circle F64, Rect F64 F64]
+
+            main : Shape
+            main = Circle 1.0
+        "#
+        );
+
+        assert_eq!(
+            generate_c_header(module)
+                .strip_prefix('\n')
+                .unwrap_or_default(),
+            indoc!(
+                r#"
+                struct Shape_Rect {
+                    double f0;
+                    double f1;
+                };
+
+                struct Shape {
+                    ROC_ALIGNAS(8) unsigned char bytes[24];
+                };
+
+                void roc__main_1_exposed_generic(struct Shape *ret);
+                int64_t roc__main_size(void);
+
+                #ifdef __cplusplus
+                }
+                #endif
+            "#
+            )
+        );
+    }
+}
@@ -1,6 +1,8 @@
-use roc_glue::load::{load_types, IgnoreErrors};
-use roc_glue::rust_glue;
+use roc_glue::load::{load_entry_points, load_types, IgnoreErrors};
+use roc_glue::{c_glue, rust_glue};
 use roc_load::Threading;
+use roc_packaging::cache::RocCacheDir;
+use roc_target::TargetInfo;
 use std::env;
 use std::fs::File;
 use std::io::Write;
@@ -48,6 +50,48 @@ pub fn generate_bindings(decl_src: &str) -> String {
     rust_glue::emit(&pairs)
 }
 
+/// The C header for a platform which provides `main`, minus the declarations from
+/// templates/header.h which every header starts with
+#[allow(dead_code)]
+pub fn generate_c_header(decl_src: &str) -> String {
+    use tempfile::tempdir;
+
+    let src = format!(
+        indoc!(
+            r#"
+                platform "main"
+                    requires {{}} {{ nothing : {{}} }}
+                    exposes []
+                    packages {{}}
+                    imports []
+                    provides [main]
+
+                {}
+            "#
+        ),
+        decl_src
+    );
+
+    let dir = tempdir().expect("Unable to create tempdir");
+    let file_path = dir.path().join("platform.roc");
+    std::fs::write(&file_path, src).unwrap();
+
+    let (types, entry_points) = load_entry_points(
+        file_path,
+        Threading::Single,
+        TargetInfo::default_x86_64(),
+        RocCacheDir::Disallowed,
+    )
+    .expect("had problems loading");
+
+    dir.close().expect("Unable to close tempdir");
+
+    let header = c_glue::emit(&types, &entry_points);
+    let prelude = std::str::from_utf8(c_glue::HEADER).unwrap();
+
+    header.strip_prefix(prelude).unwrap().to_string()
+}
+
 #[allow(dead_code)]
 pub fn fixtures_dir(dir_name: &str) -> PathBuf {
     let mut path = root_dir();
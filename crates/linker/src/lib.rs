@@ -19,6 +19,7 @@ use target_lexicon::Triple;
 mod elf;
 mod macho;
 mod pe;
+mod wasm;
 
 mod generate_dylib;
 mod metadata;
@@ -47,6 +48,12 @@ pub fn supported(link_type: LinkType, target: &Triple) -> bool {
                 ..
             } => true,
 
+            Triple {
+                architecture: target_lexicon::Architecture::Wasm32,
+                binary_format: target_lexicon::BinaryFormat::Wasm,
+                ..
+            } => true,
+
             _ => false,
         }
     } else {
@@ -54,6 +61,12 @@ pub fn supported(link_type: LinkType, target: &Triple) -> bool {
     }
 }
 
+/// Whether to link surgically when no `--linker` is given. Surgical linking for wasm32 is new,
+/// so it's opt-in there for now.
+pub fn supported_by_default(link_type: LinkType, target: &Triple) -> bool {
+    supported(link_type, target) && target.architecture != target_lexicon::Architecture::Wasm32
+}
+
 pub fn build_and_preprocess_host(
     opt_level: OptLevel,
     target: &Triple,
@@ -62,6 +75,14 @@ pub fn build_and_preprocess_host(
     exposed_to_host: Vec<String>,
     exported_closure_types: Vec<String>,
 ) {
+    if let target_lexicon::BinaryFormat::Wasm = target.binary_format {
+        // Wasm hosts are relocatable modules, so there's no stub library to link them against.
+        // They're built the same way as for the Wasm dev backend, but at the requested opt level.
+        let host = rebuild_host(opt_level, target, platform_main_roc, None);
+        crate::wasm::preprocess_wasm(&host, preprocessed_host_path);
+        return;
+    }
+
     let stub_lib = if let target_lexicon::OperatingSystem::Windows = target.operating_system {
         platform_main_roc.with_file_name("libapp.dll")
     } else {
@@ -131,6 +152,7 @@ pub fn generate_stub_lib(
             palette: DEFAULT_PALETTE,
             threading: Threading::AllAvailable,
             exec_mode: ExecutionMode::Executable,
+            opt_level: OptLevel::Normal,
         },
     )
     .unwrap_or_else(|problem| todo!("{:?}", problem));
@@ -390,7 +412,7 @@ fn preprocess(
         }
 
         target_lexicon::BinaryFormat::Wasm => {
-            todo!("Roc does not yet support web assembly hosts!");
+            crate::wasm::preprocess_wasm(host_exe_path, preprocessed_path);
         }
         target_lexicon::BinaryFormat::Unknown => {
             internal_error!("Roc does not support unknown host binary formats!");
@@ -435,7 +457,7 @@ fn surgery(
         }
 
         target_lexicon::BinaryFormat::Wasm => {
-            todo!("Roc does not yet support web assembly hosts!");
+            crate::wasm::surgery_wasm(roc_app_bytes, executable_path);
         }
         target_lexicon::BinaryFormat::Unknown => {
             internal_error!("Roc does not support unknown host binary formats!");
@@ -0,0 +1,894 @@
+//! Surgical linking for WebAssembly hosts.
+//!
+//! The preprocessed host (`wasm32.rh1`) is a relocatable Wasm module, the same one the Wasm dev
+//! backend builds on, and the app is a relocatable object file from LLVM. Surgery copies the app's
+//! functions and data into the host, resolves the calls between them, and then does the steps a
+//! linker like wasm-ld would do to make the final binary: define the stack pointer, size the memory,
+//! and add a `_start` that calls the host's `main`.
+use bitvec::vec::BitVec;
+use bumpalo::{collections::Vec, Bump};
+use roc_error_macros::{internal_error, user_error};
+use roc_wasm_module::linking::{
+    DataSymbol, IndexRelocType, WasmObjectSymbol, WASM_SYM_BINDING_LOCAL, WASM_SYM_UNDEFINED,
+};
+use roc_wasm_module::opcodes::OpCode;
+use roc_wasm_module::sections::{
+    DataMode, DataSegment, Import, ImportDesc, Limits, MemorySection, NameSection,
+};
+use roc_wasm_module::serialize::{overwrite_padded_i32, overwrite_padded_u32};
+use roc_wasm_module::{
+    round_up_to_alignment, ConstExpr, Export, ExportType, Global, GlobalType, OffsetRelocType,
+    RelocationEntry, SerialBuffer, Serialize, Signature, SymInfo, ValueType, WasmModule,
+    STACK_POINTER_GLOBAL_ID,
+};
+use std::path::Path;
+
+/// The same as the Wasm dev backend's default
+const STACK_BYTES: u32 = 1024 * 1024;
+
+const MEMORY_NAME: &str = "memory";
+const START: &str = "_start";
+
+pub(crate) fn preprocess_wasm(host_input_path: &Path, preprocessed_path: &Path) {
+    roc_build::link::preprocess_host_wasm32(host_input_path, preprocessed_path);
+
+    // Check that we'll be able to link against it, so that platform authors find out now,
+    // rather than whenever an app is built on top of it.
+    let host_bytes = std::fs::read(preprocessed_path).unwrap_or_else(|e| {
+        internal_error!("Failed to read {:?}: {}", preprocessed_path, e);
+    });
+    let arena = Bump::new();
+    load_host(&arena, &host_bytes, preprocessed_path);
+}
+
+pub(crate) fn surgery_wasm(roc_app_bytes: &[u8], executable_path: &Path) {
+    // The executable starts out as a copy of the preprocessed host
+    let host_bytes = std::fs::read(executable_path).unwrap_or_else(|e| {
+        internal_error!("Failed to read {:?}: {}", executable_path, e);
+    });
+
+    let arena = Bump::new();
+    let mut module = load_host(&arena, &host_bytes, executable_path);
+    let app = WasmModule::preload(&arena, roc_app_bytes, true).unwrap_or_else(|e| {
+        internal_error!(
+            "I couldn't parse the app's Wasm object file at byte offset {:#x}: {}",
+            e.offset,
+            e.message
+        );
+    });
+    let app = arena.alloc(app);
+
+    let called_fns = link_app(&arena, &mut module, app);
+    module.eliminate_dead_code(&arena, called_fns);
+
+    let mut buffer = std::vec::Vec::with_capacity(module.size());
+    module.serialize(&mut buffer);
+
+    std::fs::write(executable_path, buffer).unwrap_or_else(|e| {
+        internal_error!("Failed to write {:?}: {}", executable_path, e);
+    });
+}
+
+fn load_host<'a>(arena: &'a Bump, host_bytes: &[u8], path: &Path) -> WasmModule<'a> {
+    WasmModule::preload(arena, host_bytes, true).unwrap_or_else(|e| {
+        user_error!(
+            "I couldn't link against the host {:?}. There's something wrong at byte offset {:#x}.\n{}",
+            path,
+            e.offset,
+            e.message
+        );
+    })
+}
+
+/// Add the app's functions and data to the host, and make it into a complete Wasm binary.
+/// Returns the functions that are called from outside the host's own code, for dead code elimination.
+fn link_app<'a>(
+    arena: &'a Bump,
+    module: &mut WasmModule<'a>,
+    app: &'a WasmModule<'a>,
+) -> BitVec<usize> {
+    // The final binary defines its own Memory and Table, so it doesn't import them
+    module.import.imports.retain(|import| {
+        !matches!(
+            import.description,
+            ImportDesc::Mem { .. } | ImportDesc::Table { .. }
+        )
+    });
+
+    if module.names.function_names.is_empty() {
+        module.names =
+            NameSection::from_imports_and_linking_data(arena, &module.import, &module.linking);
+    }
+
+    // App functions go after the host's, so that none of the host's function indices change
+    let fn_index_offset = next_fn_index(module);
+    let app_import_fn_count = app.import.function_count() as u32;
+    let app_fn_index = |index: u32| fn_index_offset + index - app_import_fn_count;
+
+    // Point the host's calls to `roc__*` functions at the app, and export any the host doesn't call
+    let host_to_app_map = Vec::from_iter_in(
+        app.linking.symbol_table.iter().filter_map(|sym| match sym {
+            SymInfo::Function(WasmObjectSymbol::ExplicitlyNamed { flags, index, name })
+                if flags & (WASM_SYM_UNDEFINED | WASM_SYM_BINDING_LOCAL) == 0
+                    && name.starts_with("roc__") =>
+            {
+                Some((*name, app_fn_index(*index)))
+            }
+            _ => None,
+        }),
+        arena,
+    );
+    module.link_host_to_app_calls(arena, host_to_app_map);
+
+    let mut called_fns = BitVec::repeat(false, fn_index_offset as usize);
+    called_fns.extend(std::iter::repeat(true).take(app.code.function_count as usize));
+
+    let type_indices = Vec::from_iter_in(
+        (0..app.types.len() as u32).map(|app_type_index| {
+            let (params, ret_type) = app.types.look_up(app_type_index);
+            module.types.insert(Signature {
+                param_types: Vec::from_iter_in(params, arena),
+                ret_type,
+            })
+        }),
+        arena,
+    );
+
+    // Lay out the app's data after the host's, keeping the offsets between its segments
+    let app_segments = app.data.segments().unwrap_or_else(|e| {
+        internal_error!("I couldn't parse the app's Data section: {:?}", e);
+    });
+    let app_data_alignment = app
+        .linking
+        .segment_info
+        .iter()
+        .map(|segment| 1u32 << segment.align_bytes_pow2)
+        .max()
+        .unwrap_or(1);
+    let app_data_base = round_up_to_alignment!(module.data.end_addr, app_data_alignment);
+    let segment_addrs = Vec::from_iter_in(
+        app_segments.iter().map(|(mode, _)| match mode {
+            DataMode::Active {
+                offset: ConstExpr::I32(offset),
+            } => app_data_base + *offset as u32,
+            _ => internal_error!("The app has a data segment that isn't at a constant address"),
+        }),
+        arena,
+    );
+
+    let host_lookup = module.get_host_function_lookup(arena);
+    let symbol_values = Vec::from_iter_in(
+        app.linking.symbol_table.iter().map(|sym| match sym {
+            SymInfo::Function(
+                WasmObjectSymbol::ExplicitlyNamed { flags, index, .. }
+                | WasmObjectSymbol::ImplicitlyNamed { flags, index },
+            ) => {
+                if flags & WASM_SYM_UNDEFINED == 0 {
+                    app_fn_index(*index)
+                } else {
+                    let name = sym.name().unwrap_or_else(|| imported_fn_name(app, *index));
+                    match host_lookup.iter().find(|(host_name, _)| *host_name == name) {
+                        Some((_, host_fn_index)) => *host_fn_index,
+                        None => user_error!(
+                            "The app calls `{}`, but the host doesn't define or import it.",
+                            name
+                        ),
+                    }
+                }
+            }
+            SymInfo::Data(DataSymbol::Defined {
+                segment_index,
+                segment_offset,
+                ..
+            }) => segment_addrs[*segment_index as usize] + segment_offset,
+            SymInfo::Data(DataSymbol::Imported { name, .. }) => {
+                internal_error!("The app imports the data symbol `{}`", name)
+            }
+            SymInfo::Global(WasmObjectSymbol::ExplicitlyNamed { index, .. })
+            | SymInfo::Global(WasmObjectSymbol::ImplicitlyNamed { index, .. }) => {
+                let is_stack_pointer = app
+                    .import
+                    .imports
+                    .iter()
+                    .filter(|import| matches!(import.description, ImportDesc::Global { .. }))
+                    .nth(*index as usize)
+                    .map(|import| import.name)
+                    == Some("__stack_pointer");
+                if !is_stack_pointer {
+                    internal_error!("The app uses a global other than __stack_pointer");
+                }
+                STACK_POINTER_GLOBAL_ID
+            }
+            // The function table is the only table
+            SymInfo::Table(_) => 0,
+            SymInfo::Section(_) | SymInfo::Event(_) => u32::MAX,
+        }),
+        arena,
+    );
+
+    //
+    // Code section
+    //
+    let mut app_code = app.code.bytes.clone();
+    // An app with no functions has nothing in its Code section except the function count
+    let (app_code_start, code_offset) = match app.code.function_offsets.first() {
+        Some(offset) => (*offset as usize, module.code.bytes.len() - *offset as usize),
+        None => (app_code.len(), 0),
+    };
+
+    for entry in app.reloc_code.entries.iter() {
+        let value = relocation_value(
+            module,
+            &mut called_fns,
+            &symbol_values,
+            &type_indices,
+            entry,
+        );
+        apply_relocation(&mut app_code, entry, value);
+
+        // Keep relocations for calls to JS imports, whose indices may change during DCE,
+        // and for indirect calls, so that DCE knows which functions they might call.
+        match *entry {
+            RelocationEntry::Index {
+                type_id: IndexRelocType::FunctionIndexLeb,
+                offset,
+                symbol_index,
+            } => {
+                let fn_index = symbol_values[symbol_index as usize];
+                if fn_index < module.import.function_count() as u32 {
+                    let host_symbol_index =
+                        module.linking.find_imported_fn_sym_index(fn_index).unwrap();
+                    module.reloc_code.entries.push(RelocationEntry::Index {
+                        type_id: IndexRelocType::FunctionIndexLeb,
+                        offset: offset + code_offset as u32,
+                        symbol_index: host_symbol_index,
+                    });
+                }
+            }
+            RelocationEntry::Index {
+                type_id: IndexRelocType::TypeIndexLeb,
+                offset,
+                symbol_index,
+            } => {
+                module.reloc_code.entries.push(RelocationEntry::Index {
+                    type_id: IndexRelocType::TypeIndexLeb,
+                    offset: offset + code_offset as u32,
+                    symbol_index: type_indices[symbol_index as usize],
+                });
+            }
+            _ => {}
+        }
+    }
+
+    for (app_fn_offset, app_type_index) in app
+        .code
+        .function_offsets
+        .iter()
+        .zip(app.function.signatures.iter())
+    {
+        module.code.function_count += 1;
+        module
+            .code
+            .function_offsets
+            .push(*app_fn_offset + code_offset as u32);
+        module
+            .function
+            .add_sig(type_indices[*app_type_index as usize]);
+    }
+    module
+        .code
+        .bytes
+        .extend_from_slice(&app_code[app_code_start..]);
+
+    let mut app_fn_names = Vec::from_iter_in(
+        app.linking.symbol_table.iter().filter_map(|sym| match sym {
+            SymInfo::Function(WasmObjectSymbol::ExplicitlyNamed { flags, index, name })
+                if flags & WASM_SYM_UNDEFINED == 0 =>
+            {
+                Some((app_fn_index(*index), *name))
+            }
+            _ => None,
+        }),
+        arena,
+    );
+    app_fn_names.sort_by_key(|(fn_index, _)| *fn_index);
+    module.names.function_names.extend(app_fn_names);
+
+    //
+    // Data section
+    //
+    let mut app_data = app.data.relocatable_bytes();
+    for entry in app.reloc_data.entries.iter() {
+        let value = relocation_value(
+            module,
+            &mut called_fns,
+            &symbol_values,
+            &type_indices,
+            entry,
+        );
+        apply_relocation(&mut app_data, entry, value);
+    }
+
+    for ((_, range), addr) in app_segments.iter().zip(segment_addrs.iter()) {
+        module.data.append_segment(DataSegment {
+            mode: DataMode::active_at(*addr),
+            init: Vec::from_iter_in(app_data[range.clone()].iter().copied(), arena),
+        });
+        module.data.end_addr = module.data.end_addr.max(addr + range.len() as u32);
+    }
+
+    set_memory_layout(arena, module);
+    export_globals(module);
+    call_host_main(arena, module, &mut called_fns);
+
+    let fn_table_size = 1 + module.element.max_table_index();
+    module.table.function_table.limits = Limits::MinMax(fn_table_size, fn_table_size);
+
+    called_fns
+}
+
+fn imported_fn_name<'a>(module: &WasmModule<'a>, fn_index: u32) -> &'a str {
+    module
+        .import
+        .imports
+        .iter()
+        .filter(|import| import.is_function())
+        .nth(fn_index as usize)
+        .map(|import| import.name)
+        .unwrap_or_else(|| internal_error!("There's no imported function #{}", fn_index))
+}
+
+/// The value to write for a relocation, inserting functions into the table if their address is taken.
+fn relocation_value(
+    module: &mut WasmModule,
+    called_fns: &mut BitVec<usize>,
+    symbol_values: &[u32],
+    type_indices: &[u32],
+    entry: &RelocationEntry,
+) -> u32 {
+    match *entry {
+        RelocationEntry::Index {
+            type_id,
+            symbol_index,
+            ..
+        } => match type_id {
+            IndexRelocType::FunctionIndexLeb => {
+                let fn_index = symbol_values[symbol_index as usize];
+                called_fns.set(fn_index as usize, true);
+                fn_index
+            }
+            IndexRelocType::TableIndexSleb | IndexRelocType::TableIndexI32 => {
+                let fn_index = symbol_values[symbol_index as usize];
+                called_fns.set(fn_index as usize, true);
+                module.element.get_or_insert_fn(fn_index) as u32
+            }
+            // For type relocations, the "symbol index" is the app's type index
+            IndexRelocType::TypeIndexLeb => type_indices[symbol_index as usize],
+            _ => symbol_values[symbol_index as usize],
+        },
+        RelocationEntry::Offset {
+            symbol_index,
+            addend,
+            ..
+        } => (symbol_values[symbol_index as usize] as i32 + addend) as u32,
+    }
+}
+
+fn apply_relocation(bytes: &mut [u8], entry: &RelocationEntry, value: u32) {
+    match *entry {
+        RelocationEntry::Index {
+            type_id, offset, ..
+        } => {
+            let bytes = &mut bytes[offset as usize..];
+            match type_id {
+                IndexRelocType::FunctionIndexLeb
+                | IndexRelocType::TypeIndexLeb
+                | IndexRelocType::GlobalIndexLeb
+                | IndexRelocType::TableNumberLeb => overwrite_padded_u32(bytes, value),
+                IndexRelocType::TableIndexSleb => overwrite_padded_i32(bytes, value as i32),
+                IndexRelocType::TableIndexI32 => bytes[..4].copy_from_slice(&value.to_le_bytes()),
+                _ => internal_error!("Unsupported relocation type in the app: {:?}", type_id),
+            }
+        }
+        RelocationEntry::Offset {
+            type_id, offset, ..
+        } => {
+            let bytes = &mut bytes[offset as usize..];
+            match type_id {
+                OffsetRelocType::MemoryAddrLeb => overwrite_padded_u32(bytes, value),
+                OffsetRelocType::MemoryAddrSleb => overwrite_padded_i32(bytes, value as i32),
+                OffsetRelocType::MemoryAddrI32 => bytes[..4].copy_from_slice(&value.to_le_bytes()),
+                _ => internal_error!("Unsupported relocation type in the app: {:?}", type_id),
+            }
+        }
+    }
+}
+
+/// The index that the next function added to the module's Code section will get
+fn next_fn_index(module: &WasmModule) -> u32 {
+    module.import.function_count() as u32
+        + module.code.dead_import_dummy_count
+        + module.code.function_count
+}
+
+/// Put the stack after the constant data, and the heap after the stack, like the Wasm dev backend.
+fn set_memory_layout<'a>(arena: &'a Bump, module: &mut WasmModule<'a>) {
+    let stack_heap_boundary =
+        round_up_to_alignment!(module.data.end_addr + STACK_BYTES, MemorySection::PAGE_SIZE);
+
+    // The stack pointer is the only global the host may import, and it becomes a defined global
+    let sp_type = GlobalType {
+        value_type: ValueType::I32,
+        is_mutable: true,
+    };
+    let stack_pointer_import = Import {
+        module: "env",
+        name: "__stack_pointer",
+        description: ImportDesc::Global { ty: sp_type },
+    };
+    let mut imported_globals = module
+        .import
+        .imports
+        .iter()
+        .filter(|import| matches!(import.description, ImportDesc::Global { .. }));
+    if imported_globals.next() != Some(&stack_pointer_import) || imported_globals.next().is_some() {
+        user_error!("I can't link this host file. I expected it to have one imported Global called env.__stack_pointer")
+    }
+    module
+        .import
+        .imports
+        .retain(|import| !matches!(import.description, ImportDesc::Global { .. }));
+
+    module.global.append(Global {
+        ty: sp_type,
+        init: ConstExpr::I32(stack_heap_boundary as i32),
+    });
+
+    module.memory = MemorySection::new(arena, stack_heap_boundary + MemorySection::PAGE_SIZE);
+
+    module.export.append(Export {
+        name: MEMORY_NAME,
+        ty: ExportType::Mem,
+        index: 0,
+    });
+
+    // Tell malloc where the heap begins
+    if module.linking.find_internal_symbol("__heap_base").is_ok() {
+        module
+            .relocate_internal_symbol("__heap_base", stack_heap_boundary)
+            .unwrap();
+    }
+}
+
+/// Define the host's `extern` global variables, and export them so that JavaScript can set them
+fn export_globals(module: &mut WasmModule) {
+    for sym_index in 0..module.linking.symbol_table.len() {
+        let name = match module.linking.symbol_table[sym_index] {
+            SymInfo::Data(DataSymbol::Imported { name, .. }) if name != "__heap_base" => name,
+            _ => continue,
+        };
+
+        let global_value_addr = module.data.end_addr;
+        module.data.end_addr += 4;
+
+        module.reloc_code.apply_relocs_u32(
+            &mut module.code.bytes,
+            sym_index as u32,
+            global_value_addr,
+        );
+
+        let global_index = module.global.count;
+        module.global.append(Global {
+            ty: GlobalType {
+                value_type: ValueType::I32,
+                is_mutable: false,
+            },
+            init: ConstExpr::I32(global_value_addr as i32),
+        });
+
+        module.export.append(Export {
+            name,
+            ty: ExportType::Global,
+            index: global_index,
+        });
+    }
+}
+
+/// If the host has a `main` function, export a `_start` that calls it
+fn call_host_main<'a>(
+    arena: &'a Bump,
+    module: &mut WasmModule<'a>,
+    called_fns: &mut BitVec<usize>,
+) {
+    if let Ok(sym_index) = module.linking.find_internal_symbol(START) {
+        if let SymInfo::Function(WasmObjectSymbol::ExplicitlyNamed { index, .. }) =
+            module.linking.symbol_table[sym_index]
+        {
+            module.export.append(Export {
+                name: START,
+                ty: ExportType::Func,
+                index,
+            });
+            called_fns.set(index as usize, true);
+        }
+        return;
+    }
+
+    let main_fn_index = match module.linking.find_internal_symbol("main") {
+        Ok(sym_index) => match module.linking.symbol_table[sym_index] {
+            SymInfo::Function(WasmObjectSymbol::ExplicitlyNamed { index, .. }) => index,
+            _ => return,
+        },
+        Err(_) => return,
+    };
+    called_fns.set(main_fn_index as usize, true);
+
+    let import_fn_count = module.import.function_count() as u32;
+    let main_signature = module.function.signatures[(main_fn_index - import_fn_count) as usize];
+    let (main_params, main_ret_type) = module.types.look_up(main_signature);
+
+    // main(argc, argv), or main(), with zeros for all the arguments
+    let mut body = Vec::with_capacity_in(16, arena);
+    body.encode_u32(0); // no locals
+    for _ in main_params {
+        body.append_u8(OpCode::I32CONST as u8);
+        body.encode_i32(0);
+    }
+    body.append_u8(OpCode::CALL as u8);
+    body.encode_u32(main_fn_index);
+    if main_ret_type.is_some() {
+        body.append_u8(OpCode::DROP as u8);
+    }
+    body.append_u8(OpCode::END as u8);
+
+    let start_fn_index = next_fn_index(module);
+
+    module.add_function_signature(Signature {
+        param_types: Vec::new_in(arena),
+        ret_type: None,
+    });
+    module.code.function_count += 1;
+    module
+        .code
+        .function_offsets
+        .push(module.code.bytes.len() as u32);
+    body.serialize(&mut module.code.bytes);
+
+    module.names.function_names.push((start_fn_index, START));
+    module.export.append(Export {
+        name: START,
+        ty: ExportType::Func,
+        index: start_fn_index,
+    });
+    called_fns.push(true);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use roc_wasm_interp::{ImportDispatcher, Instance, Value};
+
+    const TYPE_I32_RESULT: &[u8] = &[0x60, 0, 1, 0x7f];
+    const TYPE_I32_PARAM: &[u8] = &[0x60, 1, 0x7f, 0];
+    const TYPE_VOID: &[u8] = &[0x60, 0, 0];
+
+    /// A function body, and the relocations in it, at offsets from the start of the body
+    struct Body {
+        bytes: std::vec::Vec<u8>,
+        relocs: std::vec::Vec<(u8, usize, u32, Option<i32>)>,
+    }
+
+    impl Body {
+        fn new() -> Self {
+            // no locals
+            Body {
+                bytes: vec![0],
+                relocs: vec![],
+            }
+        }
+
+        fn call(mut self, symbol_index: u32) -> Self {
+            self.bytes.push(OpCode::CALL as u8);
+            self.relocs.push((
+                IndexRelocType::FunctionIndexLeb as u8,
+                self.bytes.len(),
+                symbol_index,
+                None,
+            ));
+            self.bytes.encode_padded_u32(0);
+            self
+        }
+
+        fn load_i32(mut self, data_symbol_index: u32) -> Self {
+            self.bytes.push(OpCode::I32CONST as u8);
+            self.relocs.push((
+                OffsetRelocType::MemoryAddrSleb as u8,
+                self.bytes.len(),
+                data_symbol_index,
+                Some(0),
+            ));
+            self.bytes.encode_padded_u32(0);
+            // alignment 2^2, offset 0
+            self.bytes.extend([OpCode::I32LOAD as u8, 2, 0]);
+            self
+        }
+
+        fn end(mut self) -> Self {
+            self.bytes.push(OpCode::END as u8);
+            self
+        }
+    }
+
+    /// Just enough of the object file format to write the kind of relocatable module that
+    /// `wasm-ld --relocatable` and LLVM make
+    struct ObjectFile<'a> {
+        types: &'a [&'a [u8]],
+        /// Each import's module name, name, and encoded description
+        imports: &'a [(&'a str, &'a str, &'a [u8])],
+        /// Each defined function's type index and body
+        functions: std::vec::Vec<(u32, Body)>,
+        /// Each data segment's address and contents
+        data: &'a [(u32, &'a [u8])],
+        /// The encoded symbols
+        symbols: std::vec::Vec<std::vec::Vec<u8>>,
+    }
+
+    fn write_section(bytes: &mut std::vec::Vec<u8>, id: u8, body: &[u8]) {
+        bytes.push(id);
+        bytes.encode_u32(body.len() as u32);
+        bytes.extend_from_slice(body);
+    }
+
+    fn write_custom_section(bytes: &mut std::vec::Vec<u8>, name: &str, contents: &[u8]) {
+        let mut body = vec![];
+        name.serialize(&mut body);
+        body.extend_from_slice(contents);
+        write_section(bytes, 0, &body);
+    }
+
+    fn function_symbol(flags: u32, index: u32, name: Option<&str>) -> std::vec::Vec<u8> {
+        let mut bytes = vec![0];
+        bytes.encode_u32(flags);
+        bytes.encode_u32(index);
+        if let Some(name) = name {
+            name.serialize(&mut bytes);
+        }
+        bytes
+    }
+
+    fn data_symbol(name: &str, segment_index: u32, size: u32) -> std::vec::Vec<u8> {
+        let mut bytes = vec![1];
+        bytes.encode_u32(0);
+        name.serialize(&mut bytes);
+        bytes.encode_u32(segment_index);
+        bytes.encode_u32(0);
+        bytes.encode_u32(size);
+        bytes
+    }
+
+    impl ObjectFile<'_> {
+        fn to_bytes(&self) -> std::vec::Vec<u8> {
+            let mut bytes = b"\0asm".to_vec();
+            bytes.extend(1u32.to_le_bytes());
+
+            let mut types = vec![];
+            types.encode_u32(self.types.len() as u32);
+            for ty in self.types {
+                types.extend_from_slice(ty);
+            }
+            write_section(&mut bytes, 1, &types);
+
+            let mut imports = vec![];
+            imports.encode_u32(self.imports.len() as u32);
+            for (module, name, description) in self.imports {
+                module.serialize(&mut imports);
+                name.serialize(&mut imports);
+                imports.extend_from_slice(description);
+            }
+            write_section(&mut bytes, 2, &imports);
+
+            let mut function = vec![];
+            function.encode_u32(self.functions.len() as u32);
+            for (type_index, _) in self.functions.iter() {
+                function.encode_u32(*type_index);
+            }
+            write_section(&mut bytes, 3, &function);
+
+            // Relocation offsets are from the start of the section body
+            let mut code = vec![];
+            let mut reloc_code = vec![];
+            let mut reloc_count = 0;
+            code.encode_u32(self.functions.len() as u32);
+            for (_, body) in self.functions.iter() {
+                code.encode_u32(body.bytes.len() as u32);
+                for (type_id, offset, symbol_index, addend) in body.relocs.iter() {
+                    reloc_code.push(*type_id);
+                    reloc_code.encode_u32((code.len() + offset) as u32);
+                    reloc_code.encode_u32(*symbol_index);
+                    if let Some(addend) = addend {
+                        reloc_code.encode_i32(*addend);
+                    }
+                    reloc_count += 1;
+                }
+                code.extend_from_slice(&body.bytes);
+            }
+            write_section(&mut bytes, 10, &code);
+
+            let mut data = vec![];
+            data.encode_u32(self.data.len() as u32);
+            for (addr, contents) in self.data {
+                data.push(0); // active, in memory 0
+                data.push(OpCode::I32CONST as u8);
+                data.encode_i32(*addr as i32);
+                data.push(OpCode::END as u8);
+                data.encode_u32(contents.len() as u32);
+                data.extend_from_slice(contents);
+            }
+            write_section(&mut bytes, 11, &data);
+
+            let mut linking = vec![2]; // version
+            let mut symbol_table = vec![];
+            symbol_table.encode_u32(self.symbols.len() as u32);
+            for symbol in self.symbols.iter() {
+                symbol_table.extend_from_slice(symbol);
+            }
+            linking.push(8); // symbol table
+            linking.encode_u32(symbol_table.len() as u32);
+            linking.extend(symbol_table);
+
+            let mut segment_info = vec![];
+            segment_info.encode_u32(self.data.len() as u32);
+            for index in 0..self.data.len() {
+                format!(".data.{}", index)
+                    .as_str()
+                    .serialize(&mut segment_info);
+                segment_info.encode_u32(2); // 4-byte alignment
+                segment_info.encode_u32(0);
+            }
+            linking.push(5); // segment info
+            linking.encode_u32(segment_info.len() as u32);
+            linking.extend(segment_info);
+            write_custom_section(&mut bytes, "linking", &linking);
+
+            let mut reloc_code_section = vec![];
+            reloc_code_section.encode_u32(3); // the Code section is the fourth one
+            reloc_code_section.encode_u32(reloc_count);
+            reloc_code_section.extend(reloc_code);
+            write_custom_section(&mut bytes, "reloc.CODE", &reloc_code_section);
+
+            bytes
+        }
+    }
+
+    const MEMORY_IMPORT: &[u8] = &[2, 0, 1];
+    const STACK_POINTER_IMPORT: &[u8] = &[3, 0x7f, 1];
+
+    /// A host whose main reports what the app returns, and then the contents of its own data
+    fn host() -> std::vec::Vec<u8> {
+        ObjectFile {
+            types: &[TYPE_I32_RESULT, TYPE_I32_PARAM, TYPE_VOID],
+            imports: &[
+                ("env", "__linear_memory", MEMORY_IMPORT),
+                ("env", "__stack_pointer", STACK_POINTER_IMPORT),
+                ("env", "roc__mainForHost_1_exposed", &[0, 0]),
+                ("env", "report", &[0, 1]),
+            ],
+            functions: vec![(2, Body::new().call(0).call(1).load_i32(3).call(1).end())],
+            data: &[(0, &[7, 0, 0, 0, 0, 0, 0, 0])],
+            symbols: vec![
+                function_symbol(WASM_SYM_UNDEFINED, 0, None),
+                function_symbol(WASM_SYM_UNDEFINED, 1, None),
+                function_symbol(0, 2, Some("main")),
+                data_symbol("host_data", 0, 8),
+            ],
+        }
+        .to_bytes()
+    }
+
+    /// An app whose main returns the contents of its data
+    fn app() -> std::vec::Vec<u8> {
+        ObjectFile {
+            types: &[TYPE_I32_RESULT],
+            imports: &[("env", "__linear_memory", MEMORY_IMPORT)],
+            functions: vec![(0, Body::new().load_i32(1).end())],
+            data: &[(0, &[42, 0, 0, 0])],
+            symbols: vec![
+                function_symbol(0, 0, Some("roc__mainForHost_1_exposed")),
+                data_symbol("answer", 0, 4),
+            ],
+        }
+        .to_bytes()
+    }
+
+    /// An app with data but no functions
+    fn app_without_functions() -> std::vec::Vec<u8> {
+        ObjectFile {
+            types: &[],
+            imports: &[("env", "__linear_memory", MEMORY_IMPORT)],
+            functions: vec![],
+            data: &[(0, &[42, 0, 0, 0])],
+            symbols: vec![data_symbol("answer", 0, 4)],
+        }
+        .to_bytes()
+    }
+
+    #[derive(Default)]
+    struct Reports(std::vec::Vec<i32>);
+
+    impl ImportDispatcher for Reports {
+        fn dispatch(
+            &mut self,
+            module_name: &str,
+            function_name: &str,
+            arguments: &[Value],
+            _memory: &mut [u8],
+        ) -> Option<Value> {
+            assert_eq!(("env", "report"), (module_name, function_name));
+            match arguments {
+                [Value::I32(value)] => self.0.push(*value),
+                _ => panic!("report was called with {:?}", arguments),
+            }
+            None
+        }
+    }
+
+    #[test]
+    fn link_and_run() {
+        let dir = tempfile::tempdir().unwrap();
+        let executable_path = dir.path().join("app.wasm");
+
+        std::fs::write(&executable_path, host()).unwrap();
+        surgery_wasm(&app(), &executable_path);
+
+        let executable = std::fs::read(&executable_path).unwrap();
+        let arena = Bump::new();
+        let mut instance =
+            Instance::from_bytes(&arena, &executable, Reports::default(), false).unwrap();
+
+        instance.call_export(START, []).unwrap();
+
+        // The app's data mustn't overwrite the host's
+        assert_eq!(vec![42, 7], instance.import_dispatcher.0);
+    }
+
+    #[test]
+    fn memory_layout() {
+        let dir = tempfile::tempdir().unwrap();
+        let executable_path = dir.path().join("app.wasm");
+
+        std::fs::write(&executable_path, host()).unwrap();
+        surgery_wasm(&app(), &executable_path);
+
+        let executable = std::fs::read(&executable_path).unwrap();
+        let arena = Bump::new();
+        let module = WasmModule::preload(&arena, &executable, false).unwrap();
+
+        // The app's data goes after the host's 8 bytes
+        assert_eq!(12, module.data.end_addr);
+        assert!(module
+            .export
+            .exports
+            .iter()
+            .any(|export| export.name == MEMORY_NAME && export.ty == ExportType::Mem));
+        assert!(module
+            .import
+            .imports
+            .iter()
+            .all(|import| import.is_function()));
+    }
+
+    #[test]
+    fn link_app_without_functions() {
+        let dir = tempfile::tempdir().unwrap();
+        let executable_path = dir.path().join("app.wasm");
+
+        std::fs::write(&executable_path, host()).unwrap();
+        surgery_wasm(&app_without_functions(), &executable_path);
+
+        let executable = std::fs::read(&executable_path).unwrap();
+        let arena = Bump::new();
+        let module = WasmModule::preload(&arena, &executable, false).unwrap();
+
+        assert_eq!(12, module.data.end_addr);
+    }
+}
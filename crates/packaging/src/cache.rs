@@ -1,6 +1,7 @@
 #[cfg(not(target_family = "wasm"))]
 use {
     crate::https::{self, PackageMetadata, Problem},
+    crate::mirror::{Mirrors, PackageSource},
     roc_error_macros::internal_error,
     std::fs,
 };
@@ -13,6 +14,9 @@ use std::path::{Path, PathBuf};
 pub enum RocCacheDir<'a> {
     /// Normal scenario: reading from the user's cache dir on disk
     Persistent(&'a Path),
+    /// Reading from the user's cache dir on disk, but never downloading anything that isn't
+    /// already in there - e.g. for hermetic CI builds, after running `roc fetch`.
+    Offline(&'a Path),
     /// For build.rs and tests where we never want to be downloading anything - yell loudly if we try!
     Disallowed,
     /// For tests only; we don't want to write to the real cache during a test!
@@ -65,10 +69,23 @@ fn nixos_error_if_dynamic(url: &str, dest_dir: &Path) {
 ///
 /// Returns the path to the installed package (which will be in the cache dir somewhere), as well
 /// as the requested root module filename (optionally specified via the URL fragment).
+///
+/// If ROC_PACKAGE_MIRRORS is set, the package may be downloaded from a mirror instead of the URL
+/// itself; see the mirror module for details.
 #[cfg(not(target_family = "wasm"))]
 pub fn install_package<'a>(
     roc_cache_dir: RocCacheDir<'_>,
     url: &'a str,
+) -> Result<(PathBuf, Option<&'a str>), Problem> {
+    install_package_with_mirrors(roc_cache_dir, url, &Mirrors::from_env())
+}
+
+/// Like install_package, but with the given mirrors instead of the ones from the environment.
+#[cfg(not(target_family = "wasm"))]
+pub fn install_package_with_mirrors<'a>(
+    roc_cache_dir: RocCacheDir<'_>,
+    url: &'a str,
+    mirrors: &Mirrors,
 ) -> Result<(PathBuf, Option<&'a str>), Problem> {
     let PackageMetadata {
         cache_subdir,
@@ -77,7 +94,7 @@ pub fn install_package<'a>(
     } = PackageMetadata::try_from(url).map_err(Problem::InvalidUrl)?;
 
     match roc_cache_dir {
-        RocCacheDir::Persistent(cache_dir) => {
+        RocCacheDir::Persistent(cache_dir) | RocCacheDir::Offline(cache_dir) => {
             // e.g. ~/.cache/roc/example.com/roc-packages/
             let parent_dir = cache_dir.join(cache_subdir);
             // e.g. ~/.cache/roc/example.com/roc-packages/jDRlAFAA3738vu3-vMpLUoyxtA86Z7CaZneoOKrihbE
@@ -93,16 +110,31 @@ pub fn install_package<'a>(
                 }
 
                 Ok((dest_dir, root_module_filename))
+            } else if let RocCacheDir::Offline(_) = roc_cache_dir {
+                Err(Problem::NotCachedWhileOffline(url.to_string()))
             } else {
                 // Download into a tempdir; only move it to dest_dir if hash verification passes.
-                println!(
-                    "Downloading \u{001b}[36m{url}\u{001b}[0m\n    into {}\n",
-                    cache_dir.display()
-                );
                 let tempdir = tempfile::tempdir().map_err(Problem::IoErr)?;
                 let tempdir_path = tempdir.path();
-                let downloaded_hash =
-                    https::download_and_hash(url, tempdir_path, MAX_DOWNLOAD_BYTES)?;
+                let downloaded_hash = match mirrors.resolve(url) {
+                    PackageSource::Url(source_url) => {
+                        println!(
+                            "Downloading \u{001b}[36m{source_url}\u{001b}[0m\n    into {}\n",
+                            cache_dir.display()
+                        );
+
+                        https::download_and_hash(&source_url, tempdir_path, MAX_DOWNLOAD_BYTES)?
+                    }
+                    PackageSource::File(source_path) => {
+                        println!(
+                            "Copying \u{001b}[36m{}\u{001b}[0m\n    into {}\n",
+                            source_path.display(),
+                            cache_dir.display()
+                        );
+
+                        https::read_and_hash(&source_path, tempdir_path, MAX_DOWNLOAD_BYTES)?
+                    }
+                };
 
                 // Download the tarball into memory and verify it.
                 // The tarball name is the hash of its contents.
@@ -160,6 +192,18 @@ pub fn install_package<'a>(
     }
 }
 
+#[cfg(not(target_family = "wasm"))]
+#[test]
+fn offline_install_of_uncached_package() {
+    let cache_dir = tempfile::tempdir().unwrap();
+    let url = "https://example.com/jDRlAFAA3738vu3-vMpLUoyxtA86Z7CaZneoOKrihbE.tar.br";
+
+    match install_package(RocCacheDir::Offline(cache_dir.path()), url) {
+        Err(Problem::NotCachedWhileOffline(problem_url)) => assert_eq!(url, problem_url),
+        other => panic!("Expected NotCachedWhileOffline, but got {:?}", other),
+    }
+}
+
 #[cfg(windows)]
 // e.g. the "Roc" in %APPDATA%\\Roc
 const ROC_CACHE_DIR_NAME: &str = "Roc";
@@ -0,0 +1,268 @@
+//! Finds every package a module depends on (including the packages those packages depend on),
+//! so that `roc fetch` can download them into the cache ahead of time, and `roc vendor` can
+//! copy them into the project.
+use crate::cache::{self, RocCacheDir};
+use crate::https::{PackageMetadata, Problem};
+use bumpalo::Bump;
+use roc_parse::ast::{ExtractSpaces, Header};
+use roc_parse::module::parse_header;
+use roc_parse::state::State;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A package which was downloaded from a URL
+#[derive(Debug, Clone)]
+pub struct UrlPackage {
+    pub url: String,
+    /// Where the package's files are, in the cache dir
+    pub package_dir: PathBuf,
+    /// The package's root module, relative to package_dir (e.g. main.roc)
+    pub root_module: PathBuf,
+}
+
+/// Make sure every URL package that the given module depends on (directly, or through other
+/// packages) is in the cache, downloading the missing ones. Returns those packages in the order
+/// they were found.
+pub fn fetch_packages(
+    roc_cache_dir: RocCacheDir<'_>,
+    root_module: &Path,
+) -> Result<Vec<UrlPackage>, Problem> {
+    let mut url_packages: Vec<UrlPackage> = Vec::new();
+    let mut visited: Vec<PathBuf> = Vec::new();
+    let mut stack = vec![root_module.to_path_buf()];
+
+    while let Some(module_path) = stack.pop() {
+        if visited.contains(&module_path) {
+            continue;
+        }
+
+        let module_dir = module_path.parent().unwrap_or_else(|| Path::new("."));
+
+        for package_name in header_package_names(&module_path)? {
+            if package_name.starts_with("https://") {
+                if url_packages
+                    .iter()
+                    .any(|package| package.url == package_name)
+                {
+                    continue;
+                }
+
+                let (package_dir, opt_root_module) =
+                    cache::install_package(roc_cache_dir, &package_name)?;
+                let root_module = PathBuf::from(opt_root_module.unwrap_or("main.roc"));
+
+                stack.push(package_dir.join(&root_module));
+
+                url_packages.push(UrlPackage {
+                    url: package_name,
+                    package_dir,
+                    root_module,
+                });
+            } else {
+                stack.push(module_dir.join(package_name));
+            }
+        }
+
+        visited.push(module_path);
+    }
+
+    Ok(url_packages)
+}
+
+/// Fetch every URL package that the given module depends on, and then copy each of them into
+/// the vendor dir, using the same directory layout as the cache (the URL's domain and path,
+/// followed by the package's hash). Returns each package's root module inside the vendor dir.
+pub fn vendor_packages(
+    roc_cache_dir: RocCacheDir<'_>,
+    root_module: &Path,
+    vendor_dir: &Path,
+) -> Result<Vec<(UrlPackage, PathBuf)>, Problem> {
+    let mut vendored = Vec::new();
+
+    for package in fetch_packages(roc_cache_dir, root_module)? {
+        let PackageMetadata {
+            cache_subdir,
+            content_hash,
+            ..
+        } = PackageMetadata::try_from(package.url.as_str()).map_err(Problem::InvalidUrl)?;
+        let dest_dir = vendor_dir.join(cache_subdir).join(content_hash);
+
+        // The hash is in the dir's name, so if it's already there, it's already up to date.
+        if !dest_dir.exists() {
+            fs::create_dir_all(&dest_dir).map_err(Problem::IoErr)?;
+            fs_extra::dir::copy(
+                &package.package_dir,
+                &dest_dir,
+                &fs_extra::dir::CopyOptions {
+                    content_only: true,
+                    ..Default::default()
+                },
+            )
+            .map_err(Problem::FsExtraErr)?;
+        }
+
+        let vendored_root_module = dest_dir.join(&package.root_module);
+
+        vendored.push((package, vendored_root_module));
+    }
+
+    Ok(vendored)
+}
+
+/// The names (URLs or paths) of the packages in the `packages` section of a module's header.
+fn header_package_names(module_path: &Path) -> Result<Vec<String>, Problem> {
+    let src = fs::read(module_path).map_err(Problem::IoErr)?;
+    let arena = Bump::new();
+
+    let header = match parse_header(&arena, State::new(&src)) {
+        Ok((module, _)) => module.header,
+        Err(_) => {
+            // The module's syntax error will be reported when it gets built; until then,
+            // there are no packages we know about in there.
+            return Ok(Vec::new());
+        }
+    };
+
+    let entries = match header {
+        Header::App(header) => match header.packages {
+            Some(packages) => packages.item.items,
+            None => &[],
+        },
+        Header::Package(header) => header.packages.item.items,
+        Header::Platform(header) => header.packages.item.items,
+        Header::Interface(_) | Header::Hosted(_) => &[],
+    };
+
+    Ok(entries
+        .iter()
+        .map(|entry| {
+            entry
+                .value
+                .extract_spaces()
+                .item
+                .package_name
+                .value
+                .to_str()
+                .to_string()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const JSON_URL: &str =
+        "https://example.com/json/jDRlAFAA3738vu3-vMpLUoyxtA86Z7CaZneoOKrihbE.tar.br";
+    const UTILS_URL: &str =
+        "https://example.com/utils/oUkxSOI9zFGtSoIaMB40QPdrXphr1p1780eiui2iO9Mz.tar.br#Utils.roc";
+
+    fn write(path: &Path, contents: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    /// Puts a package which depends on nothing into the cache, as if it had been downloaded.
+    fn cache_package(cache_dir: &Path, url: &str, root_module: &str) {
+        let metadata = PackageMetadata::try_from(url).unwrap();
+        let package_dir = cache_dir
+            .join(metadata.cache_subdir)
+            .join(metadata.content_hash);
+
+        write(
+            &package_dir.join(root_module),
+            "package \"cached\"\n    exposes []\n    packages {}\n",
+        );
+    }
+
+    /// An app which depends on a URL package directly, and on another one through its platform.
+    fn write_app(project_dir: &Path) -> PathBuf {
+        let app = project_dir.join("app.roc");
+
+        write(
+            &app,
+            &format!(
+                "app \"test\"\n    packages {{ pf: \"platform/main.roc\", json: \"{JSON_URL}\" }}\n    imports []\n    provides [main] to pf\n"
+            ),
+        );
+        write(
+            &project_dir.join("platform").join("main.roc"),
+            &format!(
+                "platform \"test-platform\"\n    requires {{}} {{ main : Str }}\n    exposes []\n    packages {{ utils: \"{UTILS_URL}\" }}\n    imports []\n    provides [mainForHost]\n"
+            ),
+        );
+
+        app
+    }
+
+    #[test]
+    fn fetch_finds_packages_of_local_packages() {
+        let project_dir = tempfile::tempdir().unwrap();
+        let cache_dir = tempfile::tempdir().unwrap();
+        let app = write_app(project_dir.path());
+
+        cache_package(cache_dir.path(), JSON_URL, "main.roc");
+        cache_package(cache_dir.path(), UTILS_URL, "Utils.roc");
+
+        let packages = fetch_packages(RocCacheDir::Offline(cache_dir.path()), &app).unwrap();
+        let mut urls: Vec<&str> = packages
+            .iter()
+            .map(|package| package.url.as_str())
+            .collect();
+        urls.sort_unstable();
+
+        assert_eq!(urls, [JSON_URL, UTILS_URL]);
+
+        for package in &packages {
+            assert!(package.package_dir.starts_with(cache_dir.path()));
+            assert!(package.package_dir.join(&package.root_module).is_file());
+        }
+    }
+
+    #[test]
+    fn fetch_offline_reports_uncached_package() {
+        let project_dir = tempfile::tempdir().unwrap();
+        let cache_dir = tempfile::tempdir().unwrap();
+        let app = write_app(project_dir.path());
+
+        cache_package(cache_dir.path(), JSON_URL, "main.roc");
+
+        match fetch_packages(RocCacheDir::Offline(cache_dir.path()), &app) {
+            Err(Problem::NotCachedWhileOffline(url)) => assert_eq!(url, UTILS_URL),
+            other => panic!("Expected NotCachedWhileOffline, but got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn vendor_copies_packages_in_the_cache_layout() {
+        let project_dir = tempfile::tempdir().unwrap();
+        let cache_dir = tempfile::tempdir().unwrap();
+        let vendor_dir = project_dir.path().join("vendor");
+        let app = write_app(project_dir.path());
+
+        cache_package(cache_dir.path(), JSON_URL, "main.roc");
+        cache_package(cache_dir.path(), UTILS_URL, "Utils.roc");
+
+        // Vendoring twice must leave the already-vendored packages alone.
+        for _ in 0..2 {
+            let vendored =
+                vendor_packages(RocCacheDir::Offline(cache_dir.path()), &app, &vendor_dir).unwrap();
+
+            assert_eq!(vendored.len(), 2);
+
+            for (package, vendored_root_module) in &vendored {
+                let metadata = PackageMetadata::try_from(package.url.as_str()).unwrap();
+                let expected = vendor_dir
+                    .join(metadata.cache_subdir)
+                    .join(metadata.content_hash)
+                    .join(&package.root_module);
+
+                assert_eq!(vendored_root_module, &expected);
+                assert_eq!(
+                    fs::read_to_string(vendored_root_module).unwrap(),
+                    fs::read_to_string(package.package_dir.join(&package.root_module)).unwrap()
+                );
+            }
+        }
+    }
+}
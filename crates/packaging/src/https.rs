@@ -1,4 +1,5 @@
 use std::{
+    fs::File,
     io::{self, Read},
     path::Path,
 };
@@ -116,6 +117,8 @@ pub enum Problem {
     InvalidUrl(UrlProblem),
     /// The Content-Length header of the response exceeded max_download_bytes
     DownloadTooBig(u64),
+    /// The package at this URL isn't in the cache, and we're not allowed to download it
+    NotCachedWhileOffline(String),
 }
 
 pub fn download_and_hash(
@@ -156,6 +159,20 @@ pub fn download_and_hash(
     decompress_into(dest_dir, encoding, resp.take(max_download_bytes))
 }
 
+/// Like download_and_hash, but for a tarball on disk (e.g. in a local package mirror).
+pub fn read_and_hash(
+    path: &Path,
+    dest_dir: &Path,
+    max_download_bytes: u64,
+) -> Result<String, Problem> {
+    let file = File::open(path).map_err(Problem::IoErr)?;
+
+    // There are no headers to tell us the encoding, so infer it from the file extension.
+    let encoding = Encoding::new("", &path.to_string_lossy())?;
+
+    decompress_into(dest_dir, encoding, file.take(max_download_bytes))
+}
+
 /// The content encodings we support
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum Encoding {
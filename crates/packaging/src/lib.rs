@@ -1,4 +1,8 @@
 pub mod cache;
 #[cfg(not(target_family = "wasm"))]
+pub mod fetch;
+#[cfg(not(target_family = "wasm"))]
 pub mod https;
+#[cfg(not(target_family = "wasm"))]
+pub mod mirror;
 pub mod tarball;
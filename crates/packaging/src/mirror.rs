@@ -0,0 +1,262 @@
+//! Lets builds get packages from somewhere other than the URL in the app's header, e.g. from an
+//! internal mirror, or from tarballs on the local filesystem.
+//!
+//! Mirrors are configured with the ROC_PACKAGE_MIRRORS environment variable, which is a
+//! comma-separated list of `prefix=replacement` entries, e.g.
+//!
+//! ```text
+//! ROC_PACKAGE_MIRRORS="https://github.com=https://mirror.example.com/github,https://example.com/pkgs=/srv/roc-packages"
+//! ```
+//!
+//! A package URL which starts with one of the prefixes has that prefix replaced. If the
+//! replacement is an `http://` or `https://` URL, the package is downloaded from there;
+//! otherwise, it's read from that path on disk (which may also be written as a `file://` URL). Either way, the package is still verified against
+//! the hash in the original URL, and is cached under the original URL.
+use std::path::PathBuf;
+
+const MIRRORS_ENV_VAR: &str = "ROC_PACKAGE_MIRRORS";
+
+/// Where a package's tarball actually comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PackageSource {
+    Url(String),
+    File(PathBuf),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Mirrors {
+    /// (prefix, replacement) pairs, in the order they were given
+    entries: Vec<(String, String)>,
+}
+
+impl Mirrors {
+    /// This looks up an environment variable, so it should ideally be called once per build.
+    pub fn from_env() -> Self {
+        match std::env::var(MIRRORS_ENV_VAR) {
+            Ok(config) => Self::parse(&config),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Parses a comma-separated list of `prefix=replacement` entries, ignoring malformed ones.
+    pub fn parse(config: &str) -> Self {
+        let entries = config
+            .split(',')
+            .filter_map(|entry| {
+                let (prefix, replacement) = entry.split_once('=')?;
+                let prefix = prefix.trim();
+                let replacement = replacement.trim();
+
+                if prefix.is_empty() || replacement.is_empty() {
+                    None
+                } else {
+                    Some((prefix.to_string(), replacement.to_string()))
+                }
+            })
+            .collect();
+
+        Self { entries }
+    }
+
+    /// Where to get the tarball for the given package URL from. If more than one prefix
+    /// matches, the longest one wins.
+    pub fn resolve(&self, url: &str) -> PackageSource {
+        // The fragment names the package's root module; it isn't part of the tarball's location.
+        let without_fragment = match url.rsplit_once('#') {
+            Some((before_fragment, _)) => before_fragment,
+            None => url,
+        };
+
+        let longest_match = self
+            .entries
+            .iter()
+            .filter(|(prefix, _)| without_fragment.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len());
+
+        match longest_match {
+            Some((prefix, replacement)) => {
+                let rest = &without_fragment[prefix.len()..];
+
+                if replacement.starts_with("https://") || replacement.starts_with("http://") {
+                    PackageSource::Url(format!("{replacement}{rest}"))
+                } else {
+                    // A file:// URL is just a path with a scheme in front of it.
+                    let path = replacement.strip_prefix("file://").unwrap_or(replacement);
+
+                    PackageSource::File(PathBuf::from(format!("{path}{rest}")))
+                }
+            }
+            None => PackageSource::Url(url.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Mirrors, PackageSource};
+    use crate::cache::{install_package_with_mirrors, RocCacheDir};
+    use crate::https::Problem;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::path::PathBuf;
+    use std::thread;
+
+    const MAIN_ROC: &str = "package \"mirrored\" exposes [] packages {}\n";
+
+    /// An uncompressed tarball containing a main.roc, along with its base64url-encoded hash.
+    fn package_tarball() -> (Vec<u8>, String) {
+        let mut builder = tar::Builder::new(Vec::new());
+        let mut header = tar::Header::new_gnu();
+
+        header.set_size(MAIN_ROC.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+
+        builder
+            .append_data(&mut header, "main.roc", MAIN_ROC.as_bytes())
+            .unwrap();
+
+        let bytes = builder.into_inner().unwrap();
+        let hash = base64_url::encode(blake3::hash(&bytes).as_bytes());
+
+        (bytes, hash)
+    }
+
+    /// A stand-in for a mirror's HTTP server, which answers a single request with the given body.
+    /// Returns the server's base URL.
+    fn serve_once(body: Vec<u8>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+
+            // Read the whole request before responding, so the client doesn't see a reset.
+            while !request.ends_with(b"\r\n\r\n") {
+                let bytes_read = stream.read(&mut buf).unwrap();
+
+                if bytes_read == 0 {
+                    break;
+                }
+
+                request.extend_from_slice(&buf[..bytes_read]);
+            }
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                body.len()
+            )
+            .unwrap();
+            stream.write_all(&body).unwrap();
+        });
+
+        format!("http://127.0.0.1:{port}")
+    }
+
+    #[test]
+    fn resolve_without_mirrors() {
+        let url = "https://example.com/pkgs/abc.tar.br#main.roc";
+
+        assert_eq!(
+            PackageSource::Url(url.to_string()),
+            Mirrors::default().resolve(url)
+        );
+    }
+
+    #[test]
+    fn resolve_longest_prefix() {
+        let mirrors = Mirrors::parse(
+            "https://example.com=https://mirror.internal, https://example.com/pkgs=/srv/pkgs",
+        );
+
+        assert_eq!(
+            PackageSource::File(PathBuf::from("/srv/pkgs/abc.tar.br")),
+            mirrors.resolve("https://example.com/pkgs/abc.tar.br#main.roc")
+        );
+        assert_eq!(
+            PackageSource::Url("https://mirror.internal/other/abc.tar.br".to_string()),
+            mirrors.resolve("https://example.com/other/abc.tar.br")
+        );
+    }
+
+    #[test]
+    fn resolve_file_url() {
+        let mirrors = Mirrors::parse("https://example.com/pkgs=file:///srv/pkgs");
+
+        assert_eq!(
+            PackageSource::File(PathBuf::from("/srv/pkgs/abc.tar.br")),
+            mirrors.resolve("https://example.com/pkgs/abc.tar.br#main.roc")
+        );
+    }
+
+    #[test]
+    fn install_from_http_mirror() {
+        let (tarball, hash) = package_tarball();
+        let mirror_url = serve_once(tarball);
+        let mirrors = Mirrors::parse(&format!("https://example.com/pkgs={mirror_url}"));
+        let cache_dir = tempfile::tempdir().unwrap();
+        let url = format!("https://example.com/pkgs/{hash}.tar");
+
+        let (package_dir, root_module) =
+            install_package_with_mirrors(RocCacheDir::Persistent(cache_dir.path()), &url, &mirrors)
+                .unwrap();
+
+        // The package is cached under the original URL, not the mirror's.
+        assert_eq!(
+            cache_dir.path().join("example.com/pkgs").join(&hash),
+            package_dir
+        );
+        assert_eq!(None, root_module);
+        assert_eq!(
+            MAIN_ROC,
+            std::fs::read_to_string(package_dir.join("main.roc")).unwrap()
+        );
+    }
+
+    #[test]
+    fn install_from_file_mirror() {
+        let (tarball, hash) = package_tarball();
+        let mirror_dir = tempfile::tempdir().unwrap();
+        let mirrors = Mirrors::parse(&format!(
+            "https://example.com/pkgs={}",
+            mirror_dir.path().display()
+        ));
+        let cache_dir = tempfile::tempdir().unwrap();
+        let url = format!("https://example.com/pkgs/{hash}.tar#main.roc");
+
+        std::fs::write(mirror_dir.path().join(format!("{hash}.tar")), tarball).unwrap();
+
+        let (package_dir, root_module) =
+            install_package_with_mirrors(RocCacheDir::Persistent(cache_dir.path()), &url, &mirrors)
+                .unwrap();
+
+        assert_eq!(Some("main.roc"), root_module);
+        assert!(package_dir.join("main.roc").exists());
+    }
+
+    #[test]
+    fn mirror_must_match_hash() {
+        let (tarball, _) = package_tarball();
+        let mirror_url = serve_once(tarball);
+        let mirrors = Mirrors::parse(&format!("https://example.com/pkgs={mirror_url}"));
+        let cache_dir = tempfile::tempdir().unwrap();
+        let url = "https://example.com/pkgs/jDRlAFAA3738vu3-vMpLUoyxtA86Z7CaZneoOKrihbE.tar";
+
+        match install_package_with_mirrors(RocCacheDir::Persistent(cache_dir.path()), url, &mirrors)
+        {
+            Err(Problem::InvalidContentHash { expected, .. }) => {
+                assert_eq!("jDRlAFAA3738vu3-vMpLUoyxtA86Z7CaZneoOKrihbE", expected);
+            }
+            other => panic!("Expected InvalidContentHash, but got {:?}", other),
+        }
+
+        // Nothing unverified should have made it into the cache.
+        assert!(!cache_dir
+            .path()
+            .join("example.com/pkgs/jDRlAFAA3738vu3-vMpLUoyxtA86Z7CaZneoOKrihbE")
+            .exists());
+    }
+}
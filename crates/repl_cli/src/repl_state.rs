@@ -205,6 +205,9 @@ impl ReplState {
                     ValueDef::ExpectFx { .. } => {
                         todo!("handle receiving an `expect-fx` - what should the repl do for that?")
                     }
+                    ValueDef::Malformed(_) => {
+                        unreachable!("the repl parses without recovering from syntax errors")
+                    }
                 }
             }
             ParseOutcome::TypeDef(TypeDef::Alias {
@@ -553,22 +553,6 @@ pub fn can_problem<'b>(
 
             title = INVALID_UNICODE.to_string();
         }
-        Problem::InvalidInterpolation(region) => {
-            doc = alloc.stack([
-                alloc.reflow("This string interpolation is invalid:"),
-                alloc.region(lines.convert_region(region)),
-                alloc.concat([
-                    alloc.reflow(r"I was expecting an identifier, like "),
-                    alloc.parser_suggestion("\\u(message)"),
-                    alloc.reflow(" or "),
-                    alloc.parser_suggestion("\\u(LoremIpsum.text)"),
-                    alloc.text("."),
-                ]),
-                alloc.reflow(r"Learn more about string interpolation at TODO"),
-            ]);
-
-            title = SYNTAX_PROBLEM.to_string();
-        }
         Problem::RuntimeError(runtime_error) => {
             let answer = pretty_runtime_error(alloc, lines, runtime_error);
 
@@ -1706,6 +1690,9 @@ fn pretty_runtime_error<'b>(
         RuntimeError::MalformedClosure(_) => {
             todo!("");
         }
+        RuntimeError::MalformedSyntax(_) => {
+            unreachable!("the parser reports syntax errors it recovers from");
+        }
         RuntimeError::InvalidFloat(sign @ FloatErrorKind::PositiveInfinity, region, _raw_str)
         | RuntimeError::InvalidFloat(sign @ FloatErrorKind::NegativeInfinity, region, _raw_str) => {
             let tip = alloc
@@ -1966,12 +1953,6 @@ fn pretty_runtime_error<'b>(
                 region
             );
         }
-        RuntimeError::InvalidInterpolation(region) => {
-            todo!(
-                "TODO runtime error for an invalid string interpolation at region {:?}",
-                region
-            );
-        }
         RuntimeError::NoImplementation | RuntimeError::NoImplementationNamed { .. } => {
             todo!("no implementation, unreachable")
         }
@@ -2113,6 +2094,32 @@ fn pretty_runtime_error<'b>(
 
             title = "DEGENERATE BRANCH";
         }
+        RuntimeError::MultipleRecordBuilders(region) => {
+            let tip = alloc
+                .tip()
+                .append(alloc.reflow("You can combine them or apply them separately."));
+
+            doc = alloc.stack([
+                alloc.reflow("This function is applied to multiple record builders:"),
+                alloc.region(lines.convert_region(region)),
+                alloc.note("Functions can only take at most one record builder!"),
+                tip,
+            ]);
+
+            title = "MULTIPLE RECORD BUILDERS";
+        }
+        RuntimeError::UnappliedRecordBuilder(region) => {
+            doc = alloc.stack([
+                alloc.reflow("This record builder was not applied to a function:"),
+                alloc.region(lines.convert_region(region)),
+                alloc.reflow("However, we need a function to construct the record."),
+                alloc.note(
+                    "Functions must be applied directly. The pipe operator (|>) cannot be used.",
+                ),
+            ]);
+
+            title = "UNAPPLIED RECORD BUILDER";
+        }
     }
 
     (doc, title)
@@ -16,6 +16,16 @@ pub fn parse_problem<'a>(
     to_syntax_report(alloc, lines, filename, &parse_problem.problem.problem)
 }
 
+/// Reports a syntax error the parser recovered from while parsing the rest of the module.
+pub fn syntax_problem<'a>(
+    alloc: &'a RocDocAllocator<'a>,
+    lines: &LineInfo,
+    filename: PathBuf,
+    problem: &SyntaxError<'a>,
+) -> Report<'a> {
+    to_syntax_report(alloc, lines, filename, problem)
+}
+
 fn note_for_record_type_indent<'a>(alloc: &'a RocDocAllocator<'a>) -> RocDocBuilder<'a> {
     alloc.note("I may be confused by indentation")
 }
@@ -549,6 +559,50 @@ fn to_expr_report<'a>(
             }
         }
 
+        EExpr::OptionalValueInRecordBuilder(region) => {
+            let surroundings = Region::new(start, region.end());
+            let region = lines.convert_region(*region);
+
+            let doc = alloc.stack([
+                alloc.reflow(
+                    r"I am partway through parsing a record builder, and I found an optional field:",
+                ),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region),
+                alloc.concat([
+                    alloc.reflow("Optional fields can only appear when you destructure a record."),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "BAD RECORD BUILDER".to_string(),
+                severity: Severity::RuntimeError,
+            }
+        }
+
+        EExpr::RecordUpdateBuilder(region) => {
+            let surroundings = Region::new(start, region.end());
+            let region = lines.convert_region(*region);
+
+            let doc = alloc.stack([
+                alloc.reflow(
+                    r"I am partway through parsing a record update, and I found a record builder field:",
+                ),
+                alloc.region_with_subregion(lines.convert_region(surroundings), region),
+                alloc.concat([
+                    alloc.reflow("Record builders cannot be updated like records."),
+                ]),
+            ]);
+
+            Report {
+                filename,
+                doc,
+                title: "BAD RECORD UPDATE".to_string(),
+                severity: Severity::RuntimeError,
+            }
+        }
+
         EExpr::Space(error, pos) => to_space_report(alloc, lines, filename, error, *pos),
 
         &EExpr::Number(ENumber::End, pos) => {
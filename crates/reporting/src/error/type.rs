@@ -371,6 +371,16 @@ fn underivable_hint<'b>(
                 ])),
             ])))
         }
+        NotDerivableContext::OptionalRecordField(field) => {
+            Some(alloc.note("").append(alloc.concat([
+                alloc.reflow("I can't derive "),
+                alloc.symbol_unqualified(ability),
+                alloc.reflow(" for a record with an optional field, which in this case is "),
+                alloc.record_field(field),
+                alloc.reflow(". Optional record fields are polymorphic over records that may or may not contain them at compile time, "),
+                alloc.reflow("but are not a concept that extends to runtime!"),
+            ])))
+        }
         NotDerivableContext::Encode(reason) => match reason {
             NotDerivableEncode::Nat => {
                 Some(alloc.note("").append(alloc.concat([
@@ -1480,7 +1490,30 @@ fn to_expr_report<'b>(
             }
 
             Reason::StrInterpolation => {
-                unimplemented!("string interpolation is not implemented yet")
+                let problem = alloc.concat([
+                    alloc.text("This interpolated value needs to be a "),
+                    alloc.type_str("Str"),
+                    alloc.text(":"),
+                ]);
+
+                report_bad_type(
+                    alloc,
+                    lines,
+                    filename,
+                    severity,
+                    &category,
+                    found,
+                    expected_type,
+                    region,
+                    Some(expr_region),
+                    problem,
+                    alloc.text("Right now it’s"),
+                    alloc.concat([
+                        alloc.reflow("But only strings can be interpolated. Functions like "),
+                        alloc.symbol_qualified(Symbol::NUM_TO_STR),
+                        alloc.reflow(" can turn other values into strings."),
+                    ]),
+                )
             }
 
             Reason::RecordDefaultField(_) => {
@@ -4652,7 +4685,9 @@ fn exhaustive_problem<'a>(
                 }
             }
             BadCase => {
-                let doc = alloc.stack([
+                let missing_because_of_guard = missing.iter().any(is_missing_because_of_guard);
+
+                let mut stack = vec![
                     alloc.concat([
                         alloc.reflow("This "),
                         alloc.keyword("when"),
@@ -4666,7 +4701,20 @@ fn exhaustive_problem<'a>(
                         Add branches for them!",
                     ),
                     // alloc.hint().append(alloc.reflow("or use a hole.")),
-                ]);
+                ];
+
+                if missing_because_of_guard {
+                    stack.push(alloc.note("").append(alloc.concat([
+                        alloc.reflow("Some of these are only handled by branches with an "),
+                        alloc.keyword("if"),
+                        alloc.reflow(
+                            " guard. Nothing else handles them when the guard is false, \
+                            so they need a branch without a guard too.",
+                        ),
+                    ])));
+                }
+
+                let doc = alloc.stack(stack);
 
                 Report {
                     filename,
@@ -4735,6 +4783,15 @@ fn exhaustive_problem<'a>(
     }
 }
 
+/// Patterns that are only missing because the branches matching them have guards are wrapped in
+/// the guard constructor; see [roc_exhaustive::check].
+fn is_missing_because_of_guard(pattern: &roc_exhaustive::Pattern) -> bool {
+    matches!(
+        pattern,
+        roc_exhaustive::Pattern::Ctor(union, ..) if union.render_as == roc_exhaustive::RenderAs::Guard
+    )
+}
+
 pub fn unhandled_patterns_to_doc_block<'b>(
     alloc: &'b RocDocAllocator<'b>,
     patterns: Vec<roc_exhaustive::Pattern>,
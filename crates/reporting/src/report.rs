@@ -8,7 +8,7 @@ use std::path::{Path, PathBuf};
 use ven_pretty::{BoxAllocator, DocAllocator, DocBuilder, Render, RenderAnnotated};
 
 pub use crate::error::canonicalize::can_problem;
-pub use crate::error::parse::parse_problem;
+pub use crate::error::parse::{parse_problem, syntax_problem};
 pub use crate::error::r#type::type_problem;
 
 #[cfg(windows)]
@@ -13,7 +13,9 @@ mod test_reporting {
     use indoc::indoc;
     use roc_can::abilities::AbilitiesStore;
     use roc_can::expr::PendingDerives;
-    use roc_load::{self, ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, Threading};
+    use roc_load::{
+        self, ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, OptLevel, Threading,
+    };
     use roc_module::symbol::{Interns, ModuleId};
     use roc_packaging::cache::RocCacheDir;
     use roc_parse::module::parse_header;
@@ -127,6 +129,7 @@ mod test_reporting {
                 palette: DEFAULT_PALETTE,
                 threading: Threading::Single,
                 exec_mode: ExecutionMode::Check,
+                opt_level: OptLevel::Normal,
             };
             let result = roc_load::load_and_typecheck(
                 arena,
@@ -5144,6 +5147,143 @@ Tab characters are not allowed."###,
     "###
     );
 
+    test_report!(
+        syntax_errors_in_separate_defs,
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            x = if 5 == 5 then 1
+
+            y = [1, 2,
+
+            main = Str.concat "a" 1
+            "#
+        ),
+        @r###"
+    ── UNFINISHED IF ───────────────── tmp/syntax_errors_in_separate_defs/Test.roc ─
+
+    I was partway through parsing an `if` expression, but I got stuck here:
+
+    3│  x = if 5 == 5 then 1
+                            ^
+
+    I was expecting to see the `else` keyword next.
+
+    ── UNFINISHED LIST ─────────────── tmp/syntax_errors_in_separate_defs/Test.roc ─
+
+    I am partway through started parsing a list, but I got stuck here:
+
+    5│  y = [1, 2,
+    6│
+    7│  main = Str.concat "a" 1
+        ^
+
+    I was expecting to see a closing square bracket before this, so try
+    adding a ] and see if that helps?
+
+    Note: When I get stuck like this, it usually means that there is a
+    missing parenthesis or bracket somewhere earlier. It could also be a
+    stray keyword or operator.
+
+    ── TYPE MISMATCH ───────────────── tmp/syntax_errors_in_separate_defs/Test.roc ─
+
+    This 2nd argument to `concat` has an unexpected type:
+
+    7│  main = Str.concat "a" 1
+                              ^
+
+    The argument is a number of type:
+
+        Num *
+
+    But `concat` needs its 2nd argument to be:
+
+        Str
+    "###
+    );
+
+    test_report!(
+        syntax_error_in_when_branch,
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            main =
+                when 4 is
+                    5 -> (1
+                    _ -> 2
+
+            x = )
+            "#
+        ),
+        @r###"
+    ── UNKNOWN OPERATOR ───────────────── tmp/syntax_error_in_when_branch/Test.roc ─
+
+    This looks like an operator, but it's not one I recognize!
+
+    5│          5 -> (1
+    6│          _ -> 2
+                  ^^
+
+    The arrow -> is only used to define cases in a ``when``expression:
+
+        when color is
+            Red -> "stop!"
+            Green -> "go!"
+
+    And to define a function:
+
+        increment : I64 -> I64
+        increment = \n -> n + 1
+
+    ── MISSING EXPRESSION ─────────────── tmp/syntax_error_in_when_branch/Test.roc ─
+
+    I am partway through parsing a definition, but I got stuck here:
+
+    8│  x = )
+            ^
+
+    I was expecting to see an expression like 42 or "hello".
+    "###
+    );
+
+    test_report!(
+        syntax_error_in_middle_when_branch,
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            main =
+                when Red is
+                    Red -> "stop"
+                    Yellow -> ("slow"
+                    Green -> "go"
+                    Blue -> "?"
+            "#
+        ),
+        @r###"
+    ── UNKNOWN OPERATOR ────────── tmp/syntax_error_in_middle_when_branch/Test.roc ─
+
+    This looks like an operator, but it's not one I recognize!
+
+    6│          Yellow -> ("slow"
+    7│          Green -> "go"
+                      ^^
+
+    The arrow -> is only used to define cases in a ``when``expression:
+
+        when color is
+            Red -> "stop!"
+            Green -> "go!"
+
+    And to define a function:
+
+        increment : I64 -> I64
+        increment = \n -> n + 1
+    "###
+    );
+
     // this should get better with time
     test_report!(
         if_missing_else,
@@ -5251,20 +5391,42 @@ Tab characters are not allowed."###,
     );
 
     test_report!(
-        interpolate_not_identifier,
+        interpolate_non_str,
         r#""abc\(32)def""#,
         @r###"
-    ── SYNTAX PROBLEM ──────────────────────────────────────── /code/proj/Main.roc ─
+    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
 
-    This string interpolation is invalid:
+    This interpolated value needs to be a Str:
 
     4│      "abc\(32)def"
                   ^^
 
-    I was expecting an identifier, like \u(message) or
-    \u(LoremIpsum.text).
+    Right now it’s a number of type:
+
+        Num *
+
+    But only strings can be interpolated. Functions like `Num.toStr` can
+    turn other values into strings.
+    "###
+    );
+
+    test_report!(
+        interpolate_lone_non_str,
+        r#""\(32)""#,
+        @r###"
+    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+
+    This interpolated value needs to be a Str:
+
+    4│      "\(32)"
+               ^^
 
-    Learn more about string interpolation at TODO
+    Right now it’s a number of type:
+
+        Num *
+
+    But only strings can be interpolated. Functions like `Num.toStr` can
+    turn other values into strings.
     "###
     );
 
@@ -7999,9 +8161,141 @@ In roc, functions are always written as a lambda, like{}
         A    (note the lack of an if clause)
 
     I would have to crash if I saw one of those! Add branches for them!
+
+    Note: Some of these are only handled by branches with an `if` guard.
+    Nothing else handles them when the guard is false, so they need a
+    branch without a guard too.
+    "###
+    );
+
+    test_report!(
+        non_exhaustive_with_guard_and_missing_tag,
+        indoc!(
+            r#"
+            x : [A, B]
+            when x is
+                A if Bool.true -> ""
+            "#
+        ),
+        @r###"
+    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+
+    This `when` does not cover all the possibilities:
+
+    5│>      when x is
+    6│>          A if Bool.true -> ""
+
+    Other possibilities include:
+
+        B
+        A    (note the lack of an if clause)
+
+    I would have to crash if I saw one of those! Add branches for them!
+
+    Note: Some of these are only handled by branches with an `if` guard.
+    Nothing else handles them when the guard is false, so they need a
+    branch without a guard too.
     "###
     );
 
+    test_report!(
+        non_exhaustive_list_with_guard,
+        indoc!(
+            r#"
+            l : List U8
+            when l is
+                [] -> ""
+                [x, ..] if x > 0 -> ""
+            "#
+        ),
+        @r###"
+    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+
+    This `when` does not cover all the possibilities:
+
+    5│>      when l is
+    6│>          [] -> ""
+    7│>          [x, ..] if x > 0 -> ""
+
+    Other possibilities include:
+
+        [_, ..]    (note the lack of an if clause)
+
+    I would have to crash if I saw one of those! Add branches for them!
+
+    Note: Some of these are only handled by branches with an `if` guard.
+    Nothing else handles them when the guard is false, so they need a
+    branch without a guard too.
+    "###
+    );
+
+    test_report!(
+        non_exhaustive_list_after_wildcard,
+        indoc!(
+            r#"
+            a : List U8
+            b : List U8
+            when T a b is
+                T ([]) _ -> ""
+                T _ ([]) -> ""
+            "#
+        ),
+        @r###"
+    ── UNSAFE PATTERN ──────────────────────────────────────── /code/proj/Main.roc ─
+
+    This `when` does not cover all the possibilities:
+
+    6│>      when T a b is
+    7│>          T ([]) _ -> ""
+    8│>          T _ ([]) -> ""
+
+    Other possibilities include:
+
+        T [_, ..] [_, ..]
+
+    I would have to crash if I saw one of those! Add branches for them!
+    "###
+    );
+
+    test_report!(
+        redundant_guarded_branch,
+        indoc!(
+            r#"
+            x : [A, B]
+            when x is
+                A -> ""
+                A if Bool.true -> ""
+                B -> ""
+            "#
+        ),
+        @r###"
+    ── REDUNDANT PATTERN ───────────────────────────────────── /code/proj/Main.roc ─
+
+    The 2nd pattern is redundant:
+
+    5│       when x is
+    6│           A -> ""
+    7│>          A if Bool.true -> ""
+    8│           B -> ""
+
+    Any value of this shape will be handled by a previous pattern, so this
+    one should be removed.
+    "###
+    );
+
+    test_no_problem!(
+        guarded_branches_after_guarded_branch,
+        indoc!(
+            r#"
+            x : U8
+            when x is
+                n if n > 10 -> ""
+                n if n > 5 -> ""
+                _ -> ""
+            "#
+        )
+    );
+
     test_report!(
         invalid_record_extension_type,
         indoc!(
@@ -8070,8 +8364,8 @@ In roc, functions are always written as a lambda, like{}
 
         Type
         Unsigned8
-        Unsigned32
         Unsigned16
+        Unsigned64
 
     ── UNRECOGNIZED NAME ───────────────────────────────────── /code/proj/Main.roc ─
 
@@ -8084,8 +8378,8 @@ In roc, functions are always written as a lambda, like{}
 
         Type
         Unsigned8
-        Unsigned32
         Unsigned16
+        Unsigned64
     "###
     );
 
@@ -9582,7 +9876,8 @@ In roc, functions are always written as a lambda, like{}
 
     Only builtin abilities can be derived.
 
-    Note: The builtin abilities are `Encoding`, `Decoding`, `Hash`, `Eq`
+    Note: The builtin abilities are `Encoding`, `Decoding`, `Hash`, `Eq`, `Inspect`,
+    `Sort`
     "###
     );
 
@@ -11124,6 +11419,158 @@ I recommend using camelCase. It's the standard style in Roc code!
     "###
     );
 
+    test_report!(
+        derive_inspect_for_non_inspect_opaque,
+        indoc!(
+            r#"
+             app "test" provides [A] to "./platform"
+
+             A := B has [Inspect]
+
+             B := {}
+             "#
+        ),
+        @"" // no error, `B` is shown as `<opaque>`
+    );
+
+    test_report!(
+        derive_inspect_for_record,
+        indoc!(
+            r#"
+             app "test" provides [main] to "./platform"
+
+             main = Inspect.toStr {a: "", b: 1.5, c: [Bool.true], d: Dict.single "k" (Set.single 1u8)}
+             "#
+        ),
+        @"" // no error
+    );
+
+    test_report!(
+        cannot_derive_inspect_for_function,
+        indoc!(
+            r#"
+             app "test" provides [main] to "./platform"
+
+             main = Inspect.toStr (\x -> x)
+             "#
+        ),
+        @r###"
+    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+
+    This expression has a type that does not implement the abilities it's expected to:
+
+    3│  main = Inspect.toStr (\x -> x)
+                              ^^^^^^^
+
+    I can't generate an implementation of the `Inspect` ability for
+
+        a -> a
+
+    Note: `Inspect` cannot be generated for functions.
+    "###
+    );
+
+    test_report!(
+        cannot_derive_inspect_for_dict_containing_function,
+        indoc!(
+            r#"
+             app "test" provides [main] to "./platform"
+
+             main = Inspect.toStr (Dict.single "f" (\x -> x))
+             "#
+        ),
+        @r###"
+    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+
+    This expression has a type that does not implement the abilities it's expected to:
+
+    3│  main = Inspect.toStr (Dict.single "f" (\x -> x))
+                              ^^^^^^^^^^^^^^^^^^^^^^^^^
+
+    I can't generate an implementation of the `Inspect` ability for
+
+        Dict Str (a -> a)
+
+    In particular, an implementation for
+
+        a -> a
+
+    cannot be generated.
+
+    Note: `Inspect` cannot be generated for functions.
+    "###
+    );
+
+    test_report!(
+        cannot_derive_inspect_for_record_with_optional_field,
+        indoc!(
+            r#"
+             app "test" provides [main] to "./platform"
+
+             main =
+                 f : {x : Str, y ? Str} -> Str
+                 f = \r -> Inspect.toStr r
+
+                 f
+             "#
+        ),
+        @r###"
+    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+
+    This expression has a type that does not implement the abilities it's expected to:
+
+    5│      f = \r -> Inspect.toStr r
+                                    ^
+
+    I can't generate an implementation of the `Inspect` ability for
+
+        {
+            x : Str,
+            y ? Str,
+        }
+
+    Note: I can't derive `Inspect` for a record with an optional field,
+    which in this case is `.y`. Optional record fields are polymorphic over
+    records that may or may not contain them at compile time, but are not
+    a concept that extends to runtime!
+    "###
+    );
+
+    test_report!(
+        cannot_derive_sort_for_record_with_optional_field,
+        indoc!(
+            r#"
+             app "test" provides [main] to "./platform"
+
+             main =
+                 f : {x : Str, y ? Str} -> [LT, EQ, GT]
+                 f = \r -> Sort.compare r r
+
+                 f
+             "#
+        ),
+        @r###"
+    ── TYPE MISMATCH ───────────────────────────────────────── /code/proj/Main.roc ─
+
+    This expression has a type that does not implement the abilities it's expected to:
+
+    5│      f = \r -> Sort.compare r r
+                                   ^
+
+    I can't generate an implementation of the `Sort` ability for
+
+        {
+            x : Str,
+            y ? Str,
+        }
+
+    Note: I can't derive `Sort` for a record with an optional field, which
+    in this case is `.y`. Optional record fields are polymorphic over
+    records that may or may not contain them at compile time, but are not
+    a concept that extends to runtime!
+    "###
+    );
+
     test_report!(
         shift_by_negative,
         indoc!(
@@ -13117,4 +13564,130 @@ I recommend using camelCase. It's the standard style in Roc code!
     I would have to crash if I saw one of those! Add branches for them!
     "###
     );
+
+    test_report!(
+        multiple_record_builders,
+        indoc!(
+            r#"
+            succeed
+                { a: <- apply "a" }
+                { b: <- apply "b" }
+            "#
+        ),
+        @r###"
+    ── MULTIPLE RECORD BUILDERS ────────────────────────────── /code/proj/Main.roc ─
+
+    This function is applied to multiple record builders:
+
+    4│>      succeed
+    5│>          { a: <- apply "a" }
+    6│>          { b: <- apply "b" }
+
+    Note: Functions can only take at most one record builder!
+
+    Tip: You can combine them or apply them separately.
+    "###
+    );
+
+    test_report!(
+        unapplied_record_builder,
+        indoc!(
+            r#"
+            { a: <- apply "a" }
+            "#
+        ),
+        @r###"
+    ── UNAPPLIED RECORD BUILDER ────────────────────────────── /code/proj/Main.roc ─
+
+    This record builder was not applied to a function:
+
+    4│      { a: <- apply "a" }
+            ^^^^^^^^^^^^^^^^^^^
+
+    However, we need a function to construct the record.
+
+    Note: Functions must be applied directly. The pipe operator (|>) cannot be used.
+    "###
+    );
+
+    test_report!(
+        record_builder_apply_non_function,
+        indoc!(
+            r#"
+            succeed = \_ -> crash ""
+
+            succeed {
+                a: <- "a",
+            }
+            "#
+        ),
+        @r###"
+    ── TOO MANY ARGS ───────────────────────────────────────── /code/proj/Main.roc ─
+
+    This value is not a function, but it was given 1 argument:
+
+    7│          a: <- "a",
+                      ^^^
+
+    Are there any missing commas? Or missing parentheses?
+    "###
+    );
+
+    test_report!(
+        optional_field_in_record_builder,
+        indoc!(
+            r#"
+            {
+                a: <- apply "a",
+                b,
+                c ? "optional"
+            }
+            "#
+        ),
+        @r###"
+    ── BAD RECORD BUILDER ────────── tmp/optional_field_in_record_builder/Test.roc ─
+
+    I am partway through parsing a record builder, and I found an optional
+    field:
+
+    1│  app "test" provides [main] to "./platform"
+    2│
+    3│  main =
+    4│      {
+    5│          a: <- apply "a",
+    6│          b,
+    7│          c ? "optional"
+                ^^^^^^^^^^^^^^
+
+    Optional fields can only appear when you destructure a record.
+    "###
+    );
+
+    test_report!(
+        record_update_builder,
+        indoc!(
+            r#"
+            { rec &
+                a: <- apply "a",
+                b: 3
+            }
+            "#
+        ),
+        @r###"
+    ── BAD RECORD UPDATE ────────────────────── tmp/record_update_builder/Test.roc ─
+
+    I am partway through parsing a record update, and I found a record
+    builder field:
+
+    1│   app "test" provides [main] to "./platform"
+    2│
+    3│   main =
+    4│>      { rec &
+    5│>          a: <- apply "a",
+    6│>          b: 3
+    7│>      }
+
+    Record builders cannot be updated like records.
+    "###
+    );
 }
@@ -0,0 +1,233 @@
+//! Records how long each compiler phase took, on which thread, and for which module, so they can
+//! be written out in the [Chrome trace event format] (e.g. by `roc build --time=trace.json`).
+//!
+//! The resulting file can be opened in `chrome://tracing` or <https://ui.perfetto.dev>.
+//!
+//! Unlike the rest of this crate, this works in release builds too, since that's where timings
+//! are interesting. Recording is off until [enable] is called, and costs one atomic load per
+//! phase when it's off.
+//!
+//! [Chrome trace event format]: https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU
+
+use std::cell::Cell;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Mutex;
+use std::time::Instant;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static GENERATION: AtomicU32 = AtomicU32::new(0);
+static RECORDER: Mutex<Option<Recorder>> = Mutex::new(None);
+
+thread_local! {
+    /// This thread's id, and the generation of the recorder that it was given out by
+    static TID: Cell<Option<(u32, u32)>> = const { Cell::new(None) };
+}
+
+struct Recorder {
+    /// Every call to [enable] makes a new generation, so that threads which were given an id
+    /// by an earlier recorder (e.g. before `roc dev --watch` restarted) get named again.
+    generation: u32,
+    next_tid: u32,
+    epoch: Instant,
+    threads: Vec<(u32, String)>,
+    events: Vec<Event>,
+}
+
+struct Event {
+    phase: String,
+    module: Option<String>,
+    tid: u32,
+    start: Instant,
+    end: Instant,
+}
+
+/// Starts recording phases. Timestamps in the trace are relative to when this was called.
+pub fn enable() {
+    *RECORDER.lock().unwrap() = Some(Recorder {
+        generation: GENERATION.fetch_add(1, Ordering::Relaxed) + 1,
+        next_tid: 1,
+        epoch: Instant::now(),
+        threads: Vec::new(),
+        events: Vec::new(),
+    });
+
+    ENABLED.store(true, Ordering::Release);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Acquire)
+}
+
+/// Records that the current thread spent from `start` to `end` in the given phase, optionally
+/// on behalf of a particular module. Does nothing unless recording has been [enable]d.
+pub fn record(phase: &str, module: Option<&str>, start: Instant, end: Instant) {
+    if !is_enabled() {
+        return;
+    }
+
+    let mut recorder = RECORDER.lock().unwrap();
+    let recorder = match recorder.as_mut() {
+        Some(recorder) => recorder,
+        None => return,
+    };
+
+    let tid = TID.with(|tid| match tid.get() {
+        Some((generation, id)) if generation == recorder.generation => id,
+        _ => {
+            let id = recorder.next_tid;
+            recorder.next_tid += 1;
+
+            let thread = std::thread::current();
+            let name = match thread.name() {
+                Some(name) => name.to_string(),
+                None => format!("thread {}", id),
+            };
+
+            recorder.threads.push((id, name));
+            tid.set(Some((recorder.generation, id)));
+
+            id
+        }
+    });
+
+    recorder.events.push(Event {
+        phase: phase.to_string(),
+        module: module.map(|name| name.to_string()),
+        tid,
+        start,
+        end,
+    });
+}
+
+/// Writes everything recorded so far to the given file, as a JSON array of trace events.
+pub fn write(path: &Path) -> io::Result<()> {
+    let recorder = RECORDER.lock().unwrap();
+    let mut buf = String::from("[\n");
+
+    if let Some(recorder) = recorder.as_ref() {
+        for (tid, name) in recorder.threads.iter() {
+            writeln!(
+                buf,
+                r#"{{"name":"thread_name","ph":"M","pid":1,"tid":{},"args":{{"name":{}}}}},"#,
+                tid,
+                json_string(name)
+            )
+            .unwrap();
+        }
+
+        for event in recorder.events.iter() {
+            // Timestamps and durations are in microseconds
+            let ts = event.start.duration_since(recorder.epoch).as_nanos() as f64 / 1000.0;
+            let dur = event.end.duration_since(event.start).as_nanos() as f64 / 1000.0;
+
+            write!(
+                buf,
+                r#"{{"name":{},"cat":"roc","ph":"X","ts":{:.3},"dur":{:.3},"pid":1,"tid":{}"#,
+                json_string(&event.phase),
+                ts,
+                dur,
+                event.tid
+            )
+            .unwrap();
+
+            if let Some(module) = &event.module {
+                write!(buf, r#","args":{{"module":{}}}"#, json_string(module)).unwrap();
+            }
+
+            buf.push_str("},\n");
+        }
+    }
+
+    // Every event above ends in a comma, and JSON doesn't allow trailing ones, so the
+    // process name goes last.
+    buf.push_str(r#"{"name":"process_name","ph":"M","pid":1,"args":{"name":"roc"}}"#);
+    buf.push_str("\n]\n");
+
+    fs::write(path, buf)
+}
+
+fn json_string(string: &str) -> String {
+    let mut buf = String::with_capacity(string.len() + 2);
+
+    buf.push('"');
+
+    for ch in string.chars() {
+        match ch {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            ch if (ch as u32) < 0x20 => write!(buf, "\\u{:04x}", ch as u32).unwrap(),
+            ch => buf.push(ch),
+        }
+    }
+
+    buf.push('"');
+
+    buf
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn escape_json_strings() {
+        assert_eq!(r#""Str""#, json_string("Str"));
+        assert_eq!(r#""say \"hi\"\\\n\u0009""#, json_string("say \"hi\"\\\n\t"));
+        assert_eq!(r#""🐦""#, json_string("🐦"));
+    }
+
+    #[test]
+    fn write_trace() {
+        enable();
+
+        let start = Instant::now();
+        std::thread::Builder::new()
+            .name("worker".to_string())
+            .spawn(move || record("solve", Some("Main"), start, start))
+            .unwrap()
+            .join()
+            .unwrap();
+        std::thread::spawn(move || record("link", None, start, start))
+            .join()
+            .unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("trace.json");
+        write(&path).unwrap();
+
+        let trace = fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = trace.lines().collect();
+
+        assert_eq!(
+            vec![
+                "[",
+                r#"{"name":"thread_name","ph":"M","pid":1,"tid":1,"args":{"name":"worker"}},"#,
+                r#"{"name":"thread_name","ph":"M","pid":1,"tid":2,"args":{"name":"thread 2"}},"#,
+                r#"{"name":"process_name","ph":"M","pid":1,"args":{"name":"roc"}}"#,
+                "]",
+            ],
+            [&lines[..3], &lines[5..]].concat()
+        );
+        assert!(lines[3].starts_with(r#"{"name":"solve","cat":"roc","ph":"X","ts":"#));
+        assert!(lines[3].ends_with(r#","dur":0.000,"pid":1,"tid":1,"args":{"module":"Main"}},"#));
+        assert!(lines[4].starts_with(r#"{"name":"link","cat":"roc","ph":"X","ts":"#));
+        assert!(lines[4].ends_with(r#","dur":0.000,"pid":1,"tid":2},"#));
+
+        // A thread that recorded before enabling again gets named again in the new trace
+        record("load", None, start, start);
+        enable();
+        record("load", None, start, start);
+        write(&path).unwrap();
+
+        let trace = fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = trace.lines().collect();
+
+        assert!(lines[1].starts_with(r#"{"name":"thread_name","ph":"M","pid":1,"tid":1,"#));
+        assert!(lines[2].ends_with(r#","dur":0.000,"pid":1,"tid":1},"#));
+    }
+}
@@ -11,7 +11,8 @@
 //! This enables us to easily modify the tracing infrastructure without inducing sweeping changes.
 //!
 //! Tracing is only turned on in debug builds. Use the provided [setup_tracing] macro to turn on
-//! tracing at an executable's entry point.
+//! tracing at an executable's entry point. The exception is [chrome_trace], which records
+//! compiler phase timings in any build, for `roc build --time=<file>`.
 //!
 //! [directive-syntax]: https://docs.rs/tracing-subscriber/latest/tracing_subscriber/filter/struct.EnvFilter.html#directives
 
@@ -31,6 +32,8 @@ macro_rules! setup_tracing {
     };
 }
 
+pub mod chrome_trace;
+
 pub use tracing::debug;
 pub use tracing::info;
 
@@ -94,6 +94,7 @@ fn valgrind_test_linux(source: &str) {
             problems,
             total_time: _,
             expect_metadata: _,
+            loaded_files: _,
         }) => {
             if problems.exit_code() != 0 {
                 panic!("there are problems")
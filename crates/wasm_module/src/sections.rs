@@ -1,5 +1,6 @@
 use std::fmt::{Debug, Formatter};
 use std::io::Write;
+use std::ops::Range;
 
 use bumpalo::collections::vec::Vec;
 use bumpalo::Bump;
@@ -298,6 +299,11 @@ impl<'a> TypeSection<'a> {
         self.bytes.is_empty()
     }
 
+    /// The number of signatures in the section
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
     pub fn look_up(&'a self, sig_index: u32) -> (SignatureParamsIter<'a>, Option<ValueType>) {
         let mut offset = self.offsets[sig_index as usize];
         offset += 1; // separator
@@ -1570,6 +1576,32 @@ impl<'a> DataSection<'a> {
         index
     }
 
+    /// The section body as the offsets in "reloc.DATA" count it: the segment count, then the segments
+    pub fn relocatable_bytes(&self) -> std::vec::Vec<u8> {
+        let mut bytes = std::vec::Vec::with_capacity(MAX_SIZE_ENCODED_U32 + self.bytes.len());
+        bytes.encode_u32(self.count);
+        bytes.extend_from_slice(&self.bytes);
+        bytes
+    }
+
+    /// The mode of each segment, and where its contents are in `relocatable_bytes`
+    pub fn segments(&self) -> Result<std::vec::Vec<(DataMode, Range<usize>)>, ParseError> {
+        let mut count_bytes = std::vec::Vec::with_capacity(MAX_SIZE_ENCODED_U32);
+        count_bytes.encode_u32(self.count);
+
+        let mut segments = std::vec::Vec::with_capacity(self.count as usize);
+        let mut cursor = 0;
+        for _ in 0..self.count {
+            let mode = DataMode::parse((), &self.bytes, &mut cursor)?;
+            let len = u32::parse((), &self.bytes, &mut cursor)? as usize;
+            let start = count_bytes.len() + cursor;
+            segments.push((mode, start..start + len));
+            cursor += len;
+        }
+
+        Ok(segments)
+    }
+
     pub fn load_into(&self, memory: &mut [u8]) -> Result<(), String> {
         let mut cursor = 0;
         for _ in 0..self.count {
@@ -1613,22 +1645,21 @@ impl<'a> Parse<&'a Bump> for DataSection<'a> {
         let mut end_addr = 0;
         for _ in 0..count {
             let mode = DataMode::parse((), module_bytes, cursor)?;
-            match mode {
-                DataMode::Active {
-                    offset: ConstExpr::I32(offset_addr),
-                } if offset_addr > end_addr => {
-                    end_addr = offset_addr;
-                }
-                _ => {}
-            }
             let segment_bytes_len = u32::parse((), module_bytes, cursor)?;
             *cursor += segment_bytes_len as usize;
+
+            if let DataMode::Active {
+                offset: ConstExpr::I32(offset_addr),
+            } = mode
+            {
+                end_addr = end_addr.max(offset_addr as u32 + segment_bytes_len);
+            }
         }
 
         debug_assert_eq!(*cursor, end);
 
         Ok(DataSection {
-            end_addr: end_addr as u32,
+            end_addr,
             count,
             bytes,
         })
@@ -1920,4 +1951,30 @@ mod tests {
         }
         test_assert_types_preload(arena, &section);
     }
+
+    #[test]
+    fn test_data_section_end_addr() {
+        let arena = &Bump::new();
+        let mut section = DataSection::new(arena);
+        section.append_segment(DataSegment {
+            mode: DataMode::active_at(16),
+            init: bumpalo::vec![in arena; 1, 2, 3, 4],
+        });
+        section.append_segment(DataSegment {
+            mode: DataMode::active_at(0),
+            init: bumpalo::vec![in arena; 0; 8],
+        });
+        section.append_segment(DataSegment {
+            mode: DataMode::Passive,
+            init: bumpalo::vec![in arena; 0; 100],
+        });
+
+        let mut serialized = Vec::new_in(arena);
+        section.serialize(&mut serialized);
+        let mut cursor = 0;
+        let preloaded = DataSection::parse(arena, &serialized, &mut cursor).unwrap();
+
+        // The end of the last active segment, not its start
+        assert_eq!(preloaded.end_addr, 20);
+    }
 }
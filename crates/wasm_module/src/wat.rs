@@ -0,0 +1,318 @@
+//! Print a module in the WebAssembly text format, for `roc build --emit=wat`.
+//!
+//! This is meant for humans reading the code we generate, so it only prints the parts of the
+//! module that are useful for that: types, imports, functions and exports. Instructions are
+//! printed flat (not folded), with blocks indented.
+use std::fmt::Write;
+
+use crate::opcodes::{immediates_for, OpCode, OpImmediates};
+use crate::parse::{Parse, ParseError};
+use crate::sections::{ImportDesc, Limits};
+use crate::{ExportType, ValueType, WasmModule};
+
+pub fn module_to_wat<'a>(module: &'a WasmModule<'a>) -> Result<String, ParseError> {
+    let mut buf = String::from("(module\n");
+
+    for sig_index in 0..module.types.len() as u32 {
+        write!(buf, "  (type (;{};) (func", sig_index).unwrap();
+        push_signature(&mut buf, module, sig_index);
+        buf.push_str("))\n");
+    }
+
+    let mut fn_index = 0;
+    for import in module.import.imports.iter() {
+        write!(buf, "  (import {:?} {:?} ", import.module, import.name).unwrap();
+        match &import.description {
+            ImportDesc::Func { signature_index } => {
+                push_function_header(&mut buf, module, fn_index, *signature_index);
+                fn_index += 1;
+            }
+            ImportDesc::Table { ty } => {
+                buf.push_str("(table ");
+                push_limits(&mut buf, &ty.limits);
+                buf.push_str(" funcref");
+            }
+            ImportDesc::Mem { limits } => {
+                buf.push_str("(memory ");
+                push_limits(&mut buf, limits);
+            }
+            ImportDesc::Global { ty } => {
+                buf.push_str("(global ");
+                if ty.is_mutable {
+                    write!(buf, "(mut {})", value_type_name(ty.value_type)).unwrap();
+                } else {
+                    buf.push_str(value_type_name(ty.value_type));
+                }
+            }
+        }
+        buf.push_str("))\n");
+    }
+
+    let code_bytes = &module.code.bytes;
+    for (signature_index, fn_offset) in module
+        .function
+        .signatures
+        .iter()
+        .zip(module.code.function_offsets.iter())
+    {
+        buf.push_str("  ");
+        push_function_header(&mut buf, module, fn_index, *signature_index);
+        buf.push('\n');
+        push_function_body(&mut buf, module, code_bytes, *fn_offset as usize)?;
+        buf.push_str("  )\n");
+        fn_index += 1;
+    }
+
+    for export in module.export.exports.iter() {
+        let kind = match export.ty {
+            ExportType::Func => "func",
+            ExportType::Table => "table",
+            ExportType::Mem => "memory",
+            ExportType::Global => "global",
+        };
+        writeln!(
+            buf,
+            "  (export {:?} ({} {}))",
+            export.name, kind, export.index
+        )
+        .unwrap();
+    }
+
+    buf.push_str(")\n");
+
+    Ok(buf)
+}
+
+fn push_function_header<'a>(
+    buf: &mut String,
+    module: &'a WasmModule<'a>,
+    fn_index: u32,
+    signature_index: u32,
+) {
+    buf.push_str("(func ");
+    if let Some(name) = function_name(module, fn_index) {
+        buf.push('$');
+        push_identifier(buf, name);
+        buf.push(' ');
+    }
+    write!(buf, "(;{};) (type {})", fn_index, signature_index).unwrap();
+    push_signature(buf, module, signature_index);
+}
+
+fn push_signature<'a>(buf: &mut String, module: &'a WasmModule<'a>, signature_index: u32) {
+    let (params, opt_ret_type) = module.types.look_up(signature_index);
+    let mut params = params.peekable();
+
+    if params.peek().is_some() {
+        buf.push_str(" (param");
+        for param in params {
+            buf.push(' ');
+            buf.push_str(value_type_name(param));
+        }
+        buf.push(')');
+    }
+
+    if let Some(ret_type) = opt_ret_type {
+        write!(buf, " (result {})", value_type_name(ret_type)).unwrap();
+    }
+}
+
+fn push_function_body(
+    buf: &mut String,
+    module: &WasmModule,
+    bytes: &[u8],
+    fn_offset: usize,
+) -> Result<(), ParseError> {
+    let mut cursor = fn_offset;
+    let fn_length = u32::parse((), bytes, &mut cursor)? as usize;
+    let fn_end = cursor + fn_length;
+
+    let local_groups = u32::parse((), bytes, &mut cursor)?;
+    if local_groups > 0 {
+        buf.push_str("    (local");
+        for _ in 0..local_groups {
+            let count = u32::parse((), bytes, &mut cursor)?;
+            let value_type = ValueType::from(bytes[cursor]);
+            cursor += 1;
+            for _ in 0..count {
+                buf.push(' ');
+                buf.push_str(value_type_name(value_type));
+            }
+        }
+        buf.push_str(")\n");
+    }
+
+    let mut depth = 0;
+    while cursor < fn_end {
+        let op_offset = cursor;
+        let op = OpCode::from(bytes[cursor]);
+        let immediates = immediates_for(op).map_err(|message| ParseError {
+            message,
+            offset: op_offset,
+        })?;
+        cursor += 1;
+
+        if matches!(op, OpCode::END | OpCode::ELSE) {
+            if depth == 0 {
+                // The function body's own `end` is implied by the closing paren
+                debug_assert_eq!(op, OpCode::END);
+                break;
+            }
+            depth -= 1;
+        }
+
+        for _ in 0..depth + 2 {
+            buf.push_str("  ");
+        }
+        buf.push_str(op.wat_name());
+
+        match immediates {
+            OpImmediates::NoImmediate => {}
+            OpImmediates::Byte1 => {
+                let byte = bytes[cursor];
+                cursor += 1;
+                // memory.size and memory.grow have a reserved zero byte, which isn't printed
+                if matches!(op, OpCode::BLOCK | OpCode::LOOP | OpCode::IF)
+                    && byte != ValueType::VOID
+                {
+                    write!(buf, " (result {})", value_type_name(ValueType::from(byte))).unwrap();
+                }
+            }
+            OpImmediates::Bytes4 => {
+                let value = f32::from_le_bytes(bytes[cursor..][..4].try_into().unwrap());
+                cursor += 4;
+                push_float(buf, value as f64);
+            }
+            OpImmediates::Bytes8 => {
+                let value = f64::from_le_bytes(bytes[cursor..][..8].try_into().unwrap());
+                cursor += 8;
+                push_float(buf, value);
+            }
+            OpImmediates::Leb32x1 => {
+                if op == OpCode::I32CONST {
+                    write!(buf, " {}", i32::parse((), bytes, &mut cursor)?).unwrap();
+                } else {
+                    let index = u32::parse((), bytes, &mut cursor)?;
+                    match function_name(module, index) {
+                        Some(name) if op == OpCode::CALL => {
+                            buf.push_str(" $");
+                            push_identifier(buf, name);
+                        }
+                        _ => write!(buf, " {}", index).unwrap(),
+                    }
+                }
+            }
+            OpImmediates::Leb64x1 => {
+                write!(buf, " {}", i64::parse((), bytes, &mut cursor)?).unwrap();
+            }
+            OpImmediates::Leb32x2 => {
+                let first = u32::parse((), bytes, &mut cursor)?;
+                let second = u32::parse((), bytes, &mut cursor)?;
+                if op == OpCode::CALLINDIRECT {
+                    // type index, then table index
+                    write!(buf, " (type {})", first).unwrap();
+                } else {
+                    // memarg: alignment (as a power of 2), then offset
+                    if second != 0 {
+                        write!(buf, " offset={}", second).unwrap();
+                    }
+                    write!(buf, " align={}", 1u32 << first).unwrap();
+                }
+            }
+            OpImmediates::BrTable => {
+                // The default label comes after the others
+                let label_count = 1 + u32::parse((), bytes, &mut cursor)?;
+                for _ in 0..label_count {
+                    write!(buf, " {}", u32::parse((), bytes, &mut cursor)?).unwrap();
+                }
+            }
+        }
+
+        buf.push('\n');
+
+        if matches!(op, OpCode::BLOCK | OpCode::LOOP | OpCode::IF | OpCode::ELSE) {
+            depth += 1;
+        }
+    }
+
+    Ok(())
+}
+
+fn function_name<'a>(module: &'a WasmModule<'a>, fn_index: u32) -> Option<&'a str> {
+    module
+        .names
+        .function_names
+        .iter()
+        .find(|(index, _)| *index == fn_index)
+        .map(|(_, name)| *name)
+}
+
+/// WAT identifiers can only contain printable ASCII, excluding spaces, quotes, commas,
+/// semicolons and brackets. Anything else is replaced with an underscore.
+fn push_identifier(buf: &mut String, name: &str) {
+    for c in name.chars() {
+        let is_allowed = c.is_ascii_graphic() && !"\"',;()[]{}".contains(c);
+        buf.push(if is_allowed { c } else { '_' });
+    }
+}
+
+fn push_limits(buf: &mut String, limits: &Limits) {
+    match limits {
+        Limits::Min(min) => write!(buf, "{}", min).unwrap(),
+        Limits::MinMax(min, max) => write!(buf, "{} {}", min, max).unwrap(),
+    }
+}
+
+fn push_float(buf: &mut String, value: f64) {
+    if value.is_nan() {
+        buf.push_str(" nan");
+    } else if value.is_infinite() {
+        buf.push_str(if value > 0.0 { " inf" } else { " -inf" });
+    } else {
+        write!(buf, " {:?}", value).unwrap();
+    }
+}
+
+fn value_type_name(value_type: ValueType) -> &'static str {
+    match value_type {
+        ValueType::I32 => "i32",
+        ValueType::I64 => "i64",
+        ValueType::F32 => "f32",
+        ValueType::F64 => "f64",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::module_to_wat;
+    use crate::WasmModule;
+    use bumpalo::Bump;
+
+    #[test]
+    fn constant_function() {
+        let bytes: &[u8] = &[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // magic number & version
+            0x01, 0x05, 0x01, 0x60, 0x00, 0x01, 0x7f, // type section: () -> i32
+            0x03, 0x02, 0x01, 0x00, // function section
+            0x07, 0x08, 0x01, 0x04, b'm', b'a', b'i', b'n', 0x00, 0x00, // export section
+            0x0a, 0x09, 0x01, 0x07, 0x00, 0x41, 0x2a, 0x41, 0x7f, 0x6a, 0x0b, // code section
+        ];
+        let arena = Bump::new();
+        let module = WasmModule::preload(&arena, bytes, false).unwrap();
+
+        assert_eq!(
+            module_to_wat(&module).unwrap(),
+            concat!(
+                "(module\n",
+                "  (type (;0;) (func (result i32)))\n",
+                "  (func (;0;) (type 0) (result i32)\n",
+                "    i32.const 42\n",
+                "    i32.const -1\n",
+                "    i32.add\n",
+                "  )\n",
+                "  (export \"main\" (func 0))\n",
+                ")\n",
+            )
+        );
+    }
+}